@@ -0,0 +1,310 @@
+// dear imgui: Gamepad input for Linux, read directly from evdev devices (/dev/input/event*)
+// This can be used along with any Platform Backend that doesn't feed gamepads itself (e.g. the null backend, or a custom one).
+// (Info: the user running the application needs read access to /dev/input/event*, which usually means being in the 'input' group.)
+
+// Implemented features:
+//  [X] Platform: Gamepad support. Enabled with 'io.ConfigFlags |= ImGuiConfigFlags_NavEnableGamepad'.
+//  [X] Platform: Analog sticks and triggers, with dead zones from io.ConfigGamepadStickDeadZone/io.ConfigGamepadTriggerDeadZone.
+//  [X] Platform: Hot-plug. The device list is rescanned while no gamepad is connected, and a disconnected gamepad releases all its keys.
+// Missing features:
+//  [ ] Platform: Only the first gamepad found is used.
+//  [ ] Platform: No rumble/force feedback.
+
+// Typical usage:
+//   let mut evdev = ImGui_ImplEvdev_Init(&mut g);
+//   loop {
+//       ImGui_ImplNull_NewFrame(&mut g);
+//       ImGui_ImplEvdev_NewFrame(&mut g, &mut evdev);
+//       NewFrame(&mut g);
+//       ...
+//   }
+//   ImGui_ImplEvdev_Shutdown(&mut g, evdev);
+// The evdev data is owned by the caller instead of living in io.backend_platform_user_data, so it can run next to a platform backend.
+
+// CHANGELOG
+//  2026-10-18: Initial version.
+
+use std::ffi::CString;
+use std::mem;
+use std::time::{Duration, Instant};
+use libc::{c_float, c_int, c_ulong, c_void, input_absinfo, input_event};
+use crate::backends::backend_flags::IM_GUI_BACKEND_FLAGS_HAS_GAMEPAD;
+use crate::core::config_flags::ImGuiConfigFlags_NavEnableGamepad;
+use crate::core::context::AppContext;
+use crate::io::key::{ImGuiKey, ImGuiKey_GamepadBack, ImGuiKey_GamepadDpadDown, ImGuiKey_GamepadDpadLeft, ImGuiKey_GamepadDpadRight, ImGuiKey_GamepadDpadUp, ImGuiKey_GamepadFaceDown, ImGuiKey_GamepadFaceLeft, ImGuiKey_GamepadFaceRight, ImGuiKey_GamepadFaceUp, ImGuiKey_GamepadL1, ImGuiKey_GamepadL2, ImGuiKey_GamepadL3, ImGuiKey_GamepadLStickDown, ImGuiKey_GamepadLStickLeft, ImGuiKey_GamepadLStickRight, ImGuiKey_GamepadLStickUp, ImGuiKey_GamepadR1, ImGuiKey_GamepadR2, ImGuiKey_GamepadR3, ImGuiKey_GamepadRStickDown, ImGuiKey_GamepadRStickLeft, ImGuiKey_GamepadRStickRight, ImGuiKey_GamepadRStickUp, ImGuiKey_GamepadStart};
+
+// Subset of <linux/input-event-codes.h>
+const EV_SYN: u16 = 0x00;
+const EV_KEY: u16 = 0x01;
+const EV_ABS: u16 = 0x03;
+const SYN_DROPPED: u16 = 3;
+
+const BTN_SOUTH: u16 = 0x130;
+const BTN_EAST: u16 = 0x131;
+const BTN_NORTH: u16 = 0x133;
+const BTN_WEST: u16 = 0x134;
+const BTN_TL: u16 = 0x136;
+const BTN_TR: u16 = 0x137;
+const BTN_TL2: u16 = 0x138;
+const BTN_TR2: u16 = 0x139;
+const BTN_SELECT: u16 = 0x13a;
+const BTN_START: u16 = 0x13b;
+const BTN_THUMBL: u16 = 0x13d;
+const BTN_THUMBR: u16 = 0x13e;
+const BTN_DPAD_UP: u16 = 0x220;
+const BTN_DPAD_DOWN: u16 = 0x221;
+const BTN_DPAD_LEFT: u16 = 0x222;
+const BTN_DPAD_RIGHT: u16 = 0x223;
+const BTN_GAMEPAD: u16 = BTN_SOUTH;
+const KEY_MAX: usize = 0x2ff;
+
+const ABS_X: u16 = 0x00;
+const ABS_Y: u16 = 0x01;
+const ABS_Z: u16 = 0x02;
+const ABS_RX: u16 = 0x03;
+const ABS_RY: u16 = 0x04;
+const ABS_RZ: u16 = 0x05;
+const ABS_HAT0X: u16 = 0x10;
+const ABS_HAT0Y: u16 = 0x11;
+const ABS_MAX: usize = 0x3f;
+
+// Subset of <linux/input.h> ioctl() requests
+const fn EVIOC_READ(nr: c_ulong, size: usize) -> c_ulong {
+    (2 << 30) | ((size as c_ulong) << 16) | ((b'E' as c_ulong) << 8) | nr
+}
+const fn EVIOCGNAME(len: usize) -> c_ulong { EVIOC_READ(0x06, len) }
+const fn EVIOCGKEY(len: usize) -> c_ulong { EVIOC_READ(0x18, len) }
+const fn EVIOCGBIT(ev: u16, len: usize) -> c_ulong { EVIOC_READ(0x20 + ev as c_ulong, len) }
+const fn EVIOCGABS(abs: u16) -> c_ulong { EVIOC_READ(0x40 + abs as c_ulong, mem::size_of::<input_absinfo>()) }
+
+const BUTTON_MAP: [(u16, ImGuiKey); 16] = [
+    (BTN_START,      ImGuiKey_GamepadStart),
+    (BTN_SELECT,     ImGuiKey_GamepadBack),
+    (BTN_WEST,       ImGuiKey_GamepadFaceLeft),     // Xbox X, PS Square
+    (BTN_EAST,       ImGuiKey_GamepadFaceRight),    // Xbox B, PS Circle
+    (BTN_NORTH,      ImGuiKey_GamepadFaceUp),       // Xbox Y, PS Triangle
+    (BTN_SOUTH,      ImGuiKey_GamepadFaceDown),     // Xbox A, PS Cross
+    (BTN_TL,         ImGuiKey_GamepadL1),
+    (BTN_TR,         ImGuiKey_GamepadR1),
+    (BTN_THUMBL,     ImGuiKey_GamepadL3),
+    (BTN_THUMBR,     ImGuiKey_GamepadR3),
+    // Some drivers report the d-pad as buttons instead of a hat
+    (BTN_DPAD_LEFT,  ImGuiKey_GamepadDpadLeft),
+    (BTN_DPAD_RIGHT, ImGuiKey_GamepadDpadRight),
+    (BTN_DPAD_UP,    ImGuiKey_GamepadDpadUp),
+    (BTN_DPAD_DOWN,  ImGuiKey_GamepadDpadDown),
+    // Some drivers report the triggers as buttons only
+    (BTN_TL2,        ImGuiKey_GamepadL2),
+    (BTN_TR2,        ImGuiKey_GamepadR2),
+];
+
+// How often we look for a new device while none is connected
+const RESCAN_INTERVAL: Duration = Duration::from_secs(1);
+
+// An opened evdev gamepad
+pub struct ImGui_ImplEvdev_Device {
+    pub Fd: c_int,
+    pub Path: String,
+    pub Name: String,
+    pub HasAnalogTriggers: bool,
+    // Current state, updated from the event stream
+    pub Keys: [bool; KEY_MAX + 1],
+    pub AbsInfo: [input_absinfo; ABS_MAX + 1],
+}
+
+// Evdev data, owned by the application (see 'Typical usage' above)
+#[derive(Default)]
+pub struct ImGui_ImplEvdev_Data {
+    pub Device: Option<ImGui_ImplEvdev_Device>,
+    pub LastScanTime: Option<Instant>,
+}
+
+fn TestBit(bits: &[u8], bit: usize) -> bool {
+    bit / 8 < bits.len() && (bits[bit / 8] & (1 << (bit % 8))) != 0
+}
+
+// Map an absolute axis value to 0.0..1.0 using the range reported by the driver
+fn NormalizeAbs(info: &input_absinfo) -> c_float {
+    if info.maximum <= info.minimum {
+        return 0.0;
+    }
+    let v = (info.value - info.minimum) as c_float / (info.maximum - info.minimum) as c_float;
+    v.clamp(0.0, 1.0)
+}
+
+// Read the whole key and axis state again (after opening the device, or after the kernel dropped events)
+fn ImGui_ImplEvdev_SyncDevice(dev: &mut ImGui_ImplEvdev_Device) {
+    let mut key_bits = [0u8; KEY_MAX / 8 + 1];
+    if unsafe { libc::ioctl(dev.Fd, EVIOCGKEY(key_bits.len()), key_bits.as_mut_ptr()) } >= 0 {
+        for code in 0..=KEY_MAX {
+            dev.Keys[code] = TestBit(&key_bits, code);
+        }
+    }
+    for code in 0..=ABS_MAX {
+        let mut info: input_absinfo = unsafe { mem::zeroed() };
+        if unsafe { libc::ioctl(dev.Fd, EVIOCGABS(code as u16), &mut info as *mut input_absinfo) } >= 0 {
+            dev.AbsInfo[code] = info;
+        }
+    }
+}
+
+fn ImGui_ImplEvdev_OpenDevice(path: &str) -> Option<ImGui_ImplEvdev_Device> {
+    let c_path = CString::new(path).ok()?;
+    let fd = unsafe { libc::open(c_path.as_ptr(), libc::O_RDONLY | libc::O_NONBLOCK | libc::O_CLOEXEC) };
+    if fd < 0 {
+        return None;
+    }
+
+    // Only accept devices that report the gamepad button range (this filters out keyboards, mice, touchpads, accelerometers...)
+    let mut key_bits = [0u8; KEY_MAX / 8 + 1];
+    let mut abs_bits = [0u8; ABS_MAX / 8 + 1];
+    let ok_key = unsafe { libc::ioctl(fd, EVIOCGBIT(EV_KEY, key_bits.len()), key_bits.as_mut_ptr()) } >= 0;
+    let ok_abs = unsafe { libc::ioctl(fd, EVIOCGBIT(EV_ABS, abs_bits.len()), abs_bits.as_mut_ptr()) } >= 0;
+    if !ok_key || !TestBit(&key_bits, BTN_GAMEPAD as usize) {
+        unsafe { libc::close(fd) };
+        return None;
+    }
+
+    let mut name_buf = [0u8; 256];
+    let name_len = unsafe { libc::ioctl(fd, EVIOCGNAME(name_buf.len()), name_buf.as_mut_ptr()) };
+    let name = if name_len > 0 {
+        let end = name_buf.iter().position(|&c| c == 0).unwrap_or(name_buf.len());
+        String::from_utf8_lossy(&name_buf[..end]).into_owned()
+    } else {
+        String::new()
+    };
+
+    let mut dev = ImGui_ImplEvdev_Device {
+        Fd: fd,
+        Path: path.to_string(),
+        Name: name,
+        HasAnalogTriggers: ok_abs && TestBit(&abs_bits, ABS_Z as usize) && TestBit(&abs_bits, ABS_RZ as usize),
+        Keys: [false; KEY_MAX + 1],
+        AbsInfo: unsafe { mem::zeroed() },
+    };
+    ImGui_ImplEvdev_SyncDevice(&mut dev);
+    Some(dev)
+}
+
+fn ImGui_ImplEvdev_CloseDevice(g: &mut AppContext, dev: ImGui_ImplEvdev_Device) {
+    unsafe { libc::close(dev.Fd) };
+    g.IO.AddGamepadReleaseAllEvents();
+    g.IO.backend_flags &= !IM_GUI_BACKEND_FLAGS_HAS_GAMEPAD;
+}
+
+// Open the first gamepad in /dev/input (sorted, so the choice is stable across runs)
+fn ImGui_ImplEvdev_ScanDevices(bd: &mut ImGui_ImplEvdev_Data) {
+    bd.LastScanTime = Some(Instant::now());
+    let entries = match std::fs::read_dir("/dev/input") {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    let mut paths: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_name().to_string_lossy().starts_with("event"))
+        .map(|e| e.path().to_string_lossy().into_owned())
+        .collect();
+    paths.sort_by_key(|p| p.trim_start_matches("/dev/input/event").parse::<u32>().unwrap_or(u32::MAX));
+    bd.Device = paths.iter().find_map(|p| ImGui_ImplEvdev_OpenDevice(p));
+}
+
+// Drain pending events. Returns false when the device is gone.
+fn ImGui_ImplEvdev_ReadEvents(dev: &mut ImGui_ImplEvdev_Device) -> bool {
+    let mut events: [input_event; 64] = unsafe { mem::zeroed() };
+    loop {
+        let bytes = unsafe { libc::read(dev.Fd, events.as_mut_ptr() as *mut c_void, mem::size_of_val(&events)) };
+        if bytes < 0 {
+            let err = std::io::Error::last_os_error().raw_os_error().unwrap_or(0);
+            return err == libc::EAGAIN || err == libc::EINTR;
+        }
+        if bytes == 0 {
+            return true;
+        }
+        let count = bytes as usize / mem::size_of::<input_event>();
+        for e in &events[..count] {
+            match e.type_ {
+                EV_KEY if (e.code as usize) <= KEY_MAX => dev.Keys[e.code as usize] = e.value != 0,
+                EV_ABS if (e.code as usize) <= ABS_MAX => dev.AbsInfo[e.code as usize].value = e.value,
+                EV_SYN if e.code == SYN_DROPPED => ImGui_ImplEvdev_SyncDevice(dev),
+                _ => {}
+            }
+        }
+    }
+}
+
+// Functions
+pub fn ImGui_ImplEvdev_Init(g: &mut AppContext) -> ImGui_ImplEvdev_Data {
+    let mut bd = ImGui_ImplEvdev_Data::default();
+    ImGui_ImplEvdev_ScanDevices(&mut bd);
+    if bd.Device.is_some() {
+        g.IO.backend_flags |= IM_GUI_BACKEND_FLAGS_HAS_GAMEPAD;
+    }
+    bd
+}
+
+pub fn ImGui_ImplEvdev_Shutdown(g: &mut AppContext, mut bd: ImGui_ImplEvdev_Data) {
+    if let Some(dev) = bd.Device.take() {
+        ImGui_ImplEvdev_CloseDevice(g, dev);
+    }
+}
+
+pub fn ImGui_ImplEvdev_NewFrame(g: &mut AppContext, bd: &mut ImGui_ImplEvdev_Data) {
+    if (g.IO.ConfigFlags & ImGuiConfigFlags_NavEnableGamepad) == 0 {
+        return;
+    }
+
+    // Hot-plug: look for a gamepad once in a while when we don't have one
+    if bd.Device.is_none() {
+        let rescan = bd.LastScanTime.map_or(true, |t| t.elapsed() >= RESCAN_INTERVAL);
+        if rescan {
+            ImGui_ImplEvdev_ScanDevices(bd);
+        }
+        if bd.Device.is_none() {
+            return;
+        }
+    }
+
+    // Unplugged: read() fails with ENODEV
+    if !ImGui_ImplEvdev_ReadEvents(bd.Device.as_mut().unwrap()) {
+        let dev = bd.Device.take().unwrap();
+        ImGui_ImplEvdev_CloseDevice(g, dev);
+        bd.LastScanTime = Some(Instant::now());
+        return;
+    }
+
+    let dev = bd.Device.as_ref().unwrap();
+    let io = &mut g.IO;
+    io.backend_flags |= IM_GUI_BACKEND_FLAGS_HAS_GAMEPAD;
+    for &(code, key) in BUTTON_MAP.iter() {
+        // Analog triggers are fed below, don't let their digital twin fight with them
+        if dev.HasAnalogTriggers && (code == BTN_TL2 || code == BTN_TR2) {
+            continue;
+        }
+        io.AddKeyEvent(key, dev.Keys[code as usize]);
+    }
+
+    // D-pad reported as a hat: -1/0/+1 per axis. Only override the button state when the driver has a hat.
+    let hat_x = dev.AbsInfo[ABS_HAT0X as usize];
+    let hat_y = dev.AbsInfo[ABS_HAT0Y as usize];
+    if hat_x.maximum > hat_x.minimum && hat_y.maximum > hat_y.minimum {
+        io.AddKeyEvent(ImGuiKey_GamepadDpadLeft, hat_x.value < 0 || dev.Keys[BTN_DPAD_LEFT as usize]);
+        io.AddKeyEvent(ImGuiKey_GamepadDpadRight, hat_x.value > 0 || dev.Keys[BTN_DPAD_RIGHT as usize]);
+        io.AddKeyEvent(ImGuiKey_GamepadDpadUp, hat_y.value < 0 || dev.Keys[BTN_DPAD_UP as usize]);
+        io.AddKeyEvent(ImGuiKey_GamepadDpadDown, hat_y.value > 0 || dev.Keys[BTN_DPAD_DOWN as usize]);
+    }
+
+    if dev.HasAnalogTriggers {
+        io.AddGamepadTriggerEvent(ImGuiKey_GamepadL2, NormalizeAbs(&dev.AbsInfo[ABS_Z as usize]));
+        io.AddGamepadTriggerEvent(ImGuiKey_GamepadR2, NormalizeAbs(&dev.AbsInfo[ABS_RZ as usize]));
+    }
+
+    // Sticks: map 0..1 to -1..+1 (drivers report a centered range, e.g. -32768..32767 or 0..255). Missing axes stay centered.
+    let stick = |code: u16| {
+        let info = &dev.AbsInfo[code as usize];
+        if info.maximum > info.minimum { NormalizeAbs(info) * 2.0 - 1.0 } else { 0.0 }
+    };
+    io.AddGamepadStickEvent(ImGuiKey_GamepadLStickLeft, ImGuiKey_GamepadLStickRight, stick(ABS_X));
+    io.AddGamepadStickEvent(ImGuiKey_GamepadLStickUp, ImGuiKey_GamepadLStickDown, stick(ABS_Y));
+    io.AddGamepadStickEvent(ImGuiKey_GamepadRStickLeft, ImGuiKey_GamepadRStickRight, stick(ABS_RX));
+    io.AddGamepadStickEvent(ImGuiKey_GamepadRStickUp, ImGuiKey_GamepadRStickDown, stick(ABS_RY));
+}
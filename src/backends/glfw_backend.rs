@@ -21,6 +21,7 @@
 // CHANGELOG
 // (minor and older changes stripped away, please see git history for details)
 //  2022-XX-XX: Platform: Added support for multiple windows via the ImGuiPlatformIO interface.
+//  2026-10-18: Inputs: Gamepad hot-plug via glfwSetJoystickCallback(), first connected gamepad is used instead of GLFW_JOYSTICK_1. Dead zones now come from io.ConfigGamepadStickDeadZone/ConfigGamepadTriggerDeadZone.
 //  2022-09-01: Inputs: Honor GLFW_CURSOR_DISABLED by not setting mouse position.
 //  2022-04-30: Inputs: Fixed ImGui_ImplGlfw_TranslateUntranslatedKey() for lower case letters on OSX.
 //  2022-03-23: Inputs: Fixed a regression in 1.87 which resulted in keyboard modifiers events being reported incorrectly on Linux/X11.
@@ -124,6 +125,8 @@ struct ImGui_ImplGlfw_Data
     GLFWwindow*             KeyOwnerWindows[GLFW_KEY_LAST];
     bool                    InstalledCallbacks;
     bool                    WantUpdateMonitors;
+    bool                    WantUpdateGamepads;
+    int                     GamepadJoystickId;      // GLFW joystick id of the gamepad we read from, -1 when none is connected
 #ifdef _WIN32
     WNDPROC                 GlfwWndProc;
 #endif
@@ -137,6 +140,7 @@ struct ImGui_ImplGlfw_Data
     GLFWkeyfun              PrevUserCallbackKey;
     GLFWcharfun             PrevUserCallbackChar;
     GLFWmonitorfun          PrevUserCallbackMonitor;
+    GLFWjoystickfun         PrevUserCallbackJoystick;
 
     ImGui_ImplGlfw_Data()   { memset(this, 0, sizeof(*this)); }
 };
@@ -450,6 +454,21 @@ void ImGui_ImplGlfw_MonitorCallback(GLFWmonitor*, int)
     bd->WantUpdateMonitors = true;
 }
 
+void ImGui_ImplGlfw_JoystickCallback(int jid, int event)
+{
+    ImGui_ImplGlfw_Data* bd = ImGui_ImplGlfw_GetBackendData();
+    if (bd->PrevUserCallbackJoystick != NULL)
+        bd->PrevUserCallbackJoystick(jid, event);
+
+    // Re-pick a gamepad on the next frame. When ours went away, release its keys right now so nothing stays held.
+    bd->WantUpdateGamepads = true;
+    if (event == GLFW_DISCONNECTED && jid == bd->GamepadJoystickId)
+    {
+        bd->GamepadJoystickId = -1;
+        Imgui::GetIO().AddGamepadReleaseAllEvents();
+    }
+}
+
 void ImGui_ImplGlfw_InstallCallbacks(GLFWwindow* window)
 {
     ImGui_ImplGlfw_Data* bd = ImGui_ImplGlfw_GetBackendData();
@@ -464,6 +483,7 @@ void ImGui_ImplGlfw_InstallCallbacks(GLFWwindow* window)
     bd->PrevUserCallbackKey = glfwSetKeyCallback(window, ImGui_ImplGlfw_KeyCallback);
     bd->PrevUserCallbackChar = glfwSetCharCallback(window, ImGui_ImplGlfw_CharCallback);
     bd->PrevUserCallbackMonitor = glfwSetMonitorCallback(ImGui_ImplGlfw_MonitorCallback);
+    bd->PrevUserCallbackJoystick = glfwSetJoystickCallback(ImGui_ImplGlfw_JoystickCallback);
     bd->InstalledCallbacks = true;
 }
 
@@ -481,6 +501,7 @@ void ImGui_ImplGlfw_RestoreCallbacks(GLFWwindow* window)
     glfwSetKeyCallback(window, bd->PrevUserCallbackKey);
     glfwSetCharCallback(window, bd->PrevUserCallbackChar);
     glfwSetMonitorCallback(bd->PrevUserCallbackMonitor);
+    glfwSetJoystickCallback(bd->PrevUserCallbackJoystick);
     bd->InstalledCallbacks = false;
     bd->PrevUserCallbackWindowFocus = NULL;
     bd->PrevUserCallbackCursorEnter = NULL;
@@ -490,6 +511,7 @@ void ImGui_ImplGlfw_RestoreCallbacks(GLFWwindow* window)
     bd->PrevUserCallbackKey = NULL;
     bd->PrevUserCallbackChar = NULL;
     bd->PrevUserCallbackMonitor = NULL;
+    bd->PrevUserCallbackJoystick = NULL;
 }
 
 static bool ImGui_ImplGlfw_Init(GLFWwindow* window, bool install_callbacks, GlfwClientApi client_api)
@@ -547,6 +569,10 @@ static bool ImGui_ImplGlfw_Init(GLFWwindow* window, bool install_callbacks, Glfw
     ImGui_ImplGlfw_UpdateMonitors();
     glfwSetMonitorCallback(ImGui_ImplGlfw_MonitorCallback);
 
+    // Pick a gamepad on the first frame (later connections/disconnections are reported by the joystick callback)
+    bd->GamepadJoystickId = -1;
+    bd->WantUpdateGamepads = true;
+
     // Our mouse update function expect PlatformHandle to be filled for the main viewport
     ImguiViewport* main_viewport = Imgui::GetMainViewport();
     main_viewport->PlatformHandle = bd->Window;
@@ -700,28 +726,58 @@ static void ImGui_ImplGlfw_UpdateMouseCursor()
 }
 
 // Update gamepad inputs
-static inline float Saturate(float v) { return v < 0.0f ? 0.0f : v  > 1.0f ? 1.0f : v; }
+// Use the first connected joystick that GLFW can map as a gamepad (or the first joystick at all on GLFW < 3.3).
+static int ImGui_ImplGlfw_FindGamepad()
+{
+    for (int jid = GLFW_JOYSTICK_1; jid <= GLFW_JOYSTICK_LAST; jid++)
+    {
+#if GLFW_HAS_GAMEPAD_API
+        if (glfwJoystickIsGamepad(jid))
+            return jid;
+#else
+        if (glfwJoystickPresent(jid))
+            return jid;
+#endif
+    }
+    return -1;
+}
+
 static void ImGui_ImplGlfw_UpdateGamepads()
 {
     ImGuiIO& io = Imgui::GetIO();
+    ImGui_ImplGlfw_Data* bd = ImGui_ImplGlfw_GetBackendData();
     if ((io.ConfigFlags & ImGuiConfigFlags_NavEnableGamepad) == 0) // FIXME: Technically feeding gamepad shouldn't depend on this now that they are regular inputs.
         return;
 
+    // Without installed callbacks we don't get connect/disconnect notifications, so poll for a gamepad every frame.
+    if (bd->WantUpdateGamepads || !bd->InstalledCallbacks)
+    {
+        bd->GamepadJoystickId = ImGui_ImplGlfw_FindGamepad();
+        bd->WantUpdateGamepads = false;
+    }
+
     io.BackendFlags &= ~ImGuiBackendFlags_HasGamepad;
+    if (bd->GamepadJoystickId < 0)
+        return;
+    const int jid = bd->GamepadJoystickId;
+
+    // Sticks are fed with their raw -1..+1 value, triggers with a 0..1 value: io applies io.ConfigGamepadStickDeadZone/io.ConfigGamepadTriggerDeadZone.
 #if GLFW_HAS_GAMEPAD_API
     GLFWgamepadstate gamepad;
-    if (!glfwGetGamepadState(GLFW_JOYSTICK_1, &gamepad))
+    if (!glfwGetGamepadState(jid, &gamepad))
         return;
-    #define MAP_BUTTON(KEY_NO, BUTTON_NO, _UNUSED)          do { io.AddKeyEvent(KEY_NO, gamepad.buttons[BUTTON_NO] != 0); } while (0)
-    #define MAP_ANALOG(KEY_NO, AXIS_NO, _UNUSED, V0, V1)    do { float v = gamepad.axes[AXIS_NO]; v = (v - V0) / (V1 - V0); io.AddKeyAnalogEvent(KEY_NO, v > 0.10f, Saturate(v)); } while (0)
+    #define MAP_BUTTON(KEY_NO, BUTTON_NO, _UNUSED)                  do { io.AddKeyEvent(KEY_NO, gamepad.buttons[BUTTON_NO] != 0); } while (0)
+    #define MAP_TRIGGER(KEY_NO, AXIS_NO, _UNUSED)                   do { io.AddGamepadTriggerEvent(KEY_NO, (gamepad.axes[AXIS_NO] + 1.0f) * 0.5f); } while (0)
+    #define MAP_STICK(KEY_NEG, KEY_POS, AXIS_NO, _UNUSED)           do { io.AddGamepadStickEvent(KEY_NEG, KEY_POS, gamepad.axes[AXIS_NO]); } while (0)
 #else
     int axes_count = 0, buttons_count = 0;
-    const float* axes = glfwGetJoystickAxes(GLFW_JOYSTICK_1, &axes_count);
-    const unsigned char* buttons = glfwGetJoystickButtons(GLFW_JOYSTICK_1, &buttons_count);
+    const float* axes = glfwGetJoystickAxes(jid, &axes_count);
+    const unsigned char* buttons = glfwGetJoystickButtons(jid, &buttons_count);
     if (axes_count == 0 || buttons_count == 0)
         return;
-    #define MAP_BUTTON(KEY_NO, _UNUSED, BUTTON_NO)          do { io.AddKeyEvent(KEY_NO, (buttons_count > BUTTON_NO && buttons[BUTTON_NO] == GLFW_PRESS)); } while (0)
-    #define MAP_ANALOG(KEY_NO, _UNUSED, AXIS_NO, V0, V1)    do { float v = (axes_count > AXIS_NO) ? axes[AXIS_NO] : V0; v = (v - V0) / (V1 - V0); io.AddKeyAnalogEvent(KEY_NO, v > 0.10f, Saturate(v)); } while (0)
+    #define MAP_BUTTON(KEY_NO, _UNUSED, BUTTON_NO)                  do { io.AddKeyEvent(KEY_NO, (buttons_count > BUTTON_NO && buttons[BUTTON_NO] == GLFW_PRESS)); } while (0)
+    #define MAP_TRIGGER(KEY_NO, _UNUSED, AXIS_NO)                   do { float v = (axes_count > AXIS_NO) ? axes[AXIS_NO] : -1.0f; io.AddGamepadTriggerEvent(KEY_NO, (v + 1.0f) * 0.5f); } while (0)
+    #define MAP_STICK(KEY_NEG, KEY_POS, _UNUSED, AXIS_NO)           do { float v = (axes_count > AXIS_NO) ? axes[AXIS_NO] : 0.0f; io.AddGamepadStickEvent(KEY_NEG, KEY_POS, v); } while (0)
 #endif
     io.BackendFlags |= ImGuiBackendFlags_HasGamepad;
     MAP_BUTTON(ImGuiKey_GamepadStart,       GLFW_GAMEPAD_BUTTON_START,          7);
@@ -736,20 +792,17 @@ static void ImGui_ImplGlfw_UpdateGamepads()
     MAP_BUTTON(ImGuiKey_GamepadDpadDown,    GLFW_GAMEPAD_BUTTON_DPAD_DOWN,      12);
     MAP_BUTTON(ImGuiKey_GamepadL1,          GLFW_GAMEPAD_BUTTON_LEFT_BUMPER,    4);
     MAP_BUTTON(ImGuiKey_GamepadR1,          GLFW_GAMEPAD_BUTTON_RIGHT_BUMPER,   5);
-    MAP_ANALOG(ImGuiKey_GamepadL2,          GLFW_GAMEPAD_AXIS_LEFT_TRIGGER,     4,      -0.75f,  +1.0f);
-    MAP_ANALOG(ImGuiKey_GamepadR2,          GLFW_GAMEPAD_AXIS_RIGHT_TRIGGER,    5,      -0.75f,  +1.0f);
+    MAP_TRIGGER(ImGuiKey_GamepadL2,         GLFW_GAMEPAD_AXIS_LEFT_TRIGGER,     4);
+    MAP_TRIGGER(ImGuiKey_GamepadR2,         GLFW_GAMEPAD_AXIS_RIGHT_TRIGGER,    5);
     MAP_BUTTON(ImGuiKey_GamepadL3,          GLFW_GAMEPAD_BUTTON_LEFT_THUMB,     8);
     MAP_BUTTON(ImGuiKey_GamepadR3,          GLFW_GAMEPAD_BUTTON_RIGHT_THUMB,    9);
-    MAP_ANALOG(ImGuiKey_GamepadLStickLeft,  GLFW_GAMEPAD_AXIS_LEFT_X,           0,      -0.25f,  -1.0f);
-    MAP_ANALOG(ImGuiKey_GamepadLStickRight, GLFW_GAMEPAD_AXIS_LEFT_X,           0,      +0.25f,  +1.0f);
-    MAP_ANALOG(ImGuiKey_GamepadLStickUp,    GLFW_GAMEPAD_AXIS_LEFT_Y,           1,      -0.25f,  -1.0f);
-    MAP_ANALOG(ImGuiKey_GamepadLStickDown,  GLFW_GAMEPAD_AXIS_LEFT_Y,           1,      +0.25f,  +1.0f);
-    MAP_ANALOG(ImGuiKey_GamepadRStickLeft,  GLFW_GAMEPAD_AXIS_RIGHT_X,          2,      -0.25f,  -1.0f);
-    MAP_ANALOG(ImGuiKey_GamepadRStickRight, GLFW_GAMEPAD_AXIS_RIGHT_X,          2,      +0.25f,  +1.0f);
-    MAP_ANALOG(ImGuiKey_GamepadRStickUp,    GLFW_GAMEPAD_AXIS_RIGHT_Y,          3,      -0.25f,  -1.0f);
-    MAP_ANALOG(ImGuiKey_GamepadRStickDown,  GLFW_GAMEPAD_AXIS_RIGHT_Y,          3,      +0.25f,  +1.0f);
+    MAP_STICK(ImGuiKey_GamepadLStickLeft,   ImGuiKey_GamepadLStickRight,    GLFW_GAMEPAD_AXIS_LEFT_X,   0);
+    MAP_STICK(ImGuiKey_GamepadLStickUp,     ImGuiKey_GamepadLStickDown,     GLFW_GAMEPAD_AXIS_LEFT_Y,   1);
+    MAP_STICK(ImGuiKey_GamepadRStickLeft,   ImGuiKey_GamepadRStickRight,    GLFW_GAMEPAD_AXIS_RIGHT_X,  2);
+    MAP_STICK(ImGuiKey_GamepadRStickUp,     ImGuiKey_GamepadRStickDown,     GLFW_GAMEPAD_AXIS_RIGHT_Y,  3);
     #undef MAP_BUTTON
-    #undef MAP_ANALOG
+    #undef MAP_TRIGGER
+    #undef MAP_STICK
 }
 
 static void ImGui_ImplGlfw_UpdateMonitors()
@@ -9,12 +9,16 @@ pub mod dx10_renderer_backend;
 pub mod dx11_renderer_backend;
 #[cfg(target_os = "windows")]
 pub mod dx12_renderer_backend;
+#[cfg(target_os = "linux")]
+pub mod evdev_gamepad_backend;
 pub mod glfw_backend;
 pub mod glut_renderer_backend;
+pub mod null_platform_backend;
 pub mod opengl2_renderer_backend;
 pub mod opengl3_loader;
 pub mod opengl3_renderer_backend;
 pub mod sdl_renderer_backend;
+pub mod software_renderer_backend;
 pub mod vulkan_backend;
 pub mod win_platform_backend;
 pub mod sdl_backend;
@@ -0,0 +1,131 @@
+// dear imgui: Platform Backend for headless runs (no window, no OS input)
+// This needs to be used along with a Renderer (e.g. the software renderer backend, or none at all if you only need the ImDrawData)
+// (Info: this backend only feeds io.DisplaySize / io.DisplayFramebufferScale / io.DeltaTime so that a full NewFrame()/Render() loop can run in CI.)
+
+// Implemented features:
+//  [X] Platform: Fixed or real-time delta time. Use ImGui_ImplNull_SetFixedDeltaTime() for deterministic frames.
+//  [X] Platform: Display size changes at runtime via ImGui_ImplNull_SetDisplaySize().
+// Missing features:
+//  [ ] Platform: Clipboard support (falls back to the private clipboard of the core library).
+//  [ ] Platform: Mouse cursor shape and visibility.
+//  [ ] Platform: Multi-viewport support (multiple windows).
+// Inputs are not polled from anywhere: submit them yourself with io.AddMousePosEvent(), io.AddKeyEvent() etc.
+
+// Typical usage:
+//   let mut g = create_context(None, None);
+//   ImGui_ImplNull_Init(&mut g, Vector2::from_floats(1280.0, 720.0));
+//   ImGui_ImplSoftware_Init(&mut g);
+//   loop {
+//       ImGui_ImplSoftware_NewFrame(&mut g);
+//       ImGui_ImplNull_NewFrame(&mut g);
+//       NewFrame(&mut g);
+//       ...
+//       Render(&mut g);
+//       ImGui_ImplSoftware_RenderDrawData(&mut g, &draw_data);
+//   }
+
+// CHANGELOG
+//  2026-10-18: Initial version.
+
+use std::ptr::null_mut;
+use std::time::Instant;
+use libc::{c_float, c_void};
+use crate::core::context::AppContext;
+use crate::core::vec2::Vector2;
+
+// Null platform data
+#[derive(Debug, Clone)]
+pub struct ImGui_ImplNull_Data {
+    pub DisplaySize: Vector2,
+    pub FramebufferScale: Vector2,
+    // When > 0.0, every frame advances by exactly this amount of time instead of measuring wall clock time.
+    pub FixedDeltaTime: c_float,
+    pub Time: Option<Instant>,
+}
+
+impl Default for ImGui_ImplNull_Data {
+    fn default() -> Self {
+        Self {
+            DisplaySize: Vector2::from_floats(1280.0, 720.0),
+            FramebufferScale: Vector2::from_floats(1.0, 1.0),
+            FixedDeltaTime: 1.0 / 60.0,
+            Time: None,
+        }
+    }
+}
+
+// Backend data stored in io.backend_platform_user_data to allow support for multiple Dear ImGui contexts
+pub fn ImGui_ImplNull_GetBackendData(g: &mut AppContext) -> Option<&mut ImGui_ImplNull_Data> {
+    let bd = g.IO.backend_platform_user_data as *mut ImGui_ImplNull_Data;
+    if bd.is_null() {
+        return None;
+    }
+    unsafe { Some(&mut *bd) }
+}
+
+// Functions
+pub fn ImGui_ImplNull_Init(g: &mut AppContext, display_size: Vector2) -> bool {
+    // IM_ASSERT(g.IO.backend_platform_user_data == NULL && "Already initialized a platform backend!");
+    let mut bd = ImGui_ImplNull_Data::default();
+    bd.DisplaySize = display_size;
+
+    // Setup backend capabilities flags
+    g.IO.backend_platform_user_data = Box::into_raw(Box::new(bd)) as *mut c_void;
+    g.IO.backend_platform_data = String::from("imgui_impl_null");
+    g.IO.DisplaySize = display_size;
+    g.IO.DisplayFramebufferScale = Vector2::from_floats(1.0, 1.0);
+
+    return true;
+}
+
+pub fn ImGui_ImplNull_Shutdown(g: &mut AppContext) {
+    let bd = g.IO.backend_platform_user_data as *mut ImGui_ImplNull_Data;
+    // IM_ASSERT(bd != NULL && "No platform backend to shutdown, or already shutdown?");
+    if !bd.is_null() {
+        unsafe { drop(Box::from_raw(bd)) };
+    }
+    g.IO.backend_platform_data = String::new();
+    g.IO.backend_platform_user_data = null_mut();
+}
+
+pub fn ImGui_ImplNull_SetDisplaySize(g: &mut AppContext, display_size: Vector2, framebuffer_scale: Vector2) {
+    if let Some(bd) = ImGui_ImplNull_GetBackendData(g) {
+        bd.DisplaySize = display_size;
+        bd.FramebufferScale = framebuffer_scale;
+    }
+}
+
+// Pass 0.0 to measure real elapsed time between calls to ImGui_ImplNull_NewFrame()
+pub fn ImGui_ImplNull_SetFixedDeltaTime(g: &mut AppContext, delta_time: c_float) {
+    if let Some(bd) = ImGui_ImplNull_GetBackendData(g) {
+        bd.FixedDeltaTime = delta_time;
+    }
+}
+
+pub fn ImGui_ImplNull_NewFrame(g: &mut AppContext) {
+    let (display_size, framebuffer_scale, delta_time) = match ImGui_ImplNull_GetBackendData(g) {
+        Some(bd) => {
+            // Setup time step
+            let current_time = Instant::now();
+            let delta_time = if bd.FixedDeltaTime > 0.0 {
+                bd.FixedDeltaTime
+            } else {
+                match bd.Time {
+                    Some(prev) => (current_time - prev).as_secs_f32().max(0.00001),
+                    None => 1.0 / 60.0,
+                }
+            };
+            bd.Time = Some(current_time);
+            (bd.DisplaySize, bd.FramebufferScale, delta_time)
+        }
+        None => {
+            // IM_ASSERT(bd != NULL && "Did you call ImGui_ImplNull_Init()?");
+            return;
+        }
+    };
+
+    // Setup display size (every frame to accommodate for ImGui_ImplNull_SetDisplaySize() calls)
+    g.IO.DisplaySize = display_size;
+    g.IO.DisplayFramebufferScale = framebuffer_scale;
+    g.IO.DeltaTime = delta_time;
+}
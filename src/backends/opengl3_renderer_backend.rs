@@ -15,6 +15,7 @@
 
 // CHANGELOG
 // (minor and older changes stripped away, please see git history for details)
+//  2026-10-18: OpenGL: Added framebuffer readback, registered as PlatformIO.Renderer_ReadPixels for CaptureScreenshot().
 //  2022-XX-XX: Platform: Added support for multiple windows via the ImGuiPlatformIO interface.
 //  2022-05-23: OpenGL: Reworking 2021-12-15 "Using buffer orphaning" so it only happens on Intel GPU, seems to cause problems otherwise. (#4468, #4825, #4832, #5127).
 //  2022-05-13: OpenGL: Fix state corruption on OpenGL ES 2.0 due to not preserving GL_ELEMENT_ARRAY_BUFFER_BINDING and vertex attribute states.
@@ -325,6 +326,7 @@ bool    ImGui_ImplOpenGL3_Init(glsl_version: *const c_char)
 
     if (io.ConfigFlags & ImGuiConfigFlags_ViewportsEnable)
         ImGui_ImplOpenGL3_InitPlatformInterface();
+    Imgui::GetPlatformIO().Renderer_ReadPixels = ImGui_ImplOpenGL3_ReadPixels;
 
     return true;
 }
@@ -337,6 +339,7 @@ void    ImGui_ImplOpenGL3_Shutdown()
 
     ImGui_ImplOpenGL3_ShutdownPlatformInterface();
     ImGui_ImplOpenGL3_DestroyDeviceObjects();
+    Imgui::GetPlatformIO().Renderer_ReadPixels = NULL;
     io.BackendRendererName = NULL;
     io.BackendRendererUserData = NULL;
     IM_DELETE(bd);
@@ -351,6 +354,25 @@ void    ImGui_ImplOpenGL3_NewFrame()
         ImGui_ImplOpenGL3_CreateDeviceObjects();
 }
 
+// Read back a rectangle of the currently bound framebuffer, used by CaptureScreenshot(). (x,y) is the top-left corner in framebuffer pixels.
+// With multi-viewports the platform backend must have made the viewport's GL context current.
+static bool ImGui_ImplOpenGL3_ReadPixels(ImGuiContext* ctx, ImGuiID viewport_id, int x, int y, int w, int h, RgbaImage* out_image)
+{
+    IM_UNUSED(ctx);
+    IM_UNUSED(viewport_id);
+    GLint last_viewport[4]; glGetIntegerv(GL_VIEWPORT, last_viewport);
+    int fb_height = last_viewport[3];
+    if (w <= 0 || h <= 0 || y + h > fb_height)
+        return false;
+    GLint last_pack_alignment; glGetIntegerv(GL_PACK_ALIGNMENT, &last_pack_alignment);
+    glPixelStorei(GL_PACK_ALIGNMENT, 1);
+    *out_image = RgbaImage::new(w, h);
+    glReadPixels(x, fb_height - y - h, w, h, GL_RGBA, GL_UNSIGNED_BYTE, out_image->Pixels.as_mut_ptr());   // OpenGL origin is bottom-left
+    glPixelStorei(GL_PACK_ALIGNMENT, last_pack_alignment);
+    out_image->FlipVertically();
+    return glGetError() == GL_NO_ERROR;
+}
+
 static void ImGui_ImplOpenGL3_SetupRenderState(ImDrawData* draw_data, int fb_width, int fb_height, GLuint vertex_array_object)
 {
     ImGui_ImplOpenGL3_Data* bd = ImGui_ImplOpenGL3_GetBackendData();
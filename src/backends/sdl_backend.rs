@@ -21,6 +21,8 @@
 
 // CHANGELOG
 // (minor and older changes stripped away, please see git history for details)
+//  2026-10-18: Inputs: IME composition (SDL_TEXTEDITING) is forwarded with io.AddInputPreeditEvent() so InputText() can display it in place.
+//  2026-10-18: Inputs: Gamepad hot-plug (SDL_CONTROLLERDEVICEADDED/REMOVED), first recognized controller is used instead of joystick 0. Dead zones now come from io.ConfigGamepadStickDeadZone/ConfigGamepadTriggerDeadZone.
 //  2022-XX-XX: Platform: Added support for multiple windows via the ImGuiPlatformIO interface.
 //  2022-03-22: Inputs: Fix mouse position issues when dragging outside of boundaries. SDL_CaptureMouse() erroneously still gives out LEAVE events when hovering OS decorations.
 //  2022-03-22: Inputs: Added support for extra mouse buttons (SDL_BUTTON_X1/SDL_BUTTON_X2).
@@ -75,14 +77,14 @@
 // #include <TargetConditionals.h>
 // #endif
 
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::mem;
 use std::os::raw::c_void;
 use std::ptr::null_mut;
 use libc::{c_char, c_int};
-use sdl2::sys::{SDL_BUTTON_LEFT, SDL_BUTTON_MIDDLE, SDL_BUTTON_RIGHT, SDL_BUTTON_X1, SDL_BUTTON_X2, SDL_CaptureMouse, SDL_CreateSystemCursor, SDL_CreateWindow, SDL_Cursor, SDL_DestroyWindow, SDL_Event, SDL_free, SDL_FreeCursor, SDL_GameController, SDL_GameControllerButton, SDL_GameControllerGetAxis, SDL_GameControllerGetButton, SDL_GameControllerOpen, SDL_GetClipboardText, SDL_GetCurrentVideoDriver, SDL_GetDisplayBounds, SDL_GetDisplayDPI, SDL_GetDisplayUsableBounds, SDL_GetGlobalMouseState, SDL_GetKeyboardFocus, SDL_GetNumVideoDisplays, SDL_GetPerformanceCounter, SDL_GetPerformanceFrequency, SDL_GetRendererOutputSize, SDL_GetWindowFlags, SDL_GetWindowFromID, SDL_GetWindowID, SDL_GetWindowPosition, SDL_GetWindowSize, SDL_GetWindowWMInfo, SDL_GL_CreateContext, SDL_GL_DeleteContext, SDL_GL_GetCurrentContext, SDL_GL_GetDrawableSize, SDL_GL_MakeCurrent, SDL_GL_SetAttribute, SDL_GL_SetSwapInterval, SDL_GL_SwapWindow, SDL_GLContext, SDL_HINT_MOUSE_FOCUS_CLICKTHROUGH, SDL_Keymod, SDL_RaiseWindow, SDL_Rect, SDL_Renderer, SDL_SetClipboardText, SDL_SetCursor, SDL_SetHint, SDL_SetWindowOpacity, SDL_SetWindowPosition, SDL_SetWindowSize, SDL_SetWindowTitle, SDL_ShowCursor, SDL_ShowWindow, SDL_SYSWM_TYPE, SDL_SysWMinfo, SDL_SysWMinfo__bindgen_ty_1, SDL_version, SDL_Vulkan_CreateSurface, SDL_WarpMouseGlobal, SDL_WarpMouseInWindow, SDL_Window, VkInstance};
+use sdl2::sys::{SDL_BUTTON_LEFT, SDL_BUTTON_MIDDLE, SDL_BUTTON_RIGHT, SDL_BUTTON_X1, SDL_BUTTON_X2, SDL_CaptureMouse, SDL_CreateSystemCursor, SDL_CreateWindow, SDL_Cursor, SDL_DestroyWindow, SDL_Event, SDL_free, SDL_FreeCursor, SDL_GameController, SDL_GameControllerAxis, SDL_GameControllerButton, SDL_GameControllerClose, SDL_GameControllerGetAxis, SDL_GameControllerGetButton, SDL_GameControllerGetJoystick, SDL_GameControllerOpen, SDL_GetClipboardText, SDL_GetCurrentVideoDriver, SDL_GetDisplayBounds, SDL_GetDisplayDPI, SDL_GetDisplayUsableBounds, SDL_GetGlobalMouseState, SDL_GetKeyboardFocus, SDL_GetNumVideoDisplays, SDL_GetPerformanceCounter, SDL_GetPerformanceFrequency, SDL_GetRendererOutputSize, SDL_GetWindowFlags, SDL_GetWindowFromID, SDL_GetWindowID, SDL_GetWindowPosition, SDL_GetWindowSize, SDL_GetWindowWMInfo, SDL_GL_CreateContext, SDL_GL_DeleteContext, SDL_GL_GetCurrentContext, SDL_GL_GetDrawableSize, SDL_GL_MakeCurrent, SDL_GL_SetAttribute, SDL_GL_SetSwapInterval, SDL_GL_SwapWindow, SDL_GLContext, SDL_HINT_MOUSE_FOCUS_CLICKTHROUGH, SDL_IsGameController, SDL_JoystickInstanceID, SDL_Keymod, SDL_NumJoysticks, SDL_RaiseWindow, SDL_Rect, SDL_Renderer, SDL_SetClipboardText, SDL_SetCursor, SDL_SetHint, SDL_SetWindowOpacity, SDL_SetWindowPosition, SDL_SetWindowSize, SDL_SetWindowTitle, SDL_ShowCursor, SDL_ShowWindow, SDL_SYSWM_TYPE, SDL_SysWMinfo, SDL_SysWMinfo__bindgen_ty_1, SDL_version, SDL_Vulkan_CreateSurface, SDL_WarpMouseGlobal, SDL_WarpMouseInWindow, SDL_Window, VkInstance};
 use sdl2::sys::SDL_bool::{SDL_FALSE, SDL_TRUE};
-use sdl2::sys::SDL_EventType::{SDL_KEYDOWN, SDL_MOUSEBUTTONDOWN, SDL_MOUSEWHEEL, SDL_TEXTINPUT};
+use sdl2::sys::SDL_EventType::{SDL_CONTROLLERDEVICEADDED, SDL_CONTROLLERDEVICEREMOVED, SDL_KEYDOWN, SDL_MOUSEBUTTONDOWN, SDL_MOUSEWHEEL, SDL_TEXTEDITING, SDL_TEXTINPUT};
 use sdl2::sys::SDL_GameControllerAxis::{SDL_CONTROLLER_AXIS_LEFTX, SDL_CONTROLLER_AXIS_LEFTY, SDL_CONTROLLER_AXIS_RIGHTX, SDL_CONTROLLER_AXIS_RIGHTY, SDL_CONTROLLER_AXIS_TRIGGERLEFT, SDL_CONTROLLER_AXIS_TRIGGERRIGHT};
 use sdl2::sys::SDL_GameControllerButton::{SDL_CONTROLLER_BUTTON_A, SDL_CONTROLLER_BUTTON_B, SDL_CONTROLLER_BUTTON_BACK, SDL_CONTROLLER_BUTTON_DPAD_DOWN, SDL_CONTROLLER_BUTTON_DPAD_LEFT, SDL_CONTROLLER_BUTTON_DPAD_RIGHT, SDL_CONTROLLER_BUTTON_DPAD_UP, SDL_CONTROLLER_BUTTON_LEFTSHOULDER, SDL_CONTROLLER_BUTTON_LEFTSTICK, SDL_CONTROLLER_BUTTON_RIGHTSHOULDER, SDL_CONTROLLER_BUTTON_RIGHTSTICK, SDL_CONTROLLER_BUTTON_START, SDL_CONTROLLER_BUTTON_X, SDL_CONTROLLER_BUTTON_Y};
 use sdl2::sys::SDL_GLattr::SDL_GL_SHARE_WITH_CURRENT_CONTEXT;
@@ -94,7 +96,6 @@ use sdl2::sys::SDL_WindowFlags::{SDL_WINDOW_ALLOW_HIGHDPI, SDL_WINDOW_ALWAYS_ON_
 use windows::Win32::Foundation::HWND;
 use crate::core::config_flags::{ImGuiConfigFlags_NavEnableGamepad, ImGuiConfigFlags_NoMouseCursorChange, ImGuiConfigFlags_ViewportsEnable};
 use crate::core::context::AppContext;
-use crate::core::math_ops::ImSaturateFloat;
 use crate::io::input_ops::GetMouseCursor;
 use crate::io::io_ops::GetIO;
 use crate::io::IoContext;
@@ -152,6 +153,10 @@ pub struct ImGui_ImplSDL2_Data
     pub MouseCanUseGlobalState: bool,
     // bool            UseVulkan;
     pub UseVulkan: bool,
+    // SDL_GameController* GameController;
+    pub GameController: *mut SDL_GameController,
+    // bool            WantOpenGameController;
+    pub WantOpenGameController: bool,
 
     // ImGui_ImplSDL2_Data()   { memset(this, 0, sizeof(*this)); }
 }
@@ -370,6 +375,15 @@ pub fn ImGui_ImplSDL2_ProcessEvent(event: *const SDL_Event) -> bool
         {
             unsafe { io.AddInputCharactersUTF8(event.text.text.as_ptr()); }
             return true;
+        }
+        SDL_TEXTEDITING =>
+        {
+            // IME composition in progress: 'start' is the caret and 'length' the selected (being converted) clause, both in characters.
+            // Note that SDL truncates the composition to SDL_TEXTEDITINGEVENT_TEXT_SIZE bytes (SDL_TEXTEDITING_EXT isn't exposed by our sdl2-sys).
+            let text = unsafe { CStr::from_ptr(event.edit.text.as_ptr()) }.to_string_lossy();
+            let (start, length) = unsafe { (event.edit.start, event.edit.length) };
+            io.AddInputPreeditEvent(&text, start, start, start + length.max(0));
+            return true;
         }
          SDL_KEYDOWN |
          SDL_KEYUP=>
@@ -379,6 +393,25 @@ pub fn ImGui_ImplSDL2_ProcessEvent(event: *const SDL_Event) -> bool
             io.AddKeyEvent(key, (event.type_ == SDL_KEYDOWN as u32));
             io.SetKeyEventNativeData(key, event.key.keysym.sym, event.key.keysym.scancode as c_int, event.key.keysym.scancode as c_int); // To support legacy indexing (<1.87 user code). Legacy backend uses SDLK_*** as indices to IsKeyXXX() functions.
             return true;
+        }
+        SDL_CONTROLLERDEVICEADDED =>
+        {
+            // Hot-plug: open it on next ImGui_ImplSDL2_UpdateGamepads() if we don't have one yet
+            if bd.GameController.is_null() {
+                bd.WantOpenGameController = true;
+            }
+            return true;
+        }
+        SDL_CONTROLLERDEVICEREMOVED =>
+        {
+            // 'which' is the joystick instance id for removal events
+            if !bd.GameController.is_null() && unsafe { SDL_JoystickInstanceID(SDL_GameControllerGetJoystick(bd.GameController)) } == event.cdevice.which {
+                unsafe { SDL_GameControllerClose(bd.GameController); }
+                bd.GameController = null_mut();
+                bd.WantOpenGameController = true; // Fall back to another connected controller, if any
+                io.AddGamepadReleaseAllEvents();
+            }
+            return true;
         }
          SDL_WINDOWEVENT=>
         {
@@ -466,6 +499,8 @@ pub fn  ImGui_ImplSDL2_Init(window: *mut SDL_Window, renderer: *mut SDL_Renderer
     bd.Window = window;
     bd.Renderer = renderer;
     bd.MouseCanUseGlobalState = mouse_can_use_global_state;
+    bd.GameController = null_mut();
+    bd.WantOpenGameController = true;
 
     io.SetClipboardTextFn = ImGui_ImplSDL2_SetClipboardText;
     io.GetClipboardTextFn = ImGui_ImplSDL2_GetClipboardText;
@@ -575,6 +610,10 @@ pub fn ImGui_ImplSDL2_Shutdown(app_ctx: &mut AppContext)
     if (bd.ClipboardTextData) {
         unsafe { SDL_free(bd.ClipboardTextData as *mut c_void); }
     }
+    if !bd.GameController.is_null() {
+        unsafe { SDL_GameControllerClose(bd.GameController); }
+        bd.GameController = null_mut();
+    }
     // for (ImGuiMouseCursor cursor_n = 0; cursor_n < ImGuiMouseCursor_COUNT; cursor_n++)
     for cursor_n in 0 .. ImGuiMouseCursor_COUNT
     {
@@ -686,34 +725,63 @@ pub fn ImGui_ImplSDL2_UpdateMouseCursor()
 
 
 
+// Open the first joystick recognized as a game controller, or return NULL
+fn ImGui_ImplSDL2_OpenFirstGameController() -> *mut SDL_GameController
+{
+    let joysticks_count = unsafe { SDL_NumJoysticks() };
+    for n in 0 .. joysticks_count
+    {
+        if unsafe { SDL_IsGameController(n) } == SDL_TRUE {
+            let game_controller = unsafe { SDL_GameControllerOpen(n) };
+            if !game_controller.is_null() {
+                return game_controller;
+            }
+        }
+    }
+    null_mut()
+}
+
 pub fn ImGui_ImplSDL2_UpdateGamepads()
 {
     fn map_button(io: &mut IoContext, game_controller: *mut SDL_GameController, key: ImGuiKey, sdl_btn: SDL_GameControllerButton) {
-        unsafe { io.AddKeyEvent(KEY_NO, SDL_GameControllerGetButton(game_controller, BUTTON_NO) != 0); }
+        unsafe { io.AddKeyEvent(key, SDL_GameControllerGetButton(game_controller, sdl_btn) != 0); }
     }
     let mut io = GetIO();
+    let bd = ImGui_ImplSDL2_GetBackendData();
     // FIXME: Technically feeding gamepad shouldn't depend on this now that they are regular inputs.
     if (io.ConfigFlags & ImGuiConfigFlags_NavEnableGamepad) == 0 {
         return;
     }
 
     // Get gamepad
-    io.BackendFlags &= !mGuiBackendFlags_HasGamepad;
-    let mut game_controller = unsafe { SDL_GameControllerOpen(0) };
-    if !game_controller {
+    // (opened lazily and re-opened after SDL_CONTROLLERDEVICEADDED/SDL_CONTROLLERDEVICEREMOVED, see ImGui_ImplSDL2_ProcessEvent())
+    io.BackendFlags &= !ImGuiBackendFlags_HasGamepad;
+    if bd.WantOpenGameController {
+        bd.WantOpenGameController = false;
+        if bd.GameController.is_null() {
+            bd.GameController = ImGui_ImplSDL2_OpenFirstGameController();
+        }
+    }
+    let game_controller = bd.GameController;
+    if game_controller.is_null() {
         return;
     }
     io.BackendFlags |= ImGuiBackendFlags_HasGamepad;
 
     // Update gamepad inputs
-    // #define IM_SATURATE(V)                      (V < 0.0f ? 0.0f : V > 1.0f ? 1.0f : V)
-    // #define MAP_BUTTON(key_no, BUTTON_NO)       { io.AddKeyEvent(key_no, SDL_GameControllerGetButton(game_controller, BUTTON_NO) != 0); }
-    fn map_analog(io: &mut IoContext, game_controller: *mut SDL_GameController, key_no: ImGuiKey, axis_no: SDL_ControllerAxis, v0: f32, v1: f32) {
-        let vn = (unsafe { SDL_GameControllerGetAxis(game_controller, axis_no) } - v0) / (v1 - v0);
-        vn = ImSaturateFloat(vn);
-        io.AddKeyAnalogEvent(key_no, vn > 0.1f32, vn); }
-
-    let thumb_dead_zone = 8000f32;           // SDL_gamecontroller.h suggests using this value.
+    // Axes are normalized to -1.0..1.0 (triggers 0.0..1.0), dead zones are applied by io.AddGamepadStickEvent()/AddGamepadTriggerEvent()
+    // using io.ConfigGamepadStickDeadZone/ConfigGamepadTriggerDeadZone.
+    fn get_axis(game_controller: *mut SDL_GameController, axis_no: SDL_GameControllerAxis) -> f32 {
+        let v = unsafe { SDL_GameControllerGetAxis(game_controller, axis_no) } as f32;
+        (v / 32767f32).max(-1f32)
+    }
+    fn map_trigger(io: &mut IoContext, game_controller: *mut SDL_GameController, key_no: ImGuiKey, axis_no: SDL_GameControllerAxis) {
+        io.AddGamepadTriggerEvent(key_no, get_axis(game_controller, axis_no));
+    }
+    fn map_stick(io: &mut IoContext, game_controller: *mut SDL_GameController, key_negative: ImGuiKey, key_positive: ImGuiKey, axis_no: SDL_GameControllerAxis) {
+        io.AddGamepadStickEvent(key_negative, key_positive, get_axis(game_controller, axis_no));
+    }
+
     map_button(io, game_controller, ImGuiKey_GamepadStart,           SDL_CONTROLLER_BUTTON_START);
     map_button(io, game_controller, ImGuiKey_GamepadBack,            SDL_CONTROLLER_BUTTON_BACK);
     map_button(io, game_controller, ImGuiKey_GamepadFaceLeft,        SDL_CONTROLLER_BUTTON_X);              // Xbox X, PS Square
@@ -726,18 +794,14 @@ pub fn ImGui_ImplSDL2_UpdateGamepads()
     map_button(io, game_controller, ImGuiKey_GamepadDpadDown,        SDL_CONTROLLER_BUTTON_DPAD_DOWN);
     map_button(io, game_controller, ImGuiKey_GamepadL1,              SDL_CONTROLLER_BUTTON_LEFTSHOULDER);
     map_button(io, game_controller, ImGuiKey_GamepadR1,              SDL_CONTROLLER_BUTTON_RIGHTSHOULDER);
-    map_analog(io, game_controller, ImGuiKey_GamepadL2,              SDL_CONTROLLER_AXIS_TRIGGERLEFT,  0.0f32, 32767f32);
-    map_analog(io, game_controller, ImGuiKey_GamepadR2,              SDL_CONTROLLER_AXIS_TRIGGERRIGHT, 0.0f32, 32767f32);
+    map_trigger(io, game_controller, ImGuiKey_GamepadL2,             SDL_CONTROLLER_AXIS_TRIGGERLEFT);
+    map_trigger(io, game_controller, ImGuiKey_GamepadR2,             SDL_CONTROLLER_AXIS_TRIGGERRIGHT);
     map_button(io, game_controller, ImGuiKey_GamepadL3,              SDL_CONTROLLER_BUTTON_LEFTSTICK);
     map_button(io, game_controller, ImGuiKey_GamepadR3,              SDL_CONTROLLER_BUTTON_RIGHTSTICK);
-    map_analog(io, game_controller, ImGuiKey_GamepadLStickLeft,      SDL_CONTROLLER_AXIS_LEFTX,  thumb_dead_zone * -1f32, 32768f32 * -1f32);
-    map_analog(io, game_controller, ImGuiKey_GamepadLStickRight,     SDL_CONTROLLER_AXIS_LEFTX,  thumb_dead_zone, 32767f32);
-    map_analog(io, game_controller, ImGuiKey_GamepadLStickUp,        SDL_CONTROLLER_AXIS_LEFTY,  thumb_dead_zone * -1f32, 32768f32 * -1f32);
-    map_analog(io, game_controller, ImGuiKey_GamepadLStickDown,      SDL_CONTROLLER_AXIS_LEFTY,  thumb_dead_zone, 32767f32);
-    map_analog(io, game_controller, ImGuiKey_GamepadRStickLeft,      SDL_CONTROLLER_AXIS_RIGHTX, thumb_dead_zone * -1f32, 32768f32 * -1f32);
-    map_analog(io, game_controller, ImGuiKey_GamepadRStickRight,     SDL_CONTROLLER_AXIS_RIGHTX, thumb_dead_zone, 32767f32);
-    map_analog(io, game_controller, ImGuiKey_GamepadRStickUp,        SDL_CONTROLLER_AXIS_RIGHTY, thumb_dead_zone * -1f32, 32768f32 * -1f32);
-    map_analog(io, game_controller, ImGuiKey_GamepadRStickDown,      SDL_CONTROLLER_AXIS_RIGHTY, thumb_dead_zone, 32767f32);
+    map_stick(io, game_controller, ImGuiKey_GamepadLStickLeft,  ImGuiKey_GamepadLStickRight, SDL_CONTROLLER_AXIS_LEFTX);
+    map_stick(io, game_controller, ImGuiKey_GamepadLStickUp,    ImGuiKey_GamepadLStickDown,  SDL_CONTROLLER_AXIS_LEFTY);
+    map_stick(io, game_controller, ImGuiKey_GamepadRStickLeft,  ImGuiKey_GamepadRStickRight, SDL_CONTROLLER_AXIS_RIGHTX);
+    map_stick(io, game_controller, ImGuiKey_GamepadRStickUp,    ImGuiKey_GamepadRStickDown,  SDL_CONTROLLER_AXIS_RIGHTY);
 }
 
 // FIXME-PLATFORM: SDL doesn't have an event to notify the application of display/monitor changes
@@ -0,0 +1,483 @@
+// dear imgui: Renderer Backend for a CPU software rasterizer (no GPU required)
+// This needs to be used along with a Platform Backend (e.g. the null platform backend for headless runs, or SDL/GLFW/Win32 when a window exists)
+// (Info: the rasterizer consumes ImDrawData and writes into an RGBA8 framebuffer owned by the backend, which the application can read back or blit.)
+
+// Implemented features:
+//  [X] Renderer: User texture binding. Register RGBA8 pixels with ImGui_ImplSoftware_RegisterTexture() and use the returned value as ImTextureID.
+//  [X] Renderer: Large meshes support (64k+ vertices) with 16-bit indices.
+// Missing features:
+//  [ ] Renderer: Multi-viewport support (multiple windows).
+
+// The rasterizer follows the same conventions as the GPU backends:
+// - Orthographic projection from draw_data.DisplayPos to draw_data.DisplayPos + draw_data.DisplaySize.
+// - Scissor test using ImDrawCmd::ClipRect projected into framebuffer space.
+// - Vertex colors are interpolated across the triangle and multiplied with the sampled texture color.
+// - Blending is (SrcAlpha, OneMinusSrcAlpha) for color and (One, OneMinusSrcAlpha) for alpha, same as imgui_impl_opengl3.
+
+// CHANGELOG
+//  2026-10-18: Initial version.
+
+use std::collections::HashMap;
+use std::ptr::null_mut;
+use std::slice;
+use libc::{c_int, c_uint, size_t};
+use crate::backends::backend_flags::IM_GUI_BACKEND_FLAGS_RENDERER_HAS_VTX_OFFSET;
+use crate::core::context::AppContext;
+use crate::core::type_defs::{ImguiHandle, ImTextureID};
+use crate::core::vec2::Vector2;
+use crate::debugging::rgba_image::RgbaImage;
+use crate::drawing::draw_cmd::ImDrawCmd;
+use crate::drawing::draw_data::ImDrawData;
+use crate::drawing::draw_list::ImDrawList;
+use crate::drawing::draw_vert::ImguiDrawVertex;
+use crate::io::backend_renderer_user_data::BackendRendererUserData;
+
+// Texture id reserved for the font atlas. User textures are numbered from there.
+pub const IMGUI_IMPL_SOFTWARE_FONT_TEXTURE_ID: usize = 1;
+
+// RGBA8 texture sampled by the rasterizer
+#[derive(Default, Debug, Clone)]
+pub struct SoftwareTexture {
+    pub Width: usize,
+    pub Height: usize,
+    // 4 bytes per pixel, R G B A order (same as ImFontAtlas::GetTexDataAsRGBA32())
+    pub Pixels: Vec<u8>,
+}
+
+impl SoftwareTexture {
+    // None when 'pixels' holds less than width * height * 4 bytes
+    pub fn new(width: usize, height: usize, pixels: &[u8]) -> Option<Self> {
+        let size = width.checked_mul(height).and_then(|n| n.checked_mul(4))?;
+        Some(Self {
+            Width: width,
+            Height: height,
+            Pixels: pixels.get(..size)?.to_vec(),
+        })
+    }
+
+    // Nearest sampling with clamp-to-edge addressing. UV are in [0,1] range.
+    pub fn Sample(&self, u: f32, v: f32) -> [f32; 4] {
+        if self.Width == 0 || self.Height == 0 {
+            return [1.0, 1.0, 1.0, 1.0];
+        }
+        let x = ((u * self.Width as f32) as isize).clamp(0, self.Width as isize - 1) as usize;
+        let y = ((v * self.Height as f32) as isize).clamp(0, self.Height as isize - 1) as usize;
+        let p = &self.Pixels[(y * self.Width + x) * 4..(y * self.Width + x) * 4 + 4];
+        [
+            p[0] as f32 / 255.0,
+            p[1] as f32 / 255.0,
+            p[2] as f32 / 255.0,
+            p[3] as f32 / 255.0,
+        ]
+    }
+}
+
+// RGBA8 render target written by ImGui_ImplSoftware_RenderDrawData()
+#[derive(Default, Debug, Clone)]
+pub struct SoftwareFramebuffer {
+    pub Width: usize,
+    pub Height: usize,
+    // 4 bytes per pixel, R G B A order, rows top to bottom
+    pub Pixels: Vec<u8>,
+}
+
+impl SoftwareFramebuffer {
+    pub fn Resize(&mut self, width: usize, height: usize) {
+        if self.Width == width && self.Height == height {
+            return;
+        }
+        self.Width = width;
+        self.Height = height;
+        self.Pixels.clear();
+        self.Pixels.resize(width * height * 4, 0);
+    }
+
+    pub fn Clear(&mut self, col: [u8; 4]) {
+        for px in self.Pixels.chunks_exact_mut(4) {
+            px.copy_from_slice(&col);
+        }
+    }
+
+    // Blend a color in [0,1] range over the pixel at (x, y)
+    #[inline]
+    fn BlendPixel(&mut self, x: usize, y: usize, src: [f32; 4]) {
+        let idx = (y * self.Width + x) * 4;
+        let dst = &mut self.Pixels[idx..idx + 4];
+        let sa = src[3];
+        if sa <= 0.0 {
+            return;
+        }
+        let inv_sa = 1.0 - sa;
+        for c in 0..3 {
+            let d = dst[c] as f32 / 255.0;
+            dst[c] = ((src[c] * sa + d * inv_sa) * 255.0 + 0.5).clamp(0.0, 255.0) as u8;
+        }
+        let da = dst[3] as f32 / 255.0;
+        dst[3] = ((sa + da * inv_sa) * 255.0 + 0.5).clamp(0.0, 255.0) as u8;
+    }
+}
+
+// Software renderer data
+#[derive(Default, Debug, Clone)]
+pub struct SoftwareRendererData {
+    pub Framebuffer: SoftwareFramebuffer,
+    // Clear color applied at the start of every ImGui_ImplSoftware_RenderDrawData() call (R G B A)
+    pub ClearColor: [u8; 4],
+    pub FontTexture: SoftwareTexture,
+    // User textures, keyed by the ImTextureID value returned from ImGui_ImplSoftware_RegisterTexture()
+    pub Textures: HashMap<usize, SoftwareTexture>,
+    pub NextTextureId: usize,
+}
+
+// Backend data stored in io.backend_renderer_user_data to allow support for multiple Dear ImGui contexts
+pub fn ImGui_ImplSoftware_GetBackendData(g: &mut AppContext) -> Option<&mut SoftwareRendererData> {
+    match &mut g.IO.backend_renderer_user_data {
+        BackendRendererUserData::Software(bd) => Some(bd),
+        _ => None,
+    }
+}
+
+// Functions
+pub fn ImGui_ImplSoftware_Init(g: &mut AppContext) -> bool {
+    // IM_ASSERT(g.IO.backend_renderer_user_data == None && "Already initialized a renderer backend!");
+
+    // Setup backend capabilities flags
+    let mut bd = SoftwareRendererData::default();
+    bd.ClearColor = [0, 0, 0, 255];
+    bd.NextTextureId = IMGUI_IMPL_SOFTWARE_FONT_TEXTURE_ID + 1;
+    g.IO.backend_renderer_user_data = BackendRendererUserData::Software(bd);
+    g.IO.backend_renderer_name = String::from("imgui_impl_software");
+    g.IO.backend_flags |= IM_GUI_BACKEND_FLAGS_RENDERER_HAS_VTX_OFFSET; // We can honor the ImDrawCmd::VtxOffset field, allowing for large meshes.
+    g.PlatformIO.Renderer_ReadPixels = Some(ImGui_ImplSoftware_ReadPixels);
+
+    return true;
+}
+
+pub fn ImGui_ImplSoftware_Shutdown(g: &mut AppContext) {
+    // IM_ASSERT(bd != NULL && "No renderer backend to shutdown, or already shutdown?");
+    ImGui_ImplSoftware_DestroyDeviceObjects(g);
+    g.IO.backend_renderer_name = String::new();
+    g.IO.backend_renderer_user_data = BackendRendererUserData::None;
+    g.IO.backend_flags &= !IM_GUI_BACKEND_FLAGS_RENDERER_HAS_VTX_OFFSET;
+    g.PlatformIO.Renderer_ReadPixels = None;
+}
+
+pub fn ImGui_ImplSoftware_NewFrame(g: &mut AppContext) {
+    let needs_font = match ImGui_ImplSoftware_GetBackendData(g) {
+        Some(bd) => bd.FontTexture.Pixels.is_empty(),
+        None => {
+            // IM_ASSERT(bd != NULL && "Did you call ImGui_ImplSoftware_Init()?");
+            return;
+        }
+    };
+    if needs_font {
+        ImGui_ImplSoftware_CreateDeviceObjects(g);
+    }
+}
+
+pub fn ImGui_ImplSoftware_CreateFontsTexture(g: &mut AppContext) -> bool {
+    // Build texture atlas
+    let mut pixels: *mut c_uint = null_mut();
+    let mut width: size_t = 0;
+    let mut height: size_t = 0;
+    let atlas = match g.IO.Fonts.as_mut() {
+        Some(atlas) => atlas,
+        None => return false,
+    };
+    // Load as RGBA 32-bit: the rasterizer samples all textures the same way, so we don't special-case alpha-only fonts.
+    unsafe { atlas.GetTexDataAsRGBA32(&mut pixels, &mut width, &mut height, null_mut()) };
+    if pixels.is_null() || width == 0 || height == 0 {
+        return false;
+    }
+    let src = unsafe { slice::from_raw_parts(pixels as *const u8, width * height * 4) };
+    let font_texture = match SoftwareTexture::new(width, height, src) {
+        Some(texture) => texture,
+        None => return false,
+    };
+
+    // Store our identifier
+    atlas.SetTexID(IMGUI_IMPL_SOFTWARE_FONT_TEXTURE_ID as ImTextureID);
+    match ImGui_ImplSoftware_GetBackendData(g) {
+        Some(bd) => bd.FontTexture = font_texture,
+        None => return false,
+    }
+    return true;
+}
+
+pub fn ImGui_ImplSoftware_DestroyFontsTexture(g: &mut AppContext) {
+    if let Some(bd) = ImGui_ImplSoftware_GetBackendData(g) {
+        bd.FontTexture = SoftwareTexture::default();
+    }
+    if let Some(atlas) = g.IO.Fonts.as_mut() {
+        atlas.SetTexID(null_mut());
+    }
+}
+
+pub fn ImGui_ImplSoftware_CreateDeviceObjects(g: &mut AppContext) -> bool {
+    ImGui_ImplSoftware_CreateFontsTexture(g)
+}
+
+pub fn ImGui_ImplSoftware_DestroyDeviceObjects(g: &mut AppContext) {
+    ImGui_ImplSoftware_DestroyFontsTexture(g);
+    if let Some(bd) = ImGui_ImplSoftware_GetBackendData(g) {
+        bd.Textures.clear();
+    }
+}
+
+// Register RGBA8 pixels (4 bytes per pixel) to be used with Image()/ImageButton(). The returned value is the ImTextureID to pass.
+// Return null when the backend isn't initialized or 'pixels' is shorter than width * height * 4 bytes.
+pub fn ImGui_ImplSoftware_RegisterTexture(g: &mut AppContext, width: usize, height: usize, pixels: &[u8]) -> ImTextureID {
+    let bd = match ImGui_ImplSoftware_GetBackendData(g) {
+        Some(bd) => bd,
+        None => return null_mut(),
+    };
+    let texture = match SoftwareTexture::new(width, height, pixels) {
+        Some(texture) => texture,
+        None => return null_mut(),
+    };
+    let id = bd.NextTextureId;
+    bd.NextTextureId += 1;
+    bd.Textures.insert(id, texture);
+    id as ImTextureID
+}
+
+pub fn ImGui_ImplSoftware_UnregisterTexture(g: &mut AppContext, tex_id: ImTextureID) {
+    if let Some(bd) = ImGui_ImplSoftware_GetBackendData(g) {
+        bd.Textures.remove(&(tex_id as usize));
+    }
+}
+
+// Access the framebuffer written by the last call to ImGui_ImplSoftware_RenderDrawData()
+pub fn ImGui_ImplSoftware_GetFramebuffer(g: &mut AppContext) -> Option<&SoftwareFramebuffer> {
+    ImGui_ImplSoftware_GetBackendData(g).map(|bd| &bd.Framebuffer)
+}
+
+// Read back a rectangle of the framebuffer written by the last ImGui_ImplSoftware_RenderDrawData() call (main viewport only)
+pub fn ImGui_ImplSoftware_ReadPixels(g: &mut AppContext, _viewport_id: ImguiHandle, x: c_int, y: c_int, w: c_int, h: c_int, out_image: &mut RgbaImage) -> bool {
+    let fb = match ImGui_ImplSoftware_GetFramebuffer(g) {
+        Some(fb) => fb,
+        None => return false,
+    };
+    if x < 0 || y < 0 || w <= 0 || h <= 0 || (x + w) as usize > fb.Width || (y + h) as usize > fb.Height {
+        return false;
+    }
+    let full = RgbaImage::from_pixels(fb.Width, fb.Height, fb.Pixels.clone());
+    *out_image = full.Crop(x as usize, y as usize, w as usize, h as usize);
+    return true;
+}
+
+// Render function
+pub fn ImGui_ImplSoftware_RenderDrawData(g: &mut AppContext, draw_data: &ImDrawData) {
+    let bd = match ImGui_ImplSoftware_GetBackendData(g) {
+        Some(bd) => bd,
+        None => return,
+    };
+    ImGui_ImplSoftware_RenderDrawDataInto(bd, draw_data);
+}
+
+// Same as ImGui_ImplSoftware_RenderDrawData() but with explicit backend data, so it can be used without a current context.
+pub fn ImGui_ImplSoftware_RenderDrawDataInto(bd: &mut SoftwareRendererData, draw_data: &ImDrawData) {
+    // (The framebuffer is moved out while rasterizing so textures can be borrowed from the backend data at the same time)
+    let mut fb = std::mem::take(&mut bd.Framebuffer);
+    ImGui_ImplSoftware_RenderDrawDataToFramebuffer(bd, draw_data, &mut fb);
+    bd.Framebuffer = fb;
+}
+
+// Rasterize into a framebuffer owned by the caller, using the textures of the backend data. The backend framebuffer is left untouched.
+pub fn ImGui_ImplSoftware_RenderDrawDataToFramebuffer(bd: &SoftwareRendererData, draw_data: &ImDrawData, fb: &mut SoftwareFramebuffer) {
+    // Avoid rendering when minimized, scale coordinates for retina displays (screen coordinates != framebuffer coordinates)
+    let fb_width = (draw_data.DisplaySize.x * draw_data.FramebufferScale.x) as i32;
+    let fb_height = (draw_data.DisplaySize.y * draw_data.FramebufferScale.y) as i32;
+    if fb_width <= 0 || fb_height <= 0 {
+        return;
+    }
+    fb.Resize(fb_width as usize, fb_height as usize);
+    fb.Clear(bd.ClearColor);
+
+    // Will project scissor/clipping rectangles into framebuffer space
+    let clip_off = draw_data.DisplayPos; // (0,0) unless using multi-viewports
+    let clip_scale = draw_data.FramebufferScale; // (1,1) unless using retina display which are often (2,2)
+
+    // Render command lists
+    for cmd_list in draw_data.CmdLists.values() {
+        for pcmd in cmd_list.CmdBuffer.iter() {
+            if let Some(user_callback) = pcmd.UserCallback {
+                // User callback, registered via ImDrawList::AddCallback()
+                user_callback(cmd_list, pcmd);
+                continue;
+            }
+
+            // Project scissor/clipping rectangles into framebuffer space
+            let clip_min_x = ((pcmd.ClipRect.x - clip_off.x) * clip_scale.x).max(0.0);
+            let clip_min_y = ((pcmd.ClipRect.y - clip_off.y) * clip_scale.y).max(0.0);
+            let clip_max_x = ((pcmd.ClipRect.z - clip_off.x) * clip_scale.x).min(fb_width as f32);
+            let clip_max_y = ((pcmd.ClipRect.w - clip_off.y) * clip_scale.y).min(fb_height as f32);
+            if clip_max_x <= clip_min_x || clip_max_y <= clip_min_y {
+                continue;
+            }
+            let clip = [
+                clip_min_x as i32,
+                clip_min_y as i32,
+                clip_max_x.ceil() as i32,
+                clip_max_y.ceil() as i32,
+            ];
+
+            ImGui_ImplSoftware_RenderCmd(fb, bd, cmd_list, pcmd, &clip_off, &clip_scale, clip);
+        }
+    }
+}
+
+fn ImGui_ImplSoftware_RenderCmd(
+    fb: &mut SoftwareFramebuffer,
+    bd: &SoftwareRendererData,
+    cmd_list: &ImDrawList,
+    pcmd: &ImDrawCmd,
+    clip_off: &Vector2,
+    clip_scale: &Vector2,
+    clip: [i32; 4],
+) {
+    let tex_key = pcmd.GetTexID() as usize;
+    let texture = if tex_key == IMGUI_IMPL_SOFTWARE_FONT_TEXTURE_ID {
+        Some(&bd.FontTexture)
+    } else {
+        bd.Textures.get(&tex_key)
+    };
+    // Unknown texture ids are rendered untextured rather than skipped, so bugs stay visible.
+
+    // Malformed draw data (indices or vertices out of range) skips the whole command instead of panicking halfway through it.
+    let indices = match pcmd.IdxOffset.checked_add(pcmd.ElemCount).and_then(|idx_end| cmd_list.IdxBuffer.get(pcmd.IdxOffset..idx_end)) {
+        Some(indices) => indices,
+        None => return,
+    };
+    let vertices = match cmd_list.VtxBuffer.get(pcmd.VtxOffset..) {
+        Some(vertices) => vertices,
+        None => return,
+    };
+    if indices.iter().any(|idx| *idx as usize >= vertices.len()) {
+        return;
+    }
+
+    for tri in indices.chunks_exact(3) {
+        let v0 = &vertices[tri[0] as usize];
+        let v1 = &vertices[tri[1] as usize];
+        let v2 = &vertices[tri[2] as usize];
+        ImGui_ImplSoftware_RasterizeTriangle(fb, texture, [v0, v1, v2], clip_off, clip_scale, clip);
+    }
+}
+
+#[inline]
+fn ImGui_ImplSoftware_UnpackColor(col: u32) -> [f32; 4] {
+    [
+        (col & 0xFF) as f32 / 255.0,
+        ((col >> 8) & 0xFF) as f32 / 255.0,
+        ((col >> 16) & 0xFF) as f32 / 255.0,
+        ((col >> 24) & 0xFF) as f32 / 255.0,
+    ]
+}
+
+#[inline]
+fn ImGui_ImplSoftware_EdgeFunction(a: &Vector2, b: &Vector2, px: f32, py: f32) -> f32 {
+    (b.x - a.x) * (py - a.y) - (b.y - a.y) * (px - a.x)
+}
+
+// Scanline-free half-space rasterizer. Pixel centers are sampled at +0.5, with a top-left fill rule so shared edges are not drawn twice.
+fn ImGui_ImplSoftware_RasterizeTriangle(
+    fb: &mut SoftwareFramebuffer,
+    texture: Option<&SoftwareTexture>,
+    verts: [&ImguiDrawVertex; 3],
+    clip_off: &Vector2,
+    clip_scale: &Vector2,
+    clip: [i32; 4],
+) {
+    let mut p: [Vector2; 3] = [Vector2::default(); 3];
+    for n in 0..3 {
+        p[n] = Vector2::from_floats(
+            (verts[n].pos.x - clip_off.x) * clip_scale.x,
+            (verts[n].pos.y - clip_off.y) * clip_scale.y,
+        );
+    }
+
+    // ImDrawList emits triangles with either winding, normalize so that the edge functions are positive inside.
+    let mut area = ImGui_ImplSoftware_EdgeFunction(&p[0], &p[1], p[2].x, p[2].y);
+    if area == 0.0 {
+        return;
+    }
+    let mut order = [0usize, 1, 2];
+    if area < 0.0 {
+        order = [0, 2, 1];
+        area = -area;
+    }
+    let (a, b, c) = (p[order[0]], p[order[1]], p[order[2]]);
+    let (va, vb, vc) = (verts[order[0]], verts[order[1]], verts[order[2]]);
+
+    let min_x = (a.x.min(b.x).min(c.x).floor() as i32).max(clip[0]);
+    let min_y = (a.y.min(b.y).min(c.y).floor() as i32).max(clip[1]);
+    let max_x = (a.x.max(b.x).max(c.x).ceil() as i32).min(clip[2]);
+    let max_y = (a.y.max(b.y).max(c.y).ceil() as i32).min(clip[3]);
+    if min_x >= max_x || min_y >= max_y {
+        return;
+    }
+
+    let col_a = ImGui_ImplSoftware_UnpackColor(va.col);
+    let col_b = ImGui_ImplSoftware_UnpackColor(vb.col);
+    let col_c = ImGui_ImplSoftware_UnpackColor(vc.col);
+    let flat_color = va.col == vb.col && vb.col == vc.col;
+
+    // Top-left rule: with the winding above (clockwise on screen), an edge is 'top' if horizontal going right, 'left' if going up.
+    let is_top_left = |e0: &Vector2, e1: &Vector2| -> bool {
+        let dy = e1.y - e0.y;
+        let dx = e1.x - e0.x;
+        (dy == 0.0 && dx > 0.0) || dy < 0.0
+    };
+    // A pixel center exactly on an edge is only covered by the triangle for which that edge is top or left.
+    let inside = |w: f32, top_left: bool| -> bool { w > 0.0 || (w == 0.0 && top_left) };
+    let top_left0 = is_top_left(&b, &c);
+    let top_left1 = is_top_left(&c, &a);
+    let top_left2 = is_top_left(&a, &b);
+
+    let inv_area = 1.0 / area;
+    for y in min_y..max_y {
+        let py = y as f32 + 0.5;
+        for x in min_x..max_x {
+            let px = x as f32 + 0.5;
+            let w0 = ImGui_ImplSoftware_EdgeFunction(&b, &c, px, py);
+            let w1 = ImGui_ImplSoftware_EdgeFunction(&c, &a, px, py);
+            let w2 = ImGui_ImplSoftware_EdgeFunction(&a, &b, px, py);
+            if !inside(w0, top_left0) || !inside(w1, top_left1) || !inside(w2, top_left2) {
+                continue;
+            }
+            let l0 = w0 * inv_area;
+            let l1 = w1 * inv_area;
+            let l2 = w2 * inv_area;
+
+            let mut col = if flat_color {
+                col_a
+            } else {
+                [
+                    col_a[0] * l0 + col_b[0] * l1 + col_c[0] * l2,
+                    col_a[1] * l0 + col_b[1] * l1 + col_c[1] * l2,
+                    col_a[2] * l0 + col_b[2] * l1 + col_c[2] * l2,
+                    col_a[3] * l0 + col_b[3] * l1 + col_c[3] * l2,
+                ]
+            };
+            if let Some(tex) = texture {
+                let u = va.uv.x * l0 + vb.uv.x * l1 + vc.uv.x * l2;
+                let v = va.uv.y * l0 + vb.uv.y * l1 + vc.uv.y * l2;
+                let texel = tex.Sample(u, v);
+                for n in 0..4 {
+                    col[n] *= texel[n];
+                }
+            }
+            fb.BlendPixel(x as usize, y as usize, col);
+        }
+    }
+}
+
+// Convenience helper for applications which own their draw data: render all given lists into a fresh RGBA8 buffer.
+pub fn ImGui_ImplSoftware_RasterizeToBuffer(draw_data: &ImDrawData, font_texture: &SoftwareTexture) -> SoftwareFramebuffer {
+    let mut bd = SoftwareRendererData::default();
+    bd.ClearColor = [0, 0, 0, 255];
+    bd.FontTexture = font_texture.clone();
+    ImGui_ImplSoftware_RenderDrawDataInto(&mut bd, draw_data);
+    bd.Framebuffer
+}
@@ -114,11 +114,12 @@ use ash::vk;
 use sdl2::sys::Font;
 use crate::drawing::draw_data::ImDrawData;
 use crate::io::io_ops::GetIO;
-use libc::{c_char, c_void};
+use libc::{c_char, c_int, c_void};
 use crate::backends::backend_flags::{IM_GUI_BACKEND_FLAGS_RENDERER_HAS_VIEWPORTS, IM_GUI_BACKEND_FLAGS_RENDERER_HAS_VTX_OFFSET};
 use crate::core::config_flags::ImGuiConfigFlags_ViewportsEnable;
 use crate::core::context::AppContext;
-use crate::core::type_defs::ImTextureID;
+use crate::core::type_defs::{ImguiHandle, ImTextureID};
+use crate::debugging::rgba_image::RgbaImage;
 use crate::core::vec4::ImVec4;
 use crate::viewport::ImguiViewport;
 use crate::viewport::viewport_flags::ImguiViewportFlags_NoRendererClear;
@@ -269,6 +270,11 @@ pub struct ImGui_ImplVulkan_Data
     // Render buffers for main window
     // ImGui_ImplVulkanH_WindowRenderBuffers MainWindowRenderBuffers;
     pub MainWindowRenderBuffers: ImGui_ImplVulkanH_WindowRenderBuffers,
+    // Image of the main viewport the application rendered into, for ImGui_ImplVulkan_ReadPixels() (see ImGui_ImplVulkan_SetReadbackImage())
+    pub ReadbackImage: vk::Image,
+    pub ReadbackFormat: vk::Format,
+    pub ReadbackExtent: vk::Extent2D,
+    pub ReadbackLayout: vk::ImageLayout,
     // ImGui_ImplVulkan_Data()
     // {
     //     memset(this, 0, sizeof(*this));
@@ -329,6 +335,7 @@ pub struct ImGui_ImplVulkan_InitInfo
 }
 
 // Called by user code
+// IMGUI_IMPL_API void         ImGui_ImplVulkan_SetReadbackImage(VkImage image, VkFormat format, VkExtent2D extent, VkImageLayout layout);
 // IMGUI_IMPL_API bool         ImGui_ImplVulkan_Init(ImGui_ImplVulkan_InitInfo* info, VkRenderPass render_pass);
 // IMGUI_IMPL_API void         ImGui_ImplVulkan_Shutdown();
 // IMGUI_IMPL_API void         ImGui_ImplVulkan_NewFrame();
@@ -1212,6 +1219,7 @@ pub fn    ImGui_ImplVulkan_Init(info: *mut ImGui_ImplVulkan_InitInfo, render_pas
     if io.ConfigFlags & ImGuiConfigFlags_ViewportsEnable {
         ImGui_ImplVulkan_InitPlatformInterface();
     }
+    GetPlatformIO().Renderer_ReadPixels = Some(ImGui_ImplVulkan_ReadPixels);
 
     return true;
 }
@@ -1236,6 +1244,7 @@ pub fn ImGui_ImplVulkan_Shutdown(app_ctx: &mut AppContext)
 
     // Clean up windows
     ImGui_ImplVulkan_ShutdownPlatformInterface(app_ctx);
+    GetPlatformIO().Renderer_ReadPixels = None;
 
     io.BackendRendererName = null_mut();
     io.BackendRendererUserData = null_mut();
@@ -1249,6 +1258,156 @@ pub fn ImGui_ImplVulkan_NewFrame()
     // IM_UNUSED(bd);
 }
 
+// The application owns the main viewport framebuffer: tell the backend which image was rendered, so it can be read back by
+// CaptureScreenshot()/RequestScreenshot(). Call every frame after recording ImGui_ImplVulkan_RenderDrawData() (swapchain images
+// rotate), with the layout the image will be in once the command buffer was submitted, e.g. VK_IMAGE_LAYOUT_PRESENT_SRC_KHR.
+// The image must have been created with VK_IMAGE_USAGE_TRANSFER_SRC_BIT (for a swapchain: VkSwapchainCreateInfoKHR::imageUsage).
+pub fn ImGui_ImplVulkan_SetReadbackImage(image: vk::Image, format: vk::Format, extent: vk::Extent2D, layout: vk::ImageLayout)
+{
+    let mut bd = ImGui_ImplVulkan_GetBackendData();
+    bd.ReadbackImage = image;
+    bd.ReadbackFormat = format;
+    bd.ReadbackExtent = extent;
+    bd.ReadbackLayout = layout;
+}
+
+// Read back a rectangle of the image registered with ImGui_ImplVulkan_SetReadbackImage(), used by CaptureScreenshot(). (x,y) is the top-left corner in framebuffer pixels.
+// This waits for the queue to be idle, it is meant for tests and tools, not for every frame. Only the main viewport is supported.
+pub fn ImGui_ImplVulkan_ReadPixels(_g: &mut AppContext, viewport_id: ImguiHandle, x: c_int, y: c_int, w: c_int, h: c_int, out_image: &mut RgbaImage) -> bool
+{
+    let mut bd = ImGui_ImplVulkan_GetBackendData();
+    let mut v = &bd.VulkanInitInfo;
+    if viewport_id != GetMainViewport().ID || bd.ReadbackImage == VK_NULL_HANDLE {
+        return false;
+    }
+    if x < 0 || y < 0 || w <= 0 || h <= 0 || (x + w) as u32 > bd.ReadbackExtent.width || (y + h) as u32 > bd.ReadbackExtent.height {
+        return false;
+    }
+    let swap_red_blue = match bd.ReadbackFormat {
+        VK_FORMAT_R8G8B8A8_UNORM | VK_FORMAT_R8G8B8A8_SRGB => false,
+        VK_FORMAT_B8G8R8A8_UNORM | VK_FORMAT_B8G8R8A8_SRGB => true,
+        _ => return false,  // Other formats would need a conversion
+    };
+    let mut err: vk::Result;
+    let readback_size: vk::DeviceSize = (w * h * 4) as vk::DeviceSize;
+
+    // Create the Readback Buffer:
+    let mut buffer: vk::Buffer = VK_NULL_HANDLE;
+    let mut buffer_memory: vk::DeviceMemory = VK_NULL_HANDLE;
+    {
+        let mut buffer_info: vk::BufferCreateInfo = vk::BufferCreateInfo{};
+        buffer_info.sType = VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO;
+        buffer_info.size = readback_size;
+        buffer_info.usage = VK_BUFFER_USAGE_TRANSFER_DST_BIT;
+        buffer_info.sharingMode = VK_SHARING_MODE_EXCLUSIVE;
+        err = vkCreateBuffer(v.Device, &buffer_info, v.Allocator, &mut buffer);
+        check_vk_result(err);
+        let mut req: vk::MemoryRequirements = vk::MemoryRequirements{};
+        vk::PFN_vkGetBufferMemoryRequirements(v.Device, buffer, &mut req);
+        let mut alloc_info: vk::MemoryAllocateInfo = vk::MemoryAllocateInfo{};
+        alloc_info.sType = VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO;
+        alloc_info.allocationSize = req.size;
+        alloc_info.memoryTypeIndex = ImGui_ImplVulkan_MemoryType(VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT | VK_MEMORY_PROPERTY_HOST_COHERENT_BIT, req.memoryTypeBits);
+        err = vkAllocateMemory(v.Device, &alloc_info, v.Allocator, &mut buffer_memory);
+        check_vk_result(err);
+        err = vkBindBufferMemory(v.Device, buffer, buffer_memory, 0);
+        check_vk_result(err);
+    }
+
+    // Record the copy in a one-time command buffer:
+    let mut command_pool: vk::CommandPool = VK_NULL_HANDLE;
+    let mut command_buffer: vk::CommandBuffer = VK_NULL_HANDLE;
+    {
+        let mut pool_info: vk::CommandPoolCreateInfo = vk::CommandPoolCreateInfo{};
+        pool_info.sType = VK_STRUCTURE_TYPE_COMMAND_POOL_CREATE_INFO;
+        pool_info.flags = VK_COMMAND_POOL_CREATE_TRANSIENT_BIT;
+        pool_info.queueFamilyIndex = v.QueueFamily;
+        err = vkCreateCommandPool(v.Device, &pool_info, v.Allocator, &mut command_pool);
+        check_vk_result(err);
+        let mut alloc_info: vk::CommandBufferAllocateInfo = vk::CommandBufferAllocateInfo{};
+        alloc_info.sType = VK_STRUCTURE_TYPE_COMMAND_BUFFER_ALLOCATE_INFO;
+        alloc_info.commandPool = command_pool;
+        alloc_info.level = VK_COMMAND_BUFFER_LEVEL_PRIMARY;
+        alloc_info.commandBufferCount = 1;
+        err = vkAllocateCommandBuffers(v.Device, &alloc_info, &mut command_buffer);
+        check_vk_result(err);
+        let mut begin_info: vk::CommandBufferBeginInfo = vk::CommandBufferBeginInfo{};
+        begin_info.sType = VK_STRUCTURE_TYPE_COMMAND_BUFFER_BEGIN_INFO;
+        begin_info.flags = VK_COMMAND_BUFFER_USAGE_ONE_TIME_SUBMIT_BIT;
+        err = vkBeginCommandBuffer(command_buffer, &begin_info);
+        check_vk_result(err);
+    }
+    {
+        let mut barrier: [vk::ImageMemoryBarrier;1] = [vk::ImageMemoryBarrier{}];
+        barrier[0].sType = VK_STRUCTURE_TYPE_IMAGE_MEMORY_BARRIER;
+        barrier[0].srcAccessMask = VK_ACCESS_COLOR_ATTACHMENT_WRITE_BIT;
+        barrier[0].dstAccessMask = VK_ACCESS_TRANSFER_READ_BIT;
+        barrier[0].oldLayout = bd.ReadbackLayout;
+        barrier[0].newLayout = VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL;
+        barrier[0].srcQueueFamilyIndex = VK_QUEUE_FAMILY_IGNORED;
+        barrier[0].dstQueueFamilyIndex = VK_QUEUE_FAMILY_IGNORED;
+        barrier[0].image = bd.ReadbackImage;
+        barrier[0].subresourceRange.aspectMask = VK_IMAGE_ASPECT_COLOR_BIT;
+        barrier[0].subresourceRange.levelCount = 1;
+        barrier[0].subresourceRange.layerCount = 1;
+        vk::PFN_vkCmdPipelineBarrier(command_buffer, VK_PIPELINE_STAGE_COLOR_ATTACHMENT_OUTPUT_BIT, VK_PIPELINE_STAGE_TRANSFER_BIT, 0, 0, null_mut(), 0, null_mut(), 1, barrier);
+
+        let mut region: vk::BufferImageCopy = vk::BufferImageCopy{};
+        region.imageSubresource.aspectMask = VK_IMAGE_ASPECT_COLOR_BIT;
+        region.imageSubresource.layerCount = 1;
+        region.imageOffset.x = x;
+        region.imageOffset.y = y;
+        region.imageExtent.width = w as u32;
+        region.imageExtent.height = h as u32;
+        region.imageExtent.depth = 1;
+        vk::PFN_vkCmdCopyImageToBuffer(command_buffer, bd.ReadbackImage, VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL, buffer, 1, &region);
+
+        // Give the image back in the layout the application expects
+        barrier[0].srcAccessMask = VK_ACCESS_TRANSFER_READ_BIT;
+        barrier[0].dstAccessMask = 0;
+        barrier[0].oldLayout = VK_IMAGE_LAYOUT_TRANSFER_SRC_OPTIMAL;
+        barrier[0].newLayout = bd.ReadbackLayout;
+        vk::PFN_vkCmdPipelineBarrier(command_buffer, VK_PIPELINE_STAGE_TRANSFER_BIT, VK_PIPELINE_STAGE_BOTTOM_OF_PIPE_BIT, 0, 0, null_mut(), 0, null_mut(), 1, barrier);
+    }
+
+    // Submit and wait:
+    {
+        err = vkEndCommandBuffer(command_buffer);
+        check_vk_result(err);
+        let mut submit_info: vk::SubmitInfo = vk::SubmitInfo{};
+        submit_info.sType = VK_STRUCTURE_TYPE_SUBMIT_INFO;
+        submit_info.commandBufferCount = 1;
+        submit_info.pCommandBuffers = &command_buffer;
+        err = vkQueueSubmit(v.Queue, 1, &submit_info, VK_NULL_HANDLE);
+        check_vk_result(err);
+        err = vkQueueWaitIdle(v.Queue);
+        check_vk_result(err);
+    }
+
+    // Copy to the output image:
+    let ok = err == VK_SUCCESS;
+    if ok
+    {
+        let mut map: *mut c_void = null_mut();
+        err = vk::PFN_vkMapMemory(v.Device, buffer_memory, 0, readback_size, 0, &mut map);
+        check_vk_result(err);
+        *out_image = RgbaImage::new(w as usize, h as usize);
+        unsafe { libc::memcpy(out_image.Pixels.as_mut_ptr() as *mut c_void, map, readback_size as usize); }
+        vkUnmapMemory(v.Device, buffer_memory);
+        if swap_red_blue {
+            for px in out_image.Pixels.chunks_exact_mut(4) {
+                px.swap(0, 2);
+            }
+        }
+    }
+
+    vkFreeCommandBuffers(v.Device, command_pool, 1, &command_buffer);
+    vkDestroyCommandPool(v.Device, command_pool, v.Allocator);
+    vkDestroyBuffer(v.Device, buffer, v.Allocator);
+    vkFreeMemory(v.Device, buffer_memory, v.Allocator);
+    return ok;
+}
+
 pub fn ImGui_ImplVulkan_SetMinImageCount(min_image_count: u32)
 {
    let mut bd = ImGui_ImplVulkan_GetBackendData();
@@ -13,6 +13,7 @@
 
 // CHANGELOG
 // (minor and older changes stripped away, please see git history for details)
+//  2026-10-18: Added render target readback, registered as PlatformIO.Renderer_ReadPixels for CaptureScreenshot(). See ImGui_ImplWGPU_SetReadbackTexture().
 //  2021-11-29: Passing explicit buffer sizes to wgpuRenderPassEncoderSetVertexBuffer()/wgpuRenderPassEncoderSetIndexBuffer().
 //  2021-08-24: Fix for latest specs.
 //  2021-05-24: Add support for draw_data.FramebufferScale.
@@ -40,6 +41,10 @@
 // static WGPUQueue                g_defaultQueue = NULL;
 // static WGPUTextureFormat        g_renderTargetFormat = WGPUTextureFormat_Undefined;
 // static WGPURenderPipeline       g_pipelineState = NULL;
+// static WGPUTexture              g_readbackTexture = NULL;      // Render target of the main viewport, see ImGui_ImplWGPU_SetReadbackTexture()
+// static WGPUTextureFormat        g_readbackFormat = WGPUTextureFormat_Undefined;
+// static int                      g_readbackWidth = 0;
+// static int                      g_readbackHeight = 0;
 
 use std::borrow::Cow;
 use std::ptr::null_mut;
@@ -503,6 +508,88 @@ void ImGui_ImplWGPU_RenderDrawData(ImDrawData* draw_data, WGPURenderPassEncoder
     }
 }
 
+// The application owns the render target: tell the backend which texture the main viewport was rendered to, so it can be read back by
+// CaptureScreenshot()/RequestScreenshot(). Call every frame after ImGui_ImplWGPU_RenderDrawData() (swapchain textures rotate).
+// The texture must have been created with WGPUTextureUsage_CopySrc (for a surface: WGPUSurfaceConfiguration::usage).
+void ImGui_ImplWGPU_SetReadbackTexture(WGPUTexture texture, WGPUTextureFormat format, int width, int height)
+{
+    g_readbackTexture = texture;
+    g_readbackFormat = format;
+    g_readbackWidth = width;
+    g_readbackHeight = height;
+}
+
+static void ImGui_ImplWGPU_ReadbackMapCallback(WGPUBufferMapAsyncStatus status, void* user_data)
+{
+    *(WGPUBufferMapAsyncStatus*)user_data = status;
+}
+
+// Read back a rectangle of the texture registered with ImGui_ImplWGPU_SetReadbackTexture(), used by CaptureScreenshot(). (x,y) is the top-left corner in framebuffer pixels.
+// This blocks until the GPU is done, it is meant for tests and tools, not for every frame. Only the main viewport is supported.
+static bool ImGui_ImplWGPU_ReadPixels(ImGuiContext* ctx, ImGuiID viewport_id, int x, int y, int w, int h, RgbaImage* out_image)
+{
+    IM_UNUSED(ctx);
+    if (viewport_id != Imgui::GetMainViewport().ID || g_readbackTexture == NULL)
+        return false;
+    if (x < 0 || y < 0 || w <= 0 || h <= 0 || x + w > g_readbackWidth || y + h > g_readbackHeight)
+        return false;
+    bool swap_red_blue;
+    switch (g_readbackFormat)
+    {
+    case WGPUTextureFormat_RGBA8Unorm: case WGPUTextureFormat_RGBA8UnormSrgb: swap_red_blue = false; break;
+    case WGPUTextureFormat_BGRA8Unorm: case WGPUTextureFormat_BGRA8UnormSrgb: swap_red_blue = true; break;
+    default: return false; // Other formats would need a conversion
+    }
+
+    // Rows of a texture to buffer copy must be aligned to 256 bytes
+    const uint32_t bytes_per_row = (uint32_t)(((w * 4) + 255) & ~255);
+    WGPUBufferDescriptor buffer_desc = {};
+    buffer_desc.label = "Dear ImGui Readback Buffer";
+    buffer_desc.usage = WGPUBufferUsage_CopyDst | WGPUBufferUsage_MapRead;
+    buffer_desc.size = bytes_per_row * h;
+    WGPUBuffer buffer = wgpuDeviceCreateBuffer(g_wgpuDevice, &buffer_desc);
+
+    WGPUCommandEncoder encoder = wgpuDeviceCreateCommandEncoder(g_wgpuDevice, NULL);
+    WGPUImageCopyTexture src = {};
+    src.texture = g_readbackTexture;
+    src.mipLevel = 0;
+    src.origin = { (uint32_t)x, (uint32_t)y, 0 };
+    src.aspect = WGPUTextureAspect_All;
+    WGPUImageCopyBuffer dst = {};
+    dst.buffer = buffer;
+    dst.layout.offset = 0;
+    dst.layout.bytesPerRow = bytes_per_row;
+    dst.layout.rowsPerImage = h;
+    WGPUExtent3D size = { (uint32_t)w, (uint32_t)h, 1 };
+    wgpuCommandEncoderCopyTextureToBuffer(encoder, &src, &dst, &size);
+    WGPUCommandBuffer commands = wgpuCommandEncoderFinish(encoder, NULL);
+    wgpuQueueSubmit(g_defaultQueue, 1, &commands);
+    wgpuCommandBufferRelease(commands);
+    wgpuCommandEncoderRelease(encoder);
+
+    // Wait for the mapping (wgpuDevicePoll() is wgpu-native, Dawn and Emscripten need their own event processing here)
+    WGPUBufferMapAsyncStatus map_status = WGPUBufferMapAsyncStatus_Unknown;
+    wgpuBufferMapAsync(buffer, WGPUMapMode_Read, 0, buffer_desc.size, ImGui_ImplWGPU_ReadbackMapCallback, &map_status);
+    while (map_status == WGPUBufferMapAsyncStatus_Unknown)
+        wgpuDevicePoll(g_wgpuDevice, true, NULL);
+
+    bool ok = (map_status == WGPUBufferMapAsyncStatus_Success);
+    if (ok)
+    {
+        const unsigned char* src_pixels = (const unsigned char*)wgpuBufferGetConstMappedRange(buffer, 0, buffer_desc.size);
+        *out_image = RgbaImage::new(w, h);
+        for (int row = 0; row < h; row++)
+            memcpy(out_image->Pixels.as_mut_ptr() + row * w * 4, src_pixels + row * bytes_per_row, w * 4);
+        wgpuBufferUnmap(buffer);
+        if (swap_red_blue)
+            for (int i = 0; i < w * h * 4; i += 4)
+                ImSwap(out_image->Pixels[i], out_image->Pixels[i + 2]);
+    }
+    wgpuBufferDestroy(buffer);
+    wgpuBufferRelease(buffer);
+    return ok;
+}
+
 static void ImGui_ImplWGPU_CreateFontsTexture()
 {
     // Build texture atlas
@@ -740,6 +827,7 @@ bool ImGui_ImplWGPU_Init(WGPUDevice device, int num_frames_in_flight, WGPUTextur
         fr.IndexBufferSize = 10000;
         fr.VertexBufferSize = 5000;
     }
+    Imgui::GetPlatformIO().Renderer_ReadPixels = ImGui_ImplWGPU_ReadPixels;
 
     return true;
 }
@@ -750,6 +838,8 @@ void ImGui_ImplWGPU_Shutdown()
     delete[] g_pFrameResources;
     g_pFrameResources = NULL;
     wgpuQueueRelease(g_defaultQueue);
+    Imgui::GetPlatformIO().Renderer_ReadPixels = NULL;
+    g_readbackTexture = NULL;
     g_wgpuDevice = NULL;
     g_numFramesInFlight = 0;
     g_frameIndex = UINT_MAX;
@@ -41,6 +41,19 @@ use crate::widgets::scroll_flags::{ImGuiScrollFlags, ImGuiScrollFlags_None};
 use crate::core::settings_handler::SettingsHandler;
 use crate::widgets::shrink_width_item::ImGuiShrinkWidthItem;
 use crate::core::stack_tool::ImGuiStackTool;
+use crate::debugging::screenshot_ops::ImGuiScreenshotState;
+use crate::test_engine::test_engine::ImGuiTestEngine;
+use crate::core::settings_section::ImGuiSettingsSections;
+use crate::io::key::ImGuiKey_NamedKey_COUNT;
+use crate::widgets::multi_select::{ImGuiMultiSelectState, ImGuiMultiSelectTempData};
+use crate::widgets::box_select::ImGuiBoxSelectState;
+use crate::widgets::undo_context::ImGuiUndoContext;
+use crate::widgets::plot::{ImGuiNextPlotData, ImGuiPlot};
+use crate::widgets::node_editor::{ImGuiNextNodeEditorData, ImGuiNodeEditor};
+use crate::widgets::curve::ImGuiCurveEditorState;
+use crate::widgets::gradient::ImGuiGradientEditorState;
+use crate::widgets::sequencer::ImGuiSequencer;
+use crate::io::key_routing::{ImGuiKeyOwnerData, ImGuiKeyRoutingTable};
 use crate::core::storage::ImGuiStorage;
 use crate::style::ImguiStyle;
 use crate::style_mod::ImGuiStyleMod;
@@ -79,6 +92,7 @@ use crate::widgets::nav_item_data::ImGuiNavItemData;
 use crate::widgets::nav_layer::{ImGuiNavLayer, ImGuiNavLayer_Main};
 use crate::widgets::nav_move_flags::{ImGuiNavMoveFlags, ImGuiNavMoveFlags_None};
 use crate::widgets::popup_data::ImGuiPopupData;
+use crate::window::input_text_diagnostics::ImGuiInputTextDiagnosticsCache;
 use crate::window::input_text_state::ImGuiInputTextState;
 
 #[derive(Default, Debug, Clone)]
@@ -120,8 +134,8 @@ pub struct AppContext {
     pub GcCompactAll: bool,
     // Will call test engine hooks
     pub TestEngineHookItems: bool,
-    // Test engine user data
-    pub TestEngine: Vec<u8>,
+    // Test engine state: items gathered by the ItemAdd()/ItemInfo() hooks (see test_engine module)
+    pub TestEngine: ImGuiTestEngine,
     // Windows, sorted in display order, back to front
     pub windows: HashMap<ImguiHandle, ImguiWindow>,
     // Root windows, sorted in focus order, back to front.
@@ -210,6 +224,13 @@ pub struct AppContext {
     // we'll need to either to order the enum to make useful keys come first, either
     // redesign this into e.g. a small array.
     pub ActiveIdUsingKeyInputMask: ImBitArrayForNamedKeys,
+    // Key/Input Ownership + Shortcut Routing system
+    // - The idea is that instead of "eating" a given key, we can link to an owner.
+    // - Input query can then read input by specifying ImGuiKeyOwner_Any (== 0), ImGuiKeyOwner_None (== -1) or a custom ID.
+    // - Routing is requested ahead of time for a given chord (Key + Mods) and granted in NewFrame().
+    // ImGuiKeyOwnerData       KeysOwnerData[ImGuiKey_NamedKey_COUNT];
+    pub KeysOwnerData: Vec<ImGuiKeyOwnerData>,
+    pub KeysRoutingTable: ImGuiKeyRoutingTable,
     pub CurrentItemFlags: ImGuiItemFlags,
     // Storage for SetNextItem** functions
     pub next_item_data: ImGuiNextItemData,
@@ -405,6 +426,45 @@ pub struct AppContext {
     pub TabBars: HashMap<ImguiHandle, ImGuiTabBar>,
     pub CurrentTabBarStack: Vec<ImguiHandle>,
     pub ShrunkWidthBuffer: Vec<ImGuiShrinkWidthItem>,
+    // Multi-Select state
+    // Set between BeginMultiSelect() and EndMultiSelect() (nesting is not supported)
+    pub CurrentMultiSelect: Option<ImGuiMultiSelectTempData>,
+    // Persistent state (range source, focused item) per BeginMultiSelect() scope ID
+    pub MultiSelectStorage: HashMap<ImguiHandle, ImGuiMultiSelectState>,
+    // Only one box-select can be active at a time
+    pub BoxSelectState: ImGuiBoxSelectState,
+    // Undo contexts, pushed by PushUndoContext(). Value widgets record their edits into the last one.
+    pub UndoContextStack: Vec<*mut ImGuiUndoContext>,
+    // Nesting count of PushUndoTrackingDisabled(), used by composite widgets
+    pub UndoTrackingDisabled: i32,
+    // Plots
+    // Set between BeginPlot() and EndPlot() (nesting is not supported), moved back to Plots by EndPlot()
+    pub CurrentPlot: Option<ImGuiPlot>,
+    // Persistent state (axis ranges, hidden series) per BeginPlot() ID
+    pub Plots: HashMap<ImguiHandle, ImGuiPlot>,
+    // Set by SetNextPlotAxis()/SetNextPlotAxisLimits(), consumed by BeginPlot()
+    pub NextPlotData: ImGuiNextPlotData,
+    // Node editors
+    // Set between BeginNodeEditor() and EndNodeEditor() (nesting is not supported), moved back to NodeEditors by EndNodeEditor()
+    pub CurrentNodeEditor: Option<ImGuiNodeEditor>,
+    // Persistent state (view, node positions, selection) per BeginNodeEditor() ID
+    pub NodeEditors: HashMap<ImguiHandle, ImGuiNodeEditor>,
+    // Last editor ended by EndNodeEditor(), for IsNodeLinkCreated() and other queries
+    pub NodeEditorLastID: ImguiHandle,
+    // Set by SetNextNodeEditorState(), consumed by BeginNodeEditor()
+    pub NextNodeEditorData: ImGuiNextNodeEditorData,
+    // Curve and gradient editors
+    // Persistent state (view, selection, drag) per CurveEditor() ID
+    pub CurveEditors: HashMap<ImguiHandle, ImGuiCurveEditorState>,
+    // Persistent state (selection, drag) per GradientEditor() ID
+    pub GradientEditors: HashMap<ImguiHandle, ImGuiGradientEditorState>,
+    // Sequencers
+    // Set between BeginSequencer() and EndSequencer() (nesting is not supported), moved back to Sequencers by EndSequencer()
+    pub CurrentSequencer: Option<ImGuiSequencer>,
+    // Persistent state (zoom, clip being dragged) per BeginSequencer() ID
+    pub Sequencers: HashMap<ImguiHandle, ImGuiSequencer>,
+    // Last sequencer ended by EndSequencer(), for GetSequencerEvents()
+    pub SequencerLastID: ImguiHandle,
     // Hover Delay system
     pub HoverDelayId: ImguiHandle,
     pub HoverDelayIdPreviousFrame: ImguiHandle,
@@ -416,6 +476,8 @@ pub struct AppContext {
     // Widget state
     pub MouseLastValidPos: Vector2,
     pub InputTextState: ImGuiInputTextState,
+    // Cached ImGuiInputTextFlags_CallbackDiagnostics results per InputText() ID
+    pub InputTextDiagnostics: HashMap<ImguiHandle, ImGuiInputTextDiagnosticsCache>,
     pub InputTextPasswordFont: ImFont,
     // Temporary text input when CTRL+clicking on a slider, etc.
     pub TempInputId: ImguiHandle,
@@ -476,8 +538,12 @@ pub struct AppContext {
     pub SettingsWindow: Vec<ImGuiWindowSettings>,
     // ImGuiTable .ini settings entries
     pub SettingsTables: Vec<ImGuiTableSettings>,
+    // Typed user sections (see AddSettingsSection())
+    pub SettingsSections: ImGuiSettingsSections,
     // Hooks for extensions (e.g. test engine)
     pub hooks: HashMap<ImguiHandle, ImGuiContextHook>,
+    // Next available HookId
+    pub HookIdNext: ImguiHandle,
     // Currently capturing
     pub LogEnabled: bool,
     // Capture target
@@ -508,6 +574,8 @@ pub struct AppContext {
     pub DebugStackTool: ImGuiStackTool,
     // Hovered dock node.
     pub DebugHoveredDockNode: Option<ImGuiDockNode>,
+    // Pending/last screenshot capture (see RequestScreenshot())
+    pub Screenshot: ImGuiScreenshotState,
     // Misc
     // Calculate estimate of framerate for user over the last 60 frames..
     pub FramerateSecPerFrame: [c_float; 60],
@@ -547,7 +615,7 @@ impl AppContext {
             WithinEndChild: false,
             GcCompactAll: false,
             TestEngineHookItems: false,
-            TestEngine: vec![],
+            TestEngine: ImGuiTestEngine::default(),
             WindowsActiveCount: 0,
             CurrentWindow: ImguiWindow::default(),
             HoveredWindow: ImguiWindow::default(),
@@ -714,6 +782,7 @@ impl AppContext {
             None() => ImFontAtlas::default(),
         });
         out.ActiveIdUsingKeyInputMask.ClearAllBits();
+        out.KeysOwnerData = vec![ImGuiKeyOwnerData::default(); ImGuiKey_NamedKey_COUNT as usize];
         out.PlatformImeData.InputPos = Vector2::default();
         out.PlatformImeDataPrev.InputPos = Vector2::from_floats(-1.0, -1.0); // Different to ensure initial submission
                                                                             // libc::memset(
@@ -754,9 +823,12 @@ impl AppContext {
     }
 
     pub fn add_context_hook(&mut self, hook: &ImGuiContextHook) -> ImguiHandle {
-        self.hooks.push(hook.clone());
+        // IM_ASSERT(hook->Callback != NULL && hook->HookId == 0 && hook->Type != ImGuiContextHookType_PendingRemoval_);
         self.HookIdNext += 1;
-        self.H
+        let mut hook = hook.clone();
+        hook.HookId = self.HookIdNext;
+        self.hooks.insert(hook.HookId, hook);
+        self.HookIdNext
     }
 
     pub fn remove_context_hook(&mut self, hook_id: &ImguiHandle) {
@@ -8,7 +8,7 @@ use std::hash::{Hash, Hasher};
 // Known size hash
 // It is ok to call ImHashData on a string with known length but the ### operator won't be supported.
 // ImguiHandle ImHashData(const void* data_p, data_size: size_t, seed: u32)
-pub fn hash_data(data_p: &[u8], seed: u32) -> ImguiHandle {
+pub fn hash_data(data_p: &[u8], seed: ImguiHandle) -> ImguiHandle {
     // // crc: u32 = ~seed;
     // let mut crc = !seed;
     // let mut data = data_p;
@@ -20,6 +20,7 @@ pub fn hash_data(data_p: &[u8], seed: u32) -> ImguiHandle {
     // }
     // return !crc as ImguiHandle;
     let mut s = DefaultHasher::new();
+    seed.hash(&mut s);
     data_p.hash(&mut s);
     s.finish() as ImguiHandle
 }
@@ -30,9 +31,14 @@ pub fn hash_data(data_p: &[u8], seed: u32) -> ImguiHandle {
 // - If we reach ### in the string we discard the hash so far and reset to the seed.
 // - We don't do 'current += 2; continue;' after handling ### to keep the code smaller/faster (measured ~10% diff in Debug build)
 // ImguiHandle ImHashStr(const char* data_p, data_size: size_t, seed: u32)
-pub fn hash_string(data_p: &String, mut seed: u32) -> ImguiHandle {
+pub fn hash_string(data_p: &str, seed: ImguiHandle) -> ImguiHandle {
+    let data = match data_p.rfind("###") {
+        Some(n) => &data_p[n..],
+        None => data_p,
+    };
     let mut s = DefaultHasher::new();
-    data_p.hash(&mut s);
+    seed.hash(&mut s);
+    data.hash(&mut s);
     s.finish() as ImguiHandle
     // seed = !seed;
     // let mut crc = seed;
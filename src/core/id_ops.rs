@@ -129,7 +129,7 @@ pub fn PushOverrideID(g: &mut AppContext, id: ImguiHandle) {
 // (note that when using this pattern, TestEngine's "Stack Tool" will tend to not display the intermediate stack level.
 //  for that to work we would need to do PushOverrideID() -> ItemAdd() -> PopID() which would alter widget code a little more)
 pub fn GetIDWithSeed(arg: &str, seed: ImguiHandle) -> ImguiHandle {
-    let mut id: ImguiHandle = hash_string(arg, seed as u32);
+    let mut id: ImguiHandle = hash_string(arg, seed);
     let g = GImGui; // ImGuiContext& g = *GImGui; if (g.DebugHookIdInfo == id)
     DebugHookIdInfo(, id, IM_GUI_DATA_TYPE_STRING, str);
     return id;
@@ -214,6 +214,29 @@ pub fn ImUpperPowerOfTwo(mut v: i32) -> i32 {
     return v;
 }
 
+// Days since 1970-01-01 of a proleptic Gregorian date
+pub fn ImDaysFromCivil(y: i64, m: i64, d: i64) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let doy = (153 * ((m + 9) % 12) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+// (year, month, day) of a number of days since 1970-01-01, inverse of ImDaysFromCivil()
+pub fn ImCivilFromDays(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = (if z >= 0 { z } else { z - 146096 }) / 146097;
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    (yoe + era * 400 + if m <= 2 { 1 } else { 0 }, m, d)
+}
+
 pub fn char_is_blank(c: char) -> bool {
     return c == ' ' || c == '\t' || c == 0x3000;
 }
@@ -20,8 +20,10 @@ pub mod imvec1;
 mod log_type;
 pub mod math_ops;
 mod metrics_config;
+pub mod settings_document;
 pub mod settings_handler;
 pub mod settings_ops;
+pub mod settings_section;
 pub mod stack_level_info;
 pub mod stack_sizes;
 pub mod stack_tool;
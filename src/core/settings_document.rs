@@ -0,0 +1,916 @@
+#![allow(non_snake_case)]
+
+//-----------------------------------------------------------------------------
+// [SECTION] SETTINGS DOCUMENT
+//-----------------------------------------------------------------------------
+// - ImGuiSettingsFormat
+// - SettingsDocument::Parse(), SettingsDocument::Write()
+// - SettingsDocument::FromIni(), SettingsDocument::ToIni()
+// - WindowSettingsEntry, TableSettingsEntry, DockNodeSettingsEntry
+// - DockLayoutSettingsEntry
+// - UserSections (see settings_section.rs)
+// - ConvertSettingsFile()
+//-----------------------------------------------------------------------------
+// Structured view of everything stored in the settings file. Each settings handler fills its own typed section,
+// then the whole document is written as RON, JSON or legacy .ini.
+// FromIni() is a lossless importer: lines that are not understood are kept in 'Extra', sections of unknown types
+// are kept verbatim in 'Sections', and the order of lines and sections is recorded in 'IniOrder' fields, so
+// ini -> ron/json -> ini doesn't drop or reorder anything.
+//-----------------------------------------------------------------------------
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use libc::{c_char, c_float, c_short};
+use serde::{Deserialize, Serialize};
+use crate::core::axis::{IM_GUI_AXIS_NONE, IM_GUI_AXIS_X, IM_GUI_AXIS_Y};
+use crate::core::hash_ops::hash_string;
+use crate::core::type_defs::{ImGuiTableColumnIdx, ImguiHandle};
+use crate::core::utils::flag_set;
+use crate::core::vec2::ImVec2ih;
+use crate::docking::dock_node_flags::{ImGuiDockNodeFlags_CentralNode, ImGuiDockNodeFlags_DockSpace, ImGuiDockNodeFlags_HiddenTabBar, ImGuiDockNodeFlags_NoCloseButton, ImGuiDockNodeFlags_NoResize, ImGuiDockNodeFlags_NoTabBar, ImGuiDockNodeFlags_NoWindowMenuButton};
+use crate::docking::dock_node_settings::ImGuiDockNodeSettings;
+use crate::layout::sort_direction::{ImGuiSortDirection_Ascending, ImGuiSortDirection_Descending};
+use crate::table::table_column_settings::ImGuiTableColumnSettings;
+use crate::table::table_flags::{ImGuiTableFlags_Hideable, ImGuiTableFlags_Reorderable, ImGuiTableFlags_Resizable, ImGuiTableFlags_Sortable};
+use crate::table::table_settings::ImGuiTableSettings;
+use crate::viewport::IMGUI_VIEWPORT_DEFAULT_ID;
+use crate::window::window_settings::ImGuiWindowSettings;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImGuiSettingsFormat {
+    // Legacy "[Type][Name]" + "Key=Value" lines
+    Ini,
+    Ron,
+    Json,
+}
+
+impl ImGuiSettingsFormat {
+    // Pick the format from the file extension: ".ron", ".json", anything else is .ini
+    pub fn FromFilename(filename: &str) -> Self {
+        match Path::new(filename).extension().and_then(|ext| ext.to_str()).map(|ext| ext.to_ascii_lowercase()) {
+            Some(ext) if ext == "ron" => ImGuiSettingsFormat::Ron,
+            Some(ext) if ext == "json" => ImGuiSettingsFormat::Json,
+            _ => ImGuiSettingsFormat::Ini,
+        }
+    }
+}
+
+// IDs are written as "0x%08X" strings in every format so they stay readable and diffable
+mod settings_hex_id {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use crate::core::type_defs::ImguiHandle;
+
+    pub fn serialize<S: Serializer>(id: &ImguiHandle, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{:08X}", id))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ImguiHandle, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        super::ParseHexId(&s).ok_or_else(|| serde::de::Error::custom(format!("invalid ID \"{}\", expected 0x followed by hex digits", s)))
+    }
+}
+
+fn ParseHexId(s: &str) -> Option<ImguiHandle> {
+    let digits = s.trim().strip_prefix("0x").or_else(|| s.trim().strip_prefix("0X"))?;
+    ImguiHandle::from_str_radix(digits, 16).ok()
+}
+
+fn ParseVec2ih(s: &str) -> Option<[c_short; 2]> {
+    let (x, y) = s.split_once(',')?;
+    Some([x.trim().parse().ok()?, y.trim().parse().ok()?])
+}
+
+fn IsZeroId(id: &ImguiHandle) -> bool {
+    *id == 0
+}
+
+fn IsZeroVec2ih(v: &[c_short; 2]) -> bool {
+    v[0] == 0 && v[1] == 0
+}
+
+fn IsFalse(b: &bool) -> bool {
+    !*b
+}
+
+fn IsNoDockOrder(order: &c_short) -> bool {
+    *order == -1
+}
+
+// Write (key, line) pairs and unknown lines following the order recorded at import, where "" stands for the next
+// line of 'extra'. Lines which were not imported (new keys, entries created at runtime) follow in their default order.
+fn WriteIniLinesInOrder(order: &[String], lines: Vec<(String, String)>, extra: &[String], buf: &mut String) {
+    let mut lines: Vec<Option<(String, String)>> = lines.into_iter().map(Some).collect();
+    let mut extra_it = extra.iter();
+    for key in order {
+        let line = if key.is_empty() {
+            extra_it.next().cloned()
+        } else {
+            lines.iter_mut().find(|line| line.as_ref().map_or(false, |(line_key, _)| line_key == key)).and_then(|line| line.take()).map(|(_, line)| line)
+        };
+        if let Some(line) = line {
+            buf.push_str(&line);
+            buf.push('\n');
+        }
+    }
+    for line in lines.into_iter().flatten().map(|(_, line)| line).chain(extra_it.cloned()) {
+        buf.push_str(&line);
+        buf.push('\n');
+    }
+}
+
+//-----------------------------------------------------------------------------
+// Typed entries
+//-----------------------------------------------------------------------------
+
+// [Window][Name]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WindowSettingsEntry {
+    pub Name: String,
+    // Relative to the viewport. None when not saved, an explicit "Pos=0,0" is kept.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Pos: Option<[c_short; 2]>,
+    #[serde(skip_serializing_if = "IsZeroVec2ih")]
+    pub Size: [c_short; 2],
+    #[serde(with = "settings_hex_id", skip_serializing_if = "IsZeroId")]
+    pub ViewportId: ImguiHandle,
+    #[serde(skip_serializing_if = "IsZeroVec2ih")]
+    pub ViewportPos: [c_short; 2],
+    pub Collapsed: bool,
+    #[serde(with = "settings_hex_id", skip_serializing_if = "IsZeroId")]
+    pub DockId: ImguiHandle,
+    #[serde(skip_serializing_if = "IsNoDockOrder")]
+    pub DockOrder: c_short,
+    #[serde(with = "settings_hex_id", skip_serializing_if = "IsZeroId")]
+    pub ClassId: ImguiHandle,
+    // .ini lines which were not understood, written back as-is
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub Extra: Vec<String>,
+    // Keys in the order they were read, "" standing for the next line of 'Extra' (see WriteIniLinesInOrder())
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub IniOrder: Vec<String>,
+}
+
+impl Default for WindowSettingsEntry {
+    fn default() -> Self {
+        Self {
+            Name: String::new(),
+            Pos: None,
+            Size: [0, 0],
+            ViewportId: 0,
+            ViewportPos: [0, 0],
+            Collapsed: false,
+            DockId: 0,
+            DockOrder: -1,
+            ClassId: 0,
+            Extra: vec![],
+            IniOrder: vec![],
+        }
+    }
+}
+
+impl WindowSettingsEntry {
+    pub fn FromSettings(settings: &ImGuiWindowSettings) -> Self {
+        Self {
+            Name: settings.Name.clone(),
+            // Same rule as the legacy writer: a zero position is only saved for windows of the main viewport
+            Pos: if settings.Pos.x != 0 || settings.Pos.y != 0 || settings.ViewportId == IMGUI_VIEWPORT_DEFAULT_ID { Some([settings.Pos.x, settings.Pos.y]) } else { None },
+            Size: [settings.Size.x, settings.Size.y],
+            ViewportId: settings.ViewportId,
+            ViewportPos: [settings.ViewportPos.x, settings.ViewportPos.y],
+            Collapsed: settings.Collapsed,
+            DockId: settings.DockId,
+            DockOrder: settings.DockOrder,
+            ClassId: settings.ClassId,
+            Extra: vec![],
+            IniOrder: vec![],
+        }
+    }
+
+    pub fn ToSettings(&self) -> ImGuiWindowSettings {
+        let mut settings = ImGuiWindowSettings::new();
+        settings.ID = hash_string(&self.Name, 0);
+        settings.Name = self.Name.clone();
+        let pos = self.Pos.unwrap_or([0, 0]);
+        settings.Pos = ImVec2ih::new2(pos[0], pos[1]);
+        settings.Size = ImVec2ih::new2(self.Size[0], self.Size[1]);
+        settings.ViewportId = self.ViewportId;
+        settings.ViewportPos = ImVec2ih::new2(self.ViewportPos[0], self.ViewportPos[1]);
+        settings.Collapsed = self.Collapsed;
+        settings.DockId = self.DockId;
+        settings.DockOrder = self.DockOrder;
+        settings.ClassId = self.ClassId;
+        settings
+    }
+
+    fn ReadIniLine(&mut self, line: &str) {
+        let parsed = match line.split_once('=') {
+            Some(("Pos", v)) => ParseVec2ih(v).map(|v| self.Pos = Some(v)),
+            Some(("Size", v)) => ParseVec2ih(v).map(|v| self.Size = v),
+            Some(("ViewportId", v)) => ParseHexId(v).map(|v| self.ViewportId = v),
+            Some(("ViewportPos", v)) => ParseVec2ih(v).map(|v| self.ViewportPos = v),
+            Some(("Collapsed", v)) => v.trim().parse::<i32>().ok().map(|v| self.Collapsed = v != 0),
+            Some(("DockId", v)) => match v.split_once(',') {
+                Some((id, order)) => ParseHexId(id).zip(order.trim().parse().ok()).map(|(id, order)| {
+                    self.DockId = id;
+                    self.DockOrder = order;
+                }),
+                None => ParseHexId(v).map(|id| {
+                    self.DockId = id;
+                    self.DockOrder = -1;
+                }),
+            },
+            Some(("ClassId", v)) => ParseHexId(v).map(|v| self.ClassId = v),
+            _ => None,
+        };
+        match parsed {
+            Some(()) => self.IniOrder.push(line.split_once('=').map_or("", |(key, _)| key).to_string()),
+            None => {
+                self.Extra.push(line.to_string());
+                self.IniOrder.push(String::new());
+            }
+        }
+    }
+
+    fn WriteIni(&self, type_name: &str, buf: &mut String) {
+        // Keys which were read are written back even when they hold a default value
+        let was_read = |key: &str| self.IniOrder.iter().any(|read_key| read_key == key);
+        let mut lines: Vec<(String, String)> = vec![];
+        if (self.ViewportId != 0 && self.ViewportId != IMGUI_VIEWPORT_DEFAULT_ID) || was_read("ViewportPos") {
+            lines.push(("ViewportPos".to_string(), format!("ViewportPos={},{}", self.ViewportPos[0], self.ViewportPos[1])));
+        }
+        if (self.ViewportId != 0 && self.ViewportId != IMGUI_VIEWPORT_DEFAULT_ID) || was_read("ViewportId") {
+            lines.push(("ViewportId".to_string(), format!("ViewportId=0x{:08X}", self.ViewportId)));
+        }
+        if let Some(pos) = self.Pos {
+            lines.push(("Pos".to_string(), format!("Pos={},{}", pos[0], pos[1])));
+        }
+        if !IsZeroVec2ih(&self.Size) || was_read("Size") {
+            lines.push(("Size".to_string(), format!("Size={},{}", self.Size[0], self.Size[1])));
+        }
+        lines.push(("Collapsed".to_string(), format!("Collapsed={}", self.Collapsed as i32)));
+        if self.DockId != 0 || was_read("DockId") {
+            if self.DockOrder == -1 {
+                lines.push(("DockId".to_string(), format!("DockId=0x{:08X}", self.DockId)));
+            } else {
+                lines.push(("DockId".to_string(), format!("DockId=0x{:08X},{}", self.DockId, self.DockOrder)));
+            }
+        }
+        if self.ClassId != 0 || was_read("ClassId") {
+            lines.push(("ClassId".to_string(), format!("ClassId=0x{:08X}", self.ClassId)));
+        }
+        buf.push_str(&format!("[{}][{}]\n", type_name, self.Name));
+        WriteIniLinesInOrder(&self.IniOrder, lines, &self.Extra, buf);
+        buf.push('\n');
+    }
+}
+
+// "Column 0  UserID=0x42AD2D21 Width=100 Visible=1 Order=0 Sort=0v"
+// Fields which are None were not saved (see ImGuiTableSettings::SaveFlags)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableColumnSettingsEntry {
+    pub Index: ImGuiTableColumnIdx,
+    #[serde(with = "settings_hex_id", skip_serializing_if = "IsZeroId")]
+    pub UserID: ImguiHandle,
+    // Fixed width in pixels
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Width: Option<c_float>,
+    // Stretch weight
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Weight: Option<c_float>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Visible: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Order: Option<ImGuiTableColumnIdx>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub SortOrder: Option<ImGuiTableColumnIdx>,
+    #[serde(skip_serializing_if = "IsFalse")]
+    pub SortDescending: bool,
+}
+
+// [Table][0x%08X,%d]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TableSettingsEntry {
+    #[serde(with = "settings_hex_id")]
+    pub ID: ImguiHandle,
+    pub ColumnsCount: ImGuiTableColumnIdx,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub RefScale: Option<c_float>,
+    pub Columns: Vec<TableColumnSettingsEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub Extra: Vec<String>,
+    // "RefScale", "Column <n>" or "" for the next line of 'Extra', in the order they were read
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub IniOrder: Vec<String>,
+}
+
+impl TableSettingsEntry {
+    // Returns None when there is nothing worth saving for this table
+    pub fn FromSettings(settings: &ImGuiTableSettings) -> Option<Self> {
+        if settings.ID == 0 {
+            return None;
+        }
+        let save_size = flag_set(settings.SaveFlags, ImGuiTableFlags_Resizable);
+        let save_visible = flag_set(settings.SaveFlags, ImGuiTableFlags_Hideable);
+        let save_order = flag_set(settings.SaveFlags, ImGuiTableFlags_Reorderable);
+        let save_sort = flag_set(settings.SaveFlags, ImGuiTableFlags_Sortable);
+        if !save_size && !save_visible && !save_order && !save_sort {
+            return None;
+        }
+
+        let mut entry = Self {
+            ID: settings.ID,
+            ColumnsCount: settings.ColumnsCount,
+            RefScale: if settings.RefScale != 0.0 { Some(settings.RefScale) } else { None },
+            ..Default::default()
+        };
+        for (column_n, column) in settings.Columns.iter().take(settings.ColumnsCount as usize).enumerate() {
+            let save_column = column.UserID != 0 || save_size || save_visible || save_order || (save_sort && column.SortOrder != -1);
+            if !save_column {
+                continue;
+            }
+            entry.Columns.push(TableColumnSettingsEntry {
+                Index: column_n as ImGuiTableColumnIdx,
+                UserID: column.UserID,
+                Width: if save_size && !column.IsStretch { Some(column.WidthOrWeight) } else { None },
+                Weight: if save_size && column.IsStretch { Some(column.WidthOrWeight) } else { None },
+                Visible: if save_visible { Some(column.IsEnabled) } else { None },
+                Order: if save_order { Some(column.DisplayOrder) } else { None },
+                SortOrder: if save_sort && column.SortOrder != -1 { Some(column.SortOrder) } else { None },
+                SortDescending: save_sort && column.SortOrder != -1 && column.SortDirection == ImGuiSortDirection_Descending,
+            });
+        }
+        Some(entry)
+    }
+
+    // Write into recycled or new settings storage (see TableSettingsHandler_ReadDocument())
+    pub fn ApplyToSettings(&self, settings: &mut ImGuiTableSettings) {
+        settings.ID = self.ID;
+        settings.ColumnsCount = self.ColumnsCount;
+        settings.RefScale = self.RefScale.unwrap_or(0.0);
+        settings.SaveFlags = 0;
+        settings.WantApply = true;
+        // Same as TableSettingsInit(): every column slot is reset with its own index
+        settings.ColumnsCountMax = settings.ColumnsCountMax.max(self.ColumnsCount);
+        settings.Columns = (0..settings.ColumnsCountMax).map(|column_n| ImGuiTableColumnSettings { Index: column_n, ..ImGuiTableColumnSettings::new() }).collect();
+        for column in &self.Columns {
+            if column.Index < 0 || column.Index >= self.ColumnsCount {
+                continue;
+            }
+            let dst = &mut settings.Columns[column.Index as usize];
+            dst.Index = column.Index;
+            dst.UserID = column.UserID;
+            if let Some(width) = column.Width {
+                dst.WidthOrWeight = width;
+                dst.IsStretch = false;
+                settings.SaveFlags |= ImGuiTableFlags_Resizable;
+            }
+            if let Some(weight) = column.Weight {
+                dst.WidthOrWeight = weight;
+                dst.IsStretch = true;
+                settings.SaveFlags |= ImGuiTableFlags_Resizable;
+            }
+            if let Some(visible) = column.Visible {
+                dst.IsEnabled = visible;
+                settings.SaveFlags |= ImGuiTableFlags_Hideable;
+            }
+            if let Some(order) = column.Order {
+                dst.DisplayOrder = order;
+                settings.SaveFlags |= ImGuiTableFlags_Reorderable;
+            }
+            if let Some(sort_order) = column.SortOrder {
+                dst.SortOrder = sort_order;
+                dst.SortDirection = if column.SortDescending { ImGuiSortDirection_Descending } else { ImGuiSortDirection_Ascending };
+                settings.SaveFlags |= ImGuiTableFlags_Sortable;
+            }
+        }
+    }
+
+    fn ReadIniLine(&mut self, line: &str) {
+        if let Some(v) = line.strip_prefix("RefScale=") {
+            if let Ok(v) = v.trim().parse() {
+                self.RefScale = Some(v);
+                self.IniOrder.push("RefScale".to_string());
+                return;
+            }
+        }
+        if let Some(column) = line.strip_prefix("Column ").and_then(|rest| self.ParseColumnLine(rest)) {
+            self.IniOrder.push(format!("Column {}", column.Index));
+            self.Columns.push(column);
+            return;
+        }
+        self.Extra.push(line.to_string());
+        self.IniOrder.push(String::new());
+    }
+
+    fn ParseColumnLine(&self, rest: &str) -> Option<TableColumnSettingsEntry> {
+        let mut tokens = rest.split_whitespace();
+        let index: ImGuiTableColumnIdx = tokens.next()?.parse().ok()?;
+        if index < 0 || index >= self.ColumnsCount {
+            return None;
+        }
+        let mut column = TableColumnSettingsEntry { Index: index, ..Default::default() };
+        for token in tokens {
+            match token.split_once('=')? {
+                ("UserID", v) => column.UserID = ParseHexId(v)?,
+                ("Width", v) => column.Width = Some(v.parse::<i32>().ok()? as c_float),
+                ("Weight", v) => column.Weight = Some(v.parse().ok()?),
+                ("Visible", v) => column.Visible = Some(v.parse::<i32>().ok()? != 0),
+                ("Order", v) => column.Order = Some(v.parse().ok()?),
+                ("Sort", v) => {
+                    let (order, dir) = v.split_at(v.len().checked_sub(1)?);
+                    column.SortOrder = Some(order.parse().ok()?);
+                    column.SortDescending = dir == "^";
+                }
+                _ => return None,
+            }
+        }
+        Some(column)
+    }
+
+    fn WriteIni(&self, type_name: &str, buf: &mut String) {
+        let mut lines: Vec<(String, String)> = vec![];
+        if let Some(ref_scale) = self.RefScale {
+            lines.push(("RefScale".to_string(), format!("RefScale={}", ref_scale)));
+        }
+        for column in &self.Columns {
+            let mut line = format!("Column {:<2}", column.Index);
+            if column.UserID != 0 {
+                line.push_str(&format!(" UserID=0x{:08X}", column.UserID));
+            }
+            if let Some(weight) = column.Weight {
+                line.push_str(&format!(" Weight={:.4}", weight));
+            }
+            if let Some(width) = column.Width {
+                line.push_str(&format!(" Width={}", width as i32));
+            }
+            if let Some(visible) = column.Visible {
+                line.push_str(&format!(" Visible={}", visible as i32));
+            }
+            if let Some(order) = column.Order {
+                line.push_str(&format!(" Order={}", order));
+            }
+            if let Some(sort_order) = column.SortOrder {
+                line.push_str(&format!(" Sort={}{}", sort_order, if column.SortDescending { '^' } else { 'v' }));
+            }
+            lines.push((format!("Column {}", column.Index), line));
+        }
+        buf.push_str(&format!("[{}][0x{:08X},{}]\n", type_name, self.ID, self.ColumnsCount));
+        WriteIniLinesInOrder(&self.IniOrder, lines, &self.Extra, buf);
+        buf.push('\n');
+    }
+}
+
+// One line of [Docking][Data], e.g. "DockNode ID=0x00000002 Parent=0x00000001 SizeRef=201,322 Split=Y"
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DockNodeSettingsEntry {
+    #[serde(with = "settings_hex_id")]
+    pub ID: ImguiHandle,
+    #[serde(with = "settings_hex_id", skip_serializing_if = "IsZeroId")]
+    pub Parent: ImguiHandle,
+    // Host window of a dockspace
+    #[serde(with = "settings_hex_id", skip_serializing_if = "IsZeroId")]
+    pub Window: ImguiHandle,
+    #[serde(skip_serializing_if = "IsFalse")]
+    pub DockSpace: bool,
+    // Root nodes only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Pos: Option<[c_short; 2]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Size: Option<[c_short; 2]>,
+    // Child nodes only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub SizeRef: Option<[c_short; 2]>,
+    // 'X' or 'Y' for split nodes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub Split: Option<char>,
+    #[serde(skip_serializing_if = "IsFalse")]
+    pub NoResize: bool,
+    #[serde(skip_serializing_if = "IsFalse")]
+    pub CentralNode: bool,
+    #[serde(skip_serializing_if = "IsFalse")]
+    pub NoTabBar: bool,
+    #[serde(skip_serializing_if = "IsFalse")]
+    pub HiddenTabBar: bool,
+    #[serde(skip_serializing_if = "IsFalse")]
+    pub NoWindowMenuButton: bool,
+    #[serde(skip_serializing_if = "IsFalse")]
+    pub NoCloseButton: bool,
+    #[serde(with = "settings_hex_id", skip_serializing_if = "IsZeroId")]
+    pub Selected: ImguiHandle,
+}
+
+impl DockNodeSettingsEntry {
+    pub fn FromSettings(settings: &ImGuiDockNodeSettings) -> Self {
+        let is_root = settings.ParentNodeId == 0;
+        Self {
+            ID: settings.ID,
+            Parent: settings.ParentNodeId,
+            Window: if is_root { settings.ParentWindowId } else { 0 },
+            DockSpace: flag_set(settings.Flags, ImGuiDockNodeFlags_DockSpace),
+            Pos: if is_root { Some([settings.Pos.x, settings.Pos.y]) } else { None },
+            Size: if is_root { Some([settings.Size.x, settings.Size.y]) } else { None },
+            SizeRef: if is_root { None } else { Some([settings.SizeRef.x, settings.SizeRef.y]) },
+            Split: match settings.SplitAxis {
+                IM_GUI_AXIS_X => Some('X'),
+                IM_GUI_AXIS_Y => Some('Y'),
+                _ => None,
+            },
+            NoResize: flag_set(settings.Flags, ImGuiDockNodeFlags_NoResize),
+            CentralNode: flag_set(settings.Flags, ImGuiDockNodeFlags_CentralNode),
+            NoTabBar: flag_set(settings.Flags, ImGuiDockNodeFlags_NoTabBar),
+            HiddenTabBar: flag_set(settings.Flags, ImGuiDockNodeFlags_HiddenTabBar),
+            NoWindowMenuButton: flag_set(settings.Flags, ImGuiDockNodeFlags_NoWindowMenuButton),
+            NoCloseButton: flag_set(settings.Flags, ImGuiDockNodeFlags_NoCloseButton),
+            Selected: settings.SelectedTabId,
+        }
+    }
+
+    // 'depth' is the number of parents, see SettingsDocument::DockNodeDepth()
+    pub fn ToSettings(&self, depth: c_char) -> ImGuiDockNodeSettings {
+        let mut settings = ImGuiDockNodeSettings::new();
+        settings.ID = self.ID;
+        settings.ParentNodeId = self.Parent;
+        settings.ParentWindowId = self.Window;
+        settings.SelectedTabId = self.Selected;
+        settings.SplitAxis = match self.Split {
+            Some('X') => IM_GUI_AXIS_X,
+            Some('Y') => IM_GUI_AXIS_Y,
+            _ => IM_GUI_AXIS_NONE,
+        };
+        settings.Depth = depth;
+        let flags = [
+            (self.DockSpace, ImGuiDockNodeFlags_DockSpace),
+            (self.NoResize, ImGuiDockNodeFlags_NoResize),
+            (self.CentralNode, ImGuiDockNodeFlags_CentralNode),
+            (self.NoTabBar, ImGuiDockNodeFlags_NoTabBar),
+            (self.HiddenTabBar, ImGuiDockNodeFlags_HiddenTabBar),
+            (self.NoWindowMenuButton, ImGuiDockNodeFlags_NoWindowMenuButton),
+            (self.NoCloseButton, ImGuiDockNodeFlags_NoCloseButton),
+        ];
+        for (enabled, flag) in flags {
+            if enabled {
+                settings.Flags |= flag;
+            }
+        }
+        let pos = self.Pos.unwrap_or([0, 0]);
+        let size = self.Size.unwrap_or([0, 0]);
+        let size_ref = self.SizeRef.unwrap_or([0, 0]);
+        settings.Pos = ImVec2ih::new2(pos[0], pos[1]);
+        settings.Size = ImVec2ih::new2(size[0], size[1]);
+        settings.SizeRef = ImVec2ih::new2(size_ref[0], size_ref[1]);
+        settings
+    }
+
+    // Returns None for lines which are not a node description
+    fn ParseIniLine(line: &str) -> Option<Self> {
+        let mut tokens = line.split_whitespace();
+        let mut node = Self::default();
+        match tokens.next()? {
+            "DockNode" => {}
+            "DockSpace" => node.DockSpace = true,
+            _ => return None,
+        }
+        for token in tokens {
+            let (key, v) = token.split_once('=')?;
+            let as_flag = || v.parse::<i32>().ok().map(|x| x != 0);
+            match key {
+                "ID" => node.ID = ParseHexId(v)?,
+                "Parent" => node.Parent = ParseHexId(v)?,
+                "Window" => node.Window = ParseHexId(v)?,
+                "Pos" => node.Pos = Some(ParseVec2ih(v)?),
+                "Size" => node.Size = Some(ParseVec2ih(v)?),
+                "SizeRef" => node.SizeRef = Some(ParseVec2ih(v)?),
+                "Split" => node.Split = v.chars().next().filter(|c| *c == 'X' || *c == 'Y'),
+                "NoResize" => node.NoResize = as_flag()?,
+                "CentralNode" => node.CentralNode = as_flag()?,
+                "NoTabBar" => node.NoTabBar = as_flag()?,
+                "HiddenTabBar" => node.HiddenTabBar = as_flag()?,
+                "NoWindowMenuButton" => node.NoWindowMenuButton = as_flag()?,
+                "NoCloseButton" => node.NoCloseButton = as_flag()?,
+                "Selected" => node.Selected = ParseHexId(v)?,
+                _ => return None,
+            }
+        }
+        if node.ID == 0 {
+            return None;
+        }
+        Some(node)
+    }
+}
+
+// A named dock layout: dock tree + window placement (see dock_layout_ops.rs)
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DockLayoutSettingsEntry {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub Windows: Vec<WindowSettingsEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub DockNodes: Vec<DockNodeSettingsEntry>,
+}
+
+// A "[Type][Name]" section from a handler which doesn't provide typed data, kept as raw .ini lines.
+// Lines outside of any "[Type][Name]" section (before the first one, or after a malformed header) are kept in a
+// section with an empty TypeName and Name, written back without header.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RawSettingsSection {
+    pub TypeName: String,
+    pub Name: String,
+    pub Lines: Vec<String>,
+}
+
+//-----------------------------------------------------------------------------
+// Document
+//-----------------------------------------------------------------------------
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SettingsDocument {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub Windows: Vec<WindowSettingsEntry>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub Tables: Vec<TableSettingsEntry>,
+    // Parents are always listed before their children
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub DockNodes: Vec<DockNodeSettingsEntry>,
+    // Lines of [Docking][Data] which are not a node description
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub DockExtra: Vec<String>,
+    // Node IDs as "0x%08X", or "" for the next line of 'DockExtra', in the order they were read
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub DockIniOrder: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub Sections: Vec<RawSettingsSection>,
+    // Named layout profiles, by name. Written as "[DockLayout][Name]" + "Value=<json>" in .ini
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub DockLayouts: BTreeMap<String, DockLayoutSettingsEntry>,
+    // Entries of SettingsSection implementations, by TypeName. Written as "[UserData][TypeName]" + "Value=<json>" in .ini
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub UserSections: BTreeMap<String, serde_json::Value>,
+    // "[Type][Name]" headers in the order they were read, "" standing for the next headerless section
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub IniOrder: Vec<String>,
+}
+
+enum IniSection {
+    None,
+    User(String),
+    DockLayout(String),
+    Window(usize),
+    Table(usize),
+    Docking,
+    Raw(usize),
+}
+
+impl SettingsDocument {
+    pub fn Parse(format: ImGuiSettingsFormat, data: &str) -> io::Result<Self> {
+        let invalid = |e: String| io::Error::new(io::ErrorKind::InvalidData, e);
+        match format {
+            ImGuiSettingsFormat::Ini => Ok(SettingsDocument::FromIni(data)),
+            ImGuiSettingsFormat::Ron => ron::from_str(data).map_err(|e| invalid(e.to_string())),
+            ImGuiSettingsFormat::Json => serde_json::from_str(data).map_err(|e| invalid(e.to_string())),
+        }
+    }
+
+    pub fn Write(&self, format: ImGuiSettingsFormat) -> io::Result<String> {
+        let invalid = |e: String| io::Error::new(io::ErrorKind::InvalidData, e);
+        match format {
+            ImGuiSettingsFormat::Ini => Ok(self.ToIni()),
+            ImGuiSettingsFormat::Ron => ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).map_err(|e| invalid(e.to_string())),
+            ImGuiSettingsFormat::Json => serde_json::to_string_pretty(self).map_err(|e| invalid(e.to_string())),
+        }
+    }
+
+    // Zero-tolerance .ini import: "[Type][Name]" opens an entry. Unlike the legacy parser, ';' comment lines and lines
+    // outside of any entry are kept as unknown lines. Only empty lines are dropped.
+    pub fn FromIni(ini_data: &str) -> Self {
+        let mut doc = SettingsDocument::default();
+        let mut section = IniSection::None;
+        for line in ini_data.lines() {
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() {
+                continue;
+            }
+            if line.starts_with('[') && line.ends_with(']') {
+                // Parse "[Type][Name]". Note that 'Name' can itself contains [] characters.
+                let inner = &line[1..line.len() - 1];
+                if let Some((type_name, name)) = inner.split_once("][") {
+                    section = doc.OpenIniSection(type_name, name);
+                    doc.IniOrder.push(doc.IniHeader(&section).unwrap_or_else(|| line.to_string()));
+                    continue;
+                }
+                // Malformed header: kept with the lines which follow it
+                section = IniSection::None;
+            }
+            match section {
+                IniSection::None => {
+                    doc.Sections.push(RawSettingsSection { TypeName: String::new(), Name: String::new(), Lines: vec![line.to_string()] });
+                    doc.IniOrder.push(String::new());
+                    section = IniSection::Raw(doc.Sections.len() - 1);
+                }
+                IniSection::Window(n) => doc.Windows[n].ReadIniLine(line),
+                IniSection::Table(n) => doc.Tables[n].ReadIniLine(line),
+                IniSection::Docking => match DockNodeSettingsEntry::ParseIniLine(line) {
+                    Some(node) => {
+                        doc.DockIniOrder.push(format!("0x{:08X}", node.ID));
+                        doc.DockNodes.push(node);
+                    }
+                    None => {
+                        doc.DockExtra.push(line.to_string());
+                        doc.DockIniOrder.push(String::new());
+                    }
+                },
+                IniSection::Raw(n) => doc.Sections[n].Lines.push(line.to_string()),
+                IniSection::DockLayout(ref name) => {
+                    let layout = line.strip_prefix("Value=").and_then(|v| serde_json::from_str(v).ok());
+                    match layout {
+                        Some(layout) => { doc.DockLayouts.insert(name.clone(), layout); }
+                        None => section = doc.OpenRawIniSection("DockLayout", name, line),
+                    }
+                }
+                IniSection::User(ref type_name) => {
+                    let value = line.strip_prefix("Value=").and_then(|v| serde_json::from_str(v).ok());
+                    match value {
+                        Some(value) => { doc.UserSections.insert(type_name.clone(), value); }
+                        None => section = doc.OpenRawIniSection("UserData", type_name, line),
+                    }
+                }
+            }
+        }
+        doc
+    }
+
+    fn OpenIniSection(&mut self, type_name: &str, name: &str) -> IniSection {
+        match type_name {
+            "Window" => {
+                self.Windows.push(WindowSettingsEntry { Name: name.to_string(), ..Default::default() });
+                return IniSection::Window(self.Windows.len() - 1);
+            }
+            "Table" => {
+                let parsed = name.split_once(',').and_then(|(id, count)| Some((ParseHexId(id)?, count.trim().parse::<ImGuiTableColumnIdx>().ok()?)));
+                if let Some((id, columns_count)) = parsed {
+                    self.Tables.push(TableSettingsEntry { ID: id, ColumnsCount: columns_count, ..Default::default() });
+                    return IniSection::Table(self.Tables.len() - 1);
+                }
+            }
+            "Docking" if name == "Data" => return IniSection::Docking,
+            "DockLayout" => return IniSection::DockLayout(name.to_string()),
+            "UserData" => return IniSection::User(name.to_string()),
+            _ => {}
+        }
+        self.Sections.push(RawSettingsSection { TypeName: type_name.to_string(), Name: name.to_string(), Lines: vec![] });
+        IniSection::Raw(self.Sections.len() - 1)
+    }
+
+    // First line of a typed section which doesn't parse: this line and the rest of the section are kept as a raw section
+    // with the same header. The header is recorded again so ToIni() writes it right after the typed entry, if any.
+    fn OpenRawIniSection(&mut self, type_name: &str, name: &str, line: &str) -> IniSection {
+        self.Sections.push(RawSettingsSection { TypeName: type_name.to_string(), Name: name.to_string(), Lines: vec![line.to_string()] });
+        self.IniOrder.push(format!("[{}][{}]", type_name, name));
+        IniSection::Raw(self.Sections.len() - 1)
+    }
+
+    // Header written by ToIni() for a section just opened by OpenIniSection(), used as key of 'IniOrder'
+    fn IniHeader(&self, section: &IniSection) -> Option<String> {
+        match *section {
+            IniSection::None => None,
+            IniSection::User(ref type_name) => Some(format!("[UserData][{}]", type_name)),
+            IniSection::DockLayout(ref name) => Some(format!("[DockLayout][{}]", name)),
+            IniSection::Window(n) => Some(format!("[Window][{}]", self.Windows[n].Name)),
+            IniSection::Table(n) => Some(format!("[Table][0x{:08X},{}]", self.Tables[n].ID, self.Tables[n].ColumnsCount)),
+            IniSection::Docking => Some("[Docking][Data]".to_string()),
+            IniSection::Raw(n) => Some(format!("[{}][{}]", self.Sections[n].TypeName, self.Sections[n].Name)),
+        }
+    }
+
+    // Write in the format of the legacy WriteAll() handlers, following 'IniOrder' for documents read from .ini
+    pub fn ToIni(&self) -> String {
+        // Each section is written without its trailing empty line, which WriteIniLinesInOrder() adds back
+        let mut sections: Vec<(String, String)> = vec![];
+        let mut headerless: Vec<String> = vec![];
+        let mut push_section = |header: String, mut text: String| {
+            text.pop();
+            sections.push((header, text));
+        };
+        for window in &self.Windows {
+            let mut text = String::new();
+            window.WriteIni("Window", &mut text);
+            push_section(format!("[Window][{}]", window.Name), text);
+        }
+        for table in &self.Tables {
+            let mut text = String::new();
+            table.WriteIni("Table", &mut text);
+            push_section(format!("[Table][0x{:08X},{}]", table.ID, table.ColumnsCount), text);
+        }
+        if !self.DockNodes.is_empty() || !self.DockExtra.is_empty() {
+            let mut text = String::new();
+            self.WriteDockingIni("Docking", &mut text);
+            push_section("[Docking][Data]".to_string(), text);
+        }
+        for (name, layout) in &self.DockLayouts {
+            if let Ok(value) = serde_json::to_string(layout) {
+                push_section(format!("[DockLayout][{}]", name), format!("[DockLayout][{}]\nValue={}\n\n", name, value));
+            }
+        }
+        for (type_name, value) in &self.UserSections {
+            push_section(format!("[UserData][{}]", type_name), format!("[UserData][{}]\nValue={}\n\n", type_name, value));
+        }
+        for section in &self.Sections {
+            let mut text = String::new();
+            if !section.TypeName.is_empty() || !section.Name.is_empty() {
+                text.push_str(&format!("[{}][{}]\n", section.TypeName, section.Name));
+            }
+            for line in &section.Lines {
+                text.push_str(line);
+                text.push('\n');
+            }
+            if section.TypeName.is_empty() && section.Name.is_empty() {
+                headerless.push(text);
+            } else {
+                push_section(format!("[{}][{}]", section.TypeName, section.Name), text + "\n");
+            }
+        }
+        let mut buf = String::new();
+        WriteIniLinesInOrder(&self.IniOrder, sections, &headerless, &mut buf);
+        buf
+    }
+
+    // Number of parents of a node, from the Parent links
+    pub fn DockNodeDepth(&self, node_n: usize) -> c_char {
+        let mut depth: c_char = 0;
+        let mut parent_id = self.DockNodes[node_n].Parent;
+        while parent_id != 0 && (depth as usize) < self.DockNodes.len() {
+            parent_id = match self.DockNodes.iter().find(|node| node.ID == parent_id) {
+                Some(parent) => parent.Parent,
+                None => 0,
+            };
+            depth += 1;
+        }
+        depth
+    }
+
+    fn WriteDockingIni(&self, type_name: &str, buf: &mut String) {
+        let depths: Vec<usize> = (0..self.DockNodes.len()).map(|n| self.DockNodeDepth(n) as usize).collect();
+        let max_depth = depths.iter().cloned().max().unwrap_or(0);
+
+        let mut lines: Vec<(String, String)> = vec![];
+        for (node, depth) in self.DockNodes.iter().zip(depths) {
+            // Text align nodes to facilitate looking at .ini file
+            let mut line = " ".repeat(depth * 2);
+            line.push_str(if node.DockSpace { "DockSpace" } else { "DockNode " });
+            line.push_str(&" ".repeat((max_depth - depth) * 2));
+            line.push_str(&format!(" ID=0x{:08X}", node.ID));
+            if node.Parent != 0 {
+                line.push_str(&format!(" Parent=0x{:08X}", node.Parent));
+            } else if node.Window != 0 {
+                line.push_str(&format!(" Window=0x{:08X}", node.Window));
+            }
+            if let Some(pos) = node.Pos {
+                line.push_str(&format!(" Pos={},{}", pos[0], pos[1]));
+            }
+            if let Some(size) = node.Size {
+                line.push_str(&format!(" Size={},{}", size[0], size[1]));
+            }
+            if let Some(size_ref) = node.SizeRef {
+                line.push_str(&format!(" SizeRef={},{}", size_ref[0], size_ref[1]));
+            }
+            if let Some(split) = node.Split {
+                line.push_str(&format!(" Split={}", split));
+            }
+            let flags = [
+                ("NoResize", node.NoResize),
+                ("CentralNode", node.CentralNode),
+                ("NoTabBar", node.NoTabBar),
+                ("HiddenTabBar", node.HiddenTabBar),
+                ("NoWindowMenuButton", node.NoWindowMenuButton),
+                ("NoCloseButton", node.NoCloseButton),
+            ];
+            for (name, enabled) in flags {
+                if enabled {
+                    line.push_str(&format!(" {}=1", name));
+                }
+            }
+            if node.Selected != 0 {
+                line.push_str(&format!(" Selected=0x{:08X}", node.Selected));
+            }
+            lines.push((format!("0x{:08X}", node.ID), line));
+        }
+        buf.push_str(&format!("[{}][Data]\n", type_name));
+        WriteIniLinesInOrder(&self.DockIniOrder, lines, &self.DockExtra, buf);
+        buf.push('\n');
+    }
+}
+
+// Convert a settings file between formats, picked from the file extensions (e.g. "imgui.ini" -> "imgui.ron")
+pub fn ConvertSettingsFile<P: AsRef<Path>, Q: AsRef<Path>>(src_path: P, dst_path: Q) -> io::Result<()> {
+    let src_format = ImGuiSettingsFormat::FromFilename(&src_path.as_ref().to_string_lossy());
+    let dst_format = ImGuiSettingsFormat::FromFilename(&dst_path.as_ref().to_string_lossy());
+    let doc = SettingsDocument::Parse(src_format, &fs::read_to_string(src_path)?)?;
+    fs::write(dst_path, doc.Write(dst_format)?)
+}
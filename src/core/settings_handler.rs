@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 
 use crate::core::context::AppContext;
-use crate::core::axis::ImGuiAxis;
+use crate::core::settings_document::SettingsDocument;
 use crate::text_buffer::ImGuiTextBuffer;
 use crate::core::type_defs::ImguiHandle;
 use libc::{c_char, c_void};
@@ -22,7 +22,7 @@ pub struct SettingsHandler {
     pub ReadInitFn: fn(g: &mut AppContext, handler: *mut Self),
 
     // *mut c_void       (*ReadOpenFn)(g: &mut ImguiContext, *mut handler: ImGuiSettingsHandler, name: *const c_char);              // Read: Called when entering into a new ini entry e.g. "[Window][Name]"
-    pub ReadOpenFn: fn(g: &mut AppContext, handler: *mut Self, name: *const c_char),
+    pub ReadOpenFn: fn(g: &mut AppContext, handler: *mut Self, name: *const c_char) -> *mut c_void,
 
     // c_void        (*ReadLineFn)(g: &mut ImguiContext, *mut handler: ImGuiSettingsHandler, entry: *mut c_void, line: *const c_char); // Read: Called for every line of text within an ini entry
     pub ReadLineFn:
@@ -33,6 +33,12 @@ pub struct SettingsHandler {
 
     // c_void        (*WriteAllFn)(g: &mut ImguiContext, *mut handler: ImGuiSettingsHandler, *mut ImGuiTextBuffer out_bu0f32);      // Write: Output every entries into 'out_buf'
     pub WriteAllFn: fn(g: &mut AppContext, handler: *mut Self, out_buf: *mut ImGuiTextBuffer),
+
+    // Read: Called with the whole parsed document instead of ReadOpenFn/ReadLineFn (between ReadInitFn and ApplyAllFn)
+    pub ReadDocumentFn: Option<fn(g: &mut AppContext, handler: *mut Self, doc: &SettingsDocument)>,
+
+    // Write: Fill the handler typed section of 'doc', used instead of WriteAllFn
+    pub WriteDocumentFn: Option<fn(g: &mut AppContext, handler: *mut Self, doc: &mut SettingsDocument)>,
 }
 
 impl SettingsHandler {}
@@ -13,60 +13,77 @@
 // - LoadIniSettingsFromMemory()
 // - SaveIniSettingsToDisk()
 // - SaveIniSettingsToMemory()
+// - LoadSettingsFromDisk(), LoadSettingsFromMemory()
+// - SaveSettingsToDisk(), SaveSettingsToMemory()
 // - WindowSettingsHandler_***() [Internal]
 //-----------------------------------------------------------------------------
 
+use std::ffi::CString;
+use std::fs;
+use std::io;
 use std::ptr::null_mut;
-use libc::{c_char, memcpy, size_t, sscanf, strlen};
+use libc::{c_char, c_short, c_void, memcpy, size_t, sscanf, strlen};
 use crate::{close_file, hash_string, imgui::GImGui, window::window_settings::ImGuiWindowSettings};
 use crate::core::context::AppContext;
 use crate::core::file_ops::{ImFileLoadToMemory, ImFileWrite, open_file};
+use crate::core::settings_document::{ImGuiSettingsFormat, SettingsDocument, WindowSettingsEntry};
 use crate::core::settings_handler::SettingsHandler;
+use crate::core::settings_section::{SettingsSectionsClearAll, SettingsSectionsReadDocument, SettingsSectionsWriteDocument};
 use crate::core::string_ops::str_to_const_c_char_ptr;
 use crate::core::string_ops::ImStrchrRange;
 use crate::core::type_defs::ImguiHandle;
 use crate::text_buffer::ImGuiTextBuffer;
 use crate::core::type_defs::ImFileHandle;
-use crate::core::utils::{flag_clear, is_not_null};
-use crate::core::vec2::ImVec2ih;
+use crate::core::utils::{flag_clear, flag_set, is_not_null};
+use crate::core::vec2::{ImVec2ih, Vector2};
 use crate::window::find::FindWindowByID;
 use crate::window::ImguiWindow;
 use crate::window::ops::ApplyWindowSettings;
 use crate::window::window_flags::ImGuiWindowFlags_NoSavedSettings;
 
 // Called by NewFrame()
-pub unsafe fn UpdateSettings()
+pub fn UpdateSettings(g: &mut AppContext)
 {
     // Load settings on first frame (if not explicitly loaded manually before)
-    let g = GImGui; // ImGuiContext& g = *GImGui;
-    if (!g.SettingsLoaded)
+    if !g.SettingsLoaded
     {
         // IM_ASSERT(g.SettingsWindows.empty());
-        if (g.IO.IniFilename){
-            LoadIniSettingsFromDisk(g.IO.IniFilename);}
+        if !g.IO.IniFilename.is_empty() {
+            let ini_filename = g.IO.IniFilename.clone();
+            // A missing or unreadable file is not an error: we start with default settings
+            let _ = LoadSettingsFromDisk(g, &ini_filename);
+        }
         g.SettingsLoaded = true;
     }
 
     // Save settings (with a delay after the last modification, so we don't spam disk too much)
-    if (g.SettingsDirtyTimer > 0.0)
+    if g.SettingsDirtyTimer > 0.0
     {
         g.SettingsDirtyTimer -= g.IO.DeltaTime;
-        if (g.SettingsDirtyTimer <= 0.0)
+        if g.SettingsDirtyTimer <= 0.0
         {
-            if (g.IO.IniFilename != null_mut()){
-                save_ini_settings_to_disk(g, g.IO.IniFilename);}
-            else{
-                g.IO.WantSaveIniSettings = true; } // Let user know they can call SaveIniSettingsToMemory(). user will need to clear io.WantSaveIniSettings themselves.
+            if !g.IO.IniFilename.is_empty() {
+                let ini_filename = g.IO.IniFilename.clone();
+                save_ini_settings_to_disk(g, &ini_filename);
+            }
+            else {
+                g.IO.WantSaveIniSettings = true; // Let user know they can call SaveIniSettingsToMemory(). user will need to clear io.WantSaveIniSettings themselves.
+            }
             g.SettingsDirtyTimer = 0.0;
         }
     }
 }
 
+// Settings will be saved after io.IniSavingRate seconds
+pub fn MarkIniSettingsDirty(g: &mut AppContext) {
+    if g.SettingsDirtyTimer <= 0.0 {
+        g.SettingsDirtyTimer = g.IO.IniSavingRate;
+    }
+}
+
 pub unsafe fn mark_ini_setings_dirty2(g: &mut AppContext, window: &mut ImguiWindow) {
     if flag_clear(window.Flags, ImGuiWindowFlags_NoSavedSettings) {
-        if g.SettingsDirtyTimer <= 0.0 {
-            g.SettingsDirtyTimer = g.IO.IniSavingRate;
-        }
+        MarkIniSettingsDirty(g);
     }
 }
 
@@ -144,124 +161,131 @@ pub unsafe fn FindSettingsHandler(type_name: *const c_char) -> *mut SettingsHand
     return None;
 }
 
-pub unsafe fn ClearIniSettings()
+pub fn ClearIniSettings(g: &mut AppContext)
 {
-    let g = GImGui; // ImGuiContext& g = *GImGui;
     g.SettingsIniData.clear();
-    for (let handler_n: c_int = 0; handler_n < g.SettingsHandlers.Size; handler_n++)
-        if (g.SettingsHandlers[handler_n].ClearAllFn)
-            g.SettingsHandlers[handler_n].ClearAllFn(&g, &g.SettingsHandlers[handler_n]);
+    for handler_n in 0 .. g.settings_handlers.len()
+    {
+        let handler: *mut SettingsHandler = &mut g.settings_handlers[handler_n];
+        unsafe { ((*handler).ClearAllFn)(g, handler); }
+    }
+    SettingsSectionsClearAll(g);
 }
 
-pub unsafe fn LoadIniSettingsFromDisk(ini_filename: *const c_char)
+// Load from a .ini, .ron or .json file, picked from the extension
+pub fn LoadSettingsFromDisk(g: &mut AppContext, filename: &str) -> io::Result<()>
 {
-    file_data_size: size_t = 0;
-    char* file_data = ImFileLoadToMemory(ini_filename, "rb", &file_data_size);
-    if !file_data { return ; }
-    if (file_data_size > 0)
-        LoadIniSettingsFromMemory(file_data, file_data_size);
-    IM_FREE(file_data);
+    let data = fs::read_to_string(filename)?;
+    if data.is_empty() { return Ok(()); }
+    LoadSettingsFromMemory(g, ImGuiSettingsFormat::FromFilename(filename), &data)
 }
 
-// Zero-tolerance, no error reporting, cheap .ini parsing
-pub unsafe fn LoadIniSettingsFromMemory(ini_data: *const c_char, ini_size: size_t)
+pub fn LoadSettingsFromMemory(g: &mut AppContext, format: ImGuiSettingsFormat, data: &str) -> io::Result<()>
 {
-    let g = GImGui; // ImGuiContext& g = *GImGui;
     // IM_ASSERT(g.Initialized);
     //IM_ASSERT(!g.WithinFrameScope && "Cannot be called between NewFrame() and EndFrame()");
     //IM_ASSERT(g.SettingsLoaded == false && g.FrameCount == 0);
-
-    // For user convenience, we allow passing a non zero-terminated string (hence the ini_size parameter).
-    // For our convenience and to make the code simpler, we'll also write zero-terminators within the buffer. So let's create a writable copy..
-    if ini_size == 0 {
-        ini_size = strlen(ini_data)(); }
-    g.SettingsIniData.Buf.resize(ini_size + 1);
-    char* const buf = g.SettingsIniData.Buf.Data;
-    char* const buf_end = buf + ini_size;
-    memcpy(buf, ini_data, ini_size);
-    buf_end[0] = 0;
+    // Parse everything first so a malformed document leaves the current settings untouched
+    let doc = SettingsDocument::Parse(format, data)?;
+    g.SettingsIniData = data.to_string();
 
     // Call pre-read handlers
     // Some types will clear their data (e.g. dock information) some types will allow merge/override (window)
-    for (let handler_n: c_int = 0; handler_n < g.SettingsHandlers.Size; handler_n++)
-        if (g.SettingsHandlers[handler_n].ReadInitFn)
-            g.SettingsHandlers[handler_n].ReadInitFn(&g, &g.SettingsHandlers[handler_n]);
-
-    entry_data: *mut c_void= None;
-    ImGuiSettingsHandler* entry_handler= None;
+    for handler_n in 0 .. g.settings_handlers.len()
+    {
+        let handler: *mut SettingsHandler = &mut g.settings_handlers[handler_n];
+        unsafe { ((*handler).ReadInitFn)(g, handler); }
+    }
 
-    char* line_end= None;
-    for (char* line = buf; line < buf_end; line = line_end + 1)
+    // Typed handlers read their section directly, others are fed the raw lines of their "[Type][Name]" entries
+    for handler_n in 0 .. g.settings_handlers.len()
     {
-        // Skip new lines markers, then find end of the line
-        while (*line == '\n' || *line == '\r')
-            line+= 1;
-        line_end = line;
-        while (line_end < buf_end && *line_end != '\n' && *line_end != '\r')
-            line_end+= 1;
-        line_end[0] = 0;
-        if (line[0] == ';')
-            continue;
-        if (line[0] == '[' && line_end > line && line_end[-1] == ']')
-        {
-            // Parse "[Type][Name]". Note that 'Name' can itself contains [] characters, which is acceptable with the current format and parsing code.
-            line_end[-1] = 0;
-            let mut  name_end: *const c_char = line_end - 1;
-            let mut  type_start: *const c_char = line + 1;
-            char* type_end = ImStrchrRange(type_start, name_end, ']');
-            let mut  name_start: *const c_char = type_end ? ImStrchrRange(type_end + 1, name_end, '[') : None;
-            if (!type_end || !name_start)
+        let handler: *mut SettingsHandler = &mut g.settings_handlers[handler_n];
+        unsafe {
+            if let Some(read_document_fn) = (*handler).ReadDocumentFn {
+                read_document_fn(g, handler, &doc);
                 continue;
-            *type_end = 0; // Overwrite first ']'
-            name_start+= 1;  // Skip second '['
-            entry_handler = FindSettingsHandler(type_start);
-            entry_data = if entry_handler { entry_handler -> ReadOpenFn(&g, entry_handler, name_start) } else { None };
-        }
-        else if (entry_handler != None && entry_data != null_mut())
-        {
-            // Let type handler parse the line
-            entry_handler->ReadLineFn(&g, entry_handler, entry_data, line);
+            }
+            for section in doc.Sections.iter().filter(|section| hash_string(&section.TypeName, 0) == (*handler).TypeHash)
+            {
+                let name = CString::new(section.Name.as_str()).unwrap_or_default();
+                let entry_data = ((*handler).ReadOpenFn)(g, handler, name.as_ptr());
+                if entry_data.is_null() {
+                    continue;
+                }
+                for line in section.Lines.iter()
+                {
+                    let line = CString::new(line.as_str()).unwrap_or_default();
+                    ((*handler).ReadLineFn)(g, handler, entry_data, line.as_ptr());
+                }
+            }
         }
     }
+    SettingsSectionsReadDocument(g, &doc);
     g.SettingsLoaded = true;
 
-    // [DEBUG] Restore untouched copy so it can be browsed in Metrics (not strictly necessary)
-    memcpy(buf, ini_data, ini_size);
-
     // Call post-read handlers
-    for (let handler_n: c_int = 0; handler_n < g.SettingsHandlers.Size; handler_n++)
-        if (g.SettingsHandlers[handler_n].ApplyAllFn)
-            g.SettingsHandlers[handler_n].ApplyAllFn(&g, &g.SettingsHandlers[handler_n]);
+    for handler_n in 0 .. g.settings_handlers.len()
+    {
+        let handler: *mut SettingsHandler = &mut g.settings_handlers[handler_n];
+        unsafe { ((*handler).ApplyAllFn)(g, handler); }
+    }
+    Ok(())
+}
+
+// Call registered handlers to fill a document, then write it in the requested format
+pub fn SaveSettingsToMemory(g: &mut AppContext, format: ImGuiSettingsFormat) -> io::Result<String>
+{
+    g.SettingsDirtyTimer = 0.0;
+    let mut doc = SettingsDocument::default();
+    for handler_n in 0 .. g.settings_handlers.len()
+    {
+        let handler: *mut SettingsHandler = &mut g.settings_handlers[handler_n];
+        unsafe {
+            if let Some(write_document_fn) = (*handler).WriteDocumentFn {
+                write_document_fn(g, handler, &mut doc);
+                continue;
+            }
+            // Legacy handler: capture its .ini output and keep it as raw sections
+            let mut buf = ImGuiTextBuffer::default();
+            ((*handler).WriteAllFn)(g, handler, &mut buf);
+            doc.Sections.extend(SettingsDocument::FromIni(buf.c_str()).Sections);
+        }
+    }
+    SettingsSectionsWriteDocument(g, &mut doc);
+    let data = doc.Write(format)?;
+    g.SettingsIniData = data.clone();
+    Ok(data)
+}
+
+pub fn SaveSettingsToDisk(g: &mut AppContext, filename: &str) -> io::Result<()>
+{
+    let data = SaveSettingsToMemory(g, ImGuiSettingsFormat::FromFilename(filename))?;
+    fs::write(filename, data)
+}
+
+pub fn LoadIniSettingsFromDisk(g: &mut AppContext, ini_filename: &str)
+{
+    let _ = LoadSettingsFromDisk(g, ini_filename);
+}
+
+// Zero-tolerance, no error reporting, cheap .ini parsing
+pub fn LoadIniSettingsFromMemory(g: &mut AppContext, ini_data: &str)
+{
+    let _ = LoadSettingsFromMemory(g, ImGuiSettingsFormat::Ini, ini_data);
 }
 
 pub fn save_ini_settings_to_disk(g: &mut AppContext, ini_filename: &String)
 {
     g.SettingsDirtyTimer = 0.0;
-    if !ini_filename { return ; }
-
-    ini_data_size: size_t = 0;
-    let mut  ini_data: *const c_char = SaveIniSettingsToMemory(&ini_data_size);
-    f: ImFileHandle = open_file(ini_filename, "wt");
-    if !0.0 { return ; }
-    ImFileWrite(ini_data, sizeof, ini_data_size, 0.0);
-    // close_file(0.0);
+    if ini_filename.is_empty() { return ; }
+    let _ = SaveSettingsToDisk(g, ini_filename);
 }
 
 // Call registered handlers (e.g. SettingsHandlerWindow_WriteAll() + custom handlers) to write their stuff into a text buffer
-SaveIniSettingsToMemory: *const c_char(size_t* out_size)
+pub fn SaveIniSettingsToMemory(g: &mut AppContext) -> String
 {
-    let g = GImGui; // ImGuiContext& g = *GImGui;
-    g.SettingsDirtyTimer = 0.0;
-    g.SettingsIniData.Buf.clear();
-    g.SettingsIniData.Buf.push(0);
-    for (let handler_n: c_int = 0; handler_n < g.SettingsHandlers.Size; handler_n++)
-    {
-        ImGuiSettingsHandler* handler = &g.SettingsHandlers[handler_n];
-        handler->WriteAllFn(&g, handler, &g.SettingsIniData);
-    }
-    if (out_size)
-        *out_size = g.SettingsIniData.size();
-    return g.SettingsIniData.c_str();
+    SaveSettingsToMemory(g, ImGuiSettingsFormat::Ini).unwrap_or_default()
 }
 
 pub unsafe fn WindowSettingsHandler_ClearAll(g: &mut AppContext, SettingsHandler*)
@@ -272,7 +296,7 @@ pub unsafe fn WindowSettingsHandler_ClearAll(g: &mut AppContext, SettingsHandler
     g.SettingsWindows.clear();
 }
 
-static WindowSettingsHandler_ReadOpen: *mut c_void(AppContext *, ImGuiSettingsHandler*, name: *const c_char)
+pub unsafe fn WindowSettingsHandler_ReadOpen(g: &mut AppContext, handler: *mut SettingsHandler, name: *const c_char) -> *mut c_void
 {
     settings: *mut ImGuiWindowSettings = FindOrCreateWindowSettings(name);
     let mut id: ImguiHandle =  settings.ID;
@@ -369,3 +393,58 @@ pub unsafe fn WindowSettingsHandler_WriteAll(g: &mut AppContext, SettingsHandler
         buf->append("\n");
     }
 }
+
+// Typed version of WindowSettingsHandler_ReadOpen() + WindowSettingsHandler_ReadLine()
+pub fn WindowSettingsHandler_ReadDocument(g: &mut AppContext, _handler: *mut SettingsHandler, doc: &SettingsDocument)
+{
+    for entry in doc.Windows.iter()
+    {
+        let mut settings = entry.ToSettings();
+        settings.WantApply = true;
+        // Clear existing if recycling previous entry
+        match g.SettingsWindow.iter_mut().find(|s| s.ID == settings.ID) {
+            Some(existing) => *existing = settings,
+            None => g.SettingsWindow.push(settings),
+        }
+    }
+}
+
+// Typed version of WindowSettingsHandler_WriteAll()
+// Stored window settings updated with the windows that were active during this session, without touching g.SettingsWindow
+// (if a window wasn't opened in this session we preserve its settings)
+pub fn WindowSettingsHandler_GatherSettings(g: &AppContext) -> Vec<ImGuiWindowSettings>
+{
+    let mut settings_windows = g.SettingsWindow.clone();
+    for window in g.windows.values()
+    {
+        if flag_set(window.Flags, ImGuiWindowFlags_NoSavedSettings) {
+            continue;
+        }
+        let settings = match settings_windows.iter_mut().find(|s| s.ID == window.ID) {
+            Some(settings) => settings,
+            None => {
+                let mut settings = ImGuiWindowSettings::new();
+                settings.ID = window.ID;
+                settings.Name = window.Name.clone();
+                settings_windows.push(settings);
+                settings_windows.last_mut().unwrap()
+            }
+        };
+        // IM_ASSERT(window.DockNode == NULL || window.DockNode.ID == window.DockId);
+        settings.Pos = ImVec2ih::new3(&Vector2::from_floats(window.position.x - window.ViewportPos.x, window.position.y - window.ViewportPos.y));
+        settings.Size = ImVec2ih::new3(&window.SizeFull);
+        settings.ViewportId = window.ViewportId;
+        settings.ViewportPos = ImVec2ih::new3(&window.ViewportPos);
+        settings.DockId = window.DockId;
+        settings.ClassId = window.WindowClass.ClassId;
+        settings.DockOrder = window.DockOrder as c_short;
+        settings.Collapsed = window.Collapsed;
+    }
+    settings_windows
+}
+
+pub fn WindowSettingsHandler_WriteDocument(g: &mut AppContext, _handler: *mut SettingsHandler, doc: &mut SettingsDocument)
+{
+    g.SettingsWindow = WindowSettingsHandler_GatherSettings(g);
+    doc.Windows.extend(g.SettingsWindow.iter().map(WindowSettingsEntry::FromSettings));
+}
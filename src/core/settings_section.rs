@@ -0,0 +1,156 @@
+#![allow(non_snake_case)]
+
+//-----------------------------------------------------------------------------
+// [SECTION] USER SETTINGS SECTIONS
+//-----------------------------------------------------------------------------
+// - SettingsSection (trait)
+// - AddSettingsSection()
+// - RemoveSettingsSection()
+// - SettingsSectionsClearAll() [Internal]
+// - SettingsSectionsReadDocument() [Internal]
+// - SettingsSectionsWriteDocument() [Internal]
+//-----------------------------------------------------------------------------
+// Typed alternative to filling a SettingsHandler with raw function pointers. The application keeps its own
+// Rc<RefCell<S>> and registers a clone of it; the entry is stored next to the Window/Table/Docking data
+// in the same file (whatever its format). After modifying the state of a section, call MarkIniSettingsDirty().
+//
+//   #[derive(Default, Clone, Serialize, Deserialize)]
+//   struct PanelState { LastOpenedFile: String, Filter: String }
+//   impl SettingsSection for PanelState {
+//       type Entry = PanelState;
+//       fn TypeName(&self) -> &str { "MyApp.Panels" }
+//       fn ReadEntry(&mut self, _g: &mut AppContext, entry: PanelState) { *self = entry; }
+//       fn WriteEntry(&mut self, _g: &mut AppContext) -> Option<PanelState> { Some(self.clone()) }
+//   }
+//   let panels = Rc::new(RefCell::new(PanelState::default()));
+//   AddSettingsSection(g, panels.clone());
+//   ...
+//   panels.borrow_mut().Filter = new_filter; MarkIniSettingsDirty(g);
+//-----------------------------------------------------------------------------
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::rc::Rc;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use crate::core::context::AppContext;
+use crate::core::settings_document::SettingsDocument;
+
+pub trait SettingsSection {
+    type Entry: Serialize + DeserializeOwned;
+
+    // Name of the section in the settings file, must be unique. Disallowed characters: '[' ']'
+    fn TypeName(&self) -> &str;
+
+    // Read: called with the stored entry, after the built-in handlers were read
+    fn ReadEntry(&mut self, g: &mut AppContext, entry: Self::Entry);
+
+    // Write: return None to leave the section out of the file
+    fn WriteEntry(&mut self, g: &mut AppContext) -> Option<Self::Entry>;
+
+    // Called by ClearIniSettings()
+    fn ClearAll(&mut self, _g: &mut AppContext) {}
+}
+
+// Type-erased side of SettingsSection, entries are exchanged as format-neutral values
+pub trait ErasedSettingsSection {
+    fn TypeName(&self) -> String;
+    fn ReadValue(&self, g: &mut AppContext, value: &serde_json::Value) -> Result<(), String>;
+    fn WriteValue(&self, g: &mut AppContext) -> Result<Option<serde_json::Value>, String>;
+    fn ClearAll(&self, g: &mut AppContext);
+}
+
+impl<S: SettingsSection> ErasedSettingsSection for RefCell<S> {
+    fn TypeName(&self) -> String {
+        self.borrow().TypeName().to_string()
+    }
+
+    fn ReadValue(&self, g: &mut AppContext, value: &serde_json::Value) -> Result<(), String> {
+        let entry: S::Entry = serde_json::from_value(value.clone()).map_err(|e| e.to_string())?;
+        self.borrow_mut().ReadEntry(g, entry);
+        Ok(())
+    }
+
+    fn WriteValue(&self, g: &mut AppContext) -> Result<Option<serde_json::Value>, String> {
+        match self.borrow_mut().WriteEntry(g) {
+            Some(entry) => serde_json::to_value(entry).map(Some).map_err(|e| e.to_string()),
+            None => Ok(None),
+        }
+    }
+
+    fn ClearAll(&self, g: &mut AppContext) {
+        self.borrow_mut().ClearAll(g);
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct ImGuiSettingsSections {
+    pub Sections: Vec<Rc<dyn ErasedSettingsSection>>,
+    // Entries read from the file for sections which are not registered (yet), written back unchanged
+    pub Pending: BTreeMap<String, serde_json::Value>,
+}
+
+impl fmt::Debug for ImGuiSettingsSections {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ImGuiSettingsSections")
+            .field("Sections", &self.Sections.iter().map(|section| section.TypeName()).collect::<Vec<_>>())
+            .field("Pending", &self.Pending)
+            .finish()
+    }
+}
+
+// If the settings were already loaded, the stored entry (if any) is applied right away.
+pub fn AddSettingsSection<S: SettingsSection + 'static>(g: &mut AppContext, section: Rc<RefCell<S>>)
+{
+    let type_name = section.borrow().TypeName().to_string();
+    // IM_ASSERT(FindSettingsSection(type_name) == NULL);
+    // IM_ASSERT(type_name != "Window" && type_name != "Table" && type_name != "Docking");
+    RemoveSettingsSection(g, &type_name);
+    let section: Rc<dyn ErasedSettingsSection> = section;
+    g.SettingsSections.Sections.push(section.clone());
+    if let Some(value) = g.SettingsSections.Pending.remove(&type_name) {
+        // A stale entry which doesn't match the current type is dropped, the section keeps its defaults
+        let _ = section.ReadValue(g, &value);
+    }
+}
+
+pub fn RemoveSettingsSection(g: &mut AppContext, type_name: &str)
+{
+    g.SettingsSections.Sections.retain(|section| section.TypeName() != type_name);
+}
+
+// Called by ClearIniSettings()
+pub fn SettingsSectionsClearAll(g: &mut AppContext)
+{
+    g.SettingsSections.Pending.clear();
+    for section in g.SettingsSections.Sections.clone() {
+        section.ClearAll(g);
+    }
+}
+
+// Called by LoadSettingsFromMemory()
+pub fn SettingsSectionsReadDocument(g: &mut AppContext, doc: &SettingsDocument)
+{
+    g.SettingsSections.Pending = doc.UserSections.clone();
+    for section in g.SettingsSections.Sections.clone() {
+        if let Some(value) = g.SettingsSections.Pending.remove(&section.TypeName()) {
+            let _ = section.ReadValue(g, &value);
+        }
+    }
+}
+
+// Called by SaveSettingsToMemory()
+pub fn SettingsSectionsWriteDocument(g: &mut AppContext, doc: &mut SettingsDocument)
+{
+    doc.UserSections = g.SettingsSections.Pending.clone();
+    for section in g.SettingsSections.Sections.clone() {
+        // A section which fails to serialize is left out of the file
+        if let Ok(value) = section.WriteValue(g) {
+            match value {
+                Some(value) => { doc.UserSections.insert(section.TypeName(), value); }
+                None => { doc.UserSections.remove(&section.TypeName()); }
+            }
+        }
+    }
+}
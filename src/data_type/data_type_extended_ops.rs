@@ -0,0 +1,546 @@
+#![allow(non_snake_case)]
+
+// [SECTION] Data types without a lossless f32/f64 representation in this port
+// - ImF16ToF32(), ImF32ToF16()               IEEE 754 binary16 <-> f32, round to nearest even
+// - DataTypeIsExtended()                     IM_GUI_DATA_TYPE_F16, IM_GUI_DATA_TYPE_FIXED16_16, IM_GUI_DATA_TYPE_S128, IM_GUI_DATA_TYPE_U128
+// - DataTypeExtendedFormat()                 printf-style formatting of %d %i %u %x %X %f %F %e %E %g %G
+// - ImFormatDouble()                         same formatting for a f64
+// - ImFormatQuotient()                       same formatting for an integer divided by an integer, computed exactly
+// - DataTypeExtendedParse()                  text to value, exact for fixed point and 128-bit integers
+// - DataTypeExtendedCompare(), DataTypeExtendedClamp(), DataTypeExtendedApplyOp()
+// - DataTypeExtendedFromDouble()             values computed in f64 (e.g. converted from a unit)
+// - DataTypeExtendedToDouble()               display only
+// - DataTypeExtendedDistance()               signed distance between two values, e.g. the default drag speed
+// - DataTypeExtendedGetRatio(), DataTypeExtendedSetFromRatio()     slider position
+// - DataTypeExtendedDragApply()              drag delta, leaving what doesn't make a difference in the accumulator
+// Values are handled as their bytes (native endianness, ImGuiDataTypeTempStorage can hold any of them), as given by
+// ImScalarBytes() for the value, bounds and steps the widgets receive (see ImGuiScalar).
+// Arithmetic happens in the type itself: 128-bit integers saturate, Q16.16 adds raw 1/65536 units, f16 rounds once per operation.
+
+use libc::{c_float, c_int};
+use crate::data_type::{ImF16, ImFixed16_16, ImGuiDataType, IM_GUI_DATA_TYPE_F16, IM_GUI_DATA_TYPE_FIXED16_16, IM_GUI_DATA_TYPE_S128, IM_GUI_DATA_TYPE_U128};
+use crate::data_type::data_type_ops::{DataTypeOperation, DATA_TYPE_OPERATION_SUB};
+
+// Q16.16: 16 integer bits (signed), 16 fractional bits
+const IM_FIXED16_16_ONE: i64 = 1 << 16;
+// 10^16 / 2^16, integer: a 16-bit binary fraction times this is its exact 16-digit decimal fraction
+const IM_FIXED16_16_DECIMAL_SCALE: u64 = 152_587_890_625;
+
+pub fn ImF16ToF32(h: ImF16) -> f32 {
+    let sign: u32 = ((h & 0x8000) as u32) << 16;
+    let exp: u32 = ((h >> 10) & 0x1F) as u32;
+    let mant: u32 = (h & 0x3FF) as u32;
+    if exp == 0 {
+        // Zero and subnormals: mant * 2^-24, exact in f32
+        let v: f32 = mant as f32 * (1.0 / 16777216.0);
+        return if sign != 0 { -v } else { v };
+    }
+    if exp == 0x1F {
+        return f32::from_bits(sign | 0x7F800000 | (mant << 13)); // Inf/NaN
+    }
+    f32::from_bits(sign | ((exp + 127 - 15) << 23) | (mant << 13))
+}
+
+pub fn ImF32ToF16(v: f32) -> ImF16 {
+    let x: u32 = v.to_bits();
+    let sign: u16 = ((x >> 16) & 0x8000) as u16;
+    let exp: i32 = ((x >> 23) & 0xFF) as i32;
+    let mant: u32 = x & 0x7FFFFF;
+    if exp == 0xFF {
+        return sign | 0x7C00 | if mant != 0 { 0x200 } else { 0 }; // Inf/NaN (quiet)
+    }
+    let half_exp: i32 = exp - 127 + 15;
+    if half_exp >= 0x1F {
+        return sign | 0x7C00; // Overflow
+    }
+    let (mut half, shift): (u32, u32) = if half_exp <= 0 {
+        if half_exp < -10 {
+            return sign; // Below half the smallest subnormal
+        }
+        (0, (14 - half_exp) as u32) // Subnormal: implicit bit becomes explicit
+    } else {
+        ((half_exp as u32) << 10, 13)
+    };
+    let m: u32 = if half_exp <= 0 { mant | 0x800000 } else { mant };
+    half |= m >> shift;
+    let rem: u32 = m & ((1 << shift) - 1);
+    let halfway: u32 = 1 << (shift - 1);
+    if rem > halfway || (rem == halfway && (half & 1) != 0) {
+        half += 1; // May carry into the exponent, which is the correct result (up to Inf)
+    }
+    sign | half as u16
+}
+
+pub fn DataTypeIsExtended(data_type: ImGuiDataType) -> bool {
+    data_type == IM_GUI_DATA_TYPE_F16 || data_type == IM_GUI_DATA_TYPE_FIXED16_16 || data_type == IM_GUI_DATA_TYPE_S128 || data_type == IM_GUI_DATA_TYPE_U128
+}
+
+fn ReadF16(p: &[u8]) -> ImF16 { ImF16::from_ne_bytes([p[0], p[1]]) }
+fn ReadFixed(p: &[u8]) -> ImFixed16_16 { ImFixed16_16::from_ne_bytes(p[..4].try_into().unwrap()) }
+fn ReadS128(p: &[u8]) -> i128 { i128::from_ne_bytes(p[..16].try_into().unwrap()) }
+fn ReadU128(p: &[u8]) -> u128 { u128::from_ne_bytes(p[..16].try_into().unwrap()) }
+
+fn WriteF16(p: &mut [u8], v: ImF16) { p[..2].copy_from_slice(&v.to_ne_bytes()); }
+fn WriteFixed(p: &mut [u8], v: ImFixed16_16) { p[..4].copy_from_slice(&v.to_ne_bytes()); }
+fn WriteS128(p: &mut [u8], v: i128) { p[..16].copy_from_slice(&v.to_ne_bytes()); }
+fn WriteU128(p: &mut [u8], v: u128) { p[..16].copy_from_slice(&v.to_ne_bytes()); }
+
+// One printf conversion: "%[flags][width][.precision][length]conversion", with what surrounds it
+struct ImFormatSpec<'a> {
+    Prefix: &'a str,
+    Suffix: &'a str,
+    LeftAlign: bool,
+    ZeroPad: bool,
+    PlusSign: bool,
+    Width: usize,
+    Precision: Option<usize>,
+    Conversion: char,
+}
+
+fn ParseFormatSpec(format: &str) -> Option<ImFormatSpec<'_>> {
+    let bytes = format.as_bytes();
+    let mut start: usize = 0;
+    loop {
+        let percent = start + format[start..].find('%')?;
+        if bytes.get(percent + 1) == Some(&b'%') {
+            start = percent + 2;
+            continue;
+        }
+        start = percent;
+        break;
+    }
+    let mut spec = ImFormatSpec { Prefix: &format[..start], Suffix: "", LeftAlign: false, ZeroPad: false, PlusSign: false, Width: 0, Precision: None, Conversion: 'd' };
+    let mut p: usize = start + 1;
+    while p < bytes.len() && b"-+ #0'".contains(&bytes[p]) {
+        match bytes[p] {
+            b'-' => spec.LeftAlign = true,
+            b'0' => spec.ZeroPad = true,
+            b'+' => spec.PlusSign = true,
+            _ => {}
+        }
+        p += 1;
+    }
+    while p < bytes.len() && bytes[p].is_ascii_digit() {
+        spec.Width = spec.Width * 10 + (bytes[p] - b'0') as usize;
+        p += 1;
+    }
+    if p < bytes.len() && bytes[p] == b'.' {
+        p += 1;
+        let mut precision: usize = 0;
+        while p < bytes.len() && bytes[p].is_ascii_digit() {
+            precision = precision * 10 + (bytes[p] - b'0') as usize;
+            p += 1;
+        }
+        spec.Precision = Some(precision);
+    }
+    while p < bytes.len() && b"hlLqjzt".contains(&bytes[p]) {
+        p += 1;
+    }
+    let conversion = format[p..].chars().next()?;
+    spec.Conversion = conversion;
+    spec.Suffix = &format[p + conversion.len_utf8()..];
+    Some(spec)
+}
+
+fn FormatPad(spec: &ImFormatSpec, negative: bool, digits: String) -> String {
+    let sign: &str = if negative { "-" } else if spec.PlusSign { "+" } else { "" };
+    let len = sign.len() + digits.len();
+    let body = if spec.Width <= len {
+        format!("{}{}", sign, digits)
+    } else if spec.LeftAlign {
+        format!("{}{}{}", sign, digits, " ".repeat(spec.Width - len))
+    } else if spec.ZeroPad {
+        format!("{}{}{}", sign, "0".repeat(spec.Width - len), digits)
+    } else {
+        format!("{}{}{}", " ".repeat(spec.Width - len), sign, digits)
+    };
+    format!("{}{}{}", spec.Prefix.replace("%%", "%"), body, spec.Suffix.replace("%%", "%"))
+}
+
+// Exact decimal digits of a Q16.16 magnitude, rounded half up to 'precision' fractional digits
+fn FormatFixedMagnitude(raw_abs: u64, precision: usize) -> String {
+    let int_part: u64 = raw_abs >> 16;
+    let frac_digits: String = format!("{:016}", (raw_abs & 0xFFFF) * IM_FIXED16_16_DECIMAL_SCALE);
+    if precision >= 16 {
+        return format!("{}.{}{}", int_part, frac_digits, "0".repeat(precision - 16));
+    }
+    let mut kept: u64 = if precision > 0 { frac_digits[..precision].parse().unwrap() } else { 0 };
+    let mut int_part = int_part;
+    if frac_digits.as_bytes()[precision] >= b'5' {
+        kept += 1;
+        if kept == 10u64.pow(precision as u32) {
+            kept = 0;
+            int_part += 1;
+        }
+    }
+    if precision == 0 {
+        return format!("{}", int_part);
+    }
+    format!("{}.{:0width$}", int_part, kept, width = precision)
+}
+
+fn FormatFloat(spec: &ImFormatSpec, v: f64) -> String {
+    let precision = spec.Precision.unwrap_or(6);
+    let digits = match spec.Conversion {
+        'e' => format!("{:.*e}", precision, v.abs()),
+        'E' => format!("{:.*E}", precision, v.abs()),
+        'g' | 'G' => format!("{}", v.abs()),
+        _ => format!("{:.*}", precision, v.abs()),
+    };
+    FormatPad(spec, v.is_sign_negative() && v != 0.0, digits)
+}
+
+fn FormatInteger(spec: &ImFormatSpec, negative: bool, magnitude: u128) -> String {
+    let mut digits = match spec.Conversion {
+        'x' => format!("{:x}", magnitude),
+        'X' => format!("{:X}", magnitude),
+        'o' => format!("{:o}", magnitude),
+        _ => format!("{}", magnitude),
+    };
+    if let Some(precision) = spec.Precision {
+        if digits.len() < precision {
+            digits = format!("{}{}", "0".repeat(precision - digits.len()), digits);
+        }
+    }
+    FormatPad(spec, negative, digits)
+}
+
+// printf-style formatting of a f64 with the same conversions, for values which aren't stored as such (e.g. scaled by a unit).
+// Integer conversions print non-integral values with 2 decimals rather than truncating them.
+pub fn ImFormatDouble(format: &str, v: f64) -> String {
+    let spec = match ParseFormatSpec(format) {
+        Some(spec) => spec,
+        None => return String::from(format),
+    };
+    if "fFeEgG".contains(spec.Conversion) || !v.is_finite() {
+        return FormatFloat(&spec, v);
+    }
+    if v.fract() != 0.0 {
+        return FormatFloat(&ImFormatSpec { Precision: Some(2), Conversion: 'f', ..spec }, v);
+    }
+    FormatInteger(&spec, v < 0.0, v.abs() as u128)
+}
+
+// Same as ImFormatDouble(format, v / den) for integers (e.g. a byte count displayed in KiB), without going through a f64.
+// None for conversions other than %d %i %u %f %F, which can be left to ImFormatDouble().
+pub fn ImFormatQuotient(format: &str, v: i128, den: i128) -> Option<String> {
+    let spec = ParseFormatSpec(format)?;
+    if den <= 0 {
+        return None;
+    }
+    let (q, r) = (v.unsigned_abs() / den as u128, v.unsigned_abs() % den as u128);
+    let precision: usize = match spec.Conversion {
+        'd' | 'i' | 'u' if r == 0 => return Some(FormatInteger(&spec, v < 0, q)),
+        'd' | 'i' | 'u' => 2,
+        'f' | 'F' => spec.Precision.unwrap_or(6),
+        _ => return None,
+    };
+    // Fractional digits rounded half up, carrying into the integer part
+    let pow: u128 = 10u128.checked_pow(precision as u32)?;
+    let mut int_part: u128 = q;
+    let mut kept: u128 = (r.checked_mul(pow)? + den as u128 / 2) / den as u128;
+    if kept == pow {
+        kept = 0;
+        int_part += 1;
+    }
+    let digits = if precision == 0 { format!("{}", int_part) } else { format!("{}.{:0width$}", int_part, kept, width = precision) };
+    Some(FormatPad(&spec, v < 0, digits))
+}
+
+// 'format' is a printf format holding one conversion. Float conversions of integers and integer conversions of f16/fixed point values are accepted (the value is rounded).
+pub fn DataTypeExtendedFormat(data_type: ImGuiDataType, p_data: &[u8], format: &str) -> String {
+    let spec = match ParseFormatSpec(format) {
+        Some(spec) => spec,
+        None => return String::from(format), // No conversion: only decorations are displayed
+    };
+    let is_float_conversion = "fFeEgG".contains(spec.Conversion);
+    match data_type {
+        IM_GUI_DATA_TYPE_F16 => {
+            let v = ImF16ToF32(ReadF16(p_data)) as f64;
+            if is_float_conversion || !v.is_finite() {
+                FormatFloat(&spec, v)
+            } else {
+                FormatInteger(&spec, v < 0.0, v.abs().round() as u128)
+            }
+        }
+        IM_GUI_DATA_TYPE_FIXED16_16 => {
+            let raw = ReadFixed(p_data) as i64;
+            if spec.Conversion == 'f' || spec.Conversion == 'F' {
+                let magnitude = FormatFixedMagnitude(raw.unsigned_abs(), spec.Precision.unwrap_or(6));
+                FormatPad(&spec, raw < 0 && magnitude.bytes().any(|c| c != b'0' && c != b'.'), magnitude)
+            } else if is_float_conversion {
+                FormatFloat(&spec, raw as f64 / IM_FIXED16_16_ONE as f64) // Exact in a f64
+            } else {
+                FormatInteger(&spec, raw < 0, ((raw.unsigned_abs() + 0x8000) >> 16) as u128)
+            }
+        }
+        IM_GUI_DATA_TYPE_S128 => {
+            let v = ReadS128(p_data);
+            if spec.Conversion == 'x' || spec.Conversion == 'X' || spec.Conversion == 'o' {
+                FormatInteger(&spec, false, v as u128) // Two's complement, like printf does for negative values
+            } else {
+                FormatInteger(&spec, v < 0, v.unsigned_abs())
+            }
+        }
+        IM_GUI_DATA_TYPE_U128 => FormatInteger(&spec, false, ReadU128(p_data)),
+        _ => {
+            // IM_ASSERT(0);
+            String::new()
+        }
+    }
+}
+
+// Q16.16 from decimal text without going through a float: "-12.5" is exactly -819200 raw. Saturates.
+fn ParseFixed(text: &str) -> Option<ImFixed16_16> {
+    let (negative, digits) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    if digits.contains(|c| c == 'e' || c == 'E') {
+        let v: f64 = text.parse().ok()?;
+        return Some((v * IM_FIXED16_16_ONE as f64).round().clamp(ImFixed16_16::MIN as f64, ImFixed16_16::MAX as f64) as ImFixed16_16);
+    }
+    let (int_digits, frac_digits) = digits.split_once('.').unwrap_or((digits, ""));
+    if (int_digits.is_empty() && frac_digits.is_empty()) || !int_digits.bytes().chain(frac_digits.bytes()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let int_part: i64 = if int_digits.is_empty() { 0 } else { int_digits.parse::<u64>().map(|v| v.min(1 << 32) as i64).unwrap_or(1 << 32) };
+    // Round the fraction to the nearest 1/65536 (19 digits are more than enough to decide)
+    let frac_digits = &frac_digits[..frac_digits.len().min(19)];
+    let frac: u128 = if frac_digits.is_empty() { 0 } else { frac_digits.parse().unwrap() };
+    let frac_scale: u128 = 10u128.pow(frac_digits.len() as u32);
+    let frac_raw: i64 = ((frac * IM_FIXED16_16_ONE as u128 * 2 + frac_scale) / (frac_scale * 2)) as i64;
+    let raw: i64 = int_part * IM_FIXED16_16_ONE + frac_raw;
+    Some((if negative { -raw } else { raw }).clamp(ImFixed16_16::MIN as i64, ImFixed16_16::MAX as i64) as ImFixed16_16)
+}
+
+fn ParseInteger(text: &str, hexadecimal: bool) -> Option<(bool, u128)> {
+    let (negative, digits) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    let magnitude = if hexadecimal {
+        u128::from_str_radix(digits.trim_start_matches("0x").trim_start_matches("0X"), 16).ok()?
+    } else {
+        digits.parse::<u128>().ok()?
+    };
+    Some((negative, magnitude))
+}
+
+// Returns false when the text can't be read as a value of the type: 'p_data' is then left untouched.
+pub fn DataTypeExtendedParse(data_type: ImGuiDataType, buf: &str, p_data: &mut [u8], format: &str) -> bool {
+    let text = buf.trim();
+    if text.is_empty() {
+        return false;
+    }
+    let hexadecimal = ParseFormatSpec(format).map_or(false, |spec| spec.Conversion == 'x' || spec.Conversion == 'X');
+    match data_type {
+        IM_GUI_DATA_TYPE_F16 => match text.parse::<f32>() {
+            Ok(v) => WriteF16(p_data, ImF32ToF16(v)),
+            Err(_) => return false,
+        },
+        IM_GUI_DATA_TYPE_FIXED16_16 => match ParseFixed(text) {
+            Some(v) => WriteFixed(p_data, v),
+            None => return false,
+        },
+        IM_GUI_DATA_TYPE_S128 => match ParseInteger(text, hexadecimal) {
+            Some((false, magnitude)) => WriteS128(p_data, magnitude.min(i128::MAX as u128) as i128),
+            Some((true, magnitude)) => WriteS128(p_data, if magnitude > i128::MAX as u128 { i128::MIN } else { -(magnitude as i128) }),
+            None => return false,
+        },
+        IM_GUI_DATA_TYPE_U128 => match ParseInteger(text, hexadecimal) {
+            Some((negative, magnitude)) => WriteU128(p_data, if negative { 0 } else { magnitude }),
+            None => return false,
+        },
+        _ => return false,
+    }
+    true
+}
+
+pub fn DataTypeExtendedCompare(data_type: ImGuiDataType, lhs: &[u8], rhs: &[u8]) -> c_int {
+    let ordering = match data_type {
+        IM_GUI_DATA_TYPE_F16 => ImF16ToF32(ReadF16(lhs)).partial_cmp(&ImF16ToF32(ReadF16(rhs))),
+        IM_GUI_DATA_TYPE_FIXED16_16 => Some(ReadFixed(lhs).cmp(&ReadFixed(rhs))),
+        IM_GUI_DATA_TYPE_S128 => Some(ReadS128(lhs).cmp(&ReadS128(rhs))),
+        IM_GUI_DATA_TYPE_U128 => Some(ReadU128(lhs).cmp(&ReadU128(rhs))),
+        _ => None,
+    };
+    ordering.map_or(0, |ordering| ordering as c_int)
+}
+
+// Clamp, both sides are optional, return true if modified
+pub fn DataTypeExtendedClamp(data_type: ImGuiDataType, p_data: &mut [u8], p_min: Option<&[u8]>, p_max: Option<&[u8]>) -> bool {
+    if let Some(p_min) = p_min {
+        if DataTypeExtendedCompare(data_type, p_data, p_min) < 0 {
+            p_data.copy_from_slice(&p_min[..p_data.len()]);
+            return true;
+        }
+    }
+    if let Some(p_max) = p_max {
+        if DataTypeExtendedCompare(data_type, p_data, p_max) > 0 {
+            p_data.copy_from_slice(&p_max[..p_data.len()]);
+            return true;
+        }
+    }
+    false
+}
+
+pub fn DataTypeExtendedApplyOp(data_type: ImGuiDataType, op: DataTypeOperation, output: &mut [u8], arg1: &[u8], arg2: &[u8]) {
+    // IM_ASSERT(op == DATA_TYPE_OPERATION_ADD || op == DATA_TYPE_OPERATION_SUB);
+    let sub = op == DATA_TYPE_OPERATION_SUB;
+    match data_type {
+        IM_GUI_DATA_TYPE_F16 => {
+            let (a, b) = (ImF16ToF32(ReadF16(arg1)), ImF16ToF32(ReadF16(arg2)));
+            WriteF16(output, ImF32ToF16(if sub { a - b } else { a + b })); // Exact in f32, so only rounded once
+        }
+        IM_GUI_DATA_TYPE_FIXED16_16 => {
+            let (a, b) = (ReadFixed(arg1), ReadFixed(arg2));
+            WriteFixed(output, if sub { a.saturating_sub(b) } else { a.saturating_add(b) });
+        }
+        IM_GUI_DATA_TYPE_S128 => {
+            let (a, b) = (ReadS128(arg1), ReadS128(arg2));
+            WriteS128(output, if sub { a.saturating_sub(b) } else { a.saturating_add(b) });
+        }
+        IM_GUI_DATA_TYPE_U128 => {
+            let (a, b) = (ReadU128(arg1), ReadU128(arg2));
+            WriteU128(output, if sub { a.saturating_sub(b) } else { a.saturating_add(b) });
+        }
+        _ => {} // IM_ASSERT(0);
+    }
+}
+
+// For values computed in f64 (e.g. typed with a unit suffix). Integers are rounded, out of range values saturate.
+pub fn DataTypeExtendedFromDouble(data_type: ImGuiDataType, v: f64, p_data: &mut [u8]) {
+    match data_type {
+        IM_GUI_DATA_TYPE_F16 => WriteF16(p_data, ImF32ToF16(v as f32)),
+        IM_GUI_DATA_TYPE_FIXED16_16 => WriteFixed(p_data, (v * IM_FIXED16_16_ONE as f64).round().clamp(ImFixed16_16::MIN as f64, ImFixed16_16::MAX as f64) as ImFixed16_16),
+        IM_GUI_DATA_TYPE_S128 => WriteS128(p_data, v.round() as i128),
+        IM_GUI_DATA_TYPE_U128 => WriteU128(p_data, v.round() as u128),
+        _ => {} // IM_ASSERT(0);
+    }
+}
+
+// For display purposes (e.g. unit scaling): 128-bit integers lose precision past 2^53
+pub fn DataTypeExtendedToDouble(data_type: ImGuiDataType, p_data: &[u8]) -> f64 {
+    match data_type {
+        IM_GUI_DATA_TYPE_F16 => ImF16ToF32(ReadF16(p_data)) as f64,
+        IM_GUI_DATA_TYPE_FIXED16_16 => ReadFixed(p_data) as f64 / IM_FIXED16_16_ONE as f64,
+        IM_GUI_DATA_TYPE_S128 => ReadS128(p_data) as f64,
+        IM_GUI_DATA_TYPE_U128 => ReadU128(p_data) as f64,
+        _ => 0.0,
+    }
+}
+
+// Signed distance from 'a' to 'b', computed in the type and only then converted
+pub fn DataTypeExtendedDistance(data_type: ImGuiDataType, a: &[u8], b: &[u8]) -> f64 {
+    match data_type {
+        IM_GUI_DATA_TYPE_F16 => ImF16ToF32(ReadF16(b)) as f64 - ImF16ToF32(ReadF16(a)) as f64,
+        IM_GUI_DATA_TYPE_FIXED16_16 => (ReadFixed(b) as f64 - ReadFixed(a) as f64) / IM_FIXED16_16_ONE as f64,
+        IM_GUI_DATA_TYPE_S128 => {
+            let (a, b) = (ReadS128(a), ReadS128(b));
+            if b >= a { b.wrapping_sub(a) as u128 as f64 } else { -(a.wrapping_sub(b) as u128 as f64) }
+        }
+        IM_GUI_DATA_TYPE_U128 => {
+            let (a, b) = (ReadU128(a), ReadU128(b));
+            if b >= a { (b - a) as f64 } else { -((a - b) as f64) }
+        }
+        _ => 0.0,
+    }
+}
+
+// Position of the value between 'p_min' and 'p_max' (which may be reversed), in 0.0..1.0
+pub fn DataTypeExtendedGetRatio(data_type: ImGuiDataType, p_data: &[u8], p_min: &[u8], p_max: &[u8]) -> c_float {
+    let range = DataTypeExtendedDistance(data_type, p_min, p_max);
+    if range == 0.0 || range.is_nan() {
+        return 0.0;
+    }
+    (DataTypeExtendedDistance(data_type, p_min, p_data) / range).clamp(0.0, 1.0) as c_float
+}
+
+// Value at 't' between 'p_min' and 'p_max'. The ends of the range are reached exactly, integers are rounded.
+pub fn DataTypeExtendedSetFromRatio(data_type: ImGuiDataType, t: c_float, p_min: &[u8], p_max: &[u8], p_data: &mut [u8]) {
+    let t = (t as f64).clamp(0.0, 1.0);
+    if t <= 0.0 || t >= 1.0 {
+        p_data.copy_from_slice(&(if t <= 0.0 { p_min } else { p_max })[..p_data.len()]);
+        return;
+    }
+    match data_type {
+        IM_GUI_DATA_TYPE_F16 => {
+            let (a, b) = (ImF16ToF32(ReadF16(p_min)) as f64, ImF16ToF32(ReadF16(p_max)) as f64);
+            WriteF16(p_data, ImF32ToF16((a + (b - a) * t) as f32));
+        }
+        IM_GUI_DATA_TYPE_FIXED16_16 => {
+            let (a, b) = (ReadFixed(p_min) as f64, ReadFixed(p_max) as f64);
+            WriteFixed(p_data, (a + (b - a) * t).round() as ImFixed16_16);
+        }
+        IM_GUI_DATA_TYPE_S128 | IM_GUI_DATA_TYPE_U128 => {
+            // Offset from the min in the unsigned domain: two's complement wrapping lands back in range
+            let (a, b) = (ReadU128(p_min), ReadU128(p_max));
+            let reversed = DataTypeExtendedCompare(data_type, p_min, p_max) > 0;
+            let range: u128 = if reversed { a.wrapping_sub(b) } else { b.wrapping_sub(a) };
+            let offset: u128 = ((range as f64 * t).round() as u128).min(range);
+            WriteU128(p_data, if reversed { a.wrapping_sub(offset) } else { a.wrapping_add(offset) });
+        }
+        _ => {} // IM_ASSERT(0);
+    }
+}
+
+// Apply as much of 'accum' (in value units) as the type can represent, and leave the rest in it so slow drags still add up.
+// When clamped ('p_min' < 'p_max'), a value already past a limit isn't pulled back while pushing outward.
+pub fn DataTypeExtendedDragApply(data_type: ImGuiDataType, p_data: &mut [u8], accum: &mut c_float, p_min: Option<&[u8]>, p_max: Option<&[u8]>) -> bool {
+    let is_clamped = match (p_min, p_max) {
+        (Some(p_min), Some(p_max)) => DataTypeExtendedCompare(data_type, p_min, p_max) < 0,
+        _ => false,
+    };
+    if is_clamped && ((*accum > 0.0 && DataTypeExtendedCompare(data_type, p_data, p_max.unwrap()) >= 0) || (*accum < 0.0 && DataTypeExtendedCompare(data_type, p_data, p_min.unwrap()) <= 0)) {
+        *accum = 0.0;
+        return false;
+    }
+
+    let mut v_new: [u8; 16] = [0; 16];
+    let v_new = &mut v_new[..p_data.len()];
+    match data_type {
+        IM_GUI_DATA_TYPE_F16 => {
+            let v_cur = ImF16ToF32(ReadF16(p_data));
+            let h_new = ImF32ToF16(v_cur + *accum);
+            if h_new == ReadF16(p_data) {
+                return false; // Below half a step at this magnitude: keep accumulating
+            }
+            *accum -= ImF16ToF32(h_new) - v_cur;
+            WriteF16(v_new, h_new);
+        }
+        IM_GUI_DATA_TYPE_FIXED16_16 => {
+            let step_raw = (*accum as f64 * IM_FIXED16_16_ONE as f64).trunc();
+            if step_raw == 0.0 {
+                return false;
+            }
+            *accum -= (step_raw / IM_FIXED16_16_ONE as f64) as c_float;
+            WriteFixed(v_new, ReadFixed(p_data).saturating_add(step_raw.clamp(i32::MIN as f64, i32::MAX as f64) as i32));
+        }
+        IM_GUI_DATA_TYPE_S128 | IM_GUI_DATA_TYPE_U128 => {
+            let step = accum.trunc();
+            if step == 0.0 {
+                return false;
+            }
+            *accum -= step;
+            if data_type == IM_GUI_DATA_TYPE_S128 {
+                WriteS128(v_new, ReadS128(p_data).saturating_add(step as i128));
+            } else if step > 0.0 {
+                WriteU128(v_new, ReadU128(p_data).saturating_add(step as u128));
+            } else {
+                WriteU128(v_new, ReadU128(p_data).saturating_sub((-step) as u128));
+            }
+        }
+        _ => return false,
+    }
+    if is_clamped {
+        DataTypeExtendedClamp(data_type, v_new, p_min, p_max);
+    }
+    if v_new == p_data {
+        return false;
+    }
+    p_data.copy_from_slice(v_new);
+    true
+}
@@ -1,5 +1,7 @@
 use libc::{c_char, size_t};
 use std::mem;
+use crate::core::type_defs::ImguiHandle;
+use crate::data_type::{ImF16, ImFixed16_16};
 
 // Type information associated to one ImGuiDataType. Retrieve with DataTypeGetInfo().
 #[derive(Default, Debug, Clone, Copy)]
@@ -14,7 +16,7 @@ pub struct ImGuiDataTypeInfo {
     pub ScanFmt: String,
 }
 
-pub const GDATA_TYPE_INFO: [ImGuiDataTypeInfo; 10] = [
+pub const GDATA_TYPE_INFO: [ImGuiDataTypeInfo; 17] = [
     ImGuiDataTypeInfo {
         Size: mem::size_of::<i8>(),
         Name: String::from("i8"),
@@ -79,4 +81,47 @@ pub const GDATA_TYPE_INFO: [ImGuiDataTypeInfo; 10] = [
         PrintFmt: String::from("{}"),
         ScanFmt: String::from("%lf"),
     },
+    // Not scalar types: listed so the types which follow keep their index
+    ImGuiDataTypeInfo {
+        Size: mem::size_of::<*const c_char>(),
+        Name: String::from("string"),
+        PrintFmt: String::from("%s"),
+        ScanFmt: String::from("%s"),
+    }, // IM_GUI_DATA_TYPE_STRING
+    ImGuiDataTypeInfo {
+        Size: mem::size_of::<*const c_char>(),
+        Name: String::from("pointer"),
+        PrintFmt: String::from("%p"),
+        ScanFmt: String::from("%p"),
+    }, // IM_GUI_DATA_TYPE_POINTER
+    ImGuiDataTypeInfo {
+        Size: mem::size_of::<ImguiHandle>(),
+        Name: String::from("id"),
+        PrintFmt: String::from("0x%08X"),
+        ScanFmt: String::from("%X"),
+    }, // IM_GUI_DATA_TYPE_ID
+    ImGuiDataTypeInfo {
+        Size: mem::size_of::<ImF16>(),
+        Name: String::from("f16"),
+        PrintFmt: String::from("%.3f"),
+        ScanFmt: String::from("%f"),
+    }, // IM_GUI_DATA_TYPE_F16
+    ImGuiDataTypeInfo {
+        Size: mem::size_of::<ImFixed16_16>(),
+        Name: String::from("q16.16"),
+        PrintFmt: String::from("%.4f"),
+        ScanFmt: String::from("%f"),
+    }, // IM_GUI_DATA_TYPE_FIXED16_16
+    ImGuiDataTypeInfo {
+        Size: mem::size_of::<i128>(),
+        Name: String::from("i128"),
+        PrintFmt: String::from("%d"),
+        ScanFmt: String::from("%d"),
+    }, // IM_GUI_DATA_TYPE_S128
+    ImGuiDataTypeInfo {
+        Size: mem::size_of::<u128>(),
+        Name: String::from("u128"),
+        PrintFmt: String::from("%u"),
+        ScanFmt: String::from("%u"),
+    }, // IM_GUI_DATA_TYPE_U128
 ];
@@ -2,8 +2,13 @@ use crate::data_type::{
     ImGuiDataType, IM_GUI_DATA_TYPE_COUNT, IM_GUI_DATA_TYPE_DOUBLE, IM_GUI_DATA_TYPE_FLOAT,
     IM_GUI_DATA_TYPE_S16, IM_GUI_DATA_TYPE_S32, IM_GUI_DATA_TYPE_S64, IM_GUI_DATA_TYPE_S8,
     IM_GUI_DATA_TYPE_U16, IM_GUI_DATA_TYPE_U32, IM_GUI_DATA_TYPE_U64, IM_GUI_DATA_TYPE_U8,
+    IM_GUI_DATA_TYPE_F16, IM_GUI_DATA_TYPE_FIXED16_16, IM_GUI_DATA_TYPE_S128, IM_GUI_DATA_TYPE_U128,
 };
 use crate::data_type_info::{ImGuiDataTypeInfo, GDATA_TYPE_INFO};
+use crate::data_type::data_type_extended_ops::{DataTypeExtendedApplyOp, DataTypeExtendedClamp, DataTypeExtendedCompare, DataTypeExtendedFormat, DataTypeExtendedFromDouble, DataTypeExtendedParse, DataTypeExtendedToDouble, DataTypeIsExtended};
+use crate::data_type::data_type_scalar::{ImGuiScalar, ImScalarBytes, ImScalarBytesMut};
+use crate::widgets::unit::ImGuiUnit;
+use crate::widgets::unit_ops::{UnitFormat, UnitFormatInteger, UnitParse, UnitParseInteger};
 use crate::core::math_ops::{ImAddClampOverflow, ImSubClampOverflow};
 use libc::{c_float, c_int};
 
@@ -12,13 +17,62 @@ pub fn data_type_info(data_type: ImGuiDataType) -> ImGuiDataTypeInfo {
     return GDATA_TYPE_INFO[data_type].clone();
 }
 
-pub fn DataTypeFormatString(
+pub fn DataTypeIsInteger(data_type: ImGuiDataType) -> bool {
+    (data_type >= IM_GUI_DATA_TYPE_S8 && data_type <= IM_GUI_DATA_TYPE_U64) || data_type == IM_GUI_DATA_TYPE_S128 || data_type == IM_GUI_DATA_TYPE_U128
+}
+
+// Value behind a scalar widget's 'p_data' for integer types, exactly. None for the other types and for u128 values above i128::MAX.
+pub fn DataTypeToInteger<T: ImGuiScalar>(data_type: ImGuiDataType, p_data: &T) -> Option<i128> {
+    if !DataTypeIsInteger(data_type) {
+        return None;
+    }
+    p_data.ToInteger()
+}
+
+// Value behind a scalar widget's 'p_data' as a f64, e.g. to scale it to a unit
+pub fn DataTypeToDouble<T: ImGuiScalar>(data_type: ImGuiDataType, p_data: &T) -> f64 {
+    if DataTypeIsExtended(data_type) {
+        return DataTypeExtendedToDouble(data_type, ImScalarBytes(p_data));
+    }
+    match DataTypeToInteger(data_type, p_data) {
+        Some(v) => v as f64,
+        None => p_data.ToFloat() as f64,
+    }
+}
+
+// Integer types are rounded, out of range values saturate
+pub fn DataTypeFromDouble<T: ImGuiScalar>(data_type: ImGuiDataType, v: f64, p_data: &mut T) {
+    if DataTypeIsExtended(data_type) {
+        DataTypeExtendedFromDouble(data_type, v, ImScalarBytesMut(p_data));
+        return;
+    }
+    if DataTypeIsInteger(data_type) {
+        *p_data = T::FromInteger(v.round() as i128);
+        return;
+    }
+    *p_data = T::FromFloat(v as c_float);
+}
+
+// With a unit (see SetNextItemUnit()), 'format' applies to the scaled value and the unit suffix is appended
+pub fn DataTypeFormatString<T: ImGuiScalar>(
     buf: &mut String,
     buf_size: usize,
     data_type: ImGuiDataType,
-    p_data: c_float,
+    p_data: &T,
     format: &String,
+    unit: Option<&ImGuiUnit>,
 ) -> usize {
+    if let Some(unit) = unit {
+        *buf = match DataTypeToInteger(data_type, p_data) {
+            Some(v) => UnitFormatInteger(unit, v, format),
+            None => UnitFormat(unit, DataTypeToDouble(data_type, p_data), format),
+        };
+        return buf.len();
+    }
+    if DataTypeIsExtended(data_type) {
+        *buf = DataTypeExtendedFormat(data_type, ImScalarBytes(p_data), format);
+        return buf.len();
+    }
     todo!();
     // Signedness doesn't matter when pushing integer arguments
     if data_type == IM_GUI_DATA_TYPE_S32 || data_type == IM_GUI_DATA_TYPE_U32 {
@@ -54,7 +108,7 @@ pub type DataTypeOperation = c_int;
 pub const DATA_TYPE_OPERATION_ADD: DataTypeOperation = 0;
 pub const DATA_TYPE_OPERATION_SUB: DataTypeOperation = 1;
 
-pub unsafe fn DataTypeApplyOp<T>(
+pub unsafe fn DataTypeApplyOp<T: ImGuiScalar>(
     data_type: ImGuiDataType,
     op: DataTypeOperation,
     output: &mut T,
@@ -152,6 +206,10 @@ pub unsafe fn DataTypeApplyOp<T>(
             }
             return;
         }
+        IM_GUI_DATA_TYPE_F16 | IM_GUI_DATA_TYPE_FIXED16_16 | IM_GUI_DATA_TYPE_S128 | IM_GUI_DATA_TYPE_U128 => {
+            DataTypeExtendedApplyOp(data_type, op, ImScalarBytesMut(output), ImScalarBytes(arg1), ImScalarBytes(arg2));
+            return;
+        }
         IM_GUI_DATA_TYPE_COUNT => {}
         _ => {}
     }
@@ -160,12 +218,32 @@ pub unsafe fn DataTypeApplyOp<T>(
 
 // User can input math operators (e.g. +100) to edit a numerical values.
 // NB: This is _not_ a full expression evaluator. We should probably add one and replace this dumb mess..
-pub unsafe fn DataTypeApplyFromText(
+pub unsafe fn DataTypeApplyFromText<T: ImGuiScalar>(
     buf: &str,
     data_type: ImGuiDataType,
-    p_data: &mut c_float,
+    p_data: &mut T,
     format: &str,
+    unit: Option<&ImGuiUnit>,
 ) -> bool {
+    // With a unit the text may hold a suffix ("90deg", "2MiB"): converted to the storage unit
+    // Integers are converted in i128 when that can be done exactly (see UnitParseInteger())
+    if let Some(unit) = unit {
+        let data_backup: T = *p_data;
+        match DataTypeToInteger(data_type, p_data).and_then(|v_current| UnitParseInteger(unit, buf, v_current)) {
+            Some(v) => *p_data = T::FromInteger(v),
+            None => match UnitParse(unit, buf, DataTypeToDouble(data_type, p_data)) {
+                Some(v) => DataTypeFromDouble(data_type, v, p_data),
+                None => return false,
+            },
+        }
+        return ImScalarBytes(p_data) != ImScalarBytes(&data_backup);
+    }
+
+    if DataTypeIsExtended(data_type) {
+        let data_backup: T = *p_data;
+        return DataTypeExtendedParse(data_type, buf, ImScalarBytesMut(p_data), format) && ImScalarBytes(p_data) != ImScalarBytes(&data_backup);
+    }
+
     // while (ImCharIsBlankA(*buf))
     //     buf+= 1;
     // if !buf[0] { return  false; }
@@ -218,7 +296,7 @@ pub fn DataTypeCompareT<T>(lhs: &T, rhs: &T) -> c_int {
     return 0;
 }
 
-pub fn DataTypeCompare<T>(data_type: ImGuiDataType, arg_1: &T, arg_2: &T) -> c_int {
+pub fn DataTypeCompare<T: ImGuiScalar>(data_type: ImGuiDataType, arg_1: &T, arg_2: &T) -> c_int {
     // switch (data_type)
     // {
     // IM_GUI_DATA_TYPE_S8 =>     return DataTypeCompareT<i8  >((*const i8  )arg_1, (*const i8  )arg_2);
@@ -235,6 +313,9 @@ pub fn DataTypeCompare<T>(data_type: ImGuiDataType, arg_1: &T, arg_2: &T) -> c_i
     // }
     // // IM_ASSERT(0);
     // return 0;
+    if DataTypeIsExtended(data_type) {
+        return DataTypeExtendedCompare(data_type, ImScalarBytes(arg_1), ImScalarBytes(arg_2));
+    }
     DataTypeCompareT(arg_1, arg_2)
 }
 
@@ -255,7 +336,7 @@ pub unsafe fn DataTypeClampT<T>(v: &mut T, v_min: &T, v_max: &T) -> bool {
     return false;
 }
 
-pub unsafe fn DataTypeClamp<T>(
+pub unsafe fn DataTypeClamp<T: ImGuiScalar>(
     data_type: ImGuiDataType,
     p_data: &mut T,
     p_min: &T,
@@ -277,6 +358,9 @@ pub unsafe fn DataTypeClamp<T>(
     // }
     // // IM_ASSERT(0);
     // return false;
+    if DataTypeIsExtended(data_type) {
+        return DataTypeExtendedClamp(data_type, ImScalarBytesMut(p_data), Some(ImScalarBytes(p_min)), Some(ImScalarBytes(p_max)));
+    }
     DataTypeClampT(p_data, p_min, p_max)
 }
 
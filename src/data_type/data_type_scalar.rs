@@ -0,0 +1,111 @@
+#![allow(non_snake_case)]
+
+use std::{mem, slice};
+use libc::c_float;
+
+// Types of the values edited by DragScalar(), SliderScalar(), InputScalar() and friends, and of their bounds and steps.
+// The type must match 'data_type' in size: e.g. c_float for IM_GUI_DATA_TYPE_FLOAT, ImF16 for IM_GUI_DATA_TYPE_F16,
+// ImFixed16_16 for IM_GUI_DATA_TYPE_FIXED16_16, i128 for IM_GUI_DATA_TYPE_S128.
+// Safety: implemented only for primitive integers and floats, which have no padding and for which any bit pattern is valid.
+pub unsafe trait ImGuiScalar: Copy + Default + PartialOrd {
+    // DragBehaviorT()/SliderBehaviorT() run on a c_float for the standard types
+    fn ToFloat(self) -> c_float;
+    fn FromFloat(v: c_float) -> Self;
+    // Integers convert exactly through i128 (e.g. for unit scaling). None for floats, and for u128 values above i128::MAX.
+    fn ToInteger(self) -> Option<i128>;
+    // Saturates to the range of the type
+    fn FromInteger(v: i128) -> Self;
+}
+
+unsafe impl ImGuiScalar for i8 {
+    fn ToFloat(self) -> c_float { self as c_float }
+    fn FromFloat(v: c_float) -> Self { v as Self }
+    fn ToInteger(self) -> Option<i128> { i128::try_from(self).ok() }
+    fn FromInteger(v: i128) -> Self { v.clamp(i8::MIN as i128, i8::MAX as i128) as i8 }
+}
+
+unsafe impl ImGuiScalar for u8 {
+    fn ToFloat(self) -> c_float { self as c_float }
+    fn FromFloat(v: c_float) -> Self { v as Self }
+    fn ToInteger(self) -> Option<i128> { i128::try_from(self).ok() }
+    fn FromInteger(v: i128) -> Self { v.clamp(0, u8::MAX as i128) as u8 }
+}
+
+unsafe impl ImGuiScalar for i16 {
+    fn ToFloat(self) -> c_float { self as c_float }
+    fn FromFloat(v: c_float) -> Self { v as Self }
+    fn ToInteger(self) -> Option<i128> { i128::try_from(self).ok() }
+    fn FromInteger(v: i128) -> Self { v.clamp(i16::MIN as i128, i16::MAX as i128) as i16 }
+}
+
+unsafe impl ImGuiScalar for u16 {
+    fn ToFloat(self) -> c_float { self as c_float }
+    fn FromFloat(v: c_float) -> Self { v as Self }
+    fn ToInteger(self) -> Option<i128> { i128::try_from(self).ok() }
+    fn FromInteger(v: i128) -> Self { v.clamp(0, u16::MAX as i128) as u16 }
+}
+
+unsafe impl ImGuiScalar for i32 {
+    fn ToFloat(self) -> c_float { self as c_float }
+    fn FromFloat(v: c_float) -> Self { v as Self }
+    fn ToInteger(self) -> Option<i128> { i128::try_from(self).ok() }
+    fn FromInteger(v: i128) -> Self { v.clamp(i32::MIN as i128, i32::MAX as i128) as i32 }
+}
+
+unsafe impl ImGuiScalar for u32 {
+    fn ToFloat(self) -> c_float { self as c_float }
+    fn FromFloat(v: c_float) -> Self { v as Self }
+    fn ToInteger(self) -> Option<i128> { i128::try_from(self).ok() }
+    fn FromInteger(v: i128) -> Self { v.clamp(0, u32::MAX as i128) as u32 }
+}
+
+unsafe impl ImGuiScalar for i64 {
+    fn ToFloat(self) -> c_float { self as c_float }
+    fn FromFloat(v: c_float) -> Self { v as Self }
+    fn ToInteger(self) -> Option<i128> { i128::try_from(self).ok() }
+    fn FromInteger(v: i128) -> Self { v.clamp(i64::MIN as i128, i64::MAX as i128) as i64 }
+}
+
+unsafe impl ImGuiScalar for u64 {
+    fn ToFloat(self) -> c_float { self as c_float }
+    fn FromFloat(v: c_float) -> Self { v as Self }
+    fn ToInteger(self) -> Option<i128> { i128::try_from(self).ok() }
+    fn FromInteger(v: i128) -> Self { v.clamp(0, u64::MAX as i128) as u64 }
+}
+
+unsafe impl ImGuiScalar for i128 {
+    fn ToFloat(self) -> c_float { self as c_float }
+    fn FromFloat(v: c_float) -> Self { v as Self }
+    fn ToInteger(self) -> Option<i128> { i128::try_from(self).ok() }
+    fn FromInteger(v: i128) -> Self { v }
+}
+
+unsafe impl ImGuiScalar for u128 {
+    fn ToFloat(self) -> c_float { self as c_float }
+    fn FromFloat(v: c_float) -> Self { v as Self }
+    fn ToInteger(self) -> Option<i128> { i128::try_from(self).ok() }
+    fn FromInteger(v: i128) -> Self { v.max(0) as u128 }
+}
+
+unsafe impl ImGuiScalar for f32 {
+    fn ToFloat(self) -> c_float { self }
+    fn FromFloat(v: c_float) -> Self { v }
+    fn ToInteger(self) -> Option<i128> { None }
+    fn FromInteger(v: i128) -> Self { v as Self }
+}
+
+unsafe impl ImGuiScalar for f64 {
+    fn ToFloat(self) -> c_float { self as c_float }
+    fn FromFloat(v: c_float) -> Self { v as Self }
+    fn ToInteger(self) -> Option<i128> { None }
+    fn FromInteger(v: i128) -> Self { v as Self }
+}
+
+// Bytes of a value, exactly size_of::<T>() of them. This is what the data type functions work on (the 'void*' of the C++ API).
+pub fn ImScalarBytes<T: ImGuiScalar>(v: &T) -> &[u8] {
+    unsafe { slice::from_raw_parts(v as *const T as *const u8, mem::size_of::<T>()) }
+}
+
+pub fn ImScalarBytesMut<T: ImGuiScalar>(v: &mut T) -> &mut [u8] {
+    unsafe { slice::from_raw_parts_mut(v as *mut T as *mut u8, mem::size_of::<T>()) }
+}
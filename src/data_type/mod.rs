@@ -1,11 +1,18 @@
 use libc::c_int;
 
+pub mod data_type_extended_ops;
 pub mod data_type_info;
 pub mod data_type_ops;
+pub mod data_type_scalar;
 pub mod data_type_temp_storage;
 
 pub type ImGuiDataType = c_int;
 
+// Storage of IM_GUI_DATA_TYPE_F16 values: IEEE 754 binary16 bits, see ImF16ToF32()/ImF32ToF16()
+pub type ImF16 = u16;
+// Storage of IM_GUI_DATA_TYPE_FIXED16_16 values: the value times 65536
+pub type ImFixed16_16 = i32;
+
 // Standard Drag and Drop payload types. You can define you own payload types using short strings. Types starting with '_' are defined by Dear ImGui.
 pub type ImGuiPayloadType = i32;
 pub const IM_GUI_PAYLOAD_TYPE_COLOR3F: ImGuiPayloadType = 0;
@@ -24,5 +31,9 @@ pub const IM_GUI_DATA_TYPE_DOUBLE: ImGuiDataType = 9;
 pub const IM_GUI_DATA_TYPE_STRING: ImGuiDataType = 10;
 pub const IM_GUI_DATA_TYPE_POINTER: ImGuiDataType = 11;
 pub const IM_GUI_DATA_TYPE_ID: ImGuiDataType = 12;
+pub const IM_GUI_DATA_TYPE_F16: ImGuiDataType = 13; // ImF16, half-precision float
+pub const IM_GUI_DATA_TYPE_FIXED16_16: ImGuiDataType = 14; // ImFixed16_16, Q16.16 signed fixed point
+pub const IM_GUI_DATA_TYPE_S128: ImGuiDataType = 15; // i128
+pub const IM_GUI_DATA_TYPE_U128: ImGuiDataType = 16; // u128
 
-pub const IM_GUI_DATA_TYPE_COUNT: ImGuiDataType = 13;
+pub const IM_GUI_DATA_TYPE_COUNT: ImGuiDataType = 17;
@@ -13,7 +13,7 @@ use crate::core::condition::{ImGuiCond_FirstUseEver, ImGuiCond_Once};
 use crate::core::context::AppContext;
 use crate::context_ops::GetFrameCount;
 use crate::cursor_ops::{cursor_screen_pos, indent, unindent};
-use crate::data_type::{ImGuiDataType, IM_GUI_DATA_TYPE_STRING};
+use crate::data_type::{ImGuiDataType, IM_GUI_DATA_TYPE_ID, IM_GUI_DATA_TYPE_POINTER, IM_GUI_DATA_TYPE_S32, IM_GUI_DATA_TYPE_STRING};
 use crate::debugging::debug_log_flags::{IM_GUI_DEBUG_LOG_FLAGS_EVENT_ACTIVE_ID, IM_GUI_DEBUG_LOG_FLAGS_EVENT_CLIPPER, IM_GUI_DEBUG_LOG_FLAGS_EVENT_DOCKING, IM_GUI_DEBUG_LOG_FLAGS_EVENT_FOCUS, IM_GUI_DEBUG_LOG_FLAGS_EVENT_IO, IM_GUI_DEBUG_LOG_FLAGS_EVENT_MASK, IM_GUI_DEBUG_LOG_FLAGS_EVENT_NAV, IM_GUI_DEBUG_LOG_FLAGS_EVENT_POPUP, IM_GUI_DEBUG_LOG_FLAGS_EVENT_VIEWPORT, IM_GUI_DEBUG_LOG_FLAGS_OUTPUT_TO_TTY};
 use crate::dock_context_ops::clear_dock_context_nodes;
 use crate::docking::dock_node::ImGuiDockNode;
@@ -54,7 +54,7 @@ use crate::rect::ImRect;
 use crate::drawing::render_ops::FindRenderedTextEnd;
 use crate::widgets::scrolling_ops::{GetScrollMaxY, GetScrollY, SetScrollHereY};
 use crate::widgets::separator::Separator;
-use crate::settings_ops::{ClearIniSettings, FindWindowSettings, save_ini_settings_to_disk};
+use crate::settings_ops::{ClearIniSettings, FindWindowSettings, save_ini_settings_to_disk, SaveIniSettingsToMemory};
 use crate::core::stack_level_info::ImGuiStackLevelInfo;
 use crate::core::stack_tool::ImGuiStackTool;
 use crate::core::storage::ImGuiStorage;
@@ -93,18 +93,21 @@ use crate::window::window_settings::ImGuiWindowSettings;
 // c_void DebugHookIdInfo(ImguiHandle id, data_type: ImGuiDataType, data_id: *const c_void, data_id_end: *const c_void)
 pub fn DebugHookIdInfo(g: &mut AppContext, id: ImguiHandle, data_type: ImGuiDataType, data_id: Option<&[u8]>) {
     // let g = GImGui; // ImGuiContext& g = *GImGui;
-    let mut window = &mut g.CurrentWindow;
+    let id_stack = match g.current_window_mut() {
+        Some(window) => window.id_stack.clone(),
+        None => return,
+    };
     let mut tool = &mut g.DebugStackTool;
 
     // Step 0: stack query
     // This assume that the ID was computed with the current ID stack, which tends to be the case for our widget.
     if tool.StackLevel == -1 {
         tool.StackLevel += 1;
-        tool.Results.resize(window.id_stack.len() + 1, ImGuiStackLevelInfo::default());
+        tool.Results.resize(id_stack.len() + 1, ImGuiStackLevelInfo::default());
         // for (let n: c_int = 0; n < window.id_stack.Size + 1; n++)
-        for n in 0..window.id_stack.len() + 1 {
-            tool.Results[n].ID = if n < window.id_stack.len() {
-                window.id_stack[n]
+        for n in 0..id_stack.len() + 1 {
+            tool.Results[n].ID = if n < id_stack.len() {
+                id_stack[n]
             } else { id };
         }
         return;
@@ -112,46 +115,45 @@ pub fn DebugHookIdInfo(g: &mut AppContext, id: ImguiHandle, data_type: ImGuiData
 
     // Step 1+: query for individual level
     // IM_ASSERT(tool.StackLevel >= 0);
-    if tool.StackLevel != window.id_stack.len() as c_int {
+    if tool.StackLevel != id_stack.len() as c_int {
         return;
     }
-    let mut info: *mut ImGuiStackLevelInfo = &mut tool.Results[tool.StackLevel];
+    let info = &mut tool.Results[tool.StackLevel as usize];
     // IM_ASSERT(info.ID == id && info.QueryFrameCount > 0);
 
-    match data_type {
-        ImGuiDataType_S32 => {
-            // let fmt_1 = format!("{}", data_id);
-            // let cstr_fmt_1 = CStr::from_bytes_with_nul_unchecked(fmt_1.as_bytes());
-            // ImFormatString(info.Desc.as_mut_ptr(), IM_ARRAYSIZE(info.Desc), cstr_fmt_1.as_ptr());
-            todo!()
+    let desc = match data_type {
+        IM_GUI_DATA_TYPE_S32 => {
+            let bytes = data_id.unwrap_or(&[]);
+            let n = if bytes.len() >= 4 { c_int::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) } else { 0 };
+            format!("{}", n)
         },
         IM_GUI_DATA_TYPE_STRING => {
-            // let raw_str_1 = if data_id_end.is_null() == false { dat_id_end - data_id } else {
-            //     libc::strlen(data_id);
-            // };
-            // let data_id_cstr: CStr = Cstr::from_ptr(data_id);
-            // let data_id_str = data_id_cstr.to_str().unwrap();
-            //
-            // ImFormatString(info.Desc.as_mut_ptr(), IM_ARRAYSIZE(info.Desc), data_id);
-            todo!()
+            String::from_utf8_lossy(data_id.unwrap_or(&[])).into_owned()
         },
-        ImGuiDataType_Pointer => {
-            // ImFormatString(info.Desc.as_mut_ptr(), IM_ARRAYSIZE(info.Desc), "0x{}", data_id);
-            todo!()
+        IM_GUI_DATA_TYPE_POINTER => {
+            let bytes = data_id.unwrap_or(&[]);
+            let mut ptr_bytes = [0u8; 8];
+            ptr_bytes[..bytes.len().min(8)].copy_from_slice(&bytes[..bytes.len().min(8)]);
+            format!("(void*)0x{:X}", u64::from_le_bytes(ptr_bytes))
         },
-
-        ImGuiDataType_ID => {
-            if (info.Desc[0] != 0) { // PushOverrideID() is often used to avoid hashing twice, which would lead to 2 calls to DebugHookIdInfo(). We prioritize the first one.
+        IM_GUI_DATA_TYPE_ID => {
+            if info.Desc[0] != 0 { // PushOverrideID() is often used to avoid hashing twice, which would lead to 2 calls to DebugHookIdInfo(). We prioritize the first one.
                 return;
             }
-            // ImFormatString(info.Desc, IM_ARRAYSIZE(info.Desc), "0x{} [override]", id);
-            todo!()
+            format!("0x{:08X} [override]", id)
         },
-
         _ => {
-            todo!()
+            // IM_ASSERT(0);
+            return;
         }
     };
+
+    // ImFormatString(info.Desc, IM_ARRAYSIZE(info.Desc), ...): truncate to fit, always zero-terminated
+    let len = desc.len().min(info.Desc.len() - 1);
+    for (dst, src) in info.Desc.iter_mut().zip(desc.as_bytes()[..len].iter()) {
+        *dst = *src as c_char;
+    }
+    info.Desc[len] = 0;
     info.QuerySuccess = true;
     info.DataType = data_type;
 }
@@ -804,15 +806,16 @@ pub unsafe fn ShowMetricsWindow(p_open: &mut bool)
     if TreeNode(String::from("Settings"), String::from(""))
     {
         if SmallButton(String::from("Clear")) {
-            ClearIniSettings();
+            ClearIniSettings(g);
         }
         same_line(g, 0.0, 0.0);
         if SmallButton(String::from("Save to memory")) {
-            SaveIniSettingsToMemory();
+            SaveIniSettingsToMemory(g);
         }
         same_line(g, 0.0, 0.0);
         if SmallButton(String::from("Save to disk")) {
-            save_ini_settings_to_disk(g, g.IO.IniFilename);
+            let ini_filename = g.IO.IniFilename.clone();
+            save_ini_settings_to_disk(g, &ini_filename);
         }
         same_line(g, 0.0, 0.0);
         if g.IO.IniFilename {
@@ -1755,7 +1758,8 @@ pub unsafe fn UpdateDebugToolStackQueries()
 
     // Update queries. The steps are: -1: query Stack, >= 0: query each stack item
     // We can only perform 1 ID Info query every frame. This is designed so the GetID() tests are cheap and constant-time
-    let mut query_id: ImguiHandle =  if g.HoveredIdPreviousFrame { g.HoveredIdPreviousFrame } else { g.ActiveId };
+    // The test engine may request the ID path of an arbitrary item (see ImGuiTestContext::ItemGetPath())
+    let mut query_id: ImguiHandle = if g.TestEngine.StackQueryId != 0 { g.TestEngine.StackQueryId } else if g.HoveredIdPreviousFrame { g.HoveredIdPreviousFrame } else { g.ActiveId };
     if tool.QueryId != query_id
     {
         tool.QueryId = query_id;
@@ -1,2 +1,4 @@
 pub mod debug_log_flags;
 pub mod debug_ops;
+pub mod rgba_image;
+pub mod screenshot_ops;
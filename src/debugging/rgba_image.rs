@@ -0,0 +1,290 @@
+#![allow(non_snake_case)]
+
+// RGBA8 image used by the screenshot capture and golden-image comparison helpers.
+// Files are written without any image library: PNG uses uncompressed (stored) deflate blocks, PPM is the binary P6 variant.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct RgbaImage {
+    pub Width: usize,
+    pub Height: usize,
+    // 4 bytes per pixel, R G B A order, rows top to bottom
+    pub Pixels: Vec<u8>,
+}
+
+// Result of CompareImages()
+#[derive(Default, Debug, Clone)]
+pub struct ImageCompareResult {
+    // Number of pixels where at least one channel differs by more than the tolerance
+    pub DiffPixelsCount: usize,
+    // Largest per-channel absolute difference found anywhere in the image
+    pub MaxChannelDelta: u8,
+    // Set when the two images don't have the same dimensions. Nothing else is meaningful in that case.
+    pub SizeMismatch: bool,
+    // Actual image dimmed to 25%, with mismatching pixels painted in opaque red
+    pub DiffImage: RgbaImage,
+}
+
+impl ImageCompareResult {
+    pub fn IsMatch(&self) -> bool {
+        !self.SizeMismatch && self.DiffPixelsCount == 0
+    }
+}
+
+impl RgbaImage {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            Width: width,
+            Height: height,
+            Pixels: vec![0; width * height * 4],
+        }
+    }
+
+    pub fn from_pixels(width: usize, height: usize, pixels: Vec<u8>) -> Self {
+        // IM_ASSERT(pixels.len() == width * height * 4);
+        Self {
+            Width: width,
+            Height: height,
+            Pixels: pixels,
+        }
+    }
+
+    pub fn GetPixel(&self, x: usize, y: usize) -> [u8; 4] {
+        let idx = (y * self.Width + x) * 4;
+        [self.Pixels[idx], self.Pixels[idx + 1], self.Pixels[idx + 2], self.Pixels[idx + 3]]
+    }
+
+    pub fn SetPixel(&mut self, x: usize, y: usize, col: [u8; 4]) {
+        let idx = (y * self.Width + x) * 4;
+        self.Pixels[idx..idx + 4].copy_from_slice(&col);
+    }
+
+    // Copy a sub-rectangle. The rectangle is clamped to the image bounds.
+    pub fn Crop(&self, x: usize, y: usize, w: usize, h: usize) -> RgbaImage {
+        let x0 = x.min(self.Width);
+        let y0 = y.min(self.Height);
+        let x1 = (x + w).min(self.Width);
+        let y1 = (y + h).min(self.Height);
+        let mut out = RgbaImage::new(x1 - x0, y1 - y0);
+        for row in y0..y1 {
+            let src = &self.Pixels[(row * self.Width + x0) * 4..(row * self.Width + x1) * 4];
+            let dst_start = (row - y0) * out.Width * 4;
+            out.Pixels[dst_start..dst_start + src.len()].copy_from_slice(src);
+        }
+        out
+    }
+
+    // Flip rows, for readbacks from APIs with a bottom-left origin (e.g. glReadPixels)
+    pub fn FlipVertically(&mut self) {
+        let stride = self.Width * 4;
+        for y in 0..self.Height / 2 {
+            let (top, bottom) = self.Pixels.split_at_mut((self.Height - 1 - y) * stride);
+            top[y * stride..(y + 1) * stride].swap_with_slice(&mut bottom[..stride]);
+        }
+    }
+
+    //-----------------------------------------------------------------------------
+    // PPM (P6, binary). Alpha is dropped on write and set to 255 on read.
+    //-----------------------------------------------------------------------------
+
+    pub fn EncodePpm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.Width, self.Height).into_bytes();
+        out.reserve(self.Width * self.Height * 3);
+        for px in self.Pixels.chunks_exact(4) {
+            out.extend_from_slice(&px[0..3]);
+        }
+        out
+    }
+
+    pub fn WritePpm<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.EncodePpm())
+    }
+
+    pub fn DecodePpm(data: &[u8]) -> io::Result<RgbaImage> {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+
+        // Header is 4 whitespace separated tokens: magic, width, height, maxval. '#' starts a comment until end of line.
+        let mut tokens: Vec<String> = vec![];
+        let mut pos = 0;
+        while tokens.len() < 4 {
+            while pos < data.len() && (data[pos].is_ascii_whitespace() || data[pos] == b'#') {
+                if data[pos] == b'#' {
+                    while pos < data.len() && data[pos] != b'\n' {
+                        pos += 1;
+                    }
+                } else {
+                    pos += 1;
+                }
+            }
+            let start = pos;
+            while pos < data.len() && !data[pos].is_ascii_whitespace() {
+                pos += 1;
+            }
+            if start == pos {
+                return Err(invalid("truncated PPM header"));
+            }
+            tokens.push(String::from_utf8_lossy(&data[start..pos]).into_owned());
+        }
+        pos += 1; // Single whitespace before the raster
+
+        if tokens[0] != "P6" {
+            return Err(invalid("only binary P6 PPM files are supported"));
+        }
+        let width: usize = tokens[1].parse().map_err(|_| invalid("bad PPM width"))?;
+        let height: usize = tokens[2].parse().map_err(|_| invalid("bad PPM height"))?;
+        if tokens[3] != "255" {
+            return Err(invalid("only 8-bit PPM files are supported"));
+        }
+        let raster_end = width.checked_mul(height).and_then(|n| n.checked_mul(3)).and_then(|n| n.checked_add(pos)).ok_or_else(|| invalid("PPM size too large"))?;
+        let raster = data.get(pos..raster_end).ok_or_else(|| invalid("truncated PPM raster"))?;
+
+        let mut out = RgbaImage::new(width, height);
+        for (dst, src) in out.Pixels.chunks_exact_mut(4).zip(raster.chunks_exact(3)) {
+            dst[0..3].copy_from_slice(src);
+            dst[3] = 255;
+        }
+        Ok(out)
+    }
+
+    pub fn ReadPpm<P: AsRef<Path>>(path: P) -> io::Result<RgbaImage> {
+        RgbaImage::DecodePpm(&fs::read(path)?)
+    }
+
+    //-----------------------------------------------------------------------------
+    // PNG (8-bit RGBA, no filtering, stored deflate blocks)
+    //-----------------------------------------------------------------------------
+
+    pub fn EncodePng(&self) -> Vec<u8> {
+        // Raw scanlines, each prefixed with filter type 0 (None)
+        let stride = self.Width * 4;
+        let mut raw = Vec::with_capacity((stride + 1) * self.Height);
+        for y in 0..self.Height {
+            raw.push(0);
+            raw.extend_from_slice(&self.Pixels[y * stride..(y + 1) * stride]);
+        }
+
+        // zlib stream: header, stored blocks of at most 65535 bytes, adler32
+        let mut zlib = Vec::with_capacity(raw.len() + raw.len() / 65535 * 5 + 16);
+        zlib.extend_from_slice(&[0x78, 0x01]);
+        let mut chunks = raw.chunks(65535).peekable();
+        if chunks.peek().is_none() {
+            zlib.extend_from_slice(&[0x01, 0x00, 0x00, 0xFF, 0xFF]);
+        }
+        while let Some(chunk) = chunks.next() {
+            let is_last = chunks.peek().is_none();
+            let len = chunk.len() as u16;
+            zlib.push(if is_last { 0x01 } else { 0x00 });
+            zlib.extend_from_slice(&len.to_le_bytes());
+            zlib.extend_from_slice(&(!len).to_le_bytes());
+            zlib.extend_from_slice(chunk);
+        }
+        zlib.extend_from_slice(&ImAdler32(&raw).to_be_bytes());
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend_from_slice(&(self.Width as u32).to_be_bytes());
+        ihdr.extend_from_slice(&(self.Height as u32).to_be_bytes());
+        ihdr.extend_from_slice(&[8, 6, 0, 0, 0]); // 8 bits per channel, RGBA, deflate, adaptive filtering, no interlace
+
+        let mut out = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+        PngWriteChunk(&mut out, b"IHDR", &ihdr);
+        PngWriteChunk(&mut out, b"IDAT", &zlib);
+        PngWriteChunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    pub fn WritePng<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.EncodePng())
+    }
+}
+
+fn PngWriteChunk(out: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let crc_start = out.len();
+    out.extend_from_slice(chunk_type);
+    out.extend_from_slice(data);
+    let crc = ImCrc32Png(&out[crc_start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+// CRC-32 as specified by PNG/zlib (reflected polynomial 0xEDB88320). Not to be confused with ImHashData() which uses a different seed convention.
+fn ImCrc32Png(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFFFFFF;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB88320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+fn ImAdler32(data: &[u8]) -> u32 {
+    let mut a: u32 = 1;
+    let mut b: u32 = 0;
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+// Compare two images channel by channel. A pixel is reported as different when any channel differs by more than 'tolerance'.
+pub fn CompareImages(expected: &RgbaImage, actual: &RgbaImage, tolerance: u8) -> ImageCompareResult {
+    let mut result = ImageCompareResult::default();
+    if expected.Width != actual.Width || expected.Height != actual.Height {
+        result.SizeMismatch = true;
+        return result;
+    }
+
+    result.DiffImage = RgbaImage::new(actual.Width, actual.Height);
+    for (n, (e, a)) in expected.Pixels.chunks_exact(4).zip(actual.Pixels.chunks_exact(4)).enumerate() {
+        let mut delta: u8 = 0;
+        for c in 0..4 {
+            delta = delta.max((e[c] as i16 - a[c] as i16).unsigned_abs() as u8);
+        }
+        result.MaxChannelDelta = result.MaxChannelDelta.max(delta);
+        let dst = &mut result.DiffImage.Pixels[n * 4..n * 4 + 4];
+        if delta > tolerance {
+            result.DiffPixelsCount += 1;
+            dst.copy_from_slice(&[255, 0, 0, 255]);
+        } else {
+            dst.copy_from_slice(&[a[0] / 4, a[1] / 4, a[2] / 4, 255]);
+        }
+    }
+    result
+}
+
+// Golden-image check. When the golden file doesn't exist yet it is created from 'actual' and the check passes.
+// On mismatch the actual image and the diff image are written next to the golden file as "<name>.actual.png" and "<name>.diff.png".
+pub fn CompareWithGoldenFile<P: AsRef<Path>>(actual: &RgbaImage, golden_ppm_path: P, tolerance: u8) -> io::Result<ImageCompareResult> {
+    let golden_path = golden_ppm_path.as_ref();
+    if !golden_path.exists() {
+        actual.WritePpm(golden_path)?;
+        return Ok(ImageCompareResult {
+            DiffImage: RgbaImage::new(actual.Width, actual.Height),
+            ..Default::default()
+        });
+    }
+
+    let expected = RgbaImage::ReadPpm(golden_path)?;
+    // PPM has no alpha channel: compare against an opaque copy of the capture
+    let mut actual_opaque = actual.clone();
+    for px in actual_opaque.Pixels.chunks_exact_mut(4) {
+        px[3] = 255;
+    }
+    let result = CompareImages(&expected, &actual_opaque, tolerance);
+    if !result.IsMatch() {
+        actual.WritePng(golden_path.with_extension("actual.png"))?;
+        if !result.SizeMismatch {
+            result.DiffImage.WritePng(golden_path.with_extension("diff.png"))?;
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,171 @@
+#![allow(non_snake_case)]
+
+//-----------------------------------------------------------------------------
+// [SECTION] SCREENSHOT CAPTURE
+//-----------------------------------------------------------------------------
+// - CaptureScreenshot()
+// - RequestScreenshot()
+// - TakeScreenshotResult()
+// - ScreenshotProcessPendingReadback()
+// - ScreenshotHook_RenderPost() [Internal]
+// - ScreenshotHook_NewFramePre() [Internal]
+//-----------------------------------------------------------------------------
+// Pixels come from the active renderer backend through PlatformIO.Renderer_ReadPixels.
+// RequestScreenshot() captures exactly the frame built by the next Render() call:
+// - with the software renderer, the viewport ImDrawData is rasterized directly from the RENDER_POST hook.
+// - with a GPU renderer, the framebuffer is read back once the application has rendered it (see ScreenshotProcessPendingReadback()).
+//-----------------------------------------------------------------------------
+
+use libc::c_int;
+use crate::backends::software_renderer_backend::{ImGui_ImplSoftware_RenderDrawDataToFramebuffer, SoftwareFramebuffer};
+use crate::core::context::AppContext;
+use crate::core::context_hook::{ImGuiContextHook, ImguiContextHookType, IM_GUI_CONTEXT_HOOK_TYPE_NEW_FRAME_PRE, IM_GUI_CONTEXT_HOOK_TYPE_RENDER_POST};
+use crate::core::rect::ImRect;
+use crate::core::type_defs::{ImguiHandle, INVALID_IMGUI_HANDLE};
+use crate::core::vec2::Vector2;
+use crate::debugging::rgba_image::RgbaImage;
+use crate::io::backend_renderer_user_data::BackendRendererUserData;
+
+// What to capture
+#[derive(Debug, Clone, Copy)]
+pub enum ImGuiScreenshotTarget {
+    // Whole viewport (use the main viewport ID for single-viewport applications)
+    Viewport(ImguiHandle),
+    // Outer rectangle of a window, in the viewport it is currently displayed in
+    Window(ImguiHandle),
+    // Arbitrary rectangle in screen coordinates of the given viewport
+    Rect(ImguiHandle, ImRect),
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiScreenshotState {
+    // Capture requested with RequestScreenshot(), consumed by the next Render()
+    pub Request: Option<ImGuiScreenshotTarget>,
+    // GPU readback waiting for the application to render the frame, as (viewport id, framebuffer rect)
+    pub PendingReadback: Option<(ImguiHandle, [c_int; 4])>,
+    pub Result: Option<RgbaImage>,
+    pub HookIdRenderPost: ImguiHandle,
+    pub HookIdNewFramePre: ImguiHandle,
+}
+
+// Resolve a target into a viewport ID + rectangle in framebuffer pixels relative to the viewport origin (x, y, w, h)
+fn ScreenshotResolveTarget(g: &mut AppContext, target: &ImGuiScreenshotTarget) -> Option<(ImguiHandle, [c_int; 4])> {
+    let (viewport_id, rect) = match *target {
+        ImGuiScreenshotTarget::Viewport(viewport_id) => {
+            let viewport = g.Viewports.get(&viewport_id)?;
+            let max = Vector2::from_floats(viewport.Pos.x + viewport.Size.x, viewport.Pos.y + viewport.Size.y);
+            (viewport_id, ImRect::from_vec2(&viewport.Pos, &max))
+        }
+        ImGuiScreenshotTarget::Window(window_id) => {
+            let window = g.windows.get(&window_id)?;
+            let max = Vector2::from_floats(window.position.x + window.Size.x, window.position.y + window.Size.y);
+            (window.ViewportId, ImRect::from_vec2(&window.position, &max))
+        }
+        ImGuiScreenshotTarget::Rect(viewport_id, rect) => (viewport_id, rect),
+    };
+
+    let viewport = g.Viewports.get(&viewport_id)?;
+    let scale = if viewport.DrawData.FramebufferScale.x > 0.0 { viewport.DrawData.FramebufferScale } else { g.IO.DisplayFramebufferScale };
+    let fb_w = (viewport.Size.x * scale.x) as c_int;
+    let fb_h = (viewport.Size.y * scale.y) as c_int;
+    let x0 = (((rect.min.x - viewport.Pos.x) * scale.x) as c_int).clamp(0, fb_w);
+    let y0 = (((rect.min.y - viewport.Pos.y) * scale.y) as c_int).clamp(0, fb_h);
+    let x1 = (((rect.max.x - viewport.Pos.x) * scale.x).ceil() as c_int).clamp(0, fb_w);
+    let y1 = (((rect.max.y - viewport.Pos.y) * scale.y).ceil() as c_int).clamp(0, fb_h);
+    if x1 <= x0 || y1 <= y0 {
+        return None;
+    }
+    Some((viewport_id, [x0, y0, x1 - x0, y1 - y0]))
+}
+
+fn ScreenshotReadPixels(g: &mut AppContext, viewport_id: ImguiHandle, r: [c_int; 4]) -> Option<RgbaImage> {
+    let read_pixels = g.PlatformIO.Renderer_ReadPixels?;
+    let mut image = RgbaImage::default();
+    if !read_pixels(g, viewport_id, r[0], r[1], r[2], r[3], &mut image) {
+        return None;
+    }
+    Some(image)
+}
+
+// Read back pixels of the last frame presented by the renderer backend.
+// Returns None when the renderer backend doesn't support readback or the target is empty/unknown.
+pub fn CaptureScreenshot(g: &mut AppContext, target: ImGuiScreenshotTarget) -> Option<RgbaImage> {
+    let (viewport_id, r) = ScreenshotResolveTarget(g, &target)?;
+    ScreenshotReadPixels(g, viewport_id, r)
+}
+
+// Capture the frame that will be built by the next call to Render(). Retrieve it with TakeScreenshotResult() after the frame was rendered.
+pub fn RequestScreenshot(g: &mut AppContext, target: ImGuiScreenshotTarget) {
+    g.Screenshot.Request = Some(target);
+    g.Screenshot.Result = None;
+    if g.Screenshot.HookIdRenderPost == 0 {
+        g.Screenshot.HookIdRenderPost = ScreenshotAddHook(g, IM_GUI_CONTEXT_HOOK_TYPE_RENDER_POST, ScreenshotHook_RenderPost);
+        g.Screenshot.HookIdNewFramePre = ScreenshotAddHook(g, IM_GUI_CONTEXT_HOOK_TYPE_NEW_FRAME_PRE, ScreenshotHook_NewFramePre);
+    }
+}
+
+// Returns the image captured for the last RequestScreenshot() call, if it is available yet.
+pub fn TakeScreenshotResult(g: &mut AppContext) -> Option<RgbaImage> {
+    g.Screenshot.Result.take()
+}
+
+pub fn ScreenshotRemoveHooks(g: &mut AppContext) {
+    let hook_render_post = g.Screenshot.HookIdRenderPost;
+    let hook_new_frame_pre = g.Screenshot.HookIdNewFramePre;
+    if hook_render_post != 0 {
+        g.remove_context_hook(&hook_render_post);
+        g.remove_context_hook(&hook_new_frame_pre);
+    }
+    g.Screenshot = ImGuiScreenshotState::default();
+}
+
+fn ScreenshotAddHook(g: &mut AppContext, hook_type: ImguiContextHookType, callback: fn(g: &mut AppContext, hook: &mut ImGuiContextHook)) -> ImguiHandle {
+    let hook = ImGuiContextHook {
+        HookId: 0,
+        hook_type,
+        Owner: INVALID_IMGUI_HANDLE,
+        Callback: callback,
+        UserData: vec![],
+    };
+    g.add_context_hook(&hook)
+}
+
+// Called at the end of Render(): draw data for the requested frame is complete
+pub fn ScreenshotHook_RenderPost(g: &mut AppContext, _hook: &mut ImGuiContextHook) {
+    let target = match g.Screenshot.Request.take() {
+        Some(target) => target,
+        None => return,
+    };
+    let (viewport_id, r) = match ScreenshotResolveTarget(g, &target) {
+        Some(resolved) => resolved,
+        None => return,
+    };
+
+    // Software renderer: rasterize the draw data of this frame right away, using the textures known to the backend.
+    if let BackendRendererUserData::Software(bd) = &g.IO.backend_renderer_user_data {
+        if let Some(viewport) = g.Viewports.get(&viewport_id) {
+            let mut fb = SoftwareFramebuffer::default();
+            ImGui_ImplSoftware_RenderDrawDataToFramebuffer(bd, &viewport.DrawData, &mut fb);
+            let full = RgbaImage::from_pixels(fb.Width, fb.Height, fb.Pixels);
+            g.Screenshot.Result = Some(full.Crop(r[0] as usize, r[1] as usize, r[2] as usize, r[3] as usize));
+        }
+        return;
+    }
+
+    // GPU renderer: the application renders the draw data after Render() returns, read it back afterwards.
+    g.Screenshot.PendingReadback = Some((viewport_id, r));
+}
+
+// Perform a pending GPU readback. Call after your renderer's RenderDrawData() and before presenting/swapping,
+// as the content of a swapchain back buffer is undefined after presentation.
+// If not called, the readback happens at the start of the next NewFrame(), which is fine for off-screen framebuffers.
+pub fn ScreenshotProcessPendingReadback(g: &mut AppContext) {
+    if let Some((viewport_id, r)) = g.Screenshot.PendingReadback.take() {
+        g.Screenshot.Result = ScreenshotReadPixels(g, viewport_id, r);
+    }
+}
+
+// Called at the start of NewFrame(): the previous frame has been rendered by the application
+pub fn ScreenshotHook_NewFramePre(g: &mut AppContext, _hook: &mut ImGuiContextHook) {
+    ScreenshotProcessPendingReadback(g);
+}
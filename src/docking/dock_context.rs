@@ -3,14 +3,20 @@ use crate::docking::dock_node_settings::ImGuiDockNodeSettings;
 use crate::docking::dock_request::ImGuiDockRequest;
 use crate::core::storage::ImGuiStorage;
 use crate::core::type_defs::ImguiHandle;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use crate::core::settings_document::DockLayoutSettingsEntry;
+use crate::docking::dock_layout::{ImGuiDockLayoutRequest, ImGuiDockLayoutTransition};
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone)]
 pub struct ImGuiDockContext {
     pub dock_nodes: HashMap<ImguiHandle, ImGuiDockNode>, //ImGuiStorage, // Map ID -> ImGuiDockNode*: Active nodes
     pub Requests: Vec<ImGuiDockRequest>,
     pub NodesSettings: Vec<ImGuiDockNodeSettings>,
     pub WantFullRebuild: bool,
+    pub LayoutProfiles: BTreeMap<String, DockLayoutSettingsEntry>, // Named layouts, saved with the settings
+    pub LayoutProfileCurrent: String,                               // Name of the last saved/loaded profile, empty if none
+    pub LayoutPending: Option<ImGuiDockLayoutRequest>,               // Applied at the beginning of the next frame
+    pub LayoutTransition: Option<ImGuiDockLayoutTransition>,         // Animated switch in progress
     // ImGuiDockContext()              { memset(this, 0, sizeof(*this)); }
 }
 
@@ -5,11 +5,13 @@ use crate::core::config_flags::ImGuiConfigFlags_DockingEnable;
 use crate::core::direction::{ImGuiDir_Left, ImGuiDir_None, ImGuiDir_Right, ImGuiDir_Up};
 use crate::docking::dock_node::ImGuiDockNode;
 use crate::docking::dock_request_type::{ImGuiDockRequestType_Dock, ImGuiDockRequestType_None, ImGuiDockRequestType_Undock};
-use crate::docking_ops::{DockBuilderRemoveNodeChildNodes, DockBuilderRemoveNodeDockedWindows, DockSettingsHandler_ApplyAll, DockSettingsHandler_ClearAll, DockSettingsHandler_ReadLine, DockSettingsHandler_WriteAll};
+use crate::docking_ops::{DockBuilderRemoveNodeChildNodes, DockBuilderRemoveNodeDockedWindows, DockSettingsHandler_ApplyAll, DockSettingsHandler_ClearAll, DockSettingsHandler_ReadDocument, DockSettingsHandler_ReadLine, DockSettingsHandler_WriteAll, DockSettingsHandler_WriteDocument};
 use crate::core::hash_ops::hash_string;
 use crate::core::settings_handler::SettingsHandler;
+use crate::core::settings_ops::SaveIniSettingsToMemory;
 use crate::core::utils::is_not_null;
 use crate::core::type_defs::ImguiHandle;
+use crate::docking::dock_layout_ops::{DockContextProcessPendingLayout, DockLayoutUpdateTransition};
 
 
 pub fn init_dock_context(g: &mut AppContext)
@@ -24,6 +26,8 @@ pub fn init_dock_context(g: &mut AppContext)
     ini_handler.ReadLineFn = DockSettingsHandler_ReadLine;
     ini_handler.ApplyAllFn = DockSettingsHandler_ApplyAll;
     ini_handler.WriteAllFn = DockSettingsHandler_WriteAll;
+    ini_handler.ReadDocumentFn = Some(DockSettingsHandler_ReadDocument);
+    ini_handler.WriteDocumentFn = Some(DockSettingsHandler_WriteDocument);
     g.settings_handlers.push(ini_handler);
 }
 
@@ -56,7 +60,7 @@ pub unsafe fn DockContextRebuildNodes(g: &mut AppContext)
     let g =  ctx;
     let dc: *mut ImGuiDockContext = &ctx.DockContext;
     IMGUI_DEBUG_LOG_DOCKING("[docking] DockContextRebuildNodes\n");
-    SaveIniSettingsToMemory();
+    SaveIniSettingsToMemory(g);
     let mut root_id: ImguiHandle =  0; // Rebuild all
     clear_dock_context_nodes(ctx, root_id, false);
     DockContextBuildNodesFromSettings(ctx, dc.NodesSettings.Data, dc.NodesSettings.Size);
@@ -99,6 +103,10 @@ pub unsafe fn DockContextNewFrameUpdateUndocking(g: &mut AppContext)
         dc.WantFullRebuild = false;
     }
 
+    // Process layout profile switch (see DockLayoutApply())
+    DockContextProcessPendingLayout(g);
+    DockLayoutUpdateTransition(g);
+
     // Process Undocking requests (we need to process them _before_ the UpdateMouseMovingWindowNewFrame call in NewFrame)
     // for (let n: c_int = 0; n < dc.Requests.Size; n++)
     for n in 0 .. dc.Requests.len()
@@ -0,0 +1,28 @@
+use crate::core::settings_document::DockLayoutSettingsEntry;
+use crate::core::type_defs::ImguiHandle;
+use crate::core::vec2::Vector2;
+use libc::c_float;
+use std::collections::HashMap;
+
+// Layout switch queued by DockLayoutApply(), processed by DockContextNewFrameUpdateUndocking()
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiDockLayoutRequest {
+    pub Layout: DockLayoutSettingsEntry,
+    pub Animate: bool,
+}
+
+// Position and size of a floating window or a root dock node
+#[derive(Default, Debug, Clone, Copy)]
+pub struct ImGuiDockLayoutRect {
+    pub Pos: Vector2,
+    pub Size: Vector2,
+}
+
+// Interpolation from the previous layout to the new one (see DockLayoutUpdateTransition())
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiDockLayoutTransition {
+    pub From: HashMap<ImguiHandle, ImGuiDockLayoutRect>, // Window or root node ID -> rect before the switch
+    pub To: HashMap<ImguiHandle, ImGuiDockLayoutRect>,   // Window or root node ID -> rect in the new layout
+    pub Time: c_float,
+    pub Duration: c_float,
+}
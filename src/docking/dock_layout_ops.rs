@@ -0,0 +1,191 @@
+#![allow(non_snake_case)]
+
+//-----------------------------------------------------------------------------
+// Docking: Layout profiles
+//-----------------------------------------------------------------------------
+// - DockLayoutCapture()
+// - DockLayoutApply()
+// - DockLayoutSaveProfile()
+// - DockLayoutLoadProfile()
+// - DockLayoutRemoveProfile()
+// - DockLayoutListProfiles()
+// - DockLayoutGetCurrentProfile()
+// - DockContextProcessPendingLayout() [Internal]
+// - DockLayoutUpdateTransition() [Internal]
+//-----------------------------------------------------------------------------
+// A layout is the dock tree + the placement of the windows (same data as [Docking][Data] and [Window][...]).
+// Several named layouts can be kept side by side and are saved in the settings file as [DockLayout][Name].
+// Switching is deferred to the next NewFrame(), since nodes can't be rebuilt while windows are being submitted.
+//-----------------------------------------------------------------------------
+
+use std::collections::HashMap;
+use std::ptr::null_mut;
+use libc::c_int;
+use crate::core::config_flags::ImGuiConfigFlags_DockingEnable;
+use crate::core::context::AppContext;
+use crate::core::settings_document::{DockLayoutSettingsEntry, DockNodeSettingsEntry, SettingsDocument, WindowSettingsEntry};
+use crate::core::settings_ops::MarkIniSettingsDirty;
+use crate::core::type_defs::ImguiHandle;
+use crate::core::vec2::{ImLerpVec2, Vector2};
+use crate::docking::dock_context_ops::{clear_dock_context_nodes, DockContextBuildAddWindowsToNodes, DockContextBuildNodesFromSettings};
+use crate::docking::dock_layout::{ImGuiDockLayoutRect, ImGuiDockLayoutRequest, ImGuiDockLayoutTransition};
+use crate::docking::docking_ops::{DockSettingsHandler_GatherSettings, DockSettingsHandler_ReadDocument};
+use crate::core::settings_ops::{WindowSettingsHandler_GatherSettings, WindowSettingsHandler_ReadDocument};
+
+// Duration of an animated switch, in seconds
+const DOCK_LAYOUT_TRANSITION_DURATION: f32 = 0.25;
+
+// Capture the current dock tree and window placement into a value. Unlike saving settings, this doesn't update
+// the stored window and node settings.
+pub fn DockLayoutCapture(g: &AppContext) -> DockLayoutSettingsEntry
+{
+    let windows = WindowSettingsHandler_GatherSettings(g);
+    let nodes = if g.IO.ConfigFlags & ImGuiConfigFlags_DockingEnable != 0 { DockSettingsHandler_GatherSettings(&g.dock_context) } else { vec![] };
+    DockLayoutSettingsEntry {
+        Windows: windows.iter().map(WindowSettingsEntry::FromSettings).collect(),
+        DockNodes: nodes.iter().map(DockNodeSettingsEntry::FromSettings).collect(),
+    }
+}
+
+// Queue a layout, applied at the beginning of the next frame. With 'animate' set, floating windows and
+// dock nodes glide from their current rect to the new one instead of jumping.
+pub fn DockLayoutApply(g: &mut AppContext, layout: &DockLayoutSettingsEntry, animate: bool)
+{
+    g.dock_context.LayoutPending = Some(ImGuiDockLayoutRequest { Layout: layout.clone(), Animate: animate });
+}
+
+// Store the current layout under 'name' (replacing any previous profile with that name)
+pub fn DockLayoutSaveProfile(g: &mut AppContext, name: &str)
+{
+    // IM_ASSERT(!name.is_empty() && !name.contains('[') && !name.contains(']'));
+    let layout = DockLayoutCapture(g);
+    g.dock_context.LayoutProfiles.insert(name.to_string(), layout);
+    g.dock_context.LayoutProfileCurrent = name.to_string();
+    MarkIniSettingsDirty(g);
+}
+
+// Return false if there is no profile with that name
+pub fn DockLayoutLoadProfile(g: &mut AppContext, name: &str, animate: bool) -> bool
+{
+    let layout = match g.dock_context.LayoutProfiles.get(name) {
+        Some(layout) => layout.clone(),
+        None => return false,
+    };
+    DockLayoutApply(g, &layout, animate);
+    g.dock_context.LayoutProfileCurrent = name.to_string();
+    true
+}
+
+pub fn DockLayoutRemoveProfile(g: &mut AppContext, name: &str) -> bool
+{
+    if g.dock_context.LayoutProfiles.remove(name).is_none() {
+        return false;
+    }
+    if g.dock_context.LayoutProfileCurrent == name {
+        g.dock_context.LayoutProfileCurrent.clear();
+    }
+    MarkIniSettingsDirty(g);
+    true
+}
+
+// Sorted by name
+pub fn DockLayoutListProfiles(g: &AppContext) -> Vec<String>
+{
+    g.dock_context.LayoutProfiles.keys().cloned().collect()
+}
+
+pub fn DockLayoutGetCurrentProfile(g: &AppContext) -> &str
+{
+    g.dock_context.LayoutProfileCurrent.as_str()
+}
+
+// Rects of floating windows and root nodes, the ones a transition interpolates
+fn DockLayoutGatherRects(g: &AppContext) -> HashMap<ImguiHandle, ImGuiDockLayoutRect>
+{
+    let mut rects: HashMap<ImguiHandle, ImGuiDockLayoutRect> = HashMap::new();
+    for window in g.windows.values().filter(|window| window.DockNode.is_none()) {
+        rects.insert(window.ID, ImGuiDockLayoutRect { Pos: window.position.clone(), Size: window.SizeFull.clone() });
+    }
+    for node in g.dock_context.dock_nodes.values().filter(|node| node.IsRootNode()) {
+        rects.insert(node.ID, ImGuiDockLayoutRect { Pos: node.Pos.clone(), Size: node.Size.clone() });
+    }
+    rects
+}
+
+// Called by DockContextNewFrameUpdateUndocking(), same steps as DockSettingsHandler_ClearAll() + DockSettingsHandler_ApplyAll()
+pub unsafe fn DockContextProcessPendingLayout(g: &mut AppContext)
+{
+    let request = match g.dock_context.LayoutPending.take() {
+        Some(request) => request,
+        None => return,
+    };
+    let from_rects = if request.Animate { DockLayoutGatherRects(g) } else { HashMap::new() };
+
+    // Undock everything, then replace window and node settings with the ones of the layout
+    clear_dock_context_nodes(g, 0, false);
+    g.dock_context.NodesSettings.clear();
+    let doc = SettingsDocument { Windows: request.Layout.Windows.clone(), DockNodes: request.Layout.DockNodes.clone(), ..Default::default() };
+    WindowSettingsHandler_ReadDocument(g, null_mut(), &doc);
+    DockSettingsHandler_ReadDocument(g, null_mut(), &doc);
+
+    // Existing windows are re-targeted right away (windows which don't exist yet will pick their settings up on creation)
+    for settings in g.SettingsWindow.iter_mut().filter(|settings| settings.WantApply) {
+        if let Some(window) = g.windows.get_mut(&settings.ID) {
+            // Settings positions are relative to the viewport
+            window.ViewportPos = Vector2::from_ints(settings.ViewportPos.x as i32, settings.ViewportPos.y as i32);
+            window.position = Vector2::from_floats(window.ViewportPos.x + settings.Pos.x as f32, window.ViewportPos.y + settings.Pos.y as f32);
+            if settings.Size.x > 0 && settings.Size.y > 0 {
+                window.SizeFull = Vector2::from_ints(settings.Size.x as i32, settings.Size.y as i32);
+            }
+            window.ViewportId = settings.ViewportId;
+            window.DockId = settings.DockId;
+            window.DockOrder = settings.DockOrder as i32;
+            window.Collapsed = settings.Collapsed;
+            settings.WantApply = false;
+        }
+    }
+
+    let mut nodes_settings = g.dock_context.NodesSettings.clone();
+    DockContextBuildNodesFromSettings(g, nodes_settings.as_mut_ptr(), nodes_settings.len() as c_int);
+    DockContextBuildAddWindowsToNodes(g, 0);
+
+    g.dock_context.LayoutTransition = None;
+    if request.Animate {
+        let to_rects = DockLayoutGatherRects(g);
+        let from: HashMap<ImguiHandle, ImGuiDockLayoutRect> = from_rects.into_iter().filter(|(id, _)| to_rects.contains_key(id)).collect();
+        if !from.is_empty() {
+            g.dock_context.LayoutTransition = Some(ImGuiDockLayoutTransition { From: from, To: to_rects, Time: 0.0, Duration: DOCK_LAYOUT_TRANSITION_DURATION });
+            DockLayoutUpdateTransition(g);
+        }
+    }
+}
+
+// Called every frame by DockContextNewFrameUpdateUndocking() while a transition is in progress
+pub fn DockLayoutUpdateTransition(g: &mut AppContext)
+{
+    let delta_time = g.IO.DeltaTime;
+    let transition = match g.dock_context.LayoutTransition.as_mut() {
+        Some(transition) => transition,
+        None => return,
+    };
+    let t = if transition.Duration > 0.0 { (transition.Time / transition.Duration).min(1.0) } else { 1.0 };
+    let t = t * t * (3.0 - 2.0 * t); // Smoothstep
+    transition.Time += delta_time;
+    let finished = t >= 1.0;
+
+    for (id, from) in transition.From.iter() {
+        let to = &transition.To[id];
+        let pos = ImLerpVec2(&from.Pos, &to.Pos, t);
+        let size = ImLerpVec2(&from.Size, &to.Size, t);
+        if let Some(window) = g.windows.get_mut(id) {
+            window.position = pos;
+            window.SizeFull = size;
+        } else if let Some(node) = g.dock_context.dock_nodes.get_mut(id) {
+            node.Pos = pos;
+            node.Size = size;
+        }
+    }
+    if finished {
+        g.dock_context.LayoutTransition = None;
+    }
+}
@@ -13,7 +13,12 @@ use crate::core::condition::{ImGuiCond, ImGuiCond_Always, ImGuiCond_Appearing, I
 use crate::core::context::AppContext;
 use crate::docking::dock_node::ImGuiDockNode;
 use crate::{GImGui, SettingsHandler, hash_string};
-use crate::core::axis::{IM_GUI_AXIS_X, IM_GUI_AXIS_Y};
+use crate::core::axis::{IM_GUI_AXIS_NONE, IM_GUI_AXIS_X, IM_GUI_AXIS_Y};
+use crate::core::settings_document::{DockNodeSettingsEntry, SettingsDocument};
+use crate::core::utils::flag_clear;
+use crate::core::vec2::ImVec2ih;
+use crate::docking::dock_node_flags::ImGuiDockNodeFlags_SavedFlagsMask_;
+use crate::docking::dock_node_settings::ImGuiDockNodeSettings;
 use crate::color::color_ops::ColorConvertFloat4ToU32;
 use crate::core::config_flags::ImGuiConfigFlags_DockingEnable;
 use crate::docking::dock_context::ImGuiDockContext;
@@ -914,6 +919,8 @@ pub unsafe fn BeginDockableDragDropTarget(window: &mut ImguiWindow)
 // - DockSettingsHandler_ReadLine()
 // - DockSettingsHandler_DockNodeToSettings()
 // - DockSettingsHandler_WriteAll()
+// - DockSettingsHandler_ReadDocument()
+// - DockSettingsHandler_WriteDocument()
 //-----------------------------------------------------------------------------
 
 pub unsafe fn DockSettingsRenameNodeReferences(old_node_id: ImguiHandle, new_node_id: ImguiHandle)
@@ -1124,3 +1131,71 @@ pub unsafe fn DockSettingsHandler_WriteAll(g: &mut AppContext, SettingsHandler*
     }
     buf->appendf("\n");
 }
+
+// Typed version of DockSettingsHandler_DockNodeToSettings(), walking nodes by ID
+fn DockSettingsHandler_GatherNodeSettings(dc: &ImGuiDockContext, node_id: ImguiHandle, depth: c_int, out: &mut Vec<ImGuiDockNodeSettings>)
+{
+    let node = match dc.dock_nodes.get(&node_id) {
+        Some(node) => node,
+        None => return,
+    };
+    let mut node_settings = ImGuiDockNodeSettings::new();
+    // IM_ASSERT(depth < (1 << (sizeof(node_settings.Depth) << 3)));
+    node_settings.ID = node.ID;
+    node_settings.ParentNodeId = if node.IsRootNode() { 0 } else { node.ParentNode.ID };
+    node_settings.ParentWindowId = match &node.HostWindow {
+        Some(host_window) if node.IsDockSpace() => host_window.Parentwindow.unwrap_or(0),
+        _ => 0,
+    };
+    node_settings.SelectedTabId = node.SelectedTabId;
+    node_settings.SplitAxis = if node.IsSplitNode() { node.SplitAxis } else { IM_GUI_AXIS_NONE };
+    node_settings.Depth = depth as c_char;
+    node_settings.Flags = node.LocalFlags & ImGuiDockNodeFlags_SavedFlagsMask_;
+    node_settings.Pos = ImVec2ih::new3(&node.Pos);
+    node_settings.Size = ImVec2ih::new3(&node.Size);
+    node_settings.SizeRef = ImVec2ih::new3(&node.SizeRef);
+    out.push(node_settings);
+    if node.IsSplitNode() {
+        DockSettingsHandler_GatherNodeSettings(dc, node.ChildNodes[0].ID, depth + 1, out);
+        DockSettingsHandler_GatherNodeSettings(dc, node.ChildNodes[1].ID, depth + 1, out);
+    }
+}
+
+// Settings of all the nodes, roots sorted by ID and each followed by its children
+pub fn DockSettingsHandler_GatherSettings(dc: &ImGuiDockContext) -> Vec<ImGuiDockNodeSettings>
+{
+    let mut nodes_settings: Vec<ImGuiDockNodeSettings> = Vec::with_capacity(dc.dock_nodes.len());
+    let mut root_ids: Vec<ImguiHandle> = dc.dock_nodes.values().filter(|node| node.IsRootNode()).map(|node| node.ID).collect();
+    root_ids.sort();
+    for root_id in root_ids {
+        DockSettingsHandler_GatherNodeSettings(dc, root_id, 0, &mut nodes_settings);
+    }
+    nodes_settings
+}
+
+// Typed version of DockSettingsHandler_ReadOpen() + DockSettingsHandler_ReadLine()
+pub fn DockSettingsHandler_ReadDocument(g: &mut AppContext, _handler: *mut SettingsHandler, doc: &SettingsDocument)
+{
+    for node_n in 0 .. doc.DockNodes.len()
+    {
+        let node_settings = doc.DockNodes[node_n].ToSettings(doc.DockNodeDepth(node_n));
+        g.dock_context.NodesSettings.push(node_settings);
+    }
+    // Merge by name: a document without [DockLayout] sections (e.g. an older file) doesn't drop the profiles in memory
+    for (name, layout) in &doc.DockLayouts {
+        g.dock_context.LayoutProfiles.insert(name.clone(), layout.clone());
+    }
+}
+
+// Typed version of DockSettingsHandler_WriteAll()
+pub fn DockSettingsHandler_WriteDocument(g: &mut AppContext, _handler: *mut SettingsHandler, doc: &mut SettingsDocument)
+{
+    // Profiles are written even with docking disabled, so saving doesn't drop them
+    doc.DockLayouts = g.dock_context.LayoutProfiles.clone();
+    if flag_clear(g.IO.ConfigFlags, ImGuiConfigFlags_DockingEnable) { return ; }
+
+    // (unlike our windows settings, because nodes are always built we can do a full rewrite of the SettingsNode buffer)
+    let nodes_settings = DockSettingsHandler_GatherSettings(&g.dock_context);
+    doc.DockNodes.extend(nodes_settings.iter().map(DockNodeSettingsEntry::FromSettings));
+    g.dock_context.NodesSettings = nodes_settings;
+}
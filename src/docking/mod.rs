@@ -1,14 +1,16 @@
 pub mod dock_context;
 pub mod dock_context_ops;
+pub mod dock_layout;
+pub mod dock_layout_ops;
 mod dock_context_prune_node_data;
 pub mod dock_node;
 pub mod dock_node_flags;
 mod dock_node_ops;
-mod dock_node_settings;
+pub mod dock_node_settings;
 mod dock_node_state;
 mod dock_node_tree_info;
 mod dock_preview_data;
 mod dock_request;
 mod dock_request_type;
-mod docking_ops;
+pub mod docking_ops;
 pub mod win_dock_style;
@@ -2,8 +2,11 @@ use crate::widgets::activate_flags::IM_GUI_ACTIVATE_FLAGS_PREFER_INPUT;
 use crate::core::axis::{ImGuiAxis, IM_GUI_AXIS_X, IM_GUI_AXIS_Y};
 use crate::color::{ImGuiCol_FrameBg, ImGuiCol_FrameBgActive, ImGuiCol_FrameBgHovered};
 use crate::data_type::{
-    ImGuiDataType, IM_GUI_DATA_TYPE_DOUBLE, IM_GUI_DATA_TYPE_FLOAT, IM_GUI_DATA_TYPE_S32,
+    ImGuiDataType, IM_GUI_DATA_TYPE_DOUBLE, IM_GUI_DATA_TYPE_FLOAT, IM_GUI_DATA_TYPE_S128, IM_GUI_DATA_TYPE_S32,
+    IM_GUI_DATA_TYPE_U128,
 };
+use crate::data_type::data_type_extended_ops::{DataTypeExtendedDistance, DataTypeExtendedDragApply, DataTypeIsExtended};
+use crate::data_type::data_type_scalar::{ImGuiScalar, ImScalarBytes, ImScalarBytesMut};
 use crate::data_type_info::GDATA_TYPE_INFO;
 use crate::data_type_ops::DataTypeCompare;
 use crate::core::direction::{ImGuiDir_Left, ImGuiDir_Right};
@@ -45,6 +48,9 @@ use crate::window::ops::GetCurrentWindow;
 use crate::window::ImguiWindow;
 use crate::{data_type_ops, input_num_ops, slider_ops, text_ops, widgets, GImGui};
 use libc::{c_char, c_float, c_int, size_t, INT_MAX, INT_MIN};
+use crate::widgets::undo_ops::UndoTrackItemValue;
+use crate::widgets::unit::ImGuiUnit;
+use crate::widgets::unit_ops::{GetNextItemUnit, SetNextItemUnit};
 use std::ptr::{null, null_mut};
 
 // Widgets
@@ -236,13 +242,13 @@ pub unsafe fn DragBehaviorT<T, U>(
     return true;
 }
 
-pub unsafe fn DragBehavior(
+pub unsafe fn DragBehavior<T: ImGuiScalar>(
     id: ImguiHandle,
     data_type: ImGuiDataType,
-    p_v: &mut c_float,
+    p_v: &mut T,
     v_speed: c_float,
-    p_min: c_float,
-    p_max: c_float,
+    p_min: Option<T>,
+    p_max: Option<T>,
     format: &str,
     flags: ImGuiSliderFlags,
 ) -> bool {
@@ -270,6 +276,31 @@ pub unsafe fn DragBehavior(
         return false;
     }
 
+    // Extended types: DragBehaviorT() turns inputs into a delta on a scratch float (speed, tweak keys, accumulator),
+    // which is then applied in the type itself. The part it can't represent yet (e.g. less than 1 for an integer) stays in g.DragCurrentAccum.
+    // The bounds are of the same type as the value, so they are compared and applied exactly.
+    if DataTypeIsExtended(data_type) {
+        // IM_ASSERT(mem::size_of::<T>() == GDATA_TYPE_INFO[data_type].Size);
+        let bounds: Option<(T, T)> = p_min.zip(p_max).filter(|(v_min, v_max)| DataTypeCompare(data_type, v_min, v_max) < 0);
+        let v_speed: c_float = match bounds {
+            Some((v_min, v_max)) if v_speed == 0.0 => DataTypeExtendedDistance(data_type, ImScalarBytes(&v_min), ImScalarBytes(&v_max)) as c_float * g.DragSpeedDefaultRatio,
+            _ => v_speed,
+        };
+        let is_integer: bool = data_type == IM_GUI_DATA_TYPE_S128 || data_type == IM_GUI_DATA_TYPE_U128;
+        let mut delta: c_float = 0.0;
+        if !DragBehaviorT(IM_GUI_DATA_TYPE_FLOAT, &mut delta, v_speed, 0.0, 0.0, if is_integer { "%d" } else { format }, (flags & !ImGuiSliderFlags_Logarithmic) | ImGuiSliderFlags_NoRoundToFormat) {
+            return false;
+        }
+        g.DragCurrentAccum += delta;
+        return DataTypeExtendedDragApply(
+            data_type,
+            ImScalarBytesMut(p_v),
+            &mut g.DragCurrentAccum,
+            bounds.as_ref().map(|(v_min, _)| ImScalarBytes(v_min)),
+            bounds.as_ref().map(|(_, v_max)| ImScalarBytes(v_max)),
+        );
+    }
+
     //     match data_type
     //     {
     //     IM_GUI_DATA_TYPE_S8 =>     {
@@ -287,19 +318,24 @@ pub unsafe fn DragBehavior(
     //     IM_GUI_DATA_TYPE_COUNT =>  break;
     //     }
     //     // IM_ASSERT(0);
-    DragBehaviorT(data_type, p_v, v_speed, p_min, p_max, format, flags)
+    let mut v: c_float = p_v.ToFloat();
+    if !DragBehaviorT(data_type, &mut v, v_speed, p_min.map_or(c_float::MIN, T::ToFloat), p_max.map_or(c_float::MIN, T::ToFloat), format, flags) {
+        return false;
+    }
+    *p_v = T::FromFloat(v);
+    true
     // return false;
 }
 
 // Note: p_data, p_min and p_max are _pointers_ to a memory address holding the data. For a Drag widget, p_min and p_max are optional.
 // Read code of e.g. DragFloat(), DragInt() etc. or examples in 'Demo->Widgets->Data Types' to understand how to use this function directly.
-pub unsafe fn DragScalar<T>(
+pub unsafe fn DragScalar<T: ImGuiScalar>(
     label: String,
     data_type: ImGuiDataType,
-    p_data: &mut c_float,
+    p_data: &mut T,
     v_speed: c_float,
-    p_min: Option<c_float>,
-    p_max: Option<c_float>,
+    p_min: Option<T>,
+    p_max: Option<T>,
     format: &mut String,
     flags: ImGuiSliderFlags,
 ) -> bool {
@@ -311,7 +347,9 @@ pub unsafe fn DragScalar<T>(
     let g = GImGui; // ImGuiContext& g = *GImGui;
     let setyle = &mut g.style;
     let mut id: ImguiHandle = window.id_from_str(label, );
+    let value_before: T = *p_data;
     let w: c_float = CalcItemWidth(g);
+    let unit: Option<ImGuiUnit> = GetNextItemUnit(g);
 
     let label_size: Vector2 = CalcTextSize(, label, true, 0.0);
     let mut frame_bb: ImRect = ImRect::new(
@@ -408,25 +446,20 @@ pub unsafe fn DragScalar<T>(
         let is_clamp_input: bool = flag_set(flags, ImGuiSliderFlags_AlwaysClamp)
             && (p_min.is_none()
                 || p_max.is_none()
-                || DataTypeCompare(data_type, &p_min.unwrap_or(0.0), &p_max.unwrap_or(0.0)) < 0);
-        return input_num_ops::TempInputScalar(
+                || DataTypeCompare(data_type, &p_min.unwrap_or_default(), &p_max.unwrap_or_default()) < 0);
+        let value_changed: bool = input_num_ops::TempInputScalar(
             &mut frame_bb,
             id,
-            label,
+            label.clone(),
             data_type,
             p_data,
             format,
-            if is_clamp_input {
-                p_min.unwrap_or(0.0)
-            } else {
-                c_float::MIN
-            },
-            if is_clamp_input {
-                p_max.unwrap_or(0.0)
-            } else {
-                c_float::MIN
-            },
+            if is_clamp_input { p_min } else { None },
+            if is_clamp_input { p_max } else { None },
+            unit.as_ref(),
         );
+        UndoTrackItemValue(g, &label, p_data, value_before, value_changed);
+        return value_changed;
     }
 
     // Draw frame
@@ -457,8 +490,8 @@ pub unsafe fn DragScalar<T>(
         data_type,
         p_data,
         v_speed,
-        p_min.unwrap_or(c_float::MIN),
-        p_max.unwrap_or(c_float::MIN),
+        p_min,
+        p_max,
         format,
         flags,
     );
@@ -473,8 +506,9 @@ pub unsafe fn DragScalar<T>(
             value_buf,
             value_buf.len(),
             data_type,
-            *p_data,
+            p_data,
             format,
+            unit.as_ref(),
         );
     if g.LogEnabled {
         // LogSetNextTextDecoration("{", "}");
@@ -504,18 +538,20 @@ pub unsafe fn DragScalar<T>(
         );
     }
 
+    UndoTrackItemValue(g, &label, p_data, value_before, value_changed);
+
     // IMGUI_TEST_ENGINE_ITEM_INFO(id, label, g.last_item_data.StatusFlags);
     return value_changed;
 }
 
-pub unsafe fn DragScalarN(
+pub unsafe fn DragScalarN<T: ImGuiScalar>(
     label: String,
     data_type: ImGuiDataType,
-    p_data: &mut [c_float],
+    p_data: &mut [T],
     components: usize,
     v_speed: c_float,
-    p_min: &mut [c_float],
-    p_max: &mut [c_float],
+    p_min: &[T],
+    p_max: &[T],
     format: &str,
     flags: ImGuiSliderFlags,
 ) -> bool {
@@ -526,6 +562,7 @@ pub unsafe fn DragScalarN(
 
     let g = GImGui; // ImGuiContext& g = *GImGui;
     let mut value_changed: bool = false;
+    let unit: Option<ImGuiUnit> = GetNextItemUnit(g);
     BeginGroup();
     push_str_id(g, label);
     PushMultiItemsWidths(components, CalcItemWidth(g));
@@ -536,6 +573,9 @@ pub unsafe fn DragScalarN(
         if i > 0 {
             same_line(g, 0.0, g.style.ItemInnerSpacing.x);
         }
+        if let Some(unit) = &unit {
+            SetNextItemUnit(g, unit);
+        }
         value_changed |= DragScalar(
             "",
             data_type,
@@ -17,7 +17,7 @@ pub struct ImDrawCmd {
     pub VtxOffset: size_t, // 4    // Start offset in vertex buffer. IM_GUI_BACKEND_FLAGS_RENDERER_HAS_VTX_OFFSET: always 0, otherwise may be >0 to support meshes larger than 64K vertices with 16-bit indices.
     pub IdxOffset: size_t, // 4    // Start offset in index buffer.
     pub ElemCount: size_t, // 4    // Number of indices (multiple of 3) to be rendered as triangles. Vertices are stored in the callee ImDrawList's vtx_buffer[] array, indices in idx_buffer[].
-    pub UserCallback: ImDrawCallback, // 4-8  // If != NULL, call the function instead of rendering the vertices. clip_rect and texture_id will be set normally.
+    pub UserCallback: Option<ImDrawCallback>, // 4-8  // If != NULL, call the function instead of rendering the vertices. clip_rect and texture_id will be set normally.
     pub UserCallbackData: *mut c_void, // 4-8  // The draw callback code can access this.
 }
 
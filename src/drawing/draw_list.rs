@@ -1391,7 +1391,7 @@ impl ImDrawList {
             self.AddDrawCmd();
             curr_cmd = &mut self.CmdBuffer[self.CmdBuffer.len() - 1];
         }
-        curr_cmd.UserCallback = callback;
+        curr_cmd.UserCallback = Some(callback);
         curr_cmd.UserCallbackData = callback_data;
 
         self.AddDrawCmd(); // Force a new command after us (see comment below)
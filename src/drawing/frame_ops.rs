@@ -51,6 +51,7 @@ use crate::viewport::viewport_ops::{
     UpdateViewportsNewFrame,
 };
 use crate::window::focus::FocusTopMostWindowUnderOne;
+use crate::window::input_text_diagnostics::InputTextGcDiagnostics;
 use crate::window::ops::{AddWindowToSortBuffer, Begin, End, SetNextWindowSize};
 use crate::window::window_flags::ImGuiWindowFlags_ChildWindow;
 use crate::window::ImguiWindow;
@@ -78,7 +79,7 @@ pub fn NewFrame(g: &mut AppContext) {
     g.ConfigFlagsCurrFrame = g.IO.ConfigFlags;
 
     // Load settings on first frame, save settings when modified (after a delay)
-    UpdateSettings();
+    UpdateSettings(g);
 
     g.Time += g.IO.DeltaTime;
     g.WithinFrameScope = true;
@@ -195,6 +196,9 @@ pub fn NewFrame(g: &mut AppContext) {
         g.ActiveIdUsingKeyInputMask.ClearAllBits();
     }
 
+    // Drop the diagnostics of InputText() widgets which weren't submitted last frame
+    InputTextGcDiagnostics(g);
+
     // #ifndef IMGUI_DISABLE_OBSOLETE_KEYIO
     if g.ActiveId == 0 {
         g.ActiveIdUsingNavInputMask = 0;
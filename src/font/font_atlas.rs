@@ -418,7 +418,7 @@ impl ImFontAtlas {
     }
 
     // void              GetTexDataAsRGBA32(unsigned char** out_pixels, c_int* out_width, c_int* out_height, c_int* out_bytes_per_pixel = NULL);  // 4 bytes-per-pixel
-    pub unsafe fn GetTexAdataAsRGBA32(
+    pub unsafe fn GetTexDataAsRGBA32(
         &mut self,
         out_pixels: *mut *mut c_uint,
         out_width: *mut size_t,
@@ -1,7 +1,8 @@
-use crate::backends::{dx10_renderer_backend, dx9_renderer_backend};
+use crate::backends::{dx10_renderer_backend, dx9_renderer_backend, software_renderer_backend};
 
 pub enum BackendRendererUserData {
     None,
     Directx9(dx9_renderer_backend::DirectxData),
-    Directx10(dx10_renderer_backend::DirectxData)
+    Directx10(dx10_renderer_backend::DirectxData),
+    Software(software_renderer_backend::SoftwareRendererData),
 }
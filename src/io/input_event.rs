@@ -6,7 +6,7 @@ use crate::io::key::ImGuiKey;
 use crate::core::type_defs::ImguiHandle;
 use libc::{c_float, c_int, c_uint};
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone)]
 pub struct ImguiInputEvent {
     pub Type: ImGuiInputEventType,
     pub Source: ImGuiInputSource,
@@ -24,6 +24,8 @@ pub struct ImguiInputEvent {
     // if Type == ImGuiInputEventType_Text
     pub AppFocused: ImGuiInputEventAppFocused,
     // if Type == ImGuiInputEventType_Focus
+    pub Preedit: ImGuiInputEventPreedit,
+    // if Type == ImGuiInputEventType_Preedit
     pub IgnoredAsSame: bool,
     pub AddedByTestEngine: bool,
     // ImGuiInputEvent() { memset(this, 0, sizeof(*this)); }
@@ -57,22 +59,34 @@ pub struct ImGuiInputEventMouseViewport {
 }
 
 #[derive(Default, Debug, Clone, Copy)]
-struct ImGuiInputEventKey {
+pub struct ImGuiInputEventKey {
     // ImGuiKey pub(crate) Key;
     pub Key: ImGuiKey,
     // pub: bool(crate) Down;
-    pub Down: Down,
-    // let mut AnalogValue: c_float = 0.0;
+    pub Down: bool,
+    // c_float AnalogValue;
+    pub AnalogValue: c_float, // 0.0..1.0 for gamepad values (dead zone already applied), 0.0/1.0 for keys
 }
 
 #[derive(Default, Debug, Clone, Copy)]
-struct ImGuiInputEventText {
+pub struct ImGuiInputEventText {
     // Char: c_uint;
     pub Char: c_uint,
 }
 
-#[derive(Default,Debug,Clone.Copy)]
-struct ImGuiInputEventAppFocused {
+#[derive(Default, Debug, Clone, Copy)]
+pub struct ImGuiInputEventAppFocused {
     // Focused: bool;
     pub Focused: bool,
 }
+
+// In-progress IME composition. Committed text still arrives as ImGuiInputEventType_Text events.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ImGuiInputEventPreedit {
+    pub Text: String,
+    // Composition string, empty when the composition ended or was cancelled
+    pub CursorPos: c_int,
+    // Caret position inside Text, in characters
+    pub ClauseStart: c_int,
+    pub ClauseEnd: c_int, // Clause currently being converted, in characters. ClauseStart == ClauseEnd when the IME doesn't report one
+}
@@ -12,5 +12,6 @@ pub const ImGuiInputEventType_MouseViewport: ImGuiInputEventType = 4;
 pub const ImGuiInputEventType_Key: ImGuiInputEventType = 5;
 pub const ImGuiInputEventType_Text: ImGuiInputEventType = 6;
 pub const ImGuiInputEventType_Focus: ImGuiInputEventType = 7;
-pub const ImGuiInputEventType_COUNT: ImGuiInputEventType = 8;
+pub const ImGuiInputEventType_Preedit: ImGuiInputEventType = 8;
+pub const ImGuiInputEventType_COUNT: ImGuiInputEventType = 9;
 // };
@@ -3,11 +3,11 @@
 
 use crate::debugging::debug_log_flags::IM_GUI_DEBUG_LOG_FLAGS_EVENT_IO;
 use crate::imgui::GImGui;
-use crate::io::input_event::ImguiInputEvent;
+use crate::io::input_event::{ImGuiInputEventPreedit, ImguiInputEvent};
 use crate::io::input_event_type::{
     ImGuiInputEventType_Focus, ImGuiInputEventType_Key, ImGuiInputEventType_MouseButton,
     ImGuiInputEventType_MousePos, ImGuiInputEventType_MouseViewport,
-    ImGuiInputEventType_MouseWheel, ImGuiInputEventType_Text,
+    ImGuiInputEventType_MouseWheel, ImGuiInputEventType_Preedit, ImGuiInputEventType_Text,
 };
 use crate::widgets::input_flags::{
     ImGuiInputFlags, ImGuiInputFlags_None, ImGuiInputFlags_Repeat,
@@ -25,11 +25,14 @@ use crate::io::key::{
     ImGuiKey_ModSuper, ImGuiKey_NamedKey_BEGIN, ImGuiKey_NamedKey_END, ImGuiKey_None,
 };
 use crate::io::key_data::ImGuiKeyData;
+use crate::io::key_routing::ImGuiKeyOwner_Any;
+use crate::io::key_routing_ops::TestKeyOwner;
 use crate::io::mod_flags::ImGuiModFlags;
 use crate::io::mouse_button::ImGuiMouseButton;
 use crate::io::mouse_cursor::ImGuiMouseCursor;
 use crate::rect::ImRect;
 use crate::core::string_ops::{str_to_const_c_char_ptr, ImFormatString};
+use crate::core::type_defs::ImguiHandle;
 use crate::core::vec2::Vector2;
 use libc::{c_char, c_float, c_int, c_uint};
 use std::ffi::CString;
@@ -400,10 +403,14 @@ pub fn GetKeyVector2D(
 // Consider transitioning from 'IsKeyDown(MY_ENGINE_KEY_A)' (<1.87) to IsKeyDown(ImGuiKey_A) (>= 1.87)
 // IsKeyDown: bool(ImGuiKey key)
 pub fn IsKeyDown(key: ImGuiKey) -> bool {
+    let g = GImGui; // ImGuiContext& g = *GImGui;
     let key_data = GetKeyData(key);
     if !key_data.Down {
         return false;
     }
+    if !TestKeyOwner(g, key, ImGuiKeyOwner_Any) {
+        return false;
+    }
     return true;
 }
 
@@ -421,8 +428,16 @@ pub unsafe fn IsKeyPressed(key: ImGuiKey, repeat: bool) -> bool {
 
 // Important: unlike legacy IsKeyPressed(ImGuiKey, repeat: bool=true) which DEFAULT to repeat, this requires EXPLICIT repeat.
 // [Internal] 2022/07: Do not call this directly! It is a temporary entry point which we will soon replace with an overload for IsKeyPressed() when we introduce key ownership.
+// Keys locked with SetKeyOwner(..., ImGuiInputFlags_LockThisFrame/LockUntilRelease) are not reported: use IsKeyPressedWithOwner() or Shortcut().
 // IsKeyPressedEx: bool(ImGuiKey key, ImGuiInputFlags flags)
 pub unsafe fn IsKeyPressedEx(key: ImGuiKey, flags: ImGuiInputFlags) -> bool {
+    return IsKeyPressedWithOwner(key, ImGuiKeyOwner_Any, flags);
+}
+
+// Same as IsKeyPressedEx() but only return true if 'owner_id' passes TestKeyOwner() for the key.
+// IsKeyPressed: bool(ImGuiKey key, ImGuiID owner_id, ImGuiInputFlags flags)
+pub unsafe fn IsKeyPressedWithOwner(key: ImGuiKey, owner_id: ImguiHandle, flags: ImGuiInputFlags) -> bool {
+    let g = GImGui; // ImGuiContext& g = *GImGui;
     let key_data = GetKeyData(key);
     if !key_data.Down {
         // In theory this should already be encoded as (DownDuration < 0.0), but testing this facilitate eating mechanism (until we finish work on input ownership)
@@ -445,6 +460,9 @@ pub unsafe fn IsKeyPressedEx(key: ImGuiKey, flags: ImGuiInputFlags) -> bool {
     if !pressed {
         return false;
     }
+    if !TestKeyOwner(g, key, owner_id) {
+        return false;
+    }
     return true;
 }
 
@@ -666,6 +684,17 @@ pub fn DebugPrintInputEvent(prefix: *const c_char, e: *const ImguiInputEvent) {
                 IMGUI_DEBUG_LOG_IO("{}: AppFocused {}\n", prefix, e.AppFocused.Focused);
                 return;
             }
+            if e.Type == ImGuiInputEventType_Preedit {
+                IMGUI_DEBUG_LOG_IO(
+                    "{}: Preedit \"{}\" (cursor {}, clause {}..{})\n",
+                    prefix,
+                    e.Preedit.Text,
+                    e.Preedit.CursorPos,
+                    e.Preedit.ClauseStart,
+                    e.Preedit.ClauseEnd,
+                );
+                return;
+            }
         }
     }
 }
@@ -811,6 +840,23 @@ pub fn UpdateInputEvents(trickle_fast_inputs: bool) {
             if trickle_interleaved_keys_and_text {
                 text_inputted = true;
             }
+        } else if e.Type == ImGuiInputEventType_Preedit {
+            e.IgnoredAsSame = (io.InputPreedit == e.Preedit);
+            if !e.IgnoredAsSame {
+                // Trickling Rule: same as text, so a composition update never lands before the keys/text that preceded it
+                if trickle_fast_inputs
+                    && ((key_changed && trickle_interleaved_keys_and_text)
+                        || mouse_button_changed != 0
+                        || mouse_moved
+                        || mouse_wheeled)
+                {
+                    break;
+                }
+                io.InputPreedit = e.Preedit.clone();
+                if trickle_interleaved_keys_and_text {
+                    text_inputted = true;
+                }
+            }
         } else if e.Type == ImGuiInputEventType_Focus {
             // We intentionally overwrite this and process lower, in order to give a chance
             // to multi-viewports backends to queue AddFocusEvent(false) + AddFocusEvent(true) in same frame.
@@ -818,6 +864,9 @@ pub fn UpdateInputEvents(trickle_fast_inputs: bool) {
             e.IgnoredAsSame = (io.AppFocusLost == focus_lost);
             if !e.IgnoredAsSame {
                 io.AppFocusLost = focus_lost;
+                if focus_lost {
+                    io.InputPreedit = ImGuiInputEventPreedit::default(); // Backends don't reliably get an end-of-composition event when focus moves away
+                }
             }
         } else {
             // IM_ASSERT(0 && "Unknown event!");
@@ -247,3 +247,20 @@ pub const ImGuiKey_NavGamepadCancel: ImGuiKey = ImGuiKey_GamepadFaceRight;
 pub const ImGuiKey_NavGamepadMenu: ImGuiKey = ImGuiKey_GamepadFaceLeft;
 pub const ImGuiKey_NavGamepadInput: ImGuiKey = ImGuiKey_GamepadFaceUp;
 // };
+
+// typedef int ImGuiKeyChord;          // -> ImGuiKey | ImGuiMod_XXX    // Flags: for Shortcut(), SetShortcutRouting()
+// A key chord is a named key OR-ed with one or more ImGuiMod_XXX values (e.g. ImGuiMod_Ctrl | ImGuiKey_S).
+// The ImGuiMod_XXX bits are ImGuiModFlags shifted by 12, so (io.KeyMods << 12) gives the chord mods currently held.
+pub type ImGuiKeyChord = c_int;
+
+pub const ImGuiMod_None: ImGuiKeyChord = 0;
+pub const ImGuiMod_Ctrl: ImGuiKeyChord = 1 << 12;
+pub const ImGuiMod_Shift: ImGuiKeyChord = 1 << 13;
+pub const ImGuiMod_Alt: ImGuiKeyChord = 1 << 14;
+// Option/Menu
+pub const ImGuiMod_Super: ImGuiKeyChord = 1 << 15;
+// Cmd/Super/Windows
+pub const ImGuiMod_Shortcut: ImGuiKeyChord = 1 << 11;
+// Alias for Ctrl (non-macOS) _or_ Super (macOS), resolved with io.ConfigMacOSXBehaviors
+pub const ImGuiMod_Mask_: ImGuiKeyChord = 0xF800;
+pub const ImGuiMod_ModFlagsShift_: c_int = 12;
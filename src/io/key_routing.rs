@@ -0,0 +1,86 @@
+#![allow(non_upper_case_globals)]
+
+use crate::core::type_defs::{ImguiHandle, INVALID_IMGUI_HANDLE};
+use crate::io::key::ImGuiKey_NamedKey_COUNT;
+
+// Special owner IDs for SetKeyOwner(), TestKeyOwner() and Shortcut()
+pub const ImGuiKeyOwner_Any: ImguiHandle = 0;                      // Accept key that have an owner, UNLESS a call to SetKeyOwner() explicitly used ImGuiInputFlags_LockThisFrame or ImGuiInputFlags_LockUntilRelease.
+pub const ImGuiKeyOwner_None: ImguiHandle = INVALID_IMGUI_HANDLE;  // Require key to have no owner.
+
+// typedef ImS16 ImGuiKeyRoutingIndex;
+pub type ImGuiKeyRoutingIndex = i16;
+
+// This extends ImGuiKeyData but only for named keys (legacy keys don't support the new features)
+// Stored in main context (1 per named key). In the future it might be merged into ImGuiKeyData.
+#[derive(Debug, Clone, Copy)]
+pub struct ImGuiKeyOwnerData {
+    pub OwnerCurr: ImguiHandle,
+    pub OwnerNext: ImguiHandle,
+    pub LockThisFrame: bool,
+    // Reading this key requires explicit owner id (until end of frame). Set by ImGuiInputFlags_LockThisFrame.
+    pub LockUntilRelease: bool, // Reading this key requires explicit owner id (until key is released). Set by ImGuiInputFlags_LockUntilRelease. When this is true LockThisFrame is always true as well.
+}
+
+impl Default for ImGuiKeyOwnerData {
+    fn default() -> Self {
+        Self {
+            OwnerCurr: ImGuiKeyOwner_None,
+            OwnerNext: ImGuiKeyOwner_None,
+            LockThisFrame: false,
+            LockUntilRelease: false,
+        }
+    }
+}
+
+// Routing table entry (sizeof() == 16 bytes)
+#[derive(Debug, Clone, Copy)]
+pub struct ImGuiKeyRoutingData {
+    pub NextEntryIndex: ImGuiKeyRoutingIndex,
+    pub Mods: u16,
+    // Technically we'd only need 4-bits but for simplify we store ImGuiMod_ values which need 16-bits. ImGuiMod_Shortcut is already translated to Ctrl/Super.
+    pub RoutingNextScore: u8,
+    // Lower is better (0: perfect score)
+    pub RoutingCurr: ImguiHandle,
+    pub RoutingNext: ImguiHandle,
+}
+
+impl Default for ImGuiKeyRoutingData {
+    fn default() -> Self {
+        Self {
+            NextEntryIndex: -1,
+            Mods: 0,
+            RoutingNextScore: 255,
+            RoutingCurr: ImGuiKeyOwner_None,
+            RoutingNext: ImGuiKeyOwner_None,
+        }
+    }
+}
+
+// Routing table: maintain a desired owner for each possible key-chord (key + mods), and setup owner in NewFrame() when mods are matching.
+// Stored in main context (1 instance)
+#[derive(Debug, Clone)]
+pub struct ImGuiKeyRoutingTable {
+    // ImGuiKeyRoutingIndex            Index[ImGuiKey_NamedKey_COUNT]; // Index of first entry in Entries[]
+    pub Index: Vec<ImGuiKeyRoutingIndex>,
+    pub Entries: Vec<ImGuiKeyRoutingData>,
+    pub EntriesNext: Vec<ImGuiKeyRoutingData>, // Double-buffer to avoid reallocation (could use a shared buffer)
+}
+
+impl Default for ImGuiKeyRoutingTable {
+    fn default() -> Self {
+        Self {
+            Index: vec![-1; ImGuiKey_NamedKey_COUNT as usize],
+            Entries: vec![],
+            EntriesNext: vec![],
+        }
+    }
+}
+
+impl ImGuiKeyRoutingTable {
+    // void Clear() { for (int n = 0; n < IM_ARRAYSIZE(Index); n++) Index[n] = -1; Entries.clear(); EntriesNext.clear(); }
+    pub fn Clear(&mut self) {
+        self.Index.iter_mut().for_each(|index| *index = -1);
+        self.Entries.clear();
+        self.EntriesNext.clear();
+    }
+}
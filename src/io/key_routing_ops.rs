@@ -0,0 +1,443 @@
+#![allow(non_snake_case)]
+
+//-----------------------------------------------------------------------------
+// [SECTION] KEY OWNERSHIP, SHORTCUT ROUTING
+//-----------------------------------------------------------------------------
+// - GetKeyOwner()
+// - GetKeyOwnerData() [Internal]
+// - SetKeyOwner()
+// - SetItemKeyOwner()
+// - TestKeyOwner()
+// - UpdateKeyOwnership() [Internal]
+// - UpdateKeyRoutingTable() [Internal]
+// - GetShortcutRoutingData() [Internal]
+// - SetShortcutRouting()
+// - TestShortcutRouting()
+// - Shortcut()
+// - ParseKeyChord()
+//-----------------------------------------------------------------------------
+// Polling a key with IsKeyPressed() answers "is the key pressed?", so two windows both reacting to Ctrl+S
+// would both fire. Ownership answers "who gets the key?":
+// - A key can be claimed with SetKeyOwner()/SetItemKeyOwner(). While owned, only the owner (or code polling
+//   with ImGuiKeyOwner_Any, unless the key was locked) sees it.
+// - Shortcut() doesn't claim a key directly: it submits a route with a score, the best route submitted during
+//   frame N becomes the owner of the key-chord for frame N+1 (resolved in NewFrame()).
+// - Focused routes are scored by distance to the focused window and its focus scope (see PushFocusScope()),
+//   global routes (app-wide accelerators) by their priority.
+//-----------------------------------------------------------------------------
+
+use libc::c_int;
+use crate::core::context::AppContext;
+use crate::core::type_defs::{ImguiHandle, INVALID_IMGUI_HANDLE};
+use crate::core::utils::flag_set;
+use crate::io::input_ops::{GKeyNames, IsKeyPressedWithOwner, IsNamedKey};
+use crate::io::key::{ImGuiKey, ImGuiKeyChord, ImGuiKey_Keyboard_BEGIN, ImGuiKey_Keyboard_END, ImGuiKey_ModAlt, ImGuiKey_ModCtrl, ImGuiKey_ModShift, ImGuiKey_ModSuper, ImGuiKey_NamedKey_BEGIN, ImGuiKey_NamedKey_END, ImGuiKey_None, ImGuiMod_Alt, ImGuiMod_Ctrl, ImGuiMod_Mask_, ImGuiMod_ModFlagsShift_, ImGuiMod_Shift, ImGuiMod_Shortcut, ImGuiMod_Super};
+use crate::io::key_routing::{ImGuiKeyOwnerData, ImGuiKeyOwner_Any, ImGuiKeyOwner_None, ImGuiKeyRoutingData};
+use crate::widgets::input_flags::{ImGuiInputFlags, ImGuiInputFlags_CondDefault_, ImGuiInputFlags_CondActive, ImGuiInputFlags_CondHovered, ImGuiInputFlags_CondMask_, ImGuiInputFlags_LockThisFrame, ImGuiInputFlags_LockUntilRelease, ImGuiInputFlags_RepeatRateMask_, ImGuiInputFlags_Repeat, ImGuiInputFlags_RouteAlways, ImGuiInputFlags_RouteFocused, ImGuiInputFlags_RouteGlobal, ImGuiInputFlags_RouteGlobalHigh, ImGuiInputFlags_RouteGlobalLow, ImGuiInputFlags_RouteMask_, ImGuiInputFlags_RouteUnlessBgFocused};
+
+// inline bool IsNamedKeyOrModKey(ImGuiKey key)
+pub fn IsNamedKeyOrModKey(key: ImGuiKey) -> bool
+{
+    IsNamedKey(key) || (key & ImGuiMod_Mask_) != 0
+}
+
+// ImGuiKey ConvertSingleModFlagToKey(ImGuiKey key)
+pub fn ConvertSingleModFlagToKey(key: ImGuiKey) -> ImGuiKey
+{
+    match key {
+        ImGuiMod_Ctrl => ImGuiKey_ModCtrl,
+        ImGuiMod_Shift => ImGuiKey_ModShift,
+        ImGuiMod_Alt => ImGuiKey_ModAlt,
+        ImGuiMod_Super => ImGuiKey_ModSuper,
+        _ => key,
+    }
+}
+
+// Resolve ImGuiMod_Shortcut into Ctrl (Windows/Linux) or Super (macOS)
+pub fn ConvertShortcutMod(g: &AppContext, key_chord: ImGuiKeyChord) -> ImGuiKeyChord
+{
+    // IM_ASSERT(key_chord & ImGuiMod_Shortcut);
+    let mods = if g.IO.ConfigMacOSXBehaviors { ImGuiMod_Super } else { ImGuiMod_Ctrl };
+    (key_chord & !ImGuiMod_Shortcut) | mods
+}
+
+// Mods currently held, in ImGuiMod_XXX form (io.KeyMods holds ImGuiModFlags_XXX)
+pub fn GetKeyChordModsFromIO(g: &AppContext) -> ImGuiKeyChord
+{
+    (g.IO.KeyMods as ImGuiKeyChord) << ImGuiMod_ModFlagsShift_
+}
+
+pub fn GetKeyOwnerData(g: &mut AppContext, mut key: ImGuiKey) -> &mut ImGuiKeyOwnerData
+{
+    if (key & ImGuiMod_Mask_) != 0 {
+        key = ConvertSingleModFlagToKey(key);
+    }
+    // IM_ASSERT(IsNamedKey(key));
+    &mut g.KeysOwnerData[(key - ImGuiKey_NamedKey_BEGIN) as usize]
+}
+
+pub fn GetKeyOwner(g: &mut AppContext, key: ImGuiKey) -> ImguiHandle
+{
+    if !IsNamedKeyOrModKey(key) {
+        return ImGuiKeyOwner_None;
+    }
+    let owner_data = GetKeyOwnerData(g, key);
+    let owner_id = owner_data.OwnerCurr;
+    if g.ActiveIdUsingKeyInputMask.TestBit(key) && owner_id != g.ActiveId && owner_id != ImGuiKeyOwner_Any {
+        if key >= ImGuiKey_Keyboard_BEGIN && key < ImGuiKey_Keyboard_END {
+            return ImGuiKeyOwner_None;
+        }
+    }
+    owner_id
+}
+
+// TestKeyOwner(..., ID)   : (owner == None || owner == ID)
+// TestKeyOwner(..., None) : (owner == None)
+// TestKeyOwner(..., Any)  : no owner test
+// All paths are also testing for key not being locked, for the rare cases that key have been locked with using ImGuiInputFlags_LockXXX flags.
+pub fn TestKeyOwner(g: &mut AppContext, key: ImGuiKey, owner_id: ImguiHandle) -> bool
+{
+    if !IsNamedKeyOrModKey(key) {
+        return true;
+    }
+    if g.ActiveIdUsingKeyInputMask.TestBit(key) && owner_id != g.ActiveId && owner_id != ImGuiKeyOwner_Any {
+        if key >= ImGuiKey_Keyboard_BEGIN && key < ImGuiKey_Keyboard_END {
+            return false;
+        }
+    }
+
+    let owner_data = GetKeyOwnerData(g, key);
+    if owner_id == ImGuiKeyOwner_Any {
+        return !owner_data.LockThisFrame;
+    }
+
+    // Note: SetKeyOwner() sets OwnerCurr. It is not strictly required for most mouse routing overlap (because of ActiveId/HoveredId
+    // are acting as filter before this has a chance to filter), but sane as soon as user tries to look into things.
+    // Setting OwnerCurr in SetKeyOwner() is more consistent than testing OwnerNext here: would be inconsistent with getter and other functions.
+    if owner_data.OwnerCurr != owner_id {
+        if owner_data.LockThisFrame {
+            return false;
+        }
+        if owner_data.OwnerCurr != ImGuiKeyOwner_None {
+            return false;
+        }
+    }
+    true
+}
+
+// _LockXXX flags are useful to lock keys away from code which is not input-owner aware.
+// When using _LockXXX flags, you can use ImGuiKeyOwner_Any to lock keys from everyone.
+// - SetKeyOwner(..., None)              : clears owner
+// - SetKeyOwner(..., Any, !Lock)        : illegal (assert)
+// - SetKeyOwner(..., Any or None, Lock) : set lock
+pub fn SetKeyOwner(g: &mut AppContext, key: ImGuiKey, owner_id: ImguiHandle, flags: ImGuiInputFlags)
+{
+    // IM_ASSERT(IsNamedKeyOrModKey(key) && (owner_id != ImGuiKeyOwner_Any || (flags & (ImGuiInputFlags_LockThisFrame | ImGuiInputFlags_LockUntilRelease)))); // Can only use _Any with _LockXXX flags (to eat a key away without an ID to retrieve it)
+    // IM_ASSERT((flags & ~ImGuiInputFlags_SupportedBySetKeyOwner) == 0); // Passing flags not supported by this function!
+    let owner_data = GetKeyOwnerData(g, key);
+    owner_data.OwnerCurr = owner_id;
+    owner_data.OwnerNext = owner_id;
+
+    // We cannot lock by default as it would likely break lots of legacy code.
+    // In the case of using LockUntilRelease while key is not down we still lock during the frame (no key_data.Down test)
+    owner_data.LockUntilRelease = flag_set(flags, ImGuiInputFlags_LockUntilRelease);
+    owner_data.LockThisFrame = flag_set(flags, ImGuiInputFlags_LockThisFrame) || owner_data.LockUntilRelease;
+}
+
+// This is more or less equivalent to:
+//   if (IsItemHovered() || IsItemActive())
+//       SetKeyOwner(key, GetItemID());
+// Extensive uses of that (e.g. many calls for a single item) may want to manually perform the tests once and then call SetKeyOwner() multiple times.
+// More advanced usage scenarios may want to call SetKeyOwner() manually based on different condition.
+// Worth noting is that only one item can be hovered and only one item can be active, therefore this usage pattern doesn't need to bother with routing and priority.
+pub fn SetItemKeyOwner(g: &mut AppContext, key: ImGuiKey, mut flags: ImGuiInputFlags)
+{
+    let id = g.last_item_data.id;
+    if id == 0 || (g.HoveredId != id && g.ActiveId != id) {
+        return;
+    }
+    if (flags & ImGuiInputFlags_CondMask_) == 0 {
+        flags |= ImGuiInputFlags_CondDefault_;
+    }
+    if (g.HoveredId == id && flag_set(flags, ImGuiInputFlags_CondHovered)) || (g.ActiveId == id && flag_set(flags, ImGuiInputFlags_CondActive)) {
+        // IM_ASSERT((flags & ~ImGuiInputFlags_SupportedBySetItemKeyOwner) == 0); // Passing flags not supported by this function!
+        SetKeyOwner(g, key, id, flags & !ImGuiInputFlags_CondMask_);
+    }
+}
+
+// [Internal] Called by UpdateKeyboardInputs(): apply SetKeyOwner() requests from last frame, then resolve routes.
+pub fn UpdateKeyOwnership(g: &mut AppContext)
+{
+    for key in ImGuiKey_NamedKey_BEGIN .. ImGuiKey_NamedKey_END
+    {
+        let key_down = g.IO.KeysData[(key - ImGuiKey_NamedKey_BEGIN) as usize].Down;
+        let owner_data = &mut g.KeysOwnerData[(key - ImGuiKey_NamedKey_BEGIN) as usize];
+        owner_data.OwnerCurr = owner_data.OwnerNext;
+        // Important: ownership is released on the frame after a release. Ensure a 'MouseDown -> SetKeyOwner -> MouseUp -> next frame -> MouseDown (owner is still set)' sequence is possible.
+        if !key_down {
+            owner_data.OwnerNext = ImGuiKeyOwner_None;
+        }
+        // Clear LockUntilRelease when key is not Down anymore
+        owner_data.LockUntilRelease = owner_data.LockUntilRelease && key_down;
+        owner_data.LockThisFrame = owner_data.LockUntilRelease;
+    }
+    UpdateKeyRoutingTable(g);
+}
+
+// Rewrite the routing table: the RoutingNext submitted during last frame becomes RoutingCurr,
+// dead entries are dropped, and keys whose mods match the current ones are given to the winning route.
+pub fn UpdateKeyRoutingTable(g: &mut AppContext)
+{
+    let key_mods = GetKeyChordModsFromIO(g);
+    let rt = &mut g.KeysRoutingTable;
+    rt.EntriesNext.clear();
+    for key in ImGuiKey_NamedKey_BEGIN .. ImGuiKey_NamedKey_END
+    {
+        let new_routing_start_idx = rt.EntriesNext.len();
+        let mut old_routing_idx = rt.Index[(key - ImGuiKey_NamedKey_BEGIN) as usize];
+        while old_routing_idx != -1
+        {
+            let routing_entry = &mut rt.Entries[old_routing_idx as usize];
+            old_routing_idx = routing_entry.NextEntryIndex;
+            routing_entry.RoutingCurr = routing_entry.RoutingNext; // Update entry
+            routing_entry.RoutingNext = ImGuiKeyOwner_None;
+            routing_entry.RoutingNextScore = 255;
+            if routing_entry.RoutingCurr == ImGuiKeyOwner_None {
+                continue;
+            }
+            let routing_entry = *routing_entry;
+            rt.EntriesNext.push(routing_entry); // Write alive ones into new buffer
+
+            // Apply routing to owner if there's no owner already (RoutingCurr == None at this point)
+            if routing_entry.Mods as ImGuiKeyChord == key_mods {
+                let owner_data = &mut g.KeysOwnerData[(key - ImGuiKey_NamedKey_BEGIN) as usize];
+                if owner_data.OwnerCurr == ImGuiKeyOwner_None {
+                    owner_data.OwnerCurr = routing_entry.RoutingCurr;
+                }
+            }
+        }
+
+        // Rewrite linked-list
+        let entries_count = rt.EntriesNext.len();
+        rt.Index[(key - ImGuiKey_NamedKey_BEGIN) as usize] = if new_routing_start_idx < entries_count { new_routing_start_idx as i16 } else { -1 };
+        for n in new_routing_start_idx .. entries_count {
+            rt.EntriesNext[n].NextEntryIndex = if n + 1 < entries_count { (n + 1) as i16 } else { -1 };
+        }
+    }
+    std::mem::swap(&mut rt.Entries, &mut rt.EntriesNext); // Swap new and old indexes
+}
+
+// Find or create the routing entry for a key-chord
+pub fn GetShortcutRoutingData(g: &mut AppContext, mut key_chord: ImGuiKeyChord) -> &mut ImGuiKeyRoutingData
+{
+    if flag_set(key_chord, ImGuiMod_Shortcut) {
+        key_chord = ConvertShortcutMod(g, key_chord);
+    }
+    let mut key: ImGuiKey = key_chord & !ImGuiMod_Mask_;
+    let mods: ImGuiKeyChord = key_chord & ImGuiMod_Mask_;
+    if key == ImGuiKey_None {
+        key = ConvertSingleModFlagToKey(mods);
+    }
+    // IM_ASSERT(IsNamedKey(key));
+
+    // Get (in the majority of case, the linked list will have one element so this should be 2 reads.
+    // Subsequent elements will be contiguous in memory as list is sorted/rebuilt in NewFrame).
+    let rt = &mut g.KeysRoutingTable;
+    let index_slot = (key - ImGuiKey_NamedKey_BEGIN) as usize;
+    let mut idx = rt.Index[index_slot];
+    while idx != -1
+    {
+        if rt.Entries[idx as usize].Mods as ImGuiKeyChord == mods {
+            return &mut rt.Entries[idx as usize];
+        }
+        idx = rt.Entries[idx as usize].NextEntryIndex;
+    }
+
+    // Add to linked-list
+    let routing_data_idx = rt.Entries.len();
+    let mut routing_data = ImGuiKeyRoutingData::default();
+    routing_data.Mods = mods as u16;
+    routing_data.NextEntryIndex = rt.Index[index_slot]; // Setup linked list
+    rt.Entries.push(routing_data);
+    rt.Index[index_slot] = routing_data_idx as i16;
+    &mut rt.Entries[routing_data_idx]
+}
+
+// Current score encoding (lower is highest priority):
+//  -   0: ImGuiInputFlags_RouteGlobalHigh
+//  -   1: ImGuiInputFlags_RouteFocused (if item active)
+//  -   2: ImGuiInputFlags_RouteGlobal
+//  -   3: ImGuiInputFlags_RouteFocused (if focus scope is the one holding NavId in the focused window)
+//  -  4+: ImGuiInputFlags_RouteFocused (if window in focus-stack, +2 per parent window, +1 if not in the focused scope)
+//  - 254: ImGuiInputFlags_RouteGlobalLow
+//  - 255: never route
+// 'flags' should include an explicit routing policy
+fn CalcRoutingScore(g: &AppContext, location: ImguiHandle, focus_scope_id: ImguiHandle, owner_id: ImguiHandle, flags: ImGuiInputFlags) -> c_int
+{
+    if flag_set(flags, ImGuiInputFlags_RouteFocused)
+    {
+        // ActiveID gets top priority
+        // (we don't check g.ActiveIdUsingAllKeys here. Routing is applied but if input ownership is tested later it may discard it)
+        if owner_id != 0 && g.ActiveId == owner_id {
+            return 1;
+        }
+
+        // Focus scope holding NavId in the focused window comes next
+        if location == g.NavWindow && focus_scope_id == g.NavFocusScopeId && focus_scope_id != 0 {
+            return 3;
+        }
+
+        // Score based on distance to focused window (lower is better)
+        // Assume that SetShortcutRouting() is called from the same window (or child) which is being focused,
+        // and that sibling scopes of the focused one lose to it.
+        let location_root = g.windows.get(&location).map(|window| window.Rootwindow);
+        let mut focused = g.windows.get(&g.NavWindow);
+        if focused.is_some() && focused.map(|window| window.Rootwindow) == location_root {
+            let mut next_score: c_int = 4;
+            while let Some(window) = focused
+            {
+                if window.ID == location {
+                    // IM_ASSERT(next_score < 255);
+                    return if focus_scope_id == g.NavFocusScopeId { next_score } else { next_score + 1 };
+                }
+                // FIXME: This could be later abstracted as a focus path
+                focused = if window.Rootwindow != window.ID { window.Parentwindow.and_then(|parent| g.windows.get(&parent)) } else { None };
+                next_score += 2;
+            }
+        }
+        return 255;
+    }
+
+    // ImGuiInputFlags_RouteGlobalHigh is default, so calls without flags are not conditional
+    if flag_set(flags, ImGuiInputFlags_RouteGlobal) {
+        return 2;
+    }
+    if flag_set(flags, ImGuiInputFlags_RouteGlobalLow) {
+        return 254;
+    }
+    0
+}
+
+// Owner-less routes are keyed on the focus scope they were submitted from, so two windows/scopes polling the same chord don't share a route
+fn GetRoutingIdFromOwnerId(g: &AppContext, owner_id: ImguiHandle) -> ImguiHandle
+{
+    if owner_id != ImGuiKeyOwner_None && owner_id != ImGuiKeyOwner_Any {
+        return owner_id;
+    }
+    g.windows.get(&g.CurrentWindow).map(|window| window.dc.NavFocusScopeIdCurrent).unwrap_or(0)
+}
+
+// Request a desired route for an input chord (key + mods).
+// Return true if the route is available this frame.
+// - Routes and key ownership are attributed at the beginning of next frame based on best score and mod state.
+//   (Conceptually this does a "Submit for next frame" + "Test for current frame".
+//   As such, it could be called TrySetXXX or SubmitXXX, or the Submit and Test operations should be separate.)
+// - Using 'owner_id == ImGuiKeyOwner_Any/0': auto-assign an owner based on current focus scope (each window has its focus scope by default)
+// - Using 'owner_id == ImGuiKeyOwner_None': allows disabling/locking a shortcut.
+pub fn SetShortcutRouting(g: &mut AppContext, key_chord: ImGuiKeyChord, owner_id: ImguiHandle, mut flags: ImGuiInputFlags) -> bool
+{
+    if (flags & ImGuiInputFlags_RouteMask_) == 0 {
+        flags |= ImGuiInputFlags_RouteGlobalHigh; // IMPORTANT: This is the default for SetShortcutRouting() but NOT Shortcut()
+    }
+    // else IM_ASSERT(ImIsPowerOfTwo(flags & ImGuiInputFlags_RouteMask_)); // Check that only 1 routing flag is used
+
+    if flag_set(flags, ImGuiInputFlags_RouteUnlessBgFocused) && g.NavWindow == INVALID_IMGUI_HANDLE {
+        return false;
+    }
+    if flag_set(flags, ImGuiInputFlags_RouteAlways) {
+        return true;
+    }
+
+    let focus_scope_id = g.windows.get(&g.CurrentWindow).map(|window| window.dc.NavFocusScopeIdCurrent).unwrap_or(0);
+    let score = CalcRoutingScore(g, g.CurrentWindow, focus_scope_id, owner_id, flags);
+    if score == 255 {
+        return false;
+    }
+
+    // Submit routing for NEXT frame (assuming score is sufficient)
+    // FIXME: Could expose a way to use a "serve last" policy for same score resolution (using <= instead of <).
+    let routing_id = GetRoutingIdFromOwnerId(g, owner_id);
+    let routing_data = GetShortcutRoutingData(g, key_chord);
+    if score < routing_data.RoutingNextScore as c_int {
+        routing_data.RoutingNext = routing_id;
+        routing_data.RoutingNextScore = score as u8;
+    }
+
+    // Return routing state for CURRENT frame
+    routing_data.RoutingCurr == routing_id
+}
+
+// Currently unused by core (but used by tests)
+// Note: this cannot be turned into GetShortcutRouting() because we do the owner_id->routing_id translation, name would be more misleading.
+pub fn TestShortcutRouting(g: &mut AppContext, key_chord: ImGuiKeyChord, owner_id: ImguiHandle) -> bool
+{
+    let routing_id = GetRoutingIdFromOwnerId(g, owner_id);
+    let routing_data = GetShortcutRoutingData(g, key_chord); // FIXME: Could avoid creating entry.
+    routing_data.RoutingCurr == routing_id
+}
+
+// Return true when 'key_chord' was pressed this frame and the caller holds the route for it.
+// - Routing defaults to ImGuiInputFlags_RouteFocused: the deep-most focused window (and within it, the focus scope
+//   holding NavId) wins. Use ImGuiInputFlags_RouteGlobal/RouteGlobalLow for app-wide accelerators.
+// - Mods must match exactly: Shortcut(ImGuiMod_Ctrl | ImGuiKey_S) won't trigger with Ctrl+Shift+S held.
+pub unsafe fn Shortcut(g: &mut AppContext, mut key_chord: ImGuiKeyChord, owner_id: ImguiHandle, mut flags: ImGuiInputFlags) -> bool
+{
+    // When using (owner_id == 0/Any): SetShortcutRouting() will use the current focus scope and filter with this, so IsKeyPressed() is fine with the 0/Any.
+    if (flags & ImGuiInputFlags_RouteMask_) == 0 {
+        flags |= ImGuiInputFlags_RouteFocused;
+    }
+    if !SetShortcutRouting(g, key_chord, owner_id, flags) {
+        return false;
+    }
+
+    if flag_set(key_chord, ImGuiMod_Shortcut) {
+        key_chord = ConvertShortcutMod(g, key_chord);
+    }
+    let mods: ImGuiKeyChord = key_chord & ImGuiMod_Mask_;
+    if GetKeyChordModsFromIO(g) != mods {
+        return false;
+    }
+
+    // Special storage location for mods
+    let mut key: ImGuiKey = key_chord & !ImGuiMod_Mask_;
+    if key == ImGuiKey_None {
+        key = ConvertSingleModFlagToKey(mods);
+    }
+
+    // The route was granted to us in NewFrame(), which made us the owner of the key
+    let routing_id = GetRoutingIdFromOwnerId(g, owner_id);
+    if !IsKeyPressedWithOwner(key, routing_id, flags & (ImGuiInputFlags_Repeat | ImGuiInputFlags_RepeatRateMask_)) {
+        return false;
+    }
+    // IM_ASSERT((flags & ~ImGuiInputFlags_SupportedByShortcut) == 0); // Passing flags not supported by this function!
+    true
+}
+
+// Parse a shortcut label as displayed by MenuItem() (e.g. "Ctrl+S", "Ctrl+Shift+Z", "Alt+F4") into a key-chord.
+// Mod names are case-insensitive and "Cmd" is accepted for Super. Return None if any part isn't a known mod or key name.
+pub fn ParseKeyChord(label: &str) -> Option<ImGuiKeyChord>
+{
+    let mut key_chord: ImGuiKeyChord = 0;
+    let mut key: ImGuiKey = ImGuiKey_None;
+    for part in label.split('+').map(|part| part.trim())
+    {
+        if part.is_empty() || key != ImGuiKey_None {
+            return None; // Empty part or something after the key
+        }
+        match part.to_ascii_lowercase().as_str() {
+            "ctrl" | "control" => key_chord |= ImGuiMod_Ctrl,
+            "shift" => key_chord |= ImGuiMod_Shift,
+            "alt" | "option" => key_chord |= ImGuiMod_Alt,
+            "super" | "cmd" | "win" => key_chord |= ImGuiMod_Super,
+            _ => {
+                let n = GKeyNames.iter().position(|name| name.eq_ignore_ascii_case(part))?;
+                key = ImGuiKey_NamedKey_BEGIN + n as ImGuiKey;
+            }
+        }
+    }
+    if key == ImGuiKey_None {
+        return None;
+    }
+    Some(key_chord | key)
+}
+
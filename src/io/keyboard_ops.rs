@@ -6,6 +6,7 @@ use crate::imgui::GImGui;
 use crate::input_ops::GetKeyData;
 use crate::io::key::{ImGuiKey, ImGuiKey_Gamepad_BEGIN, ImGuiKey_Gamepad_END, ImGuiKey_Keyboard_BEGIN, ImGuiKey_Keyboard_END, ImGuiKey_KeysData_OFFSET, ImGuiKey_LegacyNativeKey_BEGIN, ImGuiKey_LegacyNativeKey_END, ImGuiKey_ModAlt, ImGuiKey_ModCtrl, ImGuiKey_ModShift, ImGuiKey_ModSuper, ImGuiKey_MouseWheelX, ImGuiKey_MouseWheelY, ImGuiKey_NamedKey_BEGIN, ImGuiKey_NamedKey_END};
 use crate::io::key_data::ImGuiKeyData;
+use crate::io::key_routing_ops::UpdateKeyOwnership;
 use crate::io::mod_flags::{ImGuiModFlags, ImGuiModFlags_Alt, ImGuiModFlags_Ctrl, ImGuiModFlags_None, ImGuiModFlags_Shift, ImGuiModFlags_Super};
 use crate::io::mouse_button::ImGuiMouseButton_COUNT;
 use crate::nav_ops::NavMoveRequestCancel;
@@ -125,6 +126,9 @@ pub unsafe fn UpdateKeyboardInputs()
             } else { key_data.DownDuration + io.DeltaTime }
         } else { -1.0 };
     }
+
+    // Update keys/input owner (named keys only): one entry per key, then apply routes submitted last frame
+    UpdateKeyOwnership(g);
 }
 
 
@@ -8,13 +8,14 @@ use crate::font::ImFont;
 use crate::font_atlas::ImFontAtlas;
 use crate::imgui::GImGui;
 use crate::a_imgui_cpp::{GImGui, ImTextCharFromUtf8};
-use input_event::ImguiInputEvent;
+use input_event::{ImGuiInputEventPreedit, ImguiInputEvent};
 use crate::backends::backend_flags::ImGuiBackendFlags;
 use crate::input_ops::{GetKeyData, IsGamepadKey};
 use crate::platform_ime_data::ImGuiPlatformImeData;
 use crate::core::string_ops::ImTextCharFromUtf8;
 use crate::core::type_defs::{ImguiHandle, ImWchar, ImWchar16};
 use crate::core::vec2::Vector2;
+use crate::io::key::{ImGuiKey_Gamepad_BEGIN, ImGuiKey_Gamepad_END};
 use crate::font::font_atlas::ImFontAtlas;
 use crate::io::backend_renderer_user_data::BackendRendererUserData;
 use crate::platform::platform_ime_data::ImGuiPlatformImeData;
@@ -27,6 +28,8 @@ pub mod input_source;
 pub mod io_ops;
 pub mod key;
 pub mod key_data;
+pub mod key_routing;
+pub mod key_routing_ops;
 pub mod keyboard_ops;
 pub mod mod_flags;
 pub mod mouse_button;
@@ -116,6 +119,11 @@ pub struct IoContext {
     // = false       // Enable allowing to move windows only when clicking on their title bar. Does not apply to windows without a title bar.
     pub ConfigMemoryCompactTimer: c_float,       // = 60f32          // Timer (in seconds) to free transient windows/tables memory buffers when unused. Set to -1.0 to disable.
 
+    // Gamepad options (used by AddGamepadStickEvent()/AddGamepadTriggerEvent(), which backends call with raw normalized values)
+    pub ConfigGamepadStickDeadZone: c_float,
+    // = 0.25           // Stick values below this (0.0..1.0, per axis) are reported as 0.0. Values above are rescaled so the analog value still spans 0.0..1.0.
+    pub ConfigGamepadTriggerDeadZone: c_float,   // = 0.10           // Same for analog triggers (L2/R2).
+
     //------------------------------------------------------------------
     // Platform Functions
     // (the imgui_impl_xxxx backend files are setting those up for you)
@@ -270,6 +278,8 @@ pub struct IoContext {
     // For AddInputCharacterUTF16()
     // ImVector<ImWchar> InputQueueCharacters;         // Queue of _characters_ input (obtained by platform backend). Fill using AddInputCharacter() helper.
     pub InputQueueCharacters: Vec<char>,
+    // In-progress IME composition, as of the last processed preedit event. Fill using AddInputPreeditEvent().
+    pub InputPreedit: ImGuiInputEventPreedit,
 }
 
 
@@ -338,6 +348,10 @@ impl IoContext {
         out.ConfigWindowsMoveFromTitleBarOnly = false;
         out.ConfigMemoryCompactTimer = 60f32;
 
+        // Gamepad options
+        out.ConfigGamepadStickDeadZone = 0.25;
+        out.ConfigGamepadTriggerDeadZone = 0.10;
+
         // Platform Functions
         out.backend_platform_data = None;
         out.backend_renderer_name = None;
@@ -449,6 +463,28 @@ impl IoContext {
         }
     }
 
+    // Queue the IME composition string (a.k.a. preedit) so the active InputText() can display it at the cursor.
+    // - cursor_pos, clause_start and clause_end are in characters (not bytes) inside 'text'.
+    // - Call with an empty string when the composition ends. The committed text must still be sent via AddInputCharacter*().
+    pub fn AddInputPreeditEvent(&mut self, text: &str, cursor_pos: c_int, clause_start: c_int, clause_end: c_int)
+    {
+        let g = GImGui; // ImGuiContext& g = *GImGui;
+        // IM_ASSERT(&g.IO == this && "Can only add events to current context.");
+        if !self.AppAcceptingEvents {
+            return;
+        }
+
+        let char_count = text.chars().count() as c_int;
+        let mut e = ImguiInputEvent::default();
+        e.Type = ImGuiInputEventType_Preedit;
+        e.Source = ImGuiInputSource_Keyboard;
+        e.Preedit.Text = String::from(text);
+        e.Preedit.CursorPos = cursor_pos.clamp(0, char_count);
+        e.Preedit.ClauseStart = clause_start.clamp(0, char_count);
+        e.Preedit.ClauseEnd = clause_end.clamp(e.Preedit.ClauseStart, char_count);
+        g.InputEventsQueue.push(e);
+    }
+
     // void ImGuiIO::ClearInputCharacters()
     pub fn ClearInputCharacters(&mut self)
     {
@@ -546,6 +582,33 @@ impl IoContext {
         self.AddKeyAnalogEvent(key, down, if down { 1.0 } else { 0.0 });
     }
 
+    // Queue a stick axis: 'value' is the raw axis position normalized to -1.0..1.0 (negative is left/up).
+    // Emits an analog event for both direction keys (e.g. ImGuiKey_GamepadLStickLeft + ImGuiKey_GamepadLStickRight)
+    // after applying io.ConfigGamepadStickDeadZone.
+    pub fn AddGamepadStickEvent(&mut self, key_negative: ImGuiKey, key_positive: ImGuiKey, value: c_float)
+    {
+        let dead_zone = self.ConfigGamepadStickDeadZone;
+        let v_neg = ApplyGamepadDeadZone(-value, dead_zone);
+        let v_pos = ApplyGamepadDeadZone(value, dead_zone);
+        self.AddKeyAnalogEvent(key_negative, v_neg > 0.10, v_neg);
+        self.AddKeyAnalogEvent(key_positive, v_pos > 0.10, v_pos);
+    }
+
+    // Queue an analog trigger: 'value' is the raw trigger position normalized to 0.0..1.0.
+    pub fn AddGamepadTriggerEvent(&mut self, key: ImGuiKey, value: c_float)
+    {
+        let v = ApplyGamepadDeadZone(value, self.ConfigGamepadTriggerDeadZone);
+        self.AddKeyAnalogEvent(key, v > 0.10, v);
+    }
+
+    // Queue a release of every gamepad key. Call when the gamepad in use is disconnected, so nothing stays held.
+    pub fn AddGamepadReleaseAllEvents(&mut self)
+    {
+        for key in ImGuiKey_Gamepad_BEGIN .. ImGuiKey_Gamepad_END {
+            self.AddKeyAnalogEvent(key, false, 0.0);
+        }
+    }
+
     // [Optional] Call after AddKeyEvent().
     // Specify native keycode, scancode + Specify index for legacy <1.87 IsKeyXXX() functions with native indices.
     // If you are writing a backend in 2022 or don't use IsKeyXXX() with native values that are not ImGuiKey values, you can avoid calling this.
@@ -698,3 +761,16 @@ impl IoContext {
 
     // void  ClearInputKeys();                                       // [Internal] Release all keys
 }
+
+// Map a raw 0.0..1.0 gamepad value to 0.0..1.0 past 'dead_zone' (negative input gives 0.0).
+// Rescaling keeps small movements just past the dead zone small, instead of jumping to 'dead_zone'.
+pub fn ApplyGamepadDeadZone(value: c_float, dead_zone: c_float) -> c_float
+{
+    if value <= dead_zone {
+        return 0.0;
+    }
+    if dead_zone >= 1.0 {
+        return 0.0;
+    }
+    ((value - dead_zone) / (1.0 - dead_zone)).min(1.0)
+}
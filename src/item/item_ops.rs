@@ -40,6 +40,7 @@ use crate::window_flags::ImGuiWindowFlags_NavFlattened;
 use crate::window_ops::IsWindowContentHoverable;
 use libc::{c_float, c_int};
 use std::ptr::null_mut;
+use crate::test_engine::test_engine::ImGuiTestEngineHook_ItemAdd;
 
 // c_void MarkItemEdited(ImguiHandle id)
 pub fn MarkItemEdited(g: &mut AppContext, id: ImguiHandle) {
@@ -484,8 +485,8 @@ pub fn ItemAdd(
     g.NextItemData.Flags = ImGuiNextItemDataFlags_None;
 
     // #ifdef IMGUI_ENABLE_TEST_ENGINE
-    if (id != 0) {
-        IMGUI_TEST_ENGINE_ITEM_ADD(nav_bb_arg.unwrap_or(bb), id);
+    if id != 0 && g.TestEngineHookItems {
+        ImGuiTestEngineHook_ItemAdd(g, nav_bb_arg.unwrap_or(bb), id);
     }
     // #endif
 
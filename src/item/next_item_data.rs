@@ -3,17 +3,35 @@
 use crate::core::condition::ImGuiCond;
 use crate::item::next_item_data_flags::{ImGuiNextItemDataFlags, ImGuiNextItemDataFlags_None};
 use crate::core::type_defs::ImguiHandle;
+use crate::widgets::multi_select::{ImGuiSelectionUserData, ImGuiSelectionUserData_Invalid};
+use crate::widgets::unit::ImGuiUnit;
 use libc::c_float;
 
-#[derive(Default, Debug, Clone)]
+#[derive(Debug, Clone)]
 pub struct ImGuiNextItemData {
     pub flags: ImGuiNextItemDataFlags,
     pub width: c_float,
     // Set by SetNextItemWidth()
     pub FocusScopeId: ImguiHandle,
-    // Set by SetNextItemMultiSelectData() (!= 0 signify value has been set, so it's an alternate version of HasSelectionData, we don't use Flags for this because they are cleared too early. This is mostly used for debugging)
+    // Set by SetNextItemSelectionUserData() (!= 0 signify value has been set, so it's an alternate version of HasSelectionData, we don't use Flags for this because they are cleared too early. This is mostly used for debugging)
     pub OpenCond: ImGuiCond,
     pub OpenVal: bool, // Set by SetNextItemOpen()
+    pub SelectionUserData: ImGuiSelectionUserData, // Set by SetNextItemSelectionUserData() (note that NULL/0 is a valid value, we use -1 == ImGuiSelectionUserData_Invalid to mark invalid values)
+    pub Unit: ImGuiUnit, // Set by SetNextItemUnit()
+}
+
+impl Default for ImGuiNextItemData {
+    fn default() -> Self {
+        Self {
+            flags: ImGuiNextItemDataFlags_None,
+            width: 0.0,
+            FocusScopeId: 0,
+            OpenCond: ImGuiCond::default(),
+            OpenVal: false,
+            SelectionUserData: ImGuiSelectionUserData_Invalid,
+            Unit: ImGuiUnit::default(),
+        }
+    }
 }
 
 impl ImGuiNextItemData {
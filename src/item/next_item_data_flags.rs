@@ -11,4 +11,5 @@ pub type ImGuiNextItemDataFlags = c_int;
 pub const ImGuiNextItemDataFlags_None: ImGuiNextItemDataFlags = 0;
 pub const ImGuiNextItemDataFlags_HasWidth: ImGuiNextItemDataFlags = 1 << 0;
 pub const ImGuiNextItemDataFlags_HasOpen: ImGuiNextItemDataFlags = 1 << 1;
+pub const ImGuiNextItemDataFlags_HasUnit: ImGuiNextItemDataFlags = 1 << 2;
 // };
@@ -21,7 +21,7 @@ use core::context_hook::IM_GUI_CONTEXT_HOOK_TYPE_SHUTDOWN;
 use std::collections::HashSet;
 use std::io::stdout;
 use std::ptr::null_mut;
-use crate::core::settings_ops::{save_ini_settings_to_disk, WindowSettingsHandler_ApplyAll, WindowSettingsHandler_ClearAll, WindowSettingsHandler_ReadLine, WindowSettingsHandler_WriteAll};
+use crate::core::settings_ops::{save_ini_settings_to_disk, WindowSettingsHandler_ApplyAll, WindowSettingsHandler_ClearAll, WindowSettingsHandler_ReadDocument, WindowSettingsHandler_ReadLine, WindowSettingsHandler_WriteAll, WindowSettingsHandler_WriteDocument};
 use crate::table::tables::TableSettingsAddSettingsHandler;
 
 mod backends;
@@ -38,6 +38,7 @@ mod io;
 mod stb;
 mod style;
 mod table;
+mod test_engine;
 mod viewport;
 mod widgets;
 mod window;
@@ -58,6 +59,8 @@ pub fn initialize(g: &mut AppContext) {
     ini_handler.ReadLineFn = WindowSettingsHandler_ReadLine;
     ini_handler.ApplyAllFn = WindowSettingsHandler_ApplyAll;
     ini_handler.WriteAllFn = WindowSettingsHandler_WriteAll;
+    ini_handler.ReadDocumentFn = Some(WindowSettingsHandler_ReadDocument);
+    ini_handler.WriteDocumentFn = Some(WindowSettingsHandler_WriteDocument);
     // AddSettingsHandler(g, &ini_handler);
     g.add_settings_handler(&ini_handler);
 
@@ -2,7 +2,10 @@
 
 use crate::core::vec2::Vector2;
 use crate::ImguiViewport;
-use libc::{c_char, c_float, c_void};
+use libc::{c_char, c_float, c_int, c_void};
+use crate::core::context::AppContext;
+use crate::core::type_defs::ImguiHandle;
+use crate::debugging::rgba_image::RgbaImage;
 use crate::platform::platform_monitor::PlatformMonitor;
 
 // (Optional) Access via GetPlatformIO()
@@ -88,6 +91,9 @@ pub struct PlatformIo {
 
     // void    (*Renderer_SwapBuffers)(vp: *mut ImguiViewport, void* render_arg);   // . . . R .  // (Optional) Call Present/SwapBuffers. 'render_arg' is the value passed to RenderPlatformWindowsDefault().
 
+    // bool    (*Renderer_ReadPixels)(ctx, ImGuiID viewport_id, int x, int y, int w, int h, RgbaImage* out_image); // (Optional) Read back a rectangle of the last rendered framebuffer of a viewport, (x,y) is the top-left corner in framebuffer pixels. Used by CaptureScreenshot().
+    pub Renderer_ReadPixels: Option<fn(g: &mut AppContext, viewport_id: ImguiHandle, x: c_int, y: c_int, w: c_int, h: c_int, out_image: &mut RgbaImage) -> bool>,
+
     // (Optional) Monitor list
     // - Updated by: app/backend. Update every frame to dynamically support changing monitor or DPI configuration.
     // - Used by: dear imgui to query DPI info, clamp popups/tooltips within same monitor and not have them straddle monitors.
@@ -1,16 +1,14 @@
 use crate::input_text_flags::ImGuiInputTextFlags_CallbackResize;
 use crate::input_text_state::ImGuiInputTextState;
 use crate::io::io_ops::GetIO;
-use crate::core::math_ops::{ImClamp, ImMax};
 use crate::stb::stb_text_edit_row::StbTexteditRow;
 use crate::stb::stb_text_edit_state::STB_TexteditState;
 use crate::stb::stb_textedit::{
     stb_text_makeundo_replace, STB_TEXTEDIT_CHARTYPE, STB_TEXTEDIT_STRING,
 };
-use crate::core::string_ops::ImTextCountUtf8BytesFromStr;
 use crate::core::type_defs::ImWchar;
 use crate::core::utils::flag_set;
-use crate::core::vec2::Vector2;
+use crate::window::input_text_storage::{InputTextCalcRangeWidth, ImGuiInputTextStorage};
 use crate::{input_text, widgets, GImGui};
 use libc::{c_float, c_int};
 
@@ -45,52 +43,81 @@ pub mod stb_tt_vertex;
 pub mod stb_undo_record;
 pub mod stb_undo_state;
 
-pub fn STB_TEXTEDIT_STRINGLEN(obj: &ImGuiInputTextState) -> usize {
-    return obj.CurLenW;
+// Keys understood by stb_textedit_key(). Any value below 0x200000 is a character to insert.
+pub const STB_TEXTEDIT_K_LEFT: c_int = 0x200000; // keyboard input to move cursor left
+pub const STB_TEXTEDIT_K_RIGHT: c_int = 0x200001; // keyboard input to move cursor right
+pub const STB_TEXTEDIT_K_UP: c_int = 0x200002; // keyboard input to move cursor up
+pub const STB_TEXTEDIT_K_DOWN: c_int = 0x200003; // keyboard input to move cursor down
+pub const STB_TEXTEDIT_K_LINESTART: c_int = 0x200004; // keyboard input to move cursor to start of line
+pub const STB_TEXTEDIT_K_LINEEND: c_int = 0x200005; // keyboard input to move cursor to end of line
+pub const STB_TEXTEDIT_K_TEXTSTART: c_int = 0x200006; // keyboard input to move cursor to start of text
+pub const STB_TEXTEDIT_K_TEXTEND: c_int = 0x200007; // keyboard input to move cursor to end of text
+pub const STB_TEXTEDIT_K_DELETE: c_int = 0x200008; // keyboard input to delete selection or character under cursor
+pub const STB_TEXTEDIT_K_BACKSPACE: c_int = 0x200009; // keyboard input to delete selection or character left of cursor
+pub const STB_TEXTEDIT_K_UNDO: c_int = 0x20000A; // keyboard input to perform undo
+pub const STB_TEXTEDIT_K_REDO: c_int = 0x20000B; // keyboard input to perform redo
+pub const STB_TEXTEDIT_K_WORDLEFT: c_int = 0x20000C; // keyboard input to move cursor left one word
+pub const STB_TEXTEDIT_K_WORDRIGHT: c_int = 0x20000D; // keyboard input to move cursor right one word
+pub const STB_TEXTEDIT_K_PGUP: c_int = 0x20000E; // keyboard input to move cursor up a page
+pub const STB_TEXTEDIT_K_PGDOWN: c_int = 0x20000F; // keyboard input to move cursor down a page
+pub const STB_TEXTEDIT_K_SHIFT: c_int = 0x400000;
+
+// GetWidth() result for a newline, so vertical moves stop at the end of a line instead of wrapping onto the next one
+pub const STB_TEXTEDIT_GETWIDTH_NEWLINE: c_float = -1.0;
+
+// The string and layout operations stb_textedit needs to edit a document, move the cursor and apply undo/redo records.
+// ImGuiInputTextState implements them over its TextW storage, ImGuiTextEditor over its lines.
+pub trait StbTexteditString {
+    fn StringLen(&self) -> usize;
+    fn GetChar(&self, idx: usize) -> char;
+    unsafe fn DeleteChars(&mut self, pos: usize, n: usize);
+    unsafe fn InsertChars(&mut self, pos: usize, new_text: &[char]) -> bool;
+    unsafe fn LayoutRow(&mut self, r: &mut StbTexteditRow, line_start_idx: usize);
+    unsafe fn GetWidth(&mut self, line_start_idx: usize, char_idx: usize) -> f32;
+    unsafe fn MoveWordLeft(&mut self, idx: usize) -> usize;
+    unsafe fn MoveWordRight(&mut self, idx: usize) -> usize;
 }
 
-pub fn STB_TEXTEDIT_GETCHAR(obj: &ImGuiInputTextState, idx: usize) -> char {
-    return obj.TextW[idx];
+pub fn STB_TEXTEDIT_STRINGLEN<S: StbTexteditString>(obj: &S) -> usize {
+    return obj.StringLen();
 }
 
-pub unsafe fn STB_TEXTEDIT_GETWIDTH(
-    obj: &mut ImGuiInputTextState,
-    line_start_idx: c_int,
-    char_idx: c_int,
+pub fn STB_TEXTEDIT_GETCHAR<S: StbTexteditString>(obj: &S, idx: usize) -> char {
+    return obj.GetChar(idx);
+}
+
+pub unsafe fn STB_TEXTEDIT_GETWIDTH<S: StbTexteditString>(
+    obj: &mut S,
+    line_start_idx: usize,
+    char_idx: usize,
 ) -> f32 {
-    let c = obj.TextW[line_start_idx + char_idx];
-    if c == '\n' {
-        return STB_TEXTEDIT_GETWIDTH_NEWLINE;
-    }
-    let g = GImGui; // ImGuiContext& g = *GImGui;
-    return g.Font.GetCharAdvance(c) * (g.FontSize / g.Font.FontSize);
+    return obj.GetWidth(line_start_idx, char_idx);
 }
 
 pub fn STB_TEXTEDIT_KEYTOTEXT(key: c_int) -> c_int {
     return if key >= 0x200000 { 0 } else { key };
 }
 
-pub unsafe fn STB_TEXTEDIT_LAYOUTROW(
+pub unsafe fn STB_TEXTEDIT_LAYOUTROW<S: StbTexteditString>(
     r: &mut StbTexteditRow,
-    obj: &mut ImGuiInputTextState,
+    obj: &mut S,
     line_start_idx: usize,
 ) {
-    let text: *const ImWchar = obj.TextW.Data;
-    let mut text_remaining: usize = 0;
-    let size: Vector2 =
-        input_text::InputTextCalcTextSizeW(text + line_start_idx, &mut text_remaining, None, true);
-    r.x0 = 0.0;
-    r.x1 = size.x;
-    r.baseline_y_delta = size.y;
-    r.ymin = 0.0;
-    r.ymax = size.y;
-    r.num_chars = (text_remaining - (text + line_start_idx));
+    obj.LayoutRow(r, line_start_idx);
+}
+
+pub unsafe fn STB_TEXTEDIT_MOVEWORDLEFT<S: StbTexteditString>(obj: &mut S, idx: usize) -> usize {
+    return obj.MoveWordLeft(idx);
+}
+
+pub unsafe fn STB_TEXTEDIT_MOVEWORDRIGHT<S: StbTexteditString>(obj: &mut S, idx: usize) -> usize {
+    return obj.MoveWordRight(idx);
 }
 
 pub unsafe fn STB_TEXTEDIT_MOVEWORDLEFT_IMPL(
     obj: &mut ImGuiInputTextState,
     mut idx: usize,
-) -> c_int {
+) -> usize {
     idx -= 1;
     while idx >= 0 && input_text::is_word_boundary_from_right(obj, idx) == false {
         idx -= 1;
@@ -133,64 +160,105 @@ pub unsafe fn STB_TEXTEDIT_MOVEWORDRIGHT_IMPL(
     };
 }
 
-pub unsafe fn STB_TEXTEDIT_DELETECHARS(obj: &mut ImGuiInputTextState, pos: c_int, n: c_int) {
-    let mut dst = obj.TextW[pos..];
-
-    // We maintain our buffer length in both UTF-8 and wchar formats
-    obj.Edited = true;
-    obj.CurLenA -= ImTextCountUtf8BytesFromStr(dst);
-    obj.CurLenW -= n;
-
-    // TODO
-    // Offset remaining text (FIXME-OPT: Use memmove)
-    // let src = &mut obj.TextW[pos + n..];
-    //     while (let c: ImWchar = *src++){
-    //     *dst + + = c;
-    // }
-    //     *dst = '\0';
+pub unsafe fn STB_TEXTEDIT_DELETECHARS<S: StbTexteditString>(obj: &mut S, pos: usize, n: usize) {
+    obj.DeleteChars(pos, n);
 }
 
-pub unsafe fn STB_TEXTEDIT_INSERTCHARS(
-    obj: &mut ImGuiInputTextState,
+pub unsafe fn STB_TEXTEDIT_INSERTCHARS<S: StbTexteditString>(
+    obj: &mut S,
     pos: usize,
-    new_text: Stringing,
+    new_text: &[char],
     new_text_len: usize,
 ) -> bool {
-    let is_resizable: bool = flag_set(obj.Flags, ImGuiInputTextFlags_CallbackResize);
-    let text_len = obj.CurLenW;
-    // IM_ASSERT(pos <= text_len);
+    obj.InsertChars(pos, &new_text[..new_text_len])
+}
 
-    let new_text_len_utf8 = ImTextCountUtf8BytesFromStr(new_text);
-    if !is_resizable && (new_text_len_utf8 + obj.CurLenA + 1 > obj.BufCapacityA) {
-        return false;
+impl StbTexteditString for ImGuiInputTextState {
+    fn StringLen(&self) -> usize {
+        self.TextW.Len()
     }
 
-    // Grow internal buffer if needed
-    if new_text_len + text_len + 1 > obj.TextW.len() {
-        if !is_resizable {
+    fn GetChar(&self, idx: usize) -> char {
+        self.TextW.GetChar(idx)
+    }
+
+    unsafe fn DeleteChars(&mut self, pos: usize, n: usize) {
+        let first_line = self.TextW.LineOfPos(pos);
+        let removed_lines = self.TextW.LineOfPos(pos + n) - first_line;
+
+        // We maintain our buffer length in both UTF-8 and wchar formats
+        self.Edited = true;
+        self.TextW.Delete(pos, n);
+        if self.TextAIsValid {
+            self.TextA.drain(pos..pos + n);
+        }
+        self.EditCount = self.EditCount.wrapping_add(1);
+        self.CurLenW = self.TextW.Len();
+        self.CurLenA = self.TextW.Utf8Len();
+        self.LineWidths.OnReplace(first_line, removed_lines, 0);
+    }
+
+    unsafe fn InsertChars(&mut self, pos: usize, new_text: &[char]) -> bool {
+        let is_resizable: bool = flag_set(self.Flags, ImGuiInputTextFlags_CallbackResize);
+        // IM_ASSERT(pos <= self.TextW.Len());
+
+        let new_text_len_utf8: usize = new_text.iter().map(|c| c.len_utf8()).sum();
+        if !is_resizable && (new_text_len_utf8 + self.CurLenA + 1 > self.BufCapacityA) {
             return false;
         }
-        // IM_ASSERT(text_len < obj.TextW.Size);
-        obj.TextW.resize(
-            text_len + ImClamp(new_text_len * 4, 32, ImMax(256, new_text_len)) + 1,
-            '\0',
-        );
+
+        let first_line = self.TextW.LineOfPos(pos);
+        let inserted_lines = new_text.iter().filter(|c| **c == '\n').count();
+        self.TextW.Insert(pos, new_text);
+        if self.TextAIsValid {
+            self.TextA.splice(pos..pos, new_text.iter().copied());
+        }
+        self.EditCount = self.EditCount.wrapping_add(1);
+        self.LineWidths.OnReplace(first_line, 0, inserted_lines);
+
+        self.Edited = true;
+        self.CurLenW = self.TextW.Len();
+        self.CurLenA += new_text_len_utf8;
+
+        return true;
+    }
+
+    // Rows are whole lines (we don't wrap), so the row width comes from the per-line width cache instead of re-measuring the text.
+    unsafe fn LayoutRow(&mut self, r: &mut StbTexteditRow, line_start_idx: usize) {
+        let g = GImGui; // ImGuiContext& g = *GImGui;
+        let font = g.Font;
+        let scale = g.FontSize / font.FontSize;
+        let line = self.TextW.LineOfPos(line_start_idx);
+        let line_end = self.TextW.LineEnd(line);
+        let width = if line_start_idx == self.TextW.LineStart(line) {
+            self.LineWidths.GetLineWidth(&self.TextW, line, &|c| font.GetCharAdvance(c) * scale)
+        } else {
+            InputTextCalcRangeWidth(&self.TextW, line_start_idx, line_end, &|c| font.GetCharAdvance(c) * scale)
+        };
+        r.x0 = 0.0;
+        r.x1 = width;
+        r.baseline_y_delta = g.FontSize;
+        r.ymin = 0.0;
+        r.ymax = g.FontSize;
+        r.num_chars = line_end - line_start_idx + if line_end < self.TextW.Len() { 1 } else { 0 };
     }
 
-    let text = obj.TextW.as_mut_slice();
-    if pos != text_len {
-        // TODO:
-        // memmove(text + pos + new_text_len, text + pos, (text_len - pos) * sizeof);
+    unsafe fn GetWidth(&mut self, line_start_idx: usize, char_idx: usize) -> f32 {
+        let c = self.TextW.GetChar(line_start_idx + char_idx);
+        if c == '\n' {
+            return STB_TEXTEDIT_GETWIDTH_NEWLINE;
+        }
+        let g = GImGui; // ImGuiContext& g = *GImGui;
+        return g.Font.GetCharAdvance(c) * (g.FontSize / g.Font.FontSize);
     }
-    // TODO:
-    // memcpy(text + pos, new_text, new_text_len * sizeof);
 
-    obj.Edited = true;
-    obj.CurLenW += new_text_len;
-    obj.CurLenA += new_text_len_utf8;
-    obj.TextW[obj.CurLenW] = '\0';
+    unsafe fn MoveWordLeft(&mut self, idx: usize) -> usize {
+        STB_TEXTEDIT_MOVEWORDLEFT_IMPL(self, idx)
+    }
 
-    return true;
+    unsafe fn MoveWordRight(&mut self, idx: usize) -> usize {
+        STB_TEXTEDIT_MOVEWORDRIGHT_IMPL(self, idx)
+    }
 }
 
 // stb_textedit internally allows for a single undo record to do addition and deletion, but somehow, calling
@@ -198,15 +266,15 @@ pub unsafe fn STB_TEXTEDIT_INSERTCHARS(
 pub unsafe fn stb_textedit_replace(
     str_arg: &mut STB_TEXTEDIT_STRING,
     state: &mut STB_TexteditState,
-    text: &STB_TEXTEDIT_CHARTYPE,
+    text: &[STB_TEXTEDIT_CHARTYPE],
     text_len: usize,
 ) {
     stb_text_makeundo_replace(str_arg, state, 0, str_arg.CurLenW, text_len);
-    ImStb::STB_TEXTEDIT_DELETECHARS(str, 0, str_arg.CurLenW);
+    STB_TEXTEDIT_DELETECHARS(str_arg, 0, str_arg.CurLenW);
     if text_len <= 0 {
         return;
     }
-    if ImStb::STB_TEXTEDIT_INSERTCHARS(str_arg, 0, text, text_len) {
+    if STB_TEXTEDIT_INSERTCHARS(str_arg, 0, text, text_len) {
         state.cursor = text_len;
         state.has_preferred_x = 0;
         return;
@@ -293,7 +293,8 @@ use crate::input_text_state::ImGuiInputTextState;
 use crate::stb::stb_find_state::StbFindState;
 use crate::stb::stb_text_edit_row::StbTexteditRow;
 use crate::stb::stb_text_edit_state::STB_TexteditState;
-use crate::stb::{STB_TEXTEDIT_DELETECHARS, STB_TEXTEDIT_GETCHAR, STB_TEXTEDIT_GETWIDTH, STB_TEXTEDIT_INSERTCHARS, STB_TEXTEDIT_LAYOUTROW, STB_TEXTEDIT_STRINGLEN};
+use crate::stb::{StbTexteditString, STB_TEXTEDIT_DELETECHARS, STB_TEXTEDIT_GETCHAR, STB_TEXTEDIT_GETWIDTH, STB_TEXTEDIT_GETWIDTH_NEWLINE, STB_TEXTEDIT_INSERTCHARS, STB_TEXTEDIT_LAYOUTROW, STB_TEXTEDIT_MOVEWORDLEFT, STB_TEXTEDIT_MOVEWORDRIGHT, STB_TEXTEDIT_STRINGLEN};
+use crate::stb::{STB_TEXTEDIT_K_BACKSPACE, STB_TEXTEDIT_K_DELETE, STB_TEXTEDIT_K_DOWN, STB_TEXTEDIT_K_LEFT, STB_TEXTEDIT_K_LINEEND, STB_TEXTEDIT_K_LINESTART, STB_TEXTEDIT_K_PGDOWN, STB_TEXTEDIT_K_PGUP, STB_TEXTEDIT_K_REDO, STB_TEXTEDIT_K_RIGHT, STB_TEXTEDIT_K_SHIFT, STB_TEXTEDIT_K_TEXTEND, STB_TEXTEDIT_K_TEXTSTART, STB_TEXTEDIT_K_UNDO, STB_TEXTEDIT_K_UP, STB_TEXTEDIT_K_WORDLEFT, STB_TEXTEDIT_K_WORDRIGHT};
 use crate::stb::stb_undo_record::StbUndoRecord;
 use crate::stb::stb_undo_state::StbUndoState;
 use crate::stb_find_state::StbFindState;
@@ -321,7 +322,7 @@ pub type STB_TEXTEDIT_CHARTYPE = char;
 pub type STB_TEXTEDIT_POSITIONTYPE  = c_int;
 
 
-pub type STB_TEXTEDIT_STRING = String;
+pub type STB_TEXTEDIT_STRING = ImGuiInputTextState;
 
 ////////////////////////////////////////////////////////////////////////////
 ////////////////////////////////////////////////////////////////////////////
@@ -347,7 +348,7 @@ pub type STB_TEXTEDIT_STRING = String;
 //
 
 // traverse the layout to locate the nearest character to a display position
-pub unsafe fn stb_text_locate_coord(str_var: &mut ImGuiInputTextState, x: c_float, y: c_float) -> usize {
+pub unsafe fn stb_text_locate_coord<S: StbTexteditString>(str_var: &mut S, x: c_float, y: c_float) -> usize {
     let mut r = StbTexteditRow::default();
     let n = STB_TEXTEDIT_STRINGLEN(str_var);
     let mut base_y: c_float = 0.0;
@@ -418,7 +419,7 @@ pub unsafe fn stb_text_locate_coord(str_var: &mut ImGuiInputTextState, x: c_floa
 }
 
 // API click: on mouse down, move the cursor to the clicked location, and reset the selection
-pub unsafe fn stb_textedit_click(str_var: &mut ImGuiInputTextState,
+pub unsafe fn stb_textedit_click<S: StbTexteditString>(str_var: &mut S,
                                  state: &mut STB_TexteditState,
                                  x: c_float,
                                  mut y: c_float) {
@@ -437,7 +438,7 @@ pub unsafe fn stb_textedit_click(str_var: &mut ImGuiInputTextState,
 }
 
 // API drag: on mouse drag, move the cursor and selection endpoint to the clicked location
-pub unsafe fn stb_textedit_drag(str_var: &mut ImGuiInputTextState, state: &mut STB_TexteditState, x: c_float, mut y: c_float) {
+pub unsafe fn stb_textedit_drag<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState, x: c_float, mut y: c_float) {
     let mut p: c_int = 0;
 
     // In single-line mode, just always make y = 0. This lets the drag keep working if the mouse
@@ -466,8 +467,8 @@ pub unsafe fn stb_textedit_drag(str_var: &mut ImGuiInputTextState, state: &mut S
 
 // find the x/y location of a character, and remember info about the previous row in
 // case we get a move-up event (for page up, we'll have to rescan)
-pub unsafe fn stb_textedit_find_charpos(find: &mut StbFindState,
-                                 str_var: &mut ImGuiInputTextState,
+pub unsafe fn stb_textedit_find_charpos<S: StbTexteditString>(find: &mut StbFindState,
+                                 str_var: &mut S,
                                  n: c_int,
                                  single_line: c_int) {
     let mut r = StbTexteditRow::default();
@@ -534,7 +535,7 @@ pub unsafe fn stb_textedit_find_charpos(find: &mut StbFindState,
 // #define STB_TEXT_HAS_SELECTION(s)   ((s)->select_start != (s)->select_end)
 
 // make the selection/cursor state valid if client altered the string
-pub fn stb_textedit_clamp(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_TexteditState) {
+pub fn stb_textedit_clamp<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState) {
     let n: c_int = STB_TEXTEDIT_STRINGLEN(str_var);
     if STB_TEXT_HAS_SELECTION(state) {
         if state.select_start > n { state.select_start = n; }
@@ -548,7 +549,7 @@ pub fn stb_textedit_clamp(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_Tex
 }
 
 // delete characters while updating undo
-pub unsafe fn stb_textedit_delete(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_TexteditState, stb_where_int: c_int, len: c_int)
+pub unsafe fn stb_textedit_delete<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState, stb_where_int: c_int, len: c_int)
 {
    stb_text_makeundo_delete(str_var, state, stb_where_int, len);
    STB_TEXTEDIT_DELETECHARS(str_var, stb_where_int, len);
@@ -556,7 +557,7 @@ pub unsafe fn stb_textedit_delete(str_var: &mut STB_TEXTEDIT_STRING, state: &mut
 }
 
 // delete the section
-pub unsafe fn stb_textedit_delete_selection(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_TexteditState) {
+pub unsafe fn stb_textedit_delete_selection<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState) {
     stb_textedit_clamp(str_var, state);
     if STB_TEXT_HAS_SELECTION(state) {
         if state.select_start < state.select_end {
@@ -592,7 +593,7 @@ pub unsafe fn stb_textedit_move_to_first(state: &mut STB_TexteditState) {
 }
 
 // move cursor to last character of selection
-pub unsafe fn stb_textedit_move_to_last(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_TexteditState) {
+pub unsafe fn stb_textedit_move_to_last<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState) {
     if STB_TEXT_HAS_SELECTION(state) {
         stb_textedit_sortselection(state);
         stb_textedit_clamp(str_var, state);
@@ -603,7 +604,7 @@ pub unsafe fn stb_textedit_move_to_last(str_var: &mut STB_TEXTEDIT_STRING, state
 }
 
 // #ifdef STB_TEXTEDIT_IS_SPACE
-pub fn is_word_boundary(str_var: &mut STB_TEXTEDIT_STRING, idx: c_int) -> bool {
+pub fn is_word_boundary<S: StbTexteditString>(str_var: &mut S, idx: c_int) -> bool {
     return if idx > 0 {
         STB_TEXTEDIT_IS_SPACE(STB_TEXTEDIT_GETCHAR(str_var, idx - 1))
         && !STB_TEXTEDIT_IS_SPACE(STB_TEXTEDIT_GETCHAR(str_var, idx)) }
@@ -613,7 +614,7 @@ pub fn is_word_boundary(str_var: &mut STB_TEXTEDIT_STRING, idx: c_int) -> bool {
 }
 
 // #ifndef STB_TEXTEDIT_MOVEWORDLEFT
-pub fn stb_textedit_move_to_word_previous(str_var: &mut STB_TEXTEDIT_STRING, mut c: c_int) -> c_int {
+pub fn stb_textedit_move_to_word_previous<S: StbTexteditString>(str_var: &mut S, mut c: c_int) -> c_int {
     c -= 1; // always move at least one character
     while c >= 0 && !is_word_boundary(str_var, c) {
         c -= 1;
@@ -629,7 +630,7 @@ pub fn stb_textedit_move_to_word_previous(str_var: &mut STB_TEXTEDIT_STRING, mut
 // #endif
 
 // #ifndef STB_TEXTEDIT_MOVEWORDRIGHT
-pub fn stb_textedit_move_to_word_next( str_var: &mut STB_TEXTEDIT_STRING, mut c: c_int ) -> c_int
+pub fn stb_textedit_move_to_word_next<S: StbTexteditString>( str_var: &mut S, mut c: c_int ) -> c_int
 {
    let len: c_int = STB_TEXTEDIT_STRINGLEN(str_var);
    c += 1; // always move at least one character
@@ -659,7 +660,7 @@ pub unsafe fn stb_textedit_prep_selection_at_cursor(state: &mut STB_TexteditStat
 }
 
 // API cut: delete selection
-pub unsafe fn stb_textedit_cut(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_TexteditState) -> bool
+pub unsafe fn stb_textedit_cut<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState) -> bool
 {
    if STB_TEXT_HAS_SELECTION(state) {
       stb_textedit_delete_selection(str_var,state); // implicitly clamps
@@ -670,9 +671,9 @@ pub unsafe fn stb_textedit_cut(str_var: &mut STB_TEXTEDIT_STRING, state: &mut ST
 }
 
 // API paste: replace existing selection with passed-in text
-pub unsafe fn stb_textedit_paste_internal(str_var: &mut String,
+pub unsafe fn stb_textedit_paste_internal<S: StbTexteditString>(str_var: &mut S,
                                    state: &mut STB_TexteditState,
-                                   text: &mut String,
+                                   text: &[STB_TEXTEDIT_CHARTYPE],
                                    len: usize) -> bool
 {
    // if there's a selection, the paste should delete it
@@ -694,7 +695,7 @@ pub unsafe fn stb_textedit_paste_internal(str_var: &mut String,
 // #endif
 
 // API key: process a keyboard input
-pub unsafe fn stb_textedit_key(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_TexteditState, key: STB_TEXTEDIT_KEYTYPE)
+pub unsafe fn stb_textedit_key<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState, key: STB_TEXTEDIT_KEYTYPE)
 {
 // retry:
    match key {
@@ -1241,7 +1242,10 @@ pub unsafe fn stb_text_create_undo_record(state: *mut StbUndoState, numchars: us
     }
 
     let mut out = &mut state.undo_rec[state.undo_point];
-    ;
+    out.chained = state.group_depth > 0 && state.group_size > 0;
+    if state.group_depth > 0 {
+        state.group_size += 1;
+    }
     state.undo_point += 1;
     return out;
 }
@@ -1266,7 +1270,18 @@ pub unsafe fn stb_text_createundo(state: *mut StbUndoState, pos: usize, insert_l
     };
 }
 
-pub unsafe fn stb_text_undo(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_TexteditState) {
+// [DEAR IMGUI] undo a whole group of chained records (e.g. one multi-cursor edit) as a single step
+pub unsafe fn stb_text_undo<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState) {
+    while state.undostate.undo_point > 0 {
+        let chained = state.undostate.undo_rec[state.undostate.undo_point as usize - 1].chained;
+        stb_text_undo_record(str_var, state);
+        if !chained {
+            break;
+        }
+    }
+}
+
+pub unsafe fn stb_text_undo_record<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState) {
     let s: *mut StbUndoState = &mut state.undostate;
     // StbUndoRecord u, *r;
     let mut u: StbUndoRecord = StbUndoRecord::defualt();
@@ -1283,6 +1298,7 @@ pub unsafe fn stb_text_undo(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_T
     r.insert_length = u.delete_length;
     r.delete_length = u.insert_length;
     r.stb_where = u.stb_where;
+    r.chained = u.chained;
 
     if u.delete_length {
         // if the undo record says to delete characters, then the redo record will
@@ -1339,7 +1355,18 @@ pub unsafe fn stb_text_undo(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_T
     s.redo_point -= 1;
 }
 
-pub unsafe fn stb_text_redo(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_TexteditState) {
+// [DEAR IMGUI] redo the first record and every record chained after it
+pub unsafe fn stb_text_redo<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState) {
+    if state.undostate.redo_point == STB_TEXTEDIT_UNDOSTATECOUNT as c_short {
+        return;
+    }
+    stb_text_redo_record(str_var, state);
+    while state.undostate.redo_point < STB_TEXTEDIT_UNDOSTATECOUNT as c_short && state.undostate.undo_rec[state.undostate.redo_point as usize].chained {
+        stb_text_redo_record(str_var, state);
+    }
+}
+
+pub unsafe fn stb_text_redo_record<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState) {
     let mut s: *mut StbUndoState = &mut state.undostate;
     let mut u: *mut StbUndoRecord = None;
     let mut r: *mut StbUndoRecord = None;
@@ -1358,6 +1385,7 @@ pub unsafe fn stb_text_redo(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_T
     u.insert_length = r.delete_length;
     u.stb_where = r.stb_where;
     u.char_storage = -1;
+    u.chained = r.chained;
 
     if r.delete_length {
         // the redo record requires us to delete characters, so the undo record
@@ -1398,7 +1426,7 @@ pub unsafe fn stb_text_makeundo_insert(state: &mut STB_TexteditState, stb_where:
    stb_text_createundo(&mut state.undostate, stb_where, 0, length);
 }
 
-pub unsafe fn stb_text_makeundo_delete(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_TexteditState, stb_where: c_int, length: c_int)
+pub unsafe fn stb_text_makeundo_delete<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState, stb_where: c_int, length: c_int)
 {
    let mut i: c_int = 0;
    p: &mut STB_TEXTEDIT_CHARTYPE = stb_text_createundo(&mut state.undostate, stb_where, length, 0);
@@ -1411,7 +1439,7 @@ pub unsafe fn stb_text_makeundo_delete(str_var: &mut STB_TEXTEDIT_STRING, state:
    }
 }
 
-pub unsafe fn stb_text_makeundo_replace(str_var: &mut STB_TEXTEDIT_STRING, state: &mut STB_TexteditState, stb_where: usize, old_length: usize, new_length: usize)
+pub unsafe fn stb_text_makeundo_replace<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState, stb_where: usize, old_length: usize, new_length: usize)
 {
    let mut i: c_int = 0;
    p: &mut STB_TEXTEDIT_CHARTYPE = stb_text_createundo(&mut state.undostate, stb_where, old_length, new_length);
@@ -1424,12 +1452,27 @@ pub unsafe fn stb_text_makeundo_replace(str_var: &mut STB_TEXTEDIT_STRING, state
    }
 }
 
+// [DEAR IMGUI] group the undo records created until the matching stb_text_end_undo_group() into one undo step
+pub fn stb_text_begin_undo_group(state: &mut StbUndoState) {
+    if state.group_depth == 0 {
+        state.group_size = 0;
+    }
+    state.group_depth += 1;
+}
+
+pub fn stb_text_end_undo_group(state: &mut StbUndoState) {
+    // IM_ASSERT(state.group_depth > 0);
+    state.group_depth -= 1;
+}
+
 // reset the state to default
 pub unsafe fn stb_textedit_clear_state(state: &mut STB_TexteditState, is_single_line: bool) {
     state.undostate.undo_point = 0;
     state.undostate.undo_char_point = 0;
     state.undostate.redo_point = STB_TEXTEDIT_UNDOSTATECOUNT as c_short;
     state.undostate.redo_char_point = STB_TEXTEDIT_UNDOCHARCOUNT as c_int;
+    state.undostate.group_depth = 0;
+    state.undostate.group_size = 0;
     state.select_end = 0;
     state.select_start = 0;
     state.cursor = 0;
@@ -1453,7 +1496,7 @@ pub unsafe fn stb_textedit_initialize_state(state: &mut STB_TexteditState, is_si
 // #pragma GCC diagnostic ignored "-Wcast-qual"
 // #endif
 
-pub unsafe fn stb_textedit_paste(str_var: &mut String, state: &mut STB_TexteditState, ctext: &mut String, len: usize) -> bool
+pub unsafe fn stb_textedit_paste<S: StbTexteditString>(str_var: &mut S, state: &mut STB_TexteditState, ctext: &[STB_TEXTEDIT_CHARTYPE], len: usize) -> bool
 {
    return stb_textedit_paste_internal(str_var, state, ctext, len);
 }
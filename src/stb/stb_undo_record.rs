@@ -8,4 +8,6 @@ pub struct StbUndoRecord {
     pub insert_length: STB_TEXTEDIT_POSITIONTYPE,
     pub delete_length: STB_TEXTEDIT_POSITIONTYPE,
     pub char_storage: c_int,
+    // [DEAR IMGUI] undone/redone together with the record before it (see stb_text_begin_undo_group())
+    pub chained: bool,
 } 
@@ -18,5 +18,9 @@ pub struct StbUndoState
 
     // undo_char_point: c_int, redo_char_point;
     pub undo_char_point: c_int,
-    pub redo_char_point: c_int
+    pub redo_char_point: c_int,
+
+    // [DEAR IMGUI] records created between stb_text_begin_undo_group() and stb_text_end_undo_group() form a single undo step
+    pub group_depth: c_int,
+    pub group_size: c_int,
 }
@@ -24,13 +24,13 @@ mod table_cell_data;
 pub mod table_column;
 pub mod table_column_flags;
 mod table_column_settings;
-mod table_column_sort_specs;
+pub mod table_column_sort_specs;
 pub mod table_flags;
 mod table_instance_data;
 pub mod table_ops;
-mod table_row_flags;
+pub mod table_row_flags;
 pub mod table_settings;
-mod table_sort_specs;
+pub mod table_sort_specs;
 pub mod table_temp_data;
 pub mod tables;
 
@@ -14,6 +14,7 @@ pub struct ImGuiTableSettings {
     pub ColumnsCount: ImGuiTableColumnIdx,
     pub ColumnsCountMax: ImGuiTableColumnIdx, // Maximum number of columns this settings instance can store, we can recycle a settings instance with lower number of columns but not higher
     pub WantApply: bool, // Set when loaded from .ini data (to enable merging/loading .ini data into an already running context)
+    pub Columns: Vec<ImGuiTableColumnSettings>, // ColumnsCountMax entries
 }
 
 impl ImGuiTableSettings {
@@ -21,6 +22,6 @@ impl ImGuiTableSettings {
 
     // *mut ImGuiTableColumnSettings   GetColumnSettings()     { return (*mut ImGuiTableColumnSettings)(this + 1); }
     pub fn GetColumnSettings(&mut self) -> *mut ImGuiTableColumnSettings {
-        todo!()
+        self.Columns.as_mut_ptr()
     }
 }
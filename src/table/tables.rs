@@ -267,6 +267,7 @@ use crate::widgets::button_flags::{ImGuiButtonFlags_AllowItemOverlap, ImGuiButto
 use crate::child_ops::{BeginChildEx, EndChild};
 use crate::content_ops::content_region_avail;
 use crate::core::context::AppContext;
+use crate::core::settings_document::{SettingsDocument, TableSettingsEntry};
 use crate::cursor_ops::cursor_screen_pos;
 use crate::core::direction::{ImGuiDir_Down, ImGuiDir_Up};
 use crate::drawing::draw_channel::ImDrawChannel;
@@ -3159,7 +3160,7 @@ pub unsafe fn TableOpenContextMenu(mut column_n: c_int)
         table.IsContextPopupOpen = true;
         table.ContextPopupColumn = column_n as ImGuiTableColumnIdx;
         table.InstanceInteracted = table.InstanceCurrent as i16;
-        let mut context_menu_id: ImguiHandle =  hash_string(str_to_const_c_char_ptr("##ContextMenu"), table.ID as u32);
+        let mut context_menu_id: ImguiHandle =  hash_string(str_to_const_c_char_ptr("##ContextMenu"), table.ID);
         OpenPopupEx(g, context_menu_id, ImGuiPopupFlags_None);
     }
 }
@@ -3167,7 +3168,7 @@ pub unsafe fn TableOpenContextMenu(mut column_n: c_int)
 pub unsafe fn TableBeginContextMenuPopup(table: *mut ImGuiTable) -> bool
 {
     if !table.IsContextPopupOpen || table.InstanceCurrent != table.InstanceInteracted as c_int { return  false; }
-    let mut context_menu_id: ImguiHandle =  hash_string(str_to_const_c_char_ptr("##ContextMenu"), table.ID as u32);
+    let mut context_menu_id: ImguiHandle =  hash_string(str_to_const_c_char_ptr("##ContextMenu"), table.ID);
     if BeginPopupEx(context_menu_id, ImGuiWindowFlags_AlwaysAutoResize | ImGuiWindowFlags_NoTitleBar | ImGuiWindowFlags_NoSavedSettings) { return  true; }
     table.IsContextPopupOpen = false;
     return false;
@@ -3290,6 +3291,8 @@ let size_all_desc: *const c_char;
 // - TableSettingsHandler_ReadOpen() [Internal]
 // - TableSettingsHandler_ReadLine() [Internal]
 // - TableSettingsHandler_WriteAll() [Internal]
+// - TableSettingsHandler_ReadDocument() [Internal]
+// - TableSettingsHandler_WriteDocument() [Internal]
 // - TableSettingsInstallHandler() [Internal]
 //-------------------------------------------------------------------------
 // [Init] 1: TableSettingsHandler_ReadXXXX()   Load and parse .ini file into TableSettings.
@@ -3626,6 +3629,36 @@ pub unsafe fn TableSettingsHandler_WriteAll(g: &mut AppContext, handler: *mut Se
     // }
 }
 
+// Typed version of TableSettingsHandler_ReadOpen() + TableSettingsHandler_ReadLine()
+pub fn TableSettingsHandler_ReadDocument(g: &mut AppContext, handler: *mut SettingsHandler, doc: &SettingsDocument)
+{
+    for entry in doc.Tables.iter()
+    {
+        match g.SettingsTables.iter_mut().find(|settings| settings.ID == entry.ID) {
+            // Recycle
+            Some(settings) if settings.ColumnsCountMax >= entry.ColumnsCount => {
+                entry.ApplyToSettings(settings);
+                continue;
+            }
+            // Invalidate storage, we won't fit because of a count change
+            Some(settings) => settings.ID = 0,
+            None => {}
+        }
+        let mut settings = ImGuiTableSettings::default();
+        settings.ColumnsCountMax = entry.ColumnsCount;
+        entry.ApplyToSettings(&mut settings);
+        g.SettingsTables.push(settings);
+    }
+}
+
+// Typed version of TableSettingsHandler_WriteAll()
+pub fn TableSettingsHandler_WriteDocument(g: &mut AppContext, handler: *mut SettingsHandler, doc: &mut SettingsDocument)
+{
+    // TableSaveSettings() may clear some of the SaveFlags when we establish that the data can be stripped
+    // (e.g. Order was unchanged), ditched settings (ID == 0) are skipped.
+    doc.Tables.extend(g.SettingsTables.iter().filter_map(TableSettingsEntry::FromSettings));
+}
+
 pub fn TableSettingsAddSettingsHandler(g: &mut AppContext)
 {
     let mut ini_handler: SettingsHandler::default();
@@ -3636,6 +3669,8 @@ pub fn TableSettingsAddSettingsHandler(g: &mut AppContext)
     ini_handler.ReadLineFn = TableSettingsHandler_ReadLine;
     ini_handler.ApplyAllFn = TableSettingsHandler_ApplyAll;
     ini_handler.WriteAllFn = TableSettingsHandler_WriteAll;
+    ini_handler.ReadDocumentFn = Some(TableSettingsHandler_ReadDocument);
+    ini_handler.WriteDocumentFn = Some(TableSettingsHandler_WriteDocument);
     // AddSettingsHandler(g, &ini_handler);
     g.add_settings_handler(&ini_handler);
 }
@@ -0,0 +1,2 @@
+pub mod test_context;
+pub mod test_engine;
@@ -0,0 +1,360 @@
+#![allow(non_snake_case)]
+
+//-----------------------------------------------------------------------------
+// [SECTION] TEST CONTEXT
+//-----------------------------------------------------------------------------
+// - ImGuiTestEngine_RunTest()
+// - ImGuiTestContext::Yield(), YieldFrames(), YieldUntil()
+// - ImGuiTestContext::MouseMoveToPos(), MouseDown(), MouseUp(), MouseClick(), MouseWheel()
+// - ImGuiTestContext::KeyDown(), KeyUp(), KeyPress(), KeyChars()
+// - ImGuiTestContext::GetID(), ItemInfo(), ItemExists(), ItemGetPath(), ItemClick()
+// - ImGuiTestContext::IsPopupOpen(), ExpectPopupOpen()
+//-----------------------------------------------------------------------------
+// Inputs are queued into g.InputEventsQueue flagged with AddedByTestEngine, exactly like a backend would submit them,
+// then frames are stepped until they have been processed. Items are addressed by ID path, e.g. "Window/##child/Button".
+//
+// Typical usage, from a #[test] without any display:
+//   let mut g = create_context(None, None);
+//   ImGui_ImplNull_Init(&mut g, Vector2::from_floats(800.0, 600.0));
+//   let result = ImGuiTestEngine_RunTest(&mut g,
+//       |g| {
+//           Begin(g, "Window", None, 0);
+//           if Button(g, "Save") { OpenPopup(g, "Confirm"); }
+//           if BeginPopup(g, "Confirm") { EndPopup(g); }
+//           End(g);
+//       },
+//       |ctx| {
+//           ctx.ItemClick("Window/Save", ImGuiMouseButton_Left)?;
+//           ctx.ExpectPopupOpen("Window/Confirm")
+//       });
+//   assert_eq!(result, Ok(()));
+//-----------------------------------------------------------------------------
+
+use libc::{c_float, c_int};
+use crate::backends::null_platform_backend::{ImGui_ImplNull_GetBackendData, ImGui_ImplNull_NewFrame};
+use crate::backends::software_renderer_backend::{ImGui_ImplSoftware_GetBackendData, ImGui_ImplSoftware_NewFrame, ImGui_ImplSoftware_RenderDrawData};
+use crate::core::context::AppContext;
+use crate::core::type_defs::ImguiHandle;
+use crate::core::utils::flag_set;
+use crate::core::vec2::Vector2;
+use crate::drawing::frame_ops::NewFrame;
+use crate::drawing::render_ops::Render;
+use crate::io::input_event::ImguiInputEvent;
+use crate::io::input_event_type::{ImGuiInputEventType_Key, ImGuiInputEventType_MouseButton, ImGuiInputEventType_MousePos, ImGuiInputEventType_MouseWheel, ImGuiInputEventType_Text};
+use crate::io::input_source::{ImGuiInputSource_Keyboard, ImGuiInputSource_Mouse};
+use crate::io::key::{ImGuiKey, ImGuiKey_ModAlt, ImGuiKey_ModCtrl, ImGuiKey_ModShift, ImGuiKey_ModSuper};
+use crate::io::mod_flags::{ImGuiModFlags, ImGuiModFlags_Alt, ImGuiModFlags_Ctrl, ImGuiModFlags_Shift, ImGuiModFlags_Super};
+use crate::io::mouse_button::ImGuiMouseButton;
+use crate::test_engine::test_engine::{ImGuiTestEngine, ImGuiTestEngine_FindItemInfo, ImGuiTestEngine_GarbageCollect, ImGuiTestEngine_GetID, ImGuiTestItemInfo};
+use crate::viewport::viewport_flags::ImguiViewportFlags_OwnedByApp;
+use crate::widgets::popup_flags::ImGuiPopupFlags_AnyPopupLevel;
+use crate::widgets::popup_ops::IsPopupOpen;
+
+pub type ImGuiTestResult = Result<(), String>;
+
+pub struct ImGuiTestContext<'a> {
+    pub UiContext: &'a mut AppContext,
+    // Called every frame between NewFrame() and Render()
+    pub GuiFunc: Box<dyn FnMut(&mut AppContext) + 'a>,
+    // Frames stepped since the test started
+    pub FrameCount: usize,
+    // Maximum number of frames to wait for an item to appear or a condition to hold
+    pub MaxFramesPerWait: usize,
+}
+
+// Run 'test_func' against the UI submitted by 'gui_func'. The context needs a platform backend (e.g. the null platform backend)
+// or a valid io.DisplaySize; a renderer backend is optional (the software renderer enables screenshots).
+pub fn ImGuiTestEngine_RunTest<G, T>(g: &mut AppContext, gui_func: G, test_func: T) -> ImGuiTestResult
+where
+    G: FnMut(&mut AppContext),
+    T: FnOnce(&mut ImGuiTestContext) -> ImGuiTestResult,
+{
+    let mut ctx = ImGuiTestContext::new(g, gui_func);
+    // Submit a first frame so items are known before the test starts
+    ctx.Yield();
+    test_func(&mut ctx)
+}
+
+impl<'a> ImGuiTestContext<'a> {
+    pub fn new<G: FnMut(&mut AppContext) + 'a>(g: &'a mut AppContext, gui_func: G) -> Self {
+        g.TestEngineHookItems = true;
+        Self {
+            UiContext: g,
+            GuiFunc: Box::new(gui_func),
+            FrameCount: 0,
+            MaxFramesPerWait: 60,
+        }
+    }
+
+    //-------------------------------------------------------------------------
+    // Frame stepping
+    //-------------------------------------------------------------------------
+
+    // Run one full frame: backends NewFrame, NewFrame(), GUI function, Render(), renderer.
+    pub fn Yield(&mut self) {
+        let g = &mut *self.UiContext;
+        if ImGui_ImplNull_GetBackendData(g).is_some() {
+            ImGui_ImplNull_NewFrame(g);
+        } else if g.IO.DeltaTime <= 0.0 {
+            g.IO.DeltaTime = 1.0 / 60.0;
+        }
+        if ImGui_ImplSoftware_GetBackendData(g).is_some() {
+            ImGui_ImplSoftware_NewFrame(g);
+        }
+
+        // Keep the Stack Tool alive while an ItemGetPath() query is in progress
+        if g.TestEngine.StackQueryId != 0 {
+            g.DebugStackTool.LastActiveFrame = g.FrameCount as c_int;
+        }
+
+        NewFrame(g);
+        (self.GuiFunc)(g);
+        Render(g);
+
+        if ImGui_ImplSoftware_GetBackendData(g).is_some() {
+            let draw_data = g.Viewports.values().find(|viewport| flag_set(viewport.Flags, ImguiViewportFlags_OwnedByApp)).map(|viewport| viewport.DrawData.clone());
+            if let Some(draw_data) = draw_data {
+                ImGui_ImplSoftware_RenderDrawData(g, &draw_data);
+            }
+        }
+
+        ImGuiTestEngine_GarbageCollect(g);
+        self.FrameCount += 1;
+    }
+
+    pub fn YieldFrames(&mut self, count: usize) {
+        for _ in 0..count {
+            self.Yield();
+        }
+    }
+
+    // Step frames until 'cond' returns true, for at most 'max_frames' frames
+    pub fn YieldUntil<F: FnMut(&mut AppContext) -> bool>(&mut self, mut cond: F, max_frames: usize) -> ImGuiTestResult {
+        for _ in 0..max_frames {
+            if cond(self.UiContext) {
+                return Ok(());
+            }
+            self.Yield();
+        }
+        if cond(self.UiContext) {
+            return Ok(());
+        }
+        Err(format!("condition still not met after {} frames", max_frames))
+    }
+
+    //-------------------------------------------------------------------------
+    // Inputs
+    //-------------------------------------------------------------------------
+
+    fn QueueInputEvent(&mut self, mut e: ImguiInputEvent) {
+        e.AddedByTestEngine = true;
+        self.UiContext.InputEventsQueue.push(e);
+    }
+
+    pub fn MouseMoveToPos(&mut self, pos: Vector2) {
+        let mut e = ImguiInputEvent::default();
+        e.Type = ImGuiInputEventType_MousePos;
+        e.Source = ImGuiInputSource_Mouse;
+        e.MousePos.PosX = pos.x;
+        e.MousePos.PosY = pos.y;
+        self.QueueInputEvent(e);
+        self.Yield();
+    }
+
+    fn QueueMouseButton(&mut self, button: ImGuiMouseButton, down: bool) {
+        let mut e = ImguiInputEvent::default();
+        e.Type = ImGuiInputEventType_MouseButton;
+        e.Source = ImGuiInputSource_Mouse;
+        e.MouseButton.Button = button;
+        e.MouseButton.Down = down;
+        self.QueueInputEvent(e);
+    }
+
+    pub fn MouseDown(&mut self, button: ImGuiMouseButton) {
+        self.QueueMouseButton(button, true);
+        self.Yield();
+    }
+
+    pub fn MouseUp(&mut self, button: ImGuiMouseButton) {
+        self.QueueMouseButton(button, false);
+        self.Yield();
+    }
+
+    // Press and release at the current mouse position, one frame each so widgets can see the press.
+    pub fn MouseClick(&mut self, button: ImGuiMouseButton) {
+        self.MouseDown(button);
+        self.MouseUp(button);
+    }
+
+    pub fn MouseWheel(&mut self, wheel_x: c_float, wheel_y: c_float) {
+        let mut e = ImguiInputEvent::default();
+        e.Type = ImGuiInputEventType_MouseWheel;
+        e.Source = ImGuiInputSource_Mouse;
+        e.MouseWheel.WheelX = wheel_x;
+        e.MouseWheel.WheelY = wheel_y;
+        self.QueueInputEvent(e);
+        self.Yield();
+    }
+
+    fn QueueKey(&mut self, key: ImGuiKey, down: bool) {
+        let mut e = ImguiInputEvent::default();
+        e.Type = ImGuiInputEventType_Key;
+        e.Source = ImGuiInputSource_Keyboard;
+        e.Key.Key = key;
+        e.Key.Down = down;
+        self.QueueInputEvent(e);
+    }
+
+    fn QueueMods(&mut self, mods: ImGuiModFlags, down: bool) {
+        let mod_keys = [
+            (ImGuiModFlags_Ctrl, ImGuiKey_ModCtrl),
+            (ImGuiModFlags_Shift, ImGuiKey_ModShift),
+            (ImGuiModFlags_Alt, ImGuiKey_ModAlt),
+            (ImGuiModFlags_Super, ImGuiKey_ModSuper),
+        ];
+        for (mod_flag, mod_key) in mod_keys {
+            if flag_set(mods, mod_flag) {
+                self.QueueKey(mod_key, down);
+            }
+        }
+    }
+
+    pub fn KeyDown(&mut self, key: ImGuiKey, mods: ImGuiModFlags) {
+        self.QueueMods(mods, true);
+        self.QueueKey(key, true);
+        self.Yield();
+    }
+
+    pub fn KeyUp(&mut self, key: ImGuiKey, mods: ImGuiModFlags) {
+        self.QueueKey(key, false);
+        self.QueueMods(mods, false);
+        self.Yield();
+    }
+
+    // Press and release a key chord, e.g. KeyPress(ImGuiKey_S, ImGuiModFlags_Ctrl)
+    pub fn KeyPress(&mut self, key: ImGuiKey, mods: ImGuiModFlags) {
+        self.KeyDown(key, mods);
+        self.KeyUp(key, mods);
+    }
+
+    // Type text into the focused widget
+    pub fn KeyChars(&mut self, text: &str) {
+        for c in text.chars() {
+            let mut e = ImguiInputEvent::default();
+            e.Type = ImGuiInputEventType_Text;
+            e.Source = ImGuiInputSource_Keyboard;
+            e.Text.Char = c as u32;
+            self.QueueInputEvent(e);
+        }
+        self.Yield();
+    }
+
+    //-------------------------------------------------------------------------
+    // Items
+    //-------------------------------------------------------------------------
+
+    pub fn GetID(&self, path: &str) -> ImguiHandle {
+        ImGuiTestEngine_GetID(self.UiContext, path)
+    }
+
+    // Locate an item by ID path, waiting up to MaxFramesPerWait frames for it to be submitted
+    pub fn ItemInfo(&mut self, path: &str) -> Result<ImGuiTestItemInfo, String> {
+        let id = self.GetID(path);
+        for n in 0..=self.MaxFramesPerWait {
+            if let Some(item) = ImGuiTestEngine_FindItemInfo(self.UiContext, id) {
+                return Ok(item.clone());
+            }
+            if n < self.MaxFramesPerWait {
+                self.Yield();
+            }
+        }
+        Err(format!("item \"{}\" (0x{:08X}) not found", path, id))
+    }
+
+    // Returns whether the item was submitted during the last frame, without waiting
+    pub fn ItemExists(&mut self, path: &str) -> bool {
+        let id = self.GetID(path);
+        ImGuiTestEngine_FindItemInfo(self.UiContext, id).is_some()
+    }
+
+    // Describe an item ID as an ID path, using the Stack Tool (one level of the ID stack gets resolved per frame).
+    // Mostly useful for error messages, e.g. to tell which item is covering the one we tried to click.
+    pub fn ItemGetPath(&mut self, id: ImguiHandle) -> String {
+        if id == 0 {
+            return String::from("<none>");
+        }
+        self.UiContext.TestEngine.StackQueryId = id;
+        for _ in 0..self.MaxFramesPerWait {
+            self.Yield();
+            let tool = &self.UiContext.DebugStackTool;
+            if tool.QueryId == id && tool.StackLevel >= 0 && tool.StackLevel as usize >= tool.Results.len() {
+                break;
+            }
+        }
+        self.UiContext.TestEngine.StackQueryId = 0;
+
+        let g = &*self.UiContext;
+        let mut path = String::new();
+        for (n, info) in g.DebugStackTool.Results.iter().enumerate() {
+            // Root level is a window ID: it is not hashed by GetID() so we use the window name
+            let window = if n == 0 && !info.QuerySuccess { g.windows.get(&info.ID) } else { None };
+            let desc = if let Some(window) = window {
+                window.Name.clone()
+            } else if info.QuerySuccess {
+                let len = info.Desc.iter().position(|&c| c == 0).unwrap_or(info.Desc.len());
+                String::from_utf8_lossy(&info.Desc[..len].iter().map(|&c| c as u8).collect::<Vec<u8>>()).into_owned()
+            } else {
+                format!("0x{:08X}", info.ID)
+            };
+            if n > 0 {
+                path.push('/');
+            }
+            path.push_str(&desc);
+        }
+        if path.is_empty() {
+            path = format!("0x{:08X}", id);
+        }
+        path
+    }
+
+    // Move the mouse over the center of an item and click it
+    pub fn ItemClick(&mut self, path: &str, button: ImGuiMouseButton) -> ImGuiTestResult {
+        let item = self.ItemInfo(path)?;
+        let center = Vector2::from_floats((item.RectFull.min.x + item.RectFull.max.x) * 0.5, (item.RectFull.min.y + item.RectFull.max.y) * 0.5);
+        self.MouseMoveToPos(center);
+
+        // Hovering is evaluated by the item itself during the frame following the move
+        self.Yield();
+        let hovered_id = self.UiContext.HoveredId;
+        if hovered_id != item.ID {
+            let hovered_path = self.ItemGetPath(hovered_id);
+            return Err(format!("cannot hover item \"{}\": mouse is over \"{}\"", path, hovered_path));
+        }
+        self.MouseClick(button);
+        Ok(())
+    }
+
+    //-------------------------------------------------------------------------
+    // Checks
+    //-------------------------------------------------------------------------
+
+    // The popup ID is the one passed to OpenPopup(), e.g. "Window/Confirm"
+    pub fn IsPopupOpen(&mut self, path: &str) -> bool {
+        let id = self.GetID(path);
+        IsPopupOpen(self.UiContext, id, ImGuiPopupFlags_AnyPopupLevel)
+    }
+
+    pub fn ExpectPopupOpen(&mut self, path: &str) -> ImGuiTestResult {
+        if self.IsPopupOpen(path) {
+            return Ok(());
+        }
+        Err(format!("expected popup \"{}\" to be open", path))
+    }
+}
+
+impl<'a> Drop for ImGuiTestContext<'a> {
+    fn drop(&mut self) {
+        self.UiContext.TestEngineHookItems = false;
+        self.UiContext.TestEngine = ImGuiTestEngine::default();
+    }
+}
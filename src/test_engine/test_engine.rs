@@ -0,0 +1,126 @@
+#![allow(non_snake_case)]
+
+//-----------------------------------------------------------------------------
+// [SECTION] TEST ENGINE HOOKS
+//-----------------------------------------------------------------------------
+// - ImGuiTestEngineHook_ItemAdd()
+// - ImGuiTestEngineHook_ItemInfo()
+// - ImGuiTestEngine_FindItemInfo()
+// - ImGuiTestEngine_GarbageCollect()
+// - ImGuiTestEngine_SplitPath()
+// - ImGuiTestEngine_GetID()
+//-----------------------------------------------------------------------------
+// While g.TestEngineHookItems is set, ItemAdd() reports every item to the test engine, which keeps
+// its last known rectangle so ImGuiTestContext can aim the mouse at it.
+//-----------------------------------------------------------------------------
+
+use std::collections::HashMap;
+use crate::core::context::AppContext;
+use crate::core::hash_ops::hash_string;
+use crate::core::rect::ImRect;
+use crate::core::type_defs::ImguiHandle;
+use crate::item::item_status_flags::ImGuiItemStatusFlags;
+use crate::window::window_flags::ImGuiWindowFlags_ChildWindow;
+use crate::core::utils::flag_set;
+
+// Items not submitted for that many frames are forgotten
+const TEST_ENGINE_ITEM_GC_FRAMES: usize = 60;
+
+// Information about an item, as last reported by ItemAdd()
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiTestItemInfo {
+    pub ID: ImguiHandle,
+    // Window the item was submitted in
+    pub Window: ImguiHandle,
+    // Top of the ID stack when the item was submitted
+    pub ParentID: ImguiHandle,
+    pub RectFull: ImRect,
+    // Only available for widgets calling the ItemInfo() hook
+    pub Label: String,
+    pub StatusFlags: ImGuiItemStatusFlags,
+    // g.FrameCount of the last frame the item was submitted
+    pub TimestampMain: usize,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiTestEngine {
+    pub Items: HashMap<ImguiHandle, ImGuiTestItemInfo>,
+    // When != 0, the Stack Tool queries the ID stack of this item instead of the hovered/active one
+    pub StackQueryId: ImguiHandle,
+}
+
+// IMGUI_TEST_ENGINE_ITEM_ADD(bb, id)
+pub fn ImGuiTestEngineHook_ItemAdd(g: &mut AppContext, bb: &ImRect, id: ImguiHandle) {
+    let frame_count = g.FrameCount;
+    let window_id = g.CurrentWindow;
+    let parent_id = g.current_window_mut().and_then(|window| window.id_stack.last().cloned()).unwrap_or(0);
+
+    let item = g.TestEngine.Items.entry(id).or_default();
+    if item.TimestampMain != frame_count {
+        item.StatusFlags = 0;
+    }
+    item.ID = id;
+    item.Window = window_id;
+    item.ParentID = parent_id;
+    item.RectFull = bb.clone();
+    item.TimestampMain = frame_count;
+}
+
+// IMGUI_TEST_ENGINE_ITEM_INFO(id, label, flags)
+pub fn ImGuiTestEngineHook_ItemInfo(g: &mut AppContext, id: ImguiHandle, label: &str, flags: ImGuiItemStatusFlags) {
+    let frame_count = g.FrameCount;
+    if let Some(item) = g.TestEngine.Items.get_mut(&id) {
+        if item.TimestampMain == frame_count {
+            item.Label = label.to_string();
+            item.StatusFlags = flags;
+        }
+    }
+}
+
+// Returns the item if it was submitted during the current or the last frame
+pub fn ImGuiTestEngine_FindItemInfo(g: &AppContext, id: ImguiHandle) -> Option<&ImGuiTestItemInfo> {
+    g.TestEngine.Items.get(&id).filter(|item| item.TimestampMain + 1 >= g.FrameCount)
+}
+
+pub fn ImGuiTestEngine_GarbageCollect(g: &mut AppContext) {
+    let frame_count = g.FrameCount;
+    g.TestEngine.Items.retain(|_, item| item.TimestampMain + TEST_ENGINE_ITEM_GC_FRAMES >= frame_count);
+}
+
+// Split "Window/##child/Button" into its components. Use "\/" for a '/' which is part of a label.
+pub fn ImGuiTestEngine_SplitPath(path: &str) -> Vec<String> {
+    let mut out: Vec<String> = vec![];
+    let mut segment = String::new();
+    let mut chars = path.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' && chars.peek() == Some(&'/') {
+            segment.push(chars.next().unwrap());
+        } else if c == '/' {
+            if !segment.is_empty() {
+                out.push(std::mem::take(&mut segment));
+            }
+        } else {
+            segment.push(c);
+        }
+    }
+    if !segment.is_empty() {
+        out.push(segment);
+    }
+    out
+}
+
+// Compute the ID of an item from its path, the same way the GetID() functions would:
+// the first component is a window name, the following ones are hashed with the ID stack formed by the previous ones.
+pub fn ImGuiTestEngine_GetID(g: &AppContext, path: &str) -> ImguiHandle {
+    let mut id: ImguiHandle = 0;
+    for (n, segment) in ImGuiTestEngine_SplitPath(path).iter().enumerate() {
+        let seed = if n == 0 { 0 } else { id };
+        id = hash_string(segment, seed);
+
+        // Items of a child window are not hashed from the ID passed to BeginChild() but from the child window own ID
+        if let Some(child) = g.windows.values().find(|window| window.ChildId == id && flag_set(window.Flags, ImGuiWindowFlags_ChildWindow)) {
+            id = child.ID;
+        }
+    }
+    id
+}
@@ -1,3 +1,4 @@
-mod text_filter;
+pub mod text_buffer;
+pub mod text_filter;
 pub mod text_flags;
 pub mod text_ops;
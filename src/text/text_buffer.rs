@@ -0,0 +1,39 @@
+#![allow(non_snake_case)]
+
+// Helper: Growable text buffer for logging/accumulating text
+// (this could be called 'ImGuiTextBuilder' / 'ImGuiStringBuilder')
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiTextBuffer {
+    pub Buf: String,
+}
+
+impl ImGuiTextBuffer {
+    pub fn size(&self) -> usize {
+        self.Buf.len()
+    }
+
+    pub fn empty(&self) -> bool {
+        self.Buf.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.Buf.clear();
+    }
+
+    pub fn reserve(&mut self, capacity: usize) {
+        self.Buf.reserve(capacity.saturating_sub(self.Buf.len()));
+    }
+
+    pub fn c_str(&self) -> &str {
+        self.Buf.as_str()
+    }
+
+    pub fn append(&mut self, text: &str) {
+        self.Buf.push_str(text);
+    }
+
+    // Use with format!(): buf.appendf(format!("[{}][{}]\n", type_name, name))
+    pub fn appendf(&mut self, text: String) {
+        self.Buf.push_str(&text);
+    }
+}
@@ -2,12 +2,13 @@
 #![allow(non_snake_case)]
 
 use std::borrow::BorrowMut;
+use std::ffi::CStr;
 use std::ptr::{null, null_mut};
 use libc::c_char;
 use crate::a_imgui_cpp::{ImStristr, ImStrncpy};
 use crate::item::item_ops::SetNextItemWidth;
 use crate::core::math_ops::ImCharIsBlankA;
-use crate::core::string_ops::{ImCharIsBlankA, ImStristr, ImStrncpy};
+use crate::core::string_ops::{ImCharIsBlankA, ImGlobMatch, ImStristr, ImStrncpy};
 
 // [Internal]
 #[derive(Default,Debug,Clone)]
@@ -144,6 +145,49 @@ impl ImGuiTextFilter {
         return false;
     }
 
+    // Like PassFilter(), but a range containing '*' or '?' has to match the whole text as a glob pattern (see ImGlobMatch()),
+    // e.g. "*.png,*.jp?g,-*_tmp*". Other ranges still match anywhere in the text.
+    // Unlike PassFilter(), a matching '-' range excludes the text wherever it is in the list.
+    pub fn PassFilterGlob(&self, text: &str) -> bool {
+        let input = unsafe { CStr::from_ptr(self.InputBuf.as_ptr()) }.to_string_lossy();
+        let mut count_grep = 0;
+        let mut included = false;
+        for f in input.split(',').map(str::trim) {
+            let (exclude, f) = match f.strip_prefix('-') {
+                Some(f) => (true, f),
+                None => (false, f),
+            };
+            if f.is_empty() {
+                continue;
+            }
+            let matched = if f.contains(['*', '?']) { ImGlobMatch(f, text) } else { text.to_lowercase().contains(&f.to_lowercase()) };
+            if exclude {
+                if matched {
+                    return false;
+                }
+            } else {
+                count_grep += 1;
+                included |= matched;
+            }
+        }
+
+        // Implicit * grep
+        included || count_grep == 0
+    }
+
+    // Replace the filter text, e.g. to set default patterns
+    pub unsafe fn SetText(&mut self, text: &str) {
+        let mut len = text.len().min(self.InputBuf.len() - 1);
+        while !text.is_char_boundary(len) {
+            len -= 1;
+        }
+        for (n, b) in text.as_bytes()[..len].iter().enumerate() {
+            self.InputBuf[n] = *b as c_char;
+        }
+        self.InputBuf[len] = 0;
+        self.Build();
+    }
+
     // IMGUI_API void      Build();
     pub unsafe fn Build(&mut self) {
         // Filters.resize(0);
@@ -29,6 +29,9 @@ pub mod viewport_flags;
 pub mod viewport_renderer_user_data;
 mod viewport_platform_handle;
 
+// #define IMGUI_VIEWPORT_DEFAULT_ID 0x11111111 // Using a constant instead of e.g. ImHashStr("ViewportDefault", 0); so it's easier to spot in the debugger. The exact value doesn't matter.
+pub const IMGUI_VIEWPORT_DEFAULT_ID: ImguiHandle = 0x11111111;
+
 
 pub struct ImguiViewport {
     pub Idx: c_int,
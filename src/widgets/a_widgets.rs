@@ -249,7 +249,12 @@ use crate::io::key::{
     ImGuiKey_None, ImGuiKey_PageDown, ImGuiKey_PageUp, ImGuiKey_RightArrow, ImGuiKey_Space,
     ImGuiKey_Tab, ImGuiKey_UpArrow, ImGuiKey_V, ImGuiKey_X, ImGuiKey_Y, ImGuiKey_Z,
 };
+use crate::io::key_routing_ops::{ParseKeyChord, Shortcut};
+use crate::widgets::box_select_ops::BoxSelectUnclipItem;
+use crate::widgets::multi_select::ImGuiSelectionUserData_Invalid;
+use crate::widgets::multi_select_ops::{IsNextItemMultiSelect, MultiSelectItemFooter, MultiSelectItemHeader};
 use crate::item::last_item_data::ImGuiLastItemData;
+use crate::widgets::input_flags::ImGuiInputFlags_RouteGlobal;
 use crate::layout::layout_ops::{same_line, ShrinkWidths, spacing};
 use crate::layout::layout_type::{ImGuiLayoutType, ImGuiLayoutType_Horizontal, ImGuiLayoutType_Vertical};
 use crate::list_clipper::ImGuiListClipper;
@@ -418,6 +423,7 @@ use std::borrow::Borrow;
 use std::env::args;
 use std::ops::Index;
 use std::ptr::{null, null_mut};
+use crate::widgets::undo_ops::{PopUndoTrackingDisabled, PushUndoTrackingDisabled, UndoTrackItemValue};
 use crate::layout::layout_ops;
 use crate::widgets::{checkbox_ops, radio_button, scrolling_ops, separator}; // Time for drag-hold to activate items accepting the ImGuiButtonFlags_PressedOnDragDropHold button behavior.
 
@@ -453,10 +459,19 @@ use crate::widgets::{checkbox_ops, radio_button, scrolling_ops, separator}; // T
 // - ColorPickerOptionsPopup() [Internal]
 //-------------------------------------------------------------------------
 
-pub unsafe fn ColorEdit3(label: String, col: [c_float; 3], flags: ImGuiColorEditFlags) -> bool {
+pub unsafe fn ColorEdit3(label: String, col: &mut [c_float; 3], flags: ImGuiColorEditFlags) -> bool {
+    let col_before: [c_float; 3] = *col;
     let mut color_b: [c_float; 4] = [col[0], col[1], col[2], 0.0];
 
-    return ColorEdit4(label, &mut color_b, flags | ImGuiColorEditFlags_NoAlpha);
+    // ColorEdit4() edits a local copy: track 'col', which the undo history can write back to
+    PushUndoTrackingDisabled(g);
+    let value_changed: bool = ColorEdit4(label.clone(), &mut color_b, flags | ImGuiColorEditFlags_NoAlpha);
+    PopUndoTrackingDisabled(g);
+    if value_changed {
+        col.copy_from_slice(&color_b[..3]);
+    }
+    UndoTrackItemValue(g, &label, col, col_before, value_changed);
+    return value_changed;
 }
 
 // ColorEdit supports RGB and HSV inputs. In case of RGB input resulting color may have undefined hue and/or saturation.
@@ -507,6 +522,9 @@ pub unsafe fn ColorEdit4(
     let setyle = &mut g.style;
     let square_sz: c_float = GetFrameHeight();
     let w_full: c_float = CalcItemWidth(g);
+    let col_before: [c_float; 4] = *col;
+    // Inner DragInt() edit temporary ints: track 'col' as a whole instead
+    PushUndoTrackingDisabled(g);
     let w_button: c_float = if flag_set(flags, ImGuiColorEditFlags_NoSmallPreview) {
         0.0
     } else {
@@ -866,6 +884,9 @@ pub unsafe fn ColorEdit4(
         MarkItemEdited(g, g.last_item_data.ID);
     }
 
+    PopUndoTrackingDisabled(g);
+    UndoTrackItemValue(g, &label, col, col_before, value_changed);
+
     return value_changed;
 }
 
@@ -2197,11 +2218,16 @@ pub unsafe fn TreeNodeBehavior(id: ImguiHandle, flags: ImGuiTreeNodeFlags, label
         window.dc.TreeJumpToParentOnPopMask |= (1 << window.dc.TreeDepth);
     }
 
+    let is_multi_select: bool = IsNextItemMultiSelect(g);
     let mut item_add: bool = ItemAdd(g, &mut interact_bb, id, None, 0);
     g.last_item_data.StatusFlags |= ImGuiItemStatusFlags_HasDisplayRect;
     g.last_item_data.DisplayRect = frame_bb;
 
-    if !item_add {
+    // Extra layer of "no logic clip" for box-select support
+    if !item_add && !(is_multi_select && BoxSelectUnclipItem(g, &interact_bb)) {
+        if is_multi_select {
+            g.next_item_data.SelectionUserData = ImGuiSelectionUserData_Invalid;
+        }
         if is_open && flag_clear(flags, ImGuiTreeNodeFlags_NoTreePushOnOpen) {
             TreePushOverrideID(id);
         }
@@ -2239,8 +2265,15 @@ pub unsafe fn TreeNodeBehavior(id: ImguiHandle, flags: ImGuiTreeNodeFlags, label
         (text_pos.x - text_offset_x) + (g.FontSize + padding.x * 2.0) + style.TouchExtraPadding.x;
     let is_mouse_x_over_arrow: bool =
         (g.IO.MousePos.x >= arrow_hit_x1 && g.IO.MousePos.x < arrow_hit_x2);
-    if (window != g.HoveredWindow || !is_mouse_x_over_arrow) {
-        button_flags |= ImGuiButtonFlags_NoKeyModifiers;
+
+    // With multi-selection we absolutely need to distinguish open vs select, so _OpenOnArrow comes by default
+    let mut flags = flags;
+    if is_multi_select {
+        flags |= if (flags & (ImGuiTreeNodeFlags_OpenOnArrow | ImGuiTreeNodeFlags_OpenOnDoubleClick)) == 0 {
+            ImGuiTreeNodeFlags_OpenOnArrow | ImGuiTreeNodeFlags_OpenOnDoubleClick
+        } else {
+            ImGuiTreeNodeFlags_OpenOnArrow
+        };
     }
 
     // Open behaviors can be altered with the _OpenOnArrow and _OnOnDoubleClick flags.
@@ -2264,6 +2297,18 @@ pub unsafe fn TreeNodeBehavior(id: ImguiHandle, flags: ImGuiTreeNodeFlags, label
     let mut selected: bool = flag_set(flags, ImGuiTreeNodeFlags_Selected);
     let was_selected: bool = selected;
 
+    // Multi-selection support (header)
+    if is_multi_select {
+        // Handle multi-select + alter button flags for it
+        MultiSelectItemHeader(g, id, &mut selected, Some(&mut button_flags));
+        if is_mouse_x_over_arrow {
+            button_flags = (button_flags | ImGuiButtonFlags_PressedOnClick)
+                & !ImGuiButtonFlags_PressedOnClickRelease;
+        }
+    } else if window != g.HoveredWindow || !is_mouse_x_over_arrow {
+        button_flags |= ImGuiButtonFlags_NoKeyModifiers;
+    }
+
     let mut hovered = false;
     let mut held = false;
     let mut pressed: bool =
@@ -2313,11 +2358,27 @@ pub unsafe fn TreeNodeBehavior(id: ImguiHandle, flags: ImGuiTreeNodeFlags, label
             g.last_item_data.StatusFlags |= ImGuiItemStatusFlags_ToggledOpen;
         }
     }
+
+    // Multi-selection support (footer)
+    if is_multi_select {
+        // Opening/closing the node must not alter the selection
+        let mut pressed_copy: bool = pressed && !toggled;
+        MultiSelectItemFooter(g, id, &mut selected, &mut pressed_copy);
+        if pressed {
+            SetNavID(
+                id,
+                window.dc.NavLayerCurrent,
+                window.dc.NavFocusScopeIdCurrent,
+                &window_rect_abs_to_rel(window, &interact_bb),
+            );
+        }
+    }
+
     if flags & ImGuiTreeNodeFlags_AllowItemOverlap {
         SetItemAllowOverlap();
     }
 
-    // In this branch, TreeNodeBehavior() cannot toggle the selection so this will never trigger.
+    // Only the multi-select footer can toggle the selection here.
     if selected != was_selected {
         //-V547
         g.last_item_data.StatusFlags |= ImGuiItemStatusFlags_ToggledSelection;
@@ -2597,7 +2658,7 @@ pub unsafe fn CollapsingHeader2(
 // FIXME: Selectable() with (size.x == 0.0) and (SelectableTextAlign.x > 0.0) followed by SameLine() is currently not supported.
 pub fn Selectable(
     label: String,
-    selected: bool,
+    mut selected: bool,
     flags: ImGuiSelectableFlags,
     size_arg: Option<Vector2>,
 ) -> bool {
@@ -2676,6 +2737,7 @@ pub fn Selectable(
     }
 
     let disabled_item: bool = flag_set(flags, ImGuiSelectableFlags_Disabled);
+    let is_multi_select: bool = IsNextItemMultiSelect(g);
     let item_add: bool = ItemAdd(
         g,
         &mut bb,
@@ -2692,7 +2754,11 @@ pub fn Selectable(
         window.ClipRect.Max.x = backup_clip_rect_max_x;
     }
 
-    if !item_add {
+    // Extra layer of "no logic clip" for box-select support
+    if !item_add && !(is_multi_select && BoxSelectUnclipItem(g, &bb)) {
+        if is_multi_select {
+            g.next_item_data.SelectionUserData = ImGuiSelectionUserData_Invalid;
+        }
         return false;
     }
 
@@ -2729,26 +2795,37 @@ pub fn Selectable(
         button_flags |= ImGuiButtonFlags_AllowItemOverlap;
     }
 
+    // Multi-selection support (header)
+    if is_multi_select {
+        // Handle multi-select + alter button flags for it
+        MultiSelectItemHeader(g, id, &mut selected, Some(&mut button_flags));
+    }
+
     let was_selected: bool = selected;
     let mut hovered = false;
     let mut held = false;
     let mut pressed: bool =
         button_ops::ButtonBehavior(g, &bb, id, &mut hovered, &mut held, button_flags);
 
-    // Auto-select when moved into
-    // - This will be more fully fleshed in the range-select branch
-    // - This is not exposed as it won't nicely work with some user side handling of shift/control
-    // - We cannot do 'if (g.NavJustMovedToId != id) { selected = false; pressed = was_selected; }' for two reasons
-    //   - (1) it would require focus scope to be set, need exposing PushFocusScope() or equivalent (e.g. BeginSelection() calling PushFocusScope())
-    //   - (2) usage will fail with clipped items
-    //   The multi-select API aim to fix those issues, e.g. may be replaced with a BeginSelection() API.
-    if flag_set(flags, ImGuiSelectableFlags_SelectOnNav)
-        && g.NavJustMovedToId != 0
-        && g.NavJustMovedToFocusScopeId == window.dc.NavFocusScopeIdCurrent
-    {
-        if g.NavJustMovedToId == id {
-            selec.ted = true;
-            pressed = true;
+    // Multi-selection support (footer)
+    if is_multi_select {
+        MultiSelectItemFooter(g, id, &mut selected, &mut pressed);
+    } else {
+        // Auto-select when moved into
+        // - This will be more fully fleshed in the range-select branch
+        // - This is not exposed as it won't nicely work with some user side handling of shift/control
+        // - We cannot do 'if (g.NavJustMovedToId != id) { selected = false; pressed = was_selected; }' for two reasons
+        //   - (1) it would require focus scope to be set, need exposing PushFocusScope() or equivalent (e.g. BeginSelection() calling PushFocusScope())
+        //   - (2) usage will fail with clipped items
+        //   The multi-select API aim to fix those issues, e.g. may be replaced with a BeginSelection() API.
+        if flag_set(flags, ImGuiSelectableFlags_SelectOnNav)
+            && g.NavJustMovedToId != 0
+            && g.NavJustMovedToFocusScopeId == window.dc.NavFocusScopeIdCurrent
+        {
+            if g.NavJustMovedToId == id {
+                selec.ted = true;
+                pressed = true;
+            }
         }
     }
 
@@ -2775,7 +2852,7 @@ pub fn Selectable(
         SetItemAllowOverlap();
     }
 
-    // In this branch, Selectable() cannot toggle the selection so this will never trigger.
+    // Only the multi-select footer can toggle the selection here.
     if selected != was_selected {
         //-V547
         g.last_item_data.StatusFlags |= ImGuiItemStatusFlags_ToggledSelection;
@@ -3934,6 +4011,10 @@ pub unsafe fn EndMenu() {
     EndPopup(g);
 }
 
+// 'shortcut' is both displayed and live: when it parses with ParseKeyChord() (e.g. "Ctrl+S") and the item is enabled,
+// pressing the chord returns true as if the item was clicked. The chord is routed globally (ImGuiInputFlags_RouteGlobal),
+// so a focused window claiming the same chord with Shortcut() wins over the menu.
+// Items of a closed menu are not submitted: call Shortcut() alongside BeginMenu() if the accelerator must work at all times.
 pub unsafe fn MenuItemEx(
     label: String,
     icon: &str,
@@ -4038,6 +4119,19 @@ pub unsafe fn MenuItemEx(
             );
         }
     }
+
+    // Trigger from the shortcut label (routed with the item as owner: when several items show the same label, only one fires)
+    if !pressed && enabled {
+        if let Some(key_chord) = ParseKeyChord(shortcut) {
+            if Shortcut(g, key_chord, g.last_item_data.id, ImGuiInputFlags_RouteGlobal) {
+                pressed = true;
+                if window.dc.LayoutType != ImGuiLayoutType_Horizontal {
+                    CloseCurrentPopup();
+                }
+            }
+        }
+    }
+
     IMGUI_TEST_ENGINE_ITEM_INFO(
         g.last_item_data.ID,
         label,
@@ -0,0 +1,37 @@
+#![allow(non_snake_case)]
+
+use crate::core::type_defs::ImguiHandle;
+use crate::core::vec2::Vector2;
+use crate::io::mod_flags::ImGuiModFlags;
+use crate::rect::ImRect;
+
+// Box-select state (currently stored in main context, since only one box-select can be active at a time)
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiBoxSelectState {
+    // Active box-selection data (persistent, 1 active at a time)
+    pub ID: ImguiHandle,
+    pub IsActive: bool,
+    pub IsStarting: bool,
+    pub IsStartedFromVoid: bool,
+    // Starting click was not from an item.
+    pub IsStartedSetNavIdOnce: bool,
+    pub RequestClear: bool,
+    pub KeyMods: ImGuiModFlags,
+    // Latched key-mods for box-select logic.
+    pub StartPosRel: Vector2,
+    // Start position in window-contents relative space (to support scrolling)
+    pub EndPosRel: Vector2,
+    // End position in window-contents relative space
+    pub ScrollAccum: Vector2,
+    // Scrolling accumulator (to behave at high-frame spaces)
+    pub Window: ImguiHandle,
+
+    // Temporary/Transient data
+    pub UnclipMode: bool,
+    // (Temp/Transient, here in hot area). Set/cleared by the BeginMultiSelect()/EndMultiSelect() owning active box-select.
+    pub UnclipRect: ImRect,
+    // Rectangle where ItemAdd() clipping may be temporarily disabled. Need support by multi-select supporting widgets.
+    pub BoxSelectRectPrev: ImRect,
+    // Selection rectangle in absolute coordinates (derived every frame from BoxSelectStartPosRel and MousePos)
+    pub BoxSelectRectCurr: ImRect,
+}
@@ -0,0 +1,246 @@
+#![allow(non_snake_case)]
+
+//-------------------------------------------------------------------------
+// [SECTION] Widgets: Box-Select support
+//-------------------------------------------------------------------------
+// This has been extracted away from Multi-Select logic in the hope that it could eventually be used elsewhere, but hasn't been yet.
+//-------------------------------------------------------------------------
+// Extra logic in MultiSelectItemFooter() and ImGuiListClipper::Step()
+//-------------------------------------------------------------------------
+// - BoxSelectPreStartDrag() [Internal]
+// - BoxSelectActivateDrag() [Internal]
+// - BoxSelectDeactivateDrag() [Internal]
+// - BoxSelectScrollWithMouseDrag() [Internal]
+// - BeginBoxSelect() [Internal]
+// - EndBoxSelect() [Internal]
+// - GetBoxSelectState() [Internal]
+// - BoxSelectUnclipItem() [Internal]
+//-------------------------------------------------------------------------
+
+use crate::color::{ImGuiCol_NavHighlight, ImGuiCol_SeparatorHovered};
+use crate::core::context::AppContext;
+use crate::core::id_ops::{ClearActiveID, KeepAliveID, SetActiveID};
+use crate::core::type_defs::ImguiHandle;
+use crate::core::utils::{flag_clear, flag_set};
+use crate::core::vec2::{ImClampVec2, ImMaxVec2, ImMinVec2, Vector2};
+use crate::drawing::draw_flags::ImDrawFlags_None;
+use crate::drawing::draw_list_ops::GetForegroundDrawList;
+use crate::io::input_ops::IsMouseDragPastThreshold;
+use crate::io::mod_flags::{ImGuiModFlags_Ctrl, ImGuiModFlags_Shift};
+use crate::io::mouse_button::ImGuiMouseButton_Left;
+use crate::rect::ImRect;
+use crate::style::style_ops::GetColorU32;
+use crate::widgets::box_select::ImGuiBoxSelectState;
+use crate::widgets::multi_select::{ImGuiSelectionUserData, ImGuiSelectionUserData_Invalid};
+use crate::widgets::multi_select_flags::{ImGuiMultiSelectFlags, ImGuiMultiSelectFlags_BoxSelect2d, ImGuiMultiSelectFlags_BoxSelectNoScroll, ImGuiMultiSelectFlags_ScopeWindow};
+use crate::widgets::scrolling_ops::{SetScrollX, SetScrollY};
+use crate::window::ImguiWindow;
+
+// Window contents relative <-> absolute positions, relative positions are preserved when scrolling.
+fn WindowPosRelToAbs(window: &ImguiWindow, p: &Vector2) -> Vector2 {
+    Vector2::from_floats(p.x + window.dc.cursor_start_pos.x, p.y + window.dc.cursor_start_pos.y)
+}
+
+fn WindowPosAbsToRel(window: &ImguiWindow, p: &Vector2) -> Vector2 {
+    Vector2::from_floats(p.x - window.dc.cursor_start_pos.x, p.y - window.dc.cursor_start_pos.y)
+}
+
+// Called when the mouse is clicked in a box-select scope: box-select becomes active once the mouse is dragged past the threshold.
+pub fn BoxSelectPreStartDrag(g: &mut AppContext, id: ImguiHandle, clicked_item: ImGuiSelectionUserData)
+{
+    let mouse_pos = g.IO.MousePos;
+    let start_pos_rel = WindowPosAbsToRel(g.current_window_mut().unwrap(), &mouse_pos);
+    let key_mods = g.IO.KeyMods;
+    let bs = &mut g.BoxSelectState;
+    bs.ID = id;
+    bs.IsStarting = true; // Consider starting box-select.
+    bs.IsStartedFromVoid = clicked_item == ImGuiSelectionUserData_Invalid;
+    bs.IsStartedSetNavIdOnce = bs.IsStartedFromVoid;
+    bs.KeyMods = key_mods;
+    bs.StartPosRel = start_pos_rel;
+    bs.EndPosRel = start_pos_rel;
+    bs.ScrollAccum = Vector2::from_floats(0.0, 0.0);
+}
+
+fn BoxSelectActivateDrag(g: &mut AppContext, window_id: ImguiHandle)
+{
+    let box_select_id = g.BoxSelectState.ID;
+    {
+        let bs = &mut g.BoxSelectState;
+        bs.IsActive = true;
+        bs.Window = window_id;
+        bs.IsStarting = false;
+        // Starting from the void without modifiers replaces the current selection
+        if bs.IsStartedFromVoid && (bs.KeyMods & (ImGuiModFlags_Ctrl | ImGuiModFlags_Shift)) == 0 {
+            bs.RequestClear = true;
+        }
+    }
+    SetActiveID(g, box_select_id, None);
+    g.ActiveIdWindow = window_id;
+}
+
+fn BoxSelectDeactivateDrag(g: &mut AppContext)
+{
+    let box_select_id = g.BoxSelectState.ID;
+    g.BoxSelectState.IsActive = false;
+    g.BoxSelectState.IsStarting = false;
+    if g.ActiveId == box_select_id {
+        ClearActiveID(g);
+    }
+    g.BoxSelectState.ID = 0;
+}
+
+// Scroll while the mouse is held outside of 'inner_r', faster as the mouse gets further away.
+unsafe fn BoxSelectScrollWithMouseDrag(g: &mut AppContext, window_id: ImguiHandle, inner_r: &ImRect)
+{
+    let mouse_pos = g.IO.MousePos;
+    let font_size = g.FontSize;
+    let delta_time = g.IO.DeltaTime;
+    let bs = &mut g.BoxSelectState;
+    // IM_ASSERT(bs.Window == window_id);
+    let window = match g.windows.get_mut(&window_id) {
+        Some(window) => window,
+        None => return,
+    };
+    for axis in 0..2 {
+        let (mouse, inner_min, inner_max, scroll_curr, scroll_max) = if axis == 0 {
+            (mouse_pos.x, inner_r.min.x, inner_r.max.x, window.scroll.x, window.scrollMax.x)
+        } else {
+            (mouse_pos.y, inner_r.min.y, inner_r.max.y, window.scroll.y, window.scrollMax.y)
+        };
+        let dist = if mouse > inner_max {
+            mouse - inner_max
+        } else if mouse < inner_min {
+            mouse - inner_min
+        } else {
+            0.0
+        };
+        if dist == 0.0 || (dist < 0.0 && scroll_curr <= 0.0) || (dist > 0.0 && scroll_curr >= scroll_max) {
+            continue;
+        }
+
+        // x1 to x4 depending on distance
+        let t = ((dist.abs() - font_size) / (font_size * 4.0)).clamp(0.0, 1.0);
+        let speed_multiplier = 1.0 + t * 3.0;
+        let scroll_step = font_size * 35.0 * speed_multiplier * dist.signum() * delta_time;
+
+        // Accumulate into a stored value so we can handle high-framerate
+        let scroll_accum = if axis == 0 { &mut bs.ScrollAccum.x } else { &mut bs.ScrollAccum.y };
+        *scroll_accum += scroll_step;
+        let scroll_step_i = scroll_accum.trunc();
+        if scroll_step_i == 0.0 {
+            continue;
+        }
+        if axis == 0 {
+            SetScrollX(window, scroll_curr + scroll_step_i);
+        } else {
+            SetScrollY(window, scroll_curr + scroll_step_i);
+        }
+        *scroll_accum -= scroll_step_i;
+    }
+}
+
+// Update the box-select owned by 'box_select_id' and compute this frame's selection rectangles.
+// Return true when box-select is active: items submitted until EndBoxSelect() then compare their rect against
+// BoxSelectRectPrev/BoxSelectRectCurr to toggle their selection.
+pub fn BeginBoxSelect(g: &mut AppContext, scope_rect: &ImRect, window_id: ImguiHandle, box_select_id: ImguiHandle, ms_flags: ImGuiMultiSelectFlags) -> bool
+{
+    KeepAliveID(g, box_select_id);
+    if g.BoxSelectState.ID != box_select_id {
+        return false;
+    }
+
+    // IsStarting is set by BoxSelectPreStartDrag(). We validate it here and lock geometry.
+    g.BoxSelectState.UnclipMode = false;
+    g.BoxSelectState.RequestClear = false;
+    if g.BoxSelectState.IsStarting && IsMouseDragPastThreshold(ImGuiMouseButton_Left, -1.0) {
+        BoxSelectActivateDrag(g, window_id);
+    } else if (g.BoxSelectState.IsStarting || g.BoxSelectState.IsActive) && !g.IO.MouseDown[ImGuiMouseButton_Left as usize] {
+        BoxSelectDeactivateDrag(g);
+    }
+    if !g.BoxSelectState.IsActive {
+        return false;
+    }
+
+    // Current frame absolute prev/current rectangles are used to toggle selection.
+    // They are derived from positions relative to scrolling space.
+    let mouse_pos = g.IO.MousePos;
+    let (mut start_pos_abs, mut prev_end_pos_abs) = {
+        let window = g.windows.get(&window_id).unwrap();
+        let bs = &g.BoxSelectState;
+        (WindowPosRelToAbs(window, &bs.StartPosRel), WindowPosRelToAbs(window, &bs.EndPosRel)) // EndPosRel is clamped already
+    };
+    let mut curr_end_pos_abs = mouse_pos;
+    if flag_set(ms_flags, ImGuiMultiSelectFlags_ScopeWindow) {
+        // Box-select scrolling only happens with ScopeWindow
+        curr_end_pos_abs = ImClampVec2(&curr_end_pos_abs, &scope_rect.min, &scope_rect.max);
+    }
+    let bs = &mut g.BoxSelectState;
+    bs.BoxSelectRectPrev = ImRect::from_vec2(&ImMinVec2(&mut start_pos_abs, &mut prev_end_pos_abs), &ImMaxVec2(&mut start_pos_abs, &mut prev_end_pos_abs));
+    bs.BoxSelectRectCurr = ImRect::from_vec2(&ImMinVec2(&mut start_pos_abs, &mut curr_end_pos_abs), &ImMaxVec2(&mut start_pos_abs, &mut curr_end_pos_abs));
+
+    // Box-select 2D mode detects horizontal changes (vertical ones are already picked by Clipper)
+    // Storing an extra rect used by widgets supporting box-select.
+    if flag_set(ms_flags, ImGuiMultiSelectFlags_BoxSelect2d) {
+        if bs.BoxSelectRectPrev.min.x != bs.BoxSelectRectCurr.min.x || bs.BoxSelectRectPrev.max.x != bs.BoxSelectRectCurr.max.x {
+            bs.UnclipMode = true;
+            bs.UnclipRect = bs.BoxSelectRectPrev;
+            bs.UnclipRect.Add2(&bs.BoxSelectRectCurr);
+        }
+    }
+    true
+}
+
+// Render the selection rectangle and scroll when dragging near the edges of the scope.
+pub unsafe fn EndBoxSelect(g: &mut AppContext, scope_rect: &ImRect, ms_flags: ImGuiMultiSelectFlags)
+{
+    // IM_ASSERT(g.BoxSelectState.IsActive);
+    let window_id = g.BoxSelectState.Window;
+    g.BoxSelectState.UnclipMode = false;
+
+    // Clamp stored position according to current scrolling view
+    let mouse_pos = g.IO.MousePos;
+    let end_pos_rel = WindowPosAbsToRel(g.windows.get(&window_id).unwrap(), &ImClampVec2(&mouse_pos, &scope_rect.min, &scope_rect.max));
+    g.BoxSelectState.EndPosRel = end_pos_rel;
+
+    // Render selection rectangle
+    // (on the foreground draw list so it isn't covered by items submitted after EndMultiSelect(), clipped to the scope by hand)
+    let mut box_select_r = g.BoxSelectState.BoxSelectRectCurr;
+    box_select_r.ClipWithFull(scope_rect);
+    let draw_list = GetForegroundDrawList(None);
+    draw_list.AddRectFilled(&box_select_r.min, &box_select_r.max, GetColorU32(ImGuiCol_SeparatorHovered, 0.30), 0.0, ImDrawFlags_None);
+    draw_list.AddRect(box_select_r.min, box_select_r.max, GetColorU32(ImGuiCol_NavHighlight, 1.0), 0.0);
+
+    // Scroll
+    let enable_scroll = flag_set(ms_flags, ImGuiMultiSelectFlags_ScopeWindow) && flag_clear(ms_flags, ImGuiMultiSelectFlags_BoxSelectNoScroll);
+    if enable_scroll {
+        let mut scroll_r = *scope_rect;
+        scroll_r.Expand(-g.FontSize);
+        if !(mouse_pos.x >= scroll_r.min.x && mouse_pos.y >= scroll_r.min.y && mouse_pos.x < scroll_r.max.x && mouse_pos.y < scroll_r.max.y) {
+            BoxSelectScrollWithMouseDrag(g, window_id, &scroll_r);
+        }
+    }
+}
+
+// Return the box-select state if it is active and owned by 'id'.
+pub fn GetBoxSelectState(g: &mut AppContext, id: ImguiHandle) -> Option<&mut ImGuiBoxSelectState>
+{
+    let bs = &mut g.BoxSelectState;
+    if id != 0 && bs.ID == id && bs.IsActive {
+        Some(bs)
+    } else {
+        None
+    }
+}
+
+// True when a multi-select item clipped by ItemAdd() must still be processed because box-select is toggling it
+// (it overlaps only one of the previous/current rectangles). Widgets supporting box-select call this before returning early.
+pub fn BoxSelectUnclipItem(g: &AppContext, bb: &ImRect) -> bool
+{
+    let bs = &g.BoxSelectState;
+    let is_owner = g.CurrentMultiSelect.as_ref().map_or(false, |ms| ms.BoxSelectId != 0 && ms.BoxSelectId == bs.ID);
+    if !is_owner || !bs.IsActive {
+        return false;
+    }
+    bs.BoxSelectRectPrev.clone().Overlaps(bb) != bs.BoxSelectRectCurr.clone().Overlaps(bb)
+}
@@ -48,6 +48,7 @@ use crate::GImGui;
 use libc::{c_float, c_int};
 use std::ptr::null;
 use crate::core::context::AppContext;
+use crate::test_engine::test_engine::ImGuiTestEngineHook_ItemAdd;
 
 pub fn ButtonBehavior(
     g: &mut AppContext,
@@ -78,8 +79,8 @@ pub fn ButtonBehavior(
     }
 
     // #ifdef IMGUI_ENABLE_TEST_ENGINE
-    if id != 0 && g.last_item_data.id != id {
-        IMGUI_TEST_ENGINE_ITEM_ADD(bb, id);
+    if id != 0 && g.last_item_data.id != id && g.TestEngineHookItems {
+        ImGuiTestEngineHook_ItemAdd(g, bb, id);
     }
     // #endif
 
@@ -16,6 +16,7 @@ use crate::window::ops::GetCurrentWindow;
 use crate::window::ImguiWindow;
 use crate::{button_ops, GImGui};
 use libc::{c_float, c_int, c_uint};
+use crate::widgets::undo_ops::UndoTrackItemValue;
 use std::ptr::null;
 
 pub unsafe fn Checkbox(label: &String, v: &mut bool) -> bool {
@@ -28,6 +29,7 @@ pub unsafe fn Checkbox(label: &String, v: &mut bool) -> bool {
     let setyle = &mut g.style;
     let mut id: ImguiHandle = window.id_from_str(label, );
     let label_size: Vector2 = CalcTextSize(, label, true, 0.0);
+    let v_before: bool = *v;
 
     let square_sz: c_float = GetFrameHeight();
     let pos: Vector2 = window.dc.cursor_pos;
@@ -128,6 +130,7 @@ pub unsafe fn Checkbox(label: &String, v: &mut bool) -> bool {
             | ImGuiItemStatusFlags_Checkable
             | (if *v { ImGuiItemStatusFlags_Checked } else { 0 }),
     );
+    UndoTrackItemValue(g, label, v, v_before, pressed);
     return pressed;
 }
 
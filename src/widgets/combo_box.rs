@@ -57,6 +57,7 @@ use crate::window::window_flags::{
 use crate::window::ImguiWindow;
 use crate::{button_ops, hash_string, popup_ops, GImGui};
 use libc::{c_char, c_float, c_int, strlen};
+use crate::widgets::undo_ops::UndoTrackItemValue;
 use std::borrow::BorrowMut;
 use std::ptr::{null, null_mut};
 
@@ -115,7 +116,7 @@ pub fn BeginCombo(
     let mut hovered = false;
     let mut held = false;
     let mut pressed: bool = ButtonBehavior(g, &bb, id, &mut hovered, &mut held, 0);
-    let mut popup_id: ImguiHandle = hash_string(&String::from("##ComboPopup"), id as u32);
+    let mut popup_id: ImguiHandle = hash_string(&String::from("##ComboPopup"), id);
     let mut popup_open: bool = IsPopupOpen(g, popup_id, ImGuiPopupFlags_None);
     if pressed && !popup_open {
         OpenPopupEx(g, popup_id, ImGuiPopupFlags_None);
@@ -431,6 +432,8 @@ pub fn Combo(
     items_count: i32,
     popup_max_height_in_items: c_int,
 ) -> bool {
+    let current_item_before = *current_item;
+
     // Call the getter to obtain the preview string which is a parameter to BeginCombo()
     let mut preview_value: String = String::default();
     if *current_item >= 0 && *current_item < items_count {
@@ -457,6 +460,7 @@ pub fn Combo(
     }
 
     if !BeginCombo(g, label, &mut preview_value, ImGuiComboFlags_None) {
+        UndoTrackItemValue(g, label, current_item, current_item_before, false);
         return false;
     }
 
@@ -486,6 +490,7 @@ pub fn Combo(
     if value_changed {
         MarkItemEdited(g, g.last_item_data.id);
     }
+    UndoTrackItemValue(g, label, current_item, current_item_before, value_changed);
 
     return value_changed;
 }
@@ -0,0 +1,49 @@
+#![allow(non_snake_case)]
+
+use libc::c_float;
+use crate::core::vec2::Vector2;
+use crate::widgets::curve_tangent_mode::{ImGuiCurveTangentMode, ImGuiCurveTangentMode_Auto};
+
+// A key of a curve edited by CurveEditor(). Keys are sorted by Time.
+#[derive(Debug, Clone, Copy)]
+pub struct ImGuiKeyframe {
+    pub Time: c_float,
+    pub Value: c_float,
+    pub InTangent: Vector2,
+    pub OutTangent: Vector2,
+    // Offsets (time, value) of the bezier handles from the key. Only stored for _Bezier keys, computed for the others.
+    pub TangentMode: ImGuiCurveTangentMode,
+    // Applies to the segment leaving the key, and to the handles of the key
+}
+
+impl ImGuiKeyframe {
+    pub fn new(time: c_float, value: c_float) -> Self {
+        Self {
+            Time: time,
+            Value: value,
+            InTangent: Vector2::from_floats(0.0, 0.0),
+            OutTangent: Vector2::from_floats(0.0, 0.0),
+            TangentMode: ImGuiCurveTangentMode_Auto,
+        }
+    }
+}
+
+// Which part of the curve is dragged
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ImGuiCurveEditorDrag {
+    #[default]
+    None,
+    Key,
+    InTangent,
+    OutTangent,
+}
+
+// Persistent state of a CurveEditor(), per ID
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiCurveEditorState {
+    pub ViewMin: Vector2,
+    pub ViewMax: Vector2,
+    // Visible (time, value) range, fitted to the keys while not editing
+    pub Selected: Option<usize>,
+    pub Drag: ImGuiCurveEditorDrag,
+}
@@ -0,0 +1,402 @@
+#![allow(non_snake_case)]
+
+//-------------------------------------------------------------------------
+// [SECTION] Widgets: CurveEditor
+//-------------------------------------------------------------------------
+// - CurveUpdateTangents()
+// - CurveEvaluate()
+// - CurveEditor()
+//-------------------------------------------------------------------------
+// Curves are a Vec<ImGuiKeyframe> sorted by time, made of cubic bezier segments between the keys (see ImBezierCubicCalc()).
+// - Click selects a key, dragging moves it between its neighbors. The tangent handles of the selected key can be dragged.
+// - Double-click adds a key. Right-click on a key opens a menu to change its tangent mode or delete it, Delete removes the selected key
+//   while the editor has the keyboard focus (clicking it gives it the focus).
+// - The view fits the keys, except while dragging so that the curve doesn't move under the mouse.
+// The keys live in a Vec, which the undo context can't snapshot: record your own undo step when IsItemDeactivatedAfterEdit()
+// returns true after CurveEditor(), a whole drag is a single edit. Inserting, deleting and changing a tangent mode are single edits too:
+// the editor stays active until the next frame to report them.
+//-------------------------------------------------------------------------
+
+use libc::c_float;
+use crate::color::{ImGuiCol_Border, ImGuiCol_FrameBg, ImGuiCol_NavHighlight, ImGuiCol_PlotLines, ImGuiCol_PlotLinesHovered, ImGuiCol_Text, ImGuiCol_TextDisabled};
+use crate::core::context::AppContext;
+use crate::core::hash_ops::hash_string;
+use crate::core::id_ops::{ClearActiveID, pop_win_id_from_stack, PushOverrideID, SetActiveID};
+use crate::core::math_ops::ImBezierCubicCalc;
+use crate::core::vec2::Vector2;
+use crate::drawing::draw_flags::ImDrawFlags_None;
+use crate::drawing::render_ops::{RenderFrame, RenderText};
+use crate::io::input_ops::IsKeyPressed;
+use crate::io::key::ImGuiKey_Delete;
+use crate::item::item_ops::{CalcItemSize, CalcItemWidth, ItemAdd, ItemHoverable, ItemSize, MarkItemEdited};
+use crate::rect::ImRect;
+use crate::style::style_ops::GetColorU32;
+use crate::text::text_ops::CalcTextSize;
+use crate::widgets::a_widgets::Selectable;
+use crate::widgets::curve::{ImGuiCurveEditorDrag, ImGuiKeyframe};
+use crate::widgets::curve_tangent_mode::{ImGuiCurveTangentMode_Auto, ImGuiCurveTangentMode_Bezier, ImGuiCurveTangentMode_Linear, ImGuiCurveTangentMode_Step};
+use crate::widgets::group_ops::{BeginGroup, EndGroup};
+use crate::widgets::nav_ops::SetFocusID;
+use crate::widgets::popup_ops::{BeginPopup, EndPopup, OpenPopup};
+use crate::widgets::selectable_flags::ImGuiSelectableFlags_None;
+use crate::widgets::separator::Separator;
+
+// Half size of the key squares, radius of the tangent handles, in pixels
+const CURVE_KEY_RADIUS: c_float = 4.0;
+const CURVE_HANDLE_RADIUS: c_float = 3.5;
+
+// Fraction of the extents of the keys added on each side of the view
+const CURVE_VIEW_PADDING: c_float = 0.1;
+
+// Bisection steps finding the bezier parameter of a time, enough for f32
+const CURVE_SOLVE_ITERATIONS: usize = 24;
+
+// Compute the tangents of the keys which aren't _Bezier from their neighbors
+pub fn CurveUpdateTangents(keys: &mut [ImGuiKeyframe]) {
+    for n in 0..keys.len() {
+        let key = keys[n];
+        let prev = if n > 0 { Some(keys[n - 1]) } else { None };
+        let next = keys.get(n + 1).copied();
+        if key.TangentMode == ImGuiCurveTangentMode_Bezier {
+            continue;
+        }
+        if key.TangentMode == ImGuiCurveTangentMode_Auto {
+            // Parallel to the line joining the neighbors, flat on the first and last keys
+            let slope = match (prev, next) {
+                (Some(prev), Some(next)) if next.Time > prev.Time => (next.Value - prev.Value) / (next.Time - prev.Time),
+                _ => 0.0,
+            };
+            let in_dt = prev.map_or(0.0, |prev| (key.Time - prev.Time) / 3.0);
+            let out_dt = next.map_or(0.0, |next| (next.Time - key.Time) / 3.0);
+            keys[n].InTangent = Vector2::from_floats(-in_dt, -in_dt * slope);
+            keys[n].OutTangent = Vector2::from_floats(out_dt, out_dt * slope);
+        } else {
+            // Pointing at the neighbors
+            keys[n].InTangent = prev.map_or(Vector2::from_floats(0.0, 0.0), |prev| Vector2::from_floats((prev.Time - key.Time) / 3.0, (prev.Value - key.Value) / 3.0));
+            keys[n].OutTangent = next.map_or(Vector2::from_floats(0.0, 0.0), |next| Vector2::from_floats((next.Time - key.Time) / 3.0, (next.Value - key.Value) / 3.0));
+        }
+    }
+}
+
+// Control points of the segment between two keys, in (time, value). Handles are kept within the segment so that time is monotonic.
+fn CurveSegmentBezier(k0: &ImGuiKeyframe, k1: &ImGuiKeyframe) -> [Vector2; 4] {
+    let dt = k1.Time - k0.Time;
+    [
+        Vector2::from_floats(k0.Time, k0.Value),
+        Vector2::from_floats(k0.Time + k0.OutTangent.x.clamp(0.0, dt), k0.Value + k0.OutTangent.y),
+        Vector2::from_floats(k1.Time + k1.InTangent.x.clamp(-dt, 0.0), k1.Value + k1.InTangent.y),
+        Vector2::from_floats(k1.Time, k1.Value),
+    ]
+}
+
+fn CurveEvaluateSegment(k0: &ImGuiKeyframe, k1: &ImGuiKeyframe, time: c_float) -> c_float {
+    let dt = k1.Time - k0.Time;
+    if dt <= 0.0 {
+        return k1.Value;
+    }
+    if k0.TangentMode == ImGuiCurveTangentMode_Step {
+        return k0.Value;
+    }
+    if k0.TangentMode == ImGuiCurveTangentMode_Linear {
+        return k0.Value + (k1.Value - k0.Value) * (time - k0.Time) / dt;
+    }
+    let b = CurveSegmentBezier(k0, k1);
+    let (mut lo, mut hi) = (0.0, 1.0);
+    for _ in 0..CURVE_SOLVE_ITERATIONS {
+        let mid = (lo + hi) * 0.5;
+        if ImBezierCubicCalc(&b[0], &b[1], &b[2], &b[3], mid).x < time {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    ImBezierCubicCalc(&b[0], &b[1], &b[2], &b[3], (lo + hi) * 0.5).y
+}
+
+// Value of a curve at 'time'. The curve is flat before the first key and after the last one, 0.0 without keys.
+// Call CurveUpdateTangents() first if the keys were changed by something else than CurveEditor().
+pub fn CurveEvaluate(keys: &[ImGuiKeyframe], time: c_float) -> c_float {
+    let (first, last) = match (keys.first(), keys.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return 0.0,
+    };
+    if time <= first.Time {
+        return first.Value;
+    }
+    if time >= last.Time {
+        return last.Value;
+    }
+    let n = keys.partition_point(|key| key.Time <= time);
+    CurveEvaluateSegment(&keys[n - 1], &keys[n], time)
+}
+
+// Returns true when the keys were changed
+pub unsafe fn CurveEditor(g: &mut AppContext, label: &str, keys: &mut Vec<ImGuiKeyframe>, size_arg: Vector2) -> bool {
+    let (skip_items, seed, window_id) = {
+        let window = g.current_window_mut().unwrap();
+        (window.skip_items, window.id_stack.last().unwrap().clone(), window.ID)
+    };
+    if skip_items {
+        return false;
+    }
+    let id = hash_string(&String::from(label), seed);
+    let label_size = CalcTextSize(g, &String::from(label), true, 0.0);
+    let font_size = g.FontSize;
+    let style_frame_padding = g.style.FramePadding;
+    let style_inner_spacing = g.style.ItemInnerSpacing;
+    let item_width = CalcItemWidth(g);
+    let frame_size = CalcItemSize(g, size_arg, item_width, font_size * 8.0);
+    let cursor_pos = g.current_window_mut().unwrap().dc.cursor_pos;
+    let frame_bb = ImRect::from_floats(cursor_pos.x, cursor_pos.y, cursor_pos.x + frame_size.x, cursor_pos.y + frame_size.y);
+    let total_size = Vector2::from_floats(frame_size.x + if label_size.x > 0.0 { style_inner_spacing.x + label_size.x } else { 0.0 }, frame_size.y);
+    let mut total_bb = ImRect::from_floats(frame_bb.min.x, frame_bb.min.y, frame_bb.min.x + total_size.x, frame_bb.max.y);
+
+    // The group makes IsItemXXX() functions cover the key menu too. The ID scopes the name of the popup.
+    BeginGroup();
+    PushOverrideID(g, id);
+    ItemSize(g, &total_size, style_frame_padding.y);
+    if !ItemAdd(g, &mut total_bb, id, Some(&frame_bb), 0) {
+        pop_win_id_from_stack(g);
+        EndGroup();
+        return false;
+    }
+
+    if keys.windows(2).any(|pair| pair[1].Time < pair[0].Time) {
+        keys.sort_by(|a, b| a.Time.total_cmp(&b.Time));
+    }
+    CurveUpdateTangents(keys);
+    let mut state = g.CurveEditors.remove(&id).unwrap_or_default();
+    if state.Selected.map_or(false, |key_n| key_n >= keys.len()) {
+        state.Selected = None;
+    }
+    if g.ActiveId != id {
+        let mut view_min = Vector2::from_floats(c_float::MAX, c_float::MAX);
+        let mut view_max = Vector2::from_floats(c_float::MIN, c_float::MIN);
+        for key in keys.iter() {
+            view_min = Vector2::from_floats(view_min.x.min(key.Time), view_min.y.min(key.Value));
+            view_max = Vector2::from_floats(view_max.x.max(key.Time), view_max.y.max(key.Value));
+        }
+        if keys.is_empty() {
+            view_min = Vector2::from_floats(0.0, 0.0);
+            view_max = Vector2::from_floats(1.0, 1.0);
+        }
+        if view_max.x - view_min.x < 1e-6 {
+            view_min.x -= 0.5;
+            view_max.x += 0.5;
+        }
+        if view_max.y - view_min.y < 1e-6 {
+            view_min.y -= 0.5;
+            view_max.y += 0.5;
+        }
+        let pad = Vector2::from_floats((view_max.x - view_min.x) * CURVE_VIEW_PADDING, (view_max.y - view_min.y) * CURVE_VIEW_PADDING);
+        state.ViewMin = Vector2::from_floats(view_min.x - pad.x, view_min.y - pad.y);
+        state.ViewMax = Vector2::from_floats(view_max.x + pad.x, view_max.y + pad.y);
+    }
+    let inner = ImRect::from_floats(frame_bb.min.x + style_frame_padding.x, frame_bb.min.y + style_frame_padding.y, frame_bb.max.x - style_frame_padding.x, frame_bb.max.y - style_frame_padding.y);
+    let (view_min, view_max) = (state.ViewMin, state.ViewMax);
+    let to_screen = |time: c_float, value: c_float| Vector2::from_floats(
+        inner.min.x + (time - view_min.x) / (view_max.x - view_min.x) * (inner.max.x - inner.min.x),
+        inner.max.y - (value - view_min.y) / (view_max.y - view_min.y) * (inner.max.y - inner.min.y),
+    );
+    let from_screen = |p: Vector2| Vector2::from_floats(
+        view_min.x + (p.x - inner.min.x) / (inner.max.x - inner.min.x) * (view_max.x - view_min.x),
+        view_min.y + (inner.max.y - p.y) / (inner.max.y - inner.min.y) * (view_max.y - view_min.y),
+    );
+    let handles_shown = |key: &ImGuiKeyframe| key.TangentMode == ImGuiCurveTangentMode_Auto || key.TangentMode == ImGuiCurveTangentMode_Bezier;
+
+    // Hovered tangent handle of the selected key, else hovered key
+    let hovered = ItemHoverable(&frame_bb, id);
+    let mouse = g.IO.MousePos;
+    let near = |p: Vector2, radius: c_float| (p.x - mouse.x).powi(2) + (p.y - mouse.y).powi(2) <= radius * radius;
+    let mut hovered_drag = ImGuiCurveEditorDrag::None;
+    let mut hovered_key = None;
+    if hovered {
+        if let Some(key_n) = state.Selected {
+            let key = keys[key_n];
+            if handles_shown(&key) {
+                if key_n > 0 && near(to_screen(key.Time + key.InTangent.x, key.Value + key.InTangent.y), CURVE_HANDLE_RADIUS * 2.0) {
+                    hovered_drag = ImGuiCurveEditorDrag::InTangent;
+                } else if key_n + 1 < keys.len() && near(to_screen(key.Time + key.OutTangent.x, key.Value + key.OutTangent.y), CURVE_HANDLE_RADIUS * 2.0) {
+                    hovered_drag = ImGuiCurveEditorDrag::OutTangent;
+                }
+                if hovered_drag != ImGuiCurveEditorDrag::None {
+                    hovered_key = Some(key_n);
+                }
+            }
+        }
+        if hovered_drag == ImGuiCurveEditorDrag::None {
+            hovered_key = keys.iter().position(|key| near(to_screen(key.Time, key.Value), CURVE_KEY_RADIUS * 2.0));
+            if hovered_key.is_some() {
+                hovered_drag = ImGuiCurveEditorDrag::Key;
+            }
+        }
+    }
+
+    let mut value_changed = false;
+    let mut edited_once = false;
+    if hovered && g.IO.MouseClicked[0] {
+        SetFocusID(id, g.current_window_mut().unwrap());
+        if hovered_drag != ImGuiCurveEditorDrag::None {
+            state.Selected = hovered_key;
+            state.Drag = hovered_drag;
+            SetActiveID(g, id, None);
+            g.ActiveIdWindow = window_id;
+        } else if g.IO.MouseClickedCount[0] == 2 {
+            let p = from_screen(mouse);
+            let key_n = keys.partition_point(|key| key.Time <= p.x);
+            keys.insert(key_n, ImGuiKeyframe::new(p.x, p.y));
+            state.Selected = Some(key_n);
+            value_changed = true;
+            edited_once = true;
+        } else {
+            state.Selected = None;
+        }
+    }
+    if hovered && g.IO.MouseClicked[1] && hovered_drag == ImGuiCurveEditorDrag::Key {
+        state.Selected = hovered_key;
+        OpenPopup("##key_menu", 0);
+    }
+    if let Some(key_n) = state.Selected {
+        if (g.NavId == id || g.ActiveId == id) && IsKeyPressed(ImGuiKey_Delete, false) {
+            keys.remove(key_n);
+            state.Selected = None;
+            state.Drag = ImGuiCurveEditorDrag::None;
+            value_changed = true;
+            edited_once = true;
+        }
+    }
+
+    if g.ActiveId == id {
+        if !g.IO.MouseDown[0] {
+            state.Drag = ImGuiCurveEditorDrag::None;
+            ClearActiveID(g);
+        } else if let Some(key_n) = state.Selected {
+            let p = from_screen(mouse);
+            let key = keys[key_n];
+            match state.Drag {
+                ImGuiCurveEditorDrag::Key => {
+                    // Keys can't cross their neighbors
+                    let min_time = if key_n > 0 { keys[key_n - 1].Time } else { c_float::MIN };
+                    let max_time = keys.get(key_n + 1).map_or(c_float::MAX, |next| next.Time);
+                    let time = p.x.clamp(min_time, max_time);
+                    value_changed |= time != key.Time || p.y != key.Value;
+                    keys[key_n].Time = time;
+                    keys[key_n].Value = p.y;
+                }
+                ImGuiCurveEditorDrag::InTangent | ImGuiCurveEditorDrag::OutTangent => {
+                    let tangent = if state.Drag == ImGuiCurveEditorDrag::InTangent {
+                        Vector2::from_floats((p.x - key.Time).min(0.0), p.y - key.Value)
+                    } else {
+                        Vector2::from_floats((p.x - key.Time).max(0.0), p.y - key.Value)
+                    };
+                    let current = if state.Drag == ImGuiCurveEditorDrag::InTangent { key.InTangent } else { key.OutTangent };
+                    if tangent.x != current.x || tangent.y != current.y {
+                        value_changed = true;
+                        keys[key_n].TangentMode = ImGuiCurveTangentMode_Bezier;
+                        if state.Drag == ImGuiCurveEditorDrag::InTangent {
+                            keys[key_n].InTangent = tangent;
+                        } else {
+                            keys[key_n].OutTangent = tangent;
+                        }
+                    }
+                }
+                ImGuiCurveEditorDrag::None => {}
+            }
+        }
+    }
+
+    if BeginPopup("##key_menu", 0) {
+        if let Some(key_n) = state.Selected {
+            for (mode, name) in [(ImGuiCurveTangentMode_Auto, "Auto"), (ImGuiCurveTangentMode_Linear, "Linear"), (ImGuiCurveTangentMode_Step, "Step"), (ImGuiCurveTangentMode_Bezier, "Bezier")] {
+                let current = keys[key_n].TangentMode == mode;
+                if Selectable(String::from(name), current, ImGuiSelectableFlags_None, None) && !current {
+                    keys[key_n].TangentMode = mode;
+                    value_changed = true;
+                    edited_once = true;
+                }
+            }
+            Separator();
+            if Selectable(String::from("Delete"), false, ImGuiSelectableFlags_None, None) {
+                keys.remove(key_n);
+                state.Selected = None;
+                value_changed = true;
+                edited_once = true;
+            }
+        }
+        EndPopup(g);
+    }
+
+    // Render
+    CurveUpdateTangents(keys);
+    RenderFrame(frame_bb.min, frame_bb.max, GetColorU32(ImGuiCol_FrameBg, 1.0), true, g.style.FrameRounding);
+    let col_axis = GetColorU32(ImGuiCol_Border, 1.0);
+    let col_curve = GetColorU32(ImGuiCol_PlotLines, 1.0);
+    let col_key = GetColorU32(ImGuiCol_Text, 1.0);
+    let col_hovered = GetColorU32(ImGuiCol_PlotLinesHovered, 1.0);
+    let col_selected = GetColorU32(ImGuiCol_NavHighlight, 1.0);
+    let col_handle = GetColorU32(ImGuiCol_TextDisabled, 1.0);
+    let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+    draw_list.PushClipRect(&inner.min, &inner.max, true);
+    let origin = to_screen(0.0, 0.0);
+    if view_min.y < 0.0 && view_max.y > 0.0 {
+        draw_list.AddLine(Vector2::from_floats(inner.min.x, origin.y), Vector2::from_floats(inner.max.x, origin.y), col_axis, 1.0);
+    }
+    if view_min.x < 0.0 && view_max.x > 0.0 {
+        draw_list.AddLine(Vector2::from_floats(origin.x, inner.min.y), Vector2::from_floats(origin.x, inner.max.y), col_axis, 1.0);
+    }
+    if let (Some(first), Some(last)) = (keys.first(), keys.last()) {
+        let p_first = to_screen(first.Time, first.Value);
+        let p_last = to_screen(last.Time, last.Value);
+        draw_list.AddLine(Vector2::from_floats(inner.min.x, p_first.y), p_first, col_curve, 1.5);
+        for pair in keys.windows(2) {
+            let (k0, k1) = (&pair[0], &pair[1]);
+            let (p0, p1) = (to_screen(k0.Time, k0.Value), to_screen(k1.Time, k1.Value));
+            if k0.TangentMode == ImGuiCurveTangentMode_Step {
+                draw_list.AddLine(p0, Vector2::from_floats(p1.x, p0.y), col_curve, 1.5);
+                draw_list.AddLine(Vector2::from_floats(p1.x, p0.y), p1, col_curve, 1.5);
+            } else if k0.TangentMode == ImGuiCurveTangentMode_Linear {
+                draw_list.AddLine(p0, p1, col_curve, 1.5);
+            } else {
+                let b = CurveSegmentBezier(k0, k1).map(|p| to_screen(p.x, p.y));
+                draw_list.AddBezierCubic(&b[0], &b[1], &b[2], &b[3], col_curve, 1.5, 0);
+            }
+        }
+        draw_list.AddLine(p_last, Vector2::from_floats(inner.max.x, p_last.y), col_curve, 1.5);
+    }
+    if let Some(key_n) = state.Selected {
+        let key = keys[key_n];
+        if handles_shown(&key) {
+            let p_key = to_screen(key.Time, key.Value);
+            for (shown, tangent, drag) in [(key_n > 0, key.InTangent, ImGuiCurveEditorDrag::InTangent), (key_n + 1 < keys.len(), key.OutTangent, ImGuiCurveEditorDrag::OutTangent)] {
+                if shown {
+                    let p_handle = to_screen(key.Time + tangent.x, key.Value + tangent.y);
+                    let active = state.Drag == drag || hovered_drag == drag;
+                    draw_list.AddLine(p_key, p_handle, col_handle, 1.0);
+                    draw_list.AddCircleFilled(&p_handle, CURVE_HANDLE_RADIUS, if active { col_hovered } else { col_handle }, 0);
+                }
+            }
+        }
+    }
+    for (key_n, key) in keys.iter().enumerate() {
+        let p = to_screen(key.Time, key.Value);
+        let col = if state.Selected == Some(key_n) { col_selected } else if hovered_key == Some(key_n) && hovered_drag == ImGuiCurveEditorDrag::Key { col_hovered } else { col_key };
+        draw_list.AddRectFilled(&Vector2::from_floats(p.x - CURVE_KEY_RADIUS, p.y - CURVE_KEY_RADIUS), &Vector2::from_floats(p.x + CURVE_KEY_RADIUS, p.y + CURVE_KEY_RADIUS), col, 0.0, ImDrawFlags_None);
+    }
+    draw_list.PopClipRect();
+    if label_size.x > 0.0 {
+        RenderText(Vector2::from_floats(frame_bb.max.x + style_inner_spacing.x, frame_bb.min.y + style_frame_padding.y), &String::from(label), true, g);
+    }
+
+    g.CurveEditors.insert(id, state);
+    pop_win_id_from_stack(g);
+    EndGroup();
+    // Edits without a drag hold the active id until the next frame, so that IsItemDeactivatedAfterEdit() reports them
+    if edited_once && g.ActiveId != id {
+        SetActiveID(g, id, None);
+        g.ActiveIdWindow = window_id;
+    }
+    if value_changed {
+        MarkItemEdited(g, g.last_item_data.ID);
+    }
+    value_changed
+}
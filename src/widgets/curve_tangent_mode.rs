@@ -0,0 +1,14 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+pub type ImGuiCurveTangentMode = c_int;
+
+// Shape of the curve around a key, see CurveEditor()
+// enum ImGuiCurveTangentMode
+// {
+pub const ImGuiCurveTangentMode_Auto: ImGuiCurveTangentMode = 0;   // Smooth, tangents follow the neighbor keys. Dragging a tangent switches the key to _Bezier.
+pub const ImGuiCurveTangentMode_Linear: ImGuiCurveTangentMode = 1;   // Straight line to the next key, tangents point at the neighbor keys.
+pub const ImGuiCurveTangentMode_Step: ImGuiCurveTangentMode = 2;   // Hold the value until the next key.
+pub const ImGuiCurveTangentMode_Bezier: ImGuiCurveTangentMode = 3;   // Tangents set by the user.
+// };
@@ -0,0 +1,173 @@
+#![allow(non_snake_case)]
+
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+use std::ptr::null;
+use libc::c_int;
+use crate::core::hash_ops::hash_string;
+use crate::core::type_defs::ImguiHandle;
+use crate::text::text_filter::ImGuiTextFilter;
+use crate::widgets::file_dialog_flags::{ImGuiFileDialogFlags, ImGuiFileDialogFlags_None, ImGuiFileDialogFlags_SelectDirectory, ImGuiFileDialogFlags_ShowHidden};
+use crate::widgets::file_system::{ImGuiFileEntry, ImGuiFileSystem};
+use crate::widgets::multi_select::{ImGuiMultiSelectIO, ImGuiSelectionRequestType_SetAll, ImGuiSelectionRequestType_SetRange};
+use crate::widgets::selection_basic_storage::ImGuiSelectionBasicStorage;
+
+// Columns of the FileDialog() listing, also the values of SortColumn
+pub const FILE_DIALOG_COLUMN_NAME: c_int = 0;
+pub const FILE_DIALOG_COLUMN_SIZE: c_int = 1;
+pub const FILE_DIALOG_COLUMN_MODIFIED: c_int = 2;
+
+// State of a FileDialog(), owned by the application so that the current directory, bookmarks and filter persist between uses.
+// Not stored in the context: the filesystem is provided by the application.
+pub struct ImGuiFileDialog {
+    pub FileSystem: Box<dyn ImGuiFileSystem>,
+    pub Flags: ImGuiFileDialogFlags,
+    // Flags of the last FileDialog() call
+    pub CurrentDir: PathBuf,
+    pub Bookmarks: Vec<PathBuf>,
+    pub Filter: ImGuiTextFilter,
+    // Glob patterns, e.g. "*.png,*.jpg". Directories are always listed.
+    pub Selection: ImGuiSelectionBasicStorage,
+    // Entry names (see EntrySelectionId()), so the selection survives sorting, filtering and refreshing
+    pub Entries: Vec<ImGuiFileEntry>,
+    // Listing of CurrentDir, sorted
+    pub VisibleEntries: Vec<c_int>,
+    // Indices into Entries passing the filter and flags
+    pub Error: Option<String>,
+    // Why CurrentDir couldn't be listed
+    pub Result: Vec<PathBuf>,
+    // Set when FileDialog() returns true
+    pub SortColumn: c_int,
+    pub SortDescending: bool,
+    pub NeedsRefresh: bool,
+}
+
+impl ImGuiFileDialog {
+    pub fn new(file_system: Box<dyn ImGuiFileSystem>) -> Self {
+        let current_dir = file_system.CurrentDir();
+        Self {
+            FileSystem: file_system,
+            Flags: ImGuiFileDialogFlags_None,
+            CurrentDir: current_dir,
+            Bookmarks: vec![],
+            Filter: unsafe { ImGuiTextFilter::new(null()) },
+            Selection: ImGuiSelectionBasicStorage::default(),
+            Entries: vec![],
+            VisibleEntries: vec![],
+            Error: None,
+            Result: vec![],
+            SortColumn: FILE_DIALOG_COLUMN_NAME,
+            SortDescending: false,
+            NeedsRefresh: true,
+        }
+    }
+
+    // Change directory. The listing is read again on the next frame.
+    pub fn SetDirectory(&mut self, path: &Path) {
+        self.CurrentDir = path.to_path_buf();
+        self.Selection.Clear();
+        self.NeedsRefresh = true;
+    }
+
+    pub fn SetFilter(&mut self, filter: &str) {
+        unsafe { self.Filter.SetText(filter) };
+        self.UpdateVisibleEntries();
+    }
+
+    pub fn AddBookmark(&mut self, path: &Path) {
+        if !self.Bookmarks.iter().any(|bookmark| bookmark == path) {
+            self.Bookmarks.push(path.to_path_buf());
+        }
+    }
+
+    // Read the listing of CurrentDir
+    pub fn Refresh(&mut self) {
+        self.NeedsRefresh = false;
+        match self.FileSystem.ReadDir(&self.CurrentDir) {
+            Ok(entries) => {
+                self.Entries = entries;
+                self.Error = None;
+            }
+            Err(err) => {
+                self.Entries.clear();
+                self.Error = Some(err.to_string());
+            }
+        }
+        // Keep the selected entries which are still listed
+        let selected: Vec<ImguiHandle> = self.Entries.iter().map(Self::EntrySelectionId).filter(|id| self.Selection.Contains(*id)).collect();
+        self.Selection.Clear();
+        for id in selected {
+            self.Selection.SetItemSelected(id, true);
+        }
+        self.SortEntries();
+    }
+
+    // Sort on SortColumn, directories first
+    pub fn SortEntries(&mut self) {
+        let (column, descending) = (self.SortColumn, self.SortDescending);
+        self.Entries.sort_by(|a, b| {
+            if a.IsDirectory != b.IsDirectory {
+                return if a.IsDirectory { Ordering::Less } else { Ordering::Greater };
+            }
+            let by_name = a.Name.to_lowercase().cmp(&b.Name.to_lowercase()).then_with(|| a.Name.cmp(&b.Name));
+            let order = match column {
+                FILE_DIALOG_COLUMN_SIZE => a.Size.cmp(&b.Size).then(by_name),
+                FILE_DIALOG_COLUMN_MODIFIED => a.Modified.cmp(&b.Modified).then(by_name),
+                _ => by_name,
+            };
+            if descending { order.reverse() } else { order }
+        });
+        self.UpdateVisibleEntries();
+    }
+
+    // Apply Flags and Filter to Entries. Hidden entries stay selected and show as such when visible again.
+    pub fn UpdateVisibleEntries(&mut self) {
+        let select_directory = self.Flags & ImGuiFileDialogFlags_SelectDirectory != 0;
+        let show_hidden = self.Flags & ImGuiFileDialogFlags_ShowHidden != 0;
+        self.VisibleEntries = self.Entries.iter().enumerate()
+            .filter(|(_, entry)| show_hidden || !entry.Name.starts_with('.'))
+            .filter(|(_, entry)| if entry.IsDirectory { true } else { !select_directory && self.Filter.PassFilterGlob(&entry.Name) })
+            .map(|(n, _)| n as c_int)
+            .collect();
+    }
+
+    pub fn VisibleEntry(&self, visible_n: c_int) -> &ImGuiFileEntry {
+        &self.Entries[self.VisibleEntries[visible_n as usize] as usize]
+    }
+
+    // Identifier stored in Selection: entry names are unique within a directory
+    pub fn EntrySelectionId(entry: &ImGuiFileEntry) -> ImguiHandle {
+        hash_string(&entry.Name, 0)
+    }
+
+    // Same as ImGuiSelectionBasicStorage::ApplyRequests(), with items passed as indices into VisibleEntries and stored by name
+    pub fn ApplySelectionRequests(&mut self, ms_io: &ImGuiMultiSelectIO) {
+        for req in ms_io.Requests.iter() {
+            if req.Type == ImGuiSelectionRequestType_SetAll {
+                self.Selection.Clear();
+                if req.Selected {
+                    for visible_n in 0..self.VisibleEntries.len() as c_int {
+                        let id = Self::EntrySelectionId(self.VisibleEntry(visible_n));
+                        self.Selection.SetItemSelected(id, true);
+                    }
+                }
+            } else if req.Type == ImGuiSelectionRequestType_SetRange {
+                let first = req.RangeFirstItem.min(req.RangeLastItem).max(0);
+                let last = req.RangeFirstItem.max(req.RangeLastItem).min(self.VisibleEntries.len() as i64 - 1);
+                for visible_n in first..=last {
+                    let id = Self::EntrySelectionId(self.VisibleEntry(visible_n as c_int));
+                    self.Selection.SetItemSelected(id, req.Selected);
+                }
+            }
+        }
+    }
+
+    // Selected entries as paths, in listing order. Entries hidden by the filter are left out.
+    pub fn SelectedPaths(&self) -> Vec<PathBuf> {
+        self.VisibleEntries.iter()
+            .map(|&n| &self.Entries[n as usize])
+            .filter(|entry| self.Selection.Contains(Self::EntrySelectionId(entry)))
+            .map(|entry| self.CurrentDir.join(&entry.Name))
+            .collect()
+    }
+}
@@ -0,0 +1,16 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+// typedef int ImGuiFileDialogFlags;      // -> enum ImGuiFileDialogFlags_   // Flags: for FileDialog()
+pub type ImGuiFileDialogFlags = c_int;
+
+// Flags for FileDialog()
+// enum ImGuiFileDialogFlags_
+// {
+pub const ImGuiFileDialogFlags_None: ImGuiFileDialogFlags = 0;
+pub const ImGuiFileDialogFlags_SelectDirectory: ImGuiFileDialogFlags = 1 << 0;   // Pick directories instead of files. Files aren't listed, confirming with nothing selected picks the current directory.
+pub const ImGuiFileDialogFlags_MultiSelect: ImGuiFileDialogFlags = 1 << 1;   // Allow selecting several entries (Ctrl+click, Shift+click, box-select).
+pub const ImGuiFileDialogFlags_ShowHidden: ImGuiFileDialogFlags = 1 << 2;   // List entries whose name starts with '.'.
+pub const ImGuiFileDialogFlags_NoBookmarks: ImGuiFileDialogFlags = 1 << 3;   // Hide the bookmarks panel.
+// };
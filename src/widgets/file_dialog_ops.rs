@@ -0,0 +1,262 @@
+#![allow(non_snake_case)]
+
+//-------------------------------------------------------------------------
+// [SECTION] Widgets: OpenFileDialog, FileDialog
+//-------------------------------------------------------------------------
+// - OpenFileDialog()
+// - FileDialog()
+// - FileDialogFormatSize() [Internal]
+// - FileDialogFormatTime() [Internal]
+//-------------------------------------------------------------------------
+// File and directory picker drawn with Dear ImGui, inside a modal popup:
+// - A breadcrumb bar of the current path (click a component to go up), a bookmarks panel (right-click a bookmark to remove it),
+//   and a table of the current directory sortable by name, size and modification time. Directories are listed first.
+// - Files are filtered with glob patterns typed in the filter field, e.g. "*.png,*.jp?g,-*_tmp*" (see PassFilterGlob()).
+// - Double-click a directory to enter it, a file to pick it. With ImGuiFileDialogFlags_MultiSelect, Ctrl+click, Shift+click
+//   and box-select pick several files.
+// - The dialog state (ImGuiFileDialog) is owned by the application, which provides the filesystem: ImGuiNativeFileSystem, or
+//   any ImGuiFileSystem implementation such as ImGuiMemoryFileSystem.
+// Usage:
+//   static dialog = ImGuiFileDialog::new(Box::new(ImGuiNativeFileSystem));
+//   if Button("Open...") { dialog.SetFilter("*.png,*.jpg"); OpenFileDialog("Open image", &mut dialog); }
+//   if FileDialog(g, "Open image", &mut dialog, ImGuiFileDialogFlags_MultiSelect) {
+//       for path in &dialog.Result { load_image(path); }
+//   }
+//-------------------------------------------------------------------------
+
+use std::path::PathBuf;
+use std::ptr::null_mut;
+use libc::c_int;
+use crate::core::child_ops::{BeginChild, EndChild};
+use crate::core::condition::ImGuiCond_FirstUseEver;
+use crate::core::context::AppContext;
+use crate::core::id_ops::{pop_win_id_from_stack, push_int_id};
+use crate::core::math_ops::ImCivilFromDays;
+use crate::core::string_ops::str_to_const_c_char_ptr;
+use crate::core::vec2::Vector2;
+use crate::drawing::frame_ops::GetFrameHeightWithSpacing;
+use crate::io::input_ops::{IsKeyPressed, IsMouseDoubleClicked};
+use crate::io::key::ImGuiKey_Escape;
+use crate::io::mouse_button::ImGuiMouseButton_Left;
+use crate::layout::layout_ops::same_line;
+use crate::layout::sort_direction::ImGuiSortDirection_Descending;
+use crate::table::table_column_flags::{ImGuiTableColumnFlags_DefaultSort, ImGuiTableColumnFlags_PreferSortDescending, ImGuiTableColumnFlags_WidthFixed, ImGuiTableColumnFlags_WidthStretch};
+use crate::table::table_flags::{ImGuiTableFlags_BordersInnerV, ImGuiTableFlags_BordersOuter, ImGuiTableFlags_Resizable, ImGuiTableFlags_RowBg, ImGuiTableFlags_ScrollY, ImGuiTableFlags_SizingFixedFit, ImGuiTableFlags_Sortable};
+use crate::table::table_row_flags::ImGuiTableRowFlags_None;
+use crate::table::tables::{BeginTable, EndTable, TableGetSortSpecs, TableHeadersRow, TableNextRow, TableSetColumnIndex, TableSetupColumn, TableSetupScrollFreeze};
+use crate::text::text_ops::{Text, TextDisabled};
+use crate::widgets::a_widgets::{MenuItem, Selectable};
+use crate::widgets::button_ops::{Button, SmallButton};
+use crate::widgets::file_dialog::ImGuiFileDialog;
+use crate::widgets::file_dialog_flags::{ImGuiFileDialogFlags, ImGuiFileDialogFlags_MultiSelect, ImGuiFileDialogFlags_NoBookmarks, ImGuiFileDialogFlags_SelectDirectory};
+use crate::widgets::multi_select::ImGuiSelectionUserData;
+use crate::widgets::multi_select_flags::{ImGuiMultiSelectFlags_BoxSelect1d, ImGuiMultiSelectFlags_ClearOnClickVoid, ImGuiMultiSelectFlags_SingleSelect};
+use crate::widgets::multi_select_ops::{BeginMultiSelect, EndMultiSelect, SetNextItemSelectionUserData};
+use crate::widgets::popup_flags::ImGuiPopupFlags_MouseButtonRight;
+use crate::widgets::popup_ops::{BeginPopupContextItem, BeginPopupModal, CloseCurrentPopup, EndPopup, OpenPopup};
+use crate::widgets::selectable_flags::{ImGuiSelectableFlags_AllowDoubleClick, ImGuiSelectableFlags_SpanAllColumns};
+use crate::widgets::unit::ImGuiUnit;
+use crate::widgets::unit_ops::UnitFormatInteger;
+use crate::window::ops::{BeginDisabled, EndDisabled, SetNextWindowSize};
+use crate::window::window_flags::ImGuiWindowFlags_None;
+
+// Sizes, in multiples of the font size
+const FILE_DIALOG_DEFAULT_WIDTH: f32 = 50.0;
+const FILE_DIALOG_DEFAULT_HEIGHT: f32 = 30.0;
+const FILE_DIALOG_BOOKMARKS_WIDTH: f32 = 10.0;
+const FILE_DIALOG_SIZE_COLUMN_WIDTH: f32 = 6.0;
+const FILE_DIALOG_MODIFIED_COLUMN_WIDTH: f32 = 9.0;
+const FILE_DIALOG_BUTTON_WIDTH: f32 = 5.0;
+
+// Open the dialog, it then shows on the next FileDialog() call with the same label
+pub unsafe fn OpenFileDialog(label: &str, dialog: &mut ImGuiFileDialog) {
+    dialog.Result.clear();
+    dialog.NeedsRefresh = true;
+    OpenPopup(label, 0);
+}
+
+// Return true on the frame the user confirms, with the picked paths in dialog.Result. The popup closes itself.
+pub unsafe fn FileDialog(g: &mut AppContext, label: &str, dialog: &mut ImGuiFileDialog, flags: ImGuiFileDialogFlags) -> bool {
+    if dialog.Flags != flags {
+        dialog.Flags = flags;
+        dialog.UpdateVisibleEntries();
+    }
+
+    let font_size = g.FontSize;
+    SetNextWindowSize(Vector2::from_floats(font_size * FILE_DIALOG_DEFAULT_WIDTH, font_size * FILE_DIALOG_DEFAULT_HEIGHT), ImGuiCond_FirstUseEver);
+    let popup_name = format!("{}\0", label);
+    if !BeginPopupModal(str_to_const_c_char_ptr(&popup_name), null_mut(), ImGuiWindowFlags_None) {
+        return false;
+    }
+    if dialog.NeedsRefresh {
+        dialog.Refresh();
+    }
+
+    let select_directory = flags & ImGuiFileDialogFlags_SelectDirectory != 0;
+    let mut navigate_to: Option<PathBuf> = None;
+    let mut confirmed = false;
+
+    // Breadcrumb bar, one button per component of the path
+    let crumbs: Vec<PathBuf> = dialog.CurrentDir.ancestors().map(|dir| dir.to_path_buf()).collect::<Vec<_>>().into_iter().rev().collect();
+    for (n, dir) in crumbs.iter().enumerate() {
+        if n > 0 {
+            same_line(g, 0.0, -1.0);
+        }
+        let name = dir.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_else(|| dir.display().to_string());
+        if Button(&format!("{}##crumb{}", name, n), None) {
+            navigate_to = Some(dir.clone());
+        }
+    }
+
+    let footer_height = GetFrameHeightWithSpacing(g);
+
+    // Bookmarks panel
+    if flags & ImGuiFileDialogFlags_NoBookmarks == 0 {
+        let mut remove_bookmark: Option<usize> = None;
+        BeginChild(String::from("##bookmarks"), Vector2::from_floats(font_size * FILE_DIALOG_BOOKMARKS_WIDTH, -footer_height), true, ImGuiWindowFlags_None);
+        if SmallButton(&String::from("+ Bookmark")) {
+            let current_dir = dialog.CurrentDir.clone();
+            dialog.AddBookmark(&current_dir);
+        }
+        for (n, bookmark) in dialog.Bookmarks.iter().enumerate() {
+            push_int_id(g, n as c_int);
+            let name = bookmark.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_else(|| bookmark.display().to_string());
+            if Selectable(name, *bookmark == dialog.CurrentDir, 0, None) {
+                navigate_to = Some(bookmark.clone());
+            }
+            if BeginPopupContextItem(str_to_const_c_char_ptr("##bookmark_menu\0"), ImGuiPopupFlags_MouseButtonRight) {
+                if MenuItem(String::from("Remove"), "", false, true) {
+                    remove_bookmark = Some(n);
+                }
+                EndPopup(g);
+            }
+            pop_win_id_from_stack(g);
+        }
+        EndChild();
+        if let Some(n) = remove_bookmark {
+            dialog.Bookmarks.remove(n);
+        }
+        same_line(g, 0.0, -1.0);
+    }
+
+    // Listing
+    let table_flags = ImGuiTableFlags_Sortable | ImGuiTableFlags_ScrollY | ImGuiTableFlags_RowBg | ImGuiTableFlags_BordersOuter | ImGuiTableFlags_BordersInnerV | ImGuiTableFlags_Resizable | ImGuiTableFlags_SizingFixedFit;
+    let mut outer_size = Vector2::from_floats(0.0, -footer_height);
+    if BeginTable("##entries", 3, table_flags, Some(&mut outer_size), 0.0) {
+        TableSetupColumn(String::from("Name"), ImGuiTableColumnFlags_WidthStretch | ImGuiTableColumnFlags_DefaultSort, 0.0, 0);
+        TableSetupColumn(String::from("Size"), ImGuiTableColumnFlags_WidthFixed | ImGuiTableColumnFlags_PreferSortDescending, font_size * FILE_DIALOG_SIZE_COLUMN_WIDTH, 0);
+        TableSetupColumn(String::from("Modified"), ImGuiTableColumnFlags_WidthFixed | ImGuiTableColumnFlags_PreferSortDescending, font_size * FILE_DIALOG_MODIFIED_COLUMN_WIDTH, 0);
+        TableSetupScrollFreeze(0, 1);
+        TableHeadersRow();
+
+        let sort_specs = TableGetSortSpecs();
+        if !sort_specs.is_null() && (*sort_specs).SpecsDirty {
+            if (*sort_specs).SpecsCount > 0 {
+                let spec = &*(*sort_specs).Specs;
+                dialog.SortColumn = spec.ColumnIndex as c_int;
+                dialog.SortDescending = spec.SortDirection == ImGuiSortDirection_Descending;
+                dialog.SortEntries();
+            }
+            (*sort_specs).SpecsDirty = false;
+        }
+
+        if let Some(error) = &dialog.Error {
+            TableNextRow(ImGuiTableRowFlags_None, 0.0);
+            TableSetColumnIndex(0);
+            TextDisabled(error.clone());
+        }
+
+        let mut ms_flags = ImGuiMultiSelectFlags_ClearOnClickVoid | ImGuiMultiSelectFlags_BoxSelect1d;
+        if flags & ImGuiFileDialogFlags_MultiSelect == 0 {
+            ms_flags |= ImGuiMultiSelectFlags_SingleSelect;
+        }
+        let size_unit = ImGuiUnit::Bytes();
+        let ms_io = BeginMultiSelect(g, ms_flags, dialog.Selection.Size, dialog.VisibleEntries.len() as c_int);
+        dialog.ApplySelectionRequests(&ms_io);
+        for visible_n in 0..dialog.VisibleEntries.len() as c_int {
+            let entry = dialog.VisibleEntry(visible_n).clone();
+            TableNextRow(ImGuiTableRowFlags_None, 0.0);
+            TableSetColumnIndex(0);
+            SetNextItemSelectionUserData(g, visible_n as ImGuiSelectionUserData);
+            let label = if entry.IsDirectory { format!("{}/", entry.Name) } else { entry.Name.clone() };
+            let selected = dialog.Selection.Contains(ImGuiFileDialog::EntrySelectionId(&entry));
+            if Selectable(label, selected, ImGuiSelectableFlags_SpanAllColumns | ImGuiSelectableFlags_AllowDoubleClick, None) && IsMouseDoubleClicked(ImGuiMouseButton_Left) {
+                let path = dialog.CurrentDir.join(&entry.Name);
+                if entry.IsDirectory {
+                    navigate_to = Some(path);
+                } else if !select_directory {
+                    dialog.Result = vec![path];
+                    confirmed = true;
+                }
+            }
+            TableSetColumnIndex(1);
+            if !entry.IsDirectory {
+                TextDisabled(FileDialogFormatSize(&size_unit, entry.Size));
+            }
+            TableSetColumnIndex(2);
+            if let Some(modified) = entry.Modified {
+                TextDisabled(FileDialogFormatTime(modified));
+            }
+        }
+        let ms_io = EndMultiSelect(g);
+        dialog.ApplySelectionRequests(&ms_io);
+        EndTable();
+    }
+
+    // Footer: filter, confirm and cancel buttons
+    let button_size = Vector2::from_floats(font_size * FILE_DIALOG_BUTTON_WIDTH, 0.0);
+    if !select_directory {
+        let filter_width = -(button_size.x * 2.0 + g.style.ItemSpacing.x * 3.0 + g.style.FramePadding.x * 4.0);
+        if dialog.Filter.Draw(str_to_const_c_char_ptr("Filter\0"), filter_width) {
+            dialog.UpdateVisibleEntries();
+        }
+        same_line(g, 0.0, -1.0);
+    } else {
+        Text(dialog.CurrentDir.display().to_string());
+        same_line(g, 0.0, -1.0);
+    }
+
+    let selected_paths = dialog.SelectedPaths();
+    BeginDisabled(!select_directory && selected_paths.is_empty());
+    if Button(&String::from(if select_directory { "Select" } else { "Open" }), Some(button_size)) {
+        if select_directory {
+            // Nothing selected picks the directory being browsed
+            dialog.Result = if selected_paths.is_empty() { vec![dialog.CurrentDir.clone()] } else { selected_paths };
+            confirmed = true;
+        } else if selected_paths.len() == 1 && dialog.FileSystem.IsDirectory(&selected_paths[0]) {
+            navigate_to = Some(selected_paths[0].clone());
+        } else {
+            dialog.Result = selected_paths.into_iter().filter(|path| !dialog.FileSystem.IsDirectory(path)).collect();
+            confirmed = !dialog.Result.is_empty();
+        }
+    }
+    EndDisabled();
+    same_line(g, 0.0, -1.0);
+    if Button(&String::from("Cancel"), Some(button_size)) || IsKeyPressed(ImGuiKey_Escape, false) {
+        dialog.Result.clear();
+        CloseCurrentPopup();
+    }
+
+    if let Some(dir) = navigate_to {
+        if !confirmed {
+            dialog.SetDirectory(&dir);
+        }
+    }
+    if confirmed {
+        CloseCurrentPopup();
+    }
+    EndPopup(g);
+    confirmed
+}
+
+// e.g. "512 B", "1.5 KiB", "20.0 MiB"
+fn FileDialogFormatSize(unit: &ImGuiUnit, size: u64) -> String {
+    UnitFormatInteger(unit, size as i128, if size < 1024 { "%.0f" } else { "%.1f" })
+}
+
+// Seconds since the Unix epoch to "YYYY-MM-DD HH:MM", in UTC
+fn FileDialogFormatTime(secs: u64) -> String {
+    let (year, month, day) = ImCivilFromDays((secs / 86400) as i64);
+    let secs_of_day = secs % 86400;
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year, month, day, secs_of_day / 3600, secs_of_day % 3600 / 60)
+}
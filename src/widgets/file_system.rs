@@ -0,0 +1,114 @@
+#![allow(non_snake_case)]
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+// An entry of a directory listing
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ImGuiFileEntry {
+    pub Name: String,
+    pub IsDirectory: bool,
+    pub Size: u64,
+    // In bytes, 0 for directories
+    pub Modified: Option<u64>,
+    // Seconds since the Unix epoch, None when unknown
+}
+
+// Filesystem access of FileDialog(). Paths are absolute.
+// - ImGuiNativeFileSystem goes through std::fs, ImGuiMemoryFileSystem serves a tree built in memory (tests, remote sessions
+//   forwarding a listing from another machine...).
+pub trait ImGuiFileSystem {
+    fn ReadDir(&self, path: &Path) -> io::Result<Vec<ImGuiFileEntry>>;
+    fn IsDirectory(&self, path: &Path) -> bool;
+    fn CurrentDir(&self) -> PathBuf;                                     // Where the dialog starts
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImGuiNativeFileSystem;
+
+impl ImGuiFileSystem for ImGuiNativeFileSystem {
+    fn ReadDir(&self, path: &Path) -> io::Result<Vec<ImGuiFileEntry>> {
+        let mut entries = Vec::new();
+        for dir_entry in fs::read_dir(path)? {
+            // Entries which vanished or can't be queried are skipped rather than failing the whole listing
+            let (dir_entry, metadata) = match dir_entry.and_then(|dir_entry| dir_entry.metadata().map(|metadata| (dir_entry, metadata))) {
+                Ok(entry) => entry,
+                Err(_) => continue,
+            };
+            entries.push(ImGuiFileEntry {
+                Name: dir_entry.file_name().to_string_lossy().into_owned(),
+                IsDirectory: metadata.is_dir(),
+                Size: if metadata.is_dir() { 0 } else { metadata.len() },
+                Modified: metadata.modified().ok().and_then(|time| time.duration_since(UNIX_EPOCH).ok()).map(|duration| duration.as_secs()),
+            });
+        }
+        Ok(entries)
+    }
+
+    fn IsDirectory(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+
+    fn CurrentDir(&self) -> PathBuf {
+        std::env::current_dir().unwrap_or_else(|_| PathBuf::from("/"))
+    }
+}
+
+// Directory tree held in memory. Roots (paths without parent) always exist.
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiMemoryFileSystem {
+    pub Entries: BTreeMap<PathBuf, ImGuiFileEntry>,
+    // Keyed by absolute path
+    pub StartDir: PathBuf,
+}
+
+impl ImGuiMemoryFileSystem {
+    pub fn new(start_dir: &Path) -> Self {
+        let mut file_system = Self { Entries: BTreeMap::new(), StartDir: start_dir.to_path_buf() };
+        file_system.AddDirectory(start_dir);
+        file_system
+    }
+
+    // Add a directory and its missing parents
+    pub fn AddDirectory(&mut self, path: &Path) {
+        for dir in path.ancestors() {
+            let name = match dir.file_name() {
+                Some(name) => name.to_string_lossy().into_owned(),
+                None => break,
+            };
+            self.Entries.entry(dir.to_path_buf()).or_insert(ImGuiFileEntry { Name: name, IsDirectory: true, ..Default::default() });
+        }
+    }
+
+    // Add or replace a file, creating its missing parent directories
+    pub fn AddFile(&mut self, path: &Path, size: u64, modified: Option<u64>) {
+        let name = match path.file_name() {
+            Some(name) => name.to_string_lossy().into_owned(),
+            None => return,
+        };
+        if let Some(parent) = path.parent() {
+            self.AddDirectory(parent);
+        }
+        self.Entries.insert(path.to_path_buf(), ImGuiFileEntry { Name: name, IsDirectory: false, Size: size, Modified: modified });
+    }
+}
+
+impl ImGuiFileSystem for ImGuiMemoryFileSystem {
+    fn ReadDir(&self, path: &Path) -> io::Result<Vec<ImGuiFileEntry>> {
+        if !self.IsDirectory(path) {
+            return Err(io::Error::new(io::ErrorKind::NotFound, format!("{} is not a directory", path.display())));
+        }
+        Ok(self.Entries.iter().filter(|(entry_path, _)| entry_path.parent() == Some(path)).map(|(_, entry)| entry.clone()).collect())
+    }
+
+    fn IsDirectory(&self, path: &Path) -> bool {
+        path.parent().is_none() || self.Entries.get(path).map_or(false, |entry| entry.IsDirectory)
+    }
+
+    fn CurrentDir(&self) -> PathBuf {
+        self.StartDir.clone()
+    }
+}
@@ -0,0 +1,71 @@
+#![allow(non_snake_case)]
+
+use libc::c_float;
+
+// A color stop of a gradient
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImGuiGradientStop {
+    pub Pos: c_float,
+    // 0.0 to 1.0
+    pub Color: [c_float; 4],
+    // RGBA
+}
+
+// Color gradient edited by GradientEditor(). Stops are sorted by Pos.
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiGradient {
+    pub Stops: Vec<ImGuiGradientStop>,
+}
+
+impl ImGuiGradient {
+    // Black to white
+    pub fn new() -> Self {
+        Self {
+            Stops: vec![
+                ImGuiGradientStop { Pos: 0.0, Color: [0.0, 0.0, 0.0, 1.0] },
+                ImGuiGradientStop { Pos: 1.0, Color: [1.0, 1.0, 1.0, 1.0] },
+            ],
+        }
+    }
+
+    pub fn SortStops(&mut self) {
+        self.Stops.sort_by(|a, b| a.Pos.total_cmp(&b.Pos));
+    }
+
+    // Insert a stop keeping the stops sorted, returns its index
+    pub fn AddStop(&mut self, pos: c_float, color: [c_float; 4]) -> usize {
+        let stop_n = self.Stops.partition_point(|stop| stop.Pos <= pos);
+        self.Stops.insert(stop_n, ImGuiGradientStop { Pos: pos, Color: color });
+        stop_n
+    }
+
+    // Color at 'pos', interpolated in RGBA between the surrounding stops. Transparent black without stops.
+    pub fn Evaluate(&self, pos: c_float) -> [c_float; 4] {
+        let (first, last) = match (self.Stops.first(), self.Stops.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return [0.0; 4],
+        };
+        if pos <= first.Pos {
+            return first.Color;
+        }
+        if pos >= last.Pos {
+            return last.Color;
+        }
+        let stop_n = self.Stops.partition_point(|stop| stop.Pos <= pos);
+        let (s0, s1) = (&self.Stops[stop_n - 1], &self.Stops[stop_n]);
+        let t = if s1.Pos > s0.Pos { (pos - s0.Pos) / (s1.Pos - s0.Pos) } else { 1.0 };
+        let mut color = [0.0; 4];
+        for n in 0..4 {
+            color[n] = s0.Color[n] + (s1.Color[n] - s0.Color[n]) * t;
+        }
+        color
+    }
+}
+
+// Persistent state of a GradientEditor(), per ID
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiGradientEditorState {
+    pub Selected: Option<usize>,
+    pub DragMoved: bool,
+    // The stop being dragged moved: releasing it doesn't open the color picker
+}
@@ -0,0 +1,213 @@
+#![allow(non_snake_case)]
+
+//-------------------------------------------------------------------------
+// [SECTION] Widgets: GradientEditor
+//-------------------------------------------------------------------------
+// - GradientEditor()
+//-------------------------------------------------------------------------
+// A bar previewing the gradient, with a marker per color stop below it.
+// - Click on a marker selects it, dragging moves the stop. Releasing a marker without moving it opens a color picker for the stop.
+// - Click on the bar adds a stop with the color the gradient has there, dragging on moves it.
+// - Right-click on a marker removes the stop, so does Delete while the editor has the keyboard focus. The last stop can't be removed.
+// Like CurveEditor(), the stops live in a Vec which the undo context can't snapshot: record your own undo step when
+// IsItemDeactivatedAfterEdit() returns true after GradientEditor(). The picker counts as part of the item while it is being used,
+// adding and removing a stop are single edits reported on the next frame.
+//-------------------------------------------------------------------------
+
+use libc::c_float;
+use crate::color::{ImGuiCol_Border, ImGuiCol_FrameBg, ImGuiCol_NavHighlight, ImGuiCol_Text};
+use crate::color::color_edit_flags::ImGuiColorEditFlags_AlphaBar;
+use crate::color::color_ops::ColorConvertFloat4ToU32;
+use crate::core::context::AppContext;
+use crate::core::hash_ops::hash_string;
+use crate::core::id_ops::{ClearActiveID, pop_win_id_from_stack, PushOverrideID, SetActiveID};
+use crate::core::vec2::Vector2;
+use crate::core::vec4::ImVec4;
+use crate::drawing::draw_flags::ImDrawFlags_None;
+use crate::drawing::render_ops::{RenderFrame, RenderText};
+use crate::io::input_ops::IsKeyPressed;
+use crate::io::key::ImGuiKey_Delete;
+use crate::item::item_ops::{CalcItemWidth, ItemAdd, ItemHoverable, ItemSize, MarkItemEdited};
+use crate::rect::ImRect;
+use crate::style::style_ops::GetColorU32;
+use crate::text::text_ops::CalcTextSize;
+use crate::widgets::a_widgets::ColorPicker4;
+use crate::widgets::gradient::ImGuiGradient;
+use crate::widgets::group_ops::{BeginGroup, EndGroup};
+use crate::widgets::nav_ops::SetFocusID;
+use crate::widgets::popup_ops::{BeginPopup, EndPopup, OpenPopup};
+
+// Height of the marker strip below the bar, as a fraction of the frame height
+const GRADIENT_MARKER_HEIGHT: c_float = 0.6;
+
+// Half width of a marker, in pixels
+const GRADIENT_MARKER_HALF_WIDTH: c_float = 5.0;
+
+// Returns true when the stops were changed
+pub unsafe fn GradientEditor(g: &mut AppContext, label: &str, gradient: &mut ImGuiGradient) -> bool {
+    let (skip_items, seed, window_id) = {
+        let window = g.current_window_mut().unwrap();
+        (window.skip_items, window.id_stack.last().unwrap().clone(), window.ID)
+    };
+    if skip_items {
+        return false;
+    }
+    let id = hash_string(&String::from(label), seed);
+    let label_size = CalcTextSize(g, &String::from(label), true, 0.0);
+    let font_size = g.FontSize;
+    let style_frame_padding = g.style.FramePadding;
+    let style_inner_spacing = g.style.ItemInnerSpacing;
+    let frame_height = font_size + style_frame_padding.y * 2.0;
+    let marker_height = (frame_height * GRADIENT_MARKER_HEIGHT).floor();
+    let item_width = CalcItemWidth(g);
+    let cursor_pos = g.current_window_mut().unwrap().dc.cursor_pos;
+    let bar_bb = ImRect::from_floats(cursor_pos.x, cursor_pos.y, cursor_pos.x + item_width, cursor_pos.y + frame_height);
+    let markers_bb = ImRect::from_floats(bar_bb.min.x, bar_bb.max.y, bar_bb.max.x, bar_bb.max.y + marker_height);
+    let frame_bb = ImRect::from_floats(bar_bb.min.x, bar_bb.min.y, markers_bb.max.x, markers_bb.max.y);
+    let total_size = Vector2::from_floats(item_width + if label_size.x > 0.0 { style_inner_spacing.x + label_size.x } else { 0.0 }, frame_bb.max.y - frame_bb.min.y);
+    let mut total_bb = ImRect::from_floats(frame_bb.min.x, frame_bb.min.y, frame_bb.min.x + total_size.x, frame_bb.max.y);
+
+    // The group makes IsItemXXX() functions cover the color picker too. The ID scopes the name of the popup.
+    BeginGroup();
+    PushOverrideID(g, id);
+    ItemSize(g, &total_size, style_frame_padding.y);
+    if !ItemAdd(g, &mut total_bb, id, Some(&frame_bb), 0) {
+        pop_win_id_from_stack(g);
+        EndGroup();
+        return false;
+    }
+
+    if gradient.Stops.windows(2).any(|pair| pair[1].Pos < pair[0].Pos) {
+        gradient.SortStops();
+    }
+    let mut state = g.GradientEditors.remove(&id).unwrap_or_default();
+    if state.Selected.map_or(false, |stop_n| stop_n >= gradient.Stops.len()) {
+        state.Selected = None;
+    }
+    let width = bar_bb.max.x - bar_bb.min.x;
+    let to_screen_x = |pos: c_float| bar_bb.min.x + pos * width;
+    let from_screen_x = |x: c_float| ((x - bar_bb.min.x) / width).clamp(0.0, 1.0);
+
+    // Hovered marker, the last one drawn wins
+    let hovered = ItemHoverable(&frame_bb, id);
+    let mouse = g.IO.MousePos;
+    let mut hovered_stop = None;
+    if hovered && mouse.y >= markers_bb.min.y {
+        hovered_stop = gradient.Stops.iter().rposition(|stop| (to_screen_x(stop.Pos) - mouse.x).abs() <= GRADIENT_MARKER_HALF_WIDTH);
+    }
+
+    let mut value_changed = false;
+    let mut edited_once = false;
+    if hovered && g.IO.MouseClicked[0] {
+        SetFocusID(id, g.current_window_mut().unwrap());
+        if hovered_stop.is_some() {
+            state.Selected = hovered_stop;
+            state.DragMoved = false;
+            SetActiveID(g, id, None);
+            g.ActiveIdWindow = window_id;
+        } else if mouse.y < bar_bb.max.y {
+            let pos = from_screen_x(mouse.x);
+            let color = gradient.Evaluate(pos);
+            state.Selected = Some(gradient.AddStop(pos, color));
+            // Dragging on moves the new stop, releasing doesn't open its picker
+            state.DragMoved = true;
+            value_changed = true;
+            edited_once = true;
+        }
+    }
+    if hovered && g.IO.MouseClicked[1] && gradient.Stops.len() > 1 {
+        if let Some(stop_n) = hovered_stop {
+            gradient.Stops.remove(stop_n);
+            state.Selected = None;
+            value_changed = true;
+            edited_once = true;
+        }
+    }
+    if let Some(stop_n) = state.Selected {
+        if g.NavId == id && g.ActiveId != id && gradient.Stops.len() > 1 && IsKeyPressed(ImGuiKey_Delete, false) {
+            gradient.Stops.remove(stop_n);
+            state.Selected = None;
+            value_changed = true;
+            edited_once = true;
+        }
+    }
+
+    if g.ActiveId == id {
+        if !g.IO.MouseDown[0] {
+            ClearActiveID(g);
+            if !state.DragMoved && state.Selected.is_some() {
+                OpenPopup("##stop_picker", 0);
+            }
+        } else if let Some(stop_n) = state.Selected {
+            // Keep the stops sorted while dragging, following the dragged one
+            let pos = from_screen_x(mouse.x);
+            if pos != gradient.Stops[stop_n].Pos {
+                state.DragMoved = true;
+                value_changed = true;
+                let stop = gradient.Stops.remove(stop_n);
+                state.Selected = Some(gradient.AddStop(pos, stop.Color));
+            }
+        }
+    }
+
+    let mut picker_window_id = None;
+    if BeginPopup("##stop_picker", 0) {
+        picker_window_id = Some(g.CurrentWindow);
+        if let Some(stop_n) = state.Selected {
+            value_changed |= ColorPicker4(String::from("##picker"), &mut gradient.Stops[stop_n].Color, ImGuiColorEditFlags_AlphaBar, 0.0);
+        }
+        EndPopup(g);
+    }
+
+    // Render
+    RenderFrame(bar_bb.min, bar_bb.max, GetColorU32(ImGuiCol_FrameBg, 1.0), true, g.style.FrameRounding);
+    let col_border = GetColorU32(ImGuiCol_Border, 1.0);
+    let col_marker = GetColorU32(ImGuiCol_Text, 1.0);
+    let col_selected = GetColorU32(ImGuiCol_NavHighlight, 1.0);
+    let to_u32 = |color: [c_float; 4]| ColorConvertFloat4ToU32(&ImVec4::from_floats(color[0], color[1], color[2], color[3]));
+    let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+    if let (Some(first), Some(last)) = (gradient.Stops.first(), gradient.Stops.last()) {
+        // Flat before the first stop and after the last one, then a horizontal gradient per pair of stops
+        let mut segments = vec![(0.0, first.Color, first.Pos, first.Color)];
+        segments.extend(gradient.Stops.windows(2).map(|pair| (pair[0].Pos, pair[0].Color, pair[1].Pos, pair[1].Color)));
+        segments.push((last.Pos, last.Color, 1.0, last.Color));
+        for (pos0, color0, pos1, color1) in segments {
+            if pos1 <= pos0 {
+                continue;
+            }
+            let (col0, col1) = (to_u32(color0), to_u32(color1));
+            draw_list.AddRectFilledMultiColor(&Vector2::from_floats(to_screen_x(pos0), bar_bb.min.y), &Vector2::from_floats(to_screen_x(pos1), bar_bb.max.y), col0, col1, col1, col0);
+        }
+    }
+    draw_list.AddRect(bar_bb.min, bar_bb.max, col_border, 0.0);
+    for (stop_n, stop) in gradient.Stops.iter().enumerate() {
+        let x = to_screen_x(stop.Pos);
+        let tip = Vector2::from_floats(x, markers_bb.min.y);
+        let body_min = Vector2::from_floats(x - GRADIENT_MARKER_HALF_WIDTH, markers_bb.min.y + marker_height * 0.4);
+        let body_max = Vector2::from_floats(x + GRADIENT_MARKER_HALF_WIDTH, markers_bb.max.y);
+        let col_outline = if state.Selected == Some(stop_n) { col_selected } else if hovered_stop == Some(stop_n) { col_marker } else { col_border };
+        draw_list.AddTriangleFilled(&tip, &Vector2::from_floats(body_max.x, body_min.y), &body_min, col_outline);
+        draw_list.AddRectFilled(&body_min, &body_max, col_outline, 0.0, ImDrawFlags_None);
+        draw_list.AddRectFilled(&Vector2::from_floats(body_min.x + 1.0, body_min.y + 1.0), &Vector2::from_floats(body_max.x - 1.0, body_max.y - 1.0), to_u32(stop.Color), 0.0, ImDrawFlags_None);
+    }
+    if label_size.x > 0.0 {
+        RenderText(Vector2::from_floats(frame_bb.max.x + style_inner_spacing.x, bar_bb.min.y + style_frame_padding.y), &String::from(label), true, g);
+    }
+
+    g.GradientEditors.insert(id, state);
+    pop_win_id_from_stack(g);
+    EndGroup();
+    // Edits without a drag hold the active id until the next frame, so that IsItemDeactivatedAfterEdit() reports them
+    if edited_once && g.ActiveId != id {
+        SetActiveID(g, id, None);
+        g.ActiveIdWindow = window_id;
+    }
+    // When the picker is being used, use its active id so that IsItemActive()/IsItemDeactivatedAfterEdit() work on GradientEditor()
+    if picker_window_id.is_some() && g.ActiveId != 0 && Some(g.ActiveIdWindow) == picker_window_id {
+        g.last_item_data.ID = g.ActiveId;
+    }
+    if value_changed {
+        MarkItemEdited(g, g.last_item_data.ID);
+    }
+    value_changed
+}
@@ -2,11 +2,11 @@
 
 use libc::c_int;
 
-// typedef int ImGuiInputFlags;            // -> enum ImGuiInputFlags_         // Flags: for IsKeyPressedEx()
+// typedef int ImGuiInputFlags;            // -> enum ImGuiInputFlags_         // Flags: for IsKeyPressedEx(), Shortcut(), SetKeyOwner()
 pub type ImGuiInputFlags = c_int;
 
 
-// Flags for IsKeyPressedEx(). In upcoming feature this will be used more (and IsKeyPressedEx() renamed)
+// Flags for IsKeyPressedEx(), Shortcut(), SetKeyOwner() and SetItemKeyOwner()
 // Don't mistake with ImGuiInputTextFlags! (for InputText() function)
 // enum ImGuiInputFlags_
 // {
@@ -17,4 +17,36 @@ pub type ImGuiInputFlags = c_int;
     pub const ImGuiInputFlags_RepeatRateNavMove: ImGuiInputFlags = 1 << 2;   // Repeat rate: Fast
     pub const ImGuiInputFlags_RepeatRateNavTweak: ImGuiInputFlags = 1 << 3;   // Repeat rate: Faster
     pub const ImGuiInputFlags_RepeatRateMask_: ImGuiInputFlags = ImGuiInputFlags_RepeatRateDefault | ImGuiInputFlags_RepeatRateNavMove | ImGuiInputFlags_RepeatRateNavTweak;
+
+    // Flags for SetItemKeyOwner()
+    pub const ImGuiInputFlags_CondHovered: ImGuiInputFlags = 1 << 4;   // Only set if item is hovered (default to both)
+    pub const ImGuiInputFlags_CondActive: ImGuiInputFlags = 1 << 5;   // Only set if item is active (default to both)
+    pub const ImGuiInputFlags_CondDefault_: ImGuiInputFlags = ImGuiInputFlags_CondHovered | ImGuiInputFlags_CondActive;
+    pub const ImGuiInputFlags_CondMask_: ImGuiInputFlags = ImGuiInputFlags_CondHovered | ImGuiInputFlags_CondActive;
+
+    // Flags for SetKeyOwner(), SetItemKeyOwner()
+    pub const ImGuiInputFlags_LockThisFrame: ImGuiInputFlags = 1 << 6;   // Access to key data will require EXPLICIT owner ID (ImGuiKeyOwner_Any/0 will NOT accept the key). Cleared at end of frame.
+    pub const ImGuiInputFlags_LockUntilRelease: ImGuiInputFlags = 1 << 7;   // Access to key data will require EXPLICIT owner ID (ImGuiKeyOwner_Any/0 will NOT accept the key). Cleared when the key is released or at end of each frame if key is released.
+
+    // Routing policies for Shortcut() + low-level SetShortcutRouting()
+    // - When a policy (other than _RouteAlways) is set, Shortcut() will register itself with SetShortcutRouting(),
+    //   allowing the system to decide where to route the input among other route-aware calls.
+    // - Shortcut() uses ImGuiInputFlags_RouteFocused by default: meaning that a simple Shortcut() poll
+    //   will register a route and only succeed when parent window is in the focus stack and if no-one
+    //   with a higher priority is claiming the shortcut.
+    // - Routing is resolved during NewFrame(): if keyboard modifiers are matching current ones: SetKeyOwner() is called + route is granted for the frame.
+    pub const ImGuiInputFlags_RouteFocused: ImGuiInputFlags = 1 << 8;   // (Default) Register focused route: Accept inputs if window is in focus stack. Deep-most focused window takes inputs. ActiveId takes inputs over deep-most focused window.
+    pub const ImGuiInputFlags_RouteGlobalLow: ImGuiInputFlags = 1 << 9;   // Register route globally (lowest priority: unless a focused window or active item registered the route) -> recommended Global priority.
+    pub const ImGuiInputFlags_RouteGlobal: ImGuiInputFlags = 1 << 10;  // Register route globally (medium priority: unless an active item registered the route, e.g. CTRL+A registered by InputText).
+    pub const ImGuiInputFlags_RouteGlobalHigh: ImGuiInputFlags = 1 << 11;  // Register route globally (highest priority: unlikely you need to use that: will interfere with every active items)
+    pub const ImGuiInputFlags_RouteMask_: ImGuiInputFlags = ImGuiInputFlags_RouteFocused | ImGuiInputFlags_RouteGlobal | ImGuiInputFlags_RouteGlobalLow | ImGuiInputFlags_RouteGlobalHigh; // _Always not part of this!
+    pub const ImGuiInputFlags_RouteAlways: ImGuiInputFlags = 1 << 12;  // Do not register route, poll keys directly.
+    pub const ImGuiInputFlags_RouteUnlessBgFocused: ImGuiInputFlags = 1 << 13;  // Global routes will not be applied if underlying background/void is focused (== no Dear ImGui windows are focused). Useful for overlay applications.
+    pub const ImGuiInputFlags_RouteExtraMask_: ImGuiInputFlags = ImGuiInputFlags_RouteAlways | ImGuiInputFlags_RouteUnlessBgFocused;
+
+    // [Internal] Mask of which function support which flags
+    pub const ImGuiInputFlags_SupportedByIsKeyPressed: ImGuiInputFlags = ImGuiInputFlags_Repeat | ImGuiInputFlags_RepeatRateMask_;
+    pub const ImGuiInputFlags_SupportedByShortcut: ImGuiInputFlags = ImGuiInputFlags_Repeat | ImGuiInputFlags_RepeatRateMask_ | ImGuiInputFlags_RouteMask_ | ImGuiInputFlags_RouteExtraMask_;
+    pub const ImGuiInputFlags_SupportedBySetKeyOwner: ImGuiInputFlags = ImGuiInputFlags_LockThisFrame | ImGuiInputFlags_LockUntilRelease;
+    pub const ImGuiInputFlags_SupportedBySetItemKeyOwner: ImGuiInputFlags = ImGuiInputFlags_SupportedBySetKeyOwner | ImGuiInputFlags_CondMask_;
 // };
@@ -3,14 +3,18 @@ use crate::widgets::button_flags::{
 };
 use crate::button_ops::ButtonEx;
 use crate::data_type::{
-    ImGuiDataType, IM_GUI_DATA_TYPE_DOUBLE, IM_GUI_DATA_TYPE_FLOAT, IM_GUI_DATA_TYPE_S32,
+    ImGuiDataType, IM_GUI_DATA_TYPE_DOUBLE, IM_GUI_DATA_TYPE_F16, IM_GUI_DATA_TYPE_FIXED16_16,
+    IM_GUI_DATA_TYPE_FLOAT, IM_GUI_DATA_TYPE_S32,
 };
+use crate::data_type::data_type_scalar::ImGuiScalar;
 use crate::data_type_info::GDATA_TYPE_INFO;
 use crate::data_type_ops::{
-    DataTypeApplyFromText, DataTypeApplyOp, DataTypeFormatString, DATA_TYPE_OPERATION_ADD,
-    DATA_TYPE_OPERATION_SUB,
+    DataTypeApplyFromText, DataTypeApplyOp, DataTypeFormatString,
+    DATA_TYPE_OPERATION_ADD, DATA_TYPE_OPERATION_SUB,
 };
 use crate::data_type_temp_storage::ImGuiDataTypeTempStorage;
+use crate::widgets::unit::ImGuiUnit;
+use crate::widgets::unit_ops::{GetNextItemUnit, SetNextItemUnit};
 use crate::frame_ops::GetFrameHeight;
 use crate::widgets::group_ops::{BeginGroup, EndGroup};
 use crate::core::id_ops::pop_win_id_from_stack;
@@ -34,12 +38,13 @@ use crate::window::ops::{BeginDisabled, EndDisabled, GetCurrentWindow};
 use crate::window::ImguiWindow;
 use crate::{data_type_ops, input_text, text_ops, widgets, GImGui};
 use libc::{c_char, c_double, c_float, c_int, size_t, strlen};
+use crate::widgets::undo_ops::UndoTrackItemValue;
 
 pub unsafe fn InputScalar_DefaultCharsFilter(
     data_type: ImGuiDataType,
     format: &str,
 ) -> ImGuiInputTextFlags {
-    if data_type == IM_GUI_DATA_TYPE_FLOAT || data_type == IM_GUI_DATA_TYPE_DOUBLE {
+    if data_type == IM_GUI_DATA_TYPE_FLOAT || data_type == IM_GUI_DATA_TYPE_DOUBLE || data_type == IM_GUI_DATA_TYPE_F16 || data_type == IM_GUI_DATA_TYPE_FIXED16_16 {
         return ImGuiInputTextFlags_CharsScientific;
     }
     const format_last_char: c_char = if format[0] {
@@ -57,15 +62,16 @@ pub unsafe fn InputScalar_DefaultCharsFilter(
 // Note that Drag/Slider functions are only forwarding the min/max values clamping values if the ImGuiSliderFlags_AlwaysClamp flag is set!
 // This is intended: this way we allow CTRL+Click manual input to set a value out of bounds, for maximum flexibility.
 // However this may not be ideal for all uses, as some user code may break on out of bound values.
-pub unsafe fn TempInputScalar(
+pub unsafe fn TempInputScalar<T: ImGuiScalar>(
     bb: &mut ImRect,
     id: ImguiHandle,
     label: String,
     data_type: ImGuiDataType,
-    p_data: &mut c_float,
+    p_data: &mut T,
     format: &mut String,
-    mut p_clamp_min: c_float,
-    mut p_clamp_max: c_float,
+    p_clamp_min: Option<T>,
+    p_clamp_max: Option<T>,
+    unit: Option<&ImGuiUnit>,
 ) -> bool {
     let mut fmt_buf = String::with_capacity(32);
     // data_buf: [c_char;32];
@@ -75,14 +81,17 @@ pub unsafe fn TempInputScalar(
         &mut data_buf,
         data_buf.len(),
         data_type,
-        p_data.to_owned(),
+        p_data,
         format,
+        unit,
     );
     ImStrTrimBlanks(&mut data_buf);
 
     let mut flags: ImGuiInputTextFlags =
         ImGuiInputTextFlags_AutoSelectAll | ImGuiInputTextFlags_NoMarkEdited;
-    flags |= InputScalar_DefaultCharsFilter(data_type, format);
+    if unit.is_none() {
+        flags |= InputScalar_DefaultCharsFilter(data_type, format); // Unit suffixes are letters
+    }
 
     let mut value_changed: bool = false;
     if input_text::TempInputText(bb, id, label, &mut data_buf, data_buf.len(), flags) {
@@ -92,19 +101,16 @@ pub unsafe fn TempInputScalar(
         // memcpy(&data_backup, p_data, data_type_size);
 
         // Apply new value (or operations) then clamp
-        data_type_ops::DataTypeApplyFromText(&data_buf, data_type, p_data, format);
-        if p_clamp_min || p_clamp_max {
-            if p_clamp_min
-                && p_clamp_max
-                && data_type_ops::DataTypeCompare(data_type, &p_clamp_min, &p_clamp_max) > 0
-            {
+        data_type_ops::DataTypeApplyFromText(&data_buf, data_type, p_data, format, unit);
+        // The clamp bounds are of the same type as the value: extended types are clamped exactly too.
+        if let (Some(mut clamp_min), Some(mut clamp_max)) = (p_clamp_min, p_clamp_max) {
+            if data_type_ops::DataTypeCompare(data_type, &clamp_min, &clamp_max) > 0 {
                 // ImSwap(p_clamp_min, p_clamp_max);
-                // let mut temp = p_clamp_min.clone();
-                let mut temp = p_clamp_min;
-                p_clamp_min = p_clamp_max;
-                p_clamp_max = p_clamp_min;
+                let temp = clamp_min;
+                clamp_min = clamp_max;
+                clamp_max = temp;
             }
-            data_type_ops::DataTypeClamp(data_type, p_data, &p_clamp_min, &p_clamp_max);
+            data_type_ops::DataTypeClamp(data_type, p_data, &clamp_min, &clamp_max);
         }
 
         // Only mark as edited if new value is different
@@ -118,12 +124,12 @@ pub unsafe fn TempInputScalar(
 
 // Note: p_data, p_step, p_step_fast are _pointers_ to a memory address holding the data. For an Input widget, p_step and p_step_fast are optional.
 // Read code of e.g. InputFloat(), InputInt() etc. or examples in 'Demo->Widgets->Data Types' to understand how to use this function directly.
-pub unsafe fn InputScalar(
+pub unsafe fn InputScalar<T: ImGuiScalar>(
     label: String,
     data_type: ImGuiDataType,
-    p_data: &mut c_float,
-    p_step: Option<c_float>,
-    p_step_fast: Option<c_float>,
+    p_data: &mut T,
+    p_step: Option<T>,
+    p_step_fast: Option<T>,
     format: &mut String,
     mut flags: ImGuiInputTextFlags,
 ) -> bool {
@@ -134,6 +140,8 @@ pub unsafe fn InputScalar(
 
     let g = GImGui; // ImGuiContext& g = *GImGui;
     let style = &mut g.style;
+    let unit: Option<ImGuiUnit> = GetNextItemUnit(g);
+    let value_before: T = *p_data;
 
     if format.is_empty() {
         *format = data_type_ops::data_type_info(data_type).PrintFmt;
@@ -141,10 +149,11 @@ pub unsafe fn InputScalar(
 
     // buf: [c_char;64];
     let mut buf = String::default();
-    DataTypeFormatString(&mut buf, buf.len(), data_type, *p_data, format);
+    DataTypeFormatString(&mut buf, buf.len(), data_type, p_data, format, unit.as_ref());
 
     // Testing ActiveId as a minor optimization as filtering is not needed until active
     if g.ActiveId == 0
+        && unit.is_none()
         && (flags
             & (ImGuiInputTextFlags_CharsDecimal
                 | ImGuiInputTextFlags_CharsHexadecimal
@@ -167,7 +176,7 @@ pub unsafe fn InputScalar(
         ));
         if InputText("", &mut buf, buf.len(), flags, None, None) {
             // PushId(label) + "" gives us the expected ID from outside point of view
-            value_changed = DataTypeApplyFromText(buf.as_str(), data_type, p_data, format);
+            value_changed = DataTypeApplyFromText(buf.as_str(), data_type, p_data, format, unit.as_ref());
         }
         IMGUI_TEST_ENGINE_ITEM_INFO(g.last_item_data.ID, label, g.last_item_data.StatusFlags);
 
@@ -180,32 +189,26 @@ pub unsafe fn InputScalar(
         }
         same_line(g, 0.0, style.ItemInnerSpacing.x);
         if ButtonEx("-", Vector2::new(button_size, button_size), button_flags) {
-            DataTypeApplyOp(
-                data_type,
-                DATA_TYPE_OPERATION_SUB,
-                p_data,
-                *p_data,
-                if g.IO.KeyCtrl && p_step_fast.is_some() {
-                    p_step_fast.unwrap()
-                } else {
-                    p_step.unwrap()
-                },
-            );
+            // The step is of the same type as the value (saturating for integers and fixed point)
+            let step: T = if g.IO.KeyCtrl && p_step_fast.is_some() {
+                p_step_fast.unwrap()
+            } else {
+                p_step.unwrap()
+            };
+            let v: T = *p_data;
+            DataTypeApplyOp(data_type, DATA_TYPE_OPERATION_SUB, p_data, &v, &step);
             value_changed = true;
         }
         same_line(g, 0.0, style.ItemInnerSpacing.x);
         if ButtonEx("+", Vector2::new(button_size, button_size), button_flags) {
-            data_type_ops::DataTypeApplyOp(
-                data_type,
-                DATA_TYPE_OPERATION_ADD,
-                p_data,
-                *p_data,
-                if g.IO.KeyCtrl && p_step_fast.is_some() {
-                    p_step_fast.unwrap()
-                } else {
-                    p_step.unwrap()
-                },
-            );
+            // The step is of the same type as the value (saturating for integers and fixed point)
+            let step: T = if g.IO.KeyCtrl && p_step_fast.is_some() {
+                p_step_fast.unwrap()
+            } else {
+                p_step.unwrap()
+            };
+            let v: T = *p_data;
+            data_type_ops::DataTypeApplyOp(data_type, DATA_TYPE_OPERATION_ADD, p_data, &v, &step);
             value_changed = true;
         }
         if flags & ImGuiInputTextFlags_ReadOnly {
@@ -223,23 +226,25 @@ pub unsafe fn InputScalar(
         EndGroup();
     } else {
         if InputText(label, &mut buf, buf.len(), flags, None, None) {
-            value_changed = DataTypeApplyFromText(buf.as_str(), data_type, p_data, format);
+            value_changed = DataTypeApplyFromText(buf.as_str(), data_type, p_data, format, unit.as_ref());
         }
     }
     if value_changed {
         MarkItemEdited(g, g.last_item_data.ID);
     }
+    // Holding a step button is a single edit: the group reports the button's active id
+    UndoTrackItemValue(g, &label, p_data, value_before, value_changed);
 
     return value_changed;
 }
 
-pub unsafe fn InputScalarN(
+pub unsafe fn InputScalarN<T: ImGuiScalar>(
     label: String,
     data_type: ImGuiDataType,
-    p_data: &mut [c_float],
+    p_data: &mut [T],
     components: usize,
-    p_step: Option<&[c_float]>,
-    p_step_fast: Option<&[c_float]>,
+    p_step: Option<&[T]>,
+    p_step_fast: Option<&[T]>,
     format: &mut String,
     flags: ImGuiInputTextFlags,
 ) -> bool {
@@ -250,6 +255,7 @@ pub unsafe fn InputScalarN(
 
     let g = GImGui; // ImGuiContext& g = *GImGui;
     let mut value_changed: bool = false;
+    let unit: Option<ImGuiUnit> = GetNextItemUnit(g);
     BeginGroup();
     PushID(label);
     PushMultiItemsWidths(components, CalcItemWidth(g));
@@ -260,6 +266,9 @@ pub unsafe fn InputScalarN(
         if i > 0 {
             same_line(g, 0.0, g.style.ItemInnerSpacing.x);
         }
+        if let Some(unit) = &unit {
+            SetNextItemUnit(g, unit);
+        }
         value_changed |= InputScalar(
             "",
             data_type,
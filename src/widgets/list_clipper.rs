@@ -153,6 +153,13 @@ impl ImGuiListClipper {
         }
     }
 
+    // Call IncludeItemByIndex() before first call to Step() if you need an item to be submitted regardless of visibility,
+    // e.g. the RangeSrcItem of a multi-selection (see BeginMultiSelect()), so Shift+click and Shift+arrows extend from it.
+    // inline void IncludeItemByIndex(int item_index) { IncludeItemsByIndex(item_index, item_index + 1); }
+    pub fn IncludeItemByIndex(&mut self, item_index: c_int) {
+        self.ForceDisplayRangeByIndices(item_index, item_index + 1);
+    }
+
     // #ifndef IMGUI_DISABLE_OBSOLETE_FUNCTIONS
     // inline ImGuiListClipper(items_count: c_int, c_float items_height = -1.0) { memset(this, 0, sizeof(*this)); ItemsCount = -1; Begin(items_count, items_height); } // [removed in 1.79]
     // #endif
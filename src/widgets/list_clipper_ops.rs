@@ -226,6 +226,41 @@ pub unsafe fn ImGuiListClipper_StepInternal(clipper: *mut ImGuiListClipper) -> b
                 ));
             }
 
+            // Add box-select range
+            // Rows are toggled when entering/leaving the selection rectangle: submit the bands where the previous and current
+            // rectangles differ, which may be out of view when scrolling fast. Half ItemSpacing on each side as Selectable()
+            // hit-boxes stray over it.
+            // Box-select on 2D area requires submitting everything under the rectangle (see BeginBoxSelect()).
+            let bs = &g.BoxSelectState;
+            if bs.IsActive && bs.Window == window.ID {
+                let spacing_y = g.style.ItemSpacing.y;
+                let (prev, curr) = (&bs.BoxSelectRectPrev, &bs.BoxSelectRectCurr);
+                if prev.min.y != curr.min.y {
+                    data.Ranges.push(ImGuiListClipperRange::FromPositions(
+                        prev.min.y.min(curr.min.y) - spacing_y,
+                        prev.min.y.max(curr.min.y) + spacing_y,
+                        0,
+                        0,
+                    ));
+                }
+                if prev.max.y != curr.max.y {
+                    data.Ranges.push(ImGuiListClipperRange::FromPositions(
+                        prev.max.y.min(curr.max.y) - spacing_y,
+                        prev.max.y.max(curr.max.y) + spacing_y,
+                        0,
+                        0,
+                    ));
+                }
+                if bs.UnclipMode {
+                    data.Ranges.push(ImGuiListClipperRange::FromPositions(
+                        bs.UnclipRect.min.y,
+                        bs.UnclipRect.max.y,
+                        0,
+                        0,
+                    ));
+                }
+            }
+
             // Add visible range
             let off_min: c_int = if is_nav_request && g.NavMoveClipDir == ImGuiDir_Up {
                 -1
@@ -1,4 +1,6 @@
 mod a_widgets;
+pub mod box_select;
+pub mod box_select_ops;
 pub mod bullet;
 pub mod button_flags;
 pub mod button_ops;
@@ -6,6 +8,15 @@ pub mod checkbox_ops;
 pub mod combo_box;
 pub mod combo_flags;
 pub mod combo_preview_data;
+pub mod curve;
+pub mod curve_ops;
+pub mod curve_tangent_mode;
+pub mod file_dialog;
+pub mod file_dialog_flags;
+pub mod file_dialog_ops;
+pub mod file_system;
+pub mod gradient;
+pub mod gradient_ops;
 pub mod group_data;
 pub mod group_ops;
 pub mod hovered_flags;
@@ -17,12 +28,24 @@ pub mod list_clipper_data;
 mod list_clipper_ops;
 mod list_clipper_range;
 pub mod merge_group;
+pub mod multi_select;
+pub mod multi_select_flags;
+pub mod multi_select_ops;
 pub mod nav_highlight_flags;
 pub mod nav_item_data;
 pub mod nav_layer;
 pub mod nav_move_flags;
 pub mod nav_ops;
+pub mod node_editor;
+pub mod node_editor_flags;
+pub mod node_editor_ops;
+pub mod node_minimap_location;
+pub mod node_pin_kind;
+pub mod plot;
 mod plot_array_getter_data;
+pub mod plot_axis_flags;
+pub mod plot_flags;
+pub mod plot_ops;
 mod plot_type;
 pub mod popup_data;
 pub mod popup_flags;
@@ -34,8 +57,13 @@ pub mod resize_grip_def;
 pub mod scroll_flags;
 pub mod scrolling_ops;
 pub mod selectable_flags;
+pub mod selection_basic_storage;
 pub mod separator;
 mod separator_flags;
+pub mod sequencer;
+pub mod sequencer_event_type;
+pub mod sequencer_flags;
+pub mod sequencer_ops;
 pub mod radio_button;
 mod splitter;
 pub mod shrink_width_item;
@@ -48,6 +76,13 @@ pub mod tab_bar_flags;
 mod tab_bar_section;
 pub mod tab_item;
 pub mod tab_item_flags;
+pub mod text_editor;
+pub mod text_editor_flags;
+pub mod text_editor_ops;
 pub mod tooltip_flags;
 pub mod tooltip_ops;
 pub mod tree_node_flags;
+pub mod undo_context;
+pub mod undo_ops;
+pub mod unit;
+pub mod unit_ops;
@@ -0,0 +1,155 @@
+#![allow(non_upper_case_globals)]
+
+use crate::core::type_defs::{ImguiHandle, INVALID_IMGUI_HANDLE};
+use crate::core::vec2::Vector2;
+use crate::io::mod_flags::ImGuiModFlags;
+use crate::widgets::multi_select_flags::ImGuiMultiSelectFlags;
+use libc::c_int;
+
+// typedef ImS64 ImGuiSelectionUserData;   // Arbitrary user data, stored with SetNextItemSelectionUserData() and given back in selection requests.
+// Most applications store an item index here: this is what ImGuiSelectionBasicStorage::ApplyRequests() expects.
+pub type ImGuiSelectionUserData = i64;
+
+pub const ImGuiSelectionUserData_Invalid: ImGuiSelectionUserData = -1;
+
+// Selection request type
+// enum ImGuiSelectionRequestType
+// {
+pub type ImGuiSelectionRequestType = c_int;
+pub const ImGuiSelectionRequestType_None: ImGuiSelectionRequestType = 0;
+pub const ImGuiSelectionRequestType_SetAll: ImGuiSelectionRequestType = 1; // Request app to clear selection (if Selected==false) or select all items (if Selected==true). We cannot set RangeFirstItem/RangeLastItem as its contents is entirely up to user (not necessarily an index)
+pub const ImGuiSelectionRequestType_SetRange: ImGuiSelectionRequestType = 2; // Request app to select/unselect [RangeFirstItem..RangeLastItem] items (inclusive) based on value of Selected. Only EndMultiSelect() request this, app code can read after BeginMultiSelect() and it will always be false.
+// };
+
+// Selection request item
+#[derive(Debug, Clone, Copy)]
+pub struct ImGuiSelectionRequest {
+    pub Type: ImGuiSelectionRequestType,
+    // ms:w, app:r     /  ms:w, app:r   // Request type. You'll most often receive 1 Clear + 1 SetRange with a single-item range.
+    pub Selected: bool,
+    // ms:w, app:r     /  ms:w, app:r   // Parameter for SetAll/SetRange requests (true = select, false = unselect)
+    pub RangeDirection: i8,
+    //                 /  ms:w  app:r   // Parameter for SetRange request: +1 when RangeFirstItem comes before RangeLastItem, -1 otherwise. Useful if you want to preserve selection order on a backward Shift+Click.
+    pub RangeFirstItem: ImGuiSelectionUserData,
+    //                 /  ms:w, app:r   // Parameter for SetRange request (this is generally == RangeSrcItem when shift selecting from top to bottom).
+    pub RangeLastItem: ImGuiSelectionUserData, //                 /  ms:w, app:r   // Parameter for SetRange request (this is generally == RangeSrcItem when shift selecting from bottom to top). Inclusive!
+}
+
+// Main IO structure returned by BeginMultiSelect()/EndMultiSelect().
+// This mainly contains a list of selection requests.
+// - Some fields are only useful if your list is dynamic and allows deletion
+// - Below: who reads/writes each fields? 'r'=read, 'w'=write, 'ms'=multi-select code, 'app'=application/user code.
+#[derive(Debug, Clone)]
+pub struct ImGuiMultiSelectIO {
+    //------------------------------------------// BeginMultiSelect / EndMultiSelect
+    pub Requests: Vec<ImGuiSelectionRequest>,
+    //  ms:w, app:r     /  ms:w  app:r   // Requests to apply to your selection data.
+    pub RangeSrcItem: ImGuiSelectionUserData,
+    //  ms:w  app:r     /                // (If using clipper) Begin: Source item (often the first selected item) must never be clipped: use clipper.IncludeItemByIndex() to ensure it is submitted.
+    pub RangeSrcReset: bool,
+    //                  /  app:w  ms:r   // (If using deletion) Set before EndMultiSelect() to reset RangeSrcItem (e.g. if deleted selection), see MultiSelectResetRangeSrc().
+    pub NavIdItem: ImGuiSelectionUserData,
+    //  ms:w, app:r     /                // (If using deletion) Last known SetNextItemSelectionUserData() value for NavId (if part of submitted items).
+    pub NavIdSelected: bool,
+    //  ms:w, app:r     /        app:r   // (If using deletion) Last known selection state for NavId (if part of submitted items).
+    pub ItemsCount: c_int, //  ms:w, app:r     /        app:r   // 'int items_count' parameter to BeginMultiSelect() is copied here for convenience, allowing simpler calls to your ApplyRequests handler. Not used internally.
+}
+
+impl Default for ImGuiMultiSelectIO {
+    fn default() -> Self {
+        Self {
+            Requests: vec![],
+            RangeSrcItem: ImGuiSelectionUserData_Invalid,
+            RangeSrcReset: false,
+            NavIdItem: ImGuiSelectionUserData_Invalid,
+            NavIdSelected: false,
+            ItemsCount: 0,
+        }
+    }
+}
+
+// Temporary storage for multi-select
+#[derive(Debug, Clone)]
+pub struct ImGuiMultiSelectTempData {
+    pub IO: ImGuiMultiSelectIO,
+    // MUST BE FIRST FIELD. Requests are set and returned by BeginMultiSelect()/EndMultiSelect() + written to by user during the loop.
+    pub StorageId: ImguiHandle,
+    pub FocusScopeId: ImguiHandle,
+    // Copied from g.CurrentFocusScopeId (unless another selection scope was pushed manually)
+    pub BoxSelectId: ImguiHandle,
+    // 0 unless box-selection is enabled with ImGuiMultiSelectFlags_BoxSelect1d/2d.
+    pub Flags: ImGuiMultiSelectFlags,
+    pub ScopeRectMin: Vector2,
+    pub BackupCursorMaxPos: Vector2,
+    pub LastSubmittedItem: ImGuiSelectionUserData,
+    // Copy of last submitted item data, used to merge output ranges.
+    pub KeyMods: ImGuiModFlags,
+    pub LoopRequestSetAll: i8,
+    // -1: no operation, 0: clear all, 1: select all.
+    pub IsEndIO: bool,
+    // Set when switching IO from BeginMultiSelect() to EndMultiSelect() state.
+    pub IsFocused: bool,
+    // Set if currently focusing the selection scope (any item of the selection). May be used if you have custom shortcut associated to selection.
+    pub IsKeyboardSetRange: bool,
+    // Set by BeginMultiSelect() when using Shift+Navigation. Because scrolling may be affected we can't afford a frame of lag with Shift+Navigation.
+    pub NavIdPassedBy: bool,
+    pub RangeSrcPassedBy: bool,
+    // Set by the item that matches RangeSrcItem.
+    pub RangeDstPassedBy: bool, // Set by the item that matches NavJustMovedToId when IsSetRange is set.
+}
+
+impl Default for ImGuiMultiSelectTempData {
+    fn default() -> Self {
+        Self {
+            IO: ImGuiMultiSelectIO::default(),
+            StorageId: 0,
+            FocusScopeId: 0,
+            BoxSelectId: 0,
+            Flags: 0,
+            ScopeRectMin: Vector2::default(),
+            BackupCursorMaxPos: Vector2::default(),
+            LastSubmittedItem: ImGuiSelectionUserData_Invalid,
+            KeyMods: 0,
+            LoopRequestSetAll: -1,
+            IsEndIO: false,
+            IsFocused: false,
+            IsKeyboardSetRange: false,
+            NavIdPassedBy: false,
+            RangeSrcPassedBy: false,
+            RangeDstPassedBy: false,
+        }
+    }
+}
+
+// Persistent storage for multi-select (as long as selection is alive)
+#[derive(Debug, Clone)]
+pub struct ImGuiMultiSelectState {
+    pub Window: ImguiHandle,
+    pub ID: ImguiHandle,
+    pub LastFrameActive: usize,
+    // Last used frame-count, for GC.
+    pub LastSelectionSize: c_int,
+    // Set by BeginMultiSelect() based on optional info provided by user. May be -1 if unknown.
+    pub RangeSelected: i8,
+    // -1 (don't have) or true/false
+    pub NavIdSelected: i8,
+    // -1 (don't have) or true/false
+    pub RangeSrcItem: ImGuiSelectionUserData,
+    //
+    pub NavIdItem: ImGuiSelectionUserData, // SetNextItemSelectionUserData() value for NavId (if part of submitted items)
+}
+
+impl Default for ImGuiMultiSelectState {
+    fn default() -> Self {
+        Self {
+            Window: INVALID_IMGUI_HANDLE,
+            ID: 0,
+            LastFrameActive: 0,
+            LastSelectionSize: 0,
+            RangeSelected: -1,
+            NavIdSelected: -1,
+            RangeSrcItem: ImGuiSelectionUserData_Invalid,
+            NavIdItem: ImGuiSelectionUserData_Invalid,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+// typedef int ImGuiMultiSelectFlags;      // -> enum ImGuiMultiSelectFlags_   // Flags: for BeginMultiSelect()
+pub type ImGuiMultiSelectFlags = c_int;
+
+// Flags for BeginMultiSelect()
+// enum ImGuiMultiSelectFlags_
+// {
+pub const ImGuiMultiSelectFlags_None: ImGuiMultiSelectFlags = 0;
+pub const ImGuiMultiSelectFlags_SingleSelect: ImGuiMultiSelectFlags = 1 << 0;   // Disable selecting more than one item. This is available to allow single-selection code to share same code/logic if desired. It essentially disables the main purpose of BeginMultiSelect() tho!
+pub const ImGuiMultiSelectFlags_NoSelectAll: ImGuiMultiSelectFlags = 1 << 1;   // Disable CTRL+A shortcut to select all.
+pub const ImGuiMultiSelectFlags_NoRangeSelect: ImGuiMultiSelectFlags = 1 << 2;   // Disable Shift+selection mouse/keyboard support (useful for unordered 2D selection). With BoxSelect is also ensure contiguous SetRange requests are not combined into one. This allows not handling interpolation in SetRange requests.
+pub const ImGuiMultiSelectFlags_NoAutoSelect: ImGuiMultiSelectFlags = 1 << 3;   // Disable selecting items when navigating (useful for e.g. supporting range-select in a list of checkboxes).
+pub const ImGuiMultiSelectFlags_NoAutoClear: ImGuiMultiSelectFlags = 1 << 4;   // Disable clearing selection when navigating or selecting another one (generally used with ImGuiMultiSelectFlags_NoAutoSelect. useful for e.g. supporting range-select in a list of checkboxes).
+pub const ImGuiMultiSelectFlags_NoAutoClearOnReselect: ImGuiMultiSelectFlags = 1 << 5;   // Disable clearing selection when clicking/selecting an already selected item.
+pub const ImGuiMultiSelectFlags_ClearOnEscape: ImGuiMultiSelectFlags = 1 << 6;   // Clear selection when pressing Escape while scope is focused.
+pub const ImGuiMultiSelectFlags_ClearOnClickVoid: ImGuiMultiSelectFlags = 1 << 7;   // Clear selection when clicking on empty location within scope.
+pub const ImGuiMultiSelectFlags_ScopeWindow: ImGuiMultiSelectFlags = 1 << 8;   // Scope for _ClearOnClickVoid. Use if BeginMultiSelect() covers a whole window or used a single time in same window. This is the default if no scope flag is set.
+pub const ImGuiMultiSelectFlags_ScopeRect: ImGuiMultiSelectFlags = 1 << 9;   // Scope for _ClearOnClickVoid. Use if BeginMultiSelect() is used multiple times in same window (e.g. a table, or several lists).
+pub const ImGuiMultiSelectFlags_SelectOnClick: ImGuiMultiSelectFlags = 1 << 10;  // Apply selection on mouse down when clicking on unselected item. (Default)
+pub const ImGuiMultiSelectFlags_SelectOnClickRelease: ImGuiMultiSelectFlags = 1 << 11;  // Apply selection on mouse release when clicking an unselected item. Allow dragging an unselected item without altering selection.
+pub const ImGuiMultiSelectFlags_BoxSelect1d: ImGuiMultiSelectFlags = 1 << 12;  // Enable box-selection with same width and same x pos items (e.g. full row Selectable()). Box-selection works better with little bit of spacing between items hit-box in order to be able to aim at empty space.
+pub const ImGuiMultiSelectFlags_BoxSelect2d: ImGuiMultiSelectFlags = 1 << 13;  // Enable box-selection with varying width or varying x pos items support (e.g. different width labels, or 2D layout/grid). This is slower: alters clipping logic so that e.g. horizontal movements will update selection of normally clipped items.
+pub const ImGuiMultiSelectFlags_BoxSelectNoScroll: ImGuiMultiSelectFlags = 1 << 14;  // Disable scrolling when box-selecting near edges of scope.
+// };
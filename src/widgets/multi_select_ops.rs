@@ -0,0 +1,508 @@
+#![allow(non_snake_case)]
+
+//-------------------------------------------------------------------------
+// [SECTION] Widgets: Multi-Select support
+//-------------------------------------------------------------------------
+// - BeginMultiSelect()
+// - EndMultiSelect()
+// - SetNextItemSelectionUserData()
+// - MultiSelectResetRangeSrc()
+// - MultiSelectItemHeader() [Internal]
+// - MultiSelectItemFooter() [Internal]
+// (box-select itself lives in box_select_ops.rs, enabled with ImGuiMultiSelectFlags_BoxSelect1d/2d)
+// - MultiSelectAddSetAll() [Internal]
+// - MultiSelectAddSetRange() [Internal]
+//-------------------------------------------------------------------------
+// The library never stores your selection: BeginMultiSelect()/EndMultiSelect() return requests
+// (SetAll, SetRange) that you apply to your own storage, e.g. with ImGuiSelectionBasicStorage.
+// Ranges are expressed with the user data of their first and last items, so items between them
+// don't need to be submitted: this is what makes it possible to use a clipper over large lists.
+// A scope is identified by the top of the ID stack: use PushID() around each scope when a window has several.
+// Usage:
+//   let ms_io = BeginMultiSelect(g, ImGuiMultiSelectFlags_ClearOnEscape, selection.Size, items.len());
+//   selection.ApplyRequests(&ms_io);
+//   let mut clipper = ImGuiListClipper::default();
+//   clipper.Begin(items.len(), -1.0);
+//   if ms_io.RangeSrcItem != ImGuiSelectionUserData_Invalid {
+//       clipper.IncludeItemByIndex(ms_io.RangeSrcItem as c_int); // Ensure RangeSrc item is not clipped
+//   }
+//   while clipper.Step() {
+//       for n in clipper.DisplayStart .. clipper.DisplayEnd {
+//           SetNextItemSelectionUserData(g, n as ImGuiSelectionUserData);
+//           Selectable(label, selection.Contains(n as ImguiHandle), ImGuiSelectableFlags_None, None);
+//       }
+//   }
+//   let ms_io = EndMultiSelect(g);
+//   selection.ApplyRequests(&ms_io);
+//-------------------------------------------------------------------------
+
+use libc::c_int;
+use crate::core::context::AppContext;
+use crate::core::type_defs::ImguiHandle;
+use crate::core::vec2::ImMaxVec2;
+use crate::core::utils::{flag_clear, flag_set};
+use crate::core::hash_ops::hash_string;
+use crate::core::id_ops::SetHoveredID;
+use crate::io::input_ops::{IsMouseClicked, IsMouseDragPastThreshold, IsMouseHoveringRect, IsMouseRelease};
+use crate::item::item_ops::IsItemHovered;
+use crate::io::input_source::{ImGuiInputSource, ImGuiInputSource_Gamepad, ImGuiInputSource_Keyboard, ImGuiInputSource_Mouse};
+use crate::io::key::{ImGuiKey_A, ImGuiKey_Escape, ImGuiMod_Ctrl};
+use crate::io::key_routing_ops::Shortcut;
+use crate::io::mod_flags::{ImGuiModFlags_Ctrl, ImGuiModFlags_None, ImGuiModFlags_Shift};
+use crate::io::mouse_button::{ImGuiMouseButton_Left, ImGuiMouseButton_Right};
+use crate::rect::ImRect;
+use crate::widgets::box_select_ops::{BeginBoxSelect, BoxSelectPreStartDrag, EndBoxSelect, GetBoxSelectState};
+use crate::widgets::button_flags::{ImGuiButtonFlags, ImGuiButtonFlags_NoHoveredOnFocus, ImGuiButtonFlags_PressedOnClick, ImGuiButtonFlags_PressedOnClickRelease};
+use crate::widgets::hovered_flags::{ImGuiHoveredFlags_AllowWhenBlockedByPopup, ImGuiHoveredFlags_None};
+use crate::widgets::input_flags::ImGuiInputFlags_None;
+use crate::widgets::multi_select::{ImGuiMultiSelectIO, ImGuiMultiSelectTempData, ImGuiSelectionRequest, ImGuiSelectionRequestType_SetAll, ImGuiSelectionRequestType_SetRange, ImGuiSelectionUserData, ImGuiSelectionUserData_Invalid};
+use crate::widgets::multi_select_flags::{ImGuiMultiSelectFlags, ImGuiMultiSelectFlags_BoxSelect1d, ImGuiMultiSelectFlags_BoxSelect2d, ImGuiMultiSelectFlags_ClearOnClickVoid, ImGuiMultiSelectFlags_ClearOnEscape, ImGuiMultiSelectFlags_NoAutoClear, ImGuiMultiSelectFlags_NoAutoClearOnReselect, ImGuiMultiSelectFlags_NoAutoSelect, ImGuiMultiSelectFlags_NoRangeSelect, ImGuiMultiSelectFlags_NoSelectAll, ImGuiMultiSelectFlags_ScopeRect, ImGuiMultiSelectFlags_ScopeWindow, ImGuiMultiSelectFlags_SelectOnClickRelease, ImGuiMultiSelectFlags_SingleSelect};
+use crate::window::focus::{PopFocusScope, PushFocusScope};
+use crate::window::props::IsWindowHovered;
+
+// Return the requests emitted before any item is submitted (Ctrl+A, Escape, clear when navigating).
+// Apply them before the loop: with a clipper, items that are clipped won't see them.
+// - 'selection_size' is the current number of selected items (-1 if unknown), 'items_count' is copied to the returned IO for convenience.
+// - Nesting BeginMultiSelect() is not supported.
+pub unsafe fn BeginMultiSelect(g: &mut AppContext, mut flags: ImGuiMultiSelectFlags, selection_size: c_int, items_count: c_int) -> ImGuiMultiSelectIO
+{
+    // IM_ASSERT(g.CurrentMultiSelect.is_none()); // No recursion allowed yet (we could allow it if we deem it useful)
+    let (window_id, id, cursor_pos, cursor_max_pos, inner_clip_rect) = {
+        let window = g.current_window_mut().unwrap();
+        (window.ID, window.id_stack.last().unwrap().clone(), window.dc.cursor_pos, window.dc.CursorMaxPos, window.InnerClipRect)
+    };
+
+    if (flags & (ImGuiMultiSelectFlags_ScopeWindow | ImGuiMultiSelectFlags_ScopeRect)) == 0 {
+        flags |= ImGuiMultiSelectFlags_ScopeWindow;
+    }
+    if flag_set(flags, ImGuiMultiSelectFlags_SingleSelect) {
+        flags &= !ImGuiMultiSelectFlags_ClearOnClickVoid;
+    }
+
+    let mut ms = ImGuiMultiSelectTempData::default();
+    ms.StorageId = id;
+    ms.FocusScopeId = id;
+    ms.Flags = flags;
+    ms.IsFocused = ms.FocusScopeId == g.NavFocusScopeId;
+    ms.BackupCursorMaxPos = cursor_max_pos;
+    ms.ScopeRectMin = cursor_pos;
+    g.current_window_mut().unwrap().dc.CursorMaxPos = cursor_pos;
+    PushFocusScope(ms.FocusScopeId);
+
+    // Use copy of keyboard mods at the time of the request, otherwise we would requires mods to be held for an extra frame.
+    ms.KeyMods = if g.NavJustMovedToId != 0 { g.NavJustMovedToKeyMods } else { g.IO.KeyMods };
+    if flag_set(flags, ImGuiMultiSelectFlags_NoRangeSelect) {
+        ms.KeyMods &= !ImGuiModFlags_Shift;
+    }
+
+    // Bind storage
+    let storage = g.MultiSelectStorage.entry(id).or_default();
+    storage.ID = id;
+    storage.LastFrameActive = g.FrameCount;
+    storage.LastSelectionSize = selection_size;
+    storage.Window = window_id;
+
+    // Output to user
+    ms.IO.RangeSrcItem = storage.RangeSrcItem;
+    ms.IO.NavIdItem = storage.NavIdItem;
+    ms.IO.NavIdSelected = storage.NavIdSelected == 1;
+    ms.IO.ItemsCount = items_count;
+
+    // Clear when using Navigation to move within the scope
+    // (we compare FocusScopeId so it possible to use multiple selections inside a same window)
+    let mut request_clear = false;
+    let mut request_select_all = false;
+
+    // Box-select handling: update active state.
+    if (flags & (ImGuiMultiSelectFlags_BoxSelect1d | ImGuiMultiSelectFlags_BoxSelect2d)) != 0 {
+        ms.BoxSelectId = hash_string(&String::from("##BoxSelect"), id); // GetID("##BoxSelect")
+        if BeginBoxSelect(g, &inner_clip_rect, window_id, ms.BoxSelectId, flags) {
+            request_clear |= g.BoxSelectState.RequestClear;
+        }
+    }
+
+    if g.NavJustMovedToId != 0 && g.NavJustMovedToFocusScopeId == ms.FocusScopeId {
+        if flag_set(ms.KeyMods, ImGuiModFlags_Shift) {
+            ms.IsKeyboardSetRange = true;
+        }
+        if (ms.KeyMods & (ImGuiModFlags_Ctrl | ImGuiModFlags_Shift)) == 0 && (flags & (ImGuiMultiSelectFlags_NoAutoClear | ImGuiMultiSelectFlags_NoAutoSelect)) == 0 {
+            request_clear = true;
+        }
+    }
+
+    // Shortcuts
+    // (routed to the selection focus scope, so another list or the window itself can still claim Ctrl+A)
+    if ms.IsFocused {
+        // Select All helper shortcut (CTRL+A)
+        // Note: we are comparing FocusScope so we don't need to be testing for IsWindowFocused()
+        if (flags & (ImGuiMultiSelectFlags_SingleSelect | ImGuiMultiSelectFlags_NoSelectAll)) == 0 {
+            if Shortcut(g, ImGuiMod_Ctrl | ImGuiKey_A, 0, ImGuiInputFlags_None) {
+                request_select_all = true;
+            }
+        }
+        if flag_set(flags, ImGuiMultiSelectFlags_ClearOnEscape) {
+            if Shortcut(g, ImGuiKey_Escape, 0, ImGuiInputFlags_None) {
+                request_clear = true;
+            }
+        }
+    }
+
+    if request_clear || request_select_all {
+        MultiSelectAddSetAll(&mut ms, request_select_all);
+        if !request_select_all {
+            g.MultiSelectStorage.get_mut(&id).unwrap().LastSelectionSize = 0;
+        }
+    }
+    ms.LoopRequestSetAll = if request_select_all { 1 } else if request_clear { 0 } else { -1 };
+    ms.LastSubmittedItem = ImGuiSelectionUserData_Invalid;
+
+    let io = ms.IO.clone();
+    g.CurrentMultiSelect = Some(ms);
+    io
+}
+
+// Return the requests emitted by the items (clicks, Shift+click ranges, Shift+arrows) and by clicking in the void.
+pub unsafe fn EndMultiSelect(g: &mut AppContext) -> ImGuiMultiSelectIO
+{
+    // IM_ASSERT(g.CurrentMultiSelect.is_some() && "Forgot to call BeginMultiSelect()?");
+    let mut ms = g.CurrentMultiSelect.take().unwrap();
+    let (scope_rect, window_id) = {
+        let window = g.current_window_mut().unwrap();
+        (ImRect::from_vec2(&ms.ScopeRectMin, &ImMaxVec2(&mut window.dc.CursorMaxPos.clone(), &mut ms.ScopeRectMin.clone())), window.ID)
+    };
+    let is_hovered = if flag_set(ms.Flags, ImGuiMultiSelectFlags_ScopeRect) {
+        IsMouseHoveringRect(&scope_rect.min, &scope_rect.max, true)
+    } else {
+        IsWindowHovered(g, ImGuiHoveredFlags_None)
+    };
+    ms.IsEndIO = true;
+
+    // Box-select: render and scroll
+    if GetBoxSelectState(g, ms.BoxSelectId).is_some() {
+        let box_select_scope_rect = if flag_set(ms.Flags, ImGuiMultiSelectFlags_ScopeRect) {
+            scope_rect
+        } else {
+            g.current_window_mut().unwrap().InnerClipRect
+        };
+        EndBoxSelect(g, &box_select_scope_rect, ms.Flags);
+    }
+
+    if is_hovered && g.HoveredId == 0 && g.HoveredWindow == window_id && IsMouseClicked(ImGuiMouseButton_Left, false) {
+        // Box-select starting from void
+        if ms.BoxSelectId != 0 && !g.BoxSelectState.IsActive && !g.BoxSelectState.IsStarting && g.IO.MouseClickedCount[ImGuiMouseButton_Left as usize] == 1 {
+            BoxSelectPreStartDrag(g, ms.BoxSelectId, ImGuiSelectionUserData_Invalid);
+            SetHoveredID(ms.BoxSelectId);
+        }
+
+        // Clear selection when clicking void?
+        // (when box-select is enabled this is deferred: dragging from the void clears via BoxSelectState.RequestClear)
+        if flag_set(ms.Flags, ImGuiMultiSelectFlags_ClearOnClickVoid) && flag_clear(ms.Flags, ImGuiMultiSelectFlags_NoAutoClear) && ms.BoxSelectId == 0 {
+            MultiSelectAddSetAll(&mut ms, false);
+        }
+    }
+    if ms.BoxSelectId != 0 && is_hovered && g.HoveredId == 0 && g.HoveredWindow == window_id && IsMouseRelease(ImGuiMouseButton_Left) && !IsMouseDragPastThreshold(ImGuiMouseButton_Left, -1.0) {
+        // Box-select enabled: a click (without drag) in the void clears on release
+        if flag_set(ms.Flags, ImGuiMultiSelectFlags_ClearOnClickVoid) && flag_clear(ms.Flags, ImGuiMultiSelectFlags_NoAutoClear) && g.IO.KeyMods == ImGuiModFlags_None && g.BoxSelectState.ID == 0 && g.ActiveId == 0 {
+            MultiSelectAddSetAll(&mut ms, false);
+        }
+    }
+
+    // Forget the range source if it was reset or wasn't submitted this frame (it was deleted or filtered out), so Shift+click
+    // doesn't extend from a stale anchor. Otherwise forget the focused item if it wasn't submitted this frame either.
+    if ms.IsFocused {
+        if let Some(storage) = g.MultiSelectStorage.get_mut(&ms.StorageId) {
+            if ms.IO.RangeSrcReset || (!ms.RangeSrcPassedBy && ms.IO.RangeSrcItem != ImGuiSelectionUserData_Invalid) {
+                storage.RangeSrcItem = ImGuiSelectionUserData_Invalid;
+                storage.RangeSelected = -1;
+            } else if !ms.NavIdPassedBy && storage.NavIdItem != ImGuiSelectionUserData_Invalid {
+                storage.NavIdItem = ImGuiSelectionUserData_Invalid;
+                storage.NavIdSelected = -1;
+            }
+        }
+    }
+
+    PopFocusScope();
+    if let Some(window) = g.current_window_mut() {
+        window.dc.CursorMaxPos = ImMaxVec2(&mut ms.BackupCursorMaxPos, &mut window.dc.CursorMaxPos.clone());
+    }
+    ms.IO
+}
+
+// Call between BeginMultiSelect() and EndMultiSelect() after deleting the selected items: the next Shift+click starts a new range.
+pub fn MultiSelectResetRangeSrc(g: &mut AppContext)
+{
+    if let Some(ms) = g.CurrentMultiSelect.as_mut() {
+        ms.IO.RangeSrcReset = true;
+    }
+}
+
+// Call before an item (Selectable(), TreeNode(), ...) submitted between BeginMultiSelect() and EndMultiSelect().
+// 'selection_user_data' is given back in selection requests: most applications pass the item index.
+pub fn SetNextItemSelectionUserData(g: &mut AppContext, selection_user_data: ImGuiSelectionUserData)
+{
+    g.next_item_data.SelectionUserData = selection_user_data;
+    g.next_item_data.FocusScopeId = g.current_window_mut().map(|window| window.dc.NavFocusScopeIdCurrent).unwrap_or(0);
+    if let Some(ms) = g.CurrentMultiSelect.as_mut() {
+        // Auto updating RangeSrcPassedBy for cases were clipper is not used (done before ItemAdd() clipping)
+        if ms.IO.RangeSrcItem == selection_user_data {
+            ms.RangeSrcPassedBy = true;
+        }
+    }
+}
+
+// [Internal] True when the item about to be submitted takes part in the current multi-selection
+pub fn IsNextItemMultiSelect(g: &AppContext) -> bool
+{
+    g.CurrentMultiSelect.is_some() && g.next_item_data.SelectionUserData != ImGuiSelectionUserData_Invalid
+}
+
+// In charge of:
+// - Applying SetAll for submitted items.
+// - Applying SetRange for submitted items and record end points.
+// - Altering button behavior flags to facilitate use with drag and drop.
+pub fn MultiSelectItemHeader(g: &mut AppContext, id: ImguiHandle, p_selected: &mut bool, p_button_flags: Option<&mut ImGuiButtonFlags>)
+{
+    let ms = g.CurrentMultiSelect.as_mut().unwrap();
+    let mut selected = *p_selected;
+    if ms.IsFocused {
+        let storage = g.MultiSelectStorage.get_mut(&ms.StorageId).unwrap();
+        let item_data = g.next_item_data.SelectionUserData;
+        // IM_ASSERT(g.next_item_data.FocusScopeId == window.dc.NavFocusScopeIdCurrent && "Forgot to call SetNextItemSelectionUserData() prior to item, required in BeginMultiSelect()/EndMultiSelect() scope");
+
+        // Apply SetAll (Clear/SelectAll) requests requested by BeginMultiSelect().
+        // This is only useful if the user hasn't processed them already, and this only works if the user isn't using the clipper.
+        // If you are using a clipper you need to process the SetAll request after calling BeginMultiSelect()
+        if ms.LoopRequestSetAll != -1 {
+            selected = ms.LoopRequestSetAll == 1;
+        }
+
+        // When using SHIFT+Nav: because it can incur scrolling we cannot afford a frame of lag with the selection highlight (otherwise scrolling would happen before selection)
+        // For this to work, we need someone to set 'RangeSrcPassedBy = true' at some point (either clipper either SetNextItemSelectionUserData() function)
+        if ms.IsKeyboardSetRange {
+            // IM_ASSERT(id != 0 && (ms.KeyMods & ImGuiModFlags_Shift) != 0);
+            let is_range_dst = !ms.RangeDstPassedBy && g.NavJustMovedToId == id; // Assume that g.NavJustMovedToId is not clipped.
+            if is_range_dst {
+                ms.RangeDstPassedBy = true;
+            }
+            if is_range_dst && storage.RangeSrcItem == ImGuiSelectionUserData_Invalid {
+                // If we don't have RangeSrc, assign RangeSrc = RangeDst
+                storage.RangeSrcItem = item_data;
+                storage.RangeSelected = if selected { 1 } else { 0 };
+            }
+            let is_range_src = storage.RangeSrcItem == item_data;
+            if is_range_src || is_range_dst || ms.RangeSrcPassedBy != ms.RangeDstPassedBy {
+                // Apply range-select value to visible items
+                // IM_ASSERT(storage.RangeSrcItem != ImGuiSelectionUserData_Invalid && storage.RangeSelected != -1);
+                selected = storage.RangeSelected != 0;
+            } else if flag_clear(ms.KeyMods, ImGuiModFlags_Ctrl) && flag_clear(ms.Flags, ImGuiMultiSelectFlags_NoAutoClear) {
+                // Clear other items
+                selected = false;
+            }
+        }
+    }
+    *p_selected = selected;
+
+    // Alter button behavior flags
+    // To handle drag and drop of multiple items we need to avoid clearing selection on click.
+    // Enabling this test makes actions using CTRL+SHIFT delay their effect on MouseUp which is annoying, but it allows drag and drop of multiple items.
+    if let Some(p_button_flags) = p_button_flags {
+        let mut button_flags = *p_button_flags;
+        button_flags |= ImGuiButtonFlags_NoHoveredOnFocus;
+        if (!selected || (g.ActiveId == id && g.ActiveIdHasBeenPressedBefore)) && flag_clear(ms.Flags, ImGuiMultiSelectFlags_SelectOnClickRelease) {
+            button_flags = (button_flags | ImGuiButtonFlags_PressedOnClick) & !ImGuiButtonFlags_PressedOnClickRelease;
+        } else {
+            button_flags |= ImGuiButtonFlags_PressedOnClickRelease;
+        }
+        *p_button_flags = button_flags;
+    }
+}
+
+// In charge of:
+// - Auto-select on navigation.
+// - Turning clicks and activations into SetAll/SetRange requests, based on Ctrl/Shift.
+// - Record current selection state for RangeSrc and NavId.
+// This is split from MultiSelectItemHeader() so the item can run its ButtonBehavior() in between.
+pub unsafe fn MultiSelectItemFooter(g: &mut AppContext, id: ImguiHandle, p_selected: &mut bool, p_pressed: &mut bool)
+{
+    let mut selected = *p_selected;
+    let mut pressed = *p_pressed;
+    let item_data = g.next_item_data.SelectionUserData;
+    g.next_item_data.SelectionUserData = ImGuiSelectionUserData_Invalid;
+    let hovered = IsItemHovered(ImGuiHoveredFlags_AllowWhenBlockedByPopup);
+
+    let ms = g.CurrentMultiSelect.as_mut().unwrap();
+    let storage = g.MultiSelectStorage.get_mut(&ms.StorageId).unwrap();
+    let is_singleselect = flag_set(ms.Flags, ImGuiMultiSelectFlags_SingleSelect);
+    let mut is_ctrl = flag_set(ms.KeyMods, ImGuiModFlags_Ctrl);
+    let mut is_shift = flag_set(ms.KeyMods, ImGuiModFlags_Shift);
+    if is_singleselect {
+        is_ctrl = false;
+        is_shift = false;
+    }
+
+    // Auto-select as you navigate a list
+    if g.NavJustMovedToId == id {
+        if flag_clear(ms.Flags, ImGuiMultiSelectFlags_NoAutoSelect) {
+            if is_ctrl && is_shift {
+                pressed = true;
+            } else if !is_ctrl {
+                selected = true;
+                pressed = true;
+            }
+        } else {
+            // With NoAutoSelect, using Shift+keyboard performs a write/copy
+            if is_shift {
+                pressed = true;
+            } else if !is_ctrl {
+                storage.RangeSrcItem = item_data; // When using Shift+Nav: update RangeSrc (e.g. in a list of checkboxes)
+            }
+        }
+    }
+
+    // Right-click on an unselected item selects it alone, so a context menu opened on it applies to it
+    if hovered && IsMouseClicked(ImGuiMouseButton_Right, false) && flag_clear(ms.Flags, ImGuiMultiSelectFlags_NoAutoSelect) {
+        if !selected && !is_ctrl && !is_shift && (g.ActiveId == 0 || g.ActiveId == id) {
+            pressed = true;
+        }
+    }
+
+    // Box-select toggle handling
+    // Items entering or leaving the selection rectangle toggle their selection (prev/curr rectangles are set by BeginBoxSelect()).
+    if ms.BoxSelectId != 0 && g.BoxSelectState.ID == ms.BoxSelectId && g.BoxSelectState.IsActive {
+        let item_rect = g.last_item_data.rect;
+        let bs = &mut g.BoxSelectState;
+        let rect_overlap_curr = bs.BoxSelectRectCurr.Overlaps(&item_rect);
+        let rect_overlap_prev = bs.BoxSelectRectPrev.Overlaps(&item_rect);
+        if (rect_overlap_curr && !rect_overlap_prev && !selected) || (rect_overlap_prev && !rect_overlap_curr) {
+            if storage.LastSelectionSize <= 0 && bs.IsStartedSetNavIdOnce {
+                pressed = true; // First item act as a pressed: code below will emit selection request and set NavId (whatever we emit here will be overridden anyway)
+                bs.IsStartedSetNavIdOnce = false;
+            } else {
+                selected = !selected;
+                MultiSelectAddSetRange(ms, selected, 1, item_data, item_data);
+            }
+            storage.LastSelectionSize = (storage.LastSelectionSize + 1).max(1);
+        }
+    }
+
+    if pressed {
+        //----------------------------------------------------------------------------------------
+        // ACTION                      | Begin  | Pressed/Activated  | End
+        //----------------------------------------------------------------------------------------
+        // Keys Navigated:             | Clear  | Src=item, Sel=1               SetRange 1
+        // Keys Navigated: Ctrl        | n/a    | n/a
+        // Keys Navigated:      Shift  | n/a    | Dst=item, Sel=1,   => Clear + SetRange 1
+        // Keys Navigated: Ctrl+Shift  | n/a    | Dst=item, Sel=Src  => Clear + SetRange Src-Dst
+        // Keys Activated:             | n/a    | Src=item, Sel=1    => Clear + SetRange 1
+        // Keys Activated: Ctrl        | n/a    | Src=item, Sel=!Sel =>         SetRange 1
+        // Keys Activated:      Shift  | n/a    | Dst=item, Sel=1    => Clear + SetRange 1
+        //----------------------------------------------------------------------------------------
+        // Mouse Pressed:              | n/a    | Src=item, Sel=1,   => Clear + SetRange 1
+        // Mouse Pressed:  Ctrl        | n/a    | Src=item, Sel=!Sel =>         SetRange 1
+        // Mouse Pressed:       Shift  | n/a    | Dst=item, Sel=1,   => Clear + SetRange 1
+        // Mouse Pressed:  Ctrl+Shift  | n/a    | Dst=item, Sel=!Sel =>         SetRange Src-Dst
+        //----------------------------------------------------------------------------------------
+
+        let input_source: ImGuiInputSource = if g.NavJustMovedToId == id || g.NavActivateId == id { g.NavInputSource } else { ImGuiInputSource_Mouse };
+        if flag_clear(ms.Flags, ImGuiMultiSelectFlags_NoAutoClear) {
+            let mut request_clear = false;
+            if is_singleselect {
+                request_clear = true;
+            } else if (input_source == ImGuiInputSource_Mouse || g.NavActivateId == id) && !is_ctrl {
+                request_clear = if flag_set(ms.Flags, ImGuiMultiSelectFlags_NoAutoClearOnReselect) { !selected } else { true };
+            } else if (input_source == ImGuiInputSource_Keyboard || input_source == ImGuiInputSource_Gamepad) && is_shift && !is_ctrl {
+                request_clear = true; // With is_shift==false the RequestClear was done in BeginIO, not necessary to do again.
+            }
+            if request_clear {
+                MultiSelectAddSetAll(ms, false);
+            }
+        }
+
+        let range_direction: c_int;
+        let range_selected: bool;
+        if is_shift && !is_singleselect {
+            if storage.RangeSrcItem == ImGuiSelectionUserData_Invalid {
+                storage.RangeSrcItem = item_data;
+            }
+            if flag_clear(ms.Flags, ImGuiMultiSelectFlags_NoAutoClearOnReselect) {
+                // Shift+Arrow always select
+                // Ctrl+Shift+Arrow copy source selection state (already stored by BeginMultiSelect() in storage.RangeSelected)
+                range_selected = if is_ctrl && storage.RangeSelected != -1 { storage.RangeSelected != 0 } else { true };
+            } else {
+                // Shift+Arrow copy source selection state
+                // Shift+Click always copy from target selection state
+                range_selected = if ms.IsKeyboardSetRange {
+                    if storage.RangeSelected != -1 { storage.RangeSelected != 0 } else { true }
+                } else {
+                    !selected
+                };
+            }
+            range_direction = if ms.RangeSrcPassedBy { 1 } else { -1 };
+        } else {
+            // Ctrl inverts selection, otherwise always select
+            selected = if flag_clear(ms.Flags, ImGuiMultiSelectFlags_NoAutoClearOnReselect) {
+                if is_ctrl { !selected } else { true }
+            } else {
+                !selected
+            };
+            storage.RangeSrcItem = item_data;
+            range_selected = selected;
+            range_direction = 1;
+        }
+        let range_src_item = storage.RangeSrcItem;
+        MultiSelectAddSetRange(ms, range_selected, range_direction, range_src_item, item_data);
+    }
+
+    // Update/store the selection state of the Source item (used by CTRL+SHIFT, when Source is unselected we perform a range unselect)
+    if storage.RangeSrcItem == item_data {
+        storage.RangeSelected = if selected { 1 } else { 0 };
+    }
+
+    // Update/store the selection state of focused item
+    if g.NavId == id {
+        storage.NavIdItem = item_data;
+        storage.NavIdSelected = if selected { 1 } else { 0 };
+    }
+    if storage.NavIdItem == item_data {
+        ms.NavIdPassedBy = true;
+    }
+    ms.LastSubmittedItem = item_data;
+
+    *p_selected = selected;
+    *p_pressed = pressed;
+}
+
+pub fn MultiSelectAddSetAll(ms: &mut ImGuiMultiSelectTempData, selected: bool)
+{
+    let req = ImGuiSelectionRequest {
+        Type: ImGuiSelectionRequestType_SetAll,
+        Selected: selected,
+        RangeDirection: 0,
+        RangeFirstItem: ImGuiSelectionUserData_Invalid,
+        RangeLastItem: ImGuiSelectionUserData_Invalid,
+    };
+    ms.IO.Requests.clear(); // Can always clear previous requests
+    ms.IO.Requests.push(req); // Add new request
+}
+
+pub fn MultiSelectAddSetRange(ms: &mut ImGuiMultiSelectTempData, selected: bool, range_dir: c_int, first_item: ImGuiSelectionUserData, last_item: ImGuiSelectionUserData)
+{
+    // Merge contiguous spans into same request (unless NoRangeSelect is set which guarantees single-item ranges)
+    if first_item == last_item && flag_clear(ms.Flags, ImGuiMultiSelectFlags_NoRangeSelect) {
+        if let Some(prev) = ms.IO.Requests.last_mut() {
+            if prev.Type == ImGuiSelectionRequestType_SetRange && prev.RangeLastItem == ms.LastSubmittedItem && prev.Selected == selected {
+                prev.RangeLastItem = last_item;
+                return;
+            }
+        }
+    }
+
+    let req = ImGuiSelectionRequest {
+        Type: ImGuiSelectionRequestType_SetRange,
+        Selected: selected,
+        RangeDirection: range_dir as i8,
+        RangeFirstItem: if range_dir > 0 { first_item } else { last_item },
+        RangeLastItem: if range_dir > 0 { last_item } else { first_item },
+    };
+    ms.IO.Requests.push(req); // Add new request
+}
@@ -0,0 +1,186 @@
+#![allow(non_snake_case)]
+
+use libc::{c_float, c_int};
+use crate::core::type_defs::ImguiHandle;
+use crate::core::vec2::Vector2;
+use crate::core::vec4::ImVec4;
+use crate::rect::ImRect;
+use crate::widgets::node_editor_flags::ImGuiNodeEditorFlags;
+use crate::widgets::node_minimap_location::ImGuiNodeMiniMapLocation;
+use crate::widgets::node_pin_kind::ImGuiNodePinKind;
+
+// Mouse interaction owning the editor (g.ActiveId == editor ID) until the button is released
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ImGuiNodeEditorInteraction {
+    #[default]
+    None,
+    DragNodes,
+    DragLink,
+    BoxSelect,
+    Pan,
+    MiniMap,
+}
+
+// Persistent state of a node, identified by the ID the application passed to BeginNode()
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiNodeEditorNode {
+    pub ID: c_int,
+    pub Pos: Vector2,
+    // Top-left corner, in grid space (saved by SaveNodeEditorState())
+    pub Size: Vector2,
+    // As of the last EndNode(), used to draw the background before the contents
+    pub TitleBarHeight: c_float,
+    // 0.0 without title bar
+    pub Rect: ImRect,
+    // Editor space, this frame
+    pub Selected: bool,
+    pub LastFrameSeen: usize,
+}
+
+// A pin submitted this frame by BeginNodeInput()/BeginNodeOutput()
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiNodeEditorPin {
+    pub ID: c_int,
+    pub NodeIdx: usize,
+    pub Kind: ImGuiNodePinKind,
+    pub Type: u32,
+    // Links can only connect pins of the same type
+    pub Pos: Vector2,
+    // Editor space, on the left or right edge of the node
+}
+
+// A link submitted this frame by NodeLink()
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiNodeEditorLink {
+    pub ID: c_int,
+    pub StartPin: c_int,
+    // Output pin
+    pub EndPin: c_int,
+    // Input pin
+}
+
+// Persistent state of a BeginNodeEditor()/EndNodeEditor() scope.
+// Three coordinate spaces are involved:
+// - grid space: node positions, independent of the view,
+// - editor space: where the nodes are laid out, editor = canvas.Min + grid + Panning,
+// - screen space: where they show, screen = canvas.Min + (editor - canvas.Min) * Zoom.
+// Between BeginNodeEditor() and EndNodeEditor() the mouse is moved to editor space, and EndNodeEditor() scales what was drawn to screen space.
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiNodeEditor {
+    pub ID: ImguiHandle,
+    pub Flags: ImGuiNodeEditorFlags,
+    pub Panning: Vector2,
+    pub Zoom: c_float,
+    pub Nodes: Vec<ImGuiNodeEditorNode>,
+    pub SelectedLinks: Vec<c_int>,
+    pub Interaction: ImGuiNodeEditorInteraction,
+    pub DragLinkStartPin: c_int,
+    // Pin the dragged link starts from
+    pub DragLinkDetached: Option<c_int>,
+    // Link picked up from its input pin: reported destroyed only when dropped away from that pin
+    pub BoxSelectStart: Vector2,
+    // Editor space
+    pub LinkCreated: Option<(c_int, c_int)>,
+    // Output pin, input pin; set by EndNodeEditor()
+    pub LinkDestroyed: Option<c_int>,
+    // Set by EndNodeEditor()
+    pub HoveredNode: Option<c_int>,
+    pub HoveredLink: Option<c_int>,
+    pub HoveredPin: Option<c_int>,
+    pub Hovered: bool,
+    // As of the last EndNodeEditor()
+
+    // [Internal, valid between BeginNodeEditor() and EndNodeEditor()]
+    pub CanvasRect: ImRect,
+    // Screen space
+    pub SubmittedNodes: Vec<usize>,
+    // Indices in Nodes, in submission order (last is drawn on top)
+    pub Pins: Vec<ImGuiNodeEditorPin>,
+    pub Links: Vec<ImGuiNodeEditorLink>,
+    pub CurrentNode: Option<usize>,
+    pub CurrentPin: Option<(c_int, ImGuiNodePinKind, u32)>,
+    pub CurrentNodeTitleBarMaxY: Option<c_float>,
+    pub MiniMap: Option<(c_float, ImGuiNodeMiniMapLocation)>,
+    // Set by NodeEditorMiniMap(): fraction of the canvas, corner
+    pub VtxStart: usize,
+    pub CmdStart: usize,
+    pub BackupMousePos: Vector2,
+    pub BackupMousePosPrev: Vector2,
+    pub BackupMouseDelta: Vector2,
+    pub BackupMouseClickedPos: [Vector2; 5],
+    pub BackupCursorPos: Vector2,
+    pub BackupCursorMaxPos: Vector2,
+    pub BackupClipRect: ImVec4,
+    pub BackupFringeScale: c_float,
+}
+
+impl ImGuiNodeEditor {
+    pub fn new(id: ImguiHandle) -> Self {
+        Self {
+            ID: id,
+            Zoom: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn FindNode(&self, id: c_int) -> Option<usize> {
+        self.Nodes.iter().position(|node| node.ID == id)
+    }
+
+    pub fn FindOrAddNode(&mut self, id: c_int) -> usize {
+        match self.FindNode(id) {
+            Some(node_idx) => node_idx,
+            None => {
+                self.Nodes.push(ImGuiNodeEditorNode { ID: id, ..Default::default() });
+                self.Nodes.len() - 1
+            }
+        }
+    }
+
+    pub fn FindPin(&self, id: c_int) -> Option<&ImGuiNodeEditorPin> {
+        self.Pins.iter().find(|pin| pin.ID == id)
+    }
+
+    // Editor space <-> screen space
+    pub fn EditorToScreen(&self, p: Vector2) -> Vector2 {
+        let origin = self.CanvasRect.min;
+        Vector2::from_floats(origin.x + (p.x - origin.x) * self.Zoom, origin.y + (p.y - origin.y) * self.Zoom)
+    }
+
+    pub fn ScreenToEditor(&self, p: Vector2) -> Vector2 {
+        let origin = self.CanvasRect.min;
+        Vector2::from_floats(origin.x + (p.x - origin.x) / self.Zoom, origin.y + (p.y - origin.y) / self.Zoom)
+    }
+
+    // Grid space <-> editor space
+    pub fn GridToEditor(&self, p: Vector2) -> Vector2 {
+        Vector2::from_floats(self.CanvasRect.min.x + p.x + self.Panning.x, self.CanvasRect.min.y + p.y + self.Panning.y)
+    }
+
+    pub fn EditorToGrid(&self, p: Vector2) -> Vector2 {
+        Vector2::from_floats(p.x - self.CanvasRect.min.x - self.Panning.x, p.y - self.CanvasRect.min.y - self.Panning.y)
+    }
+
+    // Change the zoom, keeping the grid position under 'screen_pos' in place
+    pub fn ZoomAround(&mut self, screen_pos: Vector2, zoom: c_float) {
+        let grid_pos = self.EditorToGrid(self.ScreenToEditor(screen_pos));
+        self.Zoom = zoom;
+        let editor_pos = self.ScreenToEditor(screen_pos);
+        self.Panning = Vector2::from_floats(editor_pos.x - self.CanvasRect.min.x - grid_pos.x, editor_pos.y - self.CanvasRect.min.y - grid_pos.y);
+    }
+}
+
+// Storage for SetNextNodeEditorXXX() functions, consumed by the next BeginNodeEditor()
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiNextNodeEditorData {
+    pub HasState: bool,
+    pub Panning: Vector2,
+    pub Zoom: c_float,
+    pub NodePositions: Vec<(c_int, Vector2)>,
+}
+
+impl ImGuiNextNodeEditorData {
+    pub fn ClearFlags(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -0,0 +1,17 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+// typedef int ImGuiNodeEditorFlags;      // -> enum ImGuiNodeEditorFlags_   // Flags: for BeginNodeEditor()
+pub type ImGuiNodeEditorFlags = c_int;
+
+// Flags for BeginNodeEditor()
+// enum ImGuiNodeEditorFlags_
+// {
+pub const ImGuiNodeEditorFlags_None: ImGuiNodeEditorFlags = 0;
+pub const ImGuiNodeEditorFlags_NoPan: ImGuiNodeEditorFlags = 1 << 0;   // Middle-drag doesn't pan the canvas.
+pub const ImGuiNodeEditorFlags_NoZoom: ImGuiNodeEditorFlags = 1 << 1;   // Mouse wheel doesn't zoom the canvas.
+pub const ImGuiNodeEditorFlags_NoBoxSelect: ImGuiNodeEditorFlags = 1 << 2;   // Left-drag on the empty canvas doesn't select the nodes and links in the dragged rectangle.
+pub const ImGuiNodeEditorFlags_NoGrid: ImGuiNodeEditorFlags = 1 << 3;   // Don't draw the grid lines.
+pub const ImGuiNodeEditorFlags_NoLinkDetach: ImGuiNodeEditorFlags = 1 << 4;   // Dragging from a connected input pin starts a new link instead of detaching the existing one.
+// };
@@ -0,0 +1,825 @@
+#![allow(non_snake_case)]
+
+//-------------------------------------------------------------------------
+// [SECTION] Widgets: BeginNodeEditor, EndNodeEditor, BeginNode, EndNode, NodeLink, NodeEditorMiniMap
+//-------------------------------------------------------------------------
+// - BeginNodeEditor()
+// - EndNodeEditor()
+// - BeginNode(), EndNode()
+// - BeginNodeTitleBar(), EndNodeTitleBar()
+// - BeginNodeInput(), EndNodeInput()
+// - BeginNodeOutput(), EndNodeOutput()
+// - NodeLink()
+// - NodeEditorMiniMap()
+// - IsNodeLinkCreated(), IsNodeLinkDestroyed()
+// - IsNodeEditorHovered(), GetHoveredNode(), GetHoveredLink()
+// - GetSelectedNodes(), GetSelectedLinks(), ClearNodeEditorSelection()
+// - SetNodeGridPos(), GetNodeGridPos()
+// - SaveNodeEditorState(), SetNextNodeEditorState()
+//-------------------------------------------------------------------------
+// Node graphs on an infinite canvas:
+// - Nodes are regions laid out like a group: any widget can be submitted between BeginNode() and EndNode().
+//   Pins are the widgets submitted between BeginNodeInput()/EndNodeInput() (left edge) or BeginNodeOutput()/EndNodeOutput() (right edge).
+// - Middle-drag pans, the mouse wheel zooms around the mouse. Zooming scales the vertices of everything drawn in the
+//   canvas, and the mouse is moved to the unscaled space while the nodes are submitted, so widgets inside nodes behave as usual.
+// - Left-drag on a node moves the selected nodes, left-drag on the empty canvas selects the nodes and links in the rectangle,
+//   Ctrl+click toggles the selection of a node or link.
+// - Dragging from a pin to a pin of the same type on another node reports a new link with IsNodeLinkCreated(). Dragging
+//   the link away from a connected input pin and dropping it elsewhere reports its removal with IsNodeLinkDestroyed()
+//   (and a new link too when dropped on another pin). The application owns the graph: links only exist if it submits them with NodeLink() on the following frames.
+// - Node, pin and link IDs are integers chosen by the application, pin IDs must be unique over the whole editor.
+// - Nodes can't contain legacy Columns(), which use the draw list channels the editor relies on.
+// Usage:
+//   if BeginNodeEditor(g, "graph", Vector2::from_floats(-1.0, -1.0), ImGuiNodeEditorFlags_None) {
+//       BeginNode(g, 1);
+//       BeginNodeTitleBar(g); Text("sine"); EndNodeTitleBar(g);
+//       BeginNodeInput(g, 10, PIN_FLOAT); Text("x"); EndNodeInput(g);
+//       BeginNodeOutput(g, 11, PIN_FLOAT); Text("y"); EndNodeOutput(g);
+//       EndNode(g);
+//       for link in &graph.links { NodeLink(g, link.id, link.from, link.to); }
+//       NodeEditorMiniMap(g, 0.2, ImGuiNodeMiniMapLocation_BottomRight);
+//       EndNodeEditor(g);
+//   }
+//   if let Some((from, to)) = IsNodeLinkCreated(g) { graph.add_link(from, to); }
+//   if let Some(link_id) = IsNodeLinkDestroyed(g) { graph.remove_link(link_id); }
+//-------------------------------------------------------------------------
+
+use libc::{c_float, c_int};
+use crate::color::{color_u32_from_rgba, ImGuiCol_Border, ImGuiCol_ButtonHovered, ImGuiCol_FrameBg, ImGuiCol_NavHighlight, ImGuiCol_PopupBg, ImGuiCol_Text, ImGuiCol_TitleBg, ImGuiCol_TitleBgActive};
+use crate::core::context::AppContext;
+use crate::core::cursor_ops::set_cursor_screen_pos;
+use crate::core::hash_ops::hash_string;
+use crate::core::id_ops::{ClearActiveID, pop_win_id_from_stack, push_int_id, SetActiveID};
+use crate::core::utils::flag_set;
+use crate::core::vec2::Vector2;
+use crate::drawing::draw_flags::{ImDrawFlags_None, ImDrawFlags_RoundCornersTop};
+use crate::drawing::render_ops::RenderFrame;
+use crate::item::item_ops::{CalcItemSize, CalcItemWidth, ItemAdd, ItemHoverable, ItemSize};
+use crate::rect::ImRect;
+use crate::style::style_ops::GetColorU32;
+use crate::widgets::group_ops::{BeginGroup, EndGroup};
+use crate::widgets::node_editor::{ImGuiNodeEditor, ImGuiNodeEditorInteraction, ImGuiNodeEditorLink, ImGuiNodeEditorPin};
+use crate::widgets::node_editor_flags::{ImGuiNodeEditorFlags, ImGuiNodeEditorFlags_NoBoxSelect, ImGuiNodeEditorFlags_NoGrid, ImGuiNodeEditorFlags_NoLinkDetach, ImGuiNodeEditorFlags_NoPan, ImGuiNodeEditorFlags_NoZoom};
+use crate::widgets::node_minimap_location::{ImGuiNodeMiniMapLocation, ImGuiNodeMiniMapLocation_BottomLeft, ImGuiNodeMiniMapLocation_TopLeft, ImGuiNodeMiniMapLocation_TopRight};
+use crate::widgets::node_pin_kind::{ImGuiNodePinKind, ImGuiNodePinKind_Input, ImGuiNodePinKind_Output};
+use crate::window::rect::{PopClipRect, PushClipRect};
+
+// Pin and link colors, indexed by pin type
+const NODE_PIN_TYPE_COLORS: [(u32, u32, u32); 8] = [
+    (220, 220, 220), (230, 160, 60), (90, 170, 230), (120, 200, 110),
+    (220, 100, 100), (180, 130, 220), (230, 210, 90), (90, 200, 190),
+];
+
+const NODE_EDITOR_ZOOM_MIN: c_float = 0.2;
+const NODE_EDITOR_ZOOM_MAX: c_float = 4.0;
+
+// Zoom factor per notch of mouse wheel
+const NODE_EDITOR_ZOOM_RATE: c_float = 1.1;
+
+// Distance between grid lines, in grid units
+const NODE_EDITOR_GRID_SPACING: c_float = 32.0;
+
+const NODE_ROUNDING: c_float = 4.0;
+const NODE_LINK_THICKNESS: c_float = 2.5;
+
+// Links are hit-tested against this many segments of their curve
+const NODE_LINK_HIT_SEGMENTS: usize = 24;
+
+// In pixels
+const NODE_LINK_HOVER_DISTANCE: c_float = 5.0;
+
+fn NodePinColor(pin_type: u32) -> u32 {
+    let (r, g, b) = NODE_PIN_TYPE_COLORS[pin_type as usize % NODE_PIN_TYPE_COLORS.len()];
+    color_u32_from_rgba(r, g, b, 255)
+}
+
+// Control points of the curve of a link going from an output pin to an input pin
+fn NodeLinkBezier(p1: Vector2, p4: Vector2) -> [Vector2; 4] {
+    let d = ((p4.x - p1.x).abs() * 0.5).max(40.0);
+    [p1, Vector2::from_floats(p1.x + d, p1.y), Vector2::from_floats(p4.x - d, p4.y), p4]
+}
+
+fn NodeBezierPoint(b: &[Vector2; 4], t: c_float) -> Vector2 {
+    let u = 1.0 - t;
+    let (w1, w2, w3, w4) = (u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t);
+    Vector2::from_floats(w1 * b[0].x + w2 * b[1].x + w3 * b[2].x + w4 * b[3].x, w1 * b[0].y + w2 * b[1].y + w3 * b[2].y + w4 * b[3].y)
+}
+
+fn NodeLinkDistance(b: &[Vector2; 4], p: Vector2) -> c_float {
+    let mut dist_sq = c_float::MAX;
+    let mut a = b[0];
+    for n in 1..=NODE_LINK_HIT_SEGMENTS {
+        let c = NodeBezierPoint(b, n as c_float / NODE_LINK_HIT_SEGMENTS as c_float);
+        let (dx, dy) = (c.x - a.x, c.y - a.y);
+        let len_sq = dx * dx + dy * dy;
+        let t = if len_sq > 0.0 { (((p.x - a.x) * dx + (p.y - a.y) * dy) / len_sq).clamp(0.0, 1.0) } else { 0.0 };
+        let (ex, ey) = (a.x + dx * t - p.x, a.y + dy * t - p.y);
+        dist_sq = dist_sq.min(ex * ex + ey * ey);
+        a = c;
+    }
+    dist_sq.sqrt()
+}
+
+fn NodeLinkInRect(b: &[Vector2; 4], rect: &mut ImRect) -> bool {
+    (0..=NODE_LINK_HIT_SEGMENTS).any(|n| rect.Contains(&NodeBezierPoint(b, n as c_float / NODE_LINK_HIT_SEGMENTS as c_float)))
+}
+
+// Output and input pin of a link between two pins, if they can be connected
+fn NodePinsConnectable(a: &ImGuiNodeEditorPin, b: &ImGuiNodeEditorPin) -> Option<(c_int, c_int)> {
+    if a.Kind == b.Kind || a.Type != b.Type || a.NodeIdx == b.NodeIdx {
+        return None;
+    }
+    if a.Kind == ImGuiNodePinKind_Output { Some((a.ID, b.ID)) } else { Some((b.ID, a.ID)) }
+}
+
+// Placement of the minimap: screen rectangle, and grid space -> screen mapping
+struct ImGuiNodeMiniMap {
+    Rect: ImRect,
+    GridMin: Vector2,
+    Scale: c_float,
+    Offset: Vector2,
+}
+
+impl ImGuiNodeMiniMap {
+    fn GridToScreen(&self, p: Vector2) -> Vector2 {
+        Vector2::from_floats(self.Offset.x + (p.x - self.GridMin.x) * self.Scale, self.Offset.y + (p.y - self.GridMin.y) * self.Scale)
+    }
+
+    fn ScreenToGrid(&self, p: Vector2) -> Vector2 {
+        Vector2::from_floats(self.GridMin.x + (p.x - self.Offset.x) / self.Scale, self.GridMin.y + (p.y - self.Offset.y) / self.Scale)
+    }
+}
+
+// Fit the nodes submitted this frame and the visible area of the canvas in a corner of the canvas
+fn NodeMiniMapLayout(editor: &ImGuiNodeEditor, size_fraction: c_float, location: ImGuiNodeMiniMapLocation, margin: c_float) -> ImGuiNodeMiniMap {
+    let canvas = editor.CanvasRect;
+    let size = Vector2::from_floats((canvas.max.x - canvas.min.x) * size_fraction, (canvas.max.y - canvas.min.y) * size_fraction);
+    let left = location == ImGuiNodeMiniMapLocation_BottomLeft || location == ImGuiNodeMiniMapLocation_TopLeft;
+    let top = location == ImGuiNodeMiniMapLocation_TopLeft || location == ImGuiNodeMiniMapLocation_TopRight;
+    let min_x = if left { canvas.min.x + margin } else { canvas.max.x - margin - size.x };
+    let min_y = if top { canvas.min.y + margin } else { canvas.max.y - margin - size.y };
+    let rect = ImRect::from_floats(min_x, min_y, min_x + size.x, min_y + size.y);
+
+    let view_min = editor.EditorToGrid(editor.ScreenToEditor(canvas.min));
+    let view_max = editor.EditorToGrid(editor.ScreenToEditor(canvas.max));
+    let (mut grid_min, mut grid_max) = (view_min, view_max);
+    for &node_idx in editor.SubmittedNodes.iter() {
+        let node = &editor.Nodes[node_idx];
+        grid_min = Vector2::from_floats(grid_min.x.min(node.Pos.x), grid_min.y.min(node.Pos.y));
+        grid_max = Vector2::from_floats(grid_max.x.max(node.Pos.x + node.Size.x), grid_max.y.max(node.Pos.y + node.Size.y));
+    }
+    let scale = (size.x / (grid_max.x - grid_min.x)).min(size.y / (grid_max.y - grid_min.y));
+    let offset = Vector2::from_floats(
+        rect.min.x + (size.x - (grid_max.x - grid_min.x) * scale) * 0.5,
+        rect.min.y + (size.y - (grid_max.y - grid_min.y) * scale) * 0.5,
+    );
+    ImGuiNodeMiniMap { Rect: rect, GridMin: grid_min, Scale: scale, Offset: offset }
+}
+
+//-------------------------------------------------------------------------
+// BeginNodeEditor(), EndNodeEditor()
+//-------------------------------------------------------------------------
+
+// Only call EndNodeEditor() if BeginNodeEditor() returns true
+pub unsafe fn BeginNodeEditor(g: &mut AppContext, str_id: &str, size_arg: Vector2, flags: ImGuiNodeEditorFlags) -> bool {
+    // IM_ASSERT(g.CurrentNodeEditor.is_none()); // Nesting BeginNodeEditor()/EndNodeEditor() is not supported
+    let (skip_items, seed) = {
+        let window = g.current_window_mut().unwrap();
+        (window.skip_items, window.id_stack.last().unwrap().clone())
+    };
+    if skip_items {
+        g.NextNodeEditorData.ClearFlags();
+        return false;
+    }
+    let id = hash_string(&String::from(str_id), seed);
+    let font_size = g.FontSize;
+    let item_width = CalcItemWidth(g);
+    let canvas_size = CalcItemSize(g, size_arg, item_width, font_size * 20.0);
+    let cursor_pos = g.current_window_mut().unwrap().dc.cursor_pos;
+    let mut canvas_bb = ImRect::from_floats(cursor_pos.x, cursor_pos.y, cursor_pos.x + canvas_size.x, cursor_pos.y + canvas_size.y);
+    ItemSize(g, &canvas_size, -1.0);
+    if !ItemAdd(g, &mut canvas_bb, id, None, 0) {
+        g.NextNodeEditorData.ClearFlags();
+        return false;
+    }
+
+    // Persistent state, moved to g.CurrentNodeEditor until EndNodeEditor()
+    let mut editor = g.NodeEditors.remove(&id).unwrap_or_else(|| ImGuiNodeEditor::new(id));
+    editor.Flags = flags;
+    editor.CanvasRect = canvas_bb;
+    if g.NextNodeEditorData.HasState {
+        editor.Panning = g.NextNodeEditorData.Panning;
+        editor.Zoom = g.NextNodeEditorData.Zoom.clamp(NODE_EDITOR_ZOOM_MIN, NODE_EDITOR_ZOOM_MAX);
+        for &(node_id, pos) in g.NextNodeEditorData.NodePositions.iter() {
+            let node_idx = editor.FindOrAddNode(node_id);
+            editor.Nodes[node_idx].Pos = pos;
+        }
+    }
+    g.NextNodeEditorData.ClearFlags();
+    editor.SubmittedNodes.clear();
+    editor.Pins.clear();
+    editor.Links.clear();
+    editor.CurrentNode = None;
+    editor.CurrentPin = None;
+    editor.MiniMap = None;
+    editor.LinkCreated = None;
+    editor.LinkDestroyed = None;
+
+    // Zoom before the nodes are laid out, so that they show where the mouse expects them on this frame.
+    // EndNodeEditor() tells whether the canvas itself is hovered (not a widget inside a node), as of last frame.
+    if editor.Hovered && g.IO.MouseWheel != 0.0 && !flag_set(flags, ImGuiNodeEditorFlags_NoZoom) {
+        let zoom = (editor.Zoom * NODE_EDITOR_ZOOM_RATE.powf(g.IO.MouseWheel)).clamp(NODE_EDITOR_ZOOM_MIN, NODE_EDITOR_ZOOM_MAX);
+        editor.ZoomAround(g.IO.MousePos, zoom);
+    }
+
+    RenderFrame(canvas_bb.min, canvas_bb.max, GetColorU32(ImGuiCol_FrameBg, 1.0), true, g.style.FrameRounding);
+
+    // Everything drawn from here is in editor space, until EndNodeEditor() scales it
+    let editor_clip_max = editor.ScreenToEditor(canvas_bb.max);
+    {
+        let window = g.current_window_mut().unwrap();
+        editor.BackupCursorPos = window.dc.cursor_pos;
+        editor.BackupCursorMaxPos = window.dc.CursorMaxPos;
+        editor.BackupClipRect = window.ClipRect;
+        editor.BackupFringeScale = window.DrawList._FringeScale;
+    }
+    PushClipRect(g, &canvas_bb.min, &editor_clip_max, false);
+    let col_grid = GetColorU32(ImGuiCol_Border, 0.35);
+    let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+    editor.CmdStart = draw_list.CmdBuffer.len() - 1;
+    editor.VtxStart = draw_list.VtxBuffer.len();
+    draw_list._FringeScale = editor.BackupFringeScale / editor.Zoom;
+
+    // Channel 0: grid and links, channel 1: nodes
+    draw_list.ChannelsSplit(2);
+    if !flag_set(flags, ImGuiNodeEditorFlags_NoGrid) {
+        let grid_origin = editor.GridToEditor(Vector2::from_floats(0.0, 0.0));
+        let mut x = canvas_bb.min.x + (grid_origin.x - canvas_bb.min.x).rem_euclid(NODE_EDITOR_GRID_SPACING);
+        while x < editor_clip_max.x {
+            draw_list.AddLine(Vector2::from_floats(x, canvas_bb.min.y), Vector2::from_floats(x, editor_clip_max.y), col_grid, 1.0);
+            x += NODE_EDITOR_GRID_SPACING;
+        }
+        let mut y = canvas_bb.min.y + (grid_origin.y - canvas_bb.min.y).rem_euclid(NODE_EDITOR_GRID_SPACING);
+        while y < editor_clip_max.y {
+            draw_list.AddLine(Vector2::from_floats(canvas_bb.min.x, y), Vector2::from_floats(editor_clip_max.x, y), col_grid, 1.0);
+            y += NODE_EDITOR_GRID_SPACING;
+        }
+    }
+    draw_list.ChannelsSetCurrent(1);
+
+    // Widgets inside nodes see the mouse in editor space
+    editor.BackupMousePos = g.IO.MousePos;
+    editor.BackupMousePosPrev = g.IO.MousePosPrev;
+    editor.BackupMouseDelta = g.IO.MouseDelta;
+    editor.BackupMouseClickedPos = g.IO.MouseClickedPos;
+    g.IO.MousePos = editor.ScreenToEditor(g.IO.MousePos);
+    g.IO.MousePosPrev = editor.ScreenToEditor(g.IO.MousePosPrev);
+    g.IO.MouseDelta = Vector2::from_floats(g.IO.MouseDelta.x / editor.Zoom, g.IO.MouseDelta.y / editor.Zoom);
+    for pos in g.IO.MouseClickedPos.iter_mut() {
+        *pos = editor.ScreenToEditor(*pos);
+    }
+    g.CurrentNodeEditor = Some(editor);
+    true
+}
+
+pub unsafe fn EndNodeEditor(g: &mut AppContext) {
+    // IM_ASSERT(g.CurrentNodeEditor.is_some()); // Mismatched BeginNodeEditor()/EndNodeEditor() calls
+    let mut editor = match g.CurrentNodeEditor.take() {
+        Some(editor) => editor,
+        None => return,
+    };
+    // IM_ASSERT(editor.CurrentNode.is_none()); // Missing EndNode()
+    g.IO.MousePos = editor.BackupMousePos;
+    g.IO.MousePosPrev = editor.BackupMousePosPrev;
+    g.IO.MouseDelta = editor.BackupMouseDelta;
+    g.IO.MouseClickedPos = editor.BackupMouseClickedPos;
+    let window_id = g.current_window_mut().unwrap().ID;
+    let flags = editor.Flags;
+    let canvas = editor.CanvasRect;
+    let mouse = g.IO.MousePos;
+    let mouse_editor = editor.ScreenToEditor(mouse);
+    let zoom = editor.Zoom;
+    let font_size = g.FontSize;
+    let margin = g.style.ItemInnerSpacing.x;
+
+    // The canvas is hovered when no widget inside a node is
+    editor.Hovered = ItemHoverable(&canvas, editor.ID);
+    if editor.Hovered {
+        g.HoveredIdUsingMouseWheel = true;
+    }
+    let minimap = editor.MiniMap.map(|(size_fraction, location)| NodeMiniMapLayout(&editor, size_fraction, location, margin));
+    let minimap_hovered = match &minimap {
+        Some(minimap) => editor.Hovered && { let mut rect = minimap.Rect; rect.Contains(&mouse) },
+        None => false,
+    };
+
+    // Hovered pin, else topmost hovered node, else closest hovered link
+    editor.HoveredPin = None;
+    editor.HoveredNode = None;
+    editor.HoveredLink = None;
+    if editor.Hovered && !minimap_hovered {
+        let pin_hover_radius = font_size * 0.5;
+        let mut best_dist = pin_hover_radius;
+        for pin in editor.Pins.iter() {
+            let dist = ((pin.Pos.x - mouse_editor.x).powi(2) + (pin.Pos.y - mouse_editor.y).powi(2)).sqrt();
+            if dist <= best_dist {
+                best_dist = dist;
+                editor.HoveredPin = Some(pin.ID);
+            }
+        }
+        if editor.HoveredPin.is_none() {
+            editor.HoveredNode = editor.SubmittedNodes.iter().rev().map(|&node_idx| &editor.Nodes[node_idx]).find(|node| { let mut rect = node.Rect; rect.Contains(&mouse_editor) }).map(|node| node.ID);
+        }
+        if editor.HoveredPin.is_none() && editor.HoveredNode.is_none() {
+            let mut best_dist = NODE_LINK_HOVER_DISTANCE / zoom;
+            for link in editor.Links.iter() {
+                if let (Some(start), Some(end)) = (editor.FindPin(link.StartPin), editor.FindPin(link.EndPin)) {
+                    let dist = NodeLinkDistance(&NodeLinkBezier(start.Pos, end.Pos), mouse_editor);
+                    if dist <= best_dist {
+                        best_dist = dist;
+                        editor.HoveredLink = Some(link.ID);
+                    }
+                }
+            }
+        }
+    }
+
+    // Start an interaction
+    if g.ActiveId != editor.ID {
+        editor.Interaction = ImGuiNodeEditorInteraction::None;
+        editor.DragLinkDetached = None;
+    }
+    if editor.Hovered && editor.Interaction == ImGuiNodeEditorInteraction::None {
+        let ctrl = g.IO.KeyCtrl;
+        if g.IO.MouseClicked[0] {
+            if minimap_hovered {
+                editor.Interaction = ImGuiNodeEditorInteraction::MiniMap;
+            } else if let Some(pin_id) = editor.HoveredPin {
+                let pin_kind = editor.FindPin(pin_id).unwrap().Kind;
+                let attached = editor.Links.iter().find(|link| link.EndPin == pin_id).cloned();
+                match attached {
+                    Some(link) if pin_kind == ImGuiNodePinKind_Input && !flag_set(flags, ImGuiNodeEditorFlags_NoLinkDetach) => {
+                        // Pick up the end of the existing link
+                        editor.DragLinkDetached = Some(link.ID);
+                        editor.DragLinkStartPin = link.StartPin;
+                    }
+                    _ => {
+                        editor.DragLinkDetached = None;
+                        editor.DragLinkStartPin = pin_id;
+                    }
+                }
+                editor.Interaction = ImGuiNodeEditorInteraction::DragLink;
+            } else if let Some(node_id) = editor.HoveredNode {
+                let node_idx = editor.FindNode(node_id).unwrap();
+                if ctrl {
+                    editor.Nodes[node_idx].Selected = !editor.Nodes[node_idx].Selected;
+                } else if !editor.Nodes[node_idx].Selected {
+                    NodeEditorClearSelection(&mut editor);
+                    editor.Nodes[node_idx].Selected = true;
+                }
+                if editor.Nodes[node_idx].Selected {
+                    editor.Interaction = ImGuiNodeEditorInteraction::DragNodes;
+                }
+            } else if let Some(link_id) = editor.HoveredLink {
+                let selected = editor.SelectedLinks.contains(&link_id);
+                if !ctrl {
+                    NodeEditorClearSelection(&mut editor);
+                }
+                if ctrl && selected {
+                    editor.SelectedLinks.retain(|&id| id != link_id);
+                } else {
+                    editor.SelectedLinks.push(link_id);
+                }
+            } else {
+                if !ctrl {
+                    NodeEditorClearSelection(&mut editor);
+                }
+                if !flag_set(flags, ImGuiNodeEditorFlags_NoBoxSelect) {
+                    editor.Interaction = ImGuiNodeEditorInteraction::BoxSelect;
+                    editor.BoxSelectStart = mouse_editor;
+                }
+            }
+        } else if g.IO.MouseClicked[2] && !flag_set(flags, ImGuiNodeEditorFlags_NoPan) {
+            editor.Interaction = ImGuiNodeEditorInteraction::Pan;
+        }
+        if editor.Interaction != ImGuiNodeEditorInteraction::None {
+            SetActiveID(g, editor.ID, None);
+            g.ActiveIdWindow = window_id;
+        }
+    }
+
+    // Update the interaction. Moves show from the next frame, the nodes of this frame are already laid out.
+    let mouse_delta = Vector2::from_floats(g.IO.MouseDelta.x / zoom, g.IO.MouseDelta.y / zoom);
+    let mut box_select_rect = None;
+    let interaction_done = match editor.Interaction {
+        ImGuiNodeEditorInteraction::None => false,
+        ImGuiNodeEditorInteraction::DragNodes => {
+            for node in editor.Nodes.iter_mut().filter(|node| node.Selected) {
+                node.Pos = Vector2::from_floats(node.Pos.x + mouse_delta.x, node.Pos.y + mouse_delta.y);
+            }
+            !g.IO.MouseDown[0]
+        }
+        ImGuiNodeEditorInteraction::Pan => {
+            editor.Panning = Vector2::from_floats(editor.Panning.x + mouse_delta.x, editor.Panning.y + mouse_delta.y);
+            !g.IO.MouseDown[2]
+        }
+        ImGuiNodeEditorInteraction::BoxSelect => {
+            // The selection is what the rectangle covers
+            let start = editor.BoxSelectStart;
+            let mut rect = ImRect::from_floats(start.x.min(mouse_editor.x), start.y.min(mouse_editor.y), start.x.max(mouse_editor.x), start.y.max(mouse_editor.y));
+            for &node_idx in editor.SubmittedNodes.iter() {
+                let node_rect = editor.Nodes[node_idx].Rect;
+                editor.Nodes[node_idx].Selected = rect.Overlaps(&node_rect);
+            }
+            let mut selected_links = Vec::new();
+            for link in editor.Links.iter() {
+                if let (Some(start), Some(end)) = (editor.FindPin(link.StartPin), editor.FindPin(link.EndPin)) {
+                    if NodeLinkInRect(&NodeLinkBezier(start.Pos, end.Pos), &mut rect) {
+                        selected_links.push(link.ID);
+                    }
+                }
+            }
+            editor.SelectedLinks = selected_links;
+            box_select_rect = Some(rect);
+            !g.IO.MouseDown[0]
+        }
+        ImGuiNodeEditorInteraction::DragLink => {
+            if !g.IO.MouseDown[0] {
+                if let (Some(start), Some(end)) = (editor.FindPin(editor.DragLinkStartPin), editor.HoveredPin.and_then(|pin_id| editor.FindPin(pin_id))) {
+                    editor.LinkCreated = NodePinsConnectable(start, end);
+                }
+                if let Some(link_id) = editor.DragLinkDetached.take() {
+                    // Dropped back on its own input pin: nothing changed
+                    let original = editor.Links.iter().find(|link| link.ID == link_id).map(|link| (link.StartPin, link.EndPin));
+                    if editor.LinkCreated.is_some() && editor.LinkCreated == original {
+                        editor.LinkCreated = None;
+                    } else {
+                        editor.LinkDestroyed = Some(link_id);
+                    }
+                }
+            }
+            !g.IO.MouseDown[0]
+        }
+        ImGuiNodeEditorInteraction::MiniMap => {
+            // Center the view on the clicked point
+            if let Some(minimap) = &minimap {
+                let target = minimap.ScreenToGrid(mouse);
+                let center = editor.ScreenToEditor(Vector2::from_floats((canvas.min.x + canvas.max.x) * 0.5, (canvas.min.y + canvas.max.y) * 0.5));
+                editor.Panning = Vector2::from_floats(center.x - canvas.min.x - target.x, center.y - canvas.min.y - target.y);
+            }
+            !g.IO.MouseDown[0]
+        }
+    };
+    if interaction_done {
+        editor.Interaction = ImGuiNodeEditorInteraction::None;
+        ClearActiveID(g);
+    }
+
+    // Links, behind the nodes
+    let col_selected = GetColorU32(ImGuiCol_NavHighlight, 1.0);
+    let col_box_fill = GetColorU32(ImGuiCol_NavHighlight, 0.15);
+    let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+    draw_list.ChannelsSetCurrent(0);
+    for link in editor.Links.iter() {
+        if editor.Interaction == ImGuiNodeEditorInteraction::DragLink && editor.DragLinkDetached == Some(link.ID) {
+            // Drawn as the dragged link
+            continue;
+        }
+        if let (Some(start), Some(end)) = (editor.FindPin(link.StartPin), editor.FindPin(link.EndPin)) {
+            let b = NodeLinkBezier(start.Pos, end.Pos);
+            let col = if editor.SelectedLinks.contains(&link.ID) { col_selected } else { NodePinColor(start.Type) };
+            let thickness = if editor.HoveredLink == Some(link.ID) { NODE_LINK_THICKNESS + 1.5 } else { NODE_LINK_THICKNESS };
+            draw_list.AddBezierCubic(&b[0], &b[1], &b[2], &b[3], col, thickness, 0);
+        }
+    }
+    if editor.Interaction == ImGuiNodeEditorInteraction::DragLink {
+        if let Some(start) = editor.FindPin(editor.DragLinkStartPin) {
+            // Snap to a pin it can connect to
+            let target = editor.HoveredPin.and_then(|pin_id| editor.FindPin(pin_id)).filter(|end| NodePinsConnectable(start, end).is_some());
+            let end_pos = target.map(|end| end.Pos).unwrap_or(mouse_editor);
+            let b = if start.Kind == ImGuiNodePinKind_Output { NodeLinkBezier(start.Pos, end_pos) } else { NodeLinkBezier(end_pos, start.Pos) };
+            draw_list.AddBezierCubic(&b[0], &b[1], &b[2], &b[3], NodePinColor(start.Type), NODE_LINK_THICKNESS, 0);
+        }
+    }
+    draw_list.ChannelsSetCurrent(1);
+    if let Some(rect) = box_select_rect {
+        draw_list.AddRectFilled(&rect.min, &rect.max, col_box_fill, 0.0, ImDrawFlags_None);
+        draw_list.AddRect(rect.min, rect.max, col_selected, 0.0);
+    }
+    draw_list.ChannelsMerge();
+
+    // Editor space -> screen space, for the vertices and the clipping rectangles drawn since BeginNodeEditor()
+    let origin = canvas.min;
+    let clip = editor.BackupClipRect;
+    for vtx in draw_list.VtxBuffer[editor.VtxStart..].iter_mut() {
+        vtx.pos = Vector2::from_floats(origin.x + (vtx.pos.x - origin.x) * zoom, origin.y + (vtx.pos.y - origin.y) * zoom);
+    }
+    for cmd in draw_list.CmdBuffer[editor.CmdStart..].iter_mut() {
+        cmd.ClipRect.x = (origin.x + (cmd.ClipRect.x - origin.x) * zoom).max(canvas.min.x).max(clip.x);
+        cmd.ClipRect.y = (origin.y + (cmd.ClipRect.y - origin.y) * zoom).max(canvas.min.y).max(clip.y);
+        cmd.ClipRect.z = (origin.x + (cmd.ClipRect.z - origin.x) * zoom).min(canvas.max.x).min(clip.z);
+        cmd.ClipRect.w = (origin.y + (cmd.ClipRect.w - origin.y) * zoom).min(canvas.max.y).min(clip.w);
+    }
+    draw_list._FringeScale = editor.BackupFringeScale;
+    PopClipRect(g);
+
+    // Nodes don't extend the window contents: the canvas item does
+    {
+        let window = g.current_window_mut().unwrap();
+        window.dc.cursor_pos = editor.BackupCursorPos;
+        window.dc.CursorMaxPos = editor.BackupCursorMaxPos;
+        window.dc.is_set_pos = false;
+    }
+
+    // Minimap, in screen space
+    if let Some(minimap) = &minimap {
+        let col_bg = GetColorU32(ImGuiCol_PopupBg, 0.80);
+        let col_border = GetColorU32(ImGuiCol_Border, 1.0);
+        let col_node = GetColorU32(ImGuiCol_TitleBg, 1.0);
+        let col_view = GetColorU32(ImGuiCol_Text, 1.0);
+        let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+        draw_list.AddRectFilled(&minimap.Rect.min, &minimap.Rect.max, col_bg, 0.0, ImDrawFlags_None);
+        draw_list.AddRect(minimap.Rect.min, minimap.Rect.max, col_border, 0.0);
+        for link in editor.Links.iter() {
+            if let (Some(start), Some(end)) = (editor.FindPin(link.StartPin), editor.FindPin(link.EndPin)) {
+                let p1 = minimap.GridToScreen(editor.EditorToGrid(start.Pos));
+                let p2 = minimap.GridToScreen(editor.EditorToGrid(end.Pos));
+                draw_list.AddLine(p1, p2, NodePinColor(start.Type), 1.0);
+            }
+        }
+        for &node_idx in editor.SubmittedNodes.iter() {
+            let node = &editor.Nodes[node_idx];
+            let node_min = minimap.GridToScreen(node.Pos);
+            let node_max = minimap.GridToScreen(Vector2::from_floats(node.Pos.x + node.Size.x, node.Pos.y + node.Size.y));
+            draw_list.AddRectFilled(&node_min, &node_max, if node.Selected { col_selected } else { col_node }, 0.0, ImDrawFlags_None);
+        }
+        let view_min = minimap.GridToScreen(editor.EditorToGrid(editor.ScreenToEditor(canvas.min)));
+        let view_max = minimap.GridToScreen(editor.EditorToGrid(editor.ScreenToEditor(canvas.max)));
+        draw_list.AddRect(view_min, view_max, col_view, 0.0);
+    }
+
+    g.NodeEditorLastID = editor.ID;
+    g.NodeEditors.insert(editor.ID, editor);
+}
+
+fn NodeEditorClearSelection(editor: &mut ImGuiNodeEditor) {
+    for node in editor.Nodes.iter_mut() {
+        node.Selected = false;
+    }
+    editor.SelectedLinks.clear();
+}
+
+//-------------------------------------------------------------------------
+// BeginNode(), EndNode(), pins, links
+//-------------------------------------------------------------------------
+
+pub unsafe fn BeginNode(g: &mut AppContext, node_id: c_int) {
+    let padding = g.style.WindowPadding;
+    let frame_count = g.FrameCount;
+    let col_bg = GetColorU32(ImGuiCol_PopupBg, 1.0);
+    let col_title = GetColorU32(ImGuiCol_TitleBg, 1.0);
+    let col_title_selected = GetColorU32(ImGuiCol_TitleBgActive, 1.0);
+    let col_border = GetColorU32(ImGuiCol_Border, 1.0);
+    let col_border_hovered = GetColorU32(ImGuiCol_ButtonHovered, 1.0);
+    let col_border_selected = GetColorU32(ImGuiCol_NavHighlight, 1.0);
+    let (node_min, node_size, title_bar_height, selected, hovered) = {
+        let editor = g.CurrentNodeEditor.as_mut().unwrap(); // IM_ASSERT(): Call BeginNode() between BeginNodeEditor() and EndNodeEditor()
+        // IM_ASSERT(editor.CurrentNode.is_none()); // Nesting BeginNode()/EndNode() is not supported
+        let node_idx = editor.FindOrAddNode(node_id);
+        editor.SubmittedNodes.push(node_idx);
+        editor.CurrentNode = Some(node_idx);
+        editor.CurrentNodeTitleBarMaxY = None;
+        editor.Nodes[node_idx].LastFrameSeen = frame_count;
+        let node = &editor.Nodes[node_idx];
+        (editor.GridToEditor(node.Pos), node.Size, node.TitleBarHeight, node.Selected, editor.HoveredNode == Some(node_id))
+    };
+
+    // The background uses the size of last frame, so that it is drawn under the contents
+    if node_size.x > 0.0 {
+        let node_max = Vector2::from_floats(node_min.x + node_size.x, node_min.y + node_size.y);
+        let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+        draw_list.AddRectFilled(&node_min, &node_max, col_bg, NODE_ROUNDING, ImDrawFlags_None);
+        if title_bar_height > 0.0 {
+            let title_max = Vector2::from_floats(node_max.x, node_min.y + title_bar_height);
+            draw_list.AddRectFilled(&node_min, &title_max, if selected { col_title_selected } else { col_title }, NODE_ROUNDING, ImDrawFlags_RoundCornersTop);
+        }
+        draw_list.AddRect(node_min, node_max, if selected { col_border_selected } else if hovered { col_border_hovered } else { col_border }, NODE_ROUNDING);
+    }
+    push_int_id(g, node_id);
+    set_cursor_screen_pos(g, &Vector2::from_floats(node_min.x + padding.x, node_min.y + padding.y));
+    BeginGroup();
+}
+
+pub unsafe fn EndNode(g: &mut AppContext) {
+    EndGroup();
+    pop_win_id_from_stack(g);
+    let content = g.last_item_data.rect;
+    let padding = g.style.WindowPadding;
+    let pin_radius = g.FontSize * 0.25;
+    let editor = g.CurrentNodeEditor.as_mut().unwrap();
+    let node_idx = editor.CurrentNode.take().unwrap(); // IM_ASSERT(): Mismatched BeginNode()/EndNode() calls
+    let node_min = editor.GridToEditor(editor.Nodes[node_idx].Pos);
+    let node_max = Vector2::from_floats(content.max.x + padding.x, content.max.y + padding.y);
+    let title_bar_height = editor.CurrentNodeTitleBarMaxY.take().map(|max_y| max_y - node_min.y).unwrap_or(0.0);
+    let node = &mut editor.Nodes[node_idx];
+    node.Rect = ImRect::from_floats(node_min.x, node_min.y, node_max.x, node_max.y);
+    node.Size = Vector2::from_floats(node_max.x - node_min.x, node_max.y - node_min.y);
+    node.TitleBarHeight = title_bar_height;
+
+    // Pins sit on the edges of the node
+    let hovered_pin = editor.HoveredPin;
+    let mut pin_shapes = Vec::new();
+    for pin in editor.Pins.iter_mut().filter(|pin| pin.NodeIdx == node_idx) {
+        pin.Pos.x = if pin.Kind == ImGuiNodePinKind_Input { node_min.x } else { node_max.x };
+        let radius = if hovered_pin == Some(pin.ID) { pin_radius * 1.5 } else { pin_radius };
+        pin_shapes.push((pin.Pos, radius, NodePinColor(pin.Type)));
+    }
+    let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+    for (pos, radius, col) in pin_shapes {
+        draw_list.AddCircleFilled(&pos, radius, col, 0);
+    }
+}
+
+// Optional: contents submitted in between are drawn over the title bar color
+pub unsafe fn BeginNodeTitleBar(_g: &mut AppContext) {
+    BeginGroup();
+}
+
+pub unsafe fn EndNodeTitleBar(g: &mut AppContext) {
+    EndGroup();
+    let title_max_y = g.last_item_data.rect.max.y;
+    let padding = g.style.WindowPadding;
+    let editor = g.CurrentNodeEditor.as_mut().unwrap();
+    let node_idx = editor.CurrentNode.unwrap(); // IM_ASSERT(): Call BeginNodeTitleBar() between BeginNode() and EndNode()
+    let node_min = editor.GridToEditor(editor.Nodes[node_idx].Pos);
+    editor.CurrentNodeTitleBarMaxY = Some(title_max_y + padding.y * 0.5);
+    set_cursor_screen_pos(g, &Vector2::from_floats(node_min.x + padding.x, title_max_y + padding.y));
+}
+
+unsafe fn BeginNodePin(g: &mut AppContext, pin_id: c_int, kind: ImGuiNodePinKind, pin_type: u32) {
+    let editor = g.CurrentNodeEditor.as_mut().unwrap();
+    // IM_ASSERT(editor.CurrentNode.is_some()); // Call BeginNodeInput()/BeginNodeOutput() between BeginNode() and EndNode()
+    editor.CurrentPin = Some((pin_id, kind, pin_type));
+    push_int_id(g, pin_id);
+    BeginGroup();
+}
+
+unsafe fn EndNodePin(g: &mut AppContext) {
+    EndGroup();
+    pop_win_id_from_stack(g);
+    let rect = g.last_item_data.rect;
+    let editor = g.CurrentNodeEditor.as_mut().unwrap();
+    let (pin_id, kind, pin_type) = editor.CurrentPin.take().unwrap(); // IM_ASSERT(): Mismatched BeginNodeInput()/EndNodeInput() calls
+    let node_idx = editor.CurrentNode.unwrap();
+    // X is set by EndNode(), once the width of the node is known
+    editor.Pins.push(ImGuiNodeEditorPin { ID: pin_id, NodeIdx: node_idx, Kind: kind, Type: pin_type, Pos: Vector2::from_floats(0.0, (rect.min.y + rect.max.y) * 0.5) });
+}
+
+// Pin on the left edge of the node, for the widgets submitted until EndNodeInput(). Links connect pins of the same 'pin_type'.
+pub unsafe fn BeginNodeInput(g: &mut AppContext, pin_id: c_int, pin_type: u32) {
+    BeginNodePin(g, pin_id, ImGuiNodePinKind_Input, pin_type);
+}
+
+pub unsafe fn EndNodeInput(g: &mut AppContext) {
+    EndNodePin(g);
+}
+
+// Pin on the right edge of the node, for the widgets submitted until EndNodeOutput()
+pub unsafe fn BeginNodeOutput(g: &mut AppContext, pin_id: c_int, pin_type: u32) {
+    BeginNodePin(g, pin_id, ImGuiNodePinKind_Output, pin_type);
+}
+
+pub unsafe fn EndNodeOutput(g: &mut AppContext) {
+    EndNodePin(g);
+}
+
+// Link from an output pin to an input pin. Links to pins not submitted this frame are not drawn.
+pub fn NodeLink(g: &mut AppContext, link_id: c_int, start_pin: c_int, end_pin: c_int) {
+    let editor = g.CurrentNodeEditor.as_mut().unwrap(); // IM_ASSERT(): Call NodeLink() between BeginNodeEditor() and EndNodeEditor()
+    editor.Links.push(ImGuiNodeEditorLink { ID: link_id, StartPin: start_pin, EndPin: end_pin });
+}
+
+// Overview of the nodes in a corner of the canvas, clicking or dragging in it moves the view there
+pub fn NodeEditorMiniMap(g: &mut AppContext, size_fraction: c_float, location: ImGuiNodeMiniMapLocation) {
+    let editor = g.CurrentNodeEditor.as_mut().unwrap(); // IM_ASSERT(): Call NodeEditorMiniMap() between BeginNodeEditor() and EndNodeEditor()
+    editor.MiniMap = Some((size_fraction.clamp(0.05, 1.0), location));
+}
+
+//-------------------------------------------------------------------------
+// Queries, after EndNodeEditor()
+//-------------------------------------------------------------------------
+
+fn NodeEditorForQueries(g: &AppContext) -> Option<&ImGuiNodeEditor> {
+    g.CurrentNodeEditor.as_ref().or_else(|| g.NodeEditors.get(&g.NodeEditorLastID))
+}
+
+fn NodeEditorForQueriesMut(g: &mut AppContext) -> Option<&mut ImGuiNodeEditor> {
+    match g.CurrentNodeEditor {
+        Some(ref mut editor) => Some(editor),
+        None => g.NodeEditors.get_mut(&g.NodeEditorLastID),
+    }
+}
+
+// Output pin, input pin of the link the user just dropped. The application adds it to its graph.
+pub fn IsNodeLinkCreated(g: &AppContext) -> Option<(c_int, c_int)> {
+    NodeEditorForQueries(g)?.LinkCreated
+}
+
+// Link the user just detached from its input pin. The application removes it from its graph.
+pub fn IsNodeLinkDestroyed(g: &AppContext) -> Option<c_int> {
+    NodeEditorForQueries(g)?.LinkDestroyed
+}
+
+pub fn IsNodeEditorHovered(g: &AppContext) -> bool {
+    NodeEditorForQueries(g).map_or(false, |editor| editor.Hovered)
+}
+
+pub fn GetHoveredNode(g: &AppContext) -> Option<c_int> {
+    NodeEditorForQueries(g)?.HoveredNode
+}
+
+pub fn GetHoveredLink(g: &AppContext) -> Option<c_int> {
+    NodeEditorForQueries(g)?.HoveredLink
+}
+
+// Selected nodes which were submitted on the last frame
+pub fn GetSelectedNodes(g: &AppContext) -> Vec<c_int> {
+    let frame_count = g.FrameCount;
+    NodeEditorForQueries(g).map_or(Vec::new(), |editor| editor.Nodes.iter().filter(|node| node.Selected && node.LastFrameSeen + 1 >= frame_count).map(|node| node.ID).collect())
+}
+
+pub fn GetSelectedLinks(g: &AppContext) -> Vec<c_int> {
+    NodeEditorForQueries(g).map_or(Vec::new(), |editor| editor.SelectedLinks.clone())
+}
+
+pub fn ClearNodeEditorSelection(g: &mut AppContext) {
+    if let Some(editor) = NodeEditorForQueriesMut(g) {
+        NodeEditorClearSelection(editor);
+    }
+}
+
+// Position of the top-left corner of a node, in grid space. Call before BeginNode() to place a new node.
+pub fn SetNodeGridPos(g: &mut AppContext, node_id: c_int, pos: Vector2) {
+    if let Some(editor) = NodeEditorForQueriesMut(g) {
+        let node_idx = editor.FindOrAddNode(node_id);
+        editor.Nodes[node_idx].Pos = pos;
+    }
+}
+
+pub fn GetNodeGridPos(g: &AppContext, node_id: c_int) -> Option<Vector2> {
+    let editor = NodeEditorForQueries(g)?;
+    editor.FindNode(node_id).map(|node_idx| editor.Nodes[node_idx].Pos)
+}
+
+//-------------------------------------------------------------------------
+// SaveNodeEditorState(), SetNextNodeEditorState()
+//-------------------------------------------------------------------------
+// Text format, one entry per line:
+//   Panning=-120,35.5
+//   Zoom=1.25
+//   Node=12,300,80
+//-------------------------------------------------------------------------
+
+// View and node positions of the current (or last) node editor
+pub fn SaveNodeEditorState(g: &AppContext) -> String {
+    let editor = match NodeEditorForQueries(g) {
+        Some(editor) => editor,
+        None => return String::new(),
+    };
+    let mut out = format!("Panning={},{}\nZoom={}\n", editor.Panning.x, editor.Panning.y, editor.Zoom);
+    for node in editor.Nodes.iter() {
+        out += &format!("Node={},{},{}\n", node.ID, node.Pos.x, node.Pos.y);
+    }
+    out
+}
+
+// Restore a state saved by SaveNodeEditorState() into the next BeginNodeEditor(). Returns false if 'data' is malformed.
+pub fn SetNextNodeEditorState(g: &mut AppContext, data: &str) -> bool {
+    let mut panning = Vector2::from_floats(0.0, 0.0);
+    let mut zoom: c_float = 1.0;
+    let mut node_positions = Vec::new();
+    for line in data.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let (key, value) = match line.split_once('=') {
+            Some(kv) => kv,
+            None => return false,
+        };
+        let values: Vec<&str> = value.split(',').map(str::trim).collect();
+        let floats: Option<Vec<c_float>> = values.iter().map(|v| v.parse::<c_float>().ok().filter(|v| v.is_finite())).collect();
+        match (key, floats.as_deref()) {
+            ("Panning", Some(&[x, y])) => panning = Vector2::from_floats(x, y),
+            ("Zoom", Some(&[z])) if z > 0.0 => zoom = z,
+            ("Node", Some(&[_, x, y])) => match values[0].parse::<c_int>() {
+                Ok(node_id) => node_positions.push((node_id, Vector2::from_floats(x, y))),
+                Err(_) => return false,
+            },
+            ("Panning", _) | ("Zoom", _) | ("Node", _) => return false,
+            _ => {} // Unknown entries are skipped, for forward compatibility
+        }
+    }
+    let next = &mut g.NextNodeEditorData;
+    next.HasState = true;
+    next.Panning = panning;
+    next.Zoom = zoom;
+    next.NodePositions = node_positions;
+    true
+}
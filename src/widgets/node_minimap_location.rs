@@ -0,0 +1,14 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+pub type ImGuiNodeMiniMapLocation = c_int;
+
+// Corner of the canvas where NodeEditorMiniMap() is drawn
+// enum ImGuiNodeMiniMapLocation
+// {
+pub const ImGuiNodeMiniMapLocation_BottomLeft: ImGuiNodeMiniMapLocation = 0;
+pub const ImGuiNodeMiniMapLocation_BottomRight: ImGuiNodeMiniMapLocation = 1;
+pub const ImGuiNodeMiniMapLocation_TopLeft: ImGuiNodeMiniMapLocation = 2;
+pub const ImGuiNodeMiniMapLocation_TopRight: ImGuiNodeMiniMapLocation = 3;
+// };
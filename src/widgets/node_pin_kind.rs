@@ -0,0 +1,11 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+pub type ImGuiNodePinKind = c_int;
+
+// enum ImGuiNodePinKind
+// {
+pub const ImGuiNodePinKind_Input: ImGuiNodePinKind = 0;   // Left side of the node, see BeginNodeInput()
+pub const ImGuiNodePinKind_Output: ImGuiNodePinKind = 1;   // Right side of the node, see BeginNodeOutput()
+// };
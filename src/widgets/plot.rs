@@ -0,0 +1,291 @@
+#![allow(non_snake_case)]
+
+use libc::c_float;
+use crate::core::axis::{IM_GUI_AXIS_X, IM_GUI_AXIS_Y};
+use crate::core::condition::ImGuiCond;
+use crate::core::type_defs::ImguiHandle;
+use crate::core::utils::flag_set;
+use crate::core::vec2::Vector2;
+use crate::rect::ImRect;
+use crate::widgets::plot_axis_flags::{ImGuiPlotAxisFlags, ImGuiPlotAxisFlags_LockMax, ImGuiPlotAxisFlags_LockMin, ImGuiPlotAxisFlags_LogScale, ImGuiPlotAxisFlags_None, ImGuiPlotAxisFlags_Time};
+use crate::widgets::plot_flags::ImGuiPlotFlags;
+use crate::widgets::plot_type::ImGuiPlotType;
+
+// Smallest span an axis can be zoomed to, relative to its magnitude (beyond that f64 can't tell the pixels apart)
+const PLOT_AXIS_MIN_RELATIVE_SPAN: f64 = 1e-12;
+
+// Time axes stay within +-10000 years of 1970: dates keep at most 5 digits and the calendar math stays far from i64 overflow
+pub const PLOT_TIME_AXIS_LIMIT: f64 = 10000.0 * 365.2425 * 86400.0;
+
+// Fraction of the data extents added on each side when fitting
+const PLOT_FIT_PADDING: f64 = 0.05;
+
+// A tick of an axis: grid line, and label for major ticks
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiPlotTick {
+    pub Value: f64,
+    pub Label: String,
+    // Empty for minor ticks
+    pub Major: bool,
+}
+
+// Persistent state of one axis of a plot
+#[derive(Debug, Clone)]
+pub struct ImGuiPlotAxis {
+    pub Min: f64,
+    pub Max: f64,
+    // Visible range, in data units
+    pub Flags: ImGuiPlotAxisFlags,
+    pub Label: String,
+    pub FitMin: f64,
+    pub FitMax: f64,
+    // Extents of the data submitted this frame while fitting (FitMin > FitMax when none)
+    pub PixelMin: c_float,
+    pub PixelMax: c_float,
+    // Screen position of Min and Max. The Y axis goes up: its PixelMin is below its PixelMax.
+}
+
+impl Default for ImGuiPlotAxis {
+    fn default() -> Self {
+        Self {
+            Min: 0.0,
+            Max: 1.0,
+            Flags: ImGuiPlotAxisFlags_None,
+            Label: String::new(),
+            FitMin: f64::INFINITY,
+            FitMax: f64::NEG_INFINITY,
+            PixelMin: 0.0,
+            PixelMax: 1.0,
+        }
+    }
+}
+
+impl ImGuiPlotAxis {
+    pub fn IsLog(&self) -> bool {
+        flag_set(self.Flags, ImGuiPlotAxisFlags_LogScale)
+    }
+
+    pub fn IsTime(&self) -> bool {
+        flag_set(self.Flags, ImGuiPlotAxisFlags_Time)
+    }
+
+    // Data units -> linear units in which the axis is drawn (log10 for log scales)
+    pub fn Forward(&self, v: f64) -> f64 {
+        if self.IsLog() { if v > 0.0 { v.log10() } else { f64::NAN } } else { v }
+    }
+
+    pub fn Inverse(&self, t: f64) -> f64 {
+        if self.IsLog() { 10f64.powf(t) } else { t }
+    }
+
+    // NaN for values the axis can't show (NaN, <= 0 on log scales)
+    pub fn ToPixel(&self, v: f64) -> c_float {
+        let (t0, t1) = (self.Forward(self.Min), self.Forward(self.Max));
+        let t = (self.Forward(v) - t0) / (t1 - t0);
+        (self.PixelMin as f64 + t * (self.PixelMax - self.PixelMin) as f64) as c_float
+    }
+
+    pub fn FromPixel(&self, p: c_float) -> f64 {
+        let (t0, t1) = (self.Forward(self.Min), self.Forward(self.Max));
+        let t = (p - self.PixelMin) as f64 / (self.PixelMax - self.PixelMin) as f64;
+        self.Inverse(t0 + t * (t1 - t0))
+    }
+
+    // Change the visible range, keeping locked ends. Ranges the axis can't display are ignored, time axes are clamped to PLOT_TIME_AXIS_LIMIT.
+    pub fn SetRange(&mut self, mut min: f64, mut max: f64) {
+        if self.IsTime() {
+            min = min.max(-PLOT_TIME_AXIS_LIMIT);
+            max = max.min(PLOT_TIME_AXIS_LIMIT);
+        }
+        if flag_set(self.Flags, ImGuiPlotAxisFlags_LockMin) {
+            min = self.Min;
+        }
+        if flag_set(self.Flags, ImGuiPlotAxisFlags_LockMax) {
+            max = self.Max;
+        }
+        if !min.is_finite() || !max.is_finite() || min >= max || (self.IsLog() && min <= 0.0) {
+            return;
+        }
+        if max - min <= min.abs().max(max.abs()) * PLOT_AXIS_MIN_RELATIVE_SPAN {
+            return;
+        }
+        self.Min = min;
+        self.Max = max;
+    }
+
+    // Pan by a distance in pixels. Locked axes don't pan (that would zoom them).
+    pub fn Pan(&mut self, delta: c_float) {
+        if flag_set(self.Flags, ImGuiPlotAxisFlags_LockMin) || flag_set(self.Flags, ImGuiPlotAxisFlags_LockMax) {
+            return;
+        }
+        let (mut min, mut max) = (self.FromPixel(self.PixelMin - delta), self.FromPixel(self.PixelMax - delta));
+        if self.IsTime() {
+            // Stop at the limit instead of letting SetRange() clamp one end, which would zoom
+            let shift = (-PLOT_TIME_AXIS_LIMIT - min).max(0.0) + (PLOT_TIME_AXIS_LIMIT - max).min(0.0);
+            min += shift;
+            max += shift;
+        }
+        self.SetRange(min, max);
+    }
+
+    // Zoom around a screen position: 'zoom' < 1.0 zooms in
+    pub fn Zoom(&mut self, pixel: c_float, zoom: f64) {
+        let (t0, t1) = (self.Forward(self.Min), self.Forward(self.Max));
+        let tm = self.Forward(self.FromPixel(pixel));
+        let (min, max) = (self.Inverse(tm - (tm - t0) * zoom), self.Inverse(tm + (t1 - tm) * zoom));
+        self.SetRange(min, max);
+    }
+
+    pub fn BeginFit(&mut self) {
+        self.FitMin = f64::INFINITY;
+        self.FitMax = f64::NEG_INFINITY;
+    }
+
+    pub fn ExtendFit(&mut self, v: f64) {
+        if !v.is_finite() || (self.IsLog() && v <= 0.0) {
+            return;
+        }
+        self.FitMin = self.FitMin.min(v);
+        self.FitMax = self.FitMax.max(v);
+    }
+
+    // Set the range to the extents collected since BeginFit(), with some padding
+    pub fn ApplyFit(&mut self) {
+        if self.FitMin > self.FitMax {
+            return;
+        }
+        let (mut t0, mut t1) = (self.Forward(self.FitMin), self.Forward(self.FitMax));
+        if t1 - t0 <= t0.abs().max(t1.abs()) * PLOT_AXIS_MIN_RELATIVE_SPAN {
+            // Single value: center it
+            let half = if t0 == 0.0 || self.IsLog() { 0.5 } else { t0.abs() * 0.1 };
+            t0 -= half;
+            t1 += half;
+        } else {
+            let pad = (t1 - t0) * PLOT_FIT_PADDING;
+            t0 -= pad;
+            t1 += pad;
+        }
+        self.SetRange(self.Inverse(t0), self.Inverse(t1));
+    }
+}
+
+// A series of a plot, identified by its label
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiPlotItem {
+    pub ID: ImguiHandle,
+    pub Label: String,
+    pub PlotType: ImGuiPlotType,
+    pub Color: u32,
+    pub Show: bool,
+    // Toggled by clicking its legend entry
+    pub LastFrameSeen: usize,
+    pub XSortedKey: Option<(usize, usize, u64, u64)>,
+    // Pointer, length, first and last X value of the slice XSorted was computed for
+    pub XSorted: bool,
+}
+
+// Persistent state of a BeginPlot()/EndPlot() scope
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiPlot {
+    pub ID: ImguiHandle,
+    pub Flags: ImGuiPlotFlags,
+    pub Axes: [ImGuiPlotAxis; 2],
+    // Indexed by IM_GUI_AXIS_X, IM_GUI_AXIS_Y
+    pub Items: Vec<ImGuiPlotItem>,
+    pub FrameRect: ImRect,
+    pub PlotRect: ImRect,
+    // Area where items are drawn
+    pub LegendRect: ImRect,
+    // As of the last EndPlot()
+    pub LegendHoveredItem: ImguiHandle,
+    // Item highlighted because its legend entry is hovered
+    pub FitRequest: [bool; 2],
+    // Fit on this frame (first use, double-click)
+    pub Fitting: [bool; 2],
+    // Fitting this frame: items extend the fit extents of the axis
+    pub Hovered: bool,
+    // Mouse over the plot area this frame
+    pub HeldAxes: [bool; 2],
+    // Axes panned by the active left-drag
+    pub BoxSelecting: bool,
+    pub BoxSelectStart: Vector2,
+    pub NextItemColor: Option<u32>,
+    // Set by SetNextPlotItemColor()
+}
+
+impl ImGuiPlot {
+    pub fn new(id: ImguiHandle) -> Self {
+        Self {
+            ID: id,
+            FitRequest: [true, true],
+            ..Default::default()
+        }
+    }
+
+    pub fn FindItem(&self, id: ImguiHandle) -> Option<usize> {
+        self.Items.iter().position(|item| item.ID == id)
+    }
+
+    pub fn Transform(&self) -> ImGuiPlotTransform {
+        ImGuiPlotTransform::new(&self.Axes[IM_GUI_AXIS_X as usize], &self.Axes[IM_GUI_AXIS_Y as usize])
+    }
+}
+
+// Data -> screen mapping of both axes, with the per-axis terms computed once for all the points of a series
+#[derive(Debug, Clone, Copy)]
+pub struct ImGuiPlotTransform {
+    pub Log: [bool; 2],
+    pub Origin: [f64; 2],
+    // Forward(Min) of each axis
+    pub Scale: [f64; 2],
+    // Pixels per forward unit
+    pub PixelOrigin: [f64; 2],
+}
+
+impl ImGuiPlotTransform {
+    pub fn new(axis_x: &ImGuiPlotAxis, axis_y: &ImGuiPlotAxis) -> Self {
+        let mut transform = Self { Log: [false; 2], Origin: [0.0; 2], Scale: [0.0; 2], PixelOrigin: [0.0; 2] };
+        for (n, axis) in [axis_x, axis_y].into_iter().enumerate() {
+            let (t0, t1) = (axis.Forward(axis.Min), axis.Forward(axis.Max));
+            transform.Log[n] = axis.IsLog();
+            transform.Origin[n] = t0;
+            transform.Scale[n] = (axis.PixelMax - axis.PixelMin) as f64 / (t1 - t0);
+            transform.PixelOrigin[n] = axis.PixelMin as f64;
+        }
+        transform
+    }
+
+    // Screen position of a point, None for points the axes can't show (NaN, <= 0 on log scales)
+    #[inline]
+    pub fn ToPixel(&self, x: f64, y: f64) -> Option<Vector2> {
+        let tx = if self.Log[0] { if x > 0.0 { x.log10() } else { return None } } else { x };
+        let ty = if self.Log[1] { if y > 0.0 { y.log10() } else { return None } } else { y };
+        let px = (self.PixelOrigin[0] + (tx - self.Origin[0]) * self.Scale[0]) as c_float;
+        let py = (self.PixelOrigin[1] + (ty - self.Origin[1]) * self.Scale[1]) as c_float;
+        if px.is_finite() && py.is_finite() { Some(Vector2::from_floats(px, py)) } else { None }
+    }
+}
+
+// Setup of one axis, see SetNextPlotAxis() and SetNextPlotAxisLimits()
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiNextPlotAxisData {
+    pub HasSetup: bool,
+    pub Label: String,
+    pub Flags: ImGuiPlotAxisFlags,
+    pub HasLimits: bool,
+    pub Min: f64,
+    pub Max: f64,
+    pub LimitsCond: ImGuiCond,
+}
+
+// Storage for SetNextPlotXXX() functions, consumed by the next BeginPlot()
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiNextPlotData {
+    pub Axes: [ImGuiNextPlotAxisData; 2],
+}
+
+impl ImGuiNextPlotData {
+    pub fn ClearFlags(&mut self) {
+        *self = Self::default();
+    }
+}
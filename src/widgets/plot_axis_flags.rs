@@ -0,0 +1,20 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+// typedef int ImGuiPlotAxisFlags;      // -> enum ImGuiPlotAxisFlags_   // Flags: for SetNextPlotAxis()
+pub type ImGuiPlotAxisFlags = c_int;
+
+// Flags for SetNextPlotAxis()
+// enum ImGuiPlotAxisFlags_
+// {
+pub const ImGuiPlotAxisFlags_None: ImGuiPlotAxisFlags = 0;
+pub const ImGuiPlotAxisFlags_NoGridLines: ImGuiPlotAxisFlags = 1 << 0;   // Don't draw grid lines.
+pub const ImGuiPlotAxisFlags_NoTickLabels: ImGuiPlotAxisFlags = 1 << 1;   // Don't draw tick labels (and don't reserve space for them).
+pub const ImGuiPlotAxisFlags_LockMin: ImGuiPlotAxisFlags = 1 << 2;   // The minimum stays put when panning, zooming or fitting.
+pub const ImGuiPlotAxisFlags_LockMax: ImGuiPlotAxisFlags = 1 << 3;   // The maximum stays put when panning, zooming or fitting.
+pub const ImGuiPlotAxisFlags_AutoFit: ImGuiPlotAxisFlags = 1 << 4;   // Fit the data every frame. Y axes only fit to the data within the visible X range.
+pub const ImGuiPlotAxisFlags_LogScale: ImGuiPlotAxisFlags = 1 << 5;   // Base 10 logarithmic scale. Values <= 0 are not drawn.
+pub const ImGuiPlotAxisFlags_Time: ImGuiPlotAxisFlags = 1 << 6;   // Values are UNIX timestamps in seconds (UTC), ticks are dates/times. X axis only.
+pub const ImGuiPlotAxisFlags_Lock: ImGuiPlotAxisFlags = ImGuiPlotAxisFlags_LockMin | ImGuiPlotAxisFlags_LockMax;
+// };
@@ -0,0 +1,19 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+// typedef int ImGuiPlotFlags;      // -> enum ImGuiPlotFlags_   // Flags: for BeginPlot()
+pub type ImGuiPlotFlags = c_int;
+
+// Flags for BeginPlot()
+// enum ImGuiPlotFlags_
+// {
+pub const ImGuiPlotFlags_None: ImGuiPlotFlags = 0;
+pub const ImGuiPlotFlags_NoTitle: ImGuiPlotFlags = 1 << 0;   // Don't display the title (the label up to "##").
+pub const ImGuiPlotFlags_NoLegend: ImGuiPlotFlags = 1 << 1;   // Don't display the legend.
+pub const ImGuiPlotFlags_NoMouseText: ImGuiPlotFlags = 1 << 2;   // Don't display the coordinates of the mouse while hovering the plot area.
+pub const ImGuiPlotFlags_NoInputs: ImGuiPlotFlags = 1 << 3;   // No panning, zooming, fitting or legend toggling.
+pub const ImGuiPlotFlags_NoFrame: ImGuiPlotFlags = 1 << 4;   // Don't draw the frame background.
+pub const ImGuiPlotFlags_NoBoxSelect: ImGuiPlotFlags = 1 << 5;   // Right-drag doesn't zoom to the dragged rectangle.
+pub const ImGuiPlotFlags_Crosshairs: ImGuiPlotFlags = 1 << 6;   // Draw crosshairs at the mouse position while hovering the plot area.
+// };
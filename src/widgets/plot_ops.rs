@@ -0,0 +1,1006 @@
+#![allow(non_snake_case)]
+
+//-------------------------------------------------------------------------
+// [SECTION] Widgets: BeginPlot, EndPlot, PlotLine, PlotScatter, PlotBars, PlotShaded, PlotStairs
+//-------------------------------------------------------------------------
+// - SetNextPlotAxis()
+// - SetNextPlotAxisLimits()
+// - SetNextPlotItemColor()
+// - BeginPlot()
+// - EndPlot()
+// - GetPlotMousePos()
+// - PlotLine(), PlotLineValues()
+// - PlotScatter()
+// - PlotBars()
+// - PlotShaded()
+// - PlotStairs()
+// - PlotItemEx() [Internal]
+// - PlotCalcTicks() [Internal]
+// - PlotFormatTime() [Internal]
+//-------------------------------------------------------------------------
+// Plots with several series, axes (linear, log10 or time), a legend, and mouse navigation:
+// - Left-drag pans, mouse wheel zooms around the mouse, right-drag zooms to a rectangle, double-click fits the data.
+//   Over the tick labels of an axis, those only apply to that axis.
+// - Clicking a legend entry hides/shows its series, hovering it highlights the series.
+// - Series are identified by their label, "##" hides the rest of the label from the legend as usual.
+// - When 'xs' is increasing only the visible points are processed, and lines are reduced to at most 4 points per pixel
+//   column, so series of millions of points stay cheap to draw. Other series are drawn point by point in their order.
+//   Whether 'xs' is increasing is checked once per slice: when rewriting it in place with the same length and end values,
+//   keep it sorted (or use a new label).
+// Usage:
+//   SetNextPlotAxis(g, IM_GUI_AXIS_X, "time", ImGuiPlotAxisFlags_Time);
+//   SetNextPlotAxis(g, IM_GUI_AXIS_Y, "ms", ImGuiPlotAxisFlags_AutoFit);
+//   if BeginPlot(g, "Frame times", Vector2::from_floats(-1.0, 0.0), ImGuiPlotFlags_None) {
+//       PlotLine(g, "cpu", &timestamps, &cpu_ms);
+//       PlotShaded(g, "gpu", &timestamps, &gpu_ms, 0.0);
+//       EndPlot(g);
+//   }
+//-------------------------------------------------------------------------
+
+use libc::c_float;
+use crate::color::{color_u32_from_rgba, IM_COL32_A_MASK, IM_COL32_A_SHIFT, ImGuiCol_Border, ImGuiCol_FrameBg, ImGuiCol_NavHighlight, ImGuiCol_PopupBg, ImGuiCol_Text, ImGuiCol_TextDisabled};
+use crate::core::axis::{ImGuiAxis, IM_GUI_AXIS_X, IM_GUI_AXIS_Y};
+use crate::core::condition::{ImGuiCond, ImGuiCond_Always, ImGuiCond_None};
+use crate::core::context::AppContext;
+use crate::core::hash_ops::hash_string;
+use crate::core::id_ops::{ClearActiveID, SetActiveID};
+use crate::core::math_ops::{ImCivilFromDays, ImDaysFromCivil};
+use crate::core::type_defs::ImguiHandle;
+use crate::core::utils::flag_set;
+use crate::core::vec2::Vector2;
+use crate::drawing::draw_flags::ImDrawFlags_None;
+use crate::drawing::render_ops::RenderFrame;
+use crate::item::item_ops::{CalcItemSize, CalcItemWidth, ItemAdd, ItemHoverable, ItemSize, SetItemUsingMouseWheel};
+use crate::rect::ImRect;
+use crate::style::style_ops::GetColorU32;
+use crate::text::text_ops::CalcTextSize;
+use crate::widgets::plot::{ImGuiPlot, ImGuiPlotAxis, ImGuiPlotItem, ImGuiPlotTick, ImGuiPlotTransform, PLOT_TIME_AXIS_LIMIT};
+use crate::widgets::plot_axis_flags::{ImGuiPlotAxisFlags, ImGuiPlotAxisFlags_AutoFit, ImGuiPlotAxisFlags_LogScale, ImGuiPlotAxisFlags_NoGridLines, ImGuiPlotAxisFlags_NoTickLabels, ImGuiPlotAxisFlags_Time};
+use crate::widgets::plot_flags::{ImGuiPlotFlags, ImGuiPlotFlags_Crosshairs, ImGuiPlotFlags_NoBoxSelect, ImGuiPlotFlags_NoFrame, ImGuiPlotFlags_NoInputs, ImGuiPlotFlags_NoLegend, ImGuiPlotFlags_NoMouseText, ImGuiPlotFlags_NoTitle};
+use crate::widgets::plot_type::{ImGuiPlotType, ImGuiPlotType_Histogram, ImGuiPlotType_Lines, ImGuiPlotType_Scatter, ImGuiPlotType_Shaded, ImGuiPlotType_Stairs};
+
+// Default series colors, assigned in submission order
+const PLOT_COLORMAP: [(u32, u32, u32); 10] = [
+    (76, 114, 176), (221, 132, 82), (85, 168, 104), (196, 78, 82), (129, 114, 179),
+    (147, 120, 96), (218, 139, 195), (140, 140, 140), (204, 185, 116), (100, 181, 205),
+];
+
+// Range kept per notch of mouse wheel
+const PLOT_ZOOM_RATE: f64 = 0.9;
+
+// Beyond this many points per pixel column, lines and bars are reduced per column
+const PLOT_DECIMATE_POINTS_PER_PIXEL: usize = 4;
+
+// Scatter plots with more points only draw one marker per pixel
+const PLOT_SCATTER_DEDUPE_MIN_COUNT: usize = 4096;
+
+const PLOT_MAX_TICKS: usize = 1000;
+
+// Fixed time steps, in seconds. Larger steps are whole months/years.
+const PLOT_TIME_STEPS: [f64; 29] = [
+    0.001, 0.002, 0.005, 0.01, 0.02, 0.05, 0.1, 0.2, 0.5,
+    1.0, 2.0, 5.0, 10.0, 15.0, 30.0,
+    60.0, 120.0, 300.0, 600.0, 900.0, 1800.0,
+    3600.0, 7200.0, 10800.0, 21600.0, 43200.0,
+    86400.0, 172800.0, 604800.0,
+];
+const PLOT_TIME_MONTH_STEPS: [i64; 5] = [1, 2, 3, 6, 12];
+const PLOT_SECONDS_PER_DAY: f64 = 86400.0;
+const PLOT_SECONDS_PER_MONTH: f64 = 2629746.0; // Average Gregorian month
+
+fn PlotColorMul(col: u32, alpha_mul: c_float) -> u32 {
+    let a = ((col & IM_COL32_A_MASK) >> IM_COL32_A_SHIFT) as c_float * alpha_mul;
+    (col & !IM_COL32_A_MASK) | ((a as u32).min(255) << IM_COL32_A_SHIFT)
+}
+
+fn PlotLabelDisplay(label_id: &str) -> &str {
+    &label_id[..label_id.find("##").unwrap_or(label_id.len())]
+}
+
+//-------------------------------------------------------------------------
+// Ticks
+//-------------------------------------------------------------------------
+
+// Smallest of 1, 2, 5 x 10^n not below 'x'
+fn PlotNiceNumber(x: f64) -> f64 {
+    let exp = x.log10().floor();
+    let base = 10f64.powf(exp);
+    let f = x / base;
+    let nice = if f <= 1.0 { 1.0 } else if f <= 2.0 { 2.0 } else if f <= 5.0 { 5.0 } else { 10.0 };
+    nice * base
+}
+
+// Enough decimals to tell apart values 'step' apart
+fn PlotFormatNumber(v: f64, step: f64) -> String {
+    if v == 0.0 || !v.is_finite() {
+        return format!("{}", if v == 0.0 { 0.0 } else { v });
+    }
+    let step = step.abs().max(f64::MIN_POSITIVE);
+    if v.abs() >= 1e7 || v.abs() < 1e-5 {
+        let digits = (v.abs().log10().floor() - step.log10().floor()).clamp(0.0, 15.0) as usize;
+        return format!("{:.*e}", digits, v);
+    }
+    let decimals = (-step.log10().floor()).clamp(0.0, 15.0) as usize;
+    let s = format!("{:.*}", decimals, v);
+    if s.starts_with('-') && s[1..].chars().all(|c| c == '0' || c == '.') { s[1..].to_string() } else { s }
+}
+
+fn PlotCalcTicksLinear(axis: &ImGuiPlotAxis, max_ticks: f64, out: &mut Vec<ImGuiPlotTick>) {
+    let step = PlotNiceNumber((axis.Max - axis.Min) / max_ticks);
+    if !(step > 0.0) || !step.is_finite() {
+        return;
+    }
+    let mantissa = (step / 10f64.powf(step.log10().floor())).round();
+    let minor_divs = if mantissa == 2.0 { 4 } else { 5 };
+    let k0 = (axis.Min / step).floor() as i64;
+    let k1 = (axis.Max / step).ceil() as i64;
+    for k in k0..=k1 {
+        if out.len() >= PLOT_MAX_TICKS {
+            break;
+        }
+        let v = k as f64 * step;
+        if v >= axis.Min && v <= axis.Max {
+            out.push(ImGuiPlotTick { Value: v, Label: PlotFormatNumber(v, step), Major: true });
+        }
+        for j in 1..minor_divs {
+            let vm = v + step * j as f64 / minor_divs as f64;
+            if vm >= axis.Min && vm <= axis.Max {
+                out.push(ImGuiPlotTick { Value: vm, Label: String::new(), Major: false });
+            }
+        }
+    }
+}
+
+// Major ticks on powers of 10 (every few when there isn't room), minor ticks on 2..9 x 10^n
+fn PlotCalcTicksLog(axis: &ImGuiPlotAxis, max_ticks: f64, out: &mut Vec<ImGuiPlotTick>) {
+    let d0 = axis.Min.log10().floor() as i64;
+    let d1 = axis.Max.log10().ceil() as i64;
+    let decades = (d1 - d0).max(1);
+    let label_stride = ((decades as f64 / max_ticks).ceil() as i64).max(1);
+    for d in d0..=d1 {
+        if out.len() >= PLOT_MAX_TICKS {
+            break;
+        }
+        let v = 10f64.powi(d as i32);
+        if v >= axis.Min && v <= axis.Max {
+            let major = d.rem_euclid(label_stride) == 0;
+            let label = if !major { String::new() } else if (-4..=6).contains(&d) { PlotFormatNumber(v, v) } else { format!("1e{}", d) };
+            out.push(ImGuiPlotTick { Value: v, Label: label, Major: major });
+        }
+        if label_stride == 1 {
+            for m in 2..10 {
+                let vm = v * m as f64;
+                if vm >= axis.Min && vm <= axis.Max {
+                    out.push(ImGuiPlotTick { Value: vm, Label: String::new(), Major: false });
+                }
+            }
+        }
+    }
+}
+
+fn PlotCalcTicksTime(axis: &ImGuiPlotAxis, max_ticks: f64, out: &mut Vec<ImGuiPlotTick>) {
+    // SetRange() keeps time axes within the limit, clamp anyway so the calendar math below can't overflow
+    let (min, max) = (axis.Min.max(-PLOT_TIME_AXIS_LIMIT), axis.Max.min(PLOT_TIME_AXIS_LIMIT));
+    if !(max > min) {
+        return;
+    }
+    let raw_step = (max - min) / max_ticks;
+    if let Some(&step) = PLOT_TIME_STEPS.iter().find(|&&step| step >= raw_step) {
+        let k0 = (min / step).ceil() as i64;
+        let k1 = (max / step).floor() as i64;
+        for k in k0..=k1 {
+            if out.len() >= PLOT_MAX_TICKS {
+                break;
+            }
+            let v = k as f64 * step;
+            out.push(ImGuiPlotTick { Value: v, Label: PlotFormatTime(v, step), Major: true });
+        }
+        return;
+    }
+
+    // Whole months, then whole years
+    let raw_months = raw_step / PLOT_SECONDS_PER_MONTH;
+    let months = match PLOT_TIME_MONTH_STEPS.iter().find(|&&months| months as f64 >= raw_months) {
+        Some(&months) => months,
+        None => (PlotNiceNumber(raw_months / 12.0) * 12.0) as i64,
+    };
+    let (y, m, _) = ImCivilFromDays((min / PLOT_SECONDS_PER_DAY).floor() as i64);
+    let mut month_idx = (y * 12 + m - 1 + months - 1).div_euclid(months) * months;
+    while out.len() < PLOT_MAX_TICKS {
+        let v = ImDaysFromCivil(month_idx.div_euclid(12), month_idx.rem_euclid(12) + 1, 1) as f64 * PLOT_SECONDS_PER_DAY;
+        if v > max {
+            break;
+        }
+        if v >= min {
+            out.push(ImGuiPlotTick { Value: v, Label: PlotFormatTime(v, months as f64 * PLOT_SECONDS_PER_MONTH), Major: true });
+        }
+        month_idx += months;
+    }
+}
+
+// Grid lines and labels of an axis, about one label per 'label_spacing' pixels
+pub fn PlotCalcTicks(axis: &ImGuiPlotAxis, pixels: c_float, label_spacing: c_float) -> Vec<ImGuiPlotTick> {
+    let mut ticks = Vec::new();
+    if !(axis.Max > axis.Min) {
+        return ticks;
+    }
+    let max_ticks = ((pixels / label_spacing).floor() as f64).max(2.0);
+    if flag_set(axis.Flags, ImGuiPlotAxisFlags_Time) {
+        PlotCalcTicksTime(axis, max_ticks, &mut ticks);
+    } else if axis.IsLog() {
+        PlotCalcTicksLog(axis, max_ticks, &mut ticks);
+    } else {
+        PlotCalcTicksLinear(axis, max_ticks, &mut ticks);
+    }
+    ticks
+}
+
+//-------------------------------------------------------------------------
+// Time axes: UNIX timestamps in seconds, UTC
+//-------------------------------------------------------------------------
+
+// Label of a time tick: only what changes between ticks 'step' seconds apart. Sub-day ticks at midnight show the date.
+pub fn PlotFormatTime(t: f64, step: f64) -> String {
+    let t = t.clamp(-PLOT_TIME_AXIS_LIMIT, PLOT_TIME_AXIS_LIMIT);
+    let secs = t.floor();
+    let ms = (((t - secs) * 1000.0).round() as i64).min(999);
+    let days = (secs as i64).div_euclid(86400);
+    let sod = (secs as i64).rem_euclid(86400);
+    let (y, m, d) = ImCivilFromDays(days);
+    let (hh, mm, ss) = (sod / 3600, sod / 60 % 60, sod % 60);
+    if step < 1.0 {
+        format!("{:02}:{:02}:{:02}.{:03}", hh, mm, ss, ms)
+    } else if step < 60.0 {
+        format!("{:02}:{:02}:{:02}", hh, mm, ss)
+    } else if step < PLOT_SECONDS_PER_DAY && sod != 0 {
+        format!("{:02}:{:02}", hh, mm)
+    } else if step < PLOT_SECONDS_PER_MONTH {
+        format!("{:04}-{:02}-{:02}", y, m, d)
+    } else if step < PLOT_SECONDS_PER_MONTH * 12.0 {
+        format!("{:04}-{:02}", y, m)
+    } else {
+        format!("{:04}", y)
+    }
+}
+
+// Value under the mouse, with the precision of one pixel
+fn PlotFormatMouseValue(axis: &ImGuiPlotAxis, v: f64) -> String {
+    let pixel_step = (axis.FromPixel(axis.PixelMin + 1.0) - axis.FromPixel(axis.PixelMin)).abs();
+    if flag_set(axis.Flags, ImGuiPlotAxisFlags_Time) {
+        return format!("{} {}", PlotFormatTime(v, PLOT_SECONDS_PER_DAY), PlotFormatTime(v, if pixel_step < 1.0 { 0.001 } else { 1.0 }));
+    }
+    if axis.IsLog() {
+        let decades_per_pixel = (axis.Max.log10() - axis.Min.log10()) / (axis.PixelMax - axis.PixelMin).abs() as f64;
+        return PlotFormatNumber(v, v * (10f64.powf(decades_per_pixel) - 1.0));
+    }
+    PlotFormatNumber(v, pixel_step)
+}
+
+//-------------------------------------------------------------------------
+// Setup
+//-------------------------------------------------------------------------
+
+// Label and flags of an axis of the next BeginPlot(). Call every frame: axes without setup have no label and no flags.
+pub fn SetNextPlotAxis(g: &mut AppContext, axis: ImGuiAxis, label: &str, flags: ImGuiPlotAxisFlags) {
+    let next = &mut g.NextPlotData.Axes[axis as usize];
+    next.HasSetup = true;
+    next.Label = String::from(label);
+    next.Flags = flags;
+}
+
+// Visible range of an axis of the next BeginPlot(). With ImGuiCond_Once/ImGuiCond_FirstUseEver/ImGuiCond_Appearing, only when the plot is first created.
+pub fn SetNextPlotAxisLimits(g: &mut AppContext, axis: ImGuiAxis, min: f64, max: f64, cond: ImGuiCond) {
+    let next = &mut g.NextPlotData.Axes[axis as usize];
+    next.HasLimits = true;
+    next.Min = min;
+    next.Max = max;
+    next.LimitsCond = cond;
+}
+
+// Color of the next series of the current plot (otherwise picked from the default colors when a series first appears)
+pub fn SetNextPlotItemColor(g: &mut AppContext, col: u32) {
+    // IM_ASSERT(g.CurrentPlot.is_some()); // Call between BeginPlot() and EndPlot()
+    if let Some(plot) = g.CurrentPlot.as_mut() {
+        plot.NextItemColor = Some(col);
+    }
+}
+
+//-------------------------------------------------------------------------
+// BeginPlot(), EndPlot()
+//-------------------------------------------------------------------------
+
+// Only call EndPlot() if BeginPlot() returns true
+pub unsafe fn BeginPlot(g: &mut AppContext, title_id: &str, size_arg: Vector2, flags: ImGuiPlotFlags) -> bool {
+    // IM_ASSERT(g.CurrentPlot.is_none()); // Nesting BeginPlot()/EndPlot() is not supported
+    let (skip_items, seed, window_id) = {
+        let window = g.current_window_mut().unwrap();
+        (window.skip_items, window.id_stack.last().unwrap().clone(), window.ID)
+    };
+    if skip_items {
+        g.NextPlotData.ClearFlags();
+        return false;
+    }
+    let id = hash_string(title_id, seed);
+    let style_frame_padding = g.style.FramePadding;
+    let style_inner_spacing = g.style.ItemInnerSpacing;
+    let font_size = g.FontSize;
+    let item_width = CalcItemWidth(g);
+    let frame_size = CalcItemSize(g, size_arg, item_width, font_size * 20.0);
+    let cursor_pos = g.current_window_mut().unwrap().dc.cursor_pos;
+    let mut frame_bb = ImRect::from_floats(cursor_pos.x, cursor_pos.y, cursor_pos.x + frame_size.x, cursor_pos.y + frame_size.y);
+    ItemSize(g, &frame_size, style_frame_padding.y);
+    if !ItemAdd(g, &mut frame_bb, id, None, 0) {
+        g.NextPlotData.ClearFlags();
+        return false;
+    }
+
+    // Persistent state, moved to g.CurrentPlot until EndPlot()
+    let just_created = !g.Plots.contains_key(&id);
+    let mut plot = g.Plots.remove(&id).unwrap_or_else(|| ImGuiPlot::new(id));
+    plot.Flags = flags;
+    for axis_n in 0..2 {
+        let next = &g.NextPlotData.Axes[axis_n];
+        let axis = &mut plot.Axes[axis_n];
+        axis.Label = if next.HasSetup { next.Label.clone() } else { String::new() };
+        axis.Flags = if next.HasSetup { next.Flags } else { 0 };
+        if axis_n == IM_GUI_AXIS_Y as usize {
+            axis.Flags &= !ImGuiPlotAxisFlags_Time;
+        }
+        if flag_set(axis.Flags, ImGuiPlotAxisFlags_Time) {
+            axis.Flags &= !ImGuiPlotAxisFlags_LogScale;
+        }
+        let (mut next_min, mut next_max) = (next.Min, next.Max);
+        if flag_set(axis.Flags, ImGuiPlotAxisFlags_Time) {
+            next_min = next_min.max(-PLOT_TIME_AXIS_LIMIT);
+            next_max = next_max.min(PLOT_TIME_AXIS_LIMIT);
+        }
+        if next.HasLimits && next_min < next_max && (just_created || next.LimitsCond == ImGuiCond_None || next.LimitsCond == ImGuiCond_Always) {
+            // Bypass SetRange(): explicit limits ignore locks
+            axis.Min = next_min;
+            axis.Max = next_max;
+            plot.FitRequest[axis_n] = false;
+        }
+        if axis.IsLog() && axis.Min <= 0.0 {
+            // Switched to a log scale with a range it can't show
+            axis.Max = axis.Max.max(10.0);
+            axis.Min = axis.Max * 1e-3;
+            plot.FitRequest[axis_n] = true;
+        }
+    }
+    g.NextPlotData.ClearFlags();
+
+    // Layout: [title / y label] on top, [x tick labels] [x label] below, [y tick labels] on the left
+    let title = PlotLabelDisplay(title_id);
+    let show_title = !title.is_empty() && !flag_set(flags, ImGuiPlotFlags_NoTitle);
+    let x_tick_labels = !flag_set(plot.Axes[IM_GUI_AXIS_X as usize].Flags, ImGuiPlotAxisFlags_NoTickLabels);
+    let y_tick_labels = !flag_set(plot.Axes[IM_GUI_AXIS_Y as usize].Flags, ImGuiPlotAxisFlags_NoTickLabels);
+    let x_label = plot.Axes[IM_GUI_AXIS_X as usize].Label.clone();
+    let y_label = plot.Axes[IM_GUI_AXIS_Y as usize].Label.clone();
+    let line_h = font_size + style_inner_spacing.y;
+    let top_h = if show_title || !y_label.is_empty() { line_h } else { 0.0 };
+    let bottom_h = (if x_tick_labels { line_h } else { 0.0 }) + (if !x_label.is_empty() { line_h } else { 0.0 });
+    let inner = ImRect::from_floats(frame_bb.min.x + style_frame_padding.x, frame_bb.min.y + style_frame_padding.y, frame_bb.max.x - style_frame_padding.x, frame_bb.max.y - style_frame_padding.y);
+    let plot_top = inner.min.y + top_h;
+    let plot_bottom = (inner.max.y - bottom_h).max(plot_top + 1.0);
+    let y_label_spacing = font_size * 2.5;
+    let y_ticks_w = if y_tick_labels {
+        // The labels of the Y ticks decide the left margin (the ticks are computed again after inputs changed the range)
+        let axis_y = &mut plot.Axes[IM_GUI_AXIS_Y as usize];
+        axis_y.PixelMin = plot_bottom;
+        axis_y.PixelMax = plot_top;
+        let ticks = PlotCalcTicks(axis_y, plot_bottom - plot_top, y_label_spacing);
+        ticks.iter().filter(|tick| tick.Major).map(|tick| CalcTextSize(g, &tick.Label, false, 0.0).x).fold(0.0, c_float::max) + style_inner_spacing.x
+    } else {
+        0.0
+    };
+    let plot_rect = ImRect::from_floats(inner.min.x + y_ticks_w, plot_top, (inner.max.x).max(inner.min.x + y_ticks_w + 1.0), plot_bottom);
+    {
+        let (axis_x, axis_y) = plot.Axes.split_at_mut(1);
+        axis_x[0].PixelMin = plot_rect.min.x;
+        axis_x[0].PixelMax = plot_rect.max.x;
+        axis_y[0].PixelMin = plot_rect.max.y;
+        axis_y[0].PixelMax = plot_rect.min.y;
+    }
+
+    // Inputs
+    let hovered = ItemHoverable(&frame_bb, id);
+    SetItemUsingMouseWheel();
+    let mouse = g.IO.MousePos;
+    let mouse_in_x = mouse.x >= plot_rect.min.x && mouse.x <= plot_rect.max.x;
+    let mouse_in_y = mouse.y >= plot_rect.min.y && mouse.y <= plot_rect.max.y;
+    plot.Hovered = hovered && mouse_in_x && mouse_in_y;
+    if g.ActiveId != id {
+        plot.BoxSelecting = false;
+    }
+    if !flag_set(flags, ImGuiPlotFlags_NoInputs) {
+        let legend_hovered = plot.Hovered && plot.LegendRect.Contains(&mouse);
+        let axes_hovered = [
+            plot.Hovered || (hovered && mouse_in_x && mouse.y > plot_rect.max.y),
+            plot.Hovered || (hovered && mouse_in_y && mouse.x < plot_rect.min.x),
+        ];
+        if (axes_hovered[0] || axes_hovered[1]) && !legend_hovered && g.IO.MouseClicked[0] {
+            SetActiveID(g, id, None);
+            g.ActiveIdWindow = window_id;
+            plot.HeldAxes = axes_hovered;
+            if g.IO.MouseClickedCount[0] == 2 {
+                for axis_n in 0..2 {
+                    plot.FitRequest[axis_n] |= axes_hovered[axis_n];
+                }
+            }
+        }
+        if plot.Hovered && !legend_hovered && !flag_set(flags, ImGuiPlotFlags_NoBoxSelect) && g.IO.MouseClicked[1] {
+            SetActiveID(g, id, None);
+            g.ActiveIdWindow = window_id;
+            plot.HeldAxes = [false, false];
+            plot.BoxSelecting = true;
+            plot.BoxSelectStart = mouse;
+        }
+        if g.ActiveId == id {
+            if plot.BoxSelecting {
+                if !g.IO.MouseDown[1] {
+                    // Zoom to the rectangle, on the axes it spans
+                    let min_drag = font_size * 0.5;
+                    let start = plot.BoxSelectStart;
+                    for (axis_n, p0, p1) in [(IM_GUI_AXIS_X as usize, start.x, mouse.x), (IM_GUI_AXIS_Y as usize, start.y, mouse.y)] {
+                        if (p1 - p0).abs() > min_drag {
+                            let axis = &mut plot.Axes[axis_n];
+                            let (v0, v1) = (axis.FromPixel(p0), axis.FromPixel(p1));
+                            axis.SetRange(v0.min(v1), v0.max(v1));
+                        }
+                    }
+                    plot.BoxSelecting = false;
+                    ClearActiveID(g);
+                }
+            } else if g.IO.MouseDown[0] {
+                let delta = g.IO.MouseDelta;
+                if plot.HeldAxes[0] && delta.x != 0.0 {
+                    plot.Axes[IM_GUI_AXIS_X as usize].Pan(delta.x);
+                }
+                if plot.HeldAxes[1] && delta.y != 0.0 {
+                    plot.Axes[IM_GUI_AXIS_Y as usize].Pan(delta.y);
+                }
+            } else {
+                ClearActiveID(g);
+            }
+        }
+        if g.IO.MouseWheel != 0.0 {
+            let zoom = PLOT_ZOOM_RATE.powf(g.IO.MouseWheel as f64);
+            if axes_hovered[0] {
+                plot.Axes[IM_GUI_AXIS_X as usize].Zoom(mouse.x, zoom);
+            }
+            if axes_hovered[1] {
+                plot.Axes[IM_GUI_AXIS_Y as usize].Zoom(mouse.y, zoom);
+            }
+        }
+    }
+
+    // Items extend the fit extents of the axes fitting this frame, the fit is applied by EndPlot()
+    for axis_n in 0..2 {
+        plot.Fitting[axis_n] = plot.FitRequest[axis_n] || flag_set(plot.Axes[axis_n].Flags, ImGuiPlotAxisFlags_AutoFit);
+        if plot.Fitting[axis_n] {
+            plot.Axes[axis_n].BeginFit();
+        }
+    }
+
+    // Ticks, with their label sizes measured up front
+    let axis_x = &plot.Axes[IM_GUI_AXIS_X as usize];
+    let axis_y = &plot.Axes[IM_GUI_AXIS_Y as usize];
+    let x_label_spacing = font_size * if flag_set(axis_x.Flags, ImGuiPlotAxisFlags_Time) { 7.0 } else { 5.0 };
+    let measure = |g: &mut AppContext, ticks: Vec<ImGuiPlotTick>| -> Vec<(ImGuiPlotTick, Vector2)> {
+        ticks.into_iter().map(|tick| {
+            let size = if tick.Major { CalcTextSize(g, &tick.Label, false, 0.0) } else { Vector2::from_floats(0.0, 0.0) };
+            (tick, size)
+        }).collect()
+    };
+    let x_ticks = measure(g, PlotCalcTicks(axis_x, plot_rect.max.x - plot_rect.min.x, x_label_spacing));
+    let y_ticks = measure(g, PlotCalcTicks(axis_y, plot_rect.max.y - plot_rect.min.y, y_label_spacing));
+    let title_size = CalcTextSize(g, &String::from(title), false, 0.0);
+    let x_label_size = CalcTextSize(g, &x_label, false, 0.0);
+
+    // Render
+    if !flag_set(flags, ImGuiPlotFlags_NoFrame) {
+        RenderFrame(frame_bb.min, frame_bb.max, GetColorU32(ImGuiCol_FrameBg, 1.0), true, g.style.FrameRounding);
+    }
+    let col_text = GetColorU32(ImGuiCol_Text, 1.0);
+    let col_grid_major = GetColorU32(ImGuiCol_Border, 1.0);
+    let col_grid_minor = GetColorU32(ImGuiCol_Border, 0.40);
+    let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+    if !flag_set(axis_x.Flags, ImGuiPlotAxisFlags_NoGridLines) {
+        for (tick, _) in x_ticks.iter() {
+            let px = axis_x.ToPixel(tick.Value).round() + 0.5;
+            draw_list.AddLine(Vector2::from_floats(px, plot_rect.min.y), Vector2::from_floats(px, plot_rect.max.y), if tick.Major { col_grid_major } else { col_grid_minor }, 1.0);
+        }
+    }
+    if !flag_set(axis_y.Flags, ImGuiPlotAxisFlags_NoGridLines) {
+        for (tick, _) in y_ticks.iter() {
+            let py = axis_y.ToPixel(tick.Value).round() + 0.5;
+            draw_list.AddLine(Vector2::from_floats(plot_rect.min.x, py), Vector2::from_floats(plot_rect.max.x, py), if tick.Major { col_grid_major } else { col_grid_minor }, 1.0);
+        }
+    }
+    draw_list.AddRect(plot_rect.min, plot_rect.max, col_grid_major, 0.0);
+
+    // Tick labels, skipping those which would overlap the previous one
+    if x_tick_labels {
+        let mut last_label_max_x = c_float::MIN;
+        for (tick, size) in x_ticks.iter().filter(|(tick, _)| tick.Major) {
+            let x = (axis_x.ToPixel(tick.Value) - size.x * 0.5).clamp(inner.min.x, (inner.max.x - size.x).max(inner.min.x));
+            if x < last_label_max_x + style_inner_spacing.x {
+                continue;
+            }
+            draw_list.AddText(Vector2::from_floats(x, plot_rect.max.y + style_inner_spacing.y), col_text, tick.Label.clone());
+            last_label_max_x = x + size.x;
+        }
+    }
+    if y_tick_labels {
+        let mut last_label_min_y = c_float::MAX;
+        for (tick, size) in y_ticks.iter().filter(|(tick, _)| tick.Major) {
+            let y = (axis_y.ToPixel(tick.Value) - size.y * 0.5).clamp(inner.min.y, (inner.max.y - size.y).max(inner.min.y));
+            if y + size.y > last_label_min_y {
+                continue;
+            }
+            draw_list.AddText(Vector2::from_floats(plot_rect.min.x - style_inner_spacing.x - size.x, y), col_text, tick.Label.clone());
+            last_label_min_y = y;
+        }
+    }
+
+    // Title, axis labels
+    if show_title {
+        draw_list.AddText(Vector2::from_floats((plot_rect.min.x + plot_rect.max.x - title_size.x) * 0.5, inner.min.y), col_text, String::from(title));
+    }
+    if !y_label.is_empty() {
+        draw_list.AddText(Vector2::from_floats(inner.min.x, inner.min.y), col_text, y_label);
+    }
+    if !x_label.is_empty() {
+        draw_list.AddText(Vector2::from_floats((plot_rect.min.x + plot_rect.max.x - x_label_size.x) * 0.5, inner.max.y - font_size), col_text, x_label);
+    }
+
+    // Items are clipped to the plot area, until EndPlot()
+    draw_list.PushClipRect(&plot_rect.min, &plot_rect.max, true);
+    plot.FrameRect = frame_bb;
+    plot.PlotRect = plot_rect;
+    g.CurrentPlot = Some(plot);
+    true
+}
+
+pub unsafe fn EndPlot(g: &mut AppContext) {
+    // IM_ASSERT(g.CurrentPlot.is_some()); // Mismatched BeginPlot()/EndPlot() calls
+    let mut plot = match g.CurrentPlot.take() {
+        Some(plot) => plot,
+        None => return,
+    };
+    let plot_rect = plot.PlotRect;
+    let mouse = g.IO.MousePos;
+    let font_size = g.FontSize;
+    let style_frame_padding = g.style.FramePadding;
+    let style_inner_spacing = g.style.ItemInnerSpacing;
+
+    // Fits collected from this frame's items show from the next frame
+    for axis_n in 0..2 {
+        if plot.Fitting[axis_n] {
+            plot.Axes[axis_n].ApplyFit();
+        }
+        plot.FitRequest[axis_n] = false;
+        plot.Fitting[axis_n] = false;
+    }
+
+    // Legend: series submitted this frame with a visible label
+    let legend_entries: Vec<(usize, String, Vector2)> = if flag_set(plot.Flags, ImGuiPlotFlags_NoLegend) {
+        Vec::new()
+    } else {
+        let frame_count = g.FrameCount;
+        let visible: Vec<(usize, String)> = plot.Items.iter().enumerate().filter(|(_, item)| item.LastFrameSeen == frame_count && !item.Label.is_empty()).map(|(item_n, item)| (item_n, item.Label.clone())).collect();
+        visible.into_iter().map(|(item_n, label)| {
+            let size = CalcTextSize(g, &label, false, 0.0);
+            (item_n, label, size)
+        }).collect()
+    };
+    let col_text = GetColorU32(ImGuiCol_Text, 1.0);
+    let col_text_disabled = GetColorU32(ImGuiCol_TextDisabled, 1.0);
+    let col_legend_bg = GetColorU32(ImGuiCol_PopupBg, 0.80);
+    let col_border = GetColorU32(ImGuiCol_Border, 1.0);
+    let col_highlight = GetColorU32(ImGuiCol_NavHighlight, 1.0);
+    plot.LegendHoveredItem = 0;
+    plot.LegendRect = ImRect::default();
+    if !legend_entries.is_empty() {
+        let swatch_size = font_size * 0.8;
+        let row_h = font_size + style_inner_spacing.y;
+        let text_w = legend_entries.iter().map(|(_, _, size)| size.x).fold(0.0, c_float::max);
+        let legend_min = Vector2::from_floats(plot_rect.min.x + style_inner_spacing.x, plot_rect.min.y + style_inner_spacing.y);
+        let legend_size = Vector2::from_floats(swatch_size + style_inner_spacing.x + text_w + style_frame_padding.x * 2.0, row_h * legend_entries.len() as c_float + style_frame_padding.y * 2.0 - style_inner_spacing.y);
+        plot.LegendRect = ImRect::from_floats(legend_min.x, legend_min.y, legend_min.x + legend_size.x, legend_min.y + legend_size.y);
+        let toggle_allowed = !flag_set(plot.Flags, ImGuiPlotFlags_NoInputs) && g.IO.MouseClicked[0];
+        let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+        draw_list.AddRectFilled(&plot.LegendRect.min, &plot.LegendRect.max, col_legend_bg, 0.0, ImDrawFlags_None);
+        draw_list.AddRect(plot.LegendRect.min, plot.LegendRect.max, col_border, 0.0);
+        for (row_n, (item_n, label, _)) in legend_entries.iter().enumerate() {
+            let row_min = Vector2::from_floats(legend_min.x + style_frame_padding.x, legend_min.y + style_frame_padding.y + row_h * row_n as c_float);
+            let mut row_rect = ImRect::from_floats(row_min.x, row_min.y, plot.LegendRect.max.x - style_frame_padding.x, row_min.y + font_size);
+            let item: &mut ImGuiPlotItem = &mut plot.Items[*item_n];
+            if plot.Hovered && row_rect.Contains(&mouse) {
+                plot.LegendHoveredItem = item.ID;
+                if toggle_allowed {
+                    item.Show = !item.Show;
+                }
+            }
+            let swatch_min = Vector2::from_floats(row_min.x, row_min.y + (font_size - swatch_size) * 0.5);
+            let swatch_max = Vector2::from_floats(swatch_min.x + swatch_size, swatch_min.y + swatch_size);
+            draw_list.AddRectFilled(&swatch_min, &swatch_max, if item.Show { item.Color } else { PlotColorMul(item.Color, 0.25) }, 0.0, ImDrawFlags_None);
+            draw_list.AddText(Vector2::from_floats(swatch_max.x + style_inner_spacing.x, row_min.y), if item.Show { col_text } else { col_text_disabled }, label.clone());
+        }
+    }
+
+    // Box-select rectangle, crosshairs, mouse coordinates
+    let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+    if plot.BoxSelecting {
+        let start = plot.BoxSelectStart;
+        let box_min = Vector2::from_floats(start.x.min(mouse.x), start.y.min(mouse.y));
+        let box_max = Vector2::from_floats(start.x.max(mouse.x), start.y.max(mouse.y));
+        draw_list.AddRectFilled(&box_min, &box_max, PlotColorMul(col_highlight, 0.15), 0.0, ImDrawFlags_None);
+        draw_list.AddRect(box_min, box_max, col_highlight, 0.0);
+    }
+    if plot.Hovered && flag_set(plot.Flags, ImGuiPlotFlags_Crosshairs) {
+        draw_list.AddLine(Vector2::from_floats(plot_rect.min.x, mouse.y), Vector2::from_floats(plot_rect.max.x, mouse.y), col_text, 1.0);
+        draw_list.AddLine(Vector2::from_floats(mouse.x, plot_rect.min.y), Vector2::from_floats(mouse.x, plot_rect.max.y), col_text, 1.0);
+    }
+    if plot.Hovered && !flag_set(plot.Flags, ImGuiPlotFlags_NoMouseText) {
+        let text = format!("{}, {}", PlotFormatMouseValue(&plot.Axes[IM_GUI_AXIS_X as usize], plot.Axes[IM_GUI_AXIS_X as usize].FromPixel(mouse.x)), PlotFormatMouseValue(&plot.Axes[IM_GUI_AXIS_Y as usize], plot.Axes[IM_GUI_AXIS_Y as usize].FromPixel(mouse.y)));
+        let text_size = CalcTextSize(g, &text, false, 0.0);
+        let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+        draw_list.AddText(Vector2::from_floats(plot_rect.max.x - text_size.x - style_inner_spacing.x, plot_rect.max.y - text_size.y - style_inner_spacing.y), col_text, text);
+    }
+    g.current_window_mut().unwrap().DrawList.PopClipRect();
+    g.Plots.insert(plot.ID, plot);
+}
+
+// Mouse position in plot coordinates, if the plot area of the current plot is hovered
+pub fn GetPlotMousePos(g: &AppContext) -> Option<(f64, f64)> {
+    let plot = g.CurrentPlot.as_ref()?;
+    if !plot.Hovered {
+        return None;
+    }
+    let mouse = g.IO.MousePos;
+    Some((plot.Axes[IM_GUI_AXIS_X as usize].FromPixel(mouse.x), plot.Axes[IM_GUI_AXIS_Y as usize].FromPixel(mouse.y)))
+}
+
+//-------------------------------------------------------------------------
+// Series
+//-------------------------------------------------------------------------
+
+fn PlotPartitionPoint(count: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut lo, mut hi) = (0, count);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) { lo = mid + 1; } else { hi = mid; }
+    }
+    lo
+}
+
+// Points [start, end) of a series with increasing x which lie in [x_min, x_max]
+fn PlotVisibleRange(getter: &dyn Fn(usize) -> (f64, f64), count: usize, x_min: f64, x_max: f64) -> (usize, usize) {
+    let start = PlotPartitionPoint(count, |i| getter(i).0 < x_min);
+    let end = PlotPartitionPoint(count, |i| getter(i).0 <= x_max);
+    (start, end.max(start))
+}
+
+// Points of a pixel column, reduced to the first, lowest, highest and last: the line through them covers the same pixels
+struct PlotColumn {
+    X: i64,
+    First: Vector2,
+    Last: Vector2,
+    Min: Vector2,
+    Max: Vector2,
+    MinFirst: bool,
+}
+
+impl PlotColumn {
+    fn new(x: i64, p: Vector2) -> Self {
+        Self { X: x, First: p, Last: p, Min: p, Max: p, MinFirst: true }
+    }
+
+    fn Add(&mut self, p: Vector2) {
+        if p.y < self.Min.y {
+            self.Min = p;
+            self.MinFirst = false;
+        }
+        if p.y > self.Max.y {
+            self.Max = p;
+            self.MinFirst = true;
+        }
+        self.Last = p;
+    }
+
+    fn Flush(&self, out: &mut Vec<Vector2>) {
+        let (a, b) = if self.MinFirst { (self.Min, self.Max) } else { (self.Max, self.Min) };
+        for p in [self.First, a, b, self.Last] {
+            if out.last().map_or(true, |last| last.x != p.x || last.y != p.y) {
+                out.push(p);
+            }
+        }
+    }
+}
+
+// Screen positions of the points [start, end), as runs of connected points: points the axes can't show (NaN, <= 0 on log scales) break the line.
+fn PlotBuildPolylines(transform: &ImGuiPlotTransform, getter: &dyn Fn(usize) -> (f64, f64), start: usize, end: usize, decimate: bool) -> Vec<Vec<Vector2>> {
+    let mut runs: Vec<Vec<Vector2>> = Vec::new();
+    let mut run: Vec<Vector2> = Vec::new();
+    let mut column: Option<PlotColumn> = None;
+    for i in start..end {
+        let (x, y) = getter(i);
+        match transform.ToPixel(x, y) {
+            None => {
+                if let Some(c) = column.take() {
+                    c.Flush(&mut run);
+                }
+                if !run.is_empty() {
+                    runs.push(std::mem::take(&mut run));
+                }
+            }
+            Some(p) if decimate => {
+                let x = p.x.floor() as i64;
+                match column.as_mut() {
+                    Some(c) if c.X == x => c.Add(p),
+                    _ => {
+                        if let Some(c) = column.replace(PlotColumn::new(x, p)) {
+                            c.Flush(&mut run);
+                        }
+                    }
+                }
+            }
+            Some(p) => run.push(p),
+        }
+    }
+    if let Some(c) = column.take() {
+        c.Flush(&mut run);
+    }
+    if !run.is_empty() {
+        runs.push(run);
+    }
+    runs
+}
+
+unsafe fn PlotItemEx(g: &mut AppContext, plot_type: ImGuiPlotType, label_id: &str, count: usize, getter: &dyn Fn(usize) -> (f64, f64), x_sorted: bool, param: f64) {
+    // IM_ASSERT(g.CurrentPlot.is_some()); // Call between BeginPlot() and EndPlot()
+    let mut plot = match g.CurrentPlot.take() {
+        Some(plot) => plot,
+        None => return,
+    };
+
+    // Register the series (its legend entry is shown even when hidden)
+    let item_id: ImguiHandle = hash_string(label_id, plot.ID);
+    let item_n = match plot.FindItem(item_id) {
+        Some(item_n) => item_n,
+        None => {
+            let (r, g_, b) = PLOT_COLORMAP[plot.Items.len() % PLOT_COLORMAP.len()];
+            plot.Items.push(ImGuiPlotItem { ID: item_id, Color: color_u32_from_rgba(r, g_, b, 255), Show: true, ..Default::default() });
+            plot.Items.len() - 1
+        }
+    };
+    let next_item_color = plot.NextItemColor.take();
+    let item = &mut plot.Items[item_n];
+    item.Label = String::from(PlotLabelDisplay(label_id));
+    item.PlotType = plot_type;
+    item.LastFrameSeen = g.FrameCount;
+    if let Some(col) = next_item_color {
+        item.Color = col;
+    }
+    let (show, col) = (item.Show, item.Color);
+    if !show {
+        g.CurrentPlot = Some(plot);
+        return;
+    }
+
+    let (x_min, x_max) = (plot.Axes[IM_GUI_AXIS_X as usize].Min, plot.Axes[IM_GUI_AXIS_X as usize].Max);
+    let half_width = if plot_type == ImGuiPlotType_Histogram { param * 0.5 } else { 0.0 };
+    let has_ref = plot_type == ImGuiPlotType_Histogram || plot_type == ImGuiPlotType_Shaded;
+    let y_ref = if plot_type == ImGuiPlotType_Histogram { 0.0 } else { param };
+
+    // Fit: all points when fitting X, Y alone fits the points within the visible X range
+    let (fit_x, fit_y) = (plot.Fitting[IM_GUI_AXIS_X as usize], plot.Fitting[IM_GUI_AXIS_Y as usize]);
+    if fit_x || fit_y {
+        let (fit_start, fit_end) = if fit_x || !x_sorted { (0, count) } else { PlotVisibleRange(getter, count, x_min - half_width, x_max + half_width) };
+        let (axis_x, axis_y) = plot.Axes.split_at_mut(1);
+        for i in fit_start..fit_end {
+            let (x, y) = getter(i);
+            if !fit_x && !x_sorted && (x + half_width < x_min || x - half_width > x_max) {
+                continue;
+            }
+            if fit_x {
+                axis_x[0].ExtendFit(x - half_width);
+                axis_x[0].ExtendFit(x + half_width);
+            }
+            if fit_y {
+                axis_y[0].ExtendFit(y);
+            }
+        }
+        if fit_y && has_ref && fit_end > fit_start {
+            axis_y[0].ExtendFit(y_ref);
+        }
+    }
+
+    // Points which can be visible, plus one on each side so lines reach the edges
+    let (start, end) = if x_sorted {
+        let (start, end) = PlotVisibleRange(getter, count, x_min - half_width, x_max + half_width);
+        (start.saturating_sub(1), (end + 1).min(count))
+    } else {
+        (0, count)
+    };
+    let plot_rect = plot.PlotRect;
+    let plot_width = (plot_rect.max.x - plot_rect.min.x).max(1.0) as usize;
+    let decimate = x_sorted && end - start > plot_width * PLOT_DECIMATE_POINTS_PER_PIXEL;
+    let transform = plot.Transform();
+    let highlighted = plot.LegendHoveredItem == item_id;
+    let thickness = if highlighted { 2.0 } else { 1.0 };
+    let col_fill = PlotColorMul(col, if highlighted { 0.50 } else { 0.30 });
+    let ref_px = {
+        let axis_y = &plot.Axes[IM_GUI_AXIS_Y as usize];
+        let px = axis_y.ToPixel(y_ref);
+        // A log scale can't show 0.0: bars and areas then start from the bottom
+        (if px.is_finite() { px } else { axis_y.PixelMin }).clamp(plot_rect.min.y - 1.0, plot_rect.max.y + 1.0)
+    };
+
+    match plot_type {
+        ImGuiPlotType_Lines | ImGuiPlotType_Stairs | ImGuiPlotType_Shaded => {
+            let mut runs = PlotBuildPolylines(&transform, getter, start, end, decimate);
+            if plot_type == ImGuiPlotType_Stairs {
+                // Each value holds until the next point
+                for run in runs.iter_mut() {
+                    let mut steps: Vec<Vector2> = Vec::with_capacity(run.len() * 2);
+                    for (n, p) in run.iter().enumerate() {
+                        if n > 0 {
+                            steps.push(Vector2::from_floats(p.x, run[n - 1].y));
+                        }
+                        steps.push(*p);
+                    }
+                    *run = steps;
+                }
+            }
+            let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+            if plot_type == ImGuiPlotType_Shaded {
+                // Area between the line and y_ref, split where they cross so every triangle stays convex
+                for run in runs.iter() {
+                    for seg in run.windows(2) {
+                        let (a, b) = (seg[0], seg[1]);
+                        let (a_ref, b_ref) = (Vector2::from_floats(a.x, ref_px), Vector2::from_floats(b.x, ref_px));
+                        if (a.y - ref_px) * (b.y - ref_px) < 0.0 {
+                            let t = (ref_px - a.y) / (b.y - a.y);
+                            let c = Vector2::from_floats(a.x + (b.x - a.x) * t, ref_px);
+                            draw_list.AddTriangleFilled(&a, &c, &a_ref, col_fill);
+                            draw_list.AddTriangleFilled(&c, &b, &b_ref, col_fill);
+                        } else {
+                            draw_list.AddTriangleFilled(&a, &b, &b_ref, col_fill);
+                            draw_list.AddTriangleFilled(&a, &b_ref, &a_ref, col_fill);
+                        }
+                    }
+                }
+            }
+            for run in runs.iter() {
+                if run.len() >= 2 {
+                    draw_list.AddPolyline(run, col, ImDrawFlags_None, thickness);
+                } else if let Some(p) = run.first() {
+                    draw_list.AddCircleFilled(p, thickness * 1.5, col, 0);
+                }
+            }
+        }
+        ImGuiPlotType_Scatter => {
+            // Only one marker per pixel for large series: the others would be drawn over it
+            let dedupe_w = plot_width + 1;
+            let dedupe_h = (plot_rect.max.y - plot_rect.min.y).max(1.0) as usize + 1;
+            let mut drawn: Vec<u64> = if end - start >= PLOT_SCATTER_DEDUPE_MIN_COUNT { vec![0; (dedupe_w * dedupe_h + 63) / 64] } else { Vec::new() };
+            let radius = if highlighted { 3.0 } else { 2.0 };
+            let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+            for i in start..end {
+                let (x, y) = getter(i);
+                let p = match transform.ToPixel(x, y) {
+                    Some(p) if p.x >= plot_rect.min.x && p.x <= plot_rect.max.x && p.y >= plot_rect.min.y && p.y <= plot_rect.max.y => p,
+                    _ => continue,
+                };
+                if !drawn.is_empty() {
+                    let bit = (p.x - plot_rect.min.x) as usize + (p.y - plot_rect.min.y) as usize * dedupe_w;
+                    if drawn[bit / 64] & (1u64 << (bit % 64)) != 0 {
+                        continue;
+                    }
+                    drawn[bit / 64] |= 1u64 << (bit % 64);
+                }
+                draw_list.AddCircleFilled(&p, radius, col, 0);
+            }
+        }
+        ImGuiPlotType_Histogram => {
+            let axis_x = &plot.Axes[IM_GUI_AXIS_X as usize];
+            let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+            let col_bar = if highlighted { col } else { PlotColorMul(col, 0.85) };
+            if decimate {
+                // Bars thinner than a pixel: one bar per pixel column spanning the values of the column
+                let mut column: Option<(i64, c_float, c_float)> = None;
+                let mut flush = |column: Option<(i64, c_float, c_float)>| {
+                    if let Some((x, y0, y1)) = column {
+                        draw_list.AddRectFilled(&Vector2::from_floats(x as c_float, y0), &Vector2::from_floats(x as c_float + 1.0, y1), col_bar, 0.0, ImDrawFlags_None);
+                    }
+                };
+                for i in start..end {
+                    let (x, y) = getter(i);
+                    let p = match transform.ToPixel(x, y) {
+                        Some(p) => p,
+                        None => continue,
+                    };
+                    let px = p.x.floor() as i64;
+                    match column.as_mut() {
+                        Some(c) if c.0 == px => {
+                            c.1 = c.1.min(p.y);
+                            c.2 = c.2.max(p.y);
+                        }
+                        _ => flush(column.replace((px, p.y.min(ref_px), p.y.max(ref_px)))),
+                    }
+                }
+                flush(column);
+            } else {
+                for i in start..end {
+                    let (x, y) = getter(i);
+                    let (x0, x1, py) = (axis_x.ToPixel(x - half_width), axis_x.ToPixel(x + half_width), plot.Axes[IM_GUI_AXIS_Y as usize].ToPixel(y));
+                    if !x0.is_finite() || !x1.is_finite() || !py.is_finite() {
+                        continue;
+                    }
+                    let (x0, x1) = (x0.min(x1), x0.max(x1).max(x0.min(x1) + 1.0));
+                    draw_list.AddRectFilled(&Vector2::from_floats(x0, py.min(ref_px)), &Vector2::from_floats(x1, py.max(ref_px)), col_bar, 0.0, ImDrawFlags_None);
+                }
+            }
+        }
+        _ => {}
+    }
+    g.CurrentPlot = Some(plot);
+}
+
+// Whether PlotItemEx() can binary search and decimate the series (NaN counts as unsorted).
+// Scanning the whole series every frame would cost as much as drawing it unsorted, so the result is kept by the item
+// and only recomputed when the slice (pointer, length, first or last value) changes.
+fn PlotIsSorted<T: Copy + Into<f64>>(g: &mut AppContext, label_id: &str, xs: &[T]) -> bool {
+    let plot = match g.CurrentPlot.as_mut() {
+        Some(plot) => plot,
+        None => return false,
+    };
+    let key = (xs.as_ptr() as usize, xs.len(), xs.first().map_or(0, |x| Into::<f64>::into(*x).to_bits()), xs.last().map_or(0, |x| Into::<f64>::into(*x).to_bits()));
+    let item_n = plot.FindItem(hash_string(label_id, plot.ID));
+    if let Some(item_n) = item_n {
+        if plot.Items[item_n].XSortedKey == Some(key) {
+            return plot.Items[item_n].XSorted;
+        }
+    }
+    let sorted = xs.windows(2).all(|w| w[0].into() <= w[1].into());
+    if let Some(item_n) = item_n {
+        plot.Items[item_n].XSortedKey = Some(key);
+        plot.Items[item_n].XSorted = sorted;
+    }
+    sorted
+}
+
+pub unsafe fn PlotLine<T: Copy + Into<f64>>(g: &mut AppContext, label_id: &str, xs: &[T], ys: &[T]) {
+    let x_sorted = PlotIsSorted(g, label_id, xs);
+    PlotItemEx(g, ImGuiPlotType_Lines, label_id, xs.len().min(ys.len()), &|i| (xs[i].into(), ys[i].into()), x_sorted, 0.0);
+}
+
+// Values at x_start, x_start + x_step, ...
+pub unsafe fn PlotLineValues<T: Copy + Into<f64>>(g: &mut AppContext, label_id: &str, ys: &[T], x_start: f64, x_step: f64) {
+    PlotItemEx(g, ImGuiPlotType_Lines, label_id, ys.len(), &|i| (x_start + x_step * i as f64, ys[i].into()), x_step >= 0.0, 0.0);
+}
+
+// Points in any order
+pub unsafe fn PlotScatter<T: Copy + Into<f64>>(g: &mut AppContext, label_id: &str, xs: &[T], ys: &[T]) {
+    PlotItemEx(g, ImGuiPlotType_Scatter, label_id, xs.len().min(ys.len()), &|i| (xs[i].into(), ys[i].into()), false, 0.0);
+}
+
+// Bars from 0.0 to the values, 'bar_width' in X units
+pub unsafe fn PlotBars<T: Copy + Into<f64>>(g: &mut AppContext, label_id: &str, xs: &[T], ys: &[T], bar_width: f64) {
+    let x_sorted = PlotIsSorted(g, label_id, xs);
+    PlotItemEx(g, ImGuiPlotType_Histogram, label_id, xs.len().min(ys.len()), &|i| (xs[i].into(), ys[i].into()), x_sorted, bar_width);
+}
+
+// Line with the area between it and 'y_ref' filled
+pub unsafe fn PlotShaded<T: Copy + Into<f64>>(g: &mut AppContext, label_id: &str, xs: &[T], ys: &[T], y_ref: f64) {
+    let x_sorted = PlotIsSorted(g, label_id, xs);
+    PlotItemEx(g, ImGuiPlotType_Shaded, label_id, xs.len().min(ys.len()), &|i| (xs[i].into(), ys[i].into()), x_sorted, y_ref);
+}
+
+// Each value holds until the next point
+pub unsafe fn PlotStairs<T: Copy + Into<f64>>(g: &mut AppContext, label_id: &str, xs: &[T], ys: &[T]) {
+    let x_sorted = PlotIsSorted(g, label_id, xs);
+    PlotItemEx(g, ImGuiPlotType_Stairs, label_id, xs.len().min(ys.len()), &|i| (xs[i].into(), ys[i].into()), x_sorted, 0.0);
+}
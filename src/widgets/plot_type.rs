@@ -7,5 +7,8 @@ pub type ImGuiPlotType = c_int;
 // enum ImGuiPlotType
 // {
 pub const ImGuiPlotType_Lines: ImGuiPlotType = 0;
-pub const ImGuiPlotType_Histogram: ImGuiPlotType = 1;
+pub const ImGuiPlotType_Histogram: ImGuiPlotType = 1; // Bars, see PlotBars()
+pub const ImGuiPlotType_Scatter: ImGuiPlotType = 2;
+pub const ImGuiPlotType_Shaded: ImGuiPlotType = 3;
+pub const ImGuiPlotType_Stairs: ImGuiPlotType = 4;
 // };
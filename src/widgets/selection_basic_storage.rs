@@ -0,0 +1,96 @@
+#![allow(non_snake_case)]
+
+use crate::core::type_defs::ImguiHandle;
+use crate::widgets::multi_select::{ImGuiMultiSelectIO, ImGuiSelectionRequestType_SetAll, ImGuiSelectionRequestType_SetRange};
+use libc::c_int;
+use std::collections::HashSet;
+
+// Optional helper to store multi-selection state + apply multi-selection requests.
+// - USING THIS IS NOT MANDATORY. This is only a helper and not a required API: an asset browser or outliner
+//   will often keep the selection in its own data instead (e.g. a 'selected' flag on each entity).
+// - Expects indices to be passed to SetNextItemSelectionUserData(), so SetRange requests can be iterated.
+// - AdapterIndexToStorageId() turns an index into the persistent identifier which is stored. The default implementation
+//   returns the index itself: only valid for a never changing item list.
+// - Iterate selection with 'for id in selection.iter()'.
+#[derive(Debug, Clone)]
+pub struct ImGuiSelectionBasicStorage {
+    // Members
+    pub Size: c_int,
+    // Number of selected items, maintained by this helper.
+    pub AdapterIndexToStorageId: fn(&ImGuiSelectionBasicStorage, c_int) -> ImguiHandle, // Index -> persistent identifier. Default returns the index.
+    pub _Storage: HashSet<ImguiHandle>,
+}
+
+fn AdapterIndexToStorageId_Default(_self: &ImGuiSelectionBasicStorage, idx: c_int) -> ImguiHandle {
+    idx as ImguiHandle
+}
+
+impl Default for ImGuiSelectionBasicStorage {
+    fn default() -> Self {
+        Self {
+            Size: 0,
+            AdapterIndexToStorageId: AdapterIndexToStorageId_Default,
+            _Storage: HashSet::new(),
+        }
+    }
+}
+
+impl ImGuiSelectionBasicStorage {
+    // Apply selection requests coming from BeginMultiSelect() and EndMultiSelect() functions. It uses 'items_count' passed to BeginMultiSelect()
+    // - Honoring SetRange requests requires that you can iterate/interpolate between RangeFirstItem and RangeLastItem.
+    //   If you store pointers or objects ID in ImGuiSelectionUserData you may need to perform a lookup in order to have
+    //   some way to iterate/interpolate between two items.
+    pub fn ApplyRequests(&mut self, ms_io: &ImGuiMultiSelectIO) {
+        // IM_ASSERT(ms_io.ItemsCount != -1 && "Missing value for items_count in BeginMultiSelect() call!");
+        for req in ms_io.Requests.iter() {
+            if req.Type == ImGuiSelectionRequestType_SetAll {
+                self.Clear();
+                if req.Selected {
+                    for idx in 0..ms_io.ItemsCount {
+                        let id = (self.AdapterIndexToStorageId)(self, idx);
+                        self.SetItemSelectedInternal(id, true);
+                    }
+                }
+            } else if req.Type == ImGuiSelectionRequestType_SetRange {
+                for idx in req.RangeFirstItem.min(req.RangeLastItem)..=req.RangeFirstItem.max(req.RangeLastItem) {
+                    let id = (self.AdapterIndexToStorageId)(self, idx as c_int);
+                    self.SetItemSelectedInternal(id, req.Selected);
+                }
+            }
+        }
+    }
+
+    // Query if an item id is in selection.
+    pub fn Contains(&self, id: ImguiHandle) -> bool {
+        self._Storage.contains(&id)
+    }
+
+    // Clear selection
+    pub fn Clear(&mut self) {
+        self.Size = 0;
+        self._Storage.clear();
+    }
+
+    // Swap two selections
+    pub fn Swap(&mut self, r: &mut ImGuiSelectionBasicStorage) {
+        std::mem::swap(&mut self.Size, &mut r.Size);
+        std::mem::swap(&mut self._Storage, &mut r._Storage);
+    }
+
+    // Add/remove an item from selection (generally done by ApplyRequests() function)
+    pub fn SetItemSelected(&mut self, id: ImguiHandle, selected: bool) {
+        self.SetItemSelectedInternal(id, selected);
+    }
+
+    // Iterate selected items, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = &ImguiHandle> {
+        self._Storage.iter()
+    }
+
+    fn SetItemSelectedInternal(&mut self, id: ImguiHandle, selected: bool) {
+        let changed = if selected { self._Storage.insert(id) } else { self._Storage.remove(&id) };
+        if changed {
+            self.Size += if selected { 1 } else { -1 };
+        }
+    }
+}
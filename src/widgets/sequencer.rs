@@ -0,0 +1,92 @@
+#![allow(non_snake_case)]
+
+use libc::{c_float, c_int};
+use crate::core::type_defs::ImguiHandle;
+use crate::rect::ImRect;
+use crate::widgets::sequencer_event_type::ImGuiSequencerEventType;
+use crate::widgets::sequencer_flags::ImGuiSequencerFlags;
+
+// An edit of a clip, reported by GetSequencerEvents() when the user releases the clip. The application applies it to its data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImGuiSequencerEvent {
+    pub Type: ImGuiSequencerEventType,
+    pub ClipID: c_int,
+    pub Track: c_int,
+    // Index of the track the clip is on after the edit, in submission order
+    pub Start: c_float,
+    pub End: c_float,
+    // New range of the clip, in frames
+}
+
+// Mouse interaction owning a clip (g.ActiveId == DragID) until the button is released
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ImGuiSequencerDrag {
+    #[default]
+    None,
+    Move,
+    TrimStart,
+    TrimEnd,
+}
+
+// Persistent state of a BeginSequencer()/EndSequencer() scope
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiSequencer {
+    pub ID: ImguiHandle,
+    pub Flags: ImGuiSequencerFlags,
+    pub PixelsPerFrame: c_float,
+    pub Hovered: bool,
+    // Timeline hovered, as of the last EndSequencer()
+    pub Events: Vec<ImGuiSequencerEvent>,
+    // Set by SequencerClip() on release, cleared by BeginSequencer()
+
+    // Clip being dragged
+    pub Drag: ImGuiSequencerDrag,
+    pub DragID: ImguiHandle,
+    pub DragClipID: c_int,
+    pub DragLabel: String,
+    pub DragColor: u32,
+    pub DragMouseFrame: c_float,
+    // Frame under the mouse when the drag started
+    pub DragOrigTrack: c_int,
+    pub DragOrigStart: c_float,
+    pub DragOrigEnd: c_float,
+    pub DragTrack: c_int,
+    pub DragStart: c_float,
+    pub DragEnd: c_float,
+    // Where the clip would land if released now
+
+    // [Internal, valid between BeginSequencer() and EndSequencer()]
+    pub FrameMin: c_float,
+    pub FrameMax: c_float,
+    pub CurrentFrame: c_float,
+    pub TimelineMinX: c_float,
+    // Screen position of FrameMin, scrolled with the timeline column
+    pub TimelineWidth: c_float,
+    pub TrackRects: Vec<ImRect>,
+    // Timeline cell of each track submitted by SequencerTrack()
+    pub ClipHovered: bool,
+}
+
+impl ImGuiSequencer {
+    pub fn new(id: ImguiHandle) -> Self {
+        Self {
+            ID: id,
+            DragOrigTrack: -1,
+            DragTrack: -1,
+            ..Default::default()
+        }
+    }
+
+    pub fn FrameToX(&self, frame: c_float) -> c_float {
+        self.TimelineMinX + (frame - self.FrameMin) * self.PixelsPerFrame
+    }
+
+    pub fn XToFrame(&self, x: c_float) -> c_float {
+        self.FrameMin + (x - self.TimelineMinX) / self.PixelsPerFrame
+    }
+
+    // Index of the track under a screen position, if any
+    pub fn TrackAt(&self, y: c_float) -> Option<usize> {
+        self.TrackRects.iter().position(|rect| y >= rect.min.y && y < rect.max.y)
+    }
+}
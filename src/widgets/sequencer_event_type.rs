@@ -0,0 +1,13 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+pub type ImGuiSequencerEventType = c_int;
+
+// Edit reported by GetSequencerEvents()
+// enum ImGuiSequencerEventType
+// {
+pub const ImGuiSequencerEventType_Move: ImGuiSequencerEventType = 0;   // Clip moved in time and/or to another track, its length is unchanged.
+pub const ImGuiSequencerEventType_TrimStart: ImGuiSequencerEventType = 1;   // Start of the clip moved.
+pub const ImGuiSequencerEventType_TrimEnd: ImGuiSequencerEventType = 2;   // End of the clip moved.
+// };
@@ -0,0 +1,16 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+// typedef int ImGuiSequencerFlags;      // -> enum ImGuiSequencerFlags_   // Flags: for BeginSequencer()
+pub type ImGuiSequencerFlags = c_int;
+
+// Flags for BeginSequencer()
+// enum ImGuiSequencerFlags_
+// {
+pub const ImGuiSequencerFlags_None: ImGuiSequencerFlags = 0;
+pub const ImGuiSequencerFlags_NoZoom: ImGuiSequencerFlags = 1 << 0;   // Ctrl+mouse wheel doesn't zoom the timeline.
+pub const ImGuiSequencerFlags_NoSnap: ImGuiSequencerFlags = 1 << 1;   // Clips and playhead move by fractions of frames instead of snapping to whole frames.
+pub const ImGuiSequencerFlags_NoTrim: ImGuiSequencerFlags = 1 << 2;   // Dragging the edges of a clip moves it instead of trimming it.
+pub const ImGuiSequencerFlags_NoTrackChange: ImGuiSequencerFlags = 1 << 3;   // Clips can't be dragged to another track.
+// };
@@ -0,0 +1,393 @@
+#![allow(non_snake_case)]
+
+//-------------------------------------------------------------------------
+// [SECTION] Widgets: BeginSequencer, EndSequencer, SequencerTrack, SequencerClip
+//-------------------------------------------------------------------------
+// - BeginSequencer()
+// - EndSequencer()
+// - SequencerTrack()
+// - SequencerClip()
+// - GetSequencerEvents()
+//-------------------------------------------------------------------------
+// Timeline of tracks holding clips, each clip covering a range of frames:
+// - The sequencer is a two column table. The track names column and the ruler row are frozen with TableSetupScrollFreeze(),
+//   the timeline column scrolls horizontally (Shift+mouse wheel or the scrollbar) and the tracks vertically.
+// - Ctrl+mouse wheel zooms around the mouse. Click or drag on the ruler moves the playhead.
+// - Dragging a clip moves it, on its track or another one, dragging its edges trims it. Frames snap to whole numbers.
+// - The application owns the clips: edits are reported by GetSequencerEvents() when the clip is released, the clip shows
+//   at its dragged position until then. Clip IDs are integers chosen by the application, unique within the sequencer
+//   (item IDs are derived from the sequencer ID, itself seeded by the ID stack, so other sequencers may reuse them).
+// Usage:
+//   if BeginSequencer(g, "timeline", &mut frame, 0.0, 600.0, Vector2::from_floats(-1.0, 300.0), ImGuiSequencerFlags_None) {
+//       for track in &tracks {
+//           SequencerTrack(g, &track.name);
+//           for clip in &track.clips {
+//               if SequencerClip(g, clip.id, clip.start, clip.end, &clip.name, clip.color, clip.id == selected_id) { selected_id = clip.id; }
+//           }
+//       }
+//       EndSequencer(g);
+//   }
+//   for event in GetSequencerEvents(g) { timeline.apply(&event); }
+//-------------------------------------------------------------------------
+
+use libc::{c_float, c_int};
+use crate::color::{ImGuiCol_Border, ImGuiCol_NavHighlight, ImGuiCol_PlotLinesHovered, ImGuiCol_Text, ImGuiCol_TextDisabled};
+use crate::core::context::AppContext;
+use crate::core::hash_ops::{hash_data, hash_string};
+use crate::core::id_ops::{ClearActiveID, SetActiveID};
+use crate::core::utils::flag_set;
+use crate::core::vec2::Vector2;
+use crate::drawing::draw_flags::ImDrawFlags_None;
+use crate::drawing::render_ops::RenderTextClipped;
+use crate::io::mouse_cursor::ImGuiMouseCursor_ResizeEW;
+use crate::item::item_ops::{CalcItemSize, CalcItemWidth, ItemAdd, ItemHoverable, ItemSize};
+use crate::rect::ImRect;
+use crate::style::style_ops::GetColorU32;
+use crate::table::table_column_flags::{ImGuiTableColumnFlags_NoHide, ImGuiTableColumnFlags_NoReorder, ImGuiTableColumnFlags_NoResize, ImGuiTableColumnFlags_WidthFixed};
+use crate::table::table_flags::{ImGuiTableFlags_BordersInnerH, ImGuiTableFlags_BordersInnerV, ImGuiTableFlags_NoSavedSettings, ImGuiTableFlags_Resizable, ImGuiTableFlags_ScrollX, ImGuiTableFlags_ScrollY, ImGuiTableFlags_SizingFixedFit};
+use crate::table::table_row_flags::{ImGuiTableRowFlags_Headers, ImGuiTableRowFlags_None};
+use crate::table::tables::{BeginTable, EndTable, TableNextRow, TableSetColumnIndex, TableSetColumnWidth, TableSetupColumn, TableSetupScrollFreeze};
+use crate::text::text_flags::ImGuiTextFlags_None;
+use crate::text::text_ops::TextEx;
+use crate::widgets::scrolling_ops::SetScrollX;
+use crate::widgets::sequencer::{ImGuiSequencer, ImGuiSequencerDrag, ImGuiSequencerEvent};
+use crate::widgets::sequencer_event_type::{ImGuiSequencerEventType_Move, ImGuiSequencerEventType_TrimEnd, ImGuiSequencerEventType_TrimStart};
+use crate::widgets::sequencer_flags::{ImGuiSequencerFlags, ImGuiSequencerFlags_NoSnap, ImGuiSequencerFlags_NoTrackChange, ImGuiSequencerFlags_NoTrim, ImGuiSequencerFlags_NoZoom};
+
+const SEQUENCER_DEFAULT_PIXELS_PER_FRAME: c_float = 8.0;
+const SEQUENCER_PIXELS_PER_FRAME_MIN: c_float = 0.05;
+const SEQUENCER_PIXELS_PER_FRAME_MAX: c_float = 100.0;
+
+// Zoom factor per notch of mouse wheel
+const SEQUENCER_ZOOM_RATE: c_float = 1.2;
+
+// Track height and width of the track names column, in multiples of the font size
+const SEQUENCER_TRACK_HEIGHT: c_float = 2.0;
+const SEQUENCER_NAMES_WIDTH: c_float = 8.0;
+
+// Width of the zones at the edges of a clip which trim it, in pixels
+const SEQUENCER_TRIM_HANDLE_WIDTH: c_float = 6.0;
+
+// Trimming can't make a clip shorter than this, in frames
+const SEQUENCER_CLIP_LENGTH_MIN: c_float = 1.0;
+
+// Minimum distance between labelled ticks of the ruler, in multiples of the font size
+const SEQUENCER_TICK_SPACING: c_float = 4.0;
+
+fn SequencerSnap(flags: ImGuiSequencerFlags, frame: c_float) -> c_float {
+    if flag_set(flags, ImGuiSequencerFlags_NoSnap) { frame } else { frame.round() }
+}
+
+// Frames between labelled ticks, the smallest of 1, 2, 5, 10, 20, 50... spaced by at least 'min_spacing' pixels, and between their subdivisions
+fn SequencerTickSteps(pixels_per_frame: c_float, min_spacing: c_float) -> (c_float, c_float) {
+    let mut decade = 1.0;
+    loop {
+        for (step, minor_step) in [(decade, decade / 5.0), (decade * 2.0, decade), (decade * 5.0, decade)] {
+            if step * pixels_per_frame >= min_spacing {
+                return (step, minor_step);
+            }
+        }
+        decade *= 10.0;
+    }
+}
+
+fn SequencerForQueries(g: &AppContext) -> Option<&ImGuiSequencer> {
+    g.CurrentSequencer.as_ref().or_else(|| g.Sequencers.get(&g.SequencerLastID))
+}
+
+unsafe fn SequencerRenderClip(g: &mut AppContext, bb: ImRect, label: &str, col: u32, selected: bool, hovered: bool) {
+    let rounding = g.style.FrameRounding;
+    let padding_x = g.style.FramePadding.x;
+    let col_border = GetColorU32(if selected { ImGuiCol_NavHighlight } else if hovered { ImGuiCol_Text } else { ImGuiCol_Border }, 1.0);
+    let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+    draw_list.AddRectFilled(&bb.min, &bb.max, col, rounding, ImDrawFlags_None);
+    draw_list.AddRect(bb.min, bb.max, col_border, rounding);
+    RenderTextClipped(Vector2::from_floats(bb.min.x + padding_x, bb.min.y), Vector2::from_floats(bb.max.x - padding_x, bb.max.y), &String::from(label), None, Some(Vector2::from_floats(0.0, 0.5)), Some(bb));
+}
+
+// 'current_frame' is the playhead, moved by the ruler. Returns false when the sequencer is clipped: don't call EndSequencer() then.
+pub unsafe fn BeginSequencer(g: &mut AppContext, str_id: &str, current_frame: &mut c_float, frame_min: c_float, frame_max: c_float, size_arg: Vector2, flags: ImGuiSequencerFlags) -> bool {
+    // IM_ASSERT(g.CurrentSequencer.is_none()); // Nesting BeginSequencer()/EndSequencer() is not supported
+    let (skip_items, seed) = {
+        let window = g.current_window_mut().unwrap();
+        (window.skip_items, window.id_stack.last().unwrap().clone())
+    };
+    if skip_items {
+        return false;
+    }
+    let id = hash_string(&String::from(str_id), seed);
+    let font_size = g.FontSize;
+    let style_frame_padding = g.style.FramePadding;
+    let item_width = CalcItemWidth(g);
+    let mut outer_size = CalcItemSize(g, size_arg, item_width, font_size * 12.0);
+
+    // Persistent state, moved to g.CurrentSequencer until EndSequencer()
+    let mut state = g.Sequencers.remove(&id).unwrap_or_else(|| ImGuiSequencer::new(id));
+    state.Flags = flags;
+    state.FrameMin = frame_min;
+    state.FrameMax = frame_max.max(frame_min + 1.0);
+    if state.PixelsPerFrame <= 0.0 {
+        state.PixelsPerFrame = SEQUENCER_DEFAULT_PIXELS_PER_FRAME;
+    }
+    state.Events.clear();
+    state.TrackRects.clear();
+    state.ClipHovered = false;
+
+    // Zoom around the mouse, keeping the frame under it in place. EndSequencer() tells whether the timeline is hovered, as of last frame.
+    let mut scroll_x_delta = 0.0;
+    if state.Hovered && g.IO.KeyCtrl && g.IO.MouseWheel != 0.0 && !flag_set(flags, ImGuiSequencerFlags_NoZoom) {
+        let mouse_frame = state.XToFrame(g.IO.MousePos.x);
+        let pixels_per_frame = (state.PixelsPerFrame * SEQUENCER_ZOOM_RATE.powf(g.IO.MouseWheel)).clamp(SEQUENCER_PIXELS_PER_FRAME_MIN, SEQUENCER_PIXELS_PER_FRAME_MAX);
+        scroll_x_delta = (mouse_frame - state.FrameMin) * (pixels_per_frame - state.PixelsPerFrame);
+        state.PixelsPerFrame = pixels_per_frame;
+    }
+    // Room after the last frame for its ruler label
+    let timeline_width = (state.FrameMax - state.FrameMin) * state.PixelsPerFrame + font_size * 2.0;
+    state.TimelineWidth = timeline_width;
+
+    let table_flags = ImGuiTableFlags_ScrollX | ImGuiTableFlags_ScrollY | ImGuiTableFlags_Resizable | ImGuiTableFlags_BordersInnerV | ImGuiTableFlags_BordersInnerH | ImGuiTableFlags_SizingFixedFit | ImGuiTableFlags_NoSavedSettings;
+    if !BeginTable(str_id, 2, table_flags, Some(&mut outer_size), 0.0) {
+        g.Sequencers.insert(id, state);
+        return false;
+    }
+    TableSetupColumn(String::from("##tracks"), ImGuiTableColumnFlags_WidthFixed | ImGuiTableColumnFlags_NoHide | ImGuiTableColumnFlags_NoReorder, font_size * SEQUENCER_NAMES_WIDTH, 0);
+    TableSetupColumn(String::from("##timeline"), ImGuiTableColumnFlags_WidthFixed | ImGuiTableColumnFlags_NoResize | ImGuiTableColumnFlags_NoHide | ImGuiTableColumnFlags_NoReorder, timeline_width, 0);
+    TableSetupScrollFreeze(1, 1);
+    // Follow the zoom, the initial width only applies to a new table
+    TableSetColumnWidth(1, timeline_width);
+    if scroll_x_delta != 0.0 {
+        let inner_window = &mut (*g.CurrentTable).InnerWindow;
+        let scroll_x = inner_window.scroll.x + scroll_x_delta;
+        SetScrollX(inner_window, scroll_x);
+    }
+
+    // Ruler, in the frozen row. Click or drag to move the playhead.
+    let ruler_height = font_size + style_frame_padding.y * 2.0;
+    TableNextRow(ImGuiTableRowFlags_Headers, ruler_height);
+    TableSetColumnIndex(1);
+    let (window_id, cursor_pos, clip_rect) = {
+        let window = g.current_window_mut().unwrap();
+        (window.ID, window.dc.cursor_pos, window.ClipRect)
+    };
+    state.TimelineMinX = cursor_pos.x;
+    let ruler_id = hash_string(&String::from("##ruler"), id);
+    let ruler_size = Vector2::from_floats(timeline_width, ruler_height);
+    let mut ruler_bb = ImRect::from_floats(cursor_pos.x, cursor_pos.y, cursor_pos.x + ruler_size.x, cursor_pos.y + ruler_size.y);
+    ItemSize(g, &ruler_size, -1.0);
+    if ItemAdd(g, &mut ruler_bb, ruler_id, None, 0) {
+        if ItemHoverable(&ruler_bb, ruler_id) && g.IO.MouseClicked[0] {
+            SetActiveID(g, ruler_id, None);
+            g.ActiveIdWindow = window_id;
+        }
+        if g.ActiveId == ruler_id {
+            if g.IO.MouseDown[0] {
+                *current_frame = SequencerSnap(flags, state.XToFrame(g.IO.MousePos.x)).clamp(state.FrameMin, state.FrameMax);
+            } else {
+                ClearActiveID(g);
+            }
+        }
+
+        // Labelled ticks and their subdivisions, over the visible part of the ruler
+        let (step, minor_step) = SequencerTickSteps(state.PixelsPerFrame, font_size * SEQUENCER_TICK_SPACING);
+        let draw_minor = minor_step >= 1.0 && minor_step * state.PixelsPerFrame >= 4.0;
+        let visible_min = state.XToFrame(clip_rect.x).max(state.FrameMin);
+        let visible_max = state.XToFrame(clip_rect.z).min(state.FrameMax);
+        let col_tick = GetColorU32(ImGuiCol_TextDisabled, 1.0);
+        let col_text = GetColorU32(ImGuiCol_Text, 1.0);
+        let col_playhead = GetColorU32(ImGuiCol_PlotLinesHovered, 1.0);
+        let tick_step = if draw_minor { minor_step } else { step };
+        let mut frame = (visible_min / tick_step).floor() * tick_step;
+        while frame <= visible_max {
+            if frame >= state.FrameMin {
+                let x = state.FrameToX(frame).floor();
+                let major = (frame / step).fract() == 0.0;
+                let tick_height = if major { ruler_height * 0.5 } else { ruler_height * 0.25 };
+                let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+                draw_list.AddLine(Vector2::from_floats(x, ruler_bb.max.y - tick_height), Vector2::from_floats(x, ruler_bb.max.y), col_tick, 1.0);
+                if major {
+                    draw_list.AddText(Vector2::from_floats(x + 3.0, ruler_bb.min.y + style_frame_padding.y), col_text, format!("{}", frame));
+                }
+            }
+            frame += tick_step;
+        }
+        let playhead_x = state.FrameToX(*current_frame).floor();
+        let head_size = (ruler_height * 0.25).floor();
+        let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+        draw_list.AddTriangleFilled(&Vector2::from_floats(playhead_x - head_size, ruler_bb.max.y - head_size), &Vector2::from_floats(playhead_x + head_size, ruler_bb.max.y - head_size), &Vector2::from_floats(playhead_x, ruler_bb.max.y), col_playhead);
+    }
+    state.CurrentFrame = *current_frame;
+    g.CurrentSequencer = Some(state);
+    true
+}
+
+pub unsafe fn EndSequencer(g: &mut AppContext) {
+    // IM_ASSERT(g.CurrentSequencer.is_some()); // Mismatched BeginSequencer()/EndSequencer() calls
+    let mut state = match g.CurrentSequencer.take() {
+        Some(state) => state,
+        None => return,
+    };
+    let flags = state.Flags;
+    let mouse = g.IO.MousePos;
+
+    // Update where the dragged clip would land. SequencerClip() reports it on release, the drag is lost if the clip isn't submitted anymore.
+    if state.Drag != ImGuiSequencerDrag::None && g.ActiveId != state.DragID {
+        state.Drag = ImGuiSequencerDrag::None;
+    }
+    if state.Drag != ImGuiSequencerDrag::None && g.IO.MouseDown[0] {
+        let delta = state.XToFrame(mouse.x) - state.DragMouseFrame;
+        let (orig_start, orig_end) = (state.DragOrigStart, state.DragOrigEnd);
+        match state.Drag {
+            ImGuiSequencerDrag::Move => {
+                let length = orig_end - orig_start;
+                let start = SequencerSnap(flags, orig_start + delta).max(state.FrameMin).min((state.FrameMax - length).max(state.FrameMin));
+                state.DragStart = start;
+                state.DragEnd = start + length;
+                if !flag_set(flags, ImGuiSequencerFlags_NoTrackChange) {
+                    if let Some(track) = state.TrackAt(mouse.y) {
+                        state.DragTrack = track as c_int;
+                    }
+                }
+            }
+            ImGuiSequencerDrag::TrimStart => state.DragStart = SequencerSnap(flags, orig_start + delta).max(state.FrameMin).min(orig_end - SEQUENCER_CLIP_LENGTH_MIN),
+            ImGuiSequencerDrag::TrimEnd => state.DragEnd = SequencerSnap(flags, orig_end + delta).min(state.FrameMax).max(orig_start + SEQUENCER_CLIP_LENGTH_MIN),
+            ImGuiSequencerDrag::None => {}
+        }
+    }
+
+    if let (Some(first), Some(last)) = (state.TrackRects.first().copied(), state.TrackRects.last().copied()) {
+        // Back in the timeline cell of the last track, so that what follows is clipped below the ruler
+        TableSetColumnIndex(1);
+        let clip_rect = g.current_window_mut().unwrap().ClipRect;
+        let visible_bb = ImRect::from_floats(first.min.x.max(clip_rect.x), first.min.y.max(clip_rect.y), last.max.x.min(clip_rect.z), last.max.y.min(clip_rect.w));
+
+        // Hovered when the mouse is over a clip or the empty timeline. Ctrl+mouse wheel zooms instead of scrolling.
+        let timeline_hovered = visible_bb.min.x < visible_bb.max.x && visible_bb.min.y < visible_bb.max.y && ItemHoverable(&visible_bb, state.ID);
+        state.Hovered = timeline_hovered || state.ClipHovered;
+        if state.Hovered && g.IO.KeyCtrl && !flag_set(flags, ImGuiSequencerFlags_NoZoom) {
+            g.HoveredIdUsingMouseWheel = true;
+        }
+
+        let playhead_x = state.FrameToX(state.CurrentFrame).floor();
+        let col_playhead = GetColorU32(ImGuiCol_PlotLinesHovered, 1.0);
+        g.current_window_mut().unwrap().DrawList.AddLine(Vector2::from_floats(playhead_x, first.min.y), Vector2::from_floats(playhead_x, last.max.y), col_playhead, 1.0);
+        if state.Drag != ImGuiSequencerDrag::None && state.DragTrack >= 0 && (state.DragTrack as usize) < state.TrackRects.len() {
+            let track_rect = state.TrackRects[state.DragTrack as usize];
+            let bb = ImRect::from_floats(state.FrameToX(state.DragStart), track_rect.min.y + 2.0, state.FrameToX(state.DragEnd), track_rect.max.y - 2.0);
+            SequencerRenderClip(g, bb, &state.DragLabel, state.DragColor, false, true);
+        }
+        if state.Drag == ImGuiSequencerDrag::TrimStart || state.Drag == ImGuiSequencerDrag::TrimEnd {
+            g.MouseCursor = ImGuiMouseCursor_ResizeEW;
+        }
+    } else {
+        state.Hovered = false;
+    }
+    EndTable();
+    g.SequencerLastID = state.ID;
+    g.Sequencers.insert(state.ID, state);
+}
+
+// Start a new track: a row of the table, the following SequencerClip() calls go on it
+pub unsafe fn SequencerTrack(g: &mut AppContext, label: &str) {
+    // IM_ASSERT(g.CurrentSequencer.is_some()); // Call between BeginSequencer() and EndSequencer()
+    let track_height = g.FontSize * SEQUENCER_TRACK_HEIGHT;
+    TableNextRow(ImGuiTableRowFlags_None, track_height);
+    if TableSetColumnIndex(0) {
+        TextEx(g, &String::from(label), ImGuiTextFlags_None);
+    }
+    TableSetColumnIndex(1);
+    let (row_min_y, row_max_y) = ((*g.CurrentTable).RowPosY1, (*g.CurrentTable).RowPosY2);
+    let state = match g.CurrentSequencer.as_mut() {
+        Some(state) => state,
+        None => return,
+    };
+    state.TrackRects.push(ImRect::from_floats(state.TimelineMinX, row_min_y, state.TimelineMinX + state.TimelineWidth, row_max_y));
+}
+
+// Clip from frame 'start' to frame 'end' on the current track. 'col' is the fill color.
+// Returns true when the clip was clicked (released without being moved or trimmed).
+pub unsafe fn SequencerClip(g: &mut AppContext, clip_id: c_int, start: c_float, end: c_float, label: &str, col: u32, selected: bool) -> bool {
+    let window_id = g.current_window_mut().unwrap().ID;
+    let (sequencer_id, flags, track, x_min, x_max, track_rect) = match g.CurrentSequencer.as_ref() {
+        // IM_ASSERT(!state.TrackRects.is_empty()); // Call SequencerTrack() first
+        Some(state) if !state.TrackRects.is_empty() => (state.ID, state.Flags, state.TrackRects.len() as c_int - 1, state.FrameToX(start), state.FrameToX(end), *state.TrackRects.last().unwrap()),
+        _ => return false,
+    };
+    let id = hash_data(&clip_id.to_le_bytes(), sequencer_id);
+    let bb = ImRect::from_floats(x_min, track_rect.min.y + 2.0, x_max.max(x_min + 1.0), track_rect.max.y - 2.0);
+    let mut item_bb = bb;
+    let visible = ItemAdd(g, &mut item_bb, id, None, 0);
+    // A clip scrolled out of view while being dragged still needs its release handled
+    if !visible && g.ActiveId != id {
+        return false;
+    }
+
+    // Edges trim the clip when it is wide enough to also be moved
+    let hovered = visible && ItemHoverable(&bb, id);
+    let mouse = g.IO.MousePos;
+    let trim = !flag_set(flags, ImGuiSequencerFlags_NoTrim) && bb.max.x - bb.min.x > SEQUENCER_TRIM_HANDLE_WIDTH * 3.0;
+    let hovered_drag = if trim && mouse.x < bb.min.x + SEQUENCER_TRIM_HANDLE_WIDTH {
+        ImGuiSequencerDrag::TrimStart
+    } else if trim && mouse.x > bb.max.x - SEQUENCER_TRIM_HANDLE_WIDTH {
+        ImGuiSequencerDrag::TrimEnd
+    } else {
+        ImGuiSequencerDrag::Move
+    };
+    if hovered && hovered_drag != ImGuiSequencerDrag::Move {
+        g.MouseCursor = ImGuiMouseCursor_ResizeEW;
+    }
+
+    let mut clicked = false;
+    if hovered && g.IO.MouseClicked[0] {
+        SetActiveID(g, id, None);
+        g.ActiveIdWindow = window_id;
+        let state = g.CurrentSequencer.as_mut().unwrap();
+        state.Drag = hovered_drag;
+        state.DragID = id;
+        state.DragClipID = clip_id;
+        state.DragLabel = String::from(label);
+        state.DragColor = col;
+        state.DragMouseFrame = state.XToFrame(mouse.x);
+        state.DragOrigTrack = track;
+        state.DragOrigStart = start;
+        state.DragOrigEnd = end;
+        state.DragTrack = track;
+        state.DragStart = start;
+        state.DragEnd = end;
+    }
+    if g.ActiveId == id && !g.IO.MouseDown[0] {
+        ClearActiveID(g);
+        let state = g.CurrentSequencer.as_mut().unwrap();
+        if state.DragTrack != state.DragOrigTrack || state.DragStart != state.DragOrigStart || state.DragEnd != state.DragOrigEnd {
+            let event_type = match state.Drag {
+                ImGuiSequencerDrag::TrimStart => ImGuiSequencerEventType_TrimStart,
+                ImGuiSequencerDrag::TrimEnd => ImGuiSequencerEventType_TrimEnd,
+                _ => ImGuiSequencerEventType_Move,
+            };
+            state.Events.push(ImGuiSequencerEvent { Type: event_type, ClipID: clip_id, Track: state.DragTrack, Start: state.DragStart, End: state.DragEnd });
+        } else {
+            clicked = true;
+        }
+        state.Drag = ImGuiSequencerDrag::None;
+    }
+    if hovered {
+        g.CurrentSequencer.as_mut().unwrap().ClipHovered = true;
+    }
+
+    // The dragged clip shows where it would land, drawn by EndSequencer() on its target track. Outline its current place.
+    if visible {
+        let dragged = g.ActiveId == id && g.CurrentSequencer.as_ref().unwrap().Drag != ImGuiSequencerDrag::None;
+        if dragged {
+            let rounding = g.style.FrameRounding;
+            let col_outline = GetColorU32(ImGuiCol_TextDisabled, 1.0);
+            g.current_window_mut().unwrap().DrawList.AddRect(bb.min, bb.max, col_outline, rounding);
+        } else {
+            SequencerRenderClip(g, bb, label, col, selected, hovered);
+        }
+    }
+    clicked
+}
+
+// Clip edits of the last sequencer, in the order the clips were released. Call after EndSequencer().
+pub fn GetSequencerEvents(g: &AppContext) -> Vec<ImGuiSequencerEvent> {
+    SequencerForQueries(g).map_or(Vec::new(), |state| state.Events.clone())
+}
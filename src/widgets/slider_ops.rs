@@ -6,6 +6,8 @@ use crate::color::{
 use crate::data_type::{
     ImGuiDataType, IM_GUI_DATA_TYPE_DOUBLE, IM_GUI_DATA_TYPE_FLOAT, IM_GUI_DATA_TYPE_S32,
 };
+use crate::data_type::data_type_extended_ops::{DataTypeExtendedGetRatio, DataTypeExtendedSetFromRatio, DataTypeIsExtended};
+use crate::data_type::data_type_scalar::{ImGuiScalar, ImScalarBytes, ImScalarBytesMut};
 use crate::data_type_info::GDATA_TYPE_INFO;
 use crate::core::direction::{ImGuiDir_Down, ImGuiDir_Left, ImGuiDir_Right, ImGuiDir_Up};
 use crate::widgets::group_ops::{BeginGroup, EndGroup};
@@ -46,6 +48,9 @@ use crate::window::ops::GetCurrentWindow;
 use crate::window::ImguiWindow;
 use crate::{data_type_ops, input_num_ops, text_ops, widgets};
 use libc::{c_char, c_float, c_int, size_t};
+use crate::widgets::undo_ops::UndoTrackItemValue;
+use crate::widgets::unit::ImGuiUnit;
+use crate::widgets::unit_ops::{GetNextItemUnit, SetNextItemUnit};
 use std::borrow::Borrow;
 use std::ptr::{null, null_mut};
 
@@ -324,13 +329,13 @@ pub unsafe fn SliderBehaviorT(
 // For 32-bit and larger types, slider bounds are limited to half the natural type range.
 // So e.g. an integer Slider between INT_MAX-10 and INT_MAX will fail, but an integer Slider between INT_MAX/2-10 and INT_MAX/2 will be ok.
 // It would be possible to lift that limitation with some work but it doesn't seem to be worth it for sliders.
-pub unsafe fn SliderBehavior(
+pub unsafe fn SliderBehavior<T: ImGuiScalar>(
     bb: &ImRect,
     id: ImguiHandle,
     data_type: ImGuiDataType,
-    p_v: &mut c_float,
-    p_min: c_float,
-    p_max: c_float,
+    p_v: &mut T,
+    p_min: T,
+    p_max: T,
     format: &str,
     flags: ImGuiSliderFlags,
     out_grab_bb: &mut ImRect,
@@ -346,17 +351,39 @@ pub unsafe fn SliderBehavior(
         return false;
     }
 
-    SliderBehaviorT(
+    // Extended types: the slider runs on the position of the value in the range (0.0..1.0), which is then mapped back in the type itself.
+    // Logarithmic mapping isn't supported for them.
+    if DataTypeIsExtended(data_type) {
+        // IM_ASSERT(mem::size_of::<T>() == GDATA_TYPE_INFO[data_type].Size);
+        let mut t: c_float = DataTypeExtendedGetRatio(data_type, ImScalarBytes(p_v), ImScalarBytes(&p_min), ImScalarBytes(&p_max));
+        if !SliderBehaviorT(bb, id, IM_GUI_DATA_TYPE_FLOAT, &mut t, 0.0, 1.0, format, (flags & !ImGuiSliderFlags_Logarithmic) | ImGuiSliderFlags_NoRoundToFormat, out_grab_bb) {
+            return false;
+        }
+        let mut v_new: T = *p_v;
+        DataTypeExtendedSetFromRatio(data_type, t, ImScalarBytes(&p_min), ImScalarBytes(&p_max), ImScalarBytesMut(&mut v_new));
+        if ImScalarBytes(&v_new) == ImScalarBytes(p_v) {
+            return false;
+        }
+        *p_v = v_new;
+        return true;
+    }
+
+    let mut v: c_float = p_v.ToFloat();
+    if !SliderBehaviorT(
         bb,
         id,
         IM_GUI_DATA_TYPE_S32,
-        p_v,
-        p_min,
-        p_max,
+        &mut v,
+        p_min.ToFloat(),
+        p_max.ToFloat(),
         format,
         flags,
         out_grab_bb,
-    )
+    ) {
+        return false;
+    }
+    *p_v = T::FromFloat(v);
+    true
 
     // match data_type
     // {
@@ -394,12 +421,12 @@ pub unsafe fn SliderBehavior(
 
 // Note: p_data, p_min and p_max are _pointers_ to a memory address holding the data. For a slider, they are all required.
 // Read code of e.g. SliderFloat(), SliderInt() etc. or examples in 'Demo->Widgets->Data Types' to understand how to use this function directly.
-pub unsafe fn SliderScalar(
+pub unsafe fn SliderScalar<T: ImGuiScalar>(
     label: String,
     data_type: ImGuiDataType,
-    p_data: &mut c_float,
-    p_min: c_float,
-    p_max: c_float,
+    p_data: &mut T,
+    p_min: T,
+    p_max: T,
     format: &mut String,
     flags: ImGuiSliderFlags,
 ) -> bool {
@@ -411,7 +438,9 @@ pub unsafe fn SliderScalar(
     let g = GImGui; // ImGuiContext& g = *GImGui;
     let setyle = &mut g.style;
     let mut id: ImguiHandle = window.GetID(label);
+    let value_before: T = *p_data;
     let w: c_float = CalcItemWidth(g);
+    let unit: Option<ImGuiUnit> = GetNextItemUnit(g);
 
     let label_size: Vector2 = CalcTextSize(, label, true, 0.0);
     let mut frame_bb: ImRect = ImRect::new(
@@ -483,16 +512,19 @@ pub unsafe fn SliderScalar(
     if temp_input_is_active {
         // Only clamp CTRL+Click input when ImGuiSliderFlags_AlwaysClamp is set
         let is_clamp_input: bool = flag_set(flags, ImGuiSliderFlags_AlwaysClamp);
-        return input_num_ops::TempInputScalar(
+        let value_changed: bool = input_num_ops::TempInputScalar(
             &mut frame_bb,
             id,
-            label,
+            label.clone(),
             data_type,
             p_data,
             format,
-            if is_clamp_input { p_min } else { None },
-            if is_clamp_input { p_max } else { None },
+            if is_clamp_input { Some(p_min) } else { None },
+            if is_clamp_input { Some(p_max) } else { None },
+            unit.as_ref(),
         );
+        UndoTrackItemValue(g, &label, p_data, value_before, value_changed);
+        return value_changed;
     }
 
     // Draw frame
@@ -559,8 +591,9 @@ pub unsafe fn SliderScalar(
             value_buf,
             value_buf.len(),
             data_type,
-            *p_data,
+            p_data,
             format,
+            unit.as_ref(),
         );
     if g.LogEnabled {
         LogSetNextTextDecoration("{", "}");
@@ -586,18 +619,20 @@ pub unsafe fn SliderScalar(
         );
     }
 
+    UndoTrackItemValue(g, &label, p_data, value_before, value_changed);
+
     IMGUI_TEST_ENGINE_ITEM_INFO(id, label, g.last_item_data.StatusFlags);
     return value_changed;
 }
 
 // Add multiple sliders on 1 line for compact edition of multiple components
-pub unsafe fn SliderScalarN(
+pub unsafe fn SliderScalarN<T: ImGuiScalar>(
     label: String,
     data_type: ImGuiDataType,
-    v: &mut [c_float],
+    v: &mut [T],
     components: usize,
-    v_min: &mut [c_float],
-    v_max: &mut [c_float],
+    v_min: &[T],
+    v_max: &[T],
     format: &str,
     flags: ImGuiSliderFlags,
 ) -> bool {
@@ -608,6 +643,7 @@ pub unsafe fn SliderScalarN(
 
     let g = GImGui; // ImGuiContext& g = *GImGui;
     let mut value_changed: bool = false;
+    let unit: Option<ImGuiUnit> = GetNextItemUnit(g);
     BeginGroup();
     PushID(label);
     PushMultiItemsWidths(components, CalcItemWidth(g));
@@ -618,6 +654,9 @@ pub unsafe fn SliderScalarN(
         if i > 0 {
             same_line(g, 0.0, g.style.ItemInnerSpacing.x);
         }
+        if let Some(unit) = &unit {
+            SetNextItemUnit(g, unit);
+        }
         value_changed |= SliderScalar(
             "",
             data_type,
@@ -843,13 +882,13 @@ pub unsafe fn SliderInt4(
     );
 }
 
-pub unsafe fn VSliderScalar(
+pub unsafe fn VSliderScalar<T: ImGuiScalar>(
     label: String,
     size: &Vector2,
     data_type: ImGuiDataType,
-    p_data: &mut c_float,
-    p_min: c_float,
-    p_max: c_float,
+    p_data: &mut T,
+    p_min: T,
+    p_max: T,
     format: &mut String,
     flags: ImGuiSliderFlags,
 ) -> bool {
@@ -861,6 +900,7 @@ pub unsafe fn VSliderScalar(
     let g = GImGui; // ImGuiContext& g = *GImGui;
     let setyle = &mut g.style;
     let mut id: ImguiHandle = window.GetID(label);
+    let unit: Option<ImGuiUnit> = GetNextItemUnit(g);
 
     let label_size: Vector2 = CalcTextSize(, label, true, 0.0);
     let mut frame_bb: ImRect = ImRect::new(window.dc.cursor_pos, window.dc.cursor_pos + size);
@@ -963,8 +1003,9 @@ pub unsafe fn VSliderScalar(
             value_buf,
             value_buf.len(),
             data_type,
-            *p_data,
+            p_data,
             format,
+            unit.as_ref(),
         );
     RenderTextClipped(
         Vector2::new(frame_bb.min.x, frame_bb.min.y + style.FramePadding.y),
@@ -0,0 +1,555 @@
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use libc::{c_float, c_int};
+use crate::font::ImFont;
+use crate::GImGui;
+use crate::stb::stb_text_edit_row::StbTexteditRow;
+use crate::stb::stb_text_edit_state::STB_TexteditState;
+use crate::stb::stb_textedit::{stb_text_makeundo_replace, stb_textedit_cut, stb_textedit_initialize_state, stb_textedit_key, stb_textedit_paste};
+use crate::stb::{StbTexteditString, STB_TEXTEDIT_DELETECHARS, STB_TEXTEDIT_GETWIDTH_NEWLINE, STB_TEXTEDIT_INSERTCHARS, STB_TEXTEDIT_K_REDO, STB_TEXTEDIT_K_UNDO};
+
+// Palette entries a tokenizer can assign to a span (index into ImGuiTextEditor::Palette)
+pub type ImGuiTextEditorColor = c_int;
+pub const ImGuiTextEditorColor_Default: ImGuiTextEditorColor = 0;
+pub const ImGuiTextEditorColor_Keyword: ImGuiTextEditorColor = 1;
+pub const ImGuiTextEditorColor_Type: ImGuiTextEditorColor = 2;
+pub const ImGuiTextEditorColor_Number: ImGuiTextEditorColor = 3;
+pub const ImGuiTextEditorColor_String: ImGuiTextEditorColor = 4;
+pub const ImGuiTextEditorColor_Comment: ImGuiTextEditorColor = 5;
+pub const ImGuiTextEditorColor_Preprocessor: ImGuiTextEditorColor = 6;
+pub const ImGuiTextEditorColor_Punctuation: ImGuiTextEditorColor = 7;
+pub const ImGuiTextEditorColor_COUNT: usize = 8;
+
+// A colored span of a line, in characters: [Start, End)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImGuiTextEditorToken {
+    pub Start: usize,
+    pub End: usize,
+    pub Color: ImGuiTextEditorColor,
+}
+
+// Pluggable syntax highlighting.
+// - Lines are tokenized in order. 'state_in' is what the previous line returned (0 for the first line),
+//   which lets a tokenizer carry multi-line constructs such as block comments or raw strings.
+// - Characters not covered by a token use ImGuiTextEditorColor_Default.
+// - Only lines up to the last visible one are ever tokenized and results are cached per line. After an edit, lines are
+//   tokenized again from the edited one until the state passed to the next line matches what was cached for it.
+pub trait ImGuiTextEditorTokenizer {
+    fn TokenizeLine(&self, line: &[char], state_in: u32, out_tokens: &mut Vec<ImGuiTextEditorToken>) -> u32;
+}
+
+// Tokenizer for C-like languages (GLSL/HLSL shaders, most scripting languages): '//' and '/* */' comments,
+// "strings", 'chars', numbers, '#' preprocessor lines and two configurable word lists.
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiTextEditorCLikeTokenizer {
+    pub Keywords: Vec<String>,
+    pub Types: Vec<String>,
+}
+
+const CLIKE_STATE_NONE: u32 = 0;
+const CLIKE_STATE_BLOCK_COMMENT: u32 = 1;
+
+impl ImGuiTextEditorCLikeTokenizer {
+    pub fn new(keywords: &[&str], types: &[&str]) -> Self {
+        Self {
+            Keywords: keywords.iter().map(|s| s.to_string()).collect(),
+            Types: types.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl ImGuiTextEditorTokenizer for ImGuiTextEditorCLikeTokenizer {
+    fn TokenizeLine(&self, line: &[char], state_in: u32, out_tokens: &mut Vec<ImGuiTextEditorToken>) -> u32 {
+        let len = line.len();
+        let mut i = 0;
+        let mut push = |start: usize, end: usize, color: ImGuiTextEditorColor| out_tokens.push(ImGuiTextEditorToken { Start: start, End: end, Color: color });
+
+        // Continue a block comment from a previous line
+        if state_in == CLIKE_STATE_BLOCK_COMMENT {
+            match (0..len.saturating_sub(1)).find(|&n| line[n] == '*' && line[n + 1] == '/') {
+                Some(n) => { push(0, n + 2, ImGuiTextEditorColor_Comment); i = n + 2; }
+                None => { push(0, len, ImGuiTextEditorColor_Comment); return CLIKE_STATE_BLOCK_COMMENT; }
+            }
+        }
+
+        // Preprocessor lines are colored as a whole
+        if state_in == CLIKE_STATE_NONE {
+            if let Some(first) = line.iter().position(|c| !c.is_whitespace()) {
+                if line[first] == '#' {
+                    push(first, len, ImGuiTextEditorColor_Preprocessor);
+                    return CLIKE_STATE_NONE;
+                }
+            }
+        }
+
+        while i < len {
+            let c = line[i];
+            let start = i;
+            if c == '/' && i + 1 < len && line[i + 1] == '/' {
+                push(start, len, ImGuiTextEditorColor_Comment);
+                return CLIKE_STATE_NONE;
+            } else if c == '/' && i + 1 < len && line[i + 1] == '*' {
+                match (i + 2..len.saturating_sub(1)).find(|&n| line[n] == '*' && line[n + 1] == '/') {
+                    Some(n) => { push(start, n + 2, ImGuiTextEditorColor_Comment); i = n + 2; }
+                    None => { push(start, len, ImGuiTextEditorColor_Comment); return CLIKE_STATE_BLOCK_COMMENT; }
+                }
+            } else if c == '"' || c == '\'' {
+                i += 1;
+                while i < len && line[i] != c {
+                    i += if line[i] == '\\' { 2 } else { 1 };
+                }
+                i = (i + 1).min(len);
+                push(start, i, ImGuiTextEditorColor_String);
+            } else if c.is_ascii_digit() || (c == '.' && i + 1 < len && line[i + 1].is_ascii_digit()) {
+                while i < len && (line[i].is_ascii_alphanumeric() || line[i] == '.' || line[i] == '_') {
+                    i += 1;
+                }
+                push(start, i, ImGuiTextEditorColor_Number);
+            } else if c.is_alphabetic() || c == '_' {
+                while i < len && (line[i].is_alphanumeric() || line[i] == '_') {
+                    i += 1;
+                }
+                let word: String = line[start..i].iter().collect();
+                if self.Keywords.iter().any(|k| *k == word) {
+                    push(start, i, ImGuiTextEditorColor_Keyword);
+                } else if self.Types.iter().any(|t| *t == word) {
+                    push(start, i, ImGuiTextEditorColor_Type);
+                }
+            } else if c.is_ascii_punctuation() {
+                i += 1;
+                push(start, i, ImGuiTextEditorColor_Punctuation);
+            } else {
+                i += 1;
+            }
+        }
+        CLIKE_STATE_NONE
+    }
+}
+
+// Tokenizer output cache for one line
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiTextEditorLineColors {
+    pub StateIn: u32,
+    pub StateOut: u32,
+    pub Tokens: Vec<ImGuiTextEditorToken>,
+}
+
+// Text editor document + editing state, owned by the application and passed to TextEditor() every frame.
+// - The text is stored per line, so an edit only touches the lines it spans: nothing is copied in or out of a
+//   flat buffer when the widget is activated, and a 50k lines document costs nothing while not being edited.
+// - Cursor and selection use the stb_textedit state (STB_TexteditState) with absolute character offsets, the same way
+//   InputText() does, so cursor/select_start/select_end/preferred_x have the same meaning.
+// - Keys, cut and paste go through stb_textedit_key()/stb_textedit_cut()/stb_textedit_paste() like InputText(), rows are
+//   whole lines (no wrapping). Edits are recorded in Stb.undostate, so undo/redo and their limits
+//   (STB_TEXTEDIT_UNDOSTATECOUNT records, STB_TEXTEDIT_UNDOCHARCOUNT characters) match InputText().
+// - Use SetText()/GetText() to load and save, 'Edited' tells you the document changed since you last cleared it.
+pub struct ImGuiTextEditor {
+    pub Lines: Vec<Vec<char>>,
+    pub LineStarts: Vec<usize>,                 // Absolute offset of the first character of each line (newlines count as one character)
+    pub Stb: STB_TexteditState,
+    pub CursorAnim: c_float,
+    pub CursorFollow: bool,                     // Scroll to the cursor on next frame
+    pub Edited: bool,                           // Set on every modification, never cleared by the widget
+    pub TabSize: usize,
+    pub Tokenizer: Option<Box<dyn ImGuiTextEditorTokenizer>>,
+    pub Palette: [u32; ImGuiTextEditorColor_COUNT], // 0 entries use the style's Text color
+    pub LineColors: Vec<ImGuiTextEditorLineColors>, // Tokenizer cache, valid for lines [0, ColorsValidEnd)
+    pub ColorsValidEnd: usize,
+    pub ColorsDirtyEnd: usize,                  // Entries in [ColorsValidEnd, ColorsDirtyEnd) were edited, entries after it are kept from before the edit
+    pub ContentWidth: c_float,                  // Widest line measured so far (only visible lines are measured)
+}
+
+impl Default for ImGuiTextEditor {
+    fn default() -> Self {
+        let mut stb = STB_TexteditState::default();
+        unsafe { stb_textedit_initialize_state(&mut stb, false) };
+        Self {
+            Lines: vec![Vec::new()],
+            LineStarts: vec![0],
+            Stb: stb,
+            CursorAnim: 0.0,
+            CursorFollow: false,
+            Edited: false,
+            TabSize: 4,
+            Tokenizer: None,
+            Palette: [
+                0,                  // Default
+                0xFFD69C56,         // Keyword
+                0xFFB0C94E,         // Type
+                0xFFA8CEB5,         // Number
+                0xFF7891CE,         // String
+                0xFF55996A,         // Comment
+                0xFFC086C5,         // Preprocessor
+                0xFFB4B4B4,         // Punctuation
+            ],
+            LineColors: Vec::new(),
+            ColorsValidEnd: 0,
+            ColorsDirtyEnd: 0,
+            ContentWidth: 0.0,
+        }
+    }
+}
+
+// How many lines ColorizeLines() may tokenize per call. Jumping to the end of a large document colors it over a few frames
+// instead of stalling one, lines not reached yet keep their previous colors.
+const COLORIZE_MAX_LINES_PER_FRAME: usize = 4096;
+
+impl StbTexteditString for ImGuiTextEditor {
+    fn StringLen(&self) -> usize {
+        self.TextLength()
+    }
+
+    fn GetChar(&self, idx: usize) -> char {
+        self.CharAt(idx).unwrap_or('\n')
+    }
+
+    unsafe fn DeleteChars(&mut self, pos: usize, n: usize) {
+        self.ReplaceRangeRaw(pos, pos + n, &[]);
+    }
+
+    unsafe fn InsertChars(&mut self, pos: usize, new_text: &[char]) -> bool {
+        self.ReplaceRangeRaw(pos, pos, new_text);
+        true
+    }
+
+    // Rows are whole lines: we don't wrap
+    unsafe fn LayoutRow(&mut self, r: &mut StbTexteditRow, line_start_idx: usize) {
+        let g = GImGui; // ImGuiContext& g = *GImGui;
+        let scale = g.FontSize / g.Font.FontSize;
+        let (line, column) = self.CoordOf(line_start_idx);
+        let line_len = self.Lines[line].len();
+        r.x0 = 0.0;
+        r.x1 = self.ColumnX(&g.Font, scale, line, line_len) - self.ColumnX(&g.Font, scale, line, column);
+        r.baseline_y_delta = g.FontSize;
+        r.ymin = 0.0;
+        r.ymax = g.FontSize;
+        r.num_chars = line_len - column + if line + 1 < self.Lines.len() { 1 } else { 0 };
+    }
+
+    unsafe fn GetWidth(&mut self, line_start_idx: usize, char_idx: usize) -> f32 {
+        let (line, column) = self.CoordOf(line_start_idx + char_idx);
+        let c = match self.Lines[line].get(column) {
+            Some(&c) => c,
+            None => return STB_TEXTEDIT_GETWIDTH_NEWLINE,
+        };
+        let g = GImGui; // ImGuiContext& g = *GImGui;
+        let scale = g.FontSize / g.Font.FontSize;
+        let x = if c == '\t' { self.ColumnX(&g.Font, scale, line, column) } else { 0.0 };
+        self.CharAdvance(&g.Font, scale, c, x)
+    }
+
+    // Words stop at the start of the line, and skip the whitespace before them
+    unsafe fn MoveWordLeft(&mut self, idx: usize) -> usize {
+        let (line, mut column) = self.CoordOf(idx);
+        if column == 0 {
+            return idx.saturating_sub(1);
+        }
+        let chars = &self.Lines[line];
+        while column > 0 && chars[column - 1].is_whitespace() { column -= 1; }
+        if column > 0 && IsWordChar(chars[column - 1]) {
+            while column > 0 && IsWordChar(chars[column - 1]) { column -= 1; }
+        } else if column > 0 {
+            column -= 1;
+        }
+        self.PosOf(line, column)
+    }
+
+    // Words stop at the end of the line, and include the whitespace after them
+    unsafe fn MoveWordRight(&mut self, idx: usize) -> usize {
+        let (line, mut column) = self.CoordOf(idx);
+        let chars = &self.Lines[line];
+        if column >= chars.len() {
+            return (idx + 1).min(self.TextLength());
+        }
+        if IsWordChar(chars[column]) {
+            while column < chars.len() && IsWordChar(chars[column]) { column += 1; }
+        } else if !chars[column].is_whitespace() {
+            column += 1;
+        }
+        while column < chars.len() && chars[column].is_whitespace() { column += 1; }
+        self.PosOf(line, column)
+    }
+}
+
+pub fn IsWordChar(c: char) -> bool { c.is_alphanumeric() || c == '_' }
+
+impl ImGuiTextEditor {
+    pub fn SetText(&mut self, text: &str) {
+        self.Lines = text.split('\n').map(|l| l.trim_end_matches('\r').chars().collect()).collect();
+        self.RebuildLineStarts(0);
+        unsafe { stb_textedit_initialize_state(&mut self.Stb, false) };
+        self.ClearColors();
+        self.ContentWidth = 0.0;
+        self.Edited = false;
+    }
+
+    pub fn GetText(&self) -> String {
+        let mut out = String::with_capacity(self.TextLength());
+        for (n, line) in self.Lines.iter().enumerate() {
+            if n > 0 {
+                out.push('\n');
+            }
+            out.extend(line.iter());
+        }
+        out
+    }
+
+    pub fn SetTokenizer(&mut self, tokenizer: Option<Box<dyn ImGuiTextEditorTokenizer>>) {
+        self.Tokenizer = tokenizer;
+        self.ClearColors();
+    }
+
+    fn ClearColors(&mut self) {
+        self.LineColors.clear();
+        self.ColorsValidEnd = 0;
+        self.ColorsDirtyEnd = 0;
+    }
+
+    pub fn GetLineCount(&self) -> usize { self.Lines.len() }
+    pub fn TextLength(&self) -> usize { self.LineStarts[self.Lines.len() - 1] + self.Lines.last().unwrap().len() }
+
+    // Cursor & Selection (same helpers as ImGuiInputTextState)
+    pub fn CursorAnimReset(&mut self) { self.CursorAnim = -0.3; }
+    pub fn HasSelection(&self) -> bool { self.Stb.select_start != self.Stb.select_end }
+    pub fn ClearSelection(&mut self) { self.Stb.select_start = self.Stb.cursor; self.Stb.select_end = self.Stb.cursor; }
+    pub fn SelectAll(&mut self) { self.Stb.select_start = 0; self.Stb.cursor = self.TextLength(); self.Stb.select_end = self.Stb.cursor; self.Stb.has_preferred_x = 0; }
+    pub fn GetSelection(&self) -> (usize, usize) { (self.Stb.select_start.min(self.Stb.select_end), self.Stb.select_start.max(self.Stb.select_end)) }
+
+    // Position <> (line, column) conversions
+    pub fn LineOf(&self, pos: usize) -> usize { self.LineStarts.partition_point(|&start| start <= pos) - 1 }
+    pub fn CoordOf(&self, pos: usize) -> (usize, usize) {
+        let line = self.LineOf(pos);
+        (line, (pos - self.LineStarts[line]).min(self.Lines[line].len()))
+    }
+    pub fn PosOf(&self, line: usize, column: usize) -> usize {
+        let line = line.min(self.Lines.len() - 1);
+        self.LineStarts[line] + column.min(self.Lines[line].len())
+    }
+    pub fn CharAt(&self, pos: usize) -> Option<char> {
+        let (line, column) = self.CoordOf(pos);
+        match self.Lines[line].get(column) {
+            Some(&c) => Some(c),
+            None if line + 1 < self.Lines.len() => Some('\n'),
+            None => None,
+        }
+    }
+    pub fn GetTextRange(&self, start: usize, end: usize) -> Vec<char> {
+        let mut out = Vec::with_capacity(end - start);
+        let (l0, c0) = self.CoordOf(start);
+        let (l1, c1) = self.CoordOf(end);
+        for line in l0..=l1 {
+            let from = if line == l0 { c0 } else { 0 };
+            let to = if line == l1 { c1 } else { self.Lines[line].len() };
+            out.extend_from_slice(&self.Lines[line][from..to]);
+            if line != l1 {
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    fn RebuildLineStarts(&mut self, from_line: usize) {
+        self.LineStarts.truncate(from_line.max(1));
+        self.LineStarts[0] = 0;
+        for n in self.LineStarts.len()..self.Lines.len() {
+            let prev = self.LineStarts[n - 1] + self.Lines[n - 1].len() + 1;
+            self.LineStarts.push(prev);
+        }
+    }
+
+    // Low-level edit: replace [start, end) with 'text'. Doesn't touch the cursor or the undo state.
+    fn ReplaceRangeRaw(&mut self, start: usize, end: usize, text: &[char]) {
+        let (l0, c0) = self.CoordOf(start);
+        let (l1, c1) = self.CoordOf(end);
+        let suffix: Vec<char> = self.Lines[l1][c1..].to_vec();
+        let mut new_lines: Vec<Vec<char>> = text.split(|&c| c == '\n').map(|s| s.to_vec()).collect();
+        let mut first = self.Lines[l0][..c0].to_vec();
+        first.extend_from_slice(&new_lines[0]);
+        new_lines[0] = first;
+        new_lines.last_mut().unwrap().extend_from_slice(&suffix);
+        let new_count = new_lines.len();
+        self.Lines.splice(l0..=l1, new_lines);
+        self.RebuildLineStarts(l0 + 1);
+
+        // Keep the cached colors of the lines below the edit, ColorizeLines() reuses them once the tokenizer state converges
+        if self.LineColors.len() > l1 {
+            self.LineColors.splice(l0..=l1, (0..new_count).map(|_| ImGuiTextEditorLineColors::default()));
+            let removed_count = l1 + 1 - l0;
+            let dirty_end = if self.ColorsDirtyEnd > l1 { self.ColorsDirtyEnd + new_count - removed_count } else { 0 };
+            self.ColorsDirtyEnd = dirty_end.max(l0 + new_count);
+        } else {
+            self.LineColors.truncate(l0);
+            self.ColorsDirtyEnd = l0;
+        }
+        self.ColorsValidEnd = self.ColorsValidEnd.min(l0);
+        self.Edited = true;
+    }
+
+    // Replace [start, end) with 'text', record undo and place the cursor after the inserted text.
+    pub fn ReplaceRange(&mut self, start: usize, end: usize, text: &[char]) {
+        let mut stb = std::mem::take(&mut self.Stb);
+        unsafe {
+            stb_text_makeundo_replace(self, &mut stb, start, end - start, text.len());
+            if end > start {
+                STB_TEXTEDIT_DELETECHARS(self, start, end - start);
+            }
+            if !text.is_empty() {
+                STB_TEXTEDIT_INSERTCHARS(self, start, text, text.len());
+            }
+        }
+        stb.cursor = start + text.len();
+        stb.select_start = stb.cursor;
+        stb.select_end = stb.cursor;
+        stb.has_preferred_x = 0;
+        self.Stb = stb;
+        self.CursorFollow = true;
+        self.CursorAnimReset();
+    }
+
+    // Process a key through stb_textedit_key(), same as ImGuiInputTextState::OnKeyPressed()
+    pub unsafe fn OnKeyPressed(&mut self, key: c_int) {
+        let mut stb = std::mem::take(&mut self.Stb);
+        stb_textedit_key(self, &mut stb, key);
+        self.Stb = stb;
+        self.CursorFollow = true;
+        self.CursorAnimReset();
+    }
+
+    // Delete the selection. Returns false when there is none.
+    pub unsafe fn Cut(&mut self) -> bool {
+        let mut stb = std::mem::take(&mut self.Stb);
+        let ret = stb_textedit_cut(self, &mut stb);
+        self.Stb = stb;
+        self.CursorFollow = true;
+        ret
+    }
+
+    // Replace the selection (if any) with 'text'
+    pub unsafe fn Paste(&mut self, text: &[char]) {
+        let mut stb = std::mem::take(&mut self.Stb);
+        stb_textedit_paste(self, &mut stb, text, text.len());
+        self.Stb = stb;
+        self.CursorFollow = true;
+        self.CursorAnimReset();
+    }
+
+    pub fn Undo(&mut self) {
+        unsafe { self.OnKeyPressed(STB_TEXTEDIT_K_UNDO) };
+        self.ClearSelection();
+    }
+
+    pub fn Redo(&mut self) {
+        unsafe { self.OnKeyPressed(STB_TEXTEDIT_K_REDO) };
+        self.ClearSelection();
+    }
+
+    // Make sure LineColors is valid for lines [0, line_end), within the per-frame budget
+    pub fn ColorizeLines(&mut self, line_end: usize) {
+        let line_end = line_end.min(self.Lines.len());
+        let tokenizer = match &self.Tokenizer {
+            Some(tokenizer) => tokenizer,
+            None => return,
+        };
+        let mut budget = COLORIZE_MAX_LINES_PER_FRAME;
+        while self.ColorsValidEnd < line_end && budget > 0 {
+            let n = self.ColorsValidEnd;
+            let state_in = if n > 0 { self.LineColors[n - 1].StateOut } else { 0 };
+
+            // Past the edited lines: if the line starts in the same state as before, it and every line after it are unchanged
+            if n >= self.ColorsDirtyEnd && n < self.LineColors.len() && self.LineColors[n].StateIn == state_in {
+                self.ColorsValidEnd = self.LineColors.len();
+                self.ColorsDirtyEnd = self.LineColors.len();
+                continue;
+            }
+
+            let mut colors = ImGuiTextEditorLineColors { StateIn: state_in, StateOut: 0, Tokens: Vec::new() };
+            colors.StateOut = tokenizer.TokenizeLine(&self.Lines[n], state_in, &mut colors.Tokens);
+            if n < self.LineColors.len() {
+                self.LineColors[n] = colors;
+            } else {
+                self.LineColors.push(colors);
+            }
+            self.ColorsValidEnd += 1;
+            budget -= 1;
+        }
+    }
+
+    // Find the bracket matching the one right before or right at the cursor. Returns (bracket_pos, match_pos).
+    // The search is bounded so that a missing bracket in a huge file doesn't stall the frame.
+    pub fn FindMatchingBracket(&self, max_scan: usize) -> Option<(usize, usize)> {
+        const PAIRS: [(char, char); 3] = [('(', ')'), ('[', ']'), ('{', '}')];
+        let cursor = self.Stb.cursor;
+        let candidates = [Some(cursor), cursor.checked_sub(1)];
+        for pos in candidates.iter().flatten().copied() {
+            let c = match self.CharAt(pos) { Some(c) => c, None => continue };
+            for &(open, close) in PAIRS.iter() {
+                if c != open && c != close {
+                    continue;
+                }
+                let forward = c == open;
+                let mut depth = 0;
+                let (mut line, mut column) = self.CoordOf(pos);
+                for _ in 0..max_scan {
+                    // Step one character (newlines are skipped, they can't be brackets)
+                    if forward {
+                        column += 1;
+                        while column >= self.Lines[line].len() {
+                            if line + 1 >= self.Lines.len() { return None; }
+                            line += 1;
+                            column = 0;
+                            if !self.Lines[line].is_empty() { break; }
+                        }
+                    } else {
+                        while column == 0 {
+                            if line == 0 { return None; }
+                            line -= 1;
+                            column = self.Lines[line].len();
+                        }
+                        column -= 1;
+                    }
+                    let d = self.Lines[line][column];
+                    if d == c {
+                        depth += 1;
+                    } else if d == (if forward { close } else { open }) {
+                        if depth == 0 {
+                            return Some((pos, self.PosOf(line, column)));
+                        }
+                        depth -= 1;
+                    }
+                }
+                return None;
+            }
+        }
+        None
+    }
+
+    // Leading whitespace of a line, used for auto-indent
+    pub fn GetLineIndent(&self, line: usize) -> Vec<char> {
+        self.Lines[line].iter().take_while(|c| **c == ' ' || **c == '\t').copied().collect()
+    }
+
+    // Advance of 'c' drawn at 'x' from the start of its line (tabs go to the next tab stop)
+    pub fn CharAdvance(&self, font: &ImFont, scale: c_float, c: char, x: c_float) -> c_float {
+        if c == '\t' {
+            let tab_w = font.GetCharAdvance(' ') * scale * self.TabSize.max(1) as c_float;
+            return ((x / tab_w).floor() + 1.0) * tab_w - x;
+        }
+        font.GetCharAdvance(c) * scale
+    }
+
+    // Horizontal offset of a column from the start of its line
+    pub fn ColumnX(&self, font: &ImFont, scale: c_float, line: usize, column: usize) -> c_float {
+        let mut x = 0.0;
+        for &c in self.Lines[line].iter().take(column) {
+            x += self.CharAdvance(font, scale, c, x);
+        }
+        x
+    }
+
+    // Visual column of a character (tabs expand to the next multiple of TabSize)
+    pub fn GetVisualColumn(&self, line: usize, column: usize) -> usize {
+        let tab = self.TabSize.max(1);
+        self.Lines[line][..column.min(self.Lines[line].len())].iter().fold(0, |col, &c| if c == '\t' { (col / tab + 1) * tab } else { col + 1 })
+    }
+}
@@ -0,0 +1,18 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+// typedef int ImGuiTextEditorFlags;      // -> enum ImGuiTextEditorFlags_   // Flags: for TextEditor()
+pub type ImGuiTextEditorFlags = c_int;
+
+// Flags for TextEditor()
+// enum ImGuiTextEditorFlags_
+// {
+pub const ImGuiTextEditorFlags_None: ImGuiTextEditorFlags = 0;
+pub const ImGuiTextEditorFlags_ReadOnly: ImGuiTextEditorFlags = 1 << 0;   // Allow selection and copy, but no edition.
+pub const ImGuiTextEditorFlags_NoLineNumbers: ImGuiTextEditorFlags = 1 << 1;   // Hide the line-number gutter.
+pub const ImGuiTextEditorFlags_NoCurrentLineHighlight: ImGuiTextEditorFlags = 1 << 2;   // Don't highlight the line containing the cursor.
+pub const ImGuiTextEditorFlags_NoBracketMatching: ImGuiTextEditorFlags = 1 << 3;   // Don't highlight the bracket matching the one next to the cursor.
+pub const ImGuiTextEditorFlags_TabInsertSpaces: ImGuiTextEditorFlags = 1 << 4;   // Tab inserts spaces up to the next tab stop instead of a '\t' character.
+pub const ImGuiTextEditorFlags_NoAutoIndent: ImGuiTextEditorFlags = 1 << 5;   // Enter doesn't copy the indentation of the current line.
+// };
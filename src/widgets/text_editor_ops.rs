@@ -0,0 +1,533 @@
+#![allow(non_snake_case)]
+
+//-------------------------------------------------------------------------
+// [SECTION] Widgets: TextEditor
+//-------------------------------------------------------------------------
+// - TextEditor()
+// - TextEditorHandleMouse() [Internal]
+// - TextEditorHandleKeyboard() [Internal]
+// - TextEditorColumnX() [Internal]
+// - TextEditorColumnAtX() [Internal]
+//-------------------------------------------------------------------------
+// A multi-line code editor for large documents. Unlike InputTextMultiline() the text is owned by an ImGuiTextEditor
+// kept by the application (see text_editor.rs), lines are submitted through ImGuiListClipper and only visible
+// lines are laid out, tokenized and rendered.
+// Usage:
+//   let mut editor = ImGuiTextEditor::default();
+//   editor.SetTokenizer(Some(Box::new(ImGuiTextEditorCLikeTokenizer::new(&["if", "else", "return"], &["float", "vec3"]))));
+//   editor.SetText(&source);
+//   ...
+//   if TextEditor(g, "##shader", &mut editor, Vector2::from_floats(-f32::MIN_POSITIVE, 0.0), ImGuiTextEditorFlags_None) {
+//       recompile(editor.GetText());
+//   }
+//-------------------------------------------------------------------------
+
+use std::ffi::CStr;
+use libc::{c_float, c_int};
+use crate::color::{ImGuiCol_ChildBg, ImGuiCol_FrameBg, ImGuiCol_Header, ImGuiCol_NavHighlight, ImGuiCol_Text, ImGuiCol_TextDisabled, ImGuiCol_TextSelectedBg};
+use crate::core::child_ops::{BeginChildEx, EndChild};
+use crate::core::context::AppContext;
+use crate::core::direction::{ImGuiDir_Down, ImGuiDir_Left, ImGuiDir_Right, ImGuiDir_Up};
+use crate::core::hash_ops::hash_string;
+use crate::core::id_ops::{ClearActiveID, KeepAliveID, SetActiveID};
+use crate::core::type_defs::ImguiHandle;
+use crate::core::utils::flag_set;
+use crate::core::vec2::Vector2;
+use crate::drawing::draw_flags::ImDrawFlags_None;
+use crate::io::input_ops::{IsKeyPressed, IsMouseHoveringRect};
+use crate::io::key::{ImGuiKey, ImGuiKey_A, ImGuiKey_Backspace, ImGuiKey_C, ImGuiKey_Delete, ImGuiKey_DownArrow, ImGuiKey_End, ImGuiKey_Enter, ImGuiKey_Escape, ImGuiKey_Home, ImGuiKey_Insert, ImGuiKey_KeypadEnter, ImGuiKey_LeftArrow, ImGuiKey_PageDown, ImGuiKey_PageUp, ImGuiKey_RightArrow, ImGuiKey_Tab, ImGuiKey_UpArrow, ImGuiKey_V, ImGuiKey_X, ImGuiKey_Y, ImGuiKey_Z};
+use crate::io::key_routing_ops::SetKeyOwner;
+use crate::io::mod_flags::{ImGuiModFlags_Ctrl, ImGuiModFlags_Shift, ImGuiModFlags_Super};
+use crate::io::mouse_cursor::ImGuiMouseCursor_TextInput;
+use crate::item::item_ops::{CalcItemSize, CalcItemWidth, MarkItemEdited, ItemSize};
+use crate::stb::stb_textedit::{stb_text_begin_undo_group, stb_text_end_undo_group};
+use crate::stb::{STB_TEXTEDIT_K_BACKSPACE, STB_TEXTEDIT_K_DELETE, STB_TEXTEDIT_K_DOWN, STB_TEXTEDIT_K_LEFT, STB_TEXTEDIT_K_LINEEND, STB_TEXTEDIT_K_LINESTART, STB_TEXTEDIT_K_PGDOWN, STB_TEXTEDIT_K_PGUP, STB_TEXTEDIT_K_RIGHT, STB_TEXTEDIT_K_SHIFT, STB_TEXTEDIT_K_TEXTEND, STB_TEXTEDIT_K_TEXTSTART, STB_TEXTEDIT_K_UP, STB_TEXTEDIT_K_WORDLEFT, STB_TEXTEDIT_K_WORDRIGHT};
+use crate::style::style_ops::{GetColorU32, PopStyleColor, PushStyleColor};
+use crate::style::style_var::ImGuiStyleVar_WindowPadding;
+use crate::style::style_var_ops::{PopStyleVarInt, PushStyleVarVec2};
+use crate::text::text_ops::CalcTextSize;
+use crate::widgets::input_flags::ImGuiInputFlags_None;
+use crate::widgets::list_clipper::ImGuiListClipper;
+use crate::widgets::scrolling_ops::{SetScrollX, SetScrollY};
+use crate::widgets::text_editor::{IsWordChar, ImGuiTextEditor, ImGuiTextEditorColor_Default};
+use crate::widgets::text_editor_flags::{ImGuiTextEditorFlags, ImGuiTextEditorFlags_NoAutoIndent, ImGuiTextEditorFlags_NoBracketMatching, ImGuiTextEditorFlags_NoCurrentLineHighlight, ImGuiTextEditorFlags_NoLineNumbers, ImGuiTextEditorFlags_ReadOnly, ImGuiTextEditorFlags_TabInsertSpaces};
+use crate::window::window_flags::{ImGuiWindowFlags_HorizontalScrollbar, ImGuiWindowFlags_NoMove};
+
+// How far FindMatchingBracket() may look, in characters
+const BRACKET_MATCH_MAX_SCAN: usize = 64 * 1024;
+
+fn TextEditorCharAdvance(g: &AppContext, editor: &ImGuiTextEditor, c: char, x: c_float) -> c_float {
+    editor.CharAdvance(&g.Font, g.FontSize / g.Font.FontSize, c, x)
+}
+
+// Horizontal offset of a column from the start of its line
+pub fn TextEditorColumnX(g: &AppContext, editor: &ImGuiTextEditor, line: usize, column: usize) -> c_float {
+    editor.ColumnX(&g.Font, g.FontSize / g.Font.FontSize, line, column)
+}
+
+// Nearest column boundary to a horizontal offset
+pub fn TextEditorColumnAtX(g: &AppContext, editor: &ImGuiTextEditor, line: usize, x: c_float) -> usize {
+    let mut cur_x = 0.0;
+    for (n, &c) in editor.Lines[line].iter().enumerate() {
+        let w = TextEditorCharAdvance(g, editor, c, cur_x);
+        if x < cur_x + w * 0.5 {
+            return n;
+        }
+        cur_x += w;
+    }
+    editor.Lines[line].len()
+}
+
+// Move the cursor for a mouse click/drag, extending the selection when 'select' is set (select_start stays as the anchor, as in stb_textedit_drag())
+fn TextEditorMoveCursor(editor: &mut ImGuiTextEditor, pos: usize, select: bool) {
+    if select {
+        if !editor.HasSelection() {
+            editor.Stb.select_start = editor.Stb.cursor;
+        }
+        editor.Stb.cursor = pos;
+        editor.Stb.select_end = pos;
+    } else {
+        editor.Stb.cursor = pos;
+        editor.ClearSelection();
+    }
+    editor.CursorFollow = true;
+    editor.CursorAnimReset();
+}
+
+// Indent or unindent every line touched by the selection
+fn TextEditorIndentLines(editor: &mut ImGuiTextEditor, unindent: bool, indent_unit: &[char]) {
+    let (sel_start, sel_end) = editor.GetSelection();
+    let line_first = editor.LineOf(sel_start);
+    let mut line_last = editor.LineOf(sel_end);
+    if line_last > line_first && editor.CoordOf(sel_end).1 == 0 {
+        line_last -= 1; // Selection ending at the start of a line doesn't include it
+    }
+
+    // One edit per line, grouped so the whole block is a single undo step
+    stb_text_begin_undo_group(&mut editor.Stb.undostate);
+    for line in line_first..=line_last {
+        let line_start = editor.PosOf(line, 0);
+        let chars = &editor.Lines[line];
+        let (remove, insert) = if unindent {
+            (if chars.first() == Some(&'\t') { 1 } else { chars.iter().take(editor.TabSize).take_while(|c| **c == ' ').count() }, false)
+        } else {
+            (0, !chars.is_empty())
+        };
+        if remove > 0 {
+            editor.ReplaceRange(line_start, line_start + remove, &[]);
+        } else if insert {
+            editor.ReplaceRange(line_start, line_start, indent_unit);
+        }
+    }
+    stb_text_end_undo_group(&mut editor.Stb.undostate);
+    editor.Stb.select_start = editor.PosOf(line_first, 0);
+    editor.Stb.select_end = editor.PosOf(line_last, usize::MAX);
+    editor.Stb.cursor = editor.Stb.select_end;
+}
+
+fn TextEditorGetClipboardText(g: &mut AppContext) -> Option<String> {
+    let text = (g.IO.get_clipboard_text_fn)(g.IO.clipboard_user_data);
+    if text.is_null() {
+        return None;
+    }
+    Some(unsafe { CStr::from_ptr(text) }.to_string_lossy().replace("\r\n", "\n"))
+}
+
+fn TextEditorHandleMouse(g: &mut AppContext, editor: &mut ImGuiTextEditor, id: ImguiHandle, window_id: ImguiHandle, text_origin: Vector2, text_clip_min_x: c_float, hovered: bool) -> bool {
+    let line_height = g.FontSize;
+    let mouse_pos = g.IO.MousePos;
+    let mouse_to_pos = |g: &AppContext, editor: &ImGuiTextEditor| -> usize {
+        let line = (((mouse_pos.y - text_origin.y) / line_height).floor().max(0.0) as usize).min(editor.Lines.len() - 1);
+        let column = TextEditorColumnAtX(g, editor, line, mouse_pos.x - text_origin.x);
+        editor.PosOf(line, column)
+    };
+
+    let mut made_active = false;
+    if hovered {
+        g.MouseCursor = ImGuiMouseCursor_TextInput;
+    }
+    if hovered && g.IO.MouseClicked[0] {
+        if g.ActiveId != id {
+            SetActiveID(g, id, None);
+            g.ActiveIdWindow = window_id;
+            made_active = true;
+        }
+        let pos = mouse_to_pos(g, editor);
+        let in_gutter = mouse_pos.x < text_clip_min_x;
+        if in_gutter {
+            // Click in the gutter: select the whole line
+            let line = editor.LineOf(pos);
+            editor.Stb.select_start = editor.PosOf(line, 0);
+            editor.Stb.cursor = (editor.PosOf(line, usize::MAX) + 1).min(editor.TextLength());
+            editor.Stb.select_end = editor.Stb.cursor;
+        } else if g.IO.MouseClickedCount[0] >= 3 {
+            let line = editor.LineOf(pos);
+            editor.Stb.select_start = editor.PosOf(line, 0);
+            editor.Stb.cursor = editor.PosOf(line, usize::MAX);
+            editor.Stb.select_end = editor.Stb.cursor;
+        } else if g.IO.MouseClickedCount[0] == 2 {
+            // Double-click: select word
+            let (line, column) = editor.CoordOf(pos);
+            let chars = &editor.Lines[line];
+            let (mut w0, mut w1) = (column, column);
+            while w0 > 0 && IsWordChar(chars[w0 - 1]) { w0 -= 1; }
+            while w1 < chars.len() && IsWordChar(chars[w1]) { w1 += 1; }
+            editor.Stb.select_start = editor.PosOf(line, w0);
+            editor.Stb.cursor = editor.PosOf(line, w1);
+            editor.Stb.select_end = editor.Stb.cursor;
+        } else {
+            TextEditorMoveCursor(editor, pos, g.IO.KeyShift);
+        }
+        editor.Stb.has_preferred_x = 0;
+        editor.CursorAnimReset();
+    } else if g.ActiveId == id && g.IO.MouseDown[0] && !g.IO.MouseClicked[0] && (g.IO.MouseDelta.x != 0.0 || g.IO.MouseDelta.y != 0.0) && g.IO.MouseClickedCount[0] <= 1 {
+        let pos = mouse_to_pos(g, editor);
+        TextEditorMoveCursor(editor, pos, true);
+    } else if g.ActiveId == id && g.IO.MouseClicked[0] && !hovered {
+        // Release focus when we click outside
+        ClearActiveID(g);
+    }
+    made_active
+}
+
+// Returns true when the text was modified.
+// Cursor moves, deletion, undo/redo and typing go through stb_textedit_key(), cut and paste through stb_textedit_cut()/stb_textedit_paste(),
+// as in InputText(). Editor extras (smart home, auto-indent, block indent, line cut/copy) only adjust the selection or the text passed to them.
+unsafe fn TextEditorHandleKeyboard(g: &mut AppContext, editor: &mut ImGuiTextEditor, id: ImguiHandle, flags: ImGuiTextEditorFlags) -> bool {
+    let is_readonly = flag_set(flags, ImGuiTextEditorFlags_ReadOnly);
+    let is_osx = g.IO.ConfigMacOSXBehaviors;
+    let shift = g.IO.KeyShift;
+    let k_mask = if shift { STB_TEXTEDIT_K_SHIFT } else { 0 };
+    let is_wordmove_key_down = if is_osx { g.IO.KeyAlt } else { g.IO.KeyCtrl };
+    let is_startend_key_down = is_osx && g.IO.KeySuper && !g.IO.KeyCtrl && !g.IO.KeyAlt;
+    let is_shortcut_key = if is_osx { g.IO.KeyMods == ImGuiModFlags_Super } else { g.IO.KeyMods == ImGuiModFlags_Ctrl };
+    let is_osx_shift_shortcut = is_osx && g.IO.KeyMods == (ImGuiModFlags_Super | ImGuiModFlags_Shift);
+    let edited_before = editor.Edited;
+    editor.Edited = false;
+
+    // Claim keys that would otherwise move the navigation or focus
+    for key in [ImGuiKey_Tab, ImGuiKey_Home, ImGuiKey_End, ImGuiKey_PageUp, ImGuiKey_PageDown, ImGuiKey_Escape] {
+        SetKeyOwner(g, key, id, ImGuiInputFlags_None);
+    }
+    g.ActiveIdUsingNavDirMask |= (1 << ImGuiDir_Left) | (1 << ImGuiDir_Right) | (1 << ImGuiDir_Up) | (1 << ImGuiDir_Down);
+
+    let (line, column) = editor.CoordOf(editor.Stb.cursor);
+    let pressed = |key: ImGuiKey| IsKeyPressed(key, true);
+
+    if pressed(ImGuiKey_LeftArrow) {
+        editor.OnKeyPressed((if is_startend_key_down { STB_TEXTEDIT_K_LINESTART } else if is_wordmove_key_down { STB_TEXTEDIT_K_WORDLEFT } else { STB_TEXTEDIT_K_LEFT }) | k_mask);
+    } else if pressed(ImGuiKey_RightArrow) {
+        editor.OnKeyPressed((if is_startend_key_down { STB_TEXTEDIT_K_LINEEND } else if is_wordmove_key_down { STB_TEXTEDIT_K_WORDRIGHT } else { STB_TEXTEDIT_K_RIGHT }) | k_mask);
+    } else if pressed(ImGuiKey_UpArrow) {
+        editor.OnKeyPressed((if is_startend_key_down { STB_TEXTEDIT_K_TEXTSTART } else { STB_TEXTEDIT_K_UP }) | k_mask);
+    } else if pressed(ImGuiKey_DownArrow) {
+        editor.OnKeyPressed((if is_startend_key_down { STB_TEXTEDIT_K_TEXTEND } else { STB_TEXTEDIT_K_DOWN }) | k_mask);
+    } else if pressed(ImGuiKey_PageUp) {
+        editor.OnKeyPressed(STB_TEXTEDIT_K_PGUP | k_mask);
+    } else if pressed(ImGuiKey_PageDown) {
+        editor.OnKeyPressed(STB_TEXTEDIT_K_PGDOWN | k_mask);
+    } else if pressed(ImGuiKey_Home) {
+        if g.IO.KeyCtrl {
+            editor.OnKeyPressed(STB_TEXTEDIT_K_TEXTSTART | k_mask);
+        } else {
+            // Smart home: first press goes to the first non-blank character, second press to column 0
+            editor.OnKeyPressed(STB_TEXTEDIT_K_LINESTART | k_mask);
+            let indent = editor.GetLineIndent(line).len();
+            if column != indent {
+                editor.Stb.cursor = editor.PosOf(line, indent);
+                if shift { editor.Stb.select_end = editor.Stb.cursor; } else { editor.ClearSelection(); }
+            }
+        }
+    } else if pressed(ImGuiKey_End) {
+        editor.OnKeyPressed((if g.IO.KeyCtrl { STB_TEXTEDIT_K_TEXTEND } else { STB_TEXTEDIT_K_LINEEND }) | k_mask);
+    } else if pressed(ImGuiKey_Insert) && g.IO.KeyMods == 0 {
+        editor.Stb.insert_mode = if editor.Stb.insert_mode != 0 { 0 } else { 1 };
+    } else if pressed(ImGuiKey_Escape) {
+        ClearActiveID(g);
+    } else if is_shortcut_key && pressed(ImGuiKey_A) {
+        editor.SelectAll();
+    } else if (is_shortcut_key && pressed(ImGuiKey_C)) || (g.IO.KeyMods == ImGuiModFlags_Ctrl && pressed(ImGuiKey_Insert)) {
+        // Copy the selection, or the current line when nothing is selected
+        let (start, end) = editor.GetSelection();
+        let text: String = if start != end { editor.GetTextRange(start, end).iter().collect() } else { editor.Lines[line].iter().chain(['\n'].iter()).collect() };
+        (g.IO.set_clipboard_text_fn)(g.IO.clipboard_user_data, &text);
+    } else if !is_readonly {
+        let indent_unit: Vec<char> = if flag_set(flags, ImGuiTextEditorFlags_TabInsertSpaces) { vec![' '; editor.TabSize] } else { vec!['\t'] };
+        if (is_shortcut_key && pressed(ImGuiKey_X)) || (g.IO.KeyMods == ImGuiModFlags_Shift && pressed(ImGuiKey_Delete)) {
+            // Cut the selection, or the current line when nothing is selected
+            if !editor.HasSelection() {
+                editor.Stb.select_start = editor.PosOf(line, 0);
+                editor.Stb.select_end = (editor.PosOf(line, usize::MAX) + 1).min(editor.TextLength());
+            }
+            let (start, end) = editor.GetSelection();
+            let text: String = editor.GetTextRange(start, end).iter().collect();
+            (g.IO.set_clipboard_text_fn)(g.IO.clipboard_user_data, &text);
+            editor.Cut();
+        } else if (is_shortcut_key && pressed(ImGuiKey_V)) || (g.IO.KeyMods == ImGuiModFlags_Shift && pressed(ImGuiKey_Insert)) {
+            if let Some(text) = TextEditorGetClipboardText(g) {
+                let chars: Vec<char> = text.chars().collect();
+                editor.Paste(&chars);
+            }
+        } else if is_shortcut_key && pressed(ImGuiKey_Z) {
+            editor.Undo();
+        } else if (is_shortcut_key && pressed(ImGuiKey_Y)) || (is_osx_shift_shortcut && pressed(ImGuiKey_Z)) {
+            editor.Redo();
+        } else if pressed(ImGuiKey_Backspace) {
+            if !editor.HasSelection() {
+                // Backspace over space indentation removes up to the previous tab stop
+                let indent = editor.GetLineIndent(line);
+                if is_wordmove_key_down {
+                    editor.OnKeyPressed(STB_TEXTEDIT_K_WORDLEFT | STB_TEXTEDIT_K_SHIFT);
+                } else if column > 1 && column <= indent.len() && indent.iter().all(|c| *c == ' ') {
+                    let tab = editor.TabSize.max(1);
+                    editor.Stb.select_start = editor.Stb.cursor;
+                    editor.Stb.select_end = editor.Stb.cursor - (((column - 1) % tab) + 1);
+                }
+            }
+            editor.OnKeyPressed(STB_TEXTEDIT_K_BACKSPACE | k_mask);
+        } else if pressed(ImGuiKey_Delete) {
+            if !editor.HasSelection() && is_wordmove_key_down {
+                editor.OnKeyPressed(STB_TEXTEDIT_K_WORDRIGHT | STB_TEXTEDIT_K_SHIFT);
+            }
+            editor.OnKeyPressed(STB_TEXTEDIT_K_DELETE | k_mask);
+        } else if pressed(ImGuiKey_Enter) || pressed(ImGuiKey_KeypadEnter) {
+            let mut text = vec!['\n'];
+            if !flag_set(flags, ImGuiTextEditorFlags_NoAutoIndent) {
+                text.extend(editor.GetLineIndent(line).iter().take(column));
+                // Opening a block adds one level
+                let before_cursor = editor.Lines[line][..column].iter().rev().find(|c| !c.is_whitespace()).copied();
+                if matches!(before_cursor, Some('{') | Some('(') | Some('[')) {
+                    text.extend_from_slice(&indent_unit);
+                }
+            }
+            editor.Paste(&text);
+        } else if pressed(ImGuiKey_Tab) && (g.IO.KeyMods == 0 || g.IO.KeyMods == ImGuiModFlags_Shift) {
+            let (start, end) = editor.GetSelection();
+            let multi_line = start != end && editor.LineOf(start) != editor.LineOf(end);
+            if shift || multi_line {
+                TextEditorIndentLines(editor, shift, &indent_unit);
+            } else if flag_set(flags, ImGuiTextEditorFlags_TabInsertSpaces) {
+                let tab = editor.TabSize.max(1);
+                let visual = editor.GetVisualColumn(line, column);
+                editor.Paste(&vec![' '; tab - visual % tab]);
+            } else {
+                editor.Paste(&['\t']);
+            }
+        }
+
+        // Process regular text input. We ignore CTRL inputs, but need to allow ALT+CTRL as some keyboards (e.g. German) use AltGR (which _is_ Alt+Ctrl) to input certain characters.
+        let ignore_char_inputs = (g.IO.KeyCtrl && !g.IO.KeyAlt) || (is_osx && g.IO.KeySuper);
+        if !ignore_char_inputs {
+            let chars: Vec<char> = g.IO.InputQueueCharacters.iter().copied().filter(|c| *c != '\t' && *c != '\r' && *c != '\n' && !c.is_control()).collect();
+            for c in chars {
+                editor.OnKeyPressed(c as c_int);
+            }
+        }
+    }
+    g.IO.InputQueueCharacters.clear();
+
+    let edited = editor.Edited;
+    editor.Edited |= edited_before;
+    edited
+}
+
+// Code editor widget. Returns true when the text was modified this frame.
+// - 'size_arg' follows the same rules as BeginChild(): 0.0 -> default (16 lines high / CalcItemWidth() wide), < 0.0 -> align to the right/bottom.
+pub unsafe fn TextEditor(g: &mut AppContext, label: &str, editor: &mut ImGuiTextEditor, size_arg: Vector2, flags: ImGuiTextEditorFlags) -> bool {
+    let (skip_items, seed) = {
+        let window = g.current_window_mut().unwrap();
+        (window.skip_items, window.id_stack.last().unwrap().clone())
+    };
+    if skip_items {
+        return false;
+    }
+    let id = hash_string(&String::from(label), seed);
+    let style_frame_padding = g.style.FramePadding;
+    let line_height = g.FontSize;
+    let item_width = CalcItemWidth(g);
+    let frame_size = CalcItemSize(g, size_arg, item_width, line_height * 16.0 + style_frame_padding.y * 2.0);
+
+    PushStyleColor(ImGuiCol_ChildBg, GetColorU32(ImGuiCol_FrameBg, 1.0));
+    PushStyleVarVec2(ImGuiStyleVar_WindowPadding, &Vector2::from_floats(0.0, 0.0));
+    let child_visible = BeginChildEx(String::from(label), id, frame_size, true, ImGuiWindowFlags_HorizontalScrollbar | ImGuiWindowFlags_NoMove);
+    PopStyleVarInt(1);
+    PopStyleColor(1);
+    if !child_visible {
+        EndChild();
+        return false;
+    }
+
+    let (child_id, inner_rect, scroll, cursor_start) = {
+        let window = g.current_window_mut().unwrap();
+        (window.ID, window.InnerRect, window.scroll, window.dc.cursor_pos)
+    };
+    KeepAliveID(g, id);
+
+    // Layout: [gutter][padding][text...], the gutter doesn't scroll horizontally
+    let show_line_numbers = !flag_set(flags, ImGuiTextEditorFlags_NoLineNumbers);
+    let gutter_width = if show_line_numbers {
+        let digits = format!("{}", editor.GetLineCount().max(100));
+        CalcTextSize(g, &digits, false, 0.0).x + style_frame_padding.x * 2.0
+    } else {
+        0.0
+    };
+    let text_clip_min_x = inner_rect.min.x + gutter_width;
+    let text_origin = Vector2::from_floats(cursor_start.x + gutter_width + style_frame_padding.x, cursor_start.y + style_frame_padding.y);
+    let visible_size = inner_rect.GetSize();
+    let rows_per_page = ((visible_size.y / line_height) as usize).max(1);
+    editor.Stb.row_count_per_page = rows_per_page;
+
+    // Inputs
+    let hovered = g.HoveredWindow == child_id && IsMouseHoveringRect(&inner_rect.min, &inner_rect.max, true);
+    let made_active = TextEditorHandleMouse(g, editor, id, child_id, text_origin, text_clip_min_x, hovered);
+    let is_active = g.ActiveId == id;
+    let mut edited = false;
+    if is_active {
+        g.ActiveIdAllowOverlap = !g.IO.MouseDown[0];
+        g.WantTextInputNextFrame = 1;
+        if !made_active {
+            edited = TextEditorHandleKeyboard(g, editor, id, flags);
+        }
+        editor.CursorAnim += g.IO.DeltaTime;
+    }
+
+    // Scroll to keep the cursor visible
+    let (cursor_line, cursor_column) = editor.CoordOf(editor.Stb.cursor);
+    if editor.CursorFollow {
+        let cursor_y = cursor_line as c_float * line_height;
+        if cursor_y < scroll.y {
+            SetScrollY(g.current_window_mut().unwrap(), cursor_y);
+        } else if cursor_y + line_height > scroll.y + visible_size.y - style_frame_padding.y * 2.0 {
+            SetScrollY(g.current_window_mut().unwrap(), cursor_y + line_height - visible_size.y + style_frame_padding.y * 2.0);
+        }
+        let cursor_x = TextEditorColumnX(g, editor, cursor_line, cursor_column);
+        let text_visible_w = visible_size.x - gutter_width - style_frame_padding.x * 2.0;
+        if cursor_x < scroll.x {
+            SetScrollX(g.current_window_mut().unwrap(), (cursor_x - text_visible_w * 0.25).max(0.0));
+        } else if cursor_x > scroll.x + text_visible_w {
+            SetScrollX(g.current_window_mut().unwrap(), cursor_x - text_visible_w * 0.75);
+        }
+        editor.CursorFollow = false;
+    }
+
+    let (sel_start, sel_end) = editor.GetSelection();
+    let bracket_match = if is_active && !flag_set(flags, ImGuiTextEditorFlags_NoBracketMatching) { editor.FindMatchingBracket(BRACKET_MATCH_MAX_SCAN) } else { None };
+    let cursor_visible = is_active && (!g.IO.ConfigInputTextCursorBlink || editor.CursorAnim <= 0.0 || editor.CursorAnim % 1.20 <= 0.80);
+    let col_text = GetColorU32(ImGuiCol_Text, 1.0);
+    let col_line_number = GetColorU32(ImGuiCol_TextDisabled, 1.0);
+    let col_current_line = GetColorU32(ImGuiCol_Header, 0.30);
+    let col_selection = GetColorU32(ImGuiCol_TextSelectedBg, 1.0);
+    let col_bracket = GetColorU32(ImGuiCol_NavHighlight, 0.60);
+    let col_gutter_bg = GetColorU32(ImGuiCol_FrameBg, 1.0);
+
+    // Submit lines with the clipper: only the visible ones are colorized, measured and rendered
+    // (the clipper measures from the cursor position at Begin(), so apply the top padding first)
+    {
+        let window = g.current_window_mut().unwrap();
+        window.dc.cursor_pos.y += style_frame_padding.y;
+    }
+    let mut clipper = ImGuiListClipper::default();
+    clipper.Begin(editor.GetLineCount(), line_height);
+    let mut max_line_width: c_float = 0.0;
+    while clipper.Step() {
+        editor.ColorizeLines(clipper.DisplayEnd);
+        for line in clipper.DisplayStart..clipper.DisplayEnd {
+            let line_pos = Vector2::from_floats(text_origin.x, g.current_window_mut().unwrap().dc.cursor_pos.y);
+            let line_start = editor.LineStarts[line];
+            let line_len = editor.Lines[line].len();
+
+            // Lay out the line into colored runs (tabs break runs and are not drawn)
+            let mut runs: Vec<(c_float, String, u32)> = Vec::new();
+            let mut run_continues = false;
+            let mut column_x: Vec<c_float> = Vec::with_capacity(line_len + 1);
+            let mut x = 0.0;
+            let tokens = editor.LineColors.get(line).map(|c| c.Tokens.as_slice()).unwrap_or(&[]);
+            let mut token_idx = 0;
+            for (n, &c) in editor.Lines[line].iter().enumerate() {
+                column_x.push(x);
+                while token_idx < tokens.len() && tokens[token_idx].End <= n { token_idx += 1; }
+                let color_idx = if token_idx < tokens.len() && tokens[token_idx].Start <= n { tokens[token_idx].Color } else { ImGuiTextEditorColor_Default };
+                let col = match editor.Palette[color_idx as usize] { 0 => col_text, col => col };
+                if c == '\t' {
+                    run_continues = false;
+                } else {
+                    match runs.last_mut() {
+                        Some(run) if run_continues && run.2 == col => run.1.push(c),
+                        _ => runs.push((x, c.to_string(), col)),
+                    }
+                    run_continues = true;
+                }
+                x += TextEditorCharAdvance(g, editor, c, x);
+            }
+            column_x.push(x);
+            max_line_width = max_line_width.max(x);
+
+            let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+            let row_min = Vector2::from_floats(text_clip_min_x, line_pos.y);
+            let row_max = Vector2::from_floats(inner_rect.max.x, line_pos.y + line_height);
+
+            // Current line, selection, matching brackets
+            if is_active && line == cursor_line && sel_start == sel_end && !flag_set(flags, ImGuiTextEditorFlags_NoCurrentLineHighlight) {
+                draw_list.AddRectFilled(&row_min, &row_max, col_current_line, 0.0, ImDrawFlags_None);
+            }
+            if sel_start != sel_end && sel_start <= line_start + line_len && sel_end >= line_start {
+                let c0 = sel_start.saturating_sub(line_start).min(line_len);
+                let c1 = (sel_end - line_start.min(sel_end)).min(line_len);
+                let mut x1 = line_pos.x + column_x[c1];
+                if sel_end > line_start + line_len {
+                    x1 += g.Font.GetCharAdvance(' ') * (g.FontSize / g.Font.FontSize); // Show the selected newline
+                }
+                let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+                draw_list.AddRectFilled(&Vector2::from_floats(line_pos.x + column_x[c0], line_pos.y), &Vector2::from_floats(x1, line_pos.y + line_height), col_selection, 0.0, ImDrawFlags_None);
+            }
+            let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+            if let Some((a, b)) = bracket_match {
+                for pos in [a, b] {
+                    if pos >= line_start && pos < line_start + line_len {
+                        let column = pos - line_start;
+                        draw_list.AddRect(Vector2::from_floats(line_pos.x + column_x[column], line_pos.y), Vector2::from_floats(line_pos.x + column_x[column + 1], line_pos.y + line_height), col_bracket, 0.0);
+                    }
+                }
+            }
+
+            // Text
+            for (run_x, text, col) in runs {
+                draw_list.AddText(Vector2::from_floats(line_pos.x + run_x, line_pos.y), col, text);
+            }
+
+            // Cursor
+            if cursor_visible && line == cursor_line {
+                let cx = (line_pos.x + column_x[cursor_column]).floor() + 0.5;
+                draw_list.AddLine(Vector2::from_floats(cx, line_pos.y), Vector2::from_floats(cx, line_pos.y + line_height - 0.5), col_text, 1.0);
+            }
+
+            // Gutter (drawn over text scrolled to the left)
+            if show_line_numbers {
+                let gutter_min = Vector2::from_floats(inner_rect.min.x, line_pos.y);
+                let gutter_max = Vector2::from_floats(text_clip_min_x, line_pos.y + line_height);
+                draw_list.AddRectFilled(&gutter_min, &gutter_max, col_gutter_bg, 0.0, ImDrawFlags_None);
+                let number = format!("{}", line + 1);
+                let number_w = CalcTextSize(g, &number, false, 0.0).x;
+                let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+                draw_list.AddText(Vector2::from_floats(text_clip_min_x - style_frame_padding.x - number_w, line_pos.y), if line == cursor_line { col_text } else { col_line_number }, number);
+            }
+
+            // Advance the layout cursor (also feeds the content size, hence the horizontal scrollbar)
+            ItemSize(g, &Vector2::from_floats(gutter_width + style_frame_padding.x * 2.0 + x, line_height), 0.0);
+        }
+    }
+    clipper.End();
+    {
+        // Widest line seen so far: lines are only measured once visible, so the horizontal range grows as you scroll.
+        editor.ContentWidth = editor.ContentWidth.max(max_line_width);
+        let window = g.current_window_mut().unwrap();
+        window.dc.CursorMaxPos.x = window.dc.CursorMaxPos.x.max(text_origin.x + editor.ContentWidth + style_frame_padding.x);
+        window.dc.CursorMaxPos.y += style_frame_padding.y;
+    }
+    EndChild();
+
+    if edited {
+        MarkItemEdited(g, id);
+    }
+    edited
+}
@@ -0,0 +1,64 @@
+#![allow(non_snake_case)]
+
+use std::collections::HashMap;
+use libc::c_void;
+use crate::core::type_defs::ImguiHandle;
+
+// One recorded edit: the bytes of a variable before and after it was edited through a widget
+#[derive(Debug, Clone)]
+pub struct ImGuiUndoEntry {
+    pub Data: *mut c_void,
+    // The edited variable, written back on undo/redo
+    pub Before: Vec<u8>,
+    pub After: Vec<u8>,
+    pub Label: String,
+    // Visible part of the widget label, for UndoHistoryList()
+}
+
+// An edit in progress (e.g. a drag), committed as a single entry when its item gets deactivated
+#[derive(Debug, Clone, Default)]
+pub struct ImGuiUndoPendingEdit {
+    pub Before: Vec<u8>,
+    // Value when the item got activated
+    pub LastFrameSeen: usize,
+    // Items which stop being submitted while active are forgotten
+}
+
+// Undo/redo history for value widgets (DragXXX, SliderXXX, InputScalar, ColorEdit, Checkbox, Combo).
+// Owned by the application, typically one per document or property panel. Enable it with PushUndoContext()/PopUndoContext() around the widgets.
+// Entries keep a pointer to the edited variables: they must stay valid (at the same address) while in the history, call UndoClear() when it is no longer the case.
+#[derive(Debug, Clone)]
+pub struct ImGuiUndoContext {
+    pub Entries: Vec<ImGuiUndoEntry>,
+    pub Position: usize,
+    // Number of entries currently applied: Entries[..Position] can be undone, Entries[Position..] redone
+    pub MaxEntries: usize,
+    // Oldest entries are dropped past this count
+    pub Pending: HashMap<ImguiHandle, ImGuiUndoPendingEdit>,
+    // Edits of the items currently active, keyed on their ID
+}
+
+impl Default for ImGuiUndoContext {
+    fn default() -> Self {
+        Self {
+            Entries: vec![],
+            Position: 0,
+            MaxEntries: 256,
+            Pending: HashMap::new(),
+        }
+    }
+}
+
+impl ImGuiUndoContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn CanUndo(&self) -> bool {
+        self.Position > 0
+    }
+
+    pub fn CanRedo(&self) -> bool {
+        self.Position < self.Entries.len()
+    }
+}
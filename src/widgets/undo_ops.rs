@@ -0,0 +1,193 @@
+#![allow(non_snake_case)]
+
+//-------------------------------------------------------------------------
+// [SECTION] Widgets: Undo/redo history for value widgets
+//-------------------------------------------------------------------------
+// - PushUndoContext(), PopUndoContext()
+// - UndoTrackItemValue() [Internal]
+// - PushUndoTrackingDisabled(), PopUndoTrackingDisabled() [Internal]
+// - Undo(), Redo(), UndoGoTo(), UndoClear()
+// - UndoShortcuts()
+// - UndoHistoryList()
+//-------------------------------------------------------------------------
+// Widgets report the variable they edit, with the value it had when they started running this frame.
+// - The value before an edit is captured when the item gets activated (IsItemActivated()). Widgets pass the value from
+//   before they ran because sliders and nav activation change it on the very frame the item gets activated.
+// - The edit stays pending while the item is active and is committed when IsItemDeactivatedAfterEdit() returns true:
+//   a whole drag makes a single entry. Edits made without the item being active (Combo selection) commit right away.
+// - Pending edits are keyed on the item ID, and dropped when the item stops being submitted.
+// - Changes made by the application itself (no widget edit) are not recorded.
+// InputText() is not tracked: it has its own undo stack while active.
+//-------------------------------------------------------------------------
+
+use std::{mem, ptr};
+use libc::{c_int, c_void};
+use crate::color::{ImGuiCol_Text, ImGuiCol_TextDisabled};
+use crate::core::context::AppContext;
+use crate::core::focused_flags::ImGuiFocusedFlags_RootAndChildWindows;
+use crate::core::id_ops::{pop_win_id_from_stack, push_int_id};
+use crate::drawing::render_ops::FindRenderedTextEnd;
+use crate::io::input_ops::IsKeyPressed;
+use crate::io::key::{ImGuiKey_Y, ImGuiKey_Z};
+use crate::item::item_ops::{IsItemActivated, IsItemDeactivated, IsItemDeactivatedAfterEdit};
+use crate::style::style_ops::{GetColorU32, PopStyleColor, PushStyleColor};
+use crate::widgets::a_widgets::Selectable;
+use crate::widgets::selectable_flags::ImGuiSelectableFlags_None;
+use crate::widgets::undo_context::{ImGuiUndoContext, ImGuiUndoEntry, ImGuiUndoPendingEdit};
+use crate::window::props::IsWindowFocused;
+
+// Tracked values are plain data without padding (scalars, bool, arrays of floats)
+unsafe fn UndoValueBytes<T: Copy>(v: &T) -> Vec<u8> {
+    std::slice::from_raw_parts(v as *const T as *const u8, mem::size_of::<T>()).to_vec()
+}
+
+unsafe fn UndoWriteValue(data: *mut c_void, value: &[u8]) {
+    ptr::copy_nonoverlapping(value.as_ptr(), data as *mut u8, value.len());
+}
+
+// Value widgets record their edits into the context on top of the stack. Pointers to it are kept until PopUndoContext().
+pub fn PushUndoContext(g: &mut AppContext, ctx: &mut ImGuiUndoContext) {
+    g.UndoContextStack.push(ctx as *mut ImGuiUndoContext);
+}
+
+pub fn PopUndoContext(g: &mut AppContext) {
+    // IM_ASSERT(!g.UndoContextStack.is_empty() && "Too many PopUndoContext()");
+    g.UndoContextStack.pop();
+}
+
+// Composite widgets (e.g. ColorEdit4() which edits temporary ints with DragInt()) disable tracking for their parts and track their own value
+pub fn PushUndoTrackingDisabled(g: &mut AppContext) {
+    g.UndoTrackingDisabled += 1;
+}
+
+pub fn PopUndoTrackingDisabled(g: &mut AppContext) {
+    // IM_ASSERT(g.UndoTrackingDisabled > 0);
+    g.UndoTrackingDisabled -= 1;
+}
+
+// Called by value widgets after they ran, every frame they are submitted. 'before' is the value when the widget started running.
+pub unsafe fn UndoTrackItemValue<T: Copy>(g: &mut AppContext, label: &String, p_data: &mut T, before: T, value_changed: bool) {
+    if g.UndoTrackingDisabled > 0 {
+        return;
+    }
+    let ctx: &mut ImGuiUndoContext = match g.UndoContextStack.last() {
+        Some(&ctx) => &mut *ctx,
+        None => return,
+    };
+    let id = g.last_item_data.ID;
+    let frame_count = g.FrameCount;
+    ctx.Pending.retain(|_, pending| pending.LastFrameSeen + 1 >= frame_count);
+    if (IsItemActivated() || value_changed) && !ctx.Pending.contains_key(&id) {
+        ctx.Pending.insert(id, ImGuiUndoPendingEdit { Before: UndoValueBytes(&before), LastFrameSeen: frame_count });
+    }
+    let commit = IsItemDeactivatedAfterEdit() || (value_changed && g.ActiveId != id);
+    if !commit {
+        if IsItemDeactivated() {
+            ctx.Pending.remove(&id);
+        } else if let Some(pending) = ctx.Pending.get_mut(&id) {
+            pending.LastFrameSeen = frame_count;
+        }
+        return;
+    }
+    if let Some(pending) = ctx.Pending.remove(&id) {
+        let after = UndoValueBytes(p_data);
+        if pending.Before != after {
+            let label_end = FindRenderedTextEnd(label);
+            UndoPush(ctx, ImGuiUndoEntry { Data: p_data as *mut T as *mut c_void, Before: pending.Before, After: after, Label: String::from(&label[..label_end]) });
+        }
+    }
+}
+
+fn UndoPush(ctx: &mut ImGuiUndoContext, entry: ImGuiUndoEntry) {
+    ctx.Entries.truncate(ctx.Position); // A new edit drops what could be redone
+    ctx.Entries.push(entry);
+    if ctx.Entries.len() > ctx.MaxEntries.max(1) {
+        ctx.Entries.remove(0);
+    }
+    ctx.Position = ctx.Entries.len();
+}
+
+pub unsafe fn Undo(ctx: &mut ImGuiUndoContext) -> bool {
+    if !ctx.CanUndo() {
+        return false;
+    }
+    ctx.Position -= 1;
+    let entry = ctx.Entries[ctx.Position].clone();
+    UndoWriteValue(entry.Data, &entry.Before);
+    true
+}
+
+pub unsafe fn Redo(ctx: &mut ImGuiUndoContext) -> bool {
+    if !ctx.CanRedo() {
+        return false;
+    }
+    let entry = ctx.Entries[ctx.Position].clone();
+    ctx.Position += 1;
+    UndoWriteValue(entry.Data, &entry.After);
+    true
+}
+
+// Undo or redo until 'position' entries are applied
+pub unsafe fn UndoGoTo(ctx: &mut ImGuiUndoContext, position: usize) {
+    let position = position.min(ctx.Entries.len());
+    while ctx.Position > position && Undo(ctx) {}
+    while ctx.Position < position && Redo(ctx) {}
+}
+
+// Forget the history, e.g. when the edited object changes or moves in memory
+pub fn UndoClear(ctx: &mut ImGuiUndoContext) {
+    ctx.Entries.clear();
+    ctx.Position = 0;
+    ctx.Pending.clear();
+}
+
+// [Ctrl+Z] undo, [Ctrl+Y] or [Ctrl+Shift+Z] redo ([Cmd] instead of [Ctrl] with io.ConfigMacOSXBehaviors), while the current window is focused.
+// Nothing happens while an item is active: InputText() keeps its own undo keys, and a drag in progress isn't an entry yet.
+pub unsafe fn UndoShortcuts(g: &mut AppContext, ctx: &mut ImGuiUndoContext) -> bool {
+    if g.ActiveId != 0 || !IsWindowFocused(ImGuiFocusedFlags_RootAndChildWindows) {
+        return false;
+    }
+    let io = &g.IO;
+    let is_shortcut_key: bool = (if io.ConfigMacOSXBehaviors { io.KeySuper && !io.KeyCtrl } else { io.KeyCtrl && !io.KeySuper }) && !io.KeyAlt;
+    if !is_shortcut_key {
+        return false;
+    }
+    if IsKeyPressed(ImGuiKey_Z, true) && !io.KeyShift {
+        return Undo(ctx);
+    }
+    if IsKeyPressed(ImGuiKey_Y, true) || (IsKeyPressed(ImGuiKey_Z, true) && io.KeyShift) {
+        return Redo(ctx);
+    }
+    false
+}
+
+// One selectable per entry, most recent last, entries which can be redone are grayed. Clicking one goes back or forward to it.
+// Returns true when values were changed.
+pub unsafe fn UndoHistoryList(g: &mut AppContext, ctx: &mut ImGuiUndoContext) -> bool {
+    let mut target: Option<usize> = None;
+    if Selectable(String::from("<Initial state>"), ctx.Position == 0, ImGuiSelectableFlags_None, None) {
+        target = Some(0);
+    }
+    for entry_n in 0..ctx.Entries.len() {
+        let can_redo = entry_n >= ctx.Position;
+        if can_redo {
+            PushStyleColor(ImGuiCol_Text, GetColorU32(ImGuiCol_TextDisabled, 1.0));
+        }
+        push_int_id(g, entry_n as c_int);
+        let label = if ctx.Entries[entry_n].Label.is_empty() { String::from("Edit") } else { format!("Edit {}", ctx.Entries[entry_n].Label) };
+        if Selectable(label, ctx.Position == entry_n + 1, ImGuiSelectableFlags_None, None) {
+            target = Some(entry_n + 1);
+        }
+        pop_win_id_from_stack(g);
+        if can_redo {
+            PopStyleColor(1);
+        }
+    }
+    match target {
+        Some(position) if position != ctx.Position => {
+            UndoGoTo(ctx, position);
+            true
+        }
+        _ => false,
+    }
+}
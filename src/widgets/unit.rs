@@ -0,0 +1,119 @@
+#![allow(non_snake_case)]
+
+use std::f64::consts::PI;
+
+// One way of writing a quantity, e.g. "ms" for a time stored in seconds
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiUnitScale {
+    pub Suffix: String,
+    pub Factor: f64,
+    // Storage units in one of this unit, e.g. 0.001 for "ms" when values are stored in seconds
+    pub Display: bool,
+    // Used for display (auto-scaling picks among those), otherwise only accepted on input (aliases, e.g. "us" for "µs")
+}
+
+// Unit of the value edited by the next DragScalar()/SliderScalar()/InputScalar(), see SetNextItemUnit().
+// The value stays stored in its own unit, the widget displays it scaled with a suffix and accepts typed values in any of the scales ("90deg", "1.5ms", "2MiB").
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiUnit {
+    pub Scales: Vec<ImGuiUnitScale>,
+    pub DisplayScale: usize,
+    // Index in Scales used for display when not auto-scaling, and for typed numbers without a suffix
+    pub AutoScale: bool,
+    // Display in the largest display scale not above the value, e.g. 1536 bytes as "1.50 KiB"
+}
+
+impl ImGuiUnit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn AddScale(mut self, suffix: &str, factor: f64, display: bool) -> Self {
+        self.Scales.push(ImGuiUnitScale { Suffix: String::from(suffix), Factor: factor, Display: display });
+        self
+    }
+
+    pub fn FindScale(&self, suffix: &str) -> Option<usize> {
+        self.Scales.iter().position(|scale| scale.Suffix == suffix)
+    }
+
+    // Angles stored in radians, displayed in degrees
+    pub fn Angle() -> Self {
+        Self::new()
+            .AddScale("deg", PI / 180.0, true)
+            .AddScale("°", PI / 180.0, false)
+            .AddScale("rad", 1.0, true)
+            .AddScale("turn", 2.0 * PI, false)
+            .WithDisplayUnit("deg")
+    }
+
+    // Lengths stored in meters, auto-scaled from nm to km
+    pub fn Length() -> Self {
+        let mut unit = Self::new()
+            .AddScale("nm", 1e-9, true)
+            .AddScale("µm", 1e-6, true)
+            .AddScale("um", 1e-6, false)
+            .AddScale("mm", 1e-3, true)
+            .AddScale("cm", 1e-2, false)
+            .AddScale("m", 1.0, true)
+            .AddScale("km", 1e3, true)
+            .WithDisplayUnit("m");
+        unit.AutoScale = true;
+        unit
+    }
+
+    // Sizes stored in bytes, auto-scaled in powers of 1024. Typed decimal suffixes (KB, MB..) are powers of 1000.
+    pub fn Bytes() -> Self {
+        let mut unit = Self::new()
+            .AddScale("B", 1.0, true)
+            .AddScale("KiB", 1024.0, true)
+            .AddScale("MiB", 1024.0 * 1024.0, true)
+            .AddScale("GiB", 1024.0 * 1024.0 * 1024.0, true)
+            .AddScale("TiB", 1024.0 * 1024.0 * 1024.0 * 1024.0, true)
+            .AddScale("KB", 1e3, false)
+            .AddScale("kB", 1e3, false)
+            .AddScale("MB", 1e6, false)
+            .AddScale("GB", 1e9, false)
+            .AddScale("TB", 1e12, false)
+            .WithDisplayUnit("B");
+        unit.AutoScale = true;
+        unit
+    }
+
+    // Durations stored in seconds, auto-scaled from ns to hours
+    pub fn Time() -> Self {
+        let mut unit = Self::new()
+            .AddScale("ns", 1e-9, true)
+            .AddScale("µs", 1e-6, true)
+            .AddScale("us", 1e-6, false)
+            .AddScale("ms", 1e-3, true)
+            .AddScale("s", 1.0, true)
+            .AddScale("min", 60.0, true)
+            .AddScale("h", 3600.0, true)
+            .WithDisplayUnit("s");
+        unit.AutoScale = true;
+        unit
+    }
+
+    // Values are stored in 'suffix' rather than in the base unit, e.g. Time().WithStorageUnit("ms") for a profiler storing milliseconds
+    pub fn WithStorageUnit(mut self, suffix: &str) -> Self {
+        // IM_ASSERT(self.FindScale(suffix).is_some());
+        if let Some(storage_n) = self.FindScale(suffix) {
+            let storage_factor = self.Scales[storage_n].Factor;
+            for scale in self.Scales.iter_mut() {
+                scale.Factor /= storage_factor;
+            }
+        }
+        self
+    }
+
+    // Always display in 'suffix' (disables auto-scaling), e.g. Angle().WithDisplayUnit("rad")
+    pub fn WithDisplayUnit(mut self, suffix: &str) -> Self {
+        // IM_ASSERT(self.FindScale(suffix).is_some());
+        if let Some(display_n) = self.FindScale(suffix) {
+            self.DisplayScale = display_n;
+        }
+        self.AutoScale = false;
+        self
+    }
+}
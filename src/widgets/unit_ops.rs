@@ -0,0 +1,134 @@
+#![allow(non_snake_case)]
+
+//-------------------------------------------------------------------------
+// [SECTION] Widgets: Units for DragScalar(), SliderScalar(), InputScalar()
+//-------------------------------------------------------------------------
+// - SetNextItemUnit()
+// - GetNextItemUnit() [Internal]
+// - UnitPickScale() [Internal]
+// - UnitFormat(), UnitFormatInteger() [Internal]
+// - UnitParse(), UnitParseInteger() [Internal]
+//-------------------------------------------------------------------------
+// The format string applies to the number once scaled: "%.1f" with ImGuiUnit::Time() displays "1.5 ms", "2.0 s".
+// Formats without a printf conversion (such as the default "{}" ones) display 3 decimals.
+// Typed text is a number optionally followed by one of the suffixes. Without a suffix the number is in the unit currently displayed.
+// Integer values are scaled in i128 when the scale is a whole number of storage units, so 64/128-bit values aren't rounded through a f64.
+//-------------------------------------------------------------------------
+
+use crate::core::context::AppContext;
+use crate::core::utils::flag_set;
+use crate::data_type::data_type_extended_ops::{ImFormatDouble, ImFormatQuotient};
+use crate::item::next_item_data_flags::ImGuiNextItemDataFlags_HasUnit;
+use crate::widgets::unit::ImGuiUnit;
+
+// Applies to the next DragScalar()/SliderScalar()/InputScalar() (or all components of their N variants)
+pub fn SetNextItemUnit(g: &mut AppContext, unit: &ImGuiUnit) {
+    g.NextItemData.Flags |= ImGuiNextItemDataFlags_HasUnit;
+    g.NextItemData.Unit = unit.clone();
+}
+
+// To be read before ItemAdd(), which clears the next item data
+pub fn GetNextItemUnit(g: &AppContext) -> Option<ImGuiUnit> {
+    if flag_set(g.NextItemData.Flags, ImGuiNextItemDataFlags_HasUnit) {
+        Some(g.NextItemData.Unit.clone())
+    } else {
+        None
+    }
+}
+
+// Scale 'v' (in storage units) is displayed with
+pub fn UnitPickScale(unit: &ImGuiUnit, v: f64) -> usize {
+    if !unit.AutoScale || v == 0.0 || !v.is_finite() {
+        return unit.DisplayScale;
+    }
+    let mut best: Option<usize> = None;
+    let mut smallest: Option<usize> = None;
+    for (scale_n, scale) in unit.Scales.iter().enumerate() {
+        if !scale.Display {
+            continue;
+        }
+        if smallest.map_or(true, |n| scale.Factor < unit.Scales[n].Factor) {
+            smallest = Some(scale_n);
+        }
+        if scale.Factor <= v.abs() && best.map_or(true, |n| scale.Factor > unit.Scales[n].Factor) {
+            best = Some(scale_n);
+        }
+    }
+    best.or(smallest).unwrap_or(unit.DisplayScale)
+}
+
+pub fn UnitFormat(unit: &ImGuiUnit, v: f64, format: &str) -> String {
+    let scale = match unit.Scales.get(UnitPickScale(unit, v)) {
+        Some(scale) => scale,
+        None => return ImFormatDouble(format, v),
+    };
+    let format = if format.contains('%') { format } else { "%.3f" };
+    format!("{} {}", ImFormatDouble(format, v / scale.Factor), scale.Suffix)
+}
+
+// Factor of a scale as an integer, when it is a whole number of storage units
+fn UnitIntegerFactor(factor: f64) -> Option<i128> {
+    if factor >= 1.0 && factor.fract() == 0.0 && factor < i128::MAX as f64 {
+        Some(factor as i128)
+    } else {
+        None
+    }
+}
+
+// Same as UnitFormat() for a value stored as an integer
+pub fn UnitFormatInteger(unit: &ImGuiUnit, v: i128, format: &str) -> String {
+    let scale = match unit.Scales.get(UnitPickScale(unit, v as f64)) {
+        Some(scale) => scale,
+        None => return ImFormatQuotient(format, v, 1).unwrap_or_else(|| ImFormatDouble(format, v as f64)),
+    };
+    let format = if format.contains('%') { format } else { "%.3f" };
+    match UnitIntegerFactor(scale.Factor).and_then(|factor| ImFormatQuotient(format, v, factor)) {
+        Some(text) => format!("{} {}", text, scale.Suffix),
+        None => UnitFormat(unit, v as f64, format),
+    }
+}
+
+// Number part of typed text and the factor of its suffix (of the displayed scale without one)
+fn UnitSplitText<'a>(unit: &ImGuiUnit, text: &'a str, v_current: f64) -> Option<(&'a str, f64)> {
+    let text = text.trim();
+    // Longest prefix which reads as a number: "1e3ms" is 1e3 ms, "2em" would be 2 followed by "em"
+    let number_end = (1..=text.len()).rev().filter(|&n| text.is_char_boundary(n)).find(|&n| text[..n].parse::<f64>().is_ok())?;
+    let suffix = text[number_end..].trim();
+    let factor = if suffix.is_empty() {
+        unit.Scales.get(UnitPickScale(unit, v_current))?.Factor
+    } else {
+        let scale_n = unit.FindScale(suffix).or_else(|| unit.Scales.iter().position(|scale| scale.Suffix.eq_ignore_ascii_case(suffix)))?;
+        unit.Scales[scale_n].Factor
+    };
+    Some((&text[..number_end], factor))
+}
+
+// Returns the value in storage units, None if the text isn't a number followed by nothing or a known suffix
+pub fn UnitParse(unit: &ImGuiUnit, text: &str, v_current: f64) -> Option<f64> {
+    let (number, factor) = UnitSplitText(unit, text, v_current)?;
+    let number: f64 = number.parse().ok()?;
+    Some(number * factor)
+}
+
+// Same as UnitParse() for a value stored as an integer, rounded to the nearest storage unit.
+// None when the value can't be computed exactly (exponents, scales below the storage unit, overflow): UnitParse() then applies.
+pub fn UnitParseInteger(unit: &ImGuiUnit, text: &str, v_current: i128) -> Option<i128> {
+    let (number, factor) = UnitSplitText(unit, text, v_current as f64)?;
+    let factor: i128 = UnitIntegerFactor(factor)?;
+    let (negative, digits) = match number.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, number.strip_prefix('+').unwrap_or(number)),
+    };
+    let (int_digits, frac_digits) = digits.split_once('.').unwrap_or((digits, ""));
+    if (int_digits.is_empty() && frac_digits.is_empty()) || !int_digits.bytes().chain(frac_digits.bytes()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let int_part: i128 = if int_digits.is_empty() { 0 } else { int_digits.parse().ok()? };
+    // Digits past 1e-18 of the scale don't make a difference
+    let frac_digits = &frac_digits[..frac_digits.len().min(18)];
+    let frac_den: i128 = 10i128.pow(frac_digits.len() as u32);
+    let frac_num: i128 = if frac_digits.is_empty() { 0 } else { frac_digits.parse().ok()? };
+    let frac_part: i128 = (frac_num.checked_mul(factor)? + frac_den / 2) / frac_den;
+    let v: i128 = int_part.checked_mul(factor)?.checked_add(frac_part)?;
+    Some(if negative { -v } else { v })
+}
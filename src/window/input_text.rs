@@ -16,7 +16,7 @@ use crate::core::config_flags::ImGuiConfigFlags_NavEnableGamepad;
 use crate::cursor_ops::{cursor_screen_pos, indent, set_cursor_screen_pos, unindent};
 use crate::core::direction::{ImGuiDir_Down, ImGuiDir_Left, ImGuiDir_Right, ImGuiDir_Up};
 use crate::drag_drop_ops::{AcceptDragDropPayload, BeginDragDropSource, BeginDragDropTarget, EndDragDropSource, EndDragDropTarget, SetDragDropPayload};
-use crate::drawing::draw_flags::{ImDrawFlags_RoundCornersLeft, ImDrawFlags_RoundCornersRight};
+use crate::drawing::draw_flags::{ImDrawFlags_None, ImDrawFlags_RoundCornersLeft, ImDrawFlags_RoundCornersRight};
 use crate::drawing::draw_list::ImDrawList;
 use crate::font::font_glyph::ImFontGlyph;
 use crate::font::font_ops::{PopFont, PushFont};
@@ -25,14 +25,21 @@ use crate::drawing::geometry_ops::{ImTriangleBarycentricCoords, ImTriangleCloses
 use crate::widgets::group_ops::{BeginGroup, EndGroup};
 use crate::core::id_ops::{ClearActiveID, GetIDWithSeed, pop_win_id_from_stack, PushOverrideID, SetActiveID};
 use crate::input_ops::IsKeyPressed;
+use crate::core::context::AppContext;
+use crate::io::input_event::ImGuiInputEventPreedit;
+use crate::window::input_text_completion::{InputTextCompletionClose, InputTextCompletionOnKey, InputTextCompletionRender, InputTextCompletionUpdate};
+use crate::window::input_text_diagnostics::{ImGuiInputTextDiagnosticsCache, InputTextRenderDiagnosticTooltip, InputTextRenderDiagnostics, InputTextUpdateDiagnostics};
 use crate::io::input_source::{ImGuiInputSource, ImGuiInputSource_Clipboard, ImGuiInputSource_Keyboard};
 use crate::input_text_callback_data::ImGuiInputTextCallbackData;
-use crate::input_text_flags::{ImGuiInputTextFlags, ImGuiInputTextFlags_AllowTabInput, ImGuiInputTextFlags_AlwaysOverwrite, ImGuiInputTextFlags_AutoSelectAll, ImGuiInputTextFlags_CallbackAlways, ImGuiInputTextFlags_CallbackCompletion, ImGuiInputTextFlags_CallbackEdit, ImGuiInputTextFlags_CallbackHistory, ImGuiInputTextFlags_CallbackResize, ImGuiInputTextFlags_CharsHexadecimal, ImGuiInputTextFlags_CharsUppercase, ImGuiInputTextFlags_CtrlEnterForNewLine, ImGuiInputTextFlags_EnterReturnsTrue, ImGuiInputTextFlags_MergedItem, ImGuiInputTextFlags_Multiline, ImGuiInputTextFlags_NoHorizontalScroll, ImGuiInputTextFlags_NoMarkEdited, ImGuiInputTextFlags_None, ImGuiInputTextFlags_NoUndoRedo, ImGuiInputTextFlags_Password, ImGuiInputTextFlags_ReadOnly};
+use crate::input_text_flags::{ImGuiInputTextFlags, ImGuiInputTextFlags_AllowTabInput, ImGuiInputTextFlags_AlwaysOverwrite, ImGuiInputTextFlags_AutoSelectAll, ImGuiInputTextFlags_CallbackAlways, ImGuiInputTextFlags_CallbackCandidates, ImGuiInputTextFlags_CallbackCompletion, ImGuiInputTextFlags_CallbackDiagnostics, ImGuiInputTextFlags_CallbackEdit, ImGuiInputTextFlags_CallbackHistory, ImGuiInputTextFlags_CallbackResize, ImGuiInputTextFlags_CharsHexadecimal, ImGuiInputTextFlags_CharsUppercase, ImGuiInputTextFlags_CtrlEnterForNewLine, ImGuiInputTextFlags_EnterReturnsTrue, ImGuiInputTextFlags_FindReplace, ImGuiInputTextFlags_MergedItem, ImGuiInputTextFlags_MultiCursor, ImGuiInputTextFlags_Multiline, ImGuiInputTextFlags_NoHorizontalScroll, ImGuiInputTextFlags_NoMarkEdited, ImGuiInputTextFlags_None, ImGuiInputTextFlags_NoUndoRedo, ImGuiInputTextFlags_Password, ImGuiInputTextFlags_ReadOnly};
 use crate::input_text_state::ImGuiInputTextState;
+use crate::window::input_text_find::{InputTextFindCalcBarLayout, InputTextFindClose, InputTextFindOnChar, InputTextFindOnClick, InputTextFindOnKey, InputTextFindOpen, InputTextFindRenderBar, InputTextFindRenderMatches, InputTextFindScrollToCurrent, InputTextFindStep, InputTextFindUpdate};
+use crate::window::input_text_multi_cursor::{InputTextAddCursor, InputTextBeginColumnSelect, InputTextClearExtraCursors, InputTextForEachCursor, InputTextGetCursorsText, InputTextMergeCursors, InputTextPasteAtCursors, InputTextUpdateColumnSelect};
+use crate::window::input_text_storage::{InputTextCalcRangeWidth, ImGuiInputTextStorage};
 use crate::item::item_flags::{ImGuiItemFlags, ImGuiItemFlags_Disabled, ImGuiItemFlags_Inputable, ImGuiItemFlags_NoNav, ImGuiItemFlags_NoNavDefaultFocus, ImGuiItemFlags_None, ImGuiItemFlags_NoTabStop, ImGuiItemFlags_SelectableDontClosePopup};
 use crate::item::item_ops::{CalcItemSize, CalcItemWidth, IsItemActive, ItemAdd, ItemHoverable, ItemSize, MarkItemEdited, PopItemFlag, PopItemWidth, PushItemFlag, PushItemWidth, SetNextItemWidth};
 use crate::item::item_status_flags::{ImGuiItemStatusFlags, ImGuiItemStatusFlags_FocusedByTabbing, ImGuiItemStatusFlags_HasDisplayRect, ImGuiItemStatusFlags_HoveredRect, ImGuiItemStatusFlags_HoveredWindow, ImGuiItemStatusFlags_Openable, ImGuiItemStatusFlags_Opened, ImGuiItemStatusFlags_ToggledOpen, ImGuiItemStatusFlags_ToggledSelection};
-use crate::io::key::{ImGuiKey, ImGuiKey_A, ImGuiKey_Backspace, ImGuiKey_C, ImGuiKey_Delete, ImGuiKey_DownArrow, ImGuiKey_End, ImGuiKey_Enter, ImGuiKey_Escape, ImGuiKey_Home, ImGuiKey_Insert, ImGuiKey_KeypadEnter, ImGuiKey_LeftArrow, ImGuiKey_NavGamepadActivate, ImGuiKey_NavGamepadCancel, ImGuiKey_NavGamepadInput, ImGuiKey_None, ImGuiKey_PageDown, ImGuiKey_PageUp, ImGuiKey_RightArrow, ImGuiKey_Tab, ImGuiKey_UpArrow, ImGuiKey_V, ImGuiKey_X, ImGuiKey_Y, ImGuiKey_Z};
+use crate::io::key::{ImGuiKey, ImGuiKey_A, ImGuiKey_Backspace, ImGuiKey_C, ImGuiKey_Delete, ImGuiKey_DownArrow, ImGuiKey_End, ImGuiKey_Enter, ImGuiKey_Escape, ImGuiKey_F, ImGuiKey_F3, ImGuiKey_H, ImGuiKey_Home, ImGuiKey_Insert, ImGuiKey_KeypadEnter, ImGuiKey_LeftArrow, ImGuiKey_NavGamepadActivate, ImGuiKey_NavGamepadCancel, ImGuiKey_NavGamepadInput, ImGuiKey_None, ImGuiKey_PageDown, ImGuiKey_PageUp, ImGuiKey_RightArrow, ImGuiKey_Space, ImGuiKey_Tab, ImGuiKey_UpArrow, ImGuiKey_V, ImGuiKey_X, ImGuiKey_Y, ImGuiKey_Z};
 use crate::item::last_item_data::ImGuiLastItemData;
 use crate::layout::layout_ops;
 use crate::layout::layout_ops::same_line;
@@ -50,7 +57,8 @@ use crate::drawing::render_ops::{FindRenderedTextEnd, RenderArrow, RenderArrowPo
 use crate::widgets::scrolling_ops::{GetScrollMaxY, SetScrollY};
 use crate::widgets::selectable_flags::{ImGuiSelectableFlags, ImGuiSelectableFlags_AllowDoubleClick, ImGuiSelectableFlags_AllowItemOverlap, ImGuiSelectableFlags_Disabled, ImGuiSelectableFlags_DontClosePopups, ImGuiSelectableFlags_DrawHoveredWhenHeld, ImGuiSelectableFlags_NoHoldingActiveID, ImGuiSelectableFlags_NoPadWithHalfSpacing, ImGuiSelectableFlags_SelectOnClick, ImGuiSelectableFlags_SelectOnNav, ImGuiSelectableFlags_SelectOnRelease, ImGuiSelectableFlags_SetNavIdOnHover, ImGuiSelectableFlags_SpanAllColumns, ImGuiSelectableFlags_SpanAvailWidth};
 use crate::drawing::shade_verts_ops::ShadeVertsLinearColorGradientKeepAlpha;
-use crate::stb::stb_textedit::{stb_text_createundo, stb_textedit_click, stb_textedit_cut, stb_textedit_drag, stb_textedit_initialize_state, stb_textedit_paste};
+use crate::stb::stb_textedit::{stb_text_createundo, stb_textedit_click, stb_textedit_cut, stb_textedit_drag, stb_textedit_initialize_state};
+use crate::stb::{STB_TEXTEDIT_K_BACKSPACE, STB_TEXTEDIT_K_DELETE, STB_TEXTEDIT_K_DOWN, STB_TEXTEDIT_K_LEFT, STB_TEXTEDIT_K_LINEEND, STB_TEXTEDIT_K_LINESTART, STB_TEXTEDIT_K_PGDOWN, STB_TEXTEDIT_K_PGUP, STB_TEXTEDIT_K_REDO, STB_TEXTEDIT_K_RIGHT, STB_TEXTEDIT_K_SHIFT, STB_TEXTEDIT_K_TEXTEND, STB_TEXTEDIT_K_TEXTSTART, STB_TEXTEDIT_K_UNDO, STB_TEXTEDIT_K_UP, STB_TEXTEDIT_K_WORDLEFT, STB_TEXTEDIT_K_WORDRIGHT};
 use crate::core::string_ops::{ImFormatString, ImFormatStringToTempBufferV, ImTextCharFromUtf8, ImTextCountCharsFromUtf8, ImTextCountUtf8BytesFromStr, ImTextStrFromUtf8, ImTextStrToUtf8};
 use crate::style_ops::{GetColorU32, PopStyleColor, PushStyleColor};
 use crate::style_var::{ImGuiStyleVar_ChildBorderSize, ImGuiStyleVar_ChildRounding, ImGuiStyleVar_WindowPadding};
@@ -202,13 +210,13 @@ pub unsafe fn is_separator(c: char) -> bool {
 
 pub unsafe fn is_word_boundary_from_right(obj: &mut ImGuiInputTextState, idx: usize) -> bool     {
     if obj.Flags & ImGuiInputTextFlags_Password { return  false; }
-    return if idx > 0 { (is_separator(obj.TextW[idx - 1]) & & ! is_separator(obj.TextW[idx]) )} else {true};
+    return if idx > 0 { (is_separator(obj.TextW.GetChar(idx - 1)) && !is_separator(obj.TextW.GetChar(idx)))} else {true};
 }
 
 pub unsafe fn is_word_boundary_from_left(obj: &mut ImGuiInputTextState, idx: usize) -> bool {
     if flag_set(obj.Flags , ImGuiInputTextFlags_Password) { return false; }
     return if idx > 0 {
-        (!is_separator(obj.TextW[idx - 1]) && is_separator(obj.TextW[idx])) }
+        (!is_separator(obj.TextW.GetChar(idx - 1)) && is_separator(obj.TextW.GetChar(idx))) }
     else { true };
 }
 
@@ -309,19 +317,16 @@ pub unsafe fn InputTextFilterCharacter(p_char: char, flags: ImGuiInputTextFlags,
 pub unsafe fn InputTextReconcileUndoStateAfterUserCallback(state: &mut ImGuiInputTextState, new_buf_a: &String, new_length_a: usize)
 {
     let g = GImGui; // ImGuiContext& g = *GImGui;
-    let old_buf: *const ImWchar = state.TextW.Data;
-    let old_length: usize = state.CurLenW;
-    let new_length: usize = ImTextCountCharsFromUtf8(new_buf_a);
-    g.TempBuffer.reserve_discard((new_length + 1) * sizeof);
-    let mut new_buf: String = String::from(g.TempBuffer.clone());
-    ImTextStrFromUtf8(&mut new_buf, new_length + 1, new_buf_a);
+    let old_length: usize = state.TextW.Len();
+    let new_buf: Vec<char> = new_buf_a.chars().collect();
+    let new_length: usize = new_buf.len();
 
     let shorter_length: usize = old_length.min(new_length);
     let mut first_diff: usize = 0;
     // for (first_diff = 0; first_diff < shorter_length; first_diff++)
     for first_diff in 0 .. shorter_length
     {
-        if old_buf[first_diff] != new_buf[first_diff] {
+        if state.TextW.GetChar(first_diff) != new_buf[first_diff] {
             break;
         }
     }
@@ -332,7 +337,7 @@ pub unsafe fn InputTextReconcileUndoStateAfterUserCallback(state: &mut ImGuiInpu
     // for (; old_last_diff >= first_diff && new_last_diff >= first_diff; old_last_diff--, new_last_diff--)
     while old_last_diff >= first_diff && new_last_diff >= first_diff
     {
-        if old_buf[old_last_diff] != new_buf[new_last_diff] {
+        if state.TextW.GetChar(old_last_diff) != new_buf[new_last_diff] {
             break;
         }
         old_last_diff -= 1;
@@ -353,6 +358,38 @@ pub unsafe fn InputTextReconcileUndoStateAfterUserCallback(state: &mut ImGuiInpu
 }
 }
 
+// Draw the IME composition string (see io.InputPreedit) in place at the cursor. 'pos' is the bottom-left of the cursor, as in the caret rendering.
+// The background is opaque so the composition reads cleanly over the text that follows the cursor. The whole string gets a thin underline,
+// the clause being converted gets a highlighted background and a thick underline.
+// Returns the screen position of the composition caret, and of the active clause (where the IME candidate window should be placed).
+pub unsafe fn InputTextRenderPreedit(g: &mut AppContext, draw_list: &mut ImDrawList, preedit: &ImGuiInputEventPreedit, pos: Vector2) -> (Vector2, Vector2)
+{
+    let chars: Vec<char> = preedit.Text.chars().collect();
+    let mut offset_of = |n: c_int| -> c_float {
+        let n = (n.max(0) as usize).min(chars.len());
+        CalcTextSize(g, &chars[..n].iter().collect::<String>(), false, 0.0).x
+    };
+    let text_width = offset_of(chars.len() as c_int);
+    let caret_x = pos.x + offset_of(preedit.CursorPos);
+    let clause_x0 = pos.x + offset_of(preedit.ClauseStart);
+    let clause_x1 = pos.x + offset_of(preedit.ClauseEnd);
+
+    let top = pos.y - g.FontSize;
+    draw_list.AddRectFilled(&Vector2::from_floats(pos.x, top), &Vector2::from_floats(pos.x + text_width, pos.y), GetColorU32(ImGuiCol_FrameBg, 1.0) | IM_COL32_A_MASK, 0.0, ImDrawFlags_None);
+    if clause_x1 > clause_x0 {
+        draw_list.AddRectFilled(&Vector2::from_floats(clause_x0, top), &Vector2::from_floats(clause_x1, pos.y), GetColorU32(ImGuiCol_TextSelectedBg, 1.0), 0.0, ImDrawFlags_None);
+    }
+    draw_list.AddText(Vector2::from_floats(pos.x, top), GetColorU32(ImGuiCol_Text, 1.0), preedit.Text.clone());
+
+    let col_underline = GetColorU32(ImGuiCol_Text, 1.0);
+    draw_list.AddLine(Vector2::from_floats(pos.x, pos.y - 0.5), Vector2::from_floats(pos.x + text_width, pos.y - 0.5), col_underline, 1.0);
+    if clause_x1 > clause_x0 {
+        draw_list.AddLine(Vector2::from_floats(clause_x0, pos.y - 1.0), Vector2::from_floats(clause_x1, pos.y - 1.0), col_underline, 2.0);
+    }
+
+    (Vector2::from_floats(caret_x, pos.y), Vector2::from_floats(clause_x0, pos.y))
+}
+
 // Edit a string of text
 // - buf_size account for the zero-terminator, so a buf_size of 6 can hold "Hello" but not "Hello!".
 //   This is so we can easily call InputText() on static arrays using ARRAYSIZE() and to match
@@ -386,6 +423,8 @@ pub unsafe fn InputTextEx(label: String,
     let is_readonly: bool = flag_set(flags, ImGuiInputTextFlags_ReadOnly);
     let is_password: bool = flag_set(flags, ImGuiInputTextFlags_Password);
     let is_undoable: bool = flag_clear(flags, ImGuiInputTextFlags_NoUndoRedo);
+    let is_find_enabled: bool = is_multiline && flag_set(flags, ImGuiInputTextFlags_FindReplace);
+    let is_completion_enabled: bool = flag_set(flags, ImGuiInputTextFlags_CallbackCandidates) && !is_readonly && !is_password;
     let is_resizable: bool = flag_set(flags, ImGuiInputTextFlags_CallbackResize);
     if is_resizable {}
         // IM_ASSERT(callback != NULL); // Must provide a callback if you set the ImGuiInputTextFlags_CallbackResize flag!
@@ -490,11 +529,14 @@ pub unsafe fn InputTextEx(label: String,
         // Start edition
         let mut  buf_end = 0usize;
         state.ID = id;
-        state.TextW.resize(buf_size + 1);          // wchar count <= UTF-8 count. we use +1 to make sure that .Data is always pointing to at least an empty string.
         state.TextA.clear();
         state.TextAIsValid = false;                // TextA is not valid yet (we will display buf until then)
-        state.CurLenW = ImTextStrFromUtf8(state.TextW.Data, buf_size, buf);
-        state.CurLenA = (buf_end - buf);      // We can't get the result from ImStrncpy() above because it is not UTF-8 aware. Here we'll cut off malformed UTF-8.
+        state.SetTextFromUtf8(buf);                // Also resets the line width cache, lines get measured once then only when edited.
+        state.ReadOnlyBuf.clear();
+        if is_readonly {
+            state.ReadOnlyBuf.push_str(buf);
+        }
+        InputTextCompletionClose(state);
 
         if recycle_state
         {
@@ -506,6 +548,8 @@ pub unsafe fn InputTextEx(label: String,
         {
             state.ScrollX = 0.0;
             stb_textedit_initialize_state(&mut state.Stb, !is_multiline);
+            InputTextClearExtraCursors(state);
+            InputTextFindClose(state);
         }
 
         if !is_multiline
@@ -531,7 +575,7 @@ pub unsafe fn InputTextEx(label: String,
 
         // Declare our inputs
         g.ActiveIdUsingNavDirMask |= (1 << ImGuiDir_Left) | (1 << ImGuiDir_Right);
-        if is_multiline || flag_set(flags, ImGuiInputTextFlags_CallbackHistory) {
+        if is_multiline || flag_set(flags, ImGuiInputTextFlags_CallbackHistory) || is_completion_enabled {
             g.ActiveIdUsingNavDirMask |= (1 << ImGuiDir_Up) | (1 << ImGuiDir_Down);
         }
         SetActiveIdUsingKey(ImGuiKey_Escape);
@@ -553,8 +597,9 @@ pub unsafe fn InputTextEx(label: String,
     if g.ActiveId == id && state == None{
         ClearActiveID(g);}
 
-    // Release focus when we click outside
-    if g.ActiveId == id && io.MouseClicked[0] && !init_state && !init_make_active { //-V560
+    // Release focus when we click outside (the completion list window is outside of the frame but belongs to us)
+    let completion_hovered: bool = g.ActiveId == id && state != None && state.Completion.Open && state.Completion.WindowID != 0 && g.HoveredWindow == state.Completion.WindowID;
+    if g.ActiveId == id && io.MouseClicked[0] && !init_state && !init_make_active && !completion_hovered { //-V560
         clear_active_id = true;
     }
 
@@ -562,16 +607,21 @@ pub unsafe fn InputTextEx(label: String,
     let render_cursor: bool = (g.ActiveId == id) || (state && user_scroll_active);
     let mut render_selection: bool =  state && (state.HasSelection() || select_all) && (RENDER_SELECTION_WHEN_INACTIVE || render_cursor);
     let mut value_changed: bool =  false;
+    let mut completion_typed: bool = false;
+    let mut completion_used_key: bool = false;
     let mut validated: bool =  false;
 
     // When read-only we always use the live data passed to the function
     // FIXME-OPT: Because our selection/cursor code currently needs the wide text we need to convert it when active, which is not ideal :(
     if is_readonly && state != None && (render_cursor || render_selection)
     {
-        let mut  buf_end = 0usize;
-        state.TextW.resize(buf_size + 1);
-        state.CurLenW = ImTextStrFromUtf8(state.TextW, state.TextW.len(), buf);
-        state.CurLenA = (buf_end - buf);
+        // The user may change 'buf' at any time, so it has to be checked: compare the raw bytes with the copy we loaded
+        // TextW from (a length check then a memcmp) instead of decoding it and walking the rope.
+        if state.ReadOnlyBuf.as_bytes() != buf.as_bytes() {
+            state.SetTextFromUtf8(buf);
+            state.ReadOnlyBuf.clear();
+            state.ReadOnlyBuf.push_str(buf);
+        }
         state.CursorClamp();
         render_selection &= state.HasSelection();
     }
@@ -614,6 +664,13 @@ pub unsafe fn InputTextEx(label: String,
         // Edit in progress
         let mouse_x: c_float =  (io.MousePos.x - frame_bb.min.x - style.FramePadding.x) + state.ScrollX;
         let mouse_y: c_float =  (if is_multiline { (io.MousePos.y - draw_window.dc.cursor_pos.y) }else {g.FontSize * 0.5});
+        let is_multi_cursor: bool = is_multiline && flag_set(flags, ImGuiInputTextFlags_MultiCursor);
+        let mouse_line: usize = ImMax(mouse_y / g.FontSize, 0.0) as usize;
+        let font = g.Font;
+        let font_scale: c_float = g.FontSize / font.FontSize;
+        let char_advance = |c: char| font.GetCharAdvance(c) * font_scale;
+        let find_bar = if is_find_enabled && state.Find.Open { Some(InputTextFindCalcBarLayout(g, frame_bb.min, inner_size, state.Find.ShowReplace)) } else { None };
+        let find_bar_hovered: bool = hovered && find_bar.map_or(false, |mut layout| layout.Bar.Contains(&io.MousePos));
 
         let is_osx: bool = io.ConfigMacOSXBehaviors;
         if (select_all)
@@ -621,6 +678,19 @@ pub unsafe fn InputTextEx(label: String,
             state.SelectAll();
             state.SelectedAllMouseLock = true;
         }
+        else if completion_hovered || state.Completion.MouseHeld
+        {
+            // The completion list may cover the text: its clicks (handled by InputTextCompletionRender()) and drags never reach the text
+            state.Completion.MouseHeld = io.MouseDown[0];
+        }
+        else if find_bar_hovered || state.Find.MouseHeld
+        {
+            // The find bar is drawn over the text: its clicks and drags never reach the text
+            if io.MouseClicked[0] && find_bar_hovered {
+                InputTextFindOnClick(state, &find_bar.unwrap(), &io.MousePos, is_readonly);
+            }
+            state.Find.MouseHeld = io.MouseDown[0];
+        }
         else if hovered && io.MouseClickedCount[0] >= 2 && !io.KeyShift
         {
             stb_textedit_click(state, &mut state.Stb, mouse_x, mouse_y);
@@ -662,29 +732,54 @@ pub unsafe fn InputTextEx(label: String,
         {
             if (hovered)
             {
-                if (io.KeyShift) {
-                    stb_textedit_drag(state, &mut state.Stb, mouse_x, mouse_y);
+                if is_multi_cursor && io.KeyAlt && !io.KeyCtrl
+                {
+                    // Alt+Click: start a column selection
+                    InputTextBeginColumnSelect(state, mouse_line, mouse_x);
+                    InputTextUpdateColumnSelect(state, mouse_line, mouse_x, &char_advance);
                 }
-                else {
+                else if is_multi_cursor && io.KeyCtrl && !io.KeyShift
+                {
+                    // Ctrl+Click: keep the current cursor and add a new one
+                    InputTextAddCursor(state);
                     stb_textedit_click(state, &mut state.Stb, mouse_x, mouse_y);
+                    InputTextMergeCursors(state);
+                }
+                else
+                {
+                    InputTextClearExtraCursors(state);
+                    state.Find.HasFocus = false; // Clicking in the text gives the keyboard back to it
+                    if (io.KeyShift) {
+                        stb_textedit_drag(state, &mut state.Stb, mouse_x, mouse_y);
+                    }
+                    else {
+                        stb_textedit_click(state, &mut state.Stb, mouse_x, mouse_y);
+                    }
                 }
                 state.CursorAnimReset();
             }
         }
         else if io.MouseDown[0] && !state.SelectedAllMouseLock && (io.MouseDelta.x != 0.0 || io.MouseDelta.y != 0.0)
         {
-            stb_textedit_drag(state, &mut state.Stb, mouse_x, mouse_y);
+            if state.ColumnSelectActive {
+                InputTextUpdateColumnSelect(state, mouse_line, mouse_x, &char_advance);
+            } else {
+                stb_textedit_drag(state, &mut state.Stb, mouse_x, mouse_y);
+            }
             state.CursorAnimReset();
             state.CursorFollow = true;
         }
         if state.SelectedAllMouseLock && !io.MouseDown[0] {
             state.SelectedAllMouseLock = false;
         }
+        if state.ColumnSelectActive && !io.MouseDown[0] {
+            state.ColumnSelectActive = false;
+        }
 
         // We expect backends to emit a Tab key but some also emit a Tab character which we ignore (#2467, #1336)
         // (For Tab and Enter: Win32/SFML/Allegro are sending both keys and chars, GLFW and SDL are only sending keys. For Space they all send all threes)
         let ignore_char_inputs: bool = (io.KeyCtrl && !io.KeyAlt) || (is_osx && io.KeySuper);
-        if flag_set(flags, ImGuiInputTextFlags_AllowTabInput) && IsKeyPressed(ImGuiKey_Tab, false) && !ignore_char_inputs && !io.KeyShift && !is_readonly
+        if flag_set(flags, ImGuiInputTextFlags_AllowTabInput) && IsKeyPressed(ImGuiKey_Tab, false) && !ignore_char_inputs && !io.KeyShift && !is_readonly && !state.Find.HasFocus
         {
             let mut c =  '\t'; // Insert TAB
             if InputTextFilterCharacter(c, flags, callback, callback_user_data, ImGuiInputSource_Keyboard) {
@@ -696,7 +791,15 @@ pub unsafe fn InputTextEx(label: String,
         // We ignore CTRL inputs, but need to allow ALT+CTRL as some keyboards (e.g. German) use AltGR (which _is_ Alt+Ctrl) to input certain characters.
         if io.InputQueueCharacters.Size > 0
         {
-            if !ignore_char_inputs && !is_readonly && !input_requested_by_nav {
+            if state.Find.HasFocus {
+                // Typing into the find bar
+                if !ignore_char_inputs {
+                    for n in 0 .. io.InputQueueCharacters.len() {
+                        InputTextFindOnChar(state, io.InputQueueCharacters[n]);
+                    }
+                }
+            }
+            else if !ignore_char_inputs && !is_readonly && !input_requested_by_nav {
                 // for (let n: c_int = 0; n < io.InputQueueCharacters.Size; n+ +)
                 for n in 0 .. io.InputQueueCharacters.len()
                 {
@@ -707,6 +810,7 @@ pub unsafe fn InputTextEx(label: String,
                     }
                     if InputTextFilterCharacter(c, flags, callback, callback_user_data, ImGuiInputSource_Keyboard) {
                         state.OnKeyPressed(c);
+                        completion_typed = true;
                     }
                 }
             }
@@ -734,8 +838,8 @@ pub unsafe fn InputTextEx(label: String,
         let is_shift_key_only: bool = (io.KeyMods == ImGuiModFlags_Shift);
         let is_shortcut_key: bool = if g.IO.ConfigMacOSXBehaviors { (io.KeyMods == ImGuiModFlags_Super) }else{ (io.KeyMods == ImGuiModFlags_Ctrl)};
 
-        let is_cut: bool = ((is_shortcut_key && IsKeyPressed(ImGuiKey_X, false)) || (is_shift_key_only && IsKeyPressed(ImGuiKey_Delete, false))) && !is_readonly && !is_password && (!is_multiline || state.HasSelection());
-        let is_copy: bool = ((is_shortcut_key && IsKeyPressed(ImGuiKey_C, false)) || (is_ctrl_key_only  && IsKeyPressed(ImGuiKey_Insert, false))) && !is_password && (!is_multiline || state.HasSelection());
+        let is_cut: bool = ((is_shortcut_key && IsKeyPressed(ImGuiKey_X, false)) || (is_shift_key_only && IsKeyPressed(ImGuiKey_Delete, false))) && !is_readonly && !is_password && (!is_multiline || state.HasSelection() || state.ExtraCursors.iter().any(|cursor| cursor.HasSelection()));
+        let is_copy: bool = ((is_shortcut_key && IsKeyPressed(ImGuiKey_C, false)) || (is_ctrl_key_only  && IsKeyPressed(ImGuiKey_Insert, false))) && !is_password && (!is_multiline || state.HasSelection() || state.ExtraCursors.iter().any(|cursor| cursor.HasSelection()));
         let is_paste: bool = ((is_shortcut_key && IsKeyPressed(ImGuiKey_V, false)) || (is_shift_key_only && IsKeyPressed(ImGuiKey_Insert, false))) && !is_readonly;
         let is_undo: bool = ((is_shortcut_key && IsKeyPressed(ImGuiKey_Z, false)) && !is_readonly && is_undoable);
         let is_redo: bool = ((is_shortcut_key && IsKeyPressed(ImGuiKey_Y, false)) || (is_osx_shift_shortcut && IsKeyPressed(ImGuiKey_Z, false))) && !is_readonly && is_undoable;
@@ -747,7 +851,35 @@ pub unsafe fn InputTextEx(label: String,
         let is_gamepad_validate: bool = nav_gamepad_active && (IsKeyPressed(ImGuiKey_NavGamepadActivate, false) || IsKeyPressed(ImGuiKey_NavGamepadInput, false));
         let is_cancel: bool = IsKeyPressed(ImGuiKey_Escape, false) || (nav_gamepad_active && IsKeyPressed(ImGuiKey_NavGamepadCancel, false));
 
-        if IsKeyPressed(ImGuiKey_LeftArrow, false) { state.OnKeyPressed((if is_startend_key_down { STB_TEXTEDIT_K_LINESTART} else { if is_wordmove_key_down { STB_TEXTEDIT_K_WORDLEFT}else {STB_TEXTEDIT_K_LEFT}}) | k_mask); }
+        let is_composing: bool = !is_readonly && !is_password && !io.InputPreedit.Text.is_empty();
+
+        let mut completion_force_open: bool = false;
+        if is_composing
+        {
+            // The IME owns the keyboard while a composition is in progress (Enter confirms it, Escape cancels it, arrows move between clauses)
+        }
+        else if is_completion_enabled && state.Completion.Open && InputTextCompletionOnKey(state)
+        {
+            // Arrows, Enter and Escape drive the completion popup while it is open: no cursor move, validation or history callback
+            completion_used_key = true;
+        }
+        else if is_completion_enabled && io.KeyCtrl && IsKeyPressed(ImGuiKey_Space, false)
+        {
+            completion_force_open = true;
+        }
+        else if is_find_enabled && is_shortcut_key && (IsKeyPressed(ImGuiKey_F, false) || IsKeyPressed(ImGuiKey_H, false))
+        {
+            InputTextFindOpen(state, IsKeyPressed(ImGuiKey_H, false), is_readonly);
+        }
+        else if is_find_enabled && state.Find.Open && IsKeyPressed(ImGuiKey_F3, true)
+        {
+            InputTextFindStep(state, !io.KeyShift);
+        }
+        else if is_find_enabled && state.Find.HasFocus
+        {
+            InputTextFindOnKey(g, state, is_readonly);
+        }
+        else if IsKeyPressed(ImGuiKey_LeftArrow, false) { state.OnKeyPressed((if is_startend_key_down { STB_TEXTEDIT_K_LINESTART} else { if is_wordmove_key_down { STB_TEXTEDIT_K_WORDLEFT}else {STB_TEXTEDIT_K_LEFT}}) | k_mask); }
         else if IsKeyPressed(ImGuiKey_RightArrow, false) { state.OnKeyPressed((if is_startend_key_down { STB_TEXTEDIT_K_LINEEND} else { if is_wordmove_key_down { STB_TEXTEDIT_K_WORDRIGHT}else {STB_TEXTEDIT_K_RIGHT}}) | k_mask); }
         else if IsKeyPressed(ImGuiKey_UpArrow, false) && is_multiline { if io.KeyCtrl {
             SetScrollY(draw_window, ImMax(draw_window.scroll.y - g.FontSize, 0.0));
@@ -793,6 +925,16 @@ pub unsafe fn InputTextEx(label: String,
                 }
             }
         }
+        else if is_cancel && state.Find.Open
+        {
+            // Escape closes the find bar before anything else
+            InputTextFindClose(state);
+        }
+        else if is_cancel && !state.ExtraCursors.is_empty()
+        {
+            // First Escape only drops the extra cursors
+            InputTextClearExtraCursors(state);
+        }
         else if is_cancel
         {
             clear_active_id = true;
@@ -811,15 +953,17 @@ pub unsafe fn InputTextEx(label: String,
         else if is_cut || is_copy
         {
             // Cut, Copy
-            if io.SetClipboardTextFn
+            if io.SetClipboardTextFn && !state.ExtraCursors.is_empty()
+            {
+                SetClipboardText(&InputTextGetCursorsText(state));
+            }
+            else if io.SetClipboardTextFn
             {
-                let ib: c_int = if state.HasSelection() { ImMin(state.Stb.select_start, state.Stb.select_end)} else {0};
-                let ie: c_int = if state.HasSelection() { ImMax(state.Stb.select_start, state.Stb.select_end)} else{ state.CurLenW};
-                let clipboard_data_len: usize = (ImTextCountUtf8BytesFromStr(state.TextW.Data + ib) + 1);
-                let mut clipboard_data = String::new();
-                ImTextStrToUtf8(&mut clipboard_data, clipboard_data_len, state.TextW.Data + ib);
+                let ib: usize = if state.HasSelection() { ImMin(state.Stb.select_start, state.Stb.select_end)} else {0};
+                let ie: usize = if state.HasSelection() { ImMax(state.Stb.select_start, state.Stb.select_end)} else{ state.CurLenW};
+                let mut clipboard_data = String::with_capacity(state.TextW.Utf8Offset(ie) - state.TextW.Utf8Offset(ib));
+                state.TextW.CopyRangeUtf8(ib, ie, &mut clipboard_data);
                 SetClipboardText(&clipboard_data);
-                MemFree(clipboard_data);
             }
             if is_cut
             {
@@ -827,7 +971,11 @@ pub unsafe fn InputTextEx(label: String,
                     state.SelectAll();
                 }
                 state.CursorFollow = true;
-                stb_textedit_cut(state, &mut state.Stb);
+                InputTextForEachCursor(state, &mut |state| {
+                    let mut stb = std::mem::take(&mut state.Stb);
+                    stb_textedit_cut(state, &mut stb);
+                    state.Stb = stb;
+                });
             }
         }
         else if (is_paste)
@@ -836,34 +984,30 @@ pub unsafe fn InputTextEx(label: String,
             if clipboard.is_empty() == false
             {
                 // Filter pasted buffer
-                let clipboard_len = clipboard.len();
-                let mut clipboard_filtered = String::with_capacity(clipboard_len);
-                let mut clipboard_filtered_len: usize = 0;
-                // for (s: &str = clipboard; *s; )
-                for s in clipboard
+                let mut clipboard_filtered: Vec<char> = Vec::with_capacity(clipboard.len());
+                for c in clipboard.chars()
                 {
-                    let mut c = '\0';
-                    s += ImTextCharFromUtf8(&mut c, s);
-                    if c == 0 {
-                        break(); }
+                    if c == '\0' {
+                        break; }
                     if !InputTextFilterCharacter(c, flags, callback, callback_user_data, ImGuiInputSource_Clipboard) {
                         continue;
                     }
-                    clipboard_filtered[clipboard_filtered_len] = c;
-                    clipboard_filtered_len += 1;
+                    clipboard_filtered.push(c);
                 }
-                clipboard_filtered[clipboard_filtered_len] = 0;
-                if clipboard_filtered_len > 0 // If everything was filtered, ignore the pasting operation
+                if clipboard_filtered.len() > 0 // If everything was filtered, ignore the pasting operation
                 {
-                    stb_textedit_paste(state, &mut state.Stb, &mut clipboard_filtered, clipboard_filtered_len);
+                    InputTextPasteAtCursors(state, &clipboard_filtered);
                     state.CursorFollow = true;
                 }
-                MemFree(clipboard_filtered);
             }
         }
 
+        if is_completion_enabled {
+            InputTextCompletionUpdate(state, callback, callback_user_data, flags, completion_typed, completion_force_open);
+        }
+
         // Update render selection flag after events have been handled, so selection highlight can be displayed during the same frame.
-        render_selection |= state.HasSelection() && (RENDER_SELECTION_WHEN_INACTIVE || render_cursor);
+        render_selection |= (state.HasSelection() || state.ExtraCursors.iter().any(|cursor| cursor.HasSelection())) && (RENDER_SELECTION_WHEN_INACTIVE || render_cursor);
     }
 
     // Process callbacks and apply result back to user's buffer.
@@ -880,22 +1024,19 @@ pub unsafe fn InputTextEx(label: String,
                 // Push records into the undo stack so we can CTRL+Z the revert operation itself
                 apply_new_text = state.InitialTextA.Data;
                 apply_new_text_length = state.InitialTextA.Size - 1;
-                let mut w_text: Vec<char> = vec![];
-                if apply_new_text_length > 0
-                {
-                    w_text.resize(ImTextCountCharsFromUtf8(apply_new_text.as_str()) + 1, '\0');
-                    ImTextStrFromUtf8(w_text.Data, w_text.Size, &apply_new_text);
-                }
-                stb::stb_textedit_replace(state, &mut state.Stb, w_text.Data, if (apply_new_text_length > 0) { (w_text.Size - 1)} else{ 0});
+                let w_text: Vec<char> = apply_new_text.chars().collect();
+                let mut stb_state = std::mem::take(&mut state.Stb);
+                stb::stb_textedit_replace(state, &mut stb_state, &w_text, w_text.len());
+                state.Stb = stb_state;
             }
         }
 
         // Apply ASCII value
-        if (!is_readonly)
+        // Only converted once: after that STB_TEXTEDIT_DELETECHARS/INSERTCHARS apply every edit to TextA over the edited range.
+        if !is_readonly && !state.TextAIsValid
         {
             state.TextAIsValid = true;
-            state.TextA.resize(state.TextW.Size * 4 + 1);
-            ImTextStrToUtf8(state.TextA.Data, state.TextA.Size, state.TextW.Data);
+            state.TextW.CopyRange(0, state.TextW.Len(), &mut state.TextA);
         }
 
         // When using 'ImGuiInputTextFlags_EnterReturnsTrue' as a special case we reapply the live buffer back to the input buffer before clearing ActiveId, even though strictly speaking it wasn't modified on this frame.
@@ -922,12 +1063,12 @@ pub unsafe fn InputTextEx(label: String,
                     event_flag = ImGuiInputTextFlags_CallbackCompletion;
                     event_key = ImGuiKey_Tab;
                 }
-                else if flag_set(flags, ImGuiInputTextFlags_CallbackHistory) && IsKeyPressed(ImGuiKey_UpArrow, false)
+                else if flag_set(flags, ImGuiInputTextFlags_CallbackHistory) && IsKeyPressed(ImGuiKey_UpArrow, false) && !completion_used_key
                 {
                     event_flag = ImGuiInputTextFlags_CallbackHistory;
                     event_key = ImGuiKey_UpArrow;
                 }
-                else if flag_set(flags, ImGuiInputTextFlags_CallbackHistory) && IsKeyPressed(ImGuiKey_DownArrow, false)
+                else if flag_set(flags, ImGuiInputTextFlags_CallbackHistory) && IsKeyPressed(ImGuiKey_DownArrow, false) && !completion_used_key
                 {
                     event_flag = ImGuiInputTextFlags_CallbackHistory;
                     event_key = ImGuiKey_DownArrow;
@@ -957,12 +1098,11 @@ pub unsafe fn InputTextEx(label: String,
                     callback_data.BufDirty = false;
 
                     // We have to convert from wchar-positions to UTF-8-positions, which can be pretty slow (an incentive to ditch the ImWchar buffer, see https://github.com/nothings/stb/issues/188)
-                    let mut text = state.TextW.clone();
-                    callback_data.CursorPos = ImTextCountUtf8BytesFromStr(text);
+                    callback_data.CursorPos = state.TextW.Utf8Offset(state.Stb.cursor);
                     let utf8_cursor_pos = callback_data.CursorPos;
-                    callback_data.SelectionStart = ImTextCountUtf8BytesFromStr(text);
+                    callback_data.SelectionStart = state.TextW.Utf8Offset(state.Stb.select_start);
                     let utf8_selection_start = callback_data.SelectionStart;
-                    callback_data.SelectionEnd = ImTextCountUtf8BytesFromStr(text);
+                    callback_data.SelectionEnd = state.TextW.Utf8Offset(state.Stb.select_end);
                     let utf8_selection_end = callback_data.SelectionEnd;
 
                     // Call user code
@@ -983,11 +1123,7 @@ pub unsafe fn InputTextEx(label: String,
                         // IM_ASSERT(flag_set(flags, ImGuiInputTextFlags_ReadOnly) == 0);
                         // IM_ASSERT(callback_data.BufTextLen == strlen(callback_data.Bu0f32)); // You need to maintain BufTextLen if you change the text!
                         InputTextReconcileUndoStateAfterUserCallback(state, &callback_data.Buf, callback_data.BufTextLen); // FIXME: Move the rest of this block inside function and rename to InputTextReconcileStateAfterUserCallback() ?
-                        if callback_data.BufTextLen > backup_current_text_length && is_resizable {
-                            state.TextW.resize(state.TextW.Size + (callback_data.BufTextLen - backup_current_text_length));
-                        } // Worse case scenario resize
-                        state.CurLenW = ImTextStrFromUtf8(state.TextW.Data, state.TextW.Size, &callback_data.Buf);
-                        state.CurLenA = callback_data.BufTextLen;  // Assume correct length and valid UTF-8 from user, saves us an extra strlen()
+                        state.SetTextFromUtf8(&callback_data.Buf);
                         state.CursorAnimReset();
                     }
                 }
@@ -1062,6 +1198,16 @@ pub unsafe fn InputTextEx(label: String,
         buf_display_end = buf_display.len();
     }
 
+    // Diagnostics (ImGuiInputTextFlags_CallbackDiagnostics) are requested for the text actually displayed, so offsets match what is drawn
+    // The callback only runs when that text changed, the cache is moved out of g.InputTextDiagnostics while we render.
+    let use_diagnostics: bool = flag_set(flags, ImGuiInputTextFlags_CallbackDiagnostics) && !is_displaying_hint && !is_password;
+    let mut diagnostics: ImGuiInputTextDiagnosticsCache = if use_diagnostics { g.InputTextDiagnostics.remove(&id).unwrap_or_default() } else { ImGuiInputTextDiagnosticsCache::default() };
+    if use_diagnostics {
+        InputTextUpdateDiagnostics(&mut diagnostics, callback, callback_user_data, flags, &buf_display);
+    }
+    let diagnostics_mouse_pos: Option<Vector2> = if hovered && !(g.ActiveId == id && io.MouseDown[0]) { Some(io.MousePos) } else { None }; // Not while drag-selecting
+    let mut hovered_diagnostic: Option<usize> = None;
+
     // Render text. We currently only render selection when the widget is active or while scrolling.
     // FIXME: We could remove the '&& render_cursor' to keep rendering selection when inactive.
     if render_cursor || render_selection
@@ -1076,68 +1222,36 @@ pub unsafe fn InputTextEx(label: String,
         // - Display the text (this alone can be more easily clipped)
         // - Handle scrolling, highlight selection, display cursor (those all requires some form of 1d->2d cursor position calculation)
         // - Measure text height (for scrollbar)
-        // We are attempting to do most of that in **one main pass** to minimize the computation cost (non-negligible for large amount of text) + 2nd pass for selection rendering (we could merge them by an extra refactoring effort)
+        // Line numbers come from the TextW storage and line widths from state.LineWidths, so none of this walks the whole buffer:
+        // the cost is bounded by the cursor/selection lines, the visible lines and the lines edited since last measured.
         // FIXME: This should occur on buf_display but we'd need to maintain cursor/select_start/select_end for UTF-8.
-        let text_begin: String = state.TextW.clone();
+        let font = g.Font;
+        let font_scale: c_float = g.FontSize / font.FontSize;
+        let char_advance = |c: char| font.GetCharAdvance(c) * font_scale;
+        if state.LineWidths.FontSize != g.FontSize || state.LineWidths.Widths.len() != state.TextW.LineCount() {
+            state.LineWidths.Reset(state.TextW.LineCount(), g.FontSize);
+        }
+
         // cursor_offset: ImVec2, select_start_offset;
         let mut cursor_offset = Vector2::default();
         let mut select_start_offset = Vector2::default();
 
         {
-            // Find lines numbers straddling 'cursor' (slot 0) and 'select_start' (slot 1) positions.
-            let mut searches_input_ptr: [char;2] = [ '\0', '\0' ];
-            let mut searches_result_line_no: [usize;2] = [ usize::MIN, usize::MIN ];
-            let mut searches_remaining: usize = 0;
-            if render_sdcursor
-            {
-                searches_input_ptr[0] = text_begin[state.Stb.cursor];
-                searches_result_line_no[0] = -1;
-                searches_remaining+= 1;
-            }
-            if render_selection
-            {
-                searches_input_ptr[1] = text_begin[state.Stb.select_start.min(state.Stb.select_start, state.Stb.select_end)];
-                searches_result_line_no[1] = -1;
-                searches_remaining+= 1;
-            }
-
-            // Iterate all lines to find our line numbers
-            // In multi-line mode, we never exit the loop until all lines are counted, so add one extra to the searches_remaining counter.
-            searches_remaining += if is_multiline {1} else { 0 };
-            let mut line_count: usize = 0;
-            //for (const s: *mut ImWchar = text_begin; (s = (const ImWchar*)wcschr((const wchar_t*)s, (wchar_t)'\n')) != None; s++)  // FIXME-OPT: Could use this when wchar_t are 16-bit
-            // for (*let s: ImWchar = text_begin; *s != 0; s++)
-            for s in text_begin {
-                if s == '\n'
-                {
-                    line_count+= 1;
-                    if searches_result_line_no[0] == -1 && s >= searches_input_ptr[0] {
-                        searches_result_line_no[0] = line_count;
-                        if --searches_remaining <= 0 { break; } }
-                    if searches_result_line_no[1] == -1 && s >= searches_input_ptr[1] { searches_result_line_no[1] = line_count; if --searches_remaining <= 0 { break; } }
-                }
-            line_count+= 1;
-            if searches_result_line_no[0] == -1 {
-                searches_result_line_no[0] = line_count;
-            }
-            if searches_result_line_no[1] == -1 {
-                searches_result_line_no[1] = line_count;
-            }
-
             // Calculate 2d position by finding the beginning of the line and measuring distance
-            // TODO:
-                // cursor_offset.x = InputTextCalcTextSizeW(ImStrbolW(searches_input_ptr[0], &text_begin), searches_input_ptr[0], None, false).x;
-            cursor_offset.y = searches_result_line_no[0] * g.FontSize;
-            if searches_result_line_no[1] >= 0
+            let cursor_line: usize = state.TextW.LineOfPos(state.Stb.cursor);
+            cursor_offset.x = InputTextCalcRangeWidth(&state.TextW, state.TextW.LineStart(cursor_line), state.Stb.cursor, &char_advance);
+            cursor_offset.y = (cursor_line + 1) as c_float * g.FontSize;
+            if render_selection
             {
-             // TODO
-                // select_start_offset.x = InputTextCalcTextSizeW(ImStrbolW(searches_input_ptr[1], &text_begin), searches_input_ptr[1], None, false).x;
-                select_start_offset.y = searches_result_line_no[1] * g.FontSize;
+                let select_start: usize = ImMin(state.Stb.select_start, state.Stb.select_end);
+                let select_start_line: usize = state.TextW.LineOfPos(select_start);
+                select_start_offset.x = InputTextCalcRangeWidth(&state.TextW, state.TextW.LineStart(select_start_line), select_start, &char_advance);
+                select_start_offset.y = (select_start_line + 1) as c_float * g.FontSize;
             }
 
             // Store text height (note that we haven't calculated text width at all, see GitHub issues #383, #1224)
             if is_multiline {
-                text_size = Vector2::new(inner_size.x, line_count * g.FontSize);
+                text_size = Vector2::from_floats(inner_size.x, state.TextW.LineCount() as c_float * g.FontSize);
             }
         }
 
@@ -1180,45 +1294,76 @@ pub unsafe fn InputTextEx(label: String,
             state.CursorFollow = false;
         }
 
+        // Find bar: refresh matches, bring the selected one into view, highlight the visible ones
+        let is_find_open: bool = is_find_enabled && state.Find.Open && render_cursor;
+        if is_find_open
+        {
+            InputTextFindUpdate(state);
+            if state.Find.ScrollToCurrent {
+                InputTextFindScrollToCurrent(state, draw_window, draw_pos, inner_size.x - style.FramePadding.x, g.FontSize, &char_advance);
+            }
+            InputTextFindRenderMatches(&mut draw_window.DrawList, state, draw_pos - Vector2::new(state.ScrollX, 0.0), &clip_rect, g.FontSize, &char_advance);
+        }
+
         // Draw selection
         let draw_scroll: Vector2 = Vector2::new(state.ScrollX, 0.0);
         if render_selection
         {
-            let text_selected_begin = ImMin(state.Stb.select_start, state.Stb.select_end);
-            let text_selected_end = ImMax(state.Stb.select_start, state.Stb.select_end);
+            // Primary selection first, then the ones of extra cursors (ImGuiInputTextFlags_MultiCursor)
+            let mut selections: Vec<(usize, usize)> = Vec::with_capacity(state.ExtraCursors.len() + 1);
+            if state.HasSelection() || select_all {
+                selections.push((ImMin(state.Stb.select_start, state.Stb.select_end), ImMax(state.Stb.select_start, state.Stb.select_end)));
+            }
+            selections.extend(state.ExtraCursors.iter().filter(|cursor| cursor.HasSelection()).map(|cursor| (cursor.RangeMin(), cursor.RangeMax())));
 
             bg_color: u32 = GetColorU32(ImGuiCol_TextSelectedBg, if render_cursor { 1.0} else {0.60}); // FIXME: current code flow mandate that render_cursor is always true here, we are leaving the transparent one for tests.
             let bg_offy_up: c_float =  if is_multiline { 0.0 }else {- 1.0};    // FIXME: those offsets should be part of the style? they don't play so well with multi-line selection.
             let bg_offy_dn: c_float = if is_multiline { 0.0} else {2.0};
-            let mut rect_pos: Vector2 = draw_pos + select_start_offset - draw_scroll;
-            // for (*let p: ImWchar = text_selected_begin; p < text_selected_end; )
-            for p in text_selected_begin .. text_selected_end
+            for (selection_n, &(text_selected_begin, text_selected_end)) in selections.iter().enumerate()
             {
-                if rect_pos.y > clip_rect.w + g.FontSize{
-                    break;}
+                let mut line: usize = state.TextW.LineOfPos(text_selected_begin);
+                let line_last: usize = state.TextW.LineOfPos(text_selected_end);
+                let mut rect_pos: Vector2 = if selection_n == 0 && state.HasSelection() {
+                    draw_pos + select_start_offset - draw_scroll
+                } else {
+                    let line_start_x: c_float = InputTextCalcRangeWidth(&state.TextW, state.TextW.LineStart(line), text_selected_begin, &char_advance);
+                    draw_pos + Vector2::from_floats(line_start_x, (line + 1) as c_float * g.FontSize) - draw_scroll
+                };
+
+                // Jump straight to the first visible line instead of scanning the selected text above it
                 if rect_pos.y < clip_rect.y
                 {
-                    //p = (const ImWchar*)wmemchr((const wchar_t*)p, '\n', text_selected_end - p);  // FIXME-OPT: Could use this when wchar_t are 16-bit
-                    //p = p ? p + 1 : text_selected_end;
-                    while p < text_selected_end {
-                        // TODO
-                        // if (*p + + == '\n') {
-                        //     break;
-                        // }
+                    let lines_skipped: usize = ((clip_rect.y - rect_pos.y) / g.FontSize) as usize;
+                    if lines_skipped > 0
+                    {
+                        line += lines_skipped;
+                        rect_pos.x = draw_pos.x - draw_scroll.x;
+                        rect_pos.y += lines_skipped as c_float * g.FontSize;
                     }
                 }
-                else
+                while line <= line_last
                 {
-                    // let rect_size: ImVec2 = InputTextCalcTextSizeW(p, text_selected_end, &mut p,  true);
-                    // if rect_size.x <= 0.0 { rect_size.x = IM_FLOOR(g.Font.GetCharAdvance(' ') * 0.5); } // So we can see selected empty lines
-                    // let mut rect: ImRect = ImRect::new(rect_pos + ImVec2::new(0.0, bg_offy_up - g.FontSize), rect_pos + ImVec2::new(rect_size.x, bg_offy_dn));
+                    if rect_pos.y > clip_rect.w + g.FontSize {
+                        break;}
+                    let line_start: usize = state.TextW.LineStart(line);
+                    let line_end: usize = state.TextW.LineEnd(line);
+                    let p: usize = ImMax(text_selected_begin, line_start);
+                    let p_end: usize = ImMin(text_selected_end, line_end);
+                    let mut rect_size_x: c_float = if p == line_start && p_end == line_end {
+                        state.LineWidths.GetLineWidth(&state.TextW, line, &char_advance)
+                    } else {
+                        InputTextCalcRangeWidth(&state.TextW, p, p_end, &char_advance)
+                    };
+                    if rect_size_x <= 0.0 { rect_size_x = IM_FLOOR(char_advance(' ') * 0.5); } // So we can see selected empty lines
+                    let mut rect: ImRect = ImRect::from_vec2(&(rect_pos + Vector2::from_floats(0.0, bg_offy_up - g.FontSize)), &(rect_pos + Vector2::from_floats(rect_size_x, bg_offy_dn)));
                     rect.ClipWith(clip_rect);
                     if rect.Overlaps(clip_rect) {
-                        draw_window.DrawList.AddRectFilled(rect.Min, rect.Max, bg_color);
+                        draw_window.DrawList.AddRectFilled(&rect.min, &rect.max, bg_color, 0.0, 0);
                     }
+                    rect_pos.x = draw_pos.x - draw_scroll.x;
+                    rect_pos.y += g.FontSize;
+                    line += 1;
                 }
-                rect_pos.x = draw_pos.x - draw_scroll.x;
-                rect_pos.y += g.FontSize;
             }
         }
 
@@ -1228,26 +1373,68 @@ pub unsafe fn InputTextEx(label: String,
             col: u32 = GetColorU32(if is_displaying_hint { ImGuiCol_TextDisabled } else { ImGuiCol_Text }, 0.0);
             draw_window.DrawList.AddText(g.Font, g.FontSize, draw_pos - draw_scroll, col, & buf_display, buf_display_end, 0.0, if is_multiline { None} else {& clip_rect});
         }
+        hovered_diagnostic = InputTextRenderDiagnostics(g, &mut draw_window.DrawList, &diagnostics, draw_pos - draw_scroll, &clip_rect, diagnostics_mouse_pos);
 
         // Draw blinking cursor
         if render_cursor
         {
             state.CursorAnim += io.DeltaTime;
-            let mut cursor_is_visible: bool =  (!g.IO.ConfigInputTextCursorBlink) || (state.CursorAnim <= 0.0) || ImFmod(state.CursorAnim, 1.200) <= 0.80;
-            let cursor_screen_pos: Vector2 = ImFloor(draw_pos + cursor_offset - draw_scroll);
+            let mut cursor_is_visible: bool =  ((!g.IO.ConfigInputTextCursorBlink) || (state.CursorAnim <= 0.0) || ImFmod(state.CursorAnim, 1.200) <= 0.80) && !state.Find.HasFocus;
+            let mut cursor_screen_pos: Vector2 = ImFloor(draw_pos + cursor_offset - draw_scroll);
+            let mut ime_input_pos: Vector2 = cursor_screen_pos;
+
+            // IME composition, drawn at the cursor until the IME commits it as regular characters. The caret moves inside it.
+            if !is_readonly && !is_password && !io.InputPreedit.Text.is_empty() && !state.Find.HasFocus
+            {
+                let (preedit_caret_pos, preedit_clause_pos) = InputTextRenderPreedit(g, &mut draw_window.DrawList, &io.InputPreedit, cursor_screen_pos);
+                cursor_screen_pos = ImFloor(preedit_caret_pos);
+                ime_input_pos = ImFloor(preedit_clause_pos);
+            }
+
             let mut cursor_screen_rect: ImRect = ImRect::new(cursor_screen_pos.x, cursor_screen_pos.y - g.FontSize + 0.5, cursor_screen_pos.x + 1.0, cursor_screen_pos.y - 1.5);
             if cursor_is_visible && cursor_screen_rect.Overlaps(clip_rect) {
                 draw_window.DrawList.AddLine(cursor_screen_rect.min, cursor_screen_rect.GetBL(), GetColorU32(ImGuiCol_Text, 0.0));
             }
 
+            // Extra cursors blink along with the primary one
+            if cursor_is_visible
+            {
+                for extra_cursor in state.ExtraCursors.iter()
+                {
+                    let extra_line: usize = state.TextW.LineOfPos(extra_cursor.Cursor);
+                    let extra_offset = Vector2::from_floats(
+                        InputTextCalcRangeWidth(&state.TextW, state.TextW.LineStart(extra_line), extra_cursor.Cursor, &char_advance),
+                        (extra_line + 1) as c_float * g.FontSize);
+                    let extra_screen_pos: Vector2 = ImFloor(draw_pos + extra_offset - draw_scroll);
+                    let extra_screen_rect: ImRect = ImRect::from_floats(extra_screen_pos.x, extra_screen_pos.y - g.FontSize + 0.5, extra_screen_pos.x + 1.0, extra_screen_pos.y - 1.5);
+                    if extra_screen_rect.Overlaps(clip_rect) {
+                        draw_window.DrawList.AddLine(extra_screen_rect.min, extra_screen_rect.GetBL(), GetColorU32(ImGuiCol_Text, 0.0));
+                    }
+                }
+            }
+
             // Notify OS of text input position for advanced IME (-1 x offset so that Windows IME can cover our cursor. Bit of an extra nicety.)
             if !is_readonly
             {
                 g.PlatformImeData.WantVisible = true;
-                g.PlatformImeData.InputPos = Vector2::new(cursor_screen_pos.x - 1.0, cursor_screen_pos.y - g.FontSize);
+                g.PlatformImeData.InputPos = Vector2::new(ime_input_pos.x - 1.0, ime_input_pos.y - g.FontSize);
                 g.PlatformImeData.InputLineHeight = g.FontSize;
                 g.PlatformImeViewport = window.Viewport.ID;
             }
+
+            // Completion popup, attached to the caret
+            if is_completion_enabled && state.Completion.Open
+            {
+                let caret_rect = ImRect::from_floats(cursor_screen_pos.x, cursor_screen_pos.y - g.FontSize, cursor_screen_pos.x + 1.0, cursor_screen_pos.y);
+                InputTextCompletionRender(g, id, state, &caret_rect);
+            }
+        }
+
+        // Find bar, over the text
+        if is_find_open
+        {
+            let find_bar = InputTextFindCalcBarLayout(g, frame_bb.min, inner_size, state.Find.ShowReplace);
+            InputTextFindRenderBar(g, &mut draw_window.DrawList, state, &find_bar, is_readonly);
         }
     }
     else
@@ -1268,6 +1455,7 @@ pub unsafe fn InputTextEx(label: String,
         //     col: u32 = GetColorU32(if is_displaying_hint { ImGuiCol_TextDisabled } else { ImGuiCol_Text }, 0.0);
         //     draw_window.DrawList.AddText(g.Font, g.FontSize, draw_pos, col, &buf_display, buf_display_end, 0.0, if is_multiline { None} else {& clip_rect});
         // }
+        hovered_diagnostic = InputTextRenderDiagnostics(g, &mut draw_window.DrawList, &diagnostics, draw_pos, &clip_rect, diagnostics_mouse_pos);
     }
 
     if is_password && !is_displaying_hint {
@@ -1295,6 +1483,14 @@ pub unsafe fn InputTextEx(label: String,
         }
     }
 
+    if let Some(diagnostic_n) = hovered_diagnostic {
+        InputTextRenderDiagnosticTooltip(&diagnostics.Diagnostics[diagnostic_n]);
+    }
+    if use_diagnostics {
+        diagnostics.LastFrameSeen = g.FrameCount;
+        g.InputTextDiagnostics.insert(id, diagnostics);
+    }
+
     // Log as text
     if g.LogEnabled && (!is_password || is_displaying_hint)
     {
@@ -6,6 +6,8 @@ use crate::GImGui;
 use crate::input_text_state::ImGuiInputTextState;
 use crate::core::math_ops::{ImClamp, ImMax};
 use crate::core::utils::flag_set;
+use crate::window::input_text_diagnostic_severity::ImGuiInputTextDiagnosticSeverity;
+use crate::window::input_text_diagnostics::ImGuiInputTextDiagnostic;
 
 // Shared state of InputText(), passed as an argument to your callback when a ImGuiInputTextFlags_Callback* flag is used.
 // The callback function should return 0 by default.
@@ -16,6 +18,8 @@ use crate::core::utils::flag_set;
 // - ImGuiInputTextFlags_CallbackHistory:     Callback on pressing Up/Down arrows
 // - ImGuiInputTextFlags_CallbackCharFilter:  Callback on character inputs to replace or discard them. Modify 'EventChar' to replace or discard, or return 1 in callback to discard.
 // - ImGuiInputTextFlags_CallbackResize:      Callback on buffer capacity changes request (beyond 'buf_size' parameter value), allowing the string to grow.
+// - ImGuiInputTextFlags_CallbackCandidates:  Callback when typing a word or on Ctrl+Space. 'Buf' only holds the word around the cursor. Call AddCandidate() for each completion of the word, don't modify the buffer.
+// - ImGuiInputTextFlags_CallbackDiagnostics: Callback when the displayed text changed (or after InputTextRefreshDiagnostics()), to report ranges of 'Buf' to underline. Call AddDiagnostic(), don't modify the buffer.
 #[derive(Default, Debug, Clone, Copy)]
 pub struct ImGuiInputTextCallbackData {
     // EventFlag: ImGuiInputTextFlags;      // One ImGuiInputTextFlags_Callback*    // Read-only
@@ -45,6 +49,14 @@ pub struct ImGuiInputTextCallbackData {
     pub SelectionStart: size_t,
     // c_int                 SelectionEnd;   //                                      // Read-write   // [Completion,History,Always]
     pub SelectionEnd: size_t,
+    // ImVector<ImGuiInputTextDiagnostic> Diagnostics;                     // Write        // [Diagnostics] Ranges to underline, filled with AddDiagnostic()
+    pub Diagnostics: Vec<ImGuiInputTextDiagnostic>,
+    // c_int                 CompletionWordStart; //                                 // Read-write   // [Candidates] Byte range of Buf replaced by the accepted candidate. Defaults to the whole word, narrow it to keep part of the word.
+    pub CompletionWordStart: size_t,
+    // c_int                 CompletionWordEnd;   //                                 // Read-write   // [Candidates]
+    pub CompletionWordEnd: size_t,
+    // ImVector<char*>     Candidates;     //                                      // Write        // [Candidates] Filled with AddCandidate(). Filtering by what was typed of the word is done by InputText().
+    pub Candidates: Vec<String>,
 }
 
 impl ImGuiInputTextCallbackData {
@@ -131,4 +143,15 @@ pub unsafe fn DeleteChars(&mut self, pos: usize, bytes_count: usize)
     pub fn HasSelection(&self) -> bool {
         self.SelectionStart != self.SelectionEnd
     }
+
+    // [Candidates] Offer 'candidate' as a completion of the word (CompletionWordStart..CompletionWordEnd)
+    pub fn AddCandidate(&mut self, candidate: &str) {
+        self.Candidates.push(String::from(candidate));
+    }
+
+    // [Diagnostics] Underline Buf[start..end] (byte offsets) and show 'message' in a tooltip when it is hovered.
+    // An empty range marks the character at 'start' (e.g. a missing token). Ranges are clamped to the buffer and may span several lines.
+    pub fn AddDiagnostic(&mut self, start: usize, end: usize, severity: ImGuiInputTextDiagnosticSeverity, message: &str) {
+        self.Diagnostics.push(ImGuiInputTextDiagnostic { Start: start, End: end, Severity: severity, Message: String::from(message) });
+    }
 }
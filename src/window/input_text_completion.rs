@@ -0,0 +1,328 @@
+#![allow(non_snake_case)]
+
+// [SECTION] Completion popup for InputText() (ImGuiInputTextFlags_CallbackCandidates)
+// - InputTextCompletionUpdate()              query candidates when typing (or on [Ctrl+Space]), close the popup when the cursor leaves the word
+// - InputTextCompletionQuery()               ask the user callback for candidates for the word under the cursor (only the word is passed), then filter them
+// - InputTextCompletionOnKey()               [Up/Down/PageUp/PageDown] pick, [Enter] accept, [Escape] dismiss
+// - InputTextCompletionAccept()              replace the word with the candidate as a single undo step
+// - InputTextCompletionRender()              the list window, below the caret (or above when there is no room) as placed by FindBestWindowPosForPopupEx(); click a row to accept it
+// The list is a tooltip-style window that doesn't take focus or nav: the text widget keeps the active id, so keyboard focus never
+// leaves the input while picking a candidate, and the input ignores clicks while the mouse is over that window (g.HoveredWindow).
+
+use libc::c_float;
+use crate::color::{ImGuiCol_Border, ImGuiCol_Header, ImGuiCol_HeaderHovered, ImGuiCol_Text};
+use crate::core::condition::ImGuiCond_Always;
+use crate::core::context::AppContext;
+use crate::core::direction::{ImGuiDir, ImGuiDir_None};
+use crate::core::type_defs::{ImGuiInputTextCallback, ImguiHandle};
+use crate::core::vec2::Vector2;
+use crate::drawing::draw_flags::ImDrawFlags_None;
+use crate::input_text_state::ImGuiInputTextState;
+use crate::io::input_ops::{IsKeyPressed, IsMouseHoveringRect};
+use crate::io::key::{ImGuiKey_DownArrow, ImGuiKey_Enter, ImGuiKey_Escape, ImGuiKey_KeypadEnter, ImGuiKey_PageDown, ImGuiKey_PageUp, ImGuiKey_UpArrow};
+use crate::item::item_ops::ItemSize;
+use crate::rect::ImRect;
+use crate::stb::stb_textedit::{stb_text_begin_undo_group, stb_text_end_undo_group, stb_textedit_cut, stb_textedit_paste};
+use crate::style::style_ops::GetColorU32;
+use crate::style::style_var::ImGuiStyleVar_WindowPadding;
+use crate::style::style_var_ops::{PopStyleVarInt, PushStyleVarVec2};
+use crate::text::text_ops::CalcTextSize;
+use crate::widgets::popup_ops::{FindBestWindowPosForPopupEx, GetPopupAllowedExtentRect};
+use crate::widgets::popup_position_policy::ImGuiPopupPositionPolicy_ComboBox;
+use crate::window::input_text_callback_data::ImGuiInputTextCallbackData;
+use crate::window::input_text_flags::{ImGuiInputTextFlags, ImGuiInputTextFlags_CallbackCandidates};
+use crate::window::input_text_multi_cursor::InputTextClearExtraCursors;
+use crate::window::input_text_storage::ImGuiInputTextStorage;
+use crate::window::ops::{BeginEx, End};
+use crate::window::props::SetNextWindowPos;
+use crate::window::window_flags::{ImGuiWindowFlags, ImGuiWindowFlags_AlwaysAutoResize, ImGuiWindowFlags_NoDocking, ImGuiWindowFlags_NoFocusOnAppearing, ImGuiWindowFlags_NoMove, ImGuiWindowFlags_NoNav, ImGuiWindowFlags_NoResize, ImGuiWindowFlags_NoSavedSettings, ImGuiWindowFlags_NoTitleBar, ImGuiWindowFlags_Tooltip};
+
+// Rows shown at once, the list scrolls to keep the current candidate visible
+pub const IMGUI_INPUT_TEXT_COMPLETION_MAX_ROWS: usize = 8;
+// Candidates measured to size the popup, so its width doesn't change while scrolling
+const IMGUI_INPUT_TEXT_COMPLETION_MEASURED: usize = 100;
+
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiInputTextCompletionState {
+    pub Open: bool,
+    pub MouseHeld: bool,
+    // a click started on the popup: its drag and release must not reach the text
+    pub Candidates: Vec<String>,
+    // as supplied by the callback
+    pub Filtered: Vec<usize>,
+    // indices into Candidates matching Prefix, those starting with it first
+    pub Current: usize,
+    // index into Filtered
+    pub FirstVisible: usize,
+    pub WordStart: usize,
+    pub WordEnd: usize,
+    // range replaced when accepting, in characters
+    pub Prefix: String,
+    // text between WordStart and the cursor, candidates are filtered with it (case insensitive)
+    pub EditCount: u32,
+    // state.EditCount when last queried, to requery after Backspace/Delete while open
+    pub WindowID: ImguiHandle,
+    // list window of the last frame, compared with g.HoveredWindow so clicks on it don't reach the text
+    pub LastDir: ImGuiDir,
+}
+
+fn InputTextIsCompletionWordChar(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn InputTextCompletionFilter(completion: &mut ImGuiInputTextCompletionState) {
+    let prefix = completion.Prefix.to_lowercase();
+    let mut starting: Vec<usize> = vec![];
+    let mut containing: Vec<usize> = vec![];
+    for (candidate_n, candidate) in completion.Candidates.iter().enumerate() {
+        let candidate = candidate.to_lowercase();
+        if candidate.starts_with(&prefix) {
+            starting.push(candidate_n);
+        } else if candidate.contains(&prefix) {
+            containing.push(candidate_n);
+        }
+    }
+    starting.extend(containing);
+    completion.Filtered = starting;
+    completion.Current = 0;
+    completion.FirstVisible = 0;
+}
+
+fn InputTextCompletionKeepVisible(completion: &mut ImGuiInputTextCompletionState) {
+    if completion.Current < completion.FirstVisible {
+        completion.FirstVisible = completion.Current;
+    } else if completion.Current >= completion.FirstVisible + IMGUI_INPUT_TEXT_COMPLETION_MAX_ROWS {
+        completion.FirstVisible = completion.Current + 1 - IMGUI_INPUT_TEXT_COMPLETION_MAX_ROWS;
+    }
+}
+
+pub fn InputTextCompletionClose(state: &mut ImGuiInputTextState) {
+    let completion = &mut state.Completion;
+    completion.Open = false;
+    completion.MouseHeld = false;
+    completion.Candidates.clear();
+    completion.Filtered.clear();
+    completion.EditCount = state.EditCount;
+}
+
+// The word is the identifier around the cursor. Only the word is passed to the callback as its Buf, which may narrow the replaced range.
+pub unsafe fn InputTextCompletionQuery(state: &mut ImGuiInputTextState, callback: Option<ImGuiInputTextCallback>, callback_user_data: Option<&Vec<u8>>, flags: ImGuiInputTextFlags, force_open: bool) {
+    let len = state.TextW.Len();
+    let cursor = state.Stb.cursor.min(len);
+    let mut word_start = cursor;
+    while word_start > 0 && InputTextIsCompletionWordChar(state.TextW.GetChar(word_start - 1)) {
+        word_start -= 1;
+    }
+    let mut word_end = cursor;
+    while word_end < len && InputTextIsCompletionWordChar(state.TextW.GetChar(word_end)) {
+        word_end += 1;
+    }
+
+    let mut text = String::new();
+    state.TextW.CopyRangeUtf8(word_start, word_end, &mut text);
+    let cursor_a = text.char_indices().nth(cursor - word_start).map_or(text.len(), |(n, _)| n);
+
+    let mut callback_data: ImGuiInputTextCallbackData = ImGuiInputTextCallbackData::default();
+    callback_data.EventFlag = ImGuiInputTextFlags_CallbackCandidates;
+    callback_data.Flags = flags;
+    callback_data.UserData = callback_user_data.unwrap_or(&vec![]).clone();
+    callback_data.BufTextLen = text.len();
+    callback_data.BufSize = state.BufCapacityA;
+    callback_data.CursorPos = cursor_a;
+    callback_data.SelectionStart = cursor_a;
+    callback_data.SelectionEnd = cursor_a;
+    callback_data.CompletionWordStart = 0;
+    callback_data.CompletionWordEnd = text.len();
+    callback_data.Buf = text;
+    // IM_ASSERT(callback != NULL);
+    if callback.is_some() {
+        callback.unwrap()(&mut callback_data);
+    }
+
+    // Back to characters. The word has to contain the cursor, whatever the callback did to its bounds.
+    let text = &callback_data.Buf;
+    let mut word_start_a = callback_data.CompletionWordStart.min(cursor_a);
+    let mut word_end_a = callback_data.CompletionWordEnd.clamp(cursor_a, text.len());
+    while !text.is_char_boundary(word_start_a) {
+        word_start_a -= 1;
+    }
+    while !text.is_char_boundary(word_end_a) {
+        word_end_a += 1;
+    }
+    let was_open = state.Completion.Open;
+    let completion = &mut state.Completion;
+    completion.WordStart = word_start + text[..word_start_a].chars().count();
+    completion.WordEnd = word_start + text[..word_end_a].chars().count();
+    completion.Prefix = String::from(&text[word_start_a..cursor_a]);
+    completion.Candidates = std::mem::take(&mut callback_data.Candidates);
+    completion.EditCount = state.EditCount;
+    InputTextCompletionFilter(completion);
+
+    // Nothing to show, or the word is already the only candidate
+    let word = &text[word_start_a..word_end_a];
+    completion.Open = (force_open || !completion.Prefix.is_empty()) && !completion.Filtered.is_empty();
+    if completion.Filtered.len() == 1 && completion.Candidates[completion.Filtered[0]] == word {
+        completion.Open = force_open;
+    }
+    if completion.Open && !was_open {
+        completion.LastDir = ImGuiDir_None;
+    }
+    if !completion.Open {
+        completion.MouseHeld = false;
+    }
+}
+
+// Call once per frame after keys and characters were processed
+pub unsafe fn InputTextCompletionUpdate(state: &mut ImGuiInputTextState, callback: Option<ImGuiInputTextCallback>, callback_user_data: Option<&Vec<u8>>, flags: ImGuiInputTextFlags, typed: bool, force_open: bool) {
+    if typed || force_open || (state.Completion.Open && state.Completion.EditCount != state.EditCount) {
+        InputTextCompletionQuery(state, callback, callback_user_data, flags, force_open);
+        return;
+    }
+    if state.Completion.Open && (state.HasSelection() || state.Stb.cursor < state.Completion.WordStart || state.Stb.cursor > state.Completion.WordEnd) {
+        InputTextCompletionClose(state);
+    }
+}
+
+// Replace the word around the cursor with the current candidate
+pub unsafe fn InputTextCompletionAccept(state: &mut ImGuiInputTextState) {
+    let completion = &state.Completion;
+    let candidate: Vec<char> = match completion.Filtered.get(completion.Current) {
+        Some(&candidate_n) => completion.Candidates[candidate_n].chars().collect(),
+        None => return,
+    };
+    let len = state.TextW.Len();
+    let word_end = completion.WordEnd.min(len);
+    let word_start = completion.WordStart.min(word_end);
+
+    InputTextClearExtraCursors(state);
+    stb_text_begin_undo_group(&mut state.Stb.undostate);
+    state.Stb.select_start = word_start;
+    state.Stb.select_end = word_end;
+    state.Stb.cursor = word_end;
+    let mut stb = std::mem::take(&mut state.Stb);
+    if candidate.is_empty() {
+        stb_textedit_cut(state, &mut stb);
+    } else {
+        stb_textedit_paste(state, &mut stb, &candidate, candidate.len());
+    }
+    state.Stb = stb;
+    stb_text_end_undo_group(&mut state.Stb.undostate);
+    state.CursorFollow = true;
+    state.CursorAnimReset();
+    InputTextCompletionClose(state);
+}
+
+// Returns true when the key was used by the popup, and so must not reach the text
+pub unsafe fn InputTextCompletionOnKey(state: &mut ImGuiInputTextState) -> bool {
+    let count = state.Completion.Filtered.len();
+    if count == 0 {
+        return false;
+    }
+    if IsKeyPressed(ImGuiKey_Enter, false) || IsKeyPressed(ImGuiKey_KeypadEnter, false) {
+        InputTextCompletionAccept(state);
+        return true;
+    }
+    if IsKeyPressed(ImGuiKey_Escape, false) {
+        InputTextCompletionClose(state);
+        return true;
+    }
+
+    let completion = &mut state.Completion;
+    if IsKeyPressed(ImGuiKey_DownArrow, true) {
+        completion.Current = (completion.Current + 1) % count;
+    } else if IsKeyPressed(ImGuiKey_UpArrow, true) {
+        completion.Current = (completion.Current + count - 1) % count;
+    } else if IsKeyPressed(ImGuiKey_PageDown, true) {
+        completion.Current = (completion.Current + IMGUI_INPUT_TEXT_COMPLETION_MAX_ROWS).min(count - 1);
+    } else if IsKeyPressed(ImGuiKey_PageUp, true) {
+        completion.Current = completion.Current.saturating_sub(IMGUI_INPUT_TEXT_COMPLETION_MAX_ROWS);
+    } else {
+        return false;
+    }
+    InputTextCompletionKeepVisible(completion);
+    true
+}
+
+// 'caret_rect' is the screen rectangle of the caret: the list goes below it, or above it when there is no room below
+pub unsafe fn InputTextCompletionRender(g: &mut AppContext, id: ImguiHandle, state: &mut ImGuiInputTextState, caret_rect: &ImRect) {
+    let padding = g.style.FramePadding;
+    let row_height: c_float = g.FontSize + g.style.ItemSpacing.y;
+
+    let completion = &mut state.Completion;
+    let rows = completion.Filtered.len().min(IMGUI_INPUT_TEXT_COMPLETION_MAX_ROWS);
+    if rows == 0 {
+        return;
+    }
+    let mut content_width: c_float = 0.0;
+    for &candidate_n in completion.Filtered.iter().take(IMGUI_INPUT_TEXT_COMPLETION_MEASURED) {
+        content_width = content_width.max(CalcTextSize(g, &completion.Candidates[candidate_n], false, 0.0).x);
+    }
+    let has_scrollbar = completion.Filtered.len() > rows;
+    let scrollbar_width: c_float = if has_scrollbar { 4.0 } else { 0.0 };
+    let row_width: c_float = content_width + scrollbar_width;
+    let size = Vector2::from_floats(row_width + padding.x * 2.0, rows as c_float * row_height + padding.y * 2.0);
+
+    // The size is known before Begin(), so place the window the same way BeginCombo() places its popup
+    let mut r_outer: ImRect = GetPopupAllowedExtentRect(g, g.current_window_mut().unwrap());
+    let mut caret_rect_mut = *caret_rect;
+    let pos: Vector2 = FindBestWindowPosForPopupEx(&caret_rect_mut.GetBL(), &size, &mut completion.LastDir, &mut r_outer, caret_rect, ImGuiPopupPositionPolicy_ComboBox);
+    SetNextWindowPos(g, &pos, ImGuiCond_Always, None);
+
+    let name = format!("##InputTextCompletion_{:08X}", id);
+    let window_flags: ImGuiWindowFlags = ImGuiWindowFlags_Tooltip
+        | ImGuiWindowFlags_NoTitleBar
+        | ImGuiWindowFlags_NoMove
+        | ImGuiWindowFlags_NoResize
+        | ImGuiWindowFlags_NoSavedSettings
+        | ImGuiWindowFlags_AlwaysAutoResize
+        | ImGuiWindowFlags_NoFocusOnAppearing
+        | ImGuiWindowFlags_NoNav
+        | ImGuiWindowFlags_NoDocking;
+    PushStyleVarVec2(ImGuiStyleVar_WindowPadding, &padding);
+    let is_open = BeginEx(g, &name, None, window_flags);
+    PopStyleVarInt(1);
+    completion.WindowID = g.CurrentWindow;
+    let mut clicked: Option<usize> = None;
+    if is_open {
+        let window_hovered = g.HoveredWindow == completion.WindowID;
+        let first_row_pos = g.current_window_mut().unwrap().dc.cursor_pos;
+        for row in 0..rows {
+            let filtered_n = completion.FirstVisible + row;
+            if filtered_n >= completion.Filtered.len() {
+                break;
+            }
+            let row_min = Vector2::from_floats(first_row_pos.x, first_row_pos.y + row as c_float * row_height);
+            let row_max = Vector2::from_floats(row_min.x + content_width, row_min.y + row_height);
+            let row_hovered = window_hovered && IsMouseHoveringRect(&row_min, &row_max, true);
+            if row_hovered && g.IO.MouseClicked[0] {
+                clicked = Some(filtered_n);
+            }
+            let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+            if filtered_n == completion.Current {
+                draw_list.AddRectFilled(&row_min, &row_max, GetColorU32(ImGuiCol_Header, 1.0), 0.0, ImDrawFlags_None);
+            } else if row_hovered {
+                draw_list.AddRectFilled(&row_min, &row_max, GetColorU32(ImGuiCol_HeaderHovered, 1.0), 0.0, ImDrawFlags_None);
+            }
+            let text_pos = Vector2::from_floats(row_min.x, row_min.y + g.style.ItemSpacing.y * 0.5);
+            draw_list.AddText(text_pos, GetColorU32(ImGuiCol_Text, 1.0), completion.Candidates[completion.Filtered[filtered_n]].clone());
+            ItemSize(g, &Vector2::from_floats(row_width, row_height), 0.0);
+        }
+
+        // Scroll position, as a thin grab along the right edge
+        if has_scrollbar {
+            let track_height = rows as c_float * row_height;
+            let count = completion.Filtered.len() as c_float;
+            let grab_min_y = first_row_pos.y + track_height * (completion.FirstVisible as c_float / count);
+            let grab_max_y = first_row_pos.y + track_height * ((completion.FirstVisible + rows) as c_float / count);
+            let grab_x = first_row_pos.x + content_width;
+            let draw_list = &mut g.current_window_mut().unwrap().DrawList;
+            draw_list.AddRectFilled(&Vector2::from_floats(grab_x, grab_min_y), &Vector2::from_floats(grab_x + scrollbar_width, grab_max_y), GetColorU32(ImGuiCol_Border, 1.0), scrollbar_width * 0.5, ImDrawFlags_None);
+        }
+    }
+    End();
+
+    if let Some(filtered_n) = clicked {
+        state.Completion.Current = filtered_n;
+        InputTextCompletionAccept(state);
+    }
+}
@@ -0,0 +1,11 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+// Severity of a range reported by an ImGuiInputTextFlags_CallbackDiagnostics callback. Selects the color of the squiggly underline.
+pub type ImGuiInputTextDiagnosticSeverity = c_int;
+
+pub const ImGuiInputTextDiagnosticSeverity_Hint: ImGuiInputTextDiagnosticSeverity = 0; // e.g. spelling suggestion, style note
+pub const ImGuiInputTextDiagnosticSeverity_Info: ImGuiInputTextDiagnosticSeverity = 1;
+pub const ImGuiInputTextDiagnosticSeverity_Warning: ImGuiInputTextDiagnosticSeverity = 2;
+pub const ImGuiInputTextDiagnosticSeverity_Error: ImGuiInputTextDiagnosticSeverity = 3; // e.g. compile error, misspelled word
@@ -0,0 +1,205 @@
+#![allow(non_snake_case)]
+
+// [SECTION] Diagnostics underlines for InputText() (ImGuiInputTextFlags_CallbackDiagnostics)
+// - InputTextCallDiagnostics()               ask the user callback for the ranges of the displayed text, snapped to character boundaries
+// - InputTextUpdateDiagnostics()             call it again only when the displayed text changed
+// - InputTextRefreshDiagnostics()            request the ranges again on next frame (e.g. an async check finished)
+// - InputTextRenderDiagnostics()             squiggly underline the visible part of each range, return the hovered one
+// - InputTextRenderDiagnosticTooltip()       message of the hovered range
+// - InputTextGcDiagnostics()                 drop the caches of widgets which weren't submitted last frame (called by NewFrame())
+// Ranges are byte offsets into the UTF-8 text, so the app can pass them straight from its parser or spell checker.
+// They are cached per widget with the text they were requested for, and requested again when the text changes.
+
+use std::{iter, mem};
+use libc::c_float;
+use crate::color::color_u32_from_rgba;
+use crate::core::context::AppContext;
+use crate::core::type_defs::{ImGuiInputTextCallback, ImguiHandle};
+use crate::core::vec2::Vector2;
+use crate::core::vec4::ImVec4;
+use crate::drawing::draw_flags::ImDrawFlags_None;
+use crate::drawing::draw_list::ImDrawList;
+use crate::text::text_ops::TextUnformatted;
+use crate::widgets::tooltip_ops::{BeginTooltip, EndTooltip};
+use crate::window::input_text_callback_data::ImGuiInputTextCallbackData;
+use crate::window::input_text_diagnostic_severity::{ImGuiInputTextDiagnosticSeverity, ImGuiInputTextDiagnosticSeverity_Error, ImGuiInputTextDiagnosticSeverity_Info, ImGuiInputTextDiagnosticSeverity_Warning};
+use crate::window::input_text_flags::{ImGuiInputTextFlags, ImGuiInputTextFlags_CallbackDiagnostics};
+
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiInputTextDiagnostic {
+    pub Start: usize,
+    // Byte offset of the first character
+    pub End: usize,
+    // Byte offset past the last character. Start == End marks the single character at Start.
+    pub Severity: ImGuiInputTextDiagnosticSeverity,
+    pub Message: String,
+    // Tooltip shown while the range is hovered, none if empty
+}
+
+// Diagnostics of one InputText(), stored per ID in g.InputTextDiagnostics
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiInputTextDiagnosticsCache {
+    pub Text: String,
+    // Text the diagnostics were requested for
+    pub LineStarts: Vec<usize>,
+    // Byte offset of the first character of each line of Text
+    pub Diagnostics: Vec<ImGuiInputTextDiagnostic>,
+    pub Valid: bool,
+    // Cleared by InputTextRefreshDiagnostics()
+    pub LastFrameSeen: usize,
+}
+
+// Each cache holds a copy of its widget's text: don't keep them for IDs which are gone (closed windows, table rows...)
+pub fn InputTextGcDiagnostics(g: &mut AppContext) {
+    let frame_count = g.FrameCount;
+    g.InputTextDiagnostics.retain(|_, diagnostics| diagnostics.LastFrameSeen + 1 >= frame_count);
+}
+
+// Width of the marker drawn for an empty range at the end of a line (nothing to underline there)
+const IMGUI_INPUT_TEXT_DIAGNOSTIC_EOL_WIDTH: c_float = 6.0;
+
+pub fn InputTextDiagnosticColor(severity: ImGuiInputTextDiagnosticSeverity) -> u32 {
+    match severity {
+        ImGuiInputTextDiagnosticSeverity_Error => color_u32_from_rgba(255, 64, 64, 255),
+        ImGuiInputTextDiagnosticSeverity_Warning => color_u32_from_rgba(255, 190, 40, 255),
+        ImGuiInputTextDiagnosticSeverity_Info => color_u32_from_rgba(80, 150, 255, 255),
+        _ => color_u32_from_rgba(150, 150, 150, 255),
+    }
+}
+
+fn InputTextFloorCharBoundary(text: &str, mut offset: usize) -> usize {
+    offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+// Fill 'out' from the callback, sorted by increasing severity so the most severe underline is drawn (and hovered) last.
+// 'text' is lent to the callback as its Buf and handed back, the callback must not modify it.
+pub unsafe fn InputTextCallDiagnostics(callback: Option<ImGuiInputTextCallback>, callback_user_data: Option<&Vec<u8>>, flags: ImGuiInputTextFlags, text: &mut String, out: &mut Vec<ImGuiInputTextDiagnostic>) {
+    out.clear();
+    // IM_ASSERT(callback != NULL);
+    if callback.is_none() {
+        return;
+    }
+
+    let mut callback_data: ImGuiInputTextCallbackData = ImGuiInputTextCallbackData::default();
+    callback_data.EventFlag = ImGuiInputTextFlags_CallbackDiagnostics;
+    callback_data.Flags = flags;
+    callback_data.UserData = callback_user_data.unwrap_or(&vec![]).clone();
+    callback_data.BufTextLen = text.len();
+    callback_data.BufSize = text.len() + 1;
+    callback_data.Buf = mem::take(text);
+    callback.unwrap()(&mut callback_data);
+    *text = mem::take(&mut callback_data.Buf);
+
+    for mut diag in callback_data.Diagnostics.drain(..) {
+        diag.Start = InputTextFloorCharBoundary(text, diag.Start);
+        diag.End = InputTextFloorCharBoundary(text, diag.End.max(diag.Start));
+        if diag.End == diag.Start {
+            if let Some(c) = text[diag.Start..].chars().next().filter(|&c| c != '\n') {
+                diag.End = diag.Start + c.len_utf8();
+            }
+        }
+        out.push(diag);
+    }
+    out.sort_by_key(|diag| diag.Severity);
+}
+
+// Request the ranges again if 'text' differs from the one they were requested for
+pub unsafe fn InputTextUpdateDiagnostics(cache: &mut ImGuiInputTextDiagnosticsCache, callback: Option<ImGuiInputTextCallback>, callback_user_data: Option<&Vec<u8>>, flags: ImGuiInputTextFlags, text: &String) {
+    if cache.Valid && cache.Text.as_bytes() == text.as_bytes() {
+        return;
+    }
+    cache.Text.clear();
+    cache.Text.push_str(text);
+    cache.LineStarts.clear();
+    cache.LineStarts.extend(iter::once(0).chain(text.match_indices('\n').map(|(n, _)| n + 1)));
+    InputTextCallDiagnostics(callback, callback_user_data, flags, &mut cache.Text, &mut cache.Diagnostics);
+    cache.Valid = true;
+}
+
+// Ask for the diagnostics of InputText() 'id' again on its next frame, for when the app's results changed but the text didn't
+pub fn InputTextRefreshDiagnostics(g: &mut AppContext, id: ImguiHandle) {
+    if let Some(cache) = g.InputTextDiagnostics.get_mut(&id) {
+        cache.Valid = false;
+    }
+}
+
+fn InputTextRenderSquiggle(draw_list: &mut ImDrawList, x0: c_float, x1: c_float, y: c_float, col: u32) {
+    let step: c_float = 2.0;
+    let mut points: Vec<Vector2> = Vec::with_capacity(((x1 - x0) / step) as usize + 2);
+    let mut x = x0;
+    let mut up = false;
+    while x < x1 {
+        points.push(Vector2::from_floats(x, if up { y - step } else { y }));
+        x += step;
+        up = !up;
+    }
+    points.push(Vector2::from_floats(x1, if up { y - step } else { y }));
+    draw_list.AddPolyline(&points, col, ImDrawFlags_None, 1.0);
+}
+
+// 'text_origin' is the screen position of the top-left of the first line (scrolling applied), 'mouse_pos' is None when the widget isn't hovered.
+// Returns the index of the hovered diagnostic, if any.
+pub unsafe fn InputTextRenderDiagnostics(g: &mut AppContext, draw_list: &mut ImDrawList, cache: &ImGuiInputTextDiagnosticsCache, text_origin: Vector2, clip_rect: &ImVec4, mouse_pos: Option<Vector2>) -> Option<usize> {
+    let text: &str = &cache.Text;
+    let diagnostics: &[ImGuiInputTextDiagnostic] = &cache.Diagnostics;
+    if diagnostics.is_empty() {
+        return None;
+    }
+    let line_starts: &[usize] = &cache.LineStarts;
+    let line_of = |offset: usize| line_starts.partition_point(|&line_start| line_start <= offset) - 1;
+    let font_size: c_float = g.FontSize;
+    let font_scale: c_float = g.FontSize / g.Font.FontSize;
+    let text_width = |s: &str| -> c_float { s.chars().map(|c| g.Font.GetCharAdvance(c) * font_scale).sum() };
+
+    let mut hovered: Option<usize> = None;
+    for (diag_n, diag) in diagnostics.iter().enumerate() {
+        let col = InputTextDiagnosticColor(diag.Severity);
+        let line_first = line_of(diag.Start);
+        let line_last = line_of(diag.End);
+        for line in line_first..=line_last {
+            let line_bottom: c_float = text_origin.y + (line + 1) as c_float * font_size;
+            if line_bottom < clip_rect.y {
+                continue;
+            }
+            if line_bottom - font_size > clip_rect.w {
+                break;
+            }
+            let line_start = line_starts[line];
+            let line_end = if line + 1 < line_starts.len() { line_starts[line + 1] - 1 } else { text.len() };
+            let seg_start = diag.Start.max(line_start);
+            let seg_end = diag.End.min(line_end);
+            if seg_end <= seg_start && line != line_first {
+                continue; // Range stops right after a line break
+            }
+            let x0: c_float = text_origin.x + text_width(&text[line_start..seg_start]);
+            let x1: c_float = if seg_end > seg_start {
+                x0 + text_width(&text[seg_start..seg_end])
+            } else {
+                x0 + IMGUI_INPUT_TEXT_DIAGNOSTIC_EOL_WIDTH
+            };
+            if x1 < clip_rect.x || x0 > clip_rect.z {
+                continue;
+            }
+            InputTextRenderSquiggle(draw_list, x0, x1, line_bottom - 1.0, col);
+            if let Some(mouse_pos) = mouse_pos {
+                if mouse_pos.x >= x0 && mouse_pos.x < x1 && mouse_pos.y >= line_bottom - font_size && mouse_pos.y < line_bottom {
+                    hovered = Some(diag_n);
+                }
+            }
+        }
+    }
+    hovered
+}
+
+pub unsafe fn InputTextRenderDiagnosticTooltip(diag: &ImGuiInputTextDiagnostic) {
+    if diag.Message.is_empty() {
+        return;
+    }
+    BeginTooltip();
+    TextUnformatted(diag.Message.clone());
+    EndTooltip();
+}
@@ -0,0 +1,539 @@
+#![allow(non_snake_case)]
+
+// [SECTION] Find / replace bar for InputTextMultiline() (ImGuiInputTextFlags_FindReplace)
+// - InputTextFindOpen()                      [Ctrl+F] find, [Ctrl+H] find and replace. Query is pre-filled from a single-line selection.
+// - InputTextFindUpdate()                    recompile the query and refresh the matches when the query or the text changed
+// - InputTextFindStep()                      [Enter/F3] next, [Shift+Enter/Shift+F3] previous match, selected and scrolled to
+// - InputTextFindReplaceOne()                [Enter in replace field] replace the selected match, then select the next one
+// - InputTextFindReplaceAll()                [Ctrl+Alt+Enter] replace every match as a single undo step
+// - InputTextFindOnChar(), InputTextFindOnKey(), InputTextFindOnClick()
+// - InputTextFindRenderMatches()             highlight the visible matches
+// - InputTextFindRenderBar()                 the overlay itself, in the top-right corner of the widget
+// The bar is not made of regular widgets: the text widget keeps the active id (and so the InputTextState, undo stack and selection)
+// while the query is being typed, and routes keyboard input to the bar while it has focus.
+
+use libc::c_float;
+use crate::clipboard_ops::GetClipboardText;
+use crate::color::{ImGuiCol_Border, ImGuiCol_Button, ImGuiCol_ButtonActive, ImGuiCol_ButtonHovered, ImGuiCol_FrameBg, ImGuiCol_NavHighlight, ImGuiCol_PopupBg, ImGuiCol_Text, ImGuiCol_TextDisabled, ImGuiCol_TextSelectedBg};
+use crate::core::context::AppContext;
+use crate::core::vec2::Vector2;
+use crate::core::vec4::ImVec4;
+use crate::drawing::draw_flags::ImDrawFlags_None;
+use crate::drawing::draw_list::ImDrawList;
+use crate::input_text_state::ImGuiInputTextState;
+use crate::io::input_ops::IsKeyPressed;
+use crate::io::key::{ImGuiKey_Backspace, ImGuiKey_C, ImGuiKey_Enter, ImGuiKey_Escape, ImGuiKey_KeypadEnter, ImGuiKey_R, ImGuiKey_Tab, ImGuiKey_V, ImGuiKey_W};
+use crate::io::mod_flags::{ImGuiModFlags_Alt, ImGuiModFlags_Ctrl, ImGuiModFlags_Super};
+use crate::rect::ImRect;
+use crate::stb::stb_textedit::{stb_text_begin_undo_group, stb_text_end_undo_group, stb_textedit_cut, stb_textedit_paste};
+use crate::style::style_ops::GetColorU32;
+use crate::text::text_ops::CalcTextSize;
+use crate::widgets::scroll_flags::ImGuiScrollFlags_KeepVisibleCenterY;
+use crate::widgets::scrolling_ops::ScrollToRect;
+use crate::window::input_text_find_flags::{ImGuiInputTextFindFlags, ImGuiInputTextFindFlags_CaseSensitive, ImGuiInputTextFindFlags_None, ImGuiInputTextFindFlags_Regex, ImGuiInputTextFindFlags_WholeWord};
+use crate::window::input_text_find_pattern::{ImGuiTextFindMatch, ImGuiTextFindPattern};
+use crate::window::input_text_multi_cursor::InputTextClearExtraCursors;
+use crate::window::input_text_storage::{InputTextCalcRangeWidth, ImGuiInputTextStorage};
+use crate::window::ImguiWindow;
+
+// Matches past this count are not collected (the bar displays "10000+")
+pub const IMGUI_INPUT_TEXT_FIND_MAX_MATCHES: usize = 10000;
+
+// Labels and flags of the toggle buttons, left to right
+const IMGUI_INPUT_TEXT_FIND_TOGGLES: [(&str, ImGuiInputTextFindFlags); 3] = [
+    ("Aa", ImGuiInputTextFindFlags_CaseSensitive),
+    ("W", ImGuiInputTextFindFlags_WholeWord),
+    (".*", ImGuiInputTextFindFlags_Regex),
+];
+
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiInputTextFindState {
+    pub Open: bool,
+    pub ShowReplace: bool,
+    // replace row visible (never for read-only widgets)
+    pub HasFocus: bool,
+    // keyboard input goes to the bar instead of the text
+    pub FocusReplace: bool,
+    // which field of the bar has focus
+    pub MouseHeld: bool,
+    // a click started on the bar, don't let the drag reach the text
+    pub Query: String,
+    pub Replacement: String,
+    pub Flags: ImGuiInputTextFindFlags,
+    pub Pattern: Option<ImGuiTextFindPattern>,
+    pub Error: Option<String>,
+    // why the query doesn't compile
+    pub Matches: Vec<ImGuiTextFindMatch>,
+    pub MatchesCapped: bool,
+    // stopped at IMGUI_INPUT_TEXT_FIND_MAX_MATCHES or pattern too expensive
+    pub Current: Option<usize>,
+    // index in Matches of the match equal to the selection
+    pub TextVersion: u32,
+    // ImGuiInputTextState::EditCount when Matches was computed
+    pub Dirty: bool,
+    // query or flags changed
+    pub ScrollToCurrent: bool,
+    // bring the selected match into view on next render
+}
+
+// Rectangles of the bar and its parts, in screen space
+#[derive(Default, Debug, Clone, Copy)]
+pub struct ImGuiInputTextFindBarLayout {
+    pub Bar: ImRect,
+    pub Query: ImRect,
+    pub Toggles: [ImRect; 3],
+    pub Count: ImRect,
+    pub Prev: ImRect,
+    pub Next: ImRect,
+    pub Close: ImRect,
+    pub Replacement: ImRect,
+    pub ReplaceOne: ImRect,
+    pub ReplaceAll: ImRect,
+}
+
+pub fn InputTextFindOpen(state: &mut ImGuiInputTextState, show_replace: bool, is_readonly: bool) {
+    // Seed the query with the selection, as long as it fits on one line
+    if state.HasSelection() {
+        let select_min = state.Stb.select_start.min(state.Stb.select_end);
+        let select_max = state.Stb.select_start.max(state.Stb.select_end);
+        if select_max - select_min <= 256 && state.TextW.LineOfPos(select_min) == state.TextW.LineOfPos(select_max) {
+            state.Find.Query.clear();
+            state.TextW.CopyRangeUtf8(select_min, select_max, &mut state.Find.Query);
+        }
+    }
+
+    let find = &mut state.Find;
+    find.Open = true;
+    find.HasFocus = true;
+    find.ShowReplace = show_replace && !is_readonly;
+    find.FocusReplace = false;
+    find.Dirty = true;
+}
+
+pub fn InputTextFindClose(state: &mut ImGuiInputTextState) {
+    let find = &mut state.Find;
+    find.Open = false;
+    find.HasFocus = false;
+    find.MouseHeld = false;
+    find.Matches.clear();
+    find.Current = None;
+}
+
+pub fn InputTextFindUpdate(state: &mut ImGuiInputTextState) {
+    let find = &mut state.Find;
+    if find.Dirty {
+        find.Dirty = false;
+        find.Error = None;
+        find.Pattern = None;
+        if !find.Query.is_empty() {
+            match ImGuiTextFindPattern::Compile(&find.Query, find.Flags) {
+                Ok(pattern) => find.Pattern = Some(pattern),
+                Err(error) => find.Error = Some(error),
+            }
+        }
+        find.TextVersion = state.EditCount.wrapping_sub(1); // Force refreshing the matches
+    }
+
+    if find.TextVersion != state.EditCount {
+        find.TextVersion = state.EditCount;
+        find.Matches.clear();
+        find.MatchesCapped = false;
+        // Matched in place on the edit buffer, no copy of the text
+        if let Some(pattern) = &find.Pattern {
+            let completed = pattern.FindAll(&state.TextW, IMGUI_INPUT_TEXT_FIND_MAX_MATCHES, &mut find.Matches);
+            find.MatchesCapped = !completed || find.Matches.len() >= IMGUI_INPUT_TEXT_FIND_MAX_MATCHES;
+        }
+    }
+
+    let select_min = state.Stb.select_start.min(state.Stb.select_end);
+    let select_max = state.Stb.select_start.max(state.Stb.select_end);
+    find.Current = find.Matches.binary_search_by_key(&select_min, |m| m.Start).ok().filter(|n| find.Matches[*n].End == select_max);
+}
+
+// Selection, or the cursor when there is none (select_start/select_end may be stale then).
+fn InputTextFindSelectionRange(state: &ImGuiInputTextState) -> (usize, usize) {
+    if state.HasSelection() {
+        (state.Stb.select_start.min(state.Stb.select_end), state.Stb.select_start.max(state.Stb.select_end))
+    } else {
+        (state.Stb.cursor, state.Stb.cursor)
+    }
+}
+
+fn InputTextFindSelect(state: &mut ImGuiInputTextState, match_n: usize) {
+    let m = state.Find.Matches[match_n];
+    InputTextClearExtraCursors(state);
+    state.Stb.select_start = m.Start;
+    state.Stb.select_end = m.End;
+    state.Stb.cursor = m.End;
+    state.Stb.has_preferred_x = 0;
+    state.Find.Current = Some(match_n);
+    state.Find.ScrollToCurrent = true;
+    state.CursorAnimReset();
+}
+
+// Select the next (or previous) match after the selection, wrapping around the ends of the text.
+pub fn InputTextFindStep(state: &mut ImGuiInputTextState, forward: bool) {
+    InputTextFindUpdate(state);
+    if state.Find.Matches.is_empty() {
+        return;
+    }
+    let (select_min, select_max) = InputTextFindSelectionRange(state);
+    let matches = &state.Find.Matches;
+    let match_n = if forward {
+        let n = matches.partition_point(|m| m.Start < select_max);
+        if n < matches.len() { n } else { 0 }
+    } else {
+        let n = matches.partition_point(|m| m.Start < select_min);
+        if n > 0 { n - 1 } else { matches.len() - 1 }
+    };
+    InputTextFindSelect(state, match_n);
+}
+
+// Incremental search: select the first match at or after the start of the selection.
+fn InputTextFindOnQueryChanged(state: &mut ImGuiInputTextState) {
+    state.Find.Dirty = true;
+    InputTextFindUpdate(state);
+    if state.Find.Matches.is_empty() {
+        return;
+    }
+    let (select_min, _) = InputTextFindSelectionRange(state);
+    let matches = &state.Find.Matches;
+    let n = matches.partition_point(|m| m.Start < select_min);
+    InputTextFindSelect(state, if n < matches.len() { n } else { 0 });
+}
+
+// Replace text[m.Start..m.End] through stb_textedit so it gets an undo record. Returns false if the buffer is full.
+unsafe fn InputTextFindReplaceRange(state: &mut ImGuiInputTextState, m: ImGuiTextFindMatch, replacement: &[char]) -> bool {
+    state.Stb.select_start = m.Start;
+    state.Stb.select_end = m.End;
+    state.Stb.cursor = m.End;
+    let mut stb = std::mem::take(&mut state.Stb);
+    let ok = if replacement.is_empty() {
+        stb_textedit_cut(state, &mut stb);
+        true
+    } else {
+        stb_textedit_paste(state, &mut stb, replacement, replacement.len())
+    };
+    state.Stb = stb;
+    ok
+}
+
+fn InputTextFindExpand(state: &ImGuiInputTextState, m: ImGuiTextFindMatch) -> Vec<char> {
+    let find = &state.Find;
+    let pattern = find.Pattern.as_ref().unwrap();
+    match pattern.MatchAt(&state.TextW, m.Start) {
+        Some(caps) => pattern.ExpandReplacement(&state.TextW, &caps, &find.Replacement),
+        None => find.Replacement.chars().collect(),
+    }
+}
+
+// Replace the selected match and move on to the next one. If no match is selected, only select the next one (so the user sees what gets replaced).
+pub unsafe fn InputTextFindReplaceOne(state: &mut ImGuiInputTextState) {
+    InputTextFindUpdate(state);
+    let match_n = match state.Find.Current {
+        Some(match_n) => match_n,
+        None => { InputTextFindStep(state, true); return; }
+    };
+    let m = state.Find.Matches[match_n];
+    let replacement = InputTextFindExpand(state, m);
+    InputTextClearExtraCursors(state);
+    stb_text_begin_undo_group(&mut state.Stb.undostate);
+    InputTextFindReplaceRange(state, m, &replacement);
+    stb_text_end_undo_group(&mut state.Stb.undostate);
+    state.CursorFollow = true;
+    InputTextFindStep(state, true);
+}
+
+// Replace every match as a single undo step. Returns the number of replacements.
+pub unsafe fn InputTextFindReplaceAll(state: &mut ImGuiInputTextState) -> usize {
+    InputTextFindUpdate(state);
+    if state.Find.Matches.is_empty() {
+        return 0;
+    }
+    let replacements: Vec<(ImGuiTextFindMatch, Vec<char>)> = state.Find.Matches.iter().map(|m| (*m, InputTextFindExpand(state, *m))).collect();
+
+    // Last to first so the positions of the matches still to replace stay valid
+    InputTextClearExtraCursors(state);
+    let mut count: usize = 0;
+    stb_text_begin_undo_group(&mut state.Stb.undostate);
+    for (m, replacement) in replacements.iter().rev() {
+        if !InputTextFindReplaceRange(state, *m, replacement) {
+            break;
+        }
+        count += 1;
+    }
+    stb_text_end_undo_group(&mut state.Stb.undostate);
+    state.ClearSelection();
+    state.CursorFollow = true;
+    state.CursorAnimReset();
+    count
+}
+
+fn InputTextFindFocusedField(find: &mut ImGuiInputTextFindState) -> &mut String {
+    if find.FocusReplace { &mut find.Replacement } else { &mut find.Query }
+}
+
+pub fn InputTextFindOnChar(state: &mut ImGuiInputTextState, c: char) {
+    if c == '\t' || c == '\n' || c == '\r' {
+        return;
+    }
+    InputTextFindFocusedField(&mut state.Find).push(c);
+    if !state.Find.FocusReplace {
+        InputTextFindOnQueryChanged(state);
+    }
+}
+
+// Keyboard while the bar has focus. Escape closes the bar, Tab switches fields, Enter steps or replaces,
+// Alt+C/Alt+W/Alt+R toggle case sensitivity, whole word and regex.
+pub unsafe fn InputTextFindOnKey(g: &mut AppContext, state: &mut ImGuiInputTextState, is_readonly: bool) {
+    let io = &g.IO;
+    let is_shortcut_key: bool = if io.ConfigMacOSXBehaviors { io.KeyMods == ImGuiModFlags_Super } else { io.KeyMods == ImGuiModFlags_Ctrl };
+    let is_alt_only: bool = io.KeyMods == ImGuiModFlags_Alt;
+    let is_enter_pressed: bool = IsKeyPressed(ImGuiKey_Enter, true) || IsKeyPressed(ImGuiKey_KeypadEnter, true);
+
+    if IsKeyPressed(ImGuiKey_Escape, false) {
+        InputTextFindClose(state);
+    }
+    else if IsKeyPressed(ImGuiKey_Tab, false) {
+        state.Find.FocusReplace = state.Find.ShowReplace && !state.Find.FocusReplace;
+    }
+    else if is_enter_pressed && io.KeyCtrl && io.KeyAlt {
+        if !is_readonly && state.Find.ShowReplace {
+            InputTextFindReplaceAll(state);
+        }
+    }
+    else if is_enter_pressed && state.Find.FocusReplace {
+        if !is_readonly {
+            InputTextFindReplaceOne(state);
+        }
+    }
+    else if is_enter_pressed {
+        InputTextFindStep(state, !io.KeyShift);
+    }
+    else if IsKeyPressed(ImGuiKey_Backspace, true) {
+        InputTextFindFocusedField(&mut state.Find).pop();
+        if !state.Find.FocusReplace {
+            InputTextFindOnQueryChanged(state);
+        }
+    }
+    else if is_shortcut_key && IsKeyPressed(ImGuiKey_V, false) {
+        let clipboard = GetClipboardText();
+        let line = clipboard.split('\n').next().unwrap_or("").trim_end_matches('\r');
+        InputTextFindFocusedField(&mut state.Find).push_str(line);
+        if !state.Find.FocusReplace {
+            InputTextFindOnQueryChanged(state);
+        }
+    }
+    else if is_alt_only && (IsKeyPressed(ImGuiKey_C, false) || IsKeyPressed(ImGuiKey_W, false) || IsKeyPressed(ImGuiKey_R, false)) {
+        let toggle = if IsKeyPressed(ImGuiKey_C, false) { ImGuiInputTextFindFlags_CaseSensitive } else if IsKeyPressed(ImGuiKey_W, false) { ImGuiInputTextFindFlags_WholeWord } else { ImGuiInputTextFindFlags_Regex };
+        state.Find.Flags ^= toggle;
+        InputTextFindOnQueryChanged(state);
+    }
+}
+
+// 'frame_min'/'frame_size': visible area of the widget, scrollbar excluded.
+pub fn InputTextFindCalcBarLayout(g: &mut AppContext, frame_min: Vector2, frame_size: Vector2, show_replace: bool) -> ImGuiInputTextFindBarLayout {
+    let pad = g.style.FramePadding;
+    let spacing = g.style.ItemInnerSpacing.x;
+    let row_height = g.FontSize + pad.y * 2.0;
+    let button_width = |g: &mut AppContext, label: &str| CalcTextSize(g, &String::from(label), false, 0.0).x + pad.x * 2.0;
+
+    let bar_width = (g.FontSize * 26.0).min(frame_size.x - spacing * 2.0).max(0.0);
+    let bar_height = row_height * if show_replace { 2.0 } else { 1.0 } + spacing * if show_replace { 3.0 } else { 2.0 };
+    let bar_max_x = frame_min.x + frame_size.x - spacing;
+    let bar_min = Vector2::from_floats(bar_max_x - bar_width, frame_min.y + spacing);
+
+    let mut layout = ImGuiInputTextFindBarLayout::default();
+    layout.Bar = ImRect::from_floats(bar_min.x, bar_min.y, bar_max_x, bar_min.y + bar_height);
+
+    // First row, laid out from the right: [query][Aa][W][.*] 12 of 34 [<][>][x]
+    let row_y = bar_min.y + spacing;
+    let mut x = bar_max_x - spacing;
+    let mut take = |width: c_float, y: c_float| {
+        let r = ImRect::from_floats(x - width, y, x, y + row_height);
+        x -= width;
+        r
+    };
+    let w_arrow = button_width(g, ">");
+    let w_count = CalcTextSize(g, &String::from("10000+ of 10000+"), false, 0.0).x + pad.x;
+    layout.Close = take(w_arrow, row_y);
+    layout.Next = take(w_arrow, row_y);
+    layout.Prev = take(w_arrow, row_y);
+    layout.Count = take(w_count, row_y);
+    for toggle_n in (0..IMGUI_INPUT_TEXT_FIND_TOGGLES.len()).rev() {
+        let w = button_width(g, IMGUI_INPUT_TEXT_FIND_TOGGLES[toggle_n].0);
+        layout.Toggles[toggle_n] = take(w, row_y);
+    }
+    let fields_max_x = x - spacing;
+    layout.Query = ImRect::from_floats(bar_min.x + spacing, row_y, fields_max_x.max(bar_min.x + spacing), row_y + row_height);
+
+    // Second row: [replacement][Replace][All]
+    if show_replace {
+        let row_y = row_y + row_height + spacing;
+        let w_all = button_width(g, "All");
+        let w_one = button_width(g, "Replace");
+        layout.ReplaceAll = ImRect::from_floats(bar_max_x - spacing - w_all, row_y, bar_max_x - spacing, row_y + row_height);
+        layout.ReplaceOne = ImRect::from_floats(layout.ReplaceAll.min.x - w_one, row_y, layout.ReplaceAll.min.x, row_y + row_height);
+        layout.Replacement = ImRect::from_floats(bar_min.x + spacing, row_y, layout.Query.max.x, row_y + row_height);
+    }
+    layout
+}
+
+// Mouse click on the bar. The bar always swallows the click, even between its buttons.
+pub unsafe fn InputTextFindOnClick(state: &mut ImGuiInputTextState, layout: &ImGuiInputTextFindBarLayout, mouse_pos: &Vector2, is_readonly: bool) {
+    let mut layout = *layout;
+    state.Find.HasFocus = true;
+    if layout.Query.Contains(mouse_pos) {
+        state.Find.FocusReplace = false;
+    } else if state.Find.ShowReplace && layout.Replacement.Contains(mouse_pos) {
+        state.Find.FocusReplace = true;
+    } else if layout.Prev.Contains(mouse_pos) {
+        InputTextFindStep(state, false);
+    } else if layout.Next.Contains(mouse_pos) {
+        InputTextFindStep(state, true);
+    } else if layout.Close.Contains(mouse_pos) {
+        InputTextFindClose(state);
+    } else if state.Find.ShowReplace && !is_readonly && layout.ReplaceOne.Contains(mouse_pos) {
+        InputTextFindReplaceOne(state);
+    } else if state.Find.ShowReplace && !is_readonly && layout.ReplaceAll.Contains(mouse_pos) {
+        InputTextFindReplaceAll(state);
+    } else {
+        for toggle_n in 0..IMGUI_INPUT_TEXT_FIND_TOGGLES.len() {
+            if layout.Toggles[toggle_n].Contains(mouse_pos) {
+                state.Find.Flags ^= IMGUI_INPUT_TEXT_FIND_TOGGLES[toggle_n].1;
+                InputTextFindOnQueryChanged(state);
+            }
+        }
+    }
+}
+
+// Bring the selected match into view. 'text_origin' is the screen position of the first character (scrolling applied).
+pub unsafe fn InputTextFindScrollToCurrent(state: &mut ImGuiInputTextState, window: &mut ImguiWindow, text_origin: Vector2, visible_width: c_float, font_size: c_float, char_advance: &dyn Fn(char) -> c_float) {
+    state.Find.ScrollToCurrent = false;
+    let m = match state.Find.Current {
+        Some(match_n) => state.Find.Matches[match_n],
+        None => return,
+    };
+    let line = state.TextW.LineOfPos(m.Start);
+    let line_start = state.TextW.LineStart(line);
+    let x0 = InputTextCalcRangeWidth(&state.TextW, line_start, m.Start, char_advance);
+    let x1 = if state.TextW.LineOfPos(m.End) == line { x0 + InputTextCalcRangeWidth(&state.TextW, m.Start, m.End, char_advance) } else { x0 };
+
+    // Horizontal scrolling is our own (ScrollX), vertical scrolling is the child window's
+    if x0 < state.ScrollX || x1 > state.ScrollX + visible_width {
+        state.ScrollX = (x0 - visible_width * 0.25).max(0.0).floor();
+    }
+    let y = text_origin.y + line as c_float * font_size;
+    let mut rect = ImRect::from_floats(text_origin.x + x0 - state.ScrollX, y, text_origin.x + x1 - state.ScrollX, y + font_size);
+    ScrollToRect(window, &mut rect, ImGuiScrollFlags_KeepVisibleCenterY);
+}
+
+// Highlight the matches overlapping 'clip_rect'. 'text_origin' is the screen position of the first character (scrolling applied).
+pub fn InputTextFindRenderMatches(draw_list: &mut ImDrawList, state: &mut ImGuiInputTextState, text_origin: Vector2, clip_rect: &ImVec4, font_size: c_float, char_advance: &dyn Fn(char) -> c_float) {
+    if state.Find.Matches.is_empty() || state.TextW.LineCount() == 0 {
+        return;
+    }
+    let line_count = state.TextW.LineCount();
+    let first_line = (((clip_rect.y - text_origin.y) / font_size).max(0.0) as usize).min(line_count - 1);
+    let last_line = (((clip_rect.w - text_origin.y) / font_size).max(0.0) as usize).min(line_count - 1);
+    let visible_begin = state.TextW.LineStart(first_line);
+    let visible_end = state.TextW.LineEnd(last_line);
+
+    let col = GetColorU32(ImGuiCol_TextSelectedBg, 0.45);
+    let matches = &state.Find.Matches;
+    let mut match_n = matches.partition_point(|m| m.End <= visible_begin);
+    while match_n < matches.len() && matches[match_n].Start <= visible_end {
+        let m = matches[match_n];
+        match_n += 1;
+        let mut line = state.TextW.LineOfPos(m.Start).max(first_line);
+        let line_last = state.TextW.LineOfPos(m.End).min(last_line);
+        while line <= line_last {
+            let line_start = state.TextW.LineStart(line);
+            let p = m.Start.max(line_start);
+            let p_end = m.End.min(state.TextW.LineEnd(line));
+            let x0 = InputTextCalcRangeWidth(&state.TextW, line_start, p, char_advance);
+            let x1 = x0 + InputTextCalcRangeWidth(&state.TextW, p, p_end, char_advance).max(char_advance(' ') * 0.5);
+            let y = text_origin.y + line as c_float * font_size;
+            draw_list.AddRectFilled(&Vector2::from_floats(text_origin.x + x0, y), &Vector2::from_floats(text_origin.x + x1, y + font_size), col, 0.0, ImDrawFlags_None);
+            line += 1;
+        }
+    }
+}
+
+fn InputTextFindRenderButton(g: &mut AppContext, draw_list: &mut ImDrawList, mut rect: ImRect, label: &str, toggled: bool, enabled: bool) {
+    let hovered = enabled && rect.Contains(&g.IO.MousePos);
+    if toggled || hovered {
+        let col = GetColorU32(if toggled { ImGuiCol_ButtonActive } else if g.IO.MouseDown[0] { ImGuiCol_Button } else { ImGuiCol_ButtonHovered }, 1.0);
+        draw_list.AddRectFilled(&rect.min, &rect.max, col, g.style.FrameRounding, ImDrawFlags_None);
+    }
+    let label = String::from(label);
+    let label_size = CalcTextSize(g, &label, false, 0.0);
+    let pos = Vector2::from_floats(((rect.min.x + rect.max.x - label_size.x) * 0.5).floor(), ((rect.min.y + rect.max.y - label_size.y) * 0.5).floor());
+    draw_list.AddText(pos, GetColorU32(if enabled { ImGuiCol_Text } else { ImGuiCol_TextDisabled }, 1.0), label);
+}
+
+// Text field of the bar: frame, text scrolled to keep its end visible, hint when empty and caret when focused.
+fn InputTextFindRenderField(g: &mut AppContext, draw_list: &mut ImDrawList, rect: ImRect, text: &str, hint: &str, focused: bool, error: bool) {
+    let pad = g.style.FramePadding;
+    draw_list.AddRectFilled(&rect.min, &rect.max, GetColorU32(ImGuiCol_FrameBg, 1.0), g.style.FrameRounding, ImDrawFlags_None);
+    if focused || error {
+        draw_list.AddRect(rect.min, rect.max, GetColorU32(if error { ImGuiCol_TextDisabled } else { ImGuiCol_NavHighlight }, 1.0), g.style.FrameRounding);
+    }
+    let text = String::from(text);
+    let text_width = CalcTextSize(g, &text, false, 0.0).x;
+    let visible_width = rect.max.x - rect.min.x - pad.x * 2.0;
+    let offset_x = (text_width - visible_width).max(0.0);
+    let text_pos = Vector2::from_floats(rect.min.x + pad.x - offset_x, rect.min.y + pad.y);
+
+    draw_list.PushClipRect(&rect.min, &rect.max, true);
+    if text.is_empty() {
+        draw_list.AddText(text_pos, GetColorU32(ImGuiCol_TextDisabled, 1.0), String::from(hint));
+    } else {
+        draw_list.AddText(text_pos, GetColorU32(ImGuiCol_Text, 1.0), text);
+    }
+    if focused {
+        let caret_x = (text_pos.x + text_width).floor();
+        draw_list.AddLine(Vector2::from_floats(caret_x, rect.min.y + pad.y), Vector2::from_floats(caret_x, rect.max.y - pad.y), GetColorU32(ImGuiCol_Text, 1.0));
+    }
+    draw_list.PopClipRect();
+}
+
+pub fn InputTextFindRenderBar(g: &mut AppContext, draw_list: &mut ImDrawList, state: &ImGuiInputTextState, layout: &ImGuiInputTextFindBarLayout, is_readonly: bool) {
+    let find = &state.Find;
+    draw_list.AddRectFilled(&layout.Bar.min, &layout.Bar.max, GetColorU32(ImGuiCol_PopupBg, 1.0), g.style.FrameRounding, ImDrawFlags_None);
+    draw_list.AddRect(layout.Bar.min, layout.Bar.max, GetColorU32(ImGuiCol_Border, 1.0), g.style.FrameRounding);
+
+    InputTextFindRenderField(g, draw_list, layout.Query, &find.Query, "Find", find.HasFocus && !find.FocusReplace, find.Error.is_some());
+    for (toggle_n, (label, flag)) in IMGUI_INPUT_TEXT_FIND_TOGGLES.iter().enumerate() {
+        InputTextFindRenderButton(g, draw_list, layout.Toggles[toggle_n], label, (find.Flags & flag) != ImGuiInputTextFindFlags_None, true);
+    }
+
+    // "3 of 12", "No results", or the reason the regular expression is invalid
+    let capped = if find.MatchesCapped { "+" } else { "" };
+    let count_text = if let Some(error) = &find.Error {
+        error.clone()
+    } else if find.Query.is_empty() {
+        String::new()
+    } else if find.Matches.is_empty() {
+        String::from("No results")
+    } else if let Some(match_n) = find.Current {
+        format!("{} of {}{}", match_n + 1, find.Matches.len(), capped)
+    } else {
+        format!("{}{} matches", find.Matches.len(), capped)
+    };
+    let count_pos = Vector2::from_floats(layout.Count.min.x + g.style.FramePadding.x * 0.5, layout.Count.min.y + g.style.FramePadding.y);
+    draw_list.PushClipRect(&layout.Count.min, &layout.Count.max, true);
+    draw_list.AddText(count_pos, GetColorU32(if find.Matches.is_empty() { ImGuiCol_TextDisabled } else { ImGuiCol_Text }, 1.0), count_text);
+    draw_list.PopClipRect();
+
+    let has_matches = !find.Matches.is_empty();
+    InputTextFindRenderButton(g, draw_list, layout.Prev, "<", false, has_matches);
+    InputTextFindRenderButton(g, draw_list, layout.Next, ">", false, has_matches);
+    InputTextFindRenderButton(g, draw_list, layout.Close, "x", false, true);
+
+    if find.ShowReplace {
+        InputTextFindRenderField(g, draw_list, layout.Replacement, &find.Replacement, "Replace", find.HasFocus && find.FocusReplace, false);
+        InputTextFindRenderButton(g, draw_list, layout.ReplaceOne, "Replace", false, has_matches && !is_readonly);
+        InputTextFindRenderButton(g, draw_list, layout.ReplaceAll, "All", false, has_matches && !is_readonly);
+    }
+}
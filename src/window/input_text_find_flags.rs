@@ -0,0 +1,11 @@
+#![allow(non_upper_case_globals)]
+
+use libc::c_int;
+
+// Flags: for the InputTextMultiline() find bar (ImGuiInputTextFlags_FindReplace), toggled by the buttons of the bar or Alt+C/Alt+W/Alt+R
+pub type ImGuiInputTextFindFlags = c_int;
+
+pub const ImGuiInputTextFindFlags_None: ImGuiInputTextFindFlags = 0;
+pub const ImGuiInputTextFindFlags_CaseSensitive: ImGuiInputTextFindFlags = 1 << 0; // Match letter case exactly
+pub const ImGuiInputTextFindFlags_WholeWord: ImGuiInputTextFindFlags = 1 << 1; // Only match at word boundaries on both ends
+pub const ImGuiInputTextFindFlags_Regex: ImGuiInputTextFindFlags = 1 << 2; // Query is a regular expression, replacement may use $0..$9
@@ -0,0 +1,627 @@
+#![allow(non_snake_case)]
+
+// [SECTION] Search patterns for the InputTextMultiline() find bar (ImGuiInputTextFlags_FindReplace)
+// - ImGuiTextFindPattern::Compile()          plain text or regular expression query -> pattern -> program
+// - ImGuiTextFindPattern::MatchAt()          anchored match at a position, with capture groups
+// - ImGuiTextFindPattern::FindAll()          all non-overlapping, non-empty matches
+// - ImGuiTextFindPattern::ExpandReplacement() replacement text with $0..$9 substituted
+// Small backtracking matcher working directly on the edit buffer, so we don't need to pull in a regex dependency.
+// The pattern is compiled to a program run with an explicit backtrack stack: matching never recurses, however long the text.
+// Supported syntax: literals, '.', [classes] and [^negated], \d \w \s \D \W \S \b \B \n \t \r, ^ $ (per line), (groups), (?:groups),
+// alternation '|', and the * + ? {m} {m,} {m,n} quantifiers with a lazy '?' suffix.
+
+use crate::window::input_text_find_flags::{ImGuiInputTextFindFlags, ImGuiInputTextFindFlags_CaseSensitive, ImGuiInputTextFindFlags_Regex, ImGuiInputTextFindFlags_WholeWord};
+use crate::window::input_text_storage::ImGuiInputTextStorage;
+
+// Matching gives up after this many steps, so a pathological pattern can't freeze the UI.
+pub const IMGUI_TEXT_FIND_MAX_STEPS: usize = 4 * 1024 * 1024;
+pub const IMGUI_TEXT_FIND_MAX_CAPTURES: usize = 9;
+// Counted repeats of groups are unrolled: past this many instructions the pattern is rejected as too complex.
+pub const IMGUI_TEXT_FIND_MAX_PROGRAM: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImGuiTextFindClassItem {
+    Range(char, char),
+    Digit(bool),        // \d, or \D when true
+    Word(bool),         // \w, or \W when true
+    Space(bool),        // \s, or \S when true
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImGuiTextFindNode {
+    Char(char),
+    Any,                                            // '.': anything but a new line
+    Class(Vec<ImGuiTextFindClassItem>, bool),       // items, negated
+    LineStart,
+    LineEnd,
+    WordBoundary(bool),                             // \b, or \B when true
+    Group(Vec<Vec<ImGuiTextFindNode>>, Option<usize>), // alternatives, capture index
+    Repeat(Box<ImGuiTextFindNode>, usize, usize, bool), // node, min, max, greedy
+}
+
+impl ImGuiTextFindNode {
+    // Nodes consuming exactly one character can be repeated without recursing.
+    fn IsSingleChar(&self) -> bool {
+        match self {
+            ImGuiTextFindNode::Char(_) | ImGuiTextFindNode::Any | ImGuiTextFindNode::Class(_, _) => true,
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImGuiTextFindInst {
+    Char(ImGuiTextFindNode),                            // single-character node, see IsSingleChar()
+    RepeatChar(ImGuiTextFindNode, usize, usize, bool),  // single-character node, min, max, greedy
+    Assert(ImGuiTextFindNode),                          // LineStart, LineEnd or WordBoundary
+    Split(usize, usize),                                // continue at the first, backtrack to the second
+    Jmp(usize),
+    Save(usize),                                        // capture slot: start of group n in 2*n, end in 2*n+1
+    LoopStart(usize),                                   // store the position in a loop register
+    LoopCheck(usize),                                   // fail if the iteration since LoopStart matched nothing
+    Match,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct ImGuiTextFindMatch {
+    pub Start: usize,
+    pub End: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImGuiTextFindPattern {
+    pub Alternatives: Vec<Vec<ImGuiTextFindNode>>,
+    pub Program: Vec<ImGuiTextFindInst>,
+    pub LoopCount: usize,
+    // registers used by LoopStart/LoopCheck
+    pub CaptureCount: usize,
+    pub CaseSensitive: bool,
+    pub WholeWord: bool,
+    pub IsRegex: bool,
+}
+
+pub fn TextFindIsWordChar(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn TextFindFoldCase(c: char) -> char {
+    if c.is_ascii() { c.to_ascii_lowercase() } else { c.to_lowercase().next().unwrap_or(c) }
+}
+
+impl ImGuiTextFindClassItem {
+    fn Matches(&self, c: char) -> bool {
+        match *self {
+            ImGuiTextFindClassItem::Range(a, b) => a <= c && c <= b,
+            ImGuiTextFindClassItem::Digit(neg) => c.is_ascii_digit() != neg,
+            ImGuiTextFindClassItem::Word(neg) => TextFindIsWordChar(c) != neg,
+            ImGuiTextFindClassItem::Space(neg) => c.is_whitespace() != neg,
+        }
+    }
+}
+
+// Parser
+struct ImGuiTextFindParser<'a> {
+    Src: &'a [char],
+    Pos: usize,
+    CaptureCount: usize,
+}
+
+impl<'a> ImGuiTextFindParser<'a> {
+    fn Peek(&self) -> Option<char> {
+        self.Src.get(self.Pos).copied()
+    }
+
+    fn ParseAlternatives(&mut self) -> Result<Vec<Vec<ImGuiTextFindNode>>, String> {
+        let mut alternatives = vec![self.ParseSequence()?];
+        while self.Peek() == Some('|') {
+            self.Pos += 1;
+            alternatives.push(self.ParseSequence()?);
+        }
+        Ok(alternatives)
+    }
+
+    fn ParseSequence(&mut self) -> Result<Vec<ImGuiTextFindNode>, String> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.Peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.ParseAtom()?;
+            nodes.push(self.ParseQuantifier(atom)?);
+        }
+        Ok(nodes)
+    }
+
+    fn ParseQuantifier(&mut self, atom: ImGuiTextFindNode) -> Result<ImGuiTextFindNode, String> {
+        let (min, max) = match self.Peek() {
+            Some('*') => { self.Pos += 1; (0, usize::MAX) }
+            Some('+') => { self.Pos += 1; (1, usize::MAX) }
+            Some('?') => { self.Pos += 1; (0, 1) }
+            Some('{') => match self.ParseBraces() {
+                Some(range) => range,
+                None => return Ok(atom), // Not a valid {m,n}: the brace is a literal, handled by the next ParseAtom()
+            },
+            _ => return Ok(atom),
+        };
+        if min > max {
+            return Err(String::from("Invalid repeat range"));
+        }
+        let greedy = if self.Peek() == Some('?') { self.Pos += 1; false } else { true };
+        if let Some('*') | Some('+') | Some('?') = self.Peek() {
+            return Err(String::from("Nothing to repeat"));
+        }
+        Ok(ImGuiTextFindNode::Repeat(Box::new(atom), min, max, greedy))
+    }
+
+    fn ParseNumber(&mut self) -> Option<usize> {
+        let start = self.Pos;
+        let mut value: usize = 0;
+        while let Some(d) = self.Peek().and_then(|c| c.to_digit(10)) {
+            value = value.saturating_mul(10).saturating_add(d as usize);
+            self.Pos += 1;
+        }
+        if self.Pos > start { Some(value) } else { None }
+    }
+
+    fn ParseBraces(&mut self) -> Option<(usize, usize)> {
+        let backup = self.Pos;
+        self.Pos += 1;
+        let result = (|| {
+            let min = self.ParseNumber()?;
+            let max = if self.Peek() == Some(',') {
+                self.Pos += 1;
+                self.ParseNumber().unwrap_or(usize::MAX)
+            } else {
+                min
+            };
+            if self.Peek() != Some('}') {
+                return None;
+            }
+            self.Pos += 1;
+            Some((min, max))
+        })();
+        if result.is_none() {
+            self.Pos = backup;
+        }
+        result
+    }
+
+    fn ParseAtom(&mut self) -> Result<ImGuiTextFindNode, String> {
+        let c = self.Src[self.Pos];
+        self.Pos += 1;
+        match c {
+            '.' => Ok(ImGuiTextFindNode::Any),
+            '^' => Ok(ImGuiTextFindNode::LineStart),
+            '$' => Ok(ImGuiTextFindNode::LineEnd),
+            '*' | '+' | '?' => Err(String::from("Nothing to repeat")),
+            '(' => {
+                let capture = if self.Src[self.Pos..].starts_with(&['?', ':']) {
+                    self.Pos += 2;
+                    None
+                } else {
+                    self.CaptureCount += 1;
+                    Some(self.CaptureCount)
+                };
+                let alternatives = self.ParseAlternatives()?;
+                if self.Peek() != Some(')') {
+                    return Err(String::from("Missing ')'"));
+                }
+                self.Pos += 1;
+                Ok(ImGuiTextFindNode::Group(alternatives, capture))
+            }
+            '[' => self.ParseClass(),
+            '\\' => self.ParseEscape(false),
+            _ => Ok(ImGuiTextFindNode::Char(c)),
+        }
+    }
+
+    // Escape sequence after '\'. Inside a class \b means backspace, as usual.
+    fn ParseEscape(&mut self, in_class: bool) -> Result<ImGuiTextFindNode, String> {
+        let c = self.Peek().ok_or_else(|| String::from("Trailing '\\'"))?;
+        self.Pos += 1;
+        let class = |item| ImGuiTextFindNode::Class(vec![item], false);
+        Ok(match c {
+            'd' => class(ImGuiTextFindClassItem::Digit(false)),
+            'D' => class(ImGuiTextFindClassItem::Digit(true)),
+            'w' => class(ImGuiTextFindClassItem::Word(false)),
+            'W' => class(ImGuiTextFindClassItem::Word(true)),
+            's' => class(ImGuiTextFindClassItem::Space(false)),
+            'S' => class(ImGuiTextFindClassItem::Space(true)),
+            'b' if !in_class => ImGuiTextFindNode::WordBoundary(false),
+            'B' if !in_class => ImGuiTextFindNode::WordBoundary(true),
+            'b' => ImGuiTextFindNode::Char('\x08'),
+            'n' => ImGuiTextFindNode::Char('\n'),
+            't' => ImGuiTextFindNode::Char('\t'),
+            'r' => ImGuiTextFindNode::Char('\r'),
+            _ if c.is_alphanumeric() => return Err(format!("Unknown escape '\\{}'", c)),
+            _ => ImGuiTextFindNode::Char(c),
+        })
+    }
+
+    fn ParseClass(&mut self) -> Result<ImGuiTextFindNode, String> {
+        let negated = if self.Peek() == Some('^') { self.Pos += 1; true } else { false };
+        let mut items: Vec<ImGuiTextFindClassItem> = Vec::new();
+        let mut first = true;
+        loop {
+            let c = self.Peek().ok_or_else(|| String::from("Missing ']'"))?;
+            if c == ']' && !first {
+                self.Pos += 1;
+                break;
+            }
+            first = false;
+            self.Pos += 1;
+            let lo = if c == '\\' {
+                match self.ParseEscape(true)? {
+                    ImGuiTextFindNode::Char(e) => e,
+                    ImGuiTextFindNode::Class(mut shorthand, _) => { items.append(&mut shorthand); continue; }
+                    _ => unreachable!(),
+                }
+            } else {
+                c
+            };
+            // Range, unless the '-' is the last character of the class
+            if self.Peek() == Some('-') && self.Src.get(self.Pos + 1).map_or(false, |n| *n != ']') {
+                self.Pos += 1;
+                let mut hi = self.Src[self.Pos];
+                self.Pos += 1;
+                if hi == '\\' {
+                    hi = match self.ParseEscape(true)? {
+                        ImGuiTextFindNode::Char(e) => e,
+                        _ => return Err(String::from("Invalid class range")),
+                    };
+                }
+                if hi < lo {
+                    return Err(String::from("Invalid class range"));
+                }
+                items.push(ImGuiTextFindClassItem::Range(lo, hi));
+            } else {
+                items.push(ImGuiTextFindClassItem::Range(lo, lo));
+            }
+        }
+        Ok(ImGuiTextFindNode::Class(items, negated))
+    }
+}
+
+// Compiler
+struct ImGuiTextFindCompiler {
+    Program: Vec<ImGuiTextFindInst>,
+    LoopCount: usize,
+}
+
+impl ImGuiTextFindCompiler {
+    fn Emit(&mut self, inst: ImGuiTextFindInst) -> Result<usize, String> {
+        if self.Program.len() >= IMGUI_TEXT_FIND_MAX_PROGRAM {
+            return Err(String::from("Pattern too complex"));
+        }
+        self.Program.push(inst);
+        Ok(self.Program.len() - 1)
+    }
+
+    // Split into the next alternative before each alternative but the last, jump to the end after each.
+    fn CompileAlternatives(&mut self, alternatives: &[Vec<ImGuiTextFindNode>]) -> Result<(), String> {
+        let mut jumps: Vec<usize> = Vec::new();
+        for (alternative_n, alternative) in alternatives.iter().enumerate() {
+            let split = if alternative_n + 1 < alternatives.len() { Some(self.Emit(ImGuiTextFindInst::Split(0, 0))?) } else { None };
+            for node in alternative {
+                self.CompileNode(node)?;
+            }
+            if let Some(split) = split {
+                jumps.push(self.Emit(ImGuiTextFindInst::Jmp(0))?);
+                self.Program[split] = ImGuiTextFindInst::Split(split + 1, self.Program.len());
+            }
+        }
+        let end = self.Program.len();
+        for jump in jumps {
+            self.Program[jump] = ImGuiTextFindInst::Jmp(end);
+        }
+        Ok(())
+    }
+
+    fn CompileNode(&mut self, node: &ImGuiTextFindNode) -> Result<(), String> {
+        match node {
+            ImGuiTextFindNode::Char(_) | ImGuiTextFindNode::Any | ImGuiTextFindNode::Class(_, _) => {
+                self.Emit(ImGuiTextFindInst::Char(node.clone()))?;
+            }
+            ImGuiTextFindNode::LineStart | ImGuiTextFindNode::LineEnd | ImGuiTextFindNode::WordBoundary(_) => {
+                self.Emit(ImGuiTextFindInst::Assert(node.clone()))?;
+            }
+            ImGuiTextFindNode::Group(alternatives, capture) => {
+                if let Some(n) = *capture {
+                    self.Emit(ImGuiTextFindInst::Save(n * 2))?;
+                }
+                self.CompileAlternatives(alternatives)?;
+                if let Some(n) = *capture {
+                    self.Emit(ImGuiTextFindInst::Save(n * 2 + 1))?;
+                }
+            }
+            ImGuiTextFindNode::Repeat(inner, min, max, greedy) => {
+                if inner.IsSingleChar() {
+                    self.Emit(ImGuiTextFindInst::RepeatChar((**inner).clone(), *min, *max, *greedy))?;
+                    return Ok(());
+                }
+                for _ in 0..*min {
+                    self.CompileNode(inner)?;
+                }
+                let split_to = |split: usize, exit: usize| if *greedy { ImGuiTextFindInst::Split(split + 1, exit) } else { ImGuiTextFindInst::Split(exit, split + 1) };
+                if *max == usize::MAX {
+                    // An iteration matching nothing can't make progress: stop repeating
+                    let register = self.LoopCount;
+                    self.LoopCount += 1;
+                    let split = self.Emit(ImGuiTextFindInst::Split(0, 0))?;
+                    self.Emit(ImGuiTextFindInst::LoopStart(register))?;
+                    self.CompileNode(inner)?;
+                    self.Emit(ImGuiTextFindInst::LoopCheck(register))?;
+                    self.Emit(ImGuiTextFindInst::Jmp(split))?;
+                    self.Program[split] = split_to(split, self.Program.len());
+                } else {
+                    let mut splits: Vec<usize> = Vec::new();
+                    for _ in *min..*max {
+                        splits.push(self.Emit(ImGuiTextFindInst::Split(0, 0))?);
+                        self.CompileNode(inner)?;
+                    }
+                    let exit = self.Program.len();
+                    for split in splits {
+                        self.Program[split] = split_to(split, exit);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Matcher
+struct ImGuiTextFindMatcher<'a> {
+    Pattern: &'a ImGuiTextFindPattern,
+    Text: &'a dyn ImGuiInputTextStorage,
+    Steps: usize,
+}
+
+type ImGuiTextFindCaptures = Vec<Option<ImGuiTextFindMatch>>;
+
+// What to undo or retry when the current path fails
+enum ImGuiTextFindBacktrack {
+    Resume(usize, usize),                               // pc, pos
+    RestoreSlot(usize, Option<usize>),
+    RestoreRegister(usize, usize),
+    RepeatChar(usize, usize, usize, usize, bool),       // pc after the repeat, start pos, count to try next, last count to try, greedy
+}
+
+impl<'a> ImGuiTextFindMatcher<'a> {
+    fn MatchChar(&self, node: &ImGuiTextFindNode, pos: usize) -> bool {
+        if pos >= self.Text.Len() {
+            return false;
+        }
+        let c = self.Text.GetChar(pos);
+        match node {
+            ImGuiTextFindNode::Char(p) => {
+                *p == c || (!self.Pattern.CaseSensitive && TextFindFoldCase(*p) == TextFindFoldCase(c))
+            }
+            ImGuiTextFindNode::Any => c != '\n',
+            ImGuiTextFindNode::Class(items, negated) => {
+                let mut found = items.iter().any(|item| item.Matches(c));
+                if !found && !self.Pattern.CaseSensitive {
+                    let lower = TextFindFoldCase(c);
+                    let upper = c.to_uppercase().next().unwrap_or(c);
+                    found = items.iter().any(|item| item.Matches(lower) || item.Matches(upper));
+                }
+                found != *negated
+            }
+            _ => false,
+        }
+    }
+
+    fn IsWordBoundary(&self, pos: usize) -> bool {
+        let before = pos > 0 && TextFindIsWordChar(self.Text.GetChar(pos - 1));
+        let after = pos < self.Text.Len() && TextFindIsWordChar(self.Text.GetChar(pos));
+        before != after
+    }
+
+    fn MatchAssert(&self, node: &ImGuiTextFindNode, pos: usize) -> bool {
+        match node {
+            ImGuiTextFindNode::LineStart => pos == 0 || self.Text.GetChar(pos - 1) == '\n',
+            ImGuiTextFindNode::LineEnd => pos == self.Text.Len() || self.Text.GetChar(pos) == '\n',
+            ImGuiTextFindNode::WordBoundary(negated) => self.IsWordBoundary(pos) != *negated,
+            _ => false,
+        }
+    }
+
+    // Run the program anchored at 'start'. Returns the capture slots of the first accepted match.
+    fn Run(&mut self, start: usize) -> Option<Vec<Option<usize>>> {
+        let pattern: &ImGuiTextFindPattern = self.Pattern;
+        let mut slots: Vec<Option<usize>> = vec![None; (pattern.CaptureCount + 1) * 2];
+        let mut registers: Vec<usize> = vec![0; pattern.LoopCount];
+        let mut stack: Vec<ImGuiTextFindBacktrack> = Vec::new();
+        let (mut pc, mut pos) = (0, start);
+        loop {
+            self.Steps += 1;
+            if self.Steps > IMGUI_TEXT_FIND_MAX_STEPS {
+                return None;
+            }
+            let ok = match &pattern.Program[pc] {
+                ImGuiTextFindInst::Char(node) => {
+                    let ok = self.MatchChar(node, pos);
+                    pos += 1;
+                    ok
+                }
+                ImGuiTextFindInst::RepeatChar(node, min, max, greedy) => {
+                    // Count the run once, then backtrack over it one count at a time
+                    let mut count = 0;
+                    while count < *max && self.MatchChar(node, pos + count) {
+                        count += 1;
+                    }
+                    let ok = count >= *min;
+                    if ok {
+                        let (first, next, last) = if *greedy { (count, count.wrapping_sub(1), *min) } else { (*min, *min + 1, count) };
+                        if first != last {
+                            stack.push(ImGuiTextFindBacktrack::RepeatChar(pc + 1, pos, next, last, *greedy));
+                        }
+                        pos += first;
+                    }
+                    ok
+                }
+                ImGuiTextFindInst::Assert(node) => self.MatchAssert(node, pos),
+                ImGuiTextFindInst::Split(first, second) => {
+                    stack.push(ImGuiTextFindBacktrack::Resume(*second, pos));
+                    pc = *first;
+                    continue;
+                }
+                ImGuiTextFindInst::Jmp(target) => {
+                    pc = *target;
+                    continue;
+                }
+                ImGuiTextFindInst::Save(slot) => {
+                    stack.push(ImGuiTextFindBacktrack::RestoreSlot(*slot, slots[*slot]));
+                    slots[*slot] = Some(pos);
+                    true
+                }
+                ImGuiTextFindInst::LoopStart(register) => {
+                    stack.push(ImGuiTextFindBacktrack::RestoreRegister(*register, registers[*register]));
+                    registers[*register] = pos;
+                    true
+                }
+                ImGuiTextFindInst::LoopCheck(register) => pos != registers[*register],
+                ImGuiTextFindInst::Match => {
+                    // Empty matches can't be highlighted or stepped through
+                    let accepted = pos != start && (!pattern.WholeWord || (self.IsWordBoundary(start) && self.IsWordBoundary(pos)));
+                    if accepted {
+                        slots[0] = Some(start);
+                        slots[1] = Some(pos);
+                        return Some(slots);
+                    }
+                    false
+                }
+            };
+            if ok {
+                pc += 1;
+                continue;
+            }
+
+            // Backtrack: undo slot and register writes down to the most recent alternative
+            loop {
+                match stack.pop()? {
+                    ImGuiTextFindBacktrack::Resume(resume_pc, resume_pos) => {
+                        pc = resume_pc;
+                        pos = resume_pos;
+                        break;
+                    }
+                    ImGuiTextFindBacktrack::RestoreSlot(slot, value) => slots[slot] = value,
+                    ImGuiTextFindBacktrack::RestoreRegister(register, value) => registers[register] = value,
+                    ImGuiTextFindBacktrack::RepeatChar(resume_pc, run_start, count, last, greedy) => {
+                        if count != last {
+                            let next = if greedy { count - 1 } else { count + 1 };
+                            stack.push(ImGuiTextFindBacktrack::RepeatChar(resume_pc, run_start, next, last, greedy));
+                        }
+                        pc = resume_pc;
+                        pos = run_start + count;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl ImGuiTextFindPattern {
+    pub fn Compile(query: &str, flags: ImGuiInputTextFindFlags) -> Result<Self, String> {
+        let src: Vec<char> = query.chars().collect();
+        let is_regex = (flags & ImGuiInputTextFindFlags_Regex) != 0;
+        let (alternatives, capture_count) = if is_regex {
+            let mut parser = ImGuiTextFindParser { Src: &src, Pos: 0, CaptureCount: 0 };
+            let alternatives = parser.ParseAlternatives()?;
+            if parser.Pos < src.len() {
+                return Err(String::from("Unmatched ')'"));
+            }
+            if parser.CaptureCount > IMGUI_TEXT_FIND_MAX_CAPTURES {
+                return Err(String::from("Too many groups"));
+            }
+            (alternatives, parser.CaptureCount)
+        } else {
+            (vec![src.iter().map(|c| ImGuiTextFindNode::Char(*c)).collect()], 0)
+        };
+        let mut compiler = ImGuiTextFindCompiler { Program: Vec::new(), LoopCount: 0 };
+        compiler.CompileAlternatives(&alternatives)?;
+        compiler.Emit(ImGuiTextFindInst::Match)?;
+        Ok(Self {
+            Alternatives: alternatives,
+            Program: compiler.Program,
+            LoopCount: compiler.LoopCount,
+            CaptureCount: capture_count,
+            CaseSensitive: (flags & ImGuiInputTextFindFlags_CaseSensitive) != 0,
+            WholeWord: (flags & ImGuiInputTextFindFlags_WholeWord) != 0,
+            IsRegex: is_regex,
+        })
+    }
+
+    fn MatchAtEx(&self, matcher: &mut ImGuiTextFindMatcher, pos: usize) -> Option<ImGuiTextFindCaptures> {
+        let slots = matcher.Run(pos)?;
+        Some((0..=self.CaptureCount).map(|n| match (slots[n * 2], slots[n * 2 + 1]) {
+            (Some(start), Some(end)) => Some(ImGuiTextFindMatch { Start: start, End: end }),
+            _ => None,
+        }).collect())
+    }
+
+    // Match starting exactly at 'pos'. [0] is the whole match, [1..] the capture groups.
+    pub fn MatchAt(&self, text: &dyn ImGuiInputTextStorage, pos: usize) -> Option<Vec<Option<ImGuiTextFindMatch>>> {
+        let mut matcher = ImGuiTextFindMatcher { Pattern: self, Text: text, Steps: 0 };
+        self.MatchAtEx(&mut matcher, pos)
+    }
+
+    // Collect up to 'max_matches' matches into 'out'. Returns false if matching was aborted (too many steps).
+    pub fn FindAll(&self, text: &dyn ImGuiInputTextStorage, max_matches: usize, out: &mut Vec<ImGuiTextFindMatch>) -> bool {
+        out.clear();
+        let mut matcher = ImGuiTextFindMatcher { Pattern: self, Text: text, Steps: 0 };
+        let first_char = match self.Alternatives.as_slice() {
+            [seq] if !self.IsRegex || seq.first().map_or(false, |n| matches!(n, ImGuiTextFindNode::Char(_))) => seq.first().cloned(),
+            _ => None,
+        };
+        let mut pos = 0;
+        while pos < text.Len() && out.len() < max_matches {
+            // Cheap pre-check on the first character before running the matcher
+            if let Some(node) = &first_char {
+                if !matcher.MatchChar(node, pos) {
+                    pos += 1;
+                    continue;
+                }
+            }
+            match self.MatchAtEx(&mut matcher, pos) {
+                Some(caps) => {
+                    let m = caps[0].unwrap();
+                    out.push(m);
+                    pos = m.End;
+                }
+                None => pos += 1,
+            }
+            if matcher.Steps > IMGUI_TEXT_FIND_MAX_STEPS {
+                return false;
+            }
+        }
+        true
+    }
+
+    // Replacement text for a match. With regular expressions $0..$9 insert the captures and $$ a '$'.
+    pub fn ExpandReplacement(&self, text: &dyn ImGuiInputTextStorage, caps: &[Option<ImGuiTextFindMatch>], replacement: &str) -> Vec<char> {
+        if !self.IsRegex {
+            return replacement.chars().collect();
+        }
+        let mut out: Vec<char> = Vec::with_capacity(replacement.len());
+        let mut it = replacement.chars().peekable();
+        while let Some(c) = it.next() {
+            if c == '$' {
+                match it.peek().copied() {
+                    Some('$') => { it.next(); out.push('$'); continue; }
+                    Some(d) if d.is_ascii_digit() => {
+                        it.next();
+                        if let Some(Some(m)) = caps.get(d as usize - '0' as usize) {
+                            text.VisitRange(m.Start, m.End, &mut |c| out.push(c));
+                        }
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+            out.push(c);
+        }
+        out
+    }
+}
@@ -26,6 +26,10 @@ pub const ImGuiInputTextFlags_NoUndoRedo: ImGuiInputTextFlags = 1 << 16; // Disa
 pub const ImGuiInputTextFlags_CharsScientific: ImGuiInputTextFlags = 1 << 17; // Allow 0123456789.+-*/eE (Scientific notation input)
 pub const ImGuiInputTextFlags_CallbackResize: ImGuiInputTextFlags = 1 << 18; // Callback on buffer capacity changes request (beyond 'buf_size' parameter value); allowing the string to grow. Notify when the string wants to be resized (for string types which hold a cache of their Size). You will be provided a new BufSize in the callback and NEED to honor it. (see misc/cpp/imgui_stdlib.h for an example of using this)
 pub const ImGuiInputTextFlags_CallbackEdit: ImGuiInputTextFlags = 1 << 19; // Callback on any edit (note that InputText() already returns true on edit; the callback is useful mainly to manipulate the underlying buffer while focus is active)
+pub const ImGuiInputTextFlags_MultiCursor: ImGuiInputTextFlags = 1 << 20; // Multi-line only: Ctrl+Click adds a cursor, Alt+Drag makes a column (block) selection. Typing, deleting and pasting apply at every cursor and undo as a single step.
+pub const ImGuiInputTextFlags_FindReplace: ImGuiInputTextFlags = 1 << 21; // Multi-line only: Ctrl+F opens a find bar (Ctrl+H with replace) with case/whole word/regex toggles. Read-only widgets get find only.
+pub const ImGuiInputTextFlags_CallbackDiagnostics: ImGuiInputTextFlags = 1 << 22; // Callback when the text changes, to report byte ranges of the text to underline (compile errors, spelling marks). Fill 'Diagnostics' using AddDiagnostic(). Hovering a range shows its message.
+pub const ImGuiInputTextFlags_CallbackCandidates: ImGuiInputTextFlags = 1 << 23; // Callback when typing a word (or on Ctrl+Space), to supply completion candidates with AddCandidate(). They are listed, filtered, below the caret: Up/Down to pick, Enter or click to accept, Escape to dismiss.
 
 // Obsolete names (will be removed soon)
 // #ifndef IMGUI_DISABLE_OBSOLETE_FUNCTIONS
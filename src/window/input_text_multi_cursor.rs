@@ -0,0 +1,216 @@
+#![allow(non_snake_case)]
+
+// [SECTION] Multi-cursor and column selection for InputTextMultiline() (ImGuiInputTextFlags_MultiCursor)
+// - InputTextAddCursor()                     [Ctrl+Click] keep the current cursor before stb_textedit_click() places a new primary one
+// - InputTextBeginColumnSelect()             [Alt+Click] anchor a column (block) selection
+// - InputTextUpdateColumnSelect()            [Alt+Drag] one cursor+selection per line between the anchor and the mouse
+// - InputTextForEachCursor()                 run an edit/navigation at every cursor, as a single undo step
+// - InputTextPasteAtCursors()                paste at every cursor, one clipboard line per cursor when counts match
+// - InputTextGetCursorsText()                text of all selections, one per line (for Copy/Cut)
+// - InputTextClearExtraCursors()
+// The primary cursor is always ImGuiInputTextState::Stb, so all single-cursor code paths (callbacks, scrolling, IME) keep working unchanged.
+
+use libc::{c_float, c_uchar};
+use crate::input_text_state::ImGuiInputTextState;
+use crate::stb::stb_text_edit_state::STB_TexteditState;
+use crate::stb::stb_textedit::{stb_text_begin_undo_group, stb_text_end_undo_group, stb_textedit_paste};
+use crate::window::input_text_storage::ImGuiInputTextStorage;
+
+// An extra cursor, stored with the same fields as the primary cursor in STB_TexteditState
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct ImGuiInputTextCursor {
+    pub Cursor: usize,
+    pub SelectStart: usize,
+    pub SelectEnd: usize,
+    pub HasPreferredX: c_uchar,
+    pub PreferredX: c_float,
+}
+
+impl ImGuiInputTextCursor {
+    pub fn FromStb(stb: &STB_TexteditState) -> Self {
+        Self {
+            Cursor: stb.cursor,
+            SelectStart: stb.select_start,
+            SelectEnd: stb.select_end,
+            HasPreferredX: stb.has_preferred_x,
+            PreferredX: stb.preferred_x,
+        }
+    }
+
+    pub fn ToStb(&self, stb: &mut STB_TexteditState) {
+        stb.cursor = self.Cursor;
+        stb.select_start = self.SelectStart;
+        stb.select_end = self.SelectEnd;
+        stb.has_preferred_x = self.HasPreferredX;
+        stb.preferred_x = self.PreferredX;
+    }
+
+    pub fn HasSelection(&self) -> bool {
+        self.SelectStart != self.SelectEnd
+    }
+
+    // Range covered by the cursor: its selection, or the caret position.
+    pub fn RangeMin(&self) -> usize {
+        if self.HasSelection() { self.SelectStart.min(self.SelectEnd) } else { self.Cursor }
+    }
+
+    pub fn RangeMax(&self) -> usize {
+        if self.HasSelection() { self.SelectStart.max(self.SelectEnd) } else { self.Cursor }
+    }
+
+    pub fn Overlaps(&self, other: &ImGuiInputTextCursor) -> bool {
+        self.RangeMin() <= other.RangeMax() && other.RangeMin() <= self.RangeMax()
+    }
+
+    fn Shift(&mut self, len_before: usize, len_after: usize) {
+        let shift = |pos: usize| (pos + len_after).saturating_sub(len_before);
+        self.Cursor = shift(self.Cursor);
+        self.SelectStart = shift(self.SelectStart);
+        self.SelectEnd = shift(self.SelectEnd);
+    }
+}
+
+pub fn InputTextClearExtraCursors(state: &mut ImGuiInputTextState) {
+    state.ExtraCursors.clear();
+    state.ColumnSelectActive = false;
+}
+
+// Drop extra cursors overlapping the primary one or each other. Kept sorted by position.
+pub fn InputTextMergeCursors(state: &mut ImGuiInputTextState) {
+    if state.ExtraCursors.is_empty() {
+        return;
+    }
+    let primary = ImGuiInputTextCursor::FromStb(&state.Stb);
+    state.ExtraCursors.retain(|cursor| !cursor.Overlaps(&primary));
+    state.ExtraCursors.sort_by_key(|cursor| cursor.RangeMin());
+    state.ExtraCursors.dedup_by(|next, prev| next.Overlaps(prev));
+}
+
+pub fn InputTextAddCursor(state: &mut ImGuiInputTextState) {
+    state.ExtraCursors.push(ImGuiInputTextCursor::FromStb(&state.Stb));
+}
+
+// Character position on 'line' closest to 'x' (in pixels from the start of the line).
+pub fn InputTextLocateColumn(text: &dyn ImGuiInputTextStorage, line: usize, x: c_float, char_advance: &dyn Fn(char) -> c_float) -> usize {
+    let line_start = text.LineStart(line);
+    let line_end = text.LineEnd(line);
+    let mut pos = line_start;
+    let mut line_x: c_float = 0.0;
+    let mut found = false;
+    text.VisitRange(line_start, line_end, &mut |c| {
+        if found {
+            return;
+        }
+        let w = char_advance(c);
+        if x < line_x + w * 0.5 {
+            found = true;
+        } else {
+            line_x += w;
+            pos += 1;
+        }
+    });
+    pos
+}
+
+pub fn InputTextBeginColumnSelect(state: &mut ImGuiInputTextState, line: usize, x: c_float) {
+    state.ExtraCursors.clear();
+    state.ColumnSelectActive = true;
+    state.ColumnSelectAnchorLine = line.min(state.TextW.LineCount() - 1);
+    state.ColumnSelectAnchorX = x.max(0.0);
+}
+
+// Rebuild the block from the anchor to ('line', 'x'). Lines shorter than the block get an empty cursor at their end.
+pub fn InputTextUpdateColumnSelect(state: &mut ImGuiInputTextState, line: usize, x: c_float, char_advance: &dyn Fn(char) -> c_float) {
+    let line = line.min(state.TextW.LineCount() - 1);
+    let x = x.max(0.0);
+    let line_min = line.min(state.ColumnSelectAnchorLine);
+    let line_max = line.max(state.ColumnSelectAnchorLine);
+    state.ExtraCursors.clear();
+    for line_n in line_min..=line_max {
+        let select_start = InputTextLocateColumn(&state.TextW, line_n, state.ColumnSelectAnchorX, char_advance);
+        let select_end = InputTextLocateColumn(&state.TextW, line_n, x, char_advance);
+        let cursor = ImGuiInputTextCursor { Cursor: select_end, SelectStart: select_start, SelectEnd: select_end, HasPreferredX: 1, PreferredX: x };
+        if line_n == line {
+            cursor.ToStb(&mut state.Stb); // The line under the mouse holds the primary cursor
+        } else {
+            state.ExtraCursors.push(cursor);
+        }
+    }
+}
+
+// Call 'op' once per cursor with that cursor loaded in state.Stb.
+// Cursors are visited from last to first in the text, so an edit never moves a cursor that is yet to be visited,
+// and the ones already visited are shifted by the change in text length. All undo records form a single undo step.
+pub unsafe fn InputTextForEachCursor(state: &mut ImGuiInputTextState, op: &mut dyn FnMut(&mut ImGuiInputTextState)) {
+    if state.ExtraCursors.is_empty() {
+        op(state);
+        return;
+    }
+
+    let mut cursors: Vec<ImGuiInputTextCursor> = Vec::with_capacity(state.ExtraCursors.len() + 1);
+    cursors.push(ImGuiInputTextCursor::FromStb(&state.Stb));
+    cursors.extend_from_slice(&state.ExtraCursors);
+    let mut order: Vec<usize> = (0..cursors.len()).collect();
+    order.sort_by(|a, b| cursors[*b].RangeMin().cmp(&cursors[*a].RangeMin()));
+
+    stb_text_begin_undo_group(&mut state.Stb.undostate);
+    for visit_n in 0..order.len() {
+        let cursor_n = order[visit_n];
+        let len_before = state.TextW.Len();
+        cursors[cursor_n].ToStb(&mut state.Stb);
+        op(state);
+        cursors[cursor_n] = ImGuiInputTextCursor::FromStb(&state.Stb);
+        let len_after = state.TextW.Len();
+        if len_after != len_before {
+            for &done_n in &order[..visit_n] {
+                cursors[done_n].Shift(len_before, len_after);
+            }
+        }
+    }
+    stb_text_end_undo_group(&mut state.Stb.undostate);
+
+    cursors[0].ToStb(&mut state.Stb);
+    state.ExtraCursors.clear();
+    state.ExtraCursors.extend_from_slice(&cursors[1..]);
+    InputTextMergeCursors(state);
+}
+
+// Paste 'text' at every cursor. If the text has exactly one line per cursor (e.g. it was copied from a column selection),
+// each cursor receives its own line, in document order.
+pub unsafe fn InputTextPasteAtCursors(state: &mut ImGuiInputTextState, text: &[char]) {
+    let mut lines: Vec<&[char]> = text.split(|c| *c == '\n').collect();
+    if lines.len() > 1 && lines.last().map_or(false, |line| line.is_empty()) {
+        lines.pop();
+    }
+    let cursor_count = state.ExtraCursors.len() + 1;
+    let distribute = cursor_count > 1 && lines.len() == cursor_count;
+
+    // InputTextForEachCursor() visits cursors from last to first: hand out lines in reverse order.
+    let mut cursor_n = cursor_count;
+    InputTextForEachCursor(state, &mut |state| {
+        cursor_n -= 1;
+        let piece: &[char] = if distribute { lines[cursor_n] } else { text };
+        let mut stb = std::mem::take(&mut state.Stb);
+        stb_textedit_paste(state, &mut stb, piece, piece.len());
+        state.Stb = stb;
+    });
+}
+
+// Selected text of every cursor in document order, one selection per line.
+pub fn InputTextGetCursorsText(state: &ImGuiInputTextState) -> String {
+    let mut cursors: Vec<ImGuiInputTextCursor> = Vec::with_capacity(state.ExtraCursors.len() + 1);
+    cursors.push(ImGuiInputTextCursor::FromStb(&state.Stb));
+    cursors.extend_from_slice(&state.ExtraCursors);
+    cursors.sort_by_key(|cursor| cursor.RangeMin());
+
+    let mut out = String::new();
+    let mut piece = String::new();
+    for (n, cursor) in cursors.iter().enumerate() {
+        if n > 0 {
+            out.push('\n');
+        }
+        state.TextW.CopyRangeUtf8(cursor.RangeMin(), cursor.RangeMax(), &mut piece);
+        out.push_str(&piece);
+    }
+    out
+}
@@ -7,6 +7,11 @@ use crate::stb::stb_textedit::{stb_textedit_key, STB_TEXTEDIT_UNDOSTATECOUNT};
 use crate::stb_text_edit_state::STB_TexteditState;
 use crate::stb_textedit::STB_TEXTEDIT_UNDOSTATECOUNT;
 use crate::core::type_defs::{ImWchar, ImguiHandle};
+use crate::stb::{STB_TEXTEDIT_K_REDO, STB_TEXTEDIT_K_UNDO};
+use crate::window::input_text_completion::ImGuiInputTextCompletionState;
+use crate::window::input_text_find::ImGuiInputTextFindState;
+use crate::window::input_text_multi_cursor::{ImGuiInputTextCursor, InputTextClearExtraCursors, InputTextForEachCursor};
+use crate::window::input_text_storage::{ImGuiInputTextLineWidthCache, ImGuiInputTextRope, ImGuiInputTextStorage};
 use libc::{c_float, c_int};
 
 // Internal state of the currently focused/edited text input box
@@ -18,12 +23,16 @@ pub struct ImGuiInputTextState {
     // c_int                     CurLenW, CurLenA;       // we need to maintain our buffer length in both UTF-8 and wchar format. UTF-8 length is valid even if TextA is not.
     pub CurLenW: usize,
     pub CurLenA: usize,
-    pub TextW: Vec<char>,
+    pub TextW: ImGuiInputTextRope,
     // edit buffer, we need to persist but can't guarantee the persistence of the user-provided buffer. so we copy into own buffer.
+    pub LineWidths: ImGuiInputTextLineWidthCache,
+    // width of each line of TextW, updated by the STB_TEXTEDIT_* glue so we only re-measure edited lines
     pub TextA: Vec<char>,
-    // temporary UTF8 buffer for callbacks and other operations. this is not updated in every code-path! size=capacity.
+    // temporary UTF8 buffer for callbacks and other operations. once valid, every edit of TextW is applied to it as well.
     pub InitialTextA: Vec<char>,
     // backup of end-user buffer at the time of focus (in UTF-8, unaltered)
+    pub ReadOnlyBuf: String,
+    // read-only mode: copy of the user buffer TextW was last loaded from
     pub TextAIsValid: bool,
     // temporary UTF8 buffer is not initially valid before we make the widget active (until then we pull the data from user argument)
     pub BufCapacityA: usize,
@@ -41,6 +50,18 @@ pub struct ImGuiInputTextState {
     pub Edited: bool,
     // edited this frame
     pub Flags: ImGuiInputTextFlags, // copy of InputText() flags
+    pub ExtraCursors: Vec<ImGuiInputTextCursor>,
+    // cursors besides the primary one in Stb (ImGuiInputTextFlags_MultiCursor)
+    pub ColumnSelectActive: bool,
+    // Alt+Drag column selection in progress
+    pub ColumnSelectAnchorLine: usize,
+    pub ColumnSelectAnchorX: c_float,
+    pub EditCount: u32,
+    // incremented on every change to TextW, so derived data (find matches) knows when to refresh
+    pub Find: ImGuiInputTextFindState,
+    // find / replace bar (ImGuiInputTextFlags_FindReplace)
+    pub Completion: ImGuiInputTextCompletionState,
+    // completion popup (ImGuiInputTextFlags_CallbackCandidates)
 }
 
 impl ImGuiInputTextState {
@@ -50,16 +71,34 @@ impl ImGuiInputTextState {
     pub fn ClearText(&mut self) {
         self.CurLenA = 0;
         self.CurLenW = 0;
-        self.TextW.clear();
+        self.TextW.Clear();
+        self.EditCount = self.EditCount.wrapping_add(1);
         self.TextA.clear();
+        self.LineWidths.Reset(1, self.LineWidths.FontSize);
+        InputTextClearExtraCursors(self);
         self.CursorClamp();
     }
 
     // c_void        ClearFreeMemory()           { TextW.clear(); TextA.clear(); InitialTextA.clear(); }
     pub fn ClearFreeMemory(&mut self) {
-        self.TextW.clear();
+        self.TextW = ImGuiInputTextRope::default();
+        self.LineWidths = ImGuiInputTextLineWidthCache::default();
         self.TextA.clear();
         self.InitialTextA.clear();
+        self.ReadOnlyBuf.clear();
+    }
+
+    // Replace the whole edit buffer, e.g. when activating the widget or after a callback rewrote the buffer.
+    pub fn SetTextFromUtf8(&mut self, text: &str) {
+        self.TextW.SetText(text);
+        if self.TextAIsValid {
+            self.TextA.clear();
+            self.TextA.extend(text.chars());
+        }
+        self.EditCount = self.EditCount.wrapping_add(1);
+        self.CurLenW = self.TextW.Len();
+        self.CurLenA = self.TextW.Utf8Len();
+        self.LineWidths.Reset(self.TextW.LineCount(), self.LineWidths.FontSize);
     }
 
     // c_int         GetUndoAvailCount() const   { return Stb.undostate.undo_point; }
@@ -73,8 +112,16 @@ impl ImGuiInputTextState {
     }
 
     // c_void        OnKeyPressed(key: c_int);      // Cannot be inline because we call in code in stb_textedit.h implementation
+    // With extra cursors the key is applied at every cursor, except undo/redo which restore a single cursor.
     pub unsafe fn OnKeyPressed(&mut self, key: c_int) {
-        stb_textedit_key(&mut String::from(self.TextW.clone()), &mut self.Stb, key);
+        if key == STB_TEXTEDIT_K_UNDO || key == STB_TEXTEDIT_K_REDO {
+            InputTextClearExtraCursors(self);
+        }
+        InputTextForEachCursor(self, &mut |state| {
+            let mut stb = std::mem::take(&mut state.Stb);
+            stb_textedit_key(state, &mut stb, key);
+            state.Stb = stb;
+        });
         self.CursorFollow = true;
         self.CursorAnimReset();
     }
@@ -90,6 +137,11 @@ impl ImGuiInputTextState {
         self.Stb.cursor = self.Stb.cursor.min(self.CurLenW);
         self.Stb.select_start = self.Stb.select_start.min(self.CurLenW);
         self.Stb.select_end = self.Stb.select_end.min(self.CurLenW);
+        for cursor in self.ExtraCursors.iter_mut() {
+            cursor.Cursor = cursor.Cursor.min(self.CurLenW);
+            cursor.SelectStart = cursor.SelectStart.min(self.CurLenW);
+            cursor.SelectEnd = cursor.SelectEnd.min(self.CurLenW);
+        }
     }
 
     // bool        HasSelection() const        { return Stb.select_start != Stb.select_end; }
@@ -120,6 +172,7 @@ impl ImGuiInputTextState {
 
     // c_void        SelectAll()                 { Stb.select_start = 0; Stb.cursor = Stb.select_end = CurLenW; Stb.has_preferred_x = 0; }
     pub fn SelectAll(&mut self) {
+        InputTextClearExtraCursors(self);
         self.Stb.select_start = 0;
         self.Stb.cursor = self.CurLenW;
         self.Stb.select_end = self.CurLenW;
@@ -0,0 +1,332 @@
+#![allow(non_snake_case)]
+
+use libc::c_float;
+
+// Storage backing the wide-char edit buffer of ImGuiInputTextState (TextW).
+// - The STB_TEXTEDIT_* glue and InputTextEx() only talk to the buffer through this trait, so a different
+//   container (gap buffer, piece table...) can be dropped in without touching the widget code.
+// - Positions are expressed in characters. Lines are separated by '\n', a buffer always has at least 1 line.
+pub trait ImGuiInputTextStorage {
+    fn Len(&self) -> usize;                                             // Number of characters
+    fn Utf8Len(&self) -> usize;                                         // Number of bytes once encoded to UTF-8
+    fn Utf8Offset(&self, pos: usize) -> usize;                          // Number of UTF-8 bytes before character 'pos'
+    fn GetChar(&self, pos: usize) -> char;
+    fn SetText(&mut self, text: &str);
+    fn Clear(&mut self);
+    fn Insert(&mut self, pos: usize, text: &[char]);
+    fn Delete(&mut self, pos: usize, count: usize);
+    fn VisitRange(&self, begin: usize, end: usize, visitor: &mut dyn FnMut(char));
+    fn LineCount(&self) -> usize;
+    fn LineOfPos(&self, pos: usize) -> usize;
+    fn LineStart(&self, line: usize) -> usize;
+
+    // Position of the '\n' terminating 'line', or Len() for the last line.
+    fn LineEnd(&self, line: usize) -> usize {
+        if line + 1 < self.LineCount() { self.LineStart(line + 1) - 1 } else { self.Len() }
+    }
+
+    fn CopyRange(&self, begin: usize, end: usize, out: &mut Vec<char>) {
+        out.clear();
+        out.reserve(end - begin);
+        self.VisitRange(begin, end, &mut |c| out.push(c));
+    }
+
+    fn CopyRangeUtf8(&self, begin: usize, end: usize, out: &mut String) {
+        out.clear();
+        self.VisitRange(begin, end, &mut |c| out.push(c));
+    }
+}
+
+// Chunks are split when they grow past this size, and merged with their neighbor when both fit in it.
+pub const IMGUI_INPUT_TEXT_ROPE_CHUNK_MAX: usize = 4096;
+
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiInputTextRopeChunk {
+    pub Text: Vec<char>,
+    pub NewLines: usize,
+    pub Utf8Len: usize,
+}
+
+impl ImGuiInputTextRopeChunk {
+    pub fn new(text: Vec<char>) -> Self {
+        let mut chunk = Self { Text: text, NewLines: 0, Utf8Len: 0 };
+        chunk.UpdateCounts();
+        chunk
+    }
+
+    pub fn UpdateCounts(&mut self) {
+        self.NewLines = self.Text.iter().filter(|c| **c == '\n').count();
+        self.Utf8Len = self.Text.iter().map(|c| c.len_utf8()).sum();
+    }
+}
+
+// Default ImGuiInputTextStorage: text split in bounded chunks with prefix tables over character and line counts.
+// - Lookups are a binary search over the chunk table, edits only copy within the touched chunk(s) and then
+//   refresh the prefix tables (which hold one entry per chunk, i.e. 1/4096th of the text size).
+// - Always holds at least one (possibly empty) chunk.
+#[derive(Debug, Clone)]
+pub struct ImGuiInputTextRope {
+    pub Chunks: Vec<ImGuiInputTextRopeChunk>,
+    pub ChunkStarts: Vec<usize>,       // Character index of the first character of each chunk
+    pub ChunkLineStarts: Vec<usize>,   // Number of '\n' before each chunk
+    pub TotalLen: usize,
+    pub TotalNewLines: usize,
+    pub TotalUtf8Len: usize,
+}
+
+impl Default for ImGuiInputTextRope {
+    fn default() -> Self {
+        Self {
+            Chunks: vec![ImGuiInputTextRopeChunk::default()],
+            ChunkStarts: vec![0],
+            ChunkLineStarts: vec![0],
+            TotalLen: 0,
+            TotalNewLines: 0,
+            TotalUtf8Len: 0,
+        }
+    }
+}
+
+impl ImGuiInputTextRope {
+    pub fn new(text: &str) -> Self {
+        let mut rope = Self::default();
+        rope.SetText(text);
+        rope
+    }
+
+    // Return (chunk index, offset in chunk). 'pos == Len()' maps to the end of the last chunk.
+    pub fn FindChunk(&self, pos: usize) -> (usize, usize) {
+        let chunk_n = self.ChunkStarts.partition_point(|start| *start <= pos).max(1) - 1;
+        (chunk_n, pos - self.ChunkStarts[chunk_n])
+    }
+
+    // Rebuild prefix tables from 'first_chunk' onward, dropping empty chunks and merging small neighbors.
+    fn RefreshFrom(&mut self, first_chunk: usize) {
+        let mut n = first_chunk.min(self.Chunks.len().saturating_sub(1));
+        if n > 0 {
+            n -= 1; // The previous chunk may now be mergeable with the edited one
+        }
+        let mut i = n;
+        while i < self.Chunks.len() {
+            if self.Chunks[i].Text.is_empty() && self.Chunks.len() > 1 {
+                self.Chunks.remove(i);
+                continue;
+            }
+            if i + 1 < self.Chunks.len() && self.Chunks[i].Text.len() + self.Chunks[i + 1].Text.len() <= IMGUI_INPUT_TEXT_ROPE_CHUNK_MAX / 2 {
+                let next = self.Chunks.remove(i + 1);
+                let chunk = &mut self.Chunks[i];
+                chunk.Text.extend_from_slice(&next.Text);
+                chunk.NewLines += next.NewLines;
+                chunk.Utf8Len += next.Utf8Len;
+                continue;
+            }
+            if i > first_chunk + 1 {
+                break; // Past the edited region: chunk layout is unchanged from here
+            }
+            i += 1;
+        }
+
+        self.ChunkStarts.resize(self.Chunks.len(), 0);
+        self.ChunkLineStarts.resize(self.Chunks.len(), 0);
+        let (mut start, mut line_start) = if n > 0 {
+            (self.ChunkStarts[n - 1] + self.Chunks[n - 1].Text.len(), self.ChunkLineStarts[n - 1] + self.Chunks[n - 1].NewLines)
+        } else {
+            (0, 0)
+        };
+        for chunk_n in n..self.Chunks.len() {
+            self.ChunkStarts[chunk_n] = start;
+            self.ChunkLineStarts[chunk_n] = line_start;
+            start += self.Chunks[chunk_n].Text.len();
+            line_start += self.Chunks[chunk_n].NewLines;
+        }
+        self.TotalLen = start;
+        self.TotalNewLines = line_start;
+        self.TotalUtf8Len = self.Chunks.iter().map(|chunk| chunk.Utf8Len).sum();
+    }
+
+    // Split an oversized chunk into pieces of IMGUI_INPUT_TEXT_ROPE_CHUNK_MAX / 2 characters.
+    fn SplitChunk(&mut self, chunk_n: usize) {
+        if self.Chunks[chunk_n].Text.len() <= IMGUI_INPUT_TEXT_ROPE_CHUNK_MAX {
+            return;
+        }
+        let text = std::mem::take(&mut self.Chunks[chunk_n].Text);
+        let pieces: Vec<ImGuiInputTextRopeChunk> = text
+            .chunks(IMGUI_INPUT_TEXT_ROPE_CHUNK_MAX / 2)
+            .map(|piece| ImGuiInputTextRopeChunk::new(piece.to_vec()))
+            .collect();
+        self.Chunks.splice(chunk_n..chunk_n + 1, pieces);
+    }
+}
+
+impl ImGuiInputTextStorage for ImGuiInputTextRope {
+    fn Len(&self) -> usize {
+        self.TotalLen
+    }
+
+    fn Utf8Len(&self) -> usize {
+        self.TotalUtf8Len
+    }
+
+    fn Utf8Offset(&self, pos: usize) -> usize {
+        let (chunk_n, offset) = self.FindChunk(pos.min(self.TotalLen));
+        let before: usize = self.Chunks[..chunk_n].iter().map(|chunk| chunk.Utf8Len).sum();
+        before + self.Chunks[chunk_n].Text[..offset].iter().map(|c| c.len_utf8()).sum::<usize>()
+    }
+
+    fn GetChar(&self, pos: usize) -> char {
+        if pos >= self.TotalLen {
+            return '\0';
+        }
+        let (chunk_n, offset) = self.FindChunk(pos);
+        self.Chunks[chunk_n].Text[offset]
+    }
+
+    fn SetText(&mut self, text: &str) {
+        let chars: Vec<char> = text.chars().collect();
+        self.Chunks = chars
+            .chunks(IMGUI_INPUT_TEXT_ROPE_CHUNK_MAX / 2)
+            .map(|piece| ImGuiInputTextRopeChunk::new(piece.to_vec()))
+            .collect();
+        if self.Chunks.is_empty() {
+            self.Chunks.push(ImGuiInputTextRopeChunk::default());
+        }
+        self.ChunkStarts.clear();
+        self.ChunkLineStarts.clear();
+        self.RefreshFrom(0);
+    }
+
+    fn Clear(&mut self) {
+        *self = Self::default();
+    }
+
+    fn Insert(&mut self, pos: usize, text: &[char]) {
+        if text.is_empty() {
+            return;
+        }
+        // IM_ASSERT(pos <= TotalLen);
+        let (chunk_n, offset) = self.FindChunk(pos.min(self.TotalLen));
+        {
+            let chunk = &mut self.Chunks[chunk_n];
+            chunk.Text.splice(offset..offset, text.iter().copied());
+            chunk.NewLines += text.iter().filter(|c| **c == '\n').count();
+            chunk.Utf8Len += text.iter().map(|c| c.len_utf8()).sum::<usize>();
+        }
+        self.SplitChunk(chunk_n);
+        self.RefreshFrom(chunk_n);
+    }
+
+    fn Delete(&mut self, pos: usize, count: usize) {
+        let end = (pos + count).min(self.TotalLen);
+        if pos >= end {
+            return;
+        }
+        let (first_chunk, first_offset) = self.FindChunk(pos);
+        let mut chunk_n = first_chunk;
+        let mut offset = first_offset;
+        let mut remaining = end - pos;
+        while remaining > 0 {
+            let chunk = &mut self.Chunks[chunk_n];
+            let n = remaining.min(chunk.Text.len() - offset);
+            chunk.Text.drain(offset..offset + n);
+            chunk.UpdateCounts();
+            remaining -= n;
+            chunk_n += 1;
+            offset = 0;
+        }
+        self.RefreshFrom(first_chunk);
+    }
+
+    fn VisitRange(&self, begin: usize, end: usize, visitor: &mut dyn FnMut(char)) {
+        let end = end.min(self.TotalLen);
+        if begin >= end {
+            return;
+        }
+        let (mut chunk_n, mut offset) = self.FindChunk(begin);
+        let mut remaining = end - begin;
+        while remaining > 0 {
+            let text = &self.Chunks[chunk_n].Text;
+            let n = remaining.min(text.len() - offset);
+            for c in &text[offset..offset + n] {
+                visitor(*c);
+            }
+            remaining -= n;
+            chunk_n += 1;
+            offset = 0;
+        }
+    }
+
+    fn LineCount(&self) -> usize {
+        self.TotalNewLines + 1
+    }
+
+    fn LineOfPos(&self, pos: usize) -> usize {
+        let (chunk_n, offset) = self.FindChunk(pos.min(self.TotalLen));
+        self.ChunkLineStarts[chunk_n] + self.Chunks[chunk_n].Text[..offset].iter().filter(|c| **c == '\n').count()
+    }
+
+    fn LineStart(&self, line: usize) -> usize {
+        if line == 0 {
+            return 0;
+        }
+        if line > self.TotalNewLines {
+            return self.TotalLen;
+        }
+        // Find the chunk holding the line'th '\n', then scan it.
+        let chunk_n = self.ChunkLineStarts.partition_point(|line_start| *line_start < line) - 1;
+        let mut newlines_left = line - self.ChunkLineStarts[chunk_n];
+        for (offset, c) in self.Chunks[chunk_n].Text.iter().enumerate() {
+            if *c == '\n' {
+                newlines_left -= 1;
+                if newlines_left == 0 {
+                    return self.ChunkStarts[chunk_n] + offset + 1;
+                }
+            }
+        }
+        self.TotalLen
+    }
+}
+
+// Per-line width cache for ImGuiInputTextState.
+// - Entries are in pixels at 'FontSize', a negative value marks a line that needs measuring.
+// - OnReplace() is called by the STB_TEXTEDIT_* glue for every edit, so only the touched lines get re-measured.
+#[derive(Default, Debug, Clone)]
+pub struct ImGuiInputTextLineWidthCache {
+    pub Widths: Vec<c_float>,
+    pub FontSize: c_float,
+}
+
+impl ImGuiInputTextLineWidthCache {
+    pub fn Reset(&mut self, line_count: usize, font_size: c_float) {
+        self.Widths.clear();
+        self.Widths.resize(line_count, -1.0);
+        self.FontSize = font_size;
+    }
+
+    // Lines [first_line, first_line + removed_newlines] were replaced by 'inserted_newlines + 1' lines.
+    pub fn OnReplace(&mut self, first_line: usize, removed_newlines: usize, inserted_newlines: usize) {
+        let first_line = first_line.min(self.Widths.len());
+        let last_line = (first_line + removed_newlines + 1).min(self.Widths.len());
+        self.Widths.splice(first_line..last_line, std::iter::repeat(-1.0).take(inserted_newlines + 1));
+    }
+
+    pub fn GetLineWidth(&mut self, text: &dyn ImGuiInputTextStorage, line: usize, char_advance: &dyn Fn(char) -> c_float) -> c_float {
+        if line >= self.Widths.len() {
+            self.Widths.resize(line + 1, -1.0);
+        }
+        if self.Widths[line] < 0.0 {
+            self.Widths[line] = InputTextCalcRangeWidth(text, text.LineStart(line), text.LineEnd(line), char_advance);
+        }
+        self.Widths[line]
+    }
+}
+
+// Width of characters [begin, end) laid out on a single line ('\r' is skipped, matching InputTextCalcTextSizeW()).
+pub fn InputTextCalcRangeWidth(text: &dyn ImGuiInputTextStorage, begin: usize, end: usize, char_advance: &dyn Fn(char) -> c_float) -> c_float {
+    let mut width: c_float = 0.0;
+    text.VisitRange(begin, end, &mut |c| {
+        if c != '\r' && c != '\n' {
+            width += char_advance(c);
+        }
+    });
+    width
+}
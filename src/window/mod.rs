@@ -53,8 +53,16 @@ pub mod window_stack_data;
 pub mod window_temp_data;
 pub mod input_text;
 pub mod input_text_callback_data;
+pub mod input_text_completion;
+pub mod input_text_diagnostic_severity;
+pub mod input_text_diagnostics;
+pub mod input_text_find;
+pub mod input_text_find_flags;
+pub mod input_text_find_pattern;
 pub mod input_text_flags;
 pub mod input_text_state;
+pub mod input_text_storage;
+pub mod input_text_multi_cursor;
 mod menu_columns;
 pub mod next_window_data;
 pub mod next_window_data_flags;
@@ -320,19 +328,19 @@ impl ImguiWindow {
     // ImguiHandle     GetID(*const c_char str, *const c_char str_end = NULL);
     pub fn id_by_string(&self, g: &mut AppContext, begin: &String) -> ImguiHandle {
         let mut seed: ImguiHandle = self.id_stack.last().unwrap().clone();
-        let mut id: ImguiHandle = hash_string(begin, seed as u32);
+        let mut id: ImguiHandle = hash_string(begin, seed);
         // let g = GImGui; // ImGuiContext& g = *GImGui;
         if g.DebugHookIdInfo == id {
-            DebugHookIdInfo(g, id, IM_GUI_DATA_TYPE_STRING, begin.into_bytes().borrow());
+            DebugHookIdInfo(g, id, IM_GUI_DATA_TYPE_STRING, Some(begin.as_bytes()));
         }
         return id;
     }
 
     pub fn id_by_int(&self, g: &mut AppContext, n: c_int) -> ImguiHandle {
         let mut seed = self.id_stack.last().unwrap().clone();
-        let mut id = hash_data(&n.to_le_bytes(), seed as u32);
+        let mut id = hash_data(&n.to_le_bytes(), seed);
         if g.DebugHookIdInfo == id {
-            // DebugHookIdInfo(id, IM_GUI_DATA_TYPE_S32, n, NULL);
+            DebugHookIdInfo(g, id, IM_GUI_DATA_TYPE_S32, Some(&n.to_le_bytes()));
         }
         return id;
     }
@@ -344,7 +352,7 @@ impl ImguiWindow {
         let mut seed: ImguiHandle = self.id_stack.last().unwrap().clone();
         let r_rel: ImRect = window_rect_abs_to_rel(self, r_abs);
 
-        let mut id: ImguiHandle = hash_data(&r_rel, seed as u32);
+        let mut id: ImguiHandle = hash_data(&r_rel, seed);
         return id;
     }
 
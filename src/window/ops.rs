@@ -51,10 +51,11 @@ use crate::core::vec4::ImVec4;
 use crate::window::find::{FindBlockingModal, FindWindowByName, FindWindowDisplayIndex};
 use crate::window::rect::{ClampWindowRect, PopClipRect, PushClipRect};
 use crate::window::render::{RenderWindowDecorations, RenderWindowTitleBarContents, UpdateWindowParentAndRootLinks};
-use crate::window::window_flags::{ImGuiWindowFlags, ImGuiWindowFlags_AlwaysAutoResize, ImGuiWindowFlags_AlwaysHorizontalScrollbar, ImGuiWindowFlags_AlwaysUseWindowPadding, ImGuiWindowFlags_AlwaysVerticalScrollbar, ImGuiWindowFlags_ChildMenu, ImGuiWindowFlags_ChildWindow, ImGuiWindowFlags_DockNodeHost, ImGuiWindowFlags_HorizontalScrollbar, ImGuiWindowFlags_MenuBar, ImGuiWindowFlags_Modal, ImGuiWindowFlags_NavFlattened, ImGuiWindowFlags_NoBackground, ImGuiWindowFlags_NoBringToFrontOnFocus, ImGuiWindowFlags_NoCollapse, ImGuiWindowFlags_NoDocking, ImGuiWindowFlags_NoFocusOnAppearing, ImGuiWindowFlags_NoInputs, ImGuiWindowFlags_NoMouseInputs, ImGuiWindowFlags_NoMove, ImGuiWindowFlags_NoNavFocus, ImGuiWindowFlags_NoResize, ImGuiWindowFlags_NoSavedSettings, ImGuiWindowFlags_NoScrollbar, ImGuiWindowFlags_NoTitleBar, ImGuiWindowFlags_Popup, ImGuiWindowFlags_Tooltip};
+use crate::window::window_flags::{ImGuiWindowFlags, ImGuiWindowFlags_AlwaysAutoResize, ImGuiWindowFlags_AlwaysHorizontalScrollbar, ImGuiWindowFlags_AlwaysUseWindowPadding, ImGuiWindowFlags_AlwaysVerticalScrollbar, ImGuiWindowFlags_ChildMenu, ImGuiWindowFlags_ChildWindow, ImGuiWindowFlags_DockNodeHost, ImGuiWindowFlags_HorizontalScrollbar, ImGuiWindowFlags_MenuBar, ImGuiWindowFlags_Modal, ImGuiWindowFlags_NavFlattened, ImGuiWindowFlags_NoBackground, ImGuiWindowFlags_NoBringToFrontOnFocus, ImGuiWindowFlags_NoCollapse, ImGuiWindowFlags_NoDocking, ImGuiWindowFlags_NoFocusOnAppearing, ImGuiWindowFlags_NoInputs, ImGuiWindowFlags_NoMouseInputs, ImGuiWindowFlags_NoMove, ImGuiWindowFlags_NoNavFocus, ImGuiWindowFlags_None, ImGuiWindowFlags_NoResize, ImGuiWindowFlags_NoSavedSettings, ImGuiWindowFlags_NoScrollbar, ImGuiWindowFlags_NoTitleBar, ImGuiWindowFlags_Popup, ImGuiWindowFlags_Tooltip};
 use crate::window::window_settings::ImGuiWindowSettings;
 use crate::window::window_stack_data::ImGuiWindowStackData;
 use crate::window_settings::ImGuiWindowSettings;
+use crate::test_engine::test_engine::{ImGuiTestEngineHook_ItemAdd, ImGuiTestEngineHook_ItemInfo};
 
 // static c_void SetCurrentWindow(window: &mut ImGuiWindow)
 pub fn SetCurrentWindow(g: &mut AppContext, window: ImguiHandle) {
@@ -473,8 +474,8 @@ pub unsafe fn GetWindowResizeCornerID(window: &mut ImguiWindow, n: c_int) -> Img
     let mut id: ImguiHandle = if window.DockIsActive {
         window.DockNode.Hostwindow.ID } else {
         window.ID.clone() };
-    id = hash_string(str_to_const_c_char_ptr("#RESIZE"), id as u32);
-    id = hash_data(&n, sizeof, id as u32);
+    id = hash_string(str_to_const_c_char_ptr("#RESIZE"), id);
+    id = hash_data(&n, sizeof, id);
     return id;
 }
 
@@ -484,8 +485,8 @@ pub unsafe fn GetWindowResizeBorderID(window: &mut ImguiWindow, dir: ImGuiDir) -
     // IM_ASSERT(dir >= 0 && dir < 4);
     let n: c_int = dir + 4;
     let mut id: ImguiHandle = if window.DockIsActive { window.DockNode.Hostwindow.ID } else { window.ID.clone() };
-    id = hash_string(str_to_const_c_char_ptr("#RESIZE"), id as u32);
-    id = hash_data(&n, sizeof, id as u32);
+    id = hash_string(str_to_const_c_char_ptr("#RESIZE"), id);
+    id = hash_data(&n, sizeof, id);
     return id;
 }
 
@@ -670,6 +671,12 @@ pub unsafe fn UpdateWindowManualResize(window: &mut ImguiWindow, size_auto_fit:
 // - Return false when window is collapsed, so you can early out in your code. You always need to call End() even if false is returned.
 // - Passing 'bool* p_open' displays a Close button on the upper-right corner of the window, the pointed value will be set to false when the button is pressed.
 pub fn Begin(g: &mut AppContext, name: &String, p_open: Option<&mut bool>) -> bool
+{
+    BeginEx(g, name, p_open, ImGuiWindowFlags_None)
+}
+
+// Same as Begin() with window flags, e.g. ImGuiWindowFlags_NoFocusOnAppearing
+pub fn BeginEx(g: &mut AppContext, name: &String, p_open: Option<&mut bool>, mut flags: ImGuiWindowFlags) -> bool
 {
     // let g = GImGui; // ImGuiContext& g = *GImGui;
     let style = &mut g.style;
@@ -1184,8 +1191,10 @@ pub fn Begin(g: &mut AppContext, name: &String, p_open: Option<&mut bool>) -> bo
         {
             // IM_ASSERT(window.id_stack.Size == 1);
             window.id_stack.Size = 0; // As window.id_stack[0] == window.ID here, make sure TestEngine doesn't erroneously see window as parent of itself.
-            IMGUI_TEST_ENGINE_ITEM_ADD(window.Rect(), window.ID);
-            IMGUI_TEST_ENGINE_ITEM_INFO(window.ID, window.Name, if g.HoveredWindow == window { ImGuiItemStatusFlags_HoveredRect }else { 0 });
+            let (window_rect, window_id, window_name) = (window.Rect(), window.ID, window.Name.clone());
+            ImGuiTestEngineHook_ItemAdd(g, &window_rect, window_id);
+            let status_flags = if g.HoveredWindow == window_id { ImGuiItemStatusFlags_HoveredRect } else { 0 };
+            ImGuiTestEngineHook_ItemInfo(g, window_id, &window_name, status_flags);
             window.id_stack.Size = 1;
         }
 // #endif
@@ -1490,8 +1499,9 @@ pub fn Begin(g: &mut AppContext, name: &String, p_open: Option<&mut bool>) -> bo
         }
 
         // [Test Engine] Register title bar / tab
-        if (flag_clear(window.Flags, ImGuiWindowFlags_NoTitleBar)) {
-            IMGUI_TEST_ENGINE_ITEM_ADD(g.last_item_data.rect, g.last_item_data.id);
+        if flag_clear(window.Flags, ImGuiWindowFlags_NoTitleBar) && g.TestEngineHookItems {
+            let (title_bar_item_rect, title_bar_item_id) = (g.last_item_data.rect, g.last_item_data.id);
+            ImGuiTestEngineHook_ItemAdd(g, &title_bar_item_rect, title_bar_item_id);
         }
     }
     else
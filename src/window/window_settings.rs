@@ -10,6 +10,8 @@ use libc::{c_char, c_short};
 #[derive(Default, Debug, Clone)]
 pub struct ImGuiWindowSettings {
     pub ID: ImguiHandle,
+    // Name used to compute ID, "###" marker included
+    pub Name: String,
     pub Pos: ImVec2ih,
     // NB: Settings position are stored RELATIVE to the viewport! Whereas runtime ones are absolute positions.
     pub Size: ImVec2ih,
@@ -36,6 +38,6 @@ impl ImGuiWindowSettings {
 
     // *mut char GetName()             { return (this + 1); }
     pub fn GetName(&mut self) -> String {
-        todo!()
+        self.Name.clone()
     }
 }